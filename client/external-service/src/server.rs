@@ -20,11 +20,19 @@ use sp_avn_common::{
 };
 use sp_core::{blake2_256, sr25519, H160, H256};
 use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, net::SocketAddr, sync::Arc, time::Instant};
+use std::{
+    marker::PhantomData,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::Mutex;
 use tower_http::limit::RequestBodyLimitLayer;
 
 const MAX_BODY_SIZE: usize = 100_000; // 100KB
+const MAX_PAYLOAD_SIZE: usize = 1_000_000; // 1MB
+const PAYLOAD_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
 
 #[derive(Clone)]
 pub struct AppState<Block: BlockT, ClientT: BlockBackend<Block> + UsageProvider<Block>> {
@@ -131,6 +139,7 @@ where
         .route("/eth/query", post(query::<Block, ClientT>))
         .route("/roothash/{from_block}/{to_block}", get(roothash::<Block, ClientT>))
         .route("/latest_finalised_block", get(latest_finalised_block::<Block, ClientT>))
+        .route("/watchtower/payload/{uri_hex}", get(watchtower_payload))
         .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
         .with_state(Arc::new(state));
 
@@ -372,6 +381,126 @@ where
     Ok(hex::encode(n.encode()))
 }
 
+fn payload_url(uri: &str) -> Result<url::Url, (StatusCode, String)> {
+    let url = match uri.strip_prefix("ipfs://") {
+        Some(cid) => format!("{IPFS_GATEWAY}{cid}"),
+        None => uri.to_string(),
+    };
+
+    let url = url::Url::parse(&url)
+        .map_err(|e| server_error(format!("Invalid payload uri {uri}: {e:?}")))?;
+    if url.scheme() != "https" {
+        return Err(server_error(format!("Unsupported payload uri: {uri}")))
+    }
+
+    Ok(url)
+}
+
+/// Whether `ip` is a public address. Payloads are fetched from untrusted uris, so private,
+/// loopback and link-local addresses are rejected to stop proposals probing the node's network.
+fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            let is_shared = a == 100 && (b & 0b1100_0000) == 64;
+            !(ip.is_private() ||
+                ip.is_loopback() ||
+                ip.is_link_local() ||
+                ip.is_unspecified() ||
+                ip.is_broadcast() ||
+                ip.is_documentation() ||
+                ip.is_multicast() ||
+                is_shared)
+        },
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ip(&IpAddr::V4(ip)),
+            None =>
+                !(ip.is_loopback() ||
+                    ip.is_unspecified() ||
+                    ip.is_unique_local() ||
+                    ip.is_unicast_link_local() ||
+                    ip.is_multicast()),
+        },
+    }
+}
+
+/// Resolves the host of `url`, failing if any of its addresses is not public.
+async fn resolve_public_addrs(url: &url::Url) -> Result<Vec<SocketAddr>, (StatusCode, String)> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| server_error(format!("Payload uri {url} has no host")))?;
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.trim_matches(['[', ']']), port))
+        .await
+        .map_err(|e| server_error(format!("Error resolving {host}: {e:?}")))?
+        .collect();
+
+    if addrs.is_empty() || addrs.iter().any(|addr| !is_public_ip(&addr.ip())) {
+        return Err(server_error(format!("Payload uri {url} does not resolve to a public address")))
+    }
+
+    Ok(addrs)
+}
+
+/// Fetches the content of a watchtower proposal payload and returns it hex encoded, so the
+/// offchain worker can verify it against the hash committed in the proposal. Only https uris
+/// resolving to public addresses are fetched, without following redirects.
+async fn watchtower_payload(Path(uri_hex): Path<String>) -> Result<String, (StatusCode, String)> {
+    use alloy::transports::http::reqwest;
+
+    let uri_bytes = hex::decode(uri_hex.trim_start_matches("0x"))
+        .map_err(|e| server_error(format!("Error decoding uri hex: {e:?}")))?;
+    let uri = String::from_utf8(uri_bytes)
+        .map_err(|_| server_error("Payload uri contains invalid UTF8 bytes"))?;
+    let url = payload_url(&uri)?;
+    let addrs = resolve_public_addrs(&url).await?;
+
+    let fetch_start = Instant::now();
+    let mut client = reqwest::Client::builder()
+        .timeout(PAYLOAD_FETCH_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .no_proxy();
+    if let Some(url::Host::Domain(domain)) = url.host() {
+        // Connect to the addresses checked above, so the host cannot be rebound to another one
+        client = client.resolve_to_addrs(domain, &addrs);
+    }
+    let client = client
+        .build()
+        .map_err(|e| server_error(format!("Error creating http client: {e:?}")))?;
+    let mut response = client
+        .get(url.clone())
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| server_error(format!("Error fetching payload from {url}: {e:?}")))?;
+
+    if !response.status().is_success() {
+        return Err(server_error(format!(
+            "Error fetching payload from {url}: status {}",
+            response.status()
+        )))
+    }
+
+    if response.content_length().unwrap_or_default() > MAX_PAYLOAD_SIZE as u64 {
+        return Err(server_error(format!("Payload at {url} is too large")))
+    }
+
+    let mut payload = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| server_error(format!("Error reading payload from {url}: {e:?}")))?
+    {
+        if payload.len() + chunk.len() > MAX_PAYLOAD_SIZE {
+            return Err(server_error(format!("Payload at {url} is too large")))
+        }
+        payload.extend_from_slice(&chunk);
+    }
+    log::info!("⏲️ watchtower_payload {url} ({} bytes) {:?}", payload.len(), fetch_start.elapsed());
+
+    Ok(hex::encode(payload))
+}
+
 async fn sign_hashed_data<Block: BlockT, ClientT>(
     State(state): State<Arc<AppState<Block, ClientT>>>,
     headers: HeaderMap,
@@ -8,14 +8,16 @@
 use std::sync::Arc;
 
 use runtime_common::opaque::Block;
-use sp_avn_common::primitives::{AccountId, Balance, Nonce};
+use sp_avn_common::primitives::{AccountId, Balance, BlockNumber, Nonce};
 
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+pub mod avn_anchor;
 pub mod cross_chain_voting;
+pub mod parachain_staking;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -43,19 +45,27 @@ where
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: BlockBuilder<Block>,
     C::Api: pallet_cross_chain_voting_runtime_api::CrossChainVotingApi<Block>,
+    C::Api: pallet_parachain_staking_runtime_api::ParachainStakingApi<Block, AccountId, Balance>,
+    C::Api: pallet_avn_anchor_runtime_api::AvnAnchorApi<Block, BlockNumber>,
     P: TransactionPool + Sync + Send + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
-    use crate::rpc::cross_chain_voting::{AvnApiServer, CrossChainRpc};
+    use crate::rpc::{
+        avn_anchor::{AvnAnchorApiServer, AvnAnchorRpc},
+        cross_chain_voting::{AvnApiServer, CrossChainRpc},
+        parachain_staking::{ParachainStakingApiServer, ParachainStakingRpc},
+    };
 
     let mut module = RpcExtension::new(());
     let FullDeps { client, pool } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-    module.merge(CrossChainRpc::new(client).into_rpc())?;
+    module.merge(CrossChainRpc::new(client.clone()).into_rpc())?;
+    module.merge(ParachainStakingRpc::new(client.clone()).into_rpc())?;
+    module.merge(AvnAnchorRpc::new(client).into_rpc())?;
 
     Ok(module)
 }
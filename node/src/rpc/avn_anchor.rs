@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use pallet_avn_anchor_runtime_api::{
+    AvnAnchorApi as AvnAnchorRuntimeApi, ChainId, CheckpointId, CheckpointProof,
+};
+use runtime_common::opaque::Block;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_avn_common::{eth::EthereumId, primitives::BlockNumber};
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::Block as BlockT;
+
+type BlockHash = <Block as BlockT>::Hash;
+
+/// The Ethereum merkle proof of a checkpoint and the summary root it was published under.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointProofResponse {
+    /// The ABI encoded checkpoint.
+    pub leaf: Bytes,
+    pub merkle_path: Vec<H256>,
+    pub root_hash: H256,
+    pub from_block: BlockNumber,
+    pub to_block: BlockNumber,
+    /// The Ethereum transaction publishing the root, if it has been sent.
+    pub tx_id: Option<EthereumId>,
+    /// Whether Ethereum confirmed the root.
+    pub is_finalised: bool,
+}
+
+impl From<CheckpointProof<BlockNumber>> for CheckpointProofResponse {
+    fn from(proof: CheckpointProof<BlockNumber>) -> Self {
+        Self {
+            leaf: proof.leaf.into(),
+            merkle_path: proof.merkle_path,
+            root_hash: proof.root.root_hash,
+            from_block: proof.root.from_block,
+            to_block: proof.root.to_block,
+            tx_id: proof.root.tx_id,
+            is_finalised: proof.root.is_finalised,
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait AvnAnchorApi {
+    /// Returns `None` until the checkpoint is in a validated summary root, and once its proof
+    /// has been pruned after the retention period.
+    #[method(name = "avnAnchor_getCheckpointProof")]
+    fn get_checkpoint_proof(
+        &self,
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<CheckpointProofResponse>>;
+}
+
+pub struct AvnAnchorRpc<C> {
+    client: Arc<C>,
+}
+
+impl<C> AvnAnchorRpc<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+fn runtime_err(message: impl Into<String>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32000, message.into(), None::<()>)
+}
+
+impl<C> AvnAnchorApiServer for AvnAnchorRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AvnAnchorRuntimeApi<Block, BlockNumber>,
+{
+    fn get_checkpoint_proof(
+        &self,
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<CheckpointProofResponse>> {
+        // Defaults to the best block when no block hash is given
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let proof = self
+            .client
+            .runtime_api()
+            .checkpoint_proof(at, chain_id, checkpoint_id)
+            .map_err(|e| {
+                runtime_err(format!(
+                    "Failed to read the proof of checkpoint {checkpoint_id} of chain {chain_id}: {e}"
+                ))
+            })?;
+
+        Ok(proof.map(Into::into))
+    }
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use pallet_parachain_staking_runtime_api::{
+    CandidateApy, CandidateEraInfo, EraIndex, NominationUnlock, NominatorEraShare,
+    ParachainStakingApi as ParachainStakingRuntimeApi, PendingPayout, RewardPoint,
+};
+use runtime_common::opaque::Block;
+use sp_api::ProvideRuntimeApi;
+use sp_avn_common::primitives::{AccountId, Balance};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+type BlockHash = <Block as BlockT>::Hash;
+
+#[rpc(client, server)]
+pub trait ParachainStakingApi {
+    #[method(name = "parachainStaking_currentEra")]
+    fn current_era(&self, at: Option<BlockHash>) -> RpcResult<EraIndex>;
+
+    #[method(name = "parachainStaking_candidateEraInfo")]
+    fn candidate_era_info(
+        &self,
+        era: EraIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<CandidateEraInfo<AccountId, Balance>>>;
+
+    #[method(name = "parachainStaking_eraPoints")]
+    fn era_points(&self, era: EraIndex, at: Option<BlockHash>) -> RpcResult<RewardPoint>;
+
+    #[method(name = "parachainStaking_nominatorEraShares")]
+    fn nominator_era_shares(
+        &self,
+        nominator: AccountId,
+        era: EraIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NominatorEraShare<AccountId, Balance>>>;
+
+    #[method(name = "parachainStaking_pendingPayouts")]
+    fn pending_payouts(&self, at: Option<BlockHash>) -> RpcResult<Vec<PendingPayout<Balance>>>;
+
+    #[method(name = "parachainStaking_nominationUnlocks")]
+    fn nomination_unlocks(
+        &self,
+        nominator: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NominationUnlock<AccountId, Balance>>>;
+
+    #[method(name = "parachainStaking_projectedApys")]
+    fn projected_apys(&self, at: Option<BlockHash>) -> RpcResult<Vec<CandidateApy<AccountId>>>;
+}
+
+pub struct ParachainStakingRpc<C> {
+    client: Arc<C>,
+}
+
+impl<C> ParachainStakingRpc<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+fn runtime_err(message: impl Into<String>) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32000, message.into(), None::<()>)
+}
+
+impl<C> ParachainStakingRpc<C>
+where
+    C: HeaderBackend<Block>,
+{
+    /// Defaults to the best block when no block hash is given
+    fn block_hash(&self, at: Option<BlockHash>) -> BlockHash {
+        at.unwrap_or_else(|| self.client.info().best_hash)
+    }
+}
+
+impl<C> ParachainStakingApiServer for ParachainStakingRpc<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ParachainStakingRuntimeApi<Block, AccountId, Balance>,
+{
+    fn current_era(&self, at: Option<BlockHash>) -> RpcResult<EraIndex> {
+        self.client
+            .runtime_api()
+            .current_era(self.block_hash(at))
+            .map_err(|e| runtime_err(format!("Failed to read the current era: {e}")))
+    }
+
+    fn candidate_era_info(
+        &self,
+        era: EraIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<CandidateEraInfo<AccountId, Balance>>> {
+        self.client
+            .runtime_api()
+            .candidate_era_info(self.block_hash(at), era)
+            .map_err(|e| runtime_err(format!("Failed to read candidates of era {era}: {e}")))
+    }
+
+    fn era_points(&self, era: EraIndex, at: Option<BlockHash>) -> RpcResult<RewardPoint> {
+        self.client
+            .runtime_api()
+            .era_points(self.block_hash(at), era)
+            .map_err(|e| runtime_err(format!("Failed to read points of era {era}: {e}")))
+    }
+
+    fn nominator_era_shares(
+        &self,
+        nominator: AccountId,
+        era: EraIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NominatorEraShare<AccountId, Balance>>> {
+        self.client
+            .runtime_api()
+            .nominator_era_shares(self.block_hash(at), nominator, era)
+            .map_err(|e| runtime_err(format!("Failed to read nominator shares of era {era}: {e}")))
+    }
+
+    fn pending_payouts(&self, at: Option<BlockHash>) -> RpcResult<Vec<PendingPayout<Balance>>> {
+        self.client
+            .runtime_api()
+            .pending_payouts(self.block_hash(at))
+            .map_err(|e| runtime_err(format!("Failed to read pending payouts: {e}")))
+    }
+
+    fn nomination_unlocks(
+        &self,
+        nominator: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NominationUnlock<AccountId, Balance>>> {
+        self.client
+            .runtime_api()
+            .nomination_unlocks(self.block_hash(at), nominator)
+            .map_err(|e| runtime_err(format!("Failed to read nomination requests: {e}")))
+    }
+
+    fn projected_apys(&self, at: Option<BlockHash>) -> RpcResult<Vec<CandidateApy<AccountId>>> {
+        self.client
+            .runtime_api()
+            .projected_apys(self.block_hash(at))
+            .map_err(|e| runtime_err(format!("Failed to project APYs: {e}")))
+    }
+}
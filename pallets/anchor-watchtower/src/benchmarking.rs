@@ -0,0 +1,85 @@
+// Copyright 2026 Aventus DAO.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_system::{EventRecord, RawOrigin};
+use sp_std::vec;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    let events = frame_system::Pallet::<T>::events();
+    let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len().saturating_sub(1 as usize)];
+    assert_eq!(event, &system_event);
+}
+
+fn endpoint_uri<T: Config>() -> BoundedVec<u8, T::MaxEndpointLen> {
+    let mut uri = b"https://".to_vec();
+    uri.resize(T::MaxEndpointLen::get() as usize, b'a');
+    BoundedVec::try_from(uri).unwrap()
+}
+
+/// Benchmark-only capability: make `handler` the handler of an app chain. Implemented by the mock
+/// and the runtime (where the checkpoint source lives), and required via the `benchmarks!`
+/// `where_clause`.
+pub trait BenchmarkHelper<T: Config> {
+    fn setup_chain_handler(handler: &T::AccountId) -> ChainId;
+}
+
+benchmarks! {
+    where_clause { where T: BenchmarkHelper<T> }
+
+    set_verification_endpoint {
+        let handler: T::AccountId = account("handler", 0, 0);
+        let chain_id = T::setup_chain_handler(&handler);
+        let uri = endpoint_uri::<T>();
+    }: _(RawOrigin::Signed(handler), uri.clone(), CheckpointFormat::Keccak256Preimage)
+    verify {
+        assert!(VerificationEndpoints::<T>::contains_key(chain_id));
+        assert_last_event::<T>(Event::<T>::VerificationEndpointSet {
+            chain_id,
+            uri,
+            format: CheckpointFormat::Keccak256Preimage,
+        }.into());
+    }
+
+    remove_verification_endpoint {
+        let handler: T::AccountId = account("handler", 0, 0);
+        let chain_id = T::setup_chain_handler(&handler);
+        VerificationEndpoints::<T>::insert(
+            chain_id,
+            VerificationEndpoint { uri: endpoint_uri::<T>(), format: CheckpointFormat::Hash },
+        );
+    }: _(RawOrigin::Signed(handler))
+    verify {
+        assert!(!VerificationEndpoints::<T>::contains_key(chain_id));
+        assert_last_event::<T>(Event::<T>::VerificationEndpointRemoved { chain_id }.into());
+    }
+
+    on_checkpoint_submitted {
+        let handler: T::AccountId = account("handler", 0, 0);
+        let chain_id = T::setup_chain_handler(&handler);
+        VerificationEndpoints::<T>::insert(
+            chain_id,
+            VerificationEndpoint { uri: endpoint_uri::<T>(), format: CheckpointFormat::Hash },
+        );
+        let checkpoint_id: CheckpointId = 0;
+        let checkpoint = CheckpointData { hash: H256::repeat_byte(1), origin_id: 1 };
+    }: {
+        <Pallet<T> as CheckpointHooks>::on_checkpoint_submitted(chain_id, checkpoint_id, &checkpoint);
+    }
+    verify {
+        assert_eq!(
+            PendingCheckpoints::<T>::get(Pallet::<T>::external_ref(chain_id, checkpoint_id)),
+            Some((chain_id, checkpoint_id))
+        );
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::build_default().as_externality(),
+    crate::mock::TestRuntime,
+);
@@ -0,0 +1,86 @@
+// No state mutation allowed in this file because it is used in offchain context.
+
+use crate::*;
+pub const OCW_LOCK_PREFIX: &[u8] = b"anchor-wt-ocw::lock::";
+
+impl<T: Config> Pallet<T> {
+    /// Whether the app chain agrees with the checkpoint. A challenged checkpoint is only voted
+    /// against if the app chain agrees with the challenger's counter-claim, so a checkpoint that
+    /// matches neither claim is left undecided.
+    pub fn validate_checkpoint(
+        now: BlockNumberFor<T>,
+        checkpoint: &CheckpointToVerify,
+        endpoint: &VerificationEndpoint<T>,
+        proposal_id: &ProposalId,
+    ) -> Result<bool, String> {
+        let lock_id = Self::compute_lock_id(now, proposal_id, checkpoint);
+        let mut lock = AVN::<T>::get_ocw_locker(&lock_id);
+
+        let result = match lock.try_lock() {
+            Ok(guard) => {
+                let expected_hash = Self::fetch_checkpoint_hash(checkpoint.origin_id, endpoint)?;
+                guard.forget();
+                match checkpoint.counter_hash {
+                    _ if expected_hash == checkpoint.hash => Ok(true),
+                    Some(counter_hash) if expected_hash != counter_hash =>
+                        Err("App chain checkpoint matches neither claim".to_string()),
+                    _ => Ok(false),
+                }
+            },
+            Err(_lock_error) =>
+                Err("Failed to acquire OCW lock for verification processing".to_string()),
+        };
+        result
+    }
+
+    fn fetch_checkpoint_hash(
+        origin_id: OriginId,
+        endpoint: &VerificationEndpoint<T>,
+    ) -> Result<H256, String> {
+        let mut uri = endpoint.uri.to_vec();
+        uri.extend_from_slice(format!("/{}", origin_id).as_bytes());
+        let url_path = format!("watchtower/payload/{}", hex::encode(&uri));
+
+        log::debug!("Fetching app chain checkpoint using AVN service, path: {}", url_path);
+
+        let response = AVN::<T>::get_data_from_service(url_path)
+            .map_err(|e| format!("AVN service call failed: {:?}", e))?;
+
+        let response = core::str::from_utf8(&response)
+            .map_err(|_| "Response contains invalid UTF8 bytes".to_string())?;
+        let data = hex::decode(response.trim())
+            .map_err(|_| "Response contains invalid hex string".to_string())?;
+
+        Self::checkpoint_hash(&data, endpoint.format)
+    }
+
+    pub fn checkpoint_hash(data: &[u8], format: CheckpointFormat) -> Result<H256, String> {
+        match format {
+            CheckpointFormat::Hash => {
+                let hash_str = core::str::from_utf8(data)
+                    .map_err(|_| "Checkpoint contains invalid UTF8 bytes".to_string())?;
+                let hash_str = hash_str.trim();
+                let hash_str = hash_str.strip_prefix("0x").unwrap_or(hash_str);
+
+                let mut hash: [u8; 32] = [0; 32];
+                hex::decode_to_slice(hash_str, &mut hash[..])
+                    .map_err(|_| "Checkpoint is not a hex encoded 32 byte hash".to_string())?;
+                Ok(H256::from(hash))
+            },
+            CheckpointFormat::Blake2Preimage => Ok(H256::from(sp_io::hashing::blake2_256(data))),
+            CheckpointFormat::Keccak256Preimage => Ok(H256::from(sp_io::hashing::keccak_256(data))),
+        }
+    }
+
+    fn compute_lock_id(
+        now: BlockNumberFor<T>,
+        proposal_id: &ProposalId,
+        checkpoint: &CheckpointToVerify,
+    ) -> Vec<u8> {
+        let mut lock_id = OCW_LOCK_PREFIX.to_vec();
+        lock_id.extend_from_slice(&proposal_id.encode());
+        lock_id.extend_from_slice(&now.encode());
+        lock_id.extend_from_slice(&checkpoint.encode());
+        lock_id
+    }
+}
@@ -0,0 +1,161 @@
+//! Autogenerated weights for pallet_anchor_watchtower
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 48.0.0
+//! DATE: 2026-07-08, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `ip-172-31-1-153`, CPU: `AMD EPYC 7R32`
+//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./avn-parachain-collator
+// benchmark
+// pallet
+// --chain
+// dev
+// --wasm-execution=compiled
+// --template
+// frame-weight-template.hbs
+// --pallet
+// pallet_anchor_watchtower
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// anchor_watchtower_weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_anchor_watchtower.
+pub trait WeightInfo {
+	fn set_verification_endpoint() -> Weight;
+	fn remove_verification_endpoint() -> Weight;
+	fn on_checkpoint_submitted() -> Weight;
+}
+
+/// Weights for pallet_anchor_watchtower using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::VerificationEndpoints` (r:0 w:1)
+	/// Proof: `AnchorWatchtower::VerificationEndpoints` (`max_values`: None, `max_size`: Some(279), added: 2754, mode: `MaxEncodedLen`)
+	fn set_verification_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_310_000 picoseconds.
+		Weight::from_parts(14_820_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::VerificationEndpoints` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::VerificationEndpoints` (`max_values`: None, `max_size`: Some(279), added: 2754, mode: `MaxEncodedLen`)
+	fn remove_verification_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3744`
+		// Minimum execution time: 17_930_000 picoseconds.
+		Weight::from_parts(18_460_000, 3744)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AnchorWatchtower::VerificationEndpoints` (r:1 w:0)
+	/// Proof: `AnchorWatchtower::VerificationEndpoints` (`max_values`: None, `max_size`: Some(279), added: 2754, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::QueuedCheckpoints` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::QueuedCheckpoints` (`max_values`: Some(1), `max_size`: Some(5890), added: 6385, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::PendingCheckpoints` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::PendingCheckpoints` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::PendingProposalCount` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::PendingProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::ExternalRef` (r:1 w:1)
+	/// Proof: `Watchtower::ExternalRef` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::Proposals` (r:1 w:1)
+	/// Proof: `Watchtower::Proposals` (`max_values`: None, `max_size`: Some(8843), added: 11318, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::ActiveInternalProposal` (r:1 w:1)
+	/// Proof: `Watchtower::ActiveInternalProposal` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::CheckpointInfo` (r:0 w:1)
+	/// Proof: `AnchorWatchtower::CheckpointInfo` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::ProposalStatus` (r:0 w:1)
+	/// Proof: `Watchtower::ProposalStatus` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointVerification` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointVerification` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn on_checkpoint_submitted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `18693`
+		// Minimum execution time: 52_640_000 picoseconds.
+		Weight::from_parts(53_910_000, 18693)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::VerificationEndpoints` (r:0 w:1)
+	/// Proof: `AnchorWatchtower::VerificationEndpoints` (`max_values`: None, `max_size`: Some(279), added: 2754, mode: `MaxEncodedLen`)
+	fn set_verification_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80`
+		//  Estimated: `3517`
+		// Minimum execution time: 14_310_000 picoseconds.
+		Weight::from_parts(14_820_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::VerificationEndpoints` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::VerificationEndpoints` (`max_values`: None, `max_size`: Some(279), added: 2754, mode: `MaxEncodedLen`)
+	fn remove_verification_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3744`
+		// Minimum execution time: 17_930_000 picoseconds.
+		Weight::from_parts(18_460_000, 3744)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AnchorWatchtower::VerificationEndpoints` (r:1 w:0)
+	/// Proof: `AnchorWatchtower::VerificationEndpoints` (`max_values`: None, `max_size`: Some(279), added: 2754, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::QueuedCheckpoints` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::QueuedCheckpoints` (`max_values`: Some(1), `max_size`: Some(5890), added: 6385, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::PendingCheckpoints` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::PendingCheckpoints` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::PendingProposalCount` (r:1 w:1)
+	/// Proof: `AnchorWatchtower::PendingProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::ExternalRef` (r:1 w:1)
+	/// Proof: `Watchtower::ExternalRef` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::Proposals` (r:1 w:1)
+	/// Proof: `Watchtower::Proposals` (`max_values`: None, `max_size`: Some(8843), added: 11318, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::ActiveInternalProposal` (r:1 w:1)
+	/// Proof: `Watchtower::ActiveInternalProposal` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `AnchorWatchtower::CheckpointInfo` (r:0 w:1)
+	/// Proof: `AnchorWatchtower::CheckpointInfo` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Watchtower::ProposalStatus` (r:0 w:1)
+	/// Proof: `Watchtower::ProposalStatus` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointVerification` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointVerification` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn on_checkpoint_submitted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `18693`
+		// Minimum execution time: 52_640_000 picoseconds.
+		Weight::from_parts(53_910_000, 18693)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+}
@@ -0,0 +1,654 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use frame_support::{
+    dispatch::DispatchResult, pallet_prelude::*, storage::with_storage_layer, traits::IsType,
+};
+use frame_system::{
+    offchain::{CreateInherent, CreateTransactionBase},
+    pallet_prelude::*,
+};
+
+use codec::Decode;
+use log;
+pub use pallet_avn::{self as avn};
+use pallet_avn_anchor::{
+    ChainId, CheckpointData, CheckpointHooks, CheckpointId, CheckpointInterface,
+    CheckpointVerificationStatus, OriginId,
+};
+use pallet_watchtower::{NodesInterface, Payload, Proposal, Voters};
+use sp_core::H256;
+pub use sp_runtime::{
+    traits::{Dispatchable, Hash},
+    Perbill, RuntimeAppPublic, SaturatedConversion, Saturating,
+};
+use sp_std::prelude::*;
+use sp_watchtower::*;
+
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+pub const ANCHOR_WATCHTOWER_OCW_ID: &'static [u8] = b"anchor_watchtower";
+pub const ANCHOR_CHECKPOINT_CONTEXT: &'static [u8] = b"anchor_wt_checkpoint";
+pub const ANCHOR_CHALLENGE_CONTEXT: &'static [u8] = b"anchor_wt_challenge";
+
+pub type AVN<T> = avn::Pallet<T>;
+
+pub mod checkpoint_utils;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+pub mod default_weights;
+pub use default_weights::WeightInfo;
+
+#[cfg(test)]
+#[path = "tests/mock.rs"]
+mod mock;
+#[cfg(test)]
+#[path = "tests/tests.rs"]
+mod tests;
+
+pub use pallet::*;
+
+/// Validates the payloads of off-chain checkpoint proposals against the checkpoints anchored on
+/// chain.
+pub struct CheckpointPayloadValidator<T>(PhantomData<T>);
+impl<T: Config> ProposalPayloadValidator for CheckpointPayloadValidator<T> {
+    fn validate(proposal_type: &ProposalType, payload: &[u8]) -> Option<bool> {
+        if *proposal_type != ProposalType::Checkpoint {
+            return None
+        }
+
+        let Ok(to_verify) = CheckpointToVerify::decode(&mut &payload[..]) else {
+            return Some(false)
+        };
+
+        Some(T::Checkpoints::checkpoint(to_verify.chain_id, to_verify.checkpoint_id).map_or(
+            false,
+            |checkpoint| {
+                checkpoint.hash == to_verify.hash && checkpoint.origin_id == to_verify.origin_id
+            },
+        ))
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config:
+        CreateTransactionBase<Call<Self>>
+        + CreateInherent<Call<Self>>
+        + frame_system::Config
+        + pallet_watchtower::Config
+        + pallet_avn::Config
+    {
+        type RuntimeEvent: From<Event<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>
+            + Clone
+            + Eq
+            + PartialEq
+            + core::fmt::Debug;
+
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin>
+            + From<Call<Self>>;
+
+        /// Source of the app chain checkpoints to verify
+        type Checkpoints: CheckpointInterface<Self::AccountId>;
+
+        /// Maximum length of a chain's verification endpoint
+        #[pallet::constant]
+        type MaxEndpointLen: Get<u32>;
+
+        /// Share of watchtowers that must vote for a checkpoint to be accepted
+        #[pallet::constant]
+        type VerificationThreshold: Get<Perbill>;
+
+        /// Maximum checkpoint proposals submitted to the watchtowers and not yet decided. Later
+        /// checkpoints wait in this pallet so other internal proposals are not starved.
+        #[pallet::constant]
+        type MaxPendingProposals: Get<u32>;
+
+        /// Maximum checkpoints waiting to be submitted to the watchtowers
+        #[pallet::constant]
+        type MaxQueuedCheckpoints: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Where watchtowers can look up a chain's checkpoints, keyed by the chain id. Checkpoints of
+    /// chains without an endpoint are not verified.
+    #[pallet::storage]
+    #[pallet::getter(fn verification_endpoint)]
+    pub type VerificationEndpoints<T: Config> =
+        StorageMap<_, Blake2_128Concat, ChainId, VerificationEndpoint<T>, OptionQuery>;
+
+    /// Checkpoints waiting for a watchtower decision, keyed by the external ref of their proposal.
+    #[pallet::storage]
+    pub type PendingCheckpoints<T: Config> =
+        StorageMap<_, Blake2_128Concat, H256, (ChainId, CheckpointId), OptionQuery>;
+
+    /// Number of entries in `PendingCheckpoints`
+    #[pallet::storage]
+    pub type PendingProposalCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Checkpoints waiting to be submitted to the watchtowers, oldest first, with the external ref
+    /// of their proposal.
+    #[pallet::storage]
+    pub type QueuedCheckpoints<T: Config> = StorageValue<
+        _,
+        BoundedVec<(CheckpointToVerify, H256), T::MaxQueuedCheckpoints>,
+        ValueQuery,
+    >;
+
+    /// The checkpoints watchtowers are voting on, keyed by their proposal id.
+    #[pallet::storage]
+    #[pallet::getter(fn checkpoint_info)]
+    pub type CheckpointInfo<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProposalId, CheckpointToVerify, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A chain handler registered where its checkpoints can be verified.
+        VerificationEndpointSet {
+            chain_id: ChainId,
+            uri: BoundedVec<u8, T::MaxEndpointLen>,
+            format: CheckpointFormat,
+        },
+        /// A chain handler stopped the verification of its checkpoints.
+        VerificationEndpointRemoved { chain_id: ChainId },
+        /// An anchor watchtower proposal was activated.
+        CheckpointVerificationRequested { proposal_id: ProposalId, checkpoint: CheckpointToVerify },
+        /// A checkpoint could not be submitted to the watchtowers.
+        CheckpointVerificationRequestFailed {
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            error: DispatchError,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The sender is not the handler of a registered chain.
+        ChainNotRegistered,
+        /// The chain has no verification endpoint.
+        VerificationEndpointNotFound,
+        /// The verification endpoint is empty.
+        EmptyVerificationEndpoint,
+        /// The verification endpoint is not an https uri.
+        InsecureVerificationEndpoint,
+        /// The checkpoint is already being verified under the same reference.
+        VerificationAlreadyRequested,
+        /// Too many checkpoints are waiting to be submitted to the watchtowers.
+        VerificationQueueFull,
+        /// The checkpoint data in the proposal is invalid.
+        InvalidCheckpointProposal,
+        /// External payloads are not supported.
+        ExternalPayloadNotSupported,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register where watchtowers can fetch the checkpoints of the sender's chain, so new
+        /// checkpoints get verified. `{uri}/{origin_id}` must return the checkpoint in `format`.
+        /// Only https uris resolving to public addresses are fetched.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_verification_endpoint())]
+        pub fn set_verification_endpoint(
+            origin: OriginFor<T>,
+            uri: BoundedVec<u8, T::MaxEndpointLen>,
+            format: CheckpointFormat,
+        ) -> DispatchResult {
+            let handler = ensure_signed(origin)?;
+            let chain_id =
+                T::Checkpoints::chain_id(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
+            ensure!(!uri.is_empty(), Error::<T>::EmptyVerificationEndpoint);
+            ensure!(uri.starts_with(b"https://"), Error::<T>::InsecureVerificationEndpoint);
+
+            VerificationEndpoints::<T>::insert(
+                chain_id,
+                VerificationEndpoint { uri: uri.clone(), format },
+            );
+            Self::deposit_event(Event::VerificationEndpointSet { chain_id, uri, format });
+
+            Ok(())
+        }
+
+        /// Stop verifying new checkpoints of the sender's chain.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_verification_endpoint())]
+        pub fn remove_verification_endpoint(origin: OriginFor<T>) -> DispatchResult {
+            let handler = ensure_signed(origin)?;
+            let chain_id =
+                T::Checkpoints::chain_id(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
+            ensure!(
+                VerificationEndpoints::<T>::contains_key(chain_id),
+                Error::<T>::VerificationEndpointNotFound
+            );
+
+            VerificationEndpoints::<T>::remove(chain_id);
+            Self::deposit_event(Event::VerificationEndpointRemoved { chain_id });
+
+            Ok(())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            if QueuedCheckpoints::<T>::decode_len().unwrap_or_default() == 0 {
+                return T::DbWeight::get().reads(1)
+            }
+
+            // Submit checkpoints that were waiting for earlier proposals to be decided
+            Self::submit_next_checkpoint();
+            <T as pallet::Config>::WeightInfo::on_checkpoint_submitted()
+        }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            log::debug!("Anchor watchtower OCW running for block {:?}", now);
+
+            if pallet_watchtower::Pallet::<T>::record_ocw_run(ANCHOR_WATCHTOWER_OCW_ID, now)
+                .is_err()
+            {
+                return
+            }
+
+            if sp_io::offchain::is_validator() {
+                log::debug!("🛠️  Node is validator, skipping anchor watchtower validation.");
+                return
+            }
+
+            let maybe_checkpoint = pallet_watchtower::ActiveInternalProposal::<T>::get()
+                .and_then(|id| CheckpointInfo::<T>::get(id).map(|checkpoint| (id, checkpoint)));
+            if let Some((proposal_id, checkpoint)) = maybe_checkpoint {
+                let maybe_node_info = T::Watchtowers::get_node_from_local_signing_keys();
+                let (watchtower, signing_key) = match maybe_node_info {
+                    Some(info) => info,
+                    None => return,
+                };
+
+                Self::process_pending_validation(
+                    proposal_id,
+                    checkpoint,
+                    watchtower,
+                    signing_key,
+                    now,
+                );
+            }
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub fn external_ref(chain_id: ChainId, checkpoint_id: CheckpointId) -> H256 {
+            H256::from_slice(
+                &T::Hashing::hash_of(&(ANCHOR_CHECKPOINT_CONTEXT, chain_id, checkpoint_id))
+                    .as_ref(),
+            )
+        }
+
+        /// The proposal reference used to re-verify a challenged checkpoint. It differs from
+        /// `external_ref` so it cannot clash with the proposal raised on submission.
+        pub fn challenge_external_ref(chain_id: ChainId, checkpoint_id: CheckpointId) -> H256 {
+            H256::from_slice(
+                &T::Hashing::hash_of(&(ANCHOR_CHALLENGE_CONTEXT, chain_id, checkpoint_id)).as_ref(),
+            )
+        }
+
+        fn try_request_verification(
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            checkpoint: &CheckpointData,
+            counter_hash: Option<H256>,
+            external_ref: H256,
+        ) {
+            if !VerificationEndpoints::<T>::contains_key(chain_id) {
+                return
+            }
+
+            let to_verify = CheckpointToVerify {
+                chain_id,
+                checkpoint_id,
+                origin_id: checkpoint.origin_id,
+                hash: checkpoint.hash,
+                counter_hash,
+            };
+            if let Err(error) = Self::queue_verification(to_verify, external_ref) {
+                Self::report_failed_request(chain_id, checkpoint_id, error);
+                return
+            }
+
+            T::Checkpoints::set_verification_status(
+                chain_id,
+                checkpoint_id,
+                CheckpointVerificationStatus::InProgress,
+            );
+            Self::submit_next_checkpoint();
+        }
+
+        fn queue_verification(to_verify: CheckpointToVerify, external_ref: H256) -> DispatchResult {
+            let mut queue = QueuedCheckpoints::<T>::get();
+            ensure!(
+                !PendingCheckpoints::<T>::contains_key(external_ref) &&
+                    !pallet_watchtower::ExternalRef::<T>::contains_key(external_ref) &&
+                    !queue.iter().any(|(_, queued_ref)| *queued_ref == external_ref),
+                Error::<T>::VerificationAlreadyRequested
+            );
+
+            queue
+                .try_push((to_verify, external_ref))
+                .map_err(|_| Error::<T>::VerificationQueueFull)?;
+            QueuedCheckpoints::<T>::put(queue);
+
+            Ok(())
+        }
+
+        /// Submit the oldest queued checkpoint to the watchtowers, unless too many checkpoint
+        /// proposals are still undecided. Checkpoints stay queued while the watchtower queue is
+        /// full; any other failure is reported and the checkpoint is marked as disputed.
+        fn submit_next_checkpoint() {
+            if PendingProposalCount::<T>::get() >= T::MaxPendingProposals::get() {
+                return
+            }
+
+            let mut queue = QueuedCheckpoints::<T>::get();
+            let Some((to_verify, external_ref)) = queue.first().cloned() else { return };
+
+            // A failed request must not fail the call that triggered it, so we revert our changes
+            // and report the error instead.
+            let result =
+                with_storage_layer(|| Self::request_verification(&to_verify, external_ref));
+
+            match result {
+                Ok(()) => {},
+                Err(error)
+                    if error == pallet_watchtower::Error::<T>::InnerProposalQueueFull.into() =>
+                {
+                    log::warn!(
+                        "Watchtower queue is full, checkpoint {:?} of chain {:?} stays queued",
+                        to_verify.checkpoint_id,
+                        to_verify.chain_id
+                    );
+                    return
+                },
+                Err(error) => {
+                    Self::report_failed_request(to_verify.chain_id, to_verify.checkpoint_id, error);
+                    T::Checkpoints::set_verification_status(
+                        to_verify.chain_id,
+                        to_verify.checkpoint_id,
+                        CheckpointVerificationStatus::Disputed,
+                    );
+                },
+            }
+
+            queue.remove(0);
+            QueuedCheckpoints::<T>::put(queue);
+        }
+
+        fn report_failed_request(
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            error: DispatchError,
+        ) {
+            log::error!(
+                "Failed to request verification of checkpoint {:?} of chain {:?}: {:?}",
+                checkpoint_id,
+                chain_id,
+                error
+            );
+            Self::deposit_event(Event::CheckpointVerificationRequestFailed {
+                chain_id,
+                checkpoint_id,
+                error,
+            });
+        }
+
+        fn request_verification(
+            to_verify: &CheckpointToVerify,
+            external_ref: H256,
+        ) -> DispatchResult {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+
+            let request = ProposalRequest {
+                title: "Anchor Checkpoint".as_bytes().to_vec(),
+                external_ref,
+                threshold: T::VerificationThreshold::get(),
+                payload: RawPayload::Inline(to_verify.encode()),
+                source: ProposalSource::Internal(ProposalType::Checkpoint),
+                decision_rule: DecisionRule::SimpleMajority,
+                created_at: current_block.saturated_into::<u32>(),
+                vote_duration: None,
+            };
+
+            <pallet_watchtower::Pallet<T> as WatchtowerInterface>::submit_proposal(None, request)?;
+
+            PendingCheckpoints::<T>::insert(
+                external_ref,
+                (to_verify.chain_id, to_verify.checkpoint_id),
+            );
+            PendingProposalCount::<T>::mutate(|count| *count = count.saturating_add(1));
+
+            Ok(())
+        }
+
+        fn process_new_proposal(proposal_id: ProposalId, proposal: Proposal<T>) -> DispatchResult {
+            let checkpoint = match &proposal.payload {
+                Payload::Inline(data) => CheckpointToVerify::decode(&mut &data[..])
+                    .map_err(|_| Error::<T>::InvalidCheckpointProposal)?,
+                _ => return Err(Error::<T>::ExternalPayloadNotSupported.into()),
+            };
+
+            CheckpointInfo::<T>::insert(proposal_id, checkpoint.clone());
+            Self::deposit_event(Event::CheckpointVerificationRequested { proposal_id, checkpoint });
+
+            Ok(())
+        }
+
+        fn complete_verification(
+            proposal_id: ProposalId,
+            external_ref: &H256,
+            status: CheckpointVerificationStatus,
+        ) {
+            CheckpointInfo::<T>::remove(proposal_id);
+
+            if let Some((chain_id, checkpoint_id)) = PendingCheckpoints::<T>::take(external_ref) {
+                PendingProposalCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                T::Checkpoints::set_verification_status(chain_id, checkpoint_id, status);
+            }
+        }
+
+        fn process_pending_validation(
+            proposal_id: ProposalId,
+            checkpoint: CheckpointToVerify,
+            watchtower: T::AccountId,
+            signing_key: T::SignerId,
+            now: BlockNumberFor<T>,
+        ) {
+            if Voters::<T>::contains_key(proposal_id, &watchtower) ||
+                pallet_watchtower::Pallet::<T>::vote_in_progress(
+                    proposal_id,
+                    watchtower.clone(),
+                    now,
+                )
+            {
+                log::debug!(
+                    "Vote already submitted. Proposal {:?}, Watchtower {:?}",
+                    proposal_id,
+                    watchtower
+                );
+
+                return
+            }
+
+            let Some(endpoint) = VerificationEndpoints::<T>::get(checkpoint.chain_id) else {
+                log::debug!("No verification endpoint for chain {:?}", checkpoint.chain_id);
+                return
+            };
+
+            let result = Self::validate_checkpoint(now, &checkpoint, &endpoint, &proposal_id);
+            let in_favor = match result {
+                Ok(in_favor) => in_favor,
+                Err(e) => {
+                    log::error!("Error validating checkpoint: {:?}. Error: {:?}", checkpoint, e);
+                    return
+                },
+            };
+
+            if let Err(e) = pallet_watchtower::Pallet::<T>::submit_vote(
+                proposal_id,
+                in_favor,
+                signing_key,
+                watchtower,
+                now,
+            ) {
+                log::error!("Error voting on proposal {:?}. Error: {:?}", proposal_id, e);
+            };
+        }
+    }
+
+    impl<T: Config> CheckpointHooks for Pallet<T> {
+        fn on_checkpoint_submitted(
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            checkpoint: &CheckpointData,
+        ) {
+            Self::try_request_verification(
+                chain_id,
+                checkpoint_id,
+                checkpoint,
+                None,
+                Self::external_ref(chain_id, checkpoint_id),
+            );
+        }
+
+        fn on_checkpoint_submitted_weight() -> Weight {
+            <T as pallet::Config>::WeightInfo::on_checkpoint_submitted()
+        }
+
+        fn on_checkpoint_challenged(
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            checkpoint: &CheckpointData,
+            counter_hash: H256,
+        ) {
+            Self::try_request_verification(
+                chain_id,
+                checkpoint_id,
+                checkpoint,
+                Some(counter_hash),
+                Self::challenge_external_ref(chain_id, checkpoint_id),
+            );
+        }
+
+        fn on_checkpoint_challenged_weight() -> Weight {
+            <T as pallet::Config>::WeightInfo::on_checkpoint_submitted()
+        }
+    }
+
+    impl<T: Config> WatchtowerHooks<Proposal<T>, T::AccountId> for Pallet<T> {
+        fn on_proposal_submitted(proposal_id: ProposalId, proposal: Proposal<T>) -> DispatchResult {
+            match &proposal.source {
+                ProposalSource::Internal(ProposalType::Checkpoint) =>
+                    Self::process_new_proposal(proposal_id, proposal),
+                _ => Ok(()),
+            }
+        }
+
+        fn on_voting_completed(
+            proposal_id: ProposalId,
+            external_ref: &H256,
+            result: &ProposalStatusEnum,
+        ) {
+            let status = match result {
+                ProposalStatusEnum::Resolved { passed: true } =>
+                    CheckpointVerificationStatus::Verified,
+                ProposalStatusEnum::Resolved { passed: false } =>
+                    CheckpointVerificationStatus::Rejected,
+                _ => CheckpointVerificationStatus::Disputed,
+            };
+
+            Self::complete_verification(proposal_id, external_ref, status);
+        }
+
+        fn on_cancelled(proposal_id: ProposalId, external_ref: &H256) {
+            Self::complete_verification(
+                proposal_id,
+                external_ref,
+                CheckpointVerificationStatus::Disputed,
+            );
+        }
+    }
+
+    /// How a chain's verification endpoint returns a checkpoint.
+    #[derive(
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Debug,
+        Eq,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub enum CheckpointFormat {
+        /// The hex encoded checkpoint hash.
+        Hash,
+        /// The data whose blake2-256 hash is the checkpoint.
+        Blake2Preimage,
+        /// The data whose keccak-256 hash is the checkpoint.
+        Keccak256Preimage,
+    }
+
+    #[derive(
+        Encode,
+        Decode,
+        RuntimeDebugNoBound,
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct VerificationEndpoint<T: Config> {
+        pub uri: BoundedVec<u8, T::MaxEndpointLen>,
+        pub format: CheckpointFormat,
+    }
+
+    #[derive(
+        Encode,
+        Decode,
+        Default,
+        Clone,
+        PartialEq,
+        Debug,
+        Eq,
+        TypeInfo,
+        MaxEncodedLen,
+        DecodeWithMemTracking,
+    )]
+    pub struct CheckpointToVerify {
+        pub chain_id: ChainId,
+        pub checkpoint_id: CheckpointId,
+        pub origin_id: OriginId,
+        pub hash: H256,
+        /// The hash claimed by the challenger, if the checkpoint is challenged.
+        pub counter_hash: Option<H256>,
+    }
+}
@@ -0,0 +1,471 @@
+#![cfg(test)]
+
+use crate::{self as pallet_anchor_watchtower, *};
+pub use codec::alloc::sync::Arc;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, EnsureOrigin},
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+};
+use frame_system::{self as system, EnsureRoot, EnsureSigned};
+pub use parking_lot::RwLock;
+pub use sp_avn_common::avn_tests_helpers::utilities::{
+    get_test_account_from_mnemonic, TestAccount,
+};
+use sp_core::offchain::{
+    testing::{OffchainState, PoolState, TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+pub use sp_core::{crypto::DEV_PHRASE, sr25519};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+pub use sp_runtime::{
+    testing::{TestXt, UintAuthorityId},
+    traits::{IdentityLookup, Verify},
+    BuildStorage, Perbill,
+};
+use std::{cell::RefCell, collections::HashMap};
+
+use pallet_watchtower::NodesInterface;
+
+pub type Signature = sr25519::Signature;
+pub type AccountId = <Signature as Verify>::Signer;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+type Block = frame_system::mocking::MockBlock<TestRuntime>;
+type SignerId = pallet_node_manager::sr25519::AuthorityId;
+
+frame_support::construct_runtime!(
+    pub enum TestRuntime
+    {
+        System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+        AVN: pallet_avn::{Pallet, Storage, Event, Config<T>},
+        Watchtower: pallet_watchtower::{Pallet, Call, Storage, Event<T>},
+        AnchorWatchtower: pallet_anchor_watchtower::{Pallet, Call, Storage, Event<T>},
+    }
+);
+
+impl pallet_watchtower::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type ExternalProposerOrigin = EnsureExternalProposerOrRoot;
+    type SignerId = SignerId;
+    type Public = AccountId;
+    type Signature = Signature;
+    type Watchtowers = TestNodeManager;
+    type WatchtowerHooks = AnchorWatchtower;
+    type PayloadValidator = ();
+    type WeightInfo = ();
+    type SignedTxLifetime = ConstU32<5>;
+    type MaxTitleLen = ConstU32<512>;
+    type MaxInlineLen = ConstU32<8192>;
+    type MaxUriLen = ConstU32<2040>;
+    type MaxInternalProposalLen = ConstU32<100>;
+    type MaxWeightedVoters = ConstU32<100>;
+    type MaxActiveExternalProposals = ConstU32<10>;
+}
+
+parameter_types! {
+    pub const VerificationThreshold: Perbill = Perbill::from_percent(51);
+}
+
+impl Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Checkpoints = TestCheckpoints;
+    type MaxEndpointLen = ConstU32<256>;
+    type VerificationThreshold = VerificationThreshold;
+    type MaxPendingProposals = ConstU32<2>;
+    type MaxQueuedCheckpoints = ConstU32<3>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const Period: u64 = 1;
+    pub const Offset: u64 = 0;
+}
+
+impl<LocalCall> system::offchain::CreateTransactionBase<LocalCall> for TestRuntime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for TestRuntime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = Weight::from_parts(1024 as u64, 0);
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
+    pub const ChallengePeriod: u64 = 2;
+
+    pub BlockWeights: frame_system::limits::BlockWeights =
+        frame_system::limits::BlockWeights::simple_max(
+            Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
+        );
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl system::Config for TestRuntime {
+    type Block = Block;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 0u64;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for TestRuntime {
+    type Balance = u128;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
+
+impl pallet_timestamp::Config for TestRuntime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<12000>;
+    type WeightInfo = ();
+}
+
+impl pallet_avn::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = UintAuthorityId;
+    type EthereumPublicKeyChecker = ();
+    type NewSessionHandler = ();
+    type DisabledValidatorChecker = ();
+    type WeightInfo = ();
+}
+
+pub fn get_default_voter() -> TestAccount {
+    get_test_account_from_mnemonic(DEV_PHRASE)
+}
+
+pub fn watchtower_1() -> AccountId {
+    get_default_voter().account_id()
+}
+pub fn watchtower_2() -> AccountId {
+    TestAccount::new([12u8; 32]).account_id()
+}
+pub fn watchtower_3() -> AccountId {
+    TestAccount::new([13u8; 32]).account_id()
+}
+pub fn watchtower_4() -> AccountId {
+    TestAccount::new([14u8; 32]).account_id()
+}
+pub fn watchtower_5() -> AccountId {
+    TestAccount::new([15u8; 32]).account_id()
+}
+pub fn watchtower_6() -> AccountId {
+    TestAccount::new([16u8; 32]).account_id()
+}
+pub fn watchtower_7() -> AccountId {
+    TestAccount::new([17u8; 32]).account_id()
+}
+pub fn watchtower_8() -> AccountId {
+    TestAccount::new([18u8; 32]).account_id()
+}
+pub fn watchtower_9() -> AccountId {
+    TestAccount::new([19u8; 32]).account_id()
+}
+pub fn watchtower_10() -> AccountId {
+    TestAccount::new([20u8; 32]).account_id()
+}
+
+pub fn watchtower_owner_1() -> AccountId {
+    TestAccount::new([31u8; 32]).account_id()
+}
+pub fn watchtower_owner_2() -> AccountId {
+    TestAccount::new([32u8; 32]).account_id()
+}
+pub fn watchtower_owner_3() -> AccountId {
+    TestAccount::new([33u8; 32]).account_id()
+}
+
+pub fn chain_handler() -> AccountId {
+    TestAccount::new([41u8; 32]).account_id()
+}
+
+pub const CHAIN_ID: ChainId = 7;
+
+pub fn get_signing_key_for_wt_1() -> SignerId {
+    get_default_voter().public_key().into()
+}
+
+pub fn signing_key(index: u8) -> SignerId {
+    TestAccount::new([index; 32]).public_key().into()
+}
+
+#[allow(dead_code)]
+pub fn random_user() -> AccountId {
+    TestAccount::new([99u8; 32]).account_id()
+}
+
+thread_local! {
+    pub static AUTHORIZED_WATCHTOWERS: RefCell<Vec<AccountId>> = RefCell::new(vec![
+        watchtower_1(),
+        watchtower_2(),
+        watchtower_3(),
+        watchtower_4(),
+        watchtower_5(),
+        watchtower_6(),
+        watchtower_7(),
+        watchtower_8(),
+        watchtower_9(),
+        watchtower_10(),
+    ]);
+
+    pub static NODE_SIGNING_KEYS: RefCell<std::collections::HashMap<AccountId, SignerId>> =
+        RefCell::new({
+            let mut keys = std::collections::HashMap::new();
+            keys.insert(watchtower_1(), get_signing_key_for_wt_1());
+            keys.insert(watchtower_2(), signing_key(2));
+            keys.insert(watchtower_3(), signing_key(3));
+            keys.insert(watchtower_4(), signing_key(4));
+            keys.insert(watchtower_5(), signing_key(5));
+            keys.insert(watchtower_6(), signing_key(6));
+            keys.insert(watchtower_7(), signing_key(7));
+            keys.insert(watchtower_8(), signing_key(8));
+            keys.insert(watchtower_9(), signing_key(9));
+            keys.insert(watchtower_10(), signing_key(10));
+            keys
+        });
+
+    pub static NODE_OWNERS: RefCell<std::collections::HashMap<AccountId, Vec<AccountId>>> =
+        RefCell::new({
+            let mut keys = std::collections::HashMap::new();
+            keys.insert(watchtower_owner_1(), vec![watchtower_1(), watchtower_2(), watchtower_3()]);
+            keys.insert(watchtower_owner_2(), vec![watchtower_4(), watchtower_5(), watchtower_6()]);
+            keys.insert(watchtower_owner_3(), vec![watchtower_7(), watchtower_8(), watchtower_9(), watchtower_10()]);
+            keys
+        });
+}
+
+thread_local! {
+    pub static CHAIN_HANDLERS: RefCell<HashMap<AccountId, ChainId>> =
+        RefCell::new(HashMap::from([(chain_handler(), CHAIN_ID)]));
+
+    pub static VERIFICATION_STATUS: RefCell<HashMap<(ChainId, CheckpointId), CheckpointVerificationStatus>> =
+        RefCell::new(HashMap::new());
+
+    pub static CHECKPOINTS: RefCell<HashMap<(ChainId, CheckpointId), CheckpointData>> =
+        RefCell::new(HashMap::new());
+}
+
+pub fn verification_status(
+    chain_id: ChainId,
+    checkpoint_id: CheckpointId,
+) -> Option<CheckpointVerificationStatus> {
+    VERIFICATION_STATUS.with(|s| s.borrow().get(&(chain_id, checkpoint_id)).copied())
+}
+
+pub struct TestCheckpoints;
+impl CheckpointInterface<AccountId> for TestCheckpoints {
+    fn chain_id(handler: &AccountId) -> Option<ChainId> {
+        CHAIN_HANDLERS.with(|h| h.borrow().get(handler).copied())
+    }
+
+    fn checkpoint(chain_id: ChainId, checkpoint_id: CheckpointId) -> Option<CheckpointData> {
+        CHECKPOINTS.with(|c| c.borrow().get(&(chain_id, checkpoint_id)).cloned())
+    }
+
+    fn set_verification_status(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        status: CheckpointVerificationStatus,
+    ) {
+        VERIFICATION_STATUS.with(|s| s.borrow_mut().insert((chain_id, checkpoint_id), status));
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::benchmarking::BenchmarkHelper<TestRuntime> for TestRuntime {
+    fn setup_chain_handler(handler: &AccountId) -> ChainId {
+        CHAIN_HANDLERS.with(|h| h.borrow_mut().insert(handler.clone(), CHAIN_ID));
+        CHAIN_ID
+    }
+}
+
+pub struct ExtBuilder {
+    pub storage: sp_runtime::Storage,
+    offchain_state: Option<Arc<RwLock<OffchainState>>>,
+    pool_state: Option<Arc<RwLock<PoolState>>>,
+    txpool_extension: Option<TestTransactionPoolExt>,
+    offchain_extension: Option<TestOffchainExt>,
+    offchain_registered: bool,
+}
+
+impl ExtBuilder {
+    pub fn build_default() -> Self {
+        let storage = frame_system::GenesisConfig::<TestRuntime>::default()
+            .build_storage()
+            .unwrap()
+            .into();
+
+        Self {
+            storage,
+            pool_state: None,
+            offchain_state: None,
+            txpool_extension: None,
+            offchain_extension: None,
+            offchain_registered: false,
+        }
+    }
+
+    pub fn for_offchain_worker(mut self) -> Self {
+        assert!(!self.offchain_registered);
+        let (offchain, offchain_state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        self.txpool_extension = Some(pool);
+        self.offchain_extension = Some(offchain);
+        self.pool_state = Some(pool_state);
+        self.offchain_state = Some(offchain_state);
+        self.offchain_registered = true;
+        self
+    }
+
+    pub fn as_externality(self) -> sp_io::TestExternalities {
+        let keystore = MemoryKeystore::new();
+        let (offchain, _) = TestOffchainExt::new();
+
+        let mut ext = sp_io::TestExternalities::from(self.storage);
+        ext.register_extension(KeystoreExt(Arc::new(keystore)));
+        ext.register_extension(OffchainDbExt::new(offchain));
+        // Events do not get emitted on block 0, so we increment the block here
+        ext.execute_with(|| {
+            frame_system::Pallet::<TestRuntime>::set_block_number(1u32.into());
+        });
+        ext
+    }
+
+    pub fn as_externality_with_state(
+        self,
+    ) -> (sp_io::TestExternalities, Arc<RwLock<PoolState>>, Arc<RwLock<OffchainState>>) {
+        assert!(self.offchain_registered);
+        // The local watchtower node signs its votes with the default voter's key
+        let keystore = MemoryKeystore::new();
+        keystore
+            .sr25519_generate_new(<SignerId as RuntimeAppPublic>::ID, Some(DEV_PHRASE))
+            .unwrap();
+
+        let mut ext = sp_io::TestExternalities::from(self.storage);
+        ext.register_extension(KeystoreExt(Arc::new(keystore)));
+        ext.register_extension(OffchainDbExt::new(self.offchain_extension.clone().unwrap()));
+        ext.register_extension(OffchainWorkerExt::new(self.offchain_extension.unwrap()));
+        ext.register_extension(TransactionPoolExt::new(self.txpool_extension.unwrap()));
+        ext.execute_with(|| {
+            frame_system::Pallet::<TestRuntime>::set_block_number(1u32.into());
+        });
+        (ext, self.pool_state.unwrap(), self.offchain_state.unwrap())
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) fn roll_forward(num_blocks_to_roll: u64) {
+    let mut current_block = System::block_number();
+    let target_block = current_block + num_blocks_to_roll;
+    while current_block < target_block {
+        current_block = roll_one_block();
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) fn roll_one_block() -> u64 {
+    Balances::on_finalize(System::block_number());
+    System::on_finalize(System::block_number());
+    System::set_block_number(System::block_number() + 1);
+    System::on_initialize(System::block_number());
+    Balances::on_initialize(System::block_number());
+    AnchorWatchtower::on_initialize(System::block_number());
+    Watchtower::on_idle(System::block_number(), BlockWeights::get().max_block);
+    System::block_number()
+}
+
+pub struct TestNodeManager;
+impl NodesInterface<AccountId, SignerId> for TestNodeManager {
+    fn is_authorized_watchtower(node: &AccountId) -> bool {
+        AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().contains(node))
+    }
+
+    fn is_watchtower_owner(who: &AccountId) -> bool {
+        NODE_OWNERS.with(|keys| keys.borrow().contains_key(who))
+    }
+
+    fn get_node_signing_key(node: &AccountId) -> Option<SignerId> {
+        NODE_SIGNING_KEYS.with(|keys| keys.borrow().get(node).cloned())
+    }
+
+    fn get_node_owner(node: &AccountId) -> Option<AccountId> {
+        NODE_OWNERS.with(|keys| {
+            keys.borrow()
+                .iter()
+                .find(|(_, nodes)| nodes.contains(node))
+                .map(|(owner, _)| owner.clone())
+        })
+    }
+
+    fn get_node_from_local_signing_keys() -> Option<(AccountId, SignerId)> {
+        let maybe_watchtower_1 =
+            AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().first().unwrap().clone());
+        let watchtower_1 = watchtower_1();
+        assert!(watchtower_1 == maybe_watchtower_1);
+        Some((
+            watchtower_1,
+            NODE_SIGNING_KEYS.with(|keys| keys.borrow().get(&watchtower_1).unwrap().clone()),
+        ))
+    }
+
+    fn get_watchtower_voting_weight(owner: &AccountId) -> u32 {
+        NODE_OWNERS.with(|keys| keys.borrow().get(owner).map_or(0, |v| v.len() as u32))
+    }
+
+    fn get_watchtower_stakes(_source: &ProposalSource) -> Vec<(AccountId, u128)> {
+        Vec::new()
+    }
+
+    fn get_authorized_watchtowers_count() -> u32 {
+        AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().len() as u32)
+    }
+}
+
+pub struct EnsureExternalProposerOrRoot;
+impl EnsureOrigin<RuntimeOrigin> for EnsureExternalProposerOrRoot {
+    type Success = Option<AccountId>;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        if EnsureRoot::<AccountId>::try_origin(o.clone()).is_ok() {
+            return Ok(None)
+        }
+
+        match EnsureSigned::<AccountId>::try_origin(o) {
+            Ok(who) => {
+                match Watchtower::proposal_admin() {
+                    Ok(admin) if who == admin => Ok(Some(who)),
+                    Ok(_admin) => Err(RuntimeOrigin::signed(who)), // non-admin signer → reject
+                    Err(_) => Ok(Some(who)),                       // no admin → allow anyone
+                }
+            },
+            Err(o) => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        use frame_benchmarking::whitelisted_caller;
+        Ok(RuntimeOrigin::signed(whitelisted_caller()))
+    }
+}
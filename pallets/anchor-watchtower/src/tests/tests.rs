@@ -0,0 +1,653 @@
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_core::offchain::testing::{OffchainState, PendingRequest, PoolState};
+
+const ENDPOINT: &[u8] = b"https://appchain.example.com/checkpoints";
+const ORIGIN_ID: OriginId = 42;
+
+fn endpoint_uri() -> BoundedVec<u8, ConstU32<256>> {
+    BoundedVec::try_from(ENDPOINT.to_vec()).unwrap()
+}
+
+fn set_endpoint(format: CheckpointFormat) {
+    assert_ok!(AnchorWatchtower::set_verification_endpoint(
+        RuntimeOrigin::signed(chain_handler()),
+        endpoint_uri(),
+        format
+    ));
+}
+
+fn checkpoint(hash: H256) -> CheckpointData {
+    CheckpointData { hash, origin_id: ORIGIN_ID }
+}
+
+fn submit_checkpoint(checkpoint_id: CheckpointId, hash: H256) -> ProposalId {
+    AnchorWatchtower::on_checkpoint_submitted(CHAIN_ID, checkpoint_id, &checkpoint(hash));
+    pallet_watchtower::ExternalRef::<TestRuntime>::get(AnchorWatchtower::external_ref(
+        CHAIN_ID,
+        checkpoint_id,
+    ))
+}
+
+fn challenge_checkpoint(checkpoint_id: CheckpointId, hash: H256, counter_hash: H256) -> ProposalId {
+    AnchorWatchtower::on_checkpoint_challenged(
+        CHAIN_ID,
+        checkpoint_id,
+        &checkpoint(hash),
+        counter_hash,
+    );
+    pallet_watchtower::ExternalRef::<TestRuntime>::get(AnchorWatchtower::challenge_external_ref(
+        CHAIN_ID,
+        checkpoint_id,
+    ))
+}
+
+mod verification_endpoint {
+    use super::*;
+
+    #[test]
+    fn chain_handler_can_set_an_endpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+
+            assert_eq!(
+                AnchorWatchtower::verification_endpoint(CHAIN_ID),
+                Some(VerificationEndpoint { uri: endpoint_uri(), format: CheckpointFormat::Hash })
+            );
+            System::assert_last_event(
+                Event::VerificationEndpointSet {
+                    chain_id: CHAIN_ID,
+                    uri: endpoint_uri(),
+                    format: CheckpointFormat::Hash,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_non_chain_handler() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_noop!(
+                AnchorWatchtower::set_verification_endpoint(
+                    RuntimeOrigin::signed(random_user()),
+                    endpoint_uri(),
+                    CheckpointFormat::Hash
+                ),
+                Error::<TestRuntime>::ChainNotRegistered
+            );
+            assert_noop!(
+                AnchorWatchtower::remove_verification_endpoint(
+                    RuntimeOrigin::signed(random_user())
+                ),
+                Error::<TestRuntime>::ChainNotRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_empty_endpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_noop!(
+                AnchorWatchtower::set_verification_endpoint(
+                    RuntimeOrigin::signed(chain_handler()),
+                    BoundedVec::default(),
+                    CheckpointFormat::Hash
+                ),
+                Error::<TestRuntime>::EmptyVerificationEndpoint
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_endpoint_that_is_not_https() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            for uri in [&b"http://appchain.example.com"[..], b"ipfs://checkpoints", b"appchain"] {
+                assert_noop!(
+                    AnchorWatchtower::set_verification_endpoint(
+                        RuntimeOrigin::signed(chain_handler()),
+                        BoundedVec::try_from(uri.to_vec()).unwrap(),
+                        CheckpointFormat::Hash
+                    ),
+                    Error::<TestRuntime>::InsecureVerificationEndpoint
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn chain_handler_can_remove_an_endpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_noop!(
+                AnchorWatchtower::remove_verification_endpoint(RuntimeOrigin::signed(
+                    chain_handler()
+                )),
+                Error::<TestRuntime>::VerificationEndpointNotFound
+            );
+
+            set_endpoint(CheckpointFormat::Hash);
+            assert_ok!(AnchorWatchtower::remove_verification_endpoint(RuntimeOrigin::signed(
+                chain_handler()
+            )));
+
+            assert_eq!(AnchorWatchtower::verification_endpoint(CHAIN_ID), None);
+            System::assert_last_event(
+                Event::VerificationEndpointRemoved { chain_id: CHAIN_ID }.into(),
+            );
+        });
+    }
+}
+
+mod checkpoint_submission {
+    use super::*;
+
+    #[test]
+    fn checkpoints_of_chains_without_endpoint_are_not_verified() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let proposal_id = submit_checkpoint(0, H256::repeat_byte(1));
+
+            assert_eq!(proposal_id, ProposalId::default());
+            assert_eq!(CheckpointInfo::<TestRuntime>::iter().next(), None);
+            assert_eq!(verification_status(CHAIN_ID, 0), None);
+        });
+    }
+
+    #[test]
+    fn checkpoints_are_submitted_for_verification() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let hash = H256::repeat_byte(1);
+
+            let proposal_id = submit_checkpoint(0, hash);
+
+            let expected = CheckpointToVerify {
+                chain_id: CHAIN_ID,
+                checkpoint_id: 0,
+                origin_id: ORIGIN_ID,
+                hash,
+                counter_hash: None,
+            };
+            assert_eq!(AnchorWatchtower::checkpoint_info(proposal_id), Some(expected.clone()));
+            assert_eq!(
+                PendingCheckpoints::<TestRuntime>::get(AnchorWatchtower::external_ref(CHAIN_ID, 0)),
+                Some((CHAIN_ID, 0))
+            );
+            assert_eq!(
+                verification_status(CHAIN_ID, 0),
+                Some(CheckpointVerificationStatus::InProgress)
+            );
+            System::assert_has_event(
+                Event::CheckpointVerificationRequested { proposal_id, checkpoint: expected }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn queued_checkpoints_are_verified_in_turn() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let first_proposal = submit_checkpoint(0, H256::repeat_byte(1));
+            let second_proposal = submit_checkpoint(1, H256::repeat_byte(2));
+
+            assert!(AnchorWatchtower::checkpoint_info(first_proposal).is_some());
+            // The second proposal is only voted on once it becomes active
+            assert_eq!(AnchorWatchtower::checkpoint_info(second_proposal), None);
+            assert_eq!(
+                verification_status(CHAIN_ID, 1),
+                Some(CheckpointVerificationStatus::InProgress)
+            );
+        });
+    }
+
+    #[test]
+    fn checkpoints_wait_while_too_many_proposals_are_pending() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let first_proposal = submit_checkpoint(0, H256::repeat_byte(1));
+            submit_checkpoint(1, H256::repeat_byte(2));
+            let third_proposal = submit_checkpoint(2, H256::repeat_byte(3));
+
+            assert_eq!(third_proposal, ProposalId::default());
+            assert_eq!(PendingProposalCount::<TestRuntime>::get(), 2);
+            assert_eq!(QueuedCheckpoints::<TestRuntime>::get().len(), 1);
+            assert_eq!(
+                verification_status(CHAIN_ID, 2),
+                Some(CheckpointVerificationStatus::InProgress)
+            );
+
+            roll_one_block();
+            assert_eq!(QueuedCheckpoints::<TestRuntime>::get().len(), 1);
+
+            AnchorWatchtower::on_voting_completed(
+                first_proposal,
+                &AnchorWatchtower::external_ref(CHAIN_ID, 0),
+                &ProposalStatusEnum::Resolved { passed: true },
+            );
+            roll_one_block();
+
+            assert!(QueuedCheckpoints::<TestRuntime>::get().is_empty());
+            assert_eq!(PendingProposalCount::<TestRuntime>::get(), 2);
+            assert_eq!(
+                PendingCheckpoints::<TestRuntime>::get(AnchorWatchtower::external_ref(CHAIN_ID, 2)),
+                Some((CHAIN_ID, 2))
+            );
+        });
+    }
+
+    #[test]
+    fn checkpoints_stay_queued_while_the_watchtower_queue_is_full() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            submit_checkpoint(0, H256::repeat_byte(1));
+            let watchtower_queue_len =
+                <TestRuntime as pallet_watchtower::Config>::MaxInternalProposalLen::get();
+            pallet_watchtower::Tail::<TestRuntime>::put(watchtower_queue_len as u64);
+
+            submit_checkpoint(1, H256::repeat_byte(2));
+
+            assert_eq!(QueuedCheckpoints::<TestRuntime>::get().len(), 1);
+            assert!(!System::events().iter().any(|r| matches!(
+                r.event,
+                RuntimeEvent::AnchorWatchtower(Event::CheckpointVerificationRequestFailed { .. })
+            )));
+
+            pallet_watchtower::Tail::<TestRuntime>::put(0);
+            roll_one_block();
+
+            assert!(QueuedCheckpoints::<TestRuntime>::get().is_empty());
+            assert_eq!(
+                PendingCheckpoints::<TestRuntime>::get(AnchorWatchtower::external_ref(CHAIN_ID, 1)),
+                Some((CHAIN_ID, 1))
+            );
+        });
+    }
+
+    #[test]
+    fn requests_are_reported_when_the_queue_is_full() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            for checkpoint_id in 0..5 {
+                submit_checkpoint(checkpoint_id, H256::repeat_byte(1));
+            }
+
+            submit_checkpoint(5, H256::repeat_byte(1));
+
+            assert_eq!(verification_status(CHAIN_ID, 5), None);
+            System::assert_last_event(
+                Event::CheckpointVerificationRequestFailed {
+                    chain_id: CHAIN_ID,
+                    checkpoint_id: 5,
+                    error: Error::<TestRuntime>::VerificationQueueFull.into(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn failed_requests_are_reported_without_changing_state() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let proposal_id = submit_checkpoint(0, H256::repeat_byte(1));
+
+            // The same checkpoint id produces a duplicate external ref
+            AnchorWatchtower::on_checkpoint_submitted(
+                CHAIN_ID,
+                0,
+                &checkpoint(H256::repeat_byte(2)),
+            );
+
+            assert_eq!(
+                AnchorWatchtower::checkpoint_info(proposal_id).map(|c| c.hash),
+                Some(H256::repeat_byte(1))
+            );
+            assert!(QueuedCheckpoints::<TestRuntime>::get().is_empty());
+            System::assert_last_event(
+                Event::CheckpointVerificationRequestFailed {
+                    chain_id: CHAIN_ID,
+                    checkpoint_id: 0,
+                    error: Error::<TestRuntime>::VerificationAlreadyRequested.into(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn challenged_checkpoints_are_verified_again() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let hash = H256::repeat_byte(1);
+            let counter_hash = H256::repeat_byte(2);
+            let first_proposal = submit_checkpoint(0, hash);
+
+            let challenge_proposal = challenge_checkpoint(0, hash, counter_hash);
+
+            let challenge_ref = AnchorWatchtower::challenge_external_ref(CHAIN_ID, 0);
+            assert_ne!(challenge_proposal, first_proposal);
+            assert_eq!(PendingCheckpoints::<TestRuntime>::get(challenge_ref), Some((CHAIN_ID, 0)));
+            assert_eq!(
+                AnchorWatchtower::checkpoint_info(challenge_proposal),
+                Some(CheckpointToVerify {
+                    chain_id: CHAIN_ID,
+                    checkpoint_id: 0,
+                    origin_id: ORIGIN_ID,
+                    hash,
+                    counter_hash: Some(counter_hash),
+                })
+            );
+        });
+    }
+}
+
+mod voting_result {
+    use super::*;
+
+    fn complete_voting(result: ProposalStatusEnum) -> Option<CheckpointVerificationStatus> {
+        set_endpoint(CheckpointFormat::Hash);
+        let proposal_id = submit_checkpoint(0, H256::repeat_byte(1));
+        let external_ref = AnchorWatchtower::external_ref(CHAIN_ID, 0);
+
+        AnchorWatchtower::on_voting_completed(proposal_id, &external_ref, &result);
+
+        assert_eq!(AnchorWatchtower::checkpoint_info(proposal_id), None);
+        assert_eq!(PendingCheckpoints::<TestRuntime>::get(external_ref), None);
+        assert_eq!(PendingProposalCount::<TestRuntime>::get(), 0);
+        verification_status(CHAIN_ID, 0)
+    }
+
+    #[test]
+    fn passed_proposals_verify_the_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_eq!(
+                complete_voting(ProposalStatusEnum::Resolved { passed: true }),
+                Some(CheckpointVerificationStatus::Verified)
+            );
+        });
+    }
+
+    #[test]
+    fn failed_proposals_reject_the_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_eq!(
+                complete_voting(ProposalStatusEnum::Resolved { passed: false }),
+                Some(CheckpointVerificationStatus::Rejected)
+            );
+        });
+    }
+
+    #[test]
+    fn expired_proposals_dispute_the_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_eq!(
+                complete_voting(ProposalStatusEnum::Expired),
+                Some(CheckpointVerificationStatus::Disputed)
+            );
+        });
+    }
+
+    #[test]
+    fn cancelled_proposals_dispute_the_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let proposal_id = submit_checkpoint(0, H256::repeat_byte(1));
+
+            AnchorWatchtower::on_cancelled(
+                proposal_id,
+                &AnchorWatchtower::external_ref(CHAIN_ID, 0),
+            );
+
+            assert_eq!(AnchorWatchtower::checkpoint_info(proposal_id), None);
+            assert_eq!(
+                verification_status(CHAIN_ID, 0),
+                Some(CheckpointVerificationStatus::Disputed)
+            );
+        });
+    }
+
+    #[test]
+    fn other_proposals_are_ignored() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let proposal_id = submit_checkpoint(0, H256::repeat_byte(1));
+
+            AnchorWatchtower::on_voting_completed(
+                H256::repeat_byte(9),
+                &H256::repeat_byte(9),
+                &ProposalStatusEnum::Resolved { passed: true },
+            );
+
+            assert!(AnchorWatchtower::checkpoint_info(proposal_id).is_some());
+            assert_eq!(
+                verification_status(CHAIN_ID, 0),
+                Some(CheckpointVerificationStatus::InProgress)
+            );
+        });
+    }
+}
+
+mod offchain_worker {
+    use super::*;
+
+    fn mock_checkpoint_response(state: &mut OffchainState, response: &[u8]) {
+        let uri = [ENDPOINT, format!("/{}", ORIGIN_ID).as_bytes()].concat();
+        state.expect_request(PendingRequest {
+            method: "GET".into(),
+            uri: format!("http://127.0.0.1:2020/watchtower/payload/{}", hex::encode(uri)),
+            response: Some(hex::encode(response).into_bytes()),
+            sent: true,
+            ..Default::default()
+        });
+    }
+
+    fn pop_vote_from_mempool(pool_state: Arc<RwLock<PoolState>>) -> Option<(ProposalId, bool)> {
+        let tx = pool_state.write().transactions.pop()?;
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        let RuntimeCall::Watchtower(pallet_watchtower::Call::unsigned_vote {
+            proposal_id,
+            in_favor,
+            watchtower,
+            ..
+        }) = tx.function
+        else {
+            panic!("Unexpected transaction in the mempool")
+        };
+
+        assert_eq!(watchtower, watchtower_1());
+        Some((proposal_id, in_favor))
+    }
+
+    #[test]
+    fn votes_in_favor_of_a_matching_checkpoint() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let preimage = b"app chain block 42";
+            set_endpoint(CheckpointFormat::Keccak256Preimage);
+            let proposal_id =
+                submit_checkpoint(0, H256::from(sp_io::hashing::keccak_256(preimage)));
+            mock_checkpoint_response(&mut offchain_state.write(), preimage);
+
+            AnchorWatchtower::offchain_worker(System::block_number());
+
+            assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, true)));
+        });
+    }
+
+    #[test]
+    fn votes_against_a_mismatching_checkpoint() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let proposal_id = submit_checkpoint(0, H256::repeat_byte(1));
+            let app_chain_hash = format!("0x{}", hex::encode(H256::repeat_byte(2)));
+            mock_checkpoint_response(&mut offchain_state.write(), app_chain_hash.as_bytes());
+
+            AnchorWatchtower::offchain_worker(System::block_number());
+
+            assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+        });
+    }
+
+    #[test]
+    fn votes_against_a_challenged_checkpoint_matching_the_counter_claim() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            let counter_hash = H256::repeat_byte(2);
+            let proposal_id = challenge_checkpoint(0, H256::repeat_byte(1), counter_hash);
+            let app_chain_hash = format!("0x{}", hex::encode(counter_hash));
+            mock_checkpoint_response(&mut offchain_state.write(), app_chain_hash.as_bytes());
+
+            AnchorWatchtower::offchain_worker(System::block_number());
+
+            assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+        });
+    }
+
+    #[test]
+    fn does_not_vote_on_a_challenged_checkpoint_matching_neither_claim() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            challenge_checkpoint(0, H256::repeat_byte(1), H256::repeat_byte(2));
+            let app_chain_hash = format!("0x{}", hex::encode(H256::repeat_byte(3)));
+            mock_checkpoint_response(&mut offchain_state.write(), app_chain_hash.as_bytes());
+
+            AnchorWatchtower::offchain_worker(System::block_number());
+
+            assert_eq!(pop_vote_from_mempool(pool_state), None);
+        });
+    }
+
+    #[test]
+    fn does_not_vote_when_checkpoint_cannot_be_read() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            set_endpoint(CheckpointFormat::Hash);
+            submit_checkpoint(0, H256::repeat_byte(1));
+            mock_checkpoint_response(&mut offchain_state.write(), b"not a hash");
+
+            AnchorWatchtower::offchain_worker(System::block_number());
+
+            assert_eq!(pop_vote_from_mempool(pool_state), None);
+        });
+    }
+
+    #[test]
+    fn does_not_vote_twice_on_the_same_checkpoint() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let preimage = b"app chain block 42";
+            set_endpoint(CheckpointFormat::Blake2Preimage);
+            submit_checkpoint(0, H256::from(sp_io::hashing::blake2_256(preimage)));
+            mock_checkpoint_response(&mut offchain_state.write(), preimage);
+
+            AnchorWatchtower::offchain_worker(System::block_number());
+            assert!(pop_vote_from_mempool(pool_state.clone()).is_some());
+
+            roll_forward(1);
+            AnchorWatchtower::offchain_worker(System::block_number());
+            assert_eq!(pop_vote_from_mempool(pool_state), None);
+        });
+    }
+}
+
+mod payload_validator {
+    use super::*;
+
+    fn validate(proposal_type: ProposalType, to_verify: &CheckpointToVerify) -> Option<bool> {
+        CheckpointPayloadValidator::<TestRuntime>::validate(&proposal_type, &to_verify.encode())
+    }
+
+    fn to_verify(hash: H256) -> CheckpointToVerify {
+        CheckpointToVerify {
+            chain_id: CHAIN_ID,
+            checkpoint_id: 1,
+            origin_id: ORIGIN_ID,
+            hash,
+            counter_hash: None,
+        }
+    }
+
+    #[test]
+    fn accepts_a_payload_matching_the_anchored_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let hash = H256::repeat_byte(1);
+            CHECKPOINTS.with(|c| c.borrow_mut().insert((CHAIN_ID, 1), checkpoint(hash)));
+
+            assert_eq!(validate(ProposalType::Checkpoint, &to_verify(hash)), Some(true));
+        });
+    }
+
+    #[test]
+    fn rejects_a_payload_not_matching_the_anchored_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let hash = H256::repeat_byte(1);
+            CHECKPOINTS.with(|c| c.borrow_mut().insert((CHAIN_ID, 1), checkpoint(hash)));
+
+            assert_eq!(
+                validate(ProposalType::Checkpoint, &to_verify(H256::repeat_byte(2))),
+                Some(false)
+            );
+            assert_eq!(
+                CheckpointPayloadValidator::<TestRuntime>::validate(
+                    &ProposalType::Checkpoint,
+                    b"not a checkpoint"
+                ),
+                Some(false)
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_a_payload_for_an_unknown_checkpoint() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_eq!(
+                validate(ProposalType::Checkpoint, &to_verify(H256::repeat_byte(1))),
+                Some(false)
+            );
+        });
+    }
+
+    #[test]
+    fn ignores_other_proposal_types() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            assert_eq!(validate(ProposalType::Governance, &to_verify(H256::repeat_byte(1))), None);
+        });
+    }
+}
+
+#[test]
+fn checkpoint_hash_supports_all_formats() {
+    let hash = H256::repeat_byte(3);
+    let hex_hash = hex::encode(hash);
+
+    assert_eq!(
+        AnchorWatchtower::checkpoint_hash(hex_hash.as_bytes(), CheckpointFormat::Hash),
+        Ok(hash)
+    );
+    assert_eq!(
+        AnchorWatchtower::checkpoint_hash(
+            format!("0x{}\n", hex_hash).as_bytes(),
+            CheckpointFormat::Hash
+        ),
+        Ok(hash)
+    );
+    assert!(AnchorWatchtower::checkpoint_hash(b"0x1234", CheckpointFormat::Hash).is_err());
+    assert_eq!(
+        AnchorWatchtower::checkpoint_hash(b"data", CheckpointFormat::Blake2Preimage),
+        Ok(H256::from(sp_io::hashing::blake2_256(b"data")))
+    );
+    assert_eq!(
+        AnchorWatchtower::checkpoint_hash(b"data", CheckpointFormat::Keccak256Preimage),
+        Ok(H256::from(sp_io::hashing::keccak_256(b"data")))
+    );
+}
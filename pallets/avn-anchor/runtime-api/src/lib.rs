@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_avn_anchor::{ChainId, CheckpointId, CheckpointProof, SummaryRoot};
+
+sp_api::decl_runtime_apis! {
+    pub trait AvnAnchorApi<BlockNumber> where
+        BlockNumber: Codec,
+    {
+        /// Returns the proof that a checkpoint is a leaf of a validated checkpoint summary root,
+        /// `None` if the checkpoint is not in one yet or its proof has been pruned
+        fn checkpoint_proof(
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+        ) -> Option<CheckpointProof<BlockNumber>>;
+    }
+}
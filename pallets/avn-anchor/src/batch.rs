@@ -0,0 +1,77 @@
+// Copyright 2026 Aventus DAO Ltd
+
+//! Batch checkpoint submission.
+//!
+//! A batch stores its checkpoints with consecutive ids and is charged a single fee, discounted by
+//! the chain's `BatchFeeCurve`. Batches cannot be used by linked chains because their checkpoints
+//! do not declare a parent.
+
+use crate::*;
+use sp_avn_common::PaymentHandler;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::collections::btree_set::BTreeSet;
+
+impl<T: Config> Pallet<T> {
+    /// The fee charged for a batch of `count` checkpoints of `chain_id`.
+    pub fn batch_fee(chain_id: ChainId, count: u32) -> BalanceOf<T> {
+        if count.is_zero() {
+            return Zero::zero()
+        }
+
+        let full_fee = Self::checkpoint_fee(chain_id).saturating_mul(count.into());
+        let curve = BatchFeeCurves::<T>::get(chain_id);
+        let discount = Perbill::from_parts(
+            curve.discount_per_checkpoint.deconstruct().saturating_mul(count - 1),
+        )
+        .min(curve.max_discount);
+
+        full_fee.saturating_sub(discount.mul_floor(full_fee))
+    }
+
+    pub(crate) fn do_submit_checkpoints(
+        handler: &T::AccountId,
+        chain_id: ChainId,
+        checkpoints: BoundedVec<(H256, OriginId), T::MaxCheckpointsPerBatch>,
+    ) -> DispatchResult {
+        ensure!(!checkpoints.is_empty(), Error::<T>::EmptyCheckpointBatch);
+        Self::ensure_valid_link(chain_id, None)?;
+
+        // Check every origin id before storing anything, so the batch is accepted or rejected
+        // as a whole.
+        let mut origin_ids = BTreeSet::new();
+        for (_, origin_id) in checkpoints.iter() {
+            ensure!(origin_ids.insert(*origin_id), Error::<T>::DuplicateOriginInBatch);
+            ensure!(
+                !Self::has_checkpoint_origin(chain_id, *origin_id),
+                Error::<T>::CheckpointOriginAlreadyExists
+            );
+        }
+
+        let first_checkpoint_id = NextCheckpointId::<T>::get(chain_id);
+        let mut stored = Vec::with_capacity(checkpoints.len());
+        for (hash, origin_id) in checkpoints {
+            let checkpoint_data = CheckpointData { hash, origin_id };
+            let checkpoint_id = Self::store_checkpoint(handler, chain_id, &checkpoint_data, None)?;
+            stored.push((checkpoint_id, checkpoint_data));
+        }
+
+        <Nonces<T>>::mutate(chain_id, |n| *n += 1);
+
+        let fee = Self::batch_fee(chain_id, stored.len() as u32);
+        T::PaymentHandler::pay_treasury(&fee, handler)?;
+
+        Self::deposit_event(Event::CheckpointsSubmitted {
+            handler: handler.clone(),
+            chain_id,
+            first_checkpoint_id,
+            hashes: stored.iter().map(|(_, checkpoint_data)| checkpoint_data.hash).collect(),
+            fee,
+        });
+
+        for (checkpoint_id, checkpoint_data) in stored.iter() {
+            T::CheckpointHooks::on_checkpoint_submitted(chain_id, *checkpoint_id, checkpoint_data);
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,14 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::{encode_signed_submit_checkpoint_params, encode_signed_update_chain_handler_params};
+use crate::{
+    encode_signed_submit_checkpoint_params, encode_signed_submit_checkpoints_params,
+    encode_signed_submit_linked_checkpoint_params, encode_signed_update_chain_handler_params,
+};
 use codec::{Decode, Encode};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::{traits::Currency, BoundedVec};
-use frame_system::RawOrigin;
+use frame_support::{traits::Currency, weights::WeightMeter, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_application_crypto::KeyTypeId;
 use sp_avn_common::{benchmarking::convert_sr25519_signature, AppChainInterface, Asset, Proof};
 use sp_core::H256;
@@ -79,6 +82,53 @@ fn setup_chain<T: Config>(handler: &T::AccountId) -> Result<ChainId, &'static st
     Ok(chain_id)
 }
 
+fn setup_pending_checkpoint<T: Config>(
+    submitter: &T::AccountId,
+) -> Result<(ChainId, CheckpointId), &'static str> {
+    let chain_id = setup_chain::<T>(submitter)?;
+    let checkpoint_id: CheckpointId = 0;
+    Checkpoints::<T>::insert(
+        chain_id,
+        checkpoint_id,
+        CheckpointData { hash: H256::from([1u8; 32]), origin_id: 42u64 },
+    );
+    DisputeWindows::<T>::insert(
+        chain_id,
+        checkpoint_id,
+        DisputeWindow { submitter: submitter.clone(), closes_at: 100u32.into() },
+    );
+    Ok((chain_id, checkpoint_id))
+}
+
+fn checkpoint_batch<T: Config>(n: u32) -> BoundedVec<(H256, OriginId), T::MaxCheckpointsPerBatch> {
+    let checkpoints: Vec<(H256, OriginId)> =
+        (0..n).map(|i| (H256::repeat_byte(i as u8), i as OriginId)).collect();
+    BoundedVec::try_from(checkpoints).expect("batch is within bounds")
+}
+
+/// Store a pending checkpoint as the new head of the linked chain `chain_id`.
+fn append_linked_checkpoint<T: Config>(
+    chain_id: ChainId,
+    submitter: &T::AccountId,
+    checkpoint_id: CheckpointId,
+    link: CheckpointLink,
+) {
+    LinkedCheckpoints::<T>::insert(chain_id, true);
+    Checkpoints::<T>::insert(
+        chain_id,
+        checkpoint_id,
+        CheckpointData { hash: H256::from([1u8; 32]), origin_id: checkpoint_id },
+    );
+    OriginIdToCheckpoint::<T>::insert(chain_id, checkpoint_id, checkpoint_id);
+    NextCheckpointId::<T>::insert(chain_id, checkpoint_id + 1);
+    DisputeWindows::<T>::insert(
+        chain_id,
+        checkpoint_id,
+        DisputeWindow { submitter: submitter.clone(), closes_at: 100u32.into() },
+    );
+    Pallet::<T>::append_to_branch(chain_id, checkpoint_id, link);
+}
+
 /// Fully register an app chain (handler + asset-registry entry) so it can accrue rewards.
 /// `seed` must be unique per chain to avoid duplicate handler/token-location errors.
 fn register_appchain_for_bench<T: Config>(
@@ -501,6 +551,185 @@ benchmarks! {
     verify {
         assert!(PeriodChainReward::<T>::iter_prefix(period).next().is_none());
     }
+    set_challenge_period {
+        let handler: T::AccountId = create_account_id::<T>(0);
+        let chain_id = setup_chain::<T>(&handler)?;
+        let period: BlockNumberFor<T> = 100u32.into();
+    }: _(RawOrigin::Root, chain_id, period)
+    verify {
+        assert_eq!(ChallengePeriod::<T>::get(chain_id), period);
+    }
+
+    challenge_checkpoint {
+        let submitter: T::AccountId = create_account_id::<T>(0);
+        let challenger: T::AccountId = create_account_id::<T>(1);
+        setup_balance::<T>(&submitter);
+        setup_balance::<T>(&challenger);
+        let (chain_id, checkpoint_id) = setup_pending_checkpoint::<T>(&submitter)?;
+        let counter_hash = H256::from([2u8; 32]);
+    }: _(RawOrigin::Signed(challenger.clone()), chain_id, checkpoint_id, counter_hash)
+    verify {
+        let challenge = Challenges::<T>::get(chain_id, checkpoint_id).ok_or("Challenge not stored")?;
+        assert_eq!(challenge.challenger, challenger);
+        assert_eq!(challenge.defender_bond, T::ChallengeBond::get());
+    }
+
+    // Worst case: the challenge succeeds, so the checkpoint is also marked as rejected.
+    resolve_challenge {
+        let submitter: T::AccountId = create_account_id::<T>(0);
+        let challenger: T::AccountId = create_account_id::<T>(1);
+        setup_balance::<T>(&submitter);
+        setup_balance::<T>(&challenger);
+        let chain_id = setup_chain::<T>(&submitter)?;
+        append_linked_checkpoint::<T>(chain_id, &submitter, 0, CheckpointLink { parent: None, height: 1 });
+        let checkpoint_id: CheckpointId = 1;
+        append_linked_checkpoint::<T>(chain_id, &submitter, checkpoint_id, CheckpointLink { parent: Some(0), height: 2 });
+        Pallet::<T>::challenge_checkpoint(
+            RawOrigin::Signed(challenger.clone()).into(),
+            chain_id,
+            checkpoint_id,
+            H256::from([2u8; 32]),
+        )?;
+    }: _(RawOrigin::Root, chain_id, checkpoint_id, false)
+    verify {
+        assert_eq!(Pallet::<T>::checkpoint_status(chain_id, checkpoint_id), Some(CheckpointStatus::Rejected));
+        assert_eq!(ChainHeads::<T>::get(chain_id), Some(ChainHead { checkpoint_id: 0, height: 1 }));
+    }
+
+    set_linked_checkpoints {
+        let handler: T::AccountId = create_account_id::<T>(0);
+        let chain_id = setup_chain::<T>(&handler)?;
+    }: _(RawOrigin::Root, chain_id, false)
+    verify {
+        assert!(!LinkedCheckpoints::<T>::get(chain_id));
+    }
+
+    submit_linked_checkpoint {
+        let handler: T::AccountId = create_account_id::<T>(0);
+        let chain_id = setup_chain::<T>(&handler)?;
+        setup_balance::<T>(&handler);
+        ensure_fee_payment_possible::<T>(chain_id, &handler)?;
+        ChallengePeriod::<T>::insert(chain_id, BlockNumberFor::<T>::from(100u32));
+        append_linked_checkpoint::<T>(chain_id, &handler, 0, CheckpointLink { parent: None, height: 1 });
+
+        let checkpoint = H256::from([0u8; 32]);
+        let origin_id = 42u64;
+        let link = CheckpointLink { parent: Some(0), height: 2 };
+    }: _(RawOrigin::Signed(handler.clone()), checkpoint, origin_id, link)
+    verify {
+        assert_eq!(Checkpoints::<T>::get(chain_id, 1), Some(CheckpointData { hash: checkpoint, origin_id }));
+        assert_eq!(ChainHeads::<T>::get(chain_id), Some(ChainHead { checkpoint_id: 1, height: 2 }));
+    }
+
+    signed_submit_linked_checkpoint {
+        let signer_pair = SignerId::generate_pair(None);
+        let handler: T::AccountId = T::AccountId::decode(&mut Encode::encode(&signer_pair).as_slice())
+            .expect("valid account id");
+        let relayer: T::AccountId = create_account_id::<T>(1);
+
+        setup_balance::<T>(&handler);
+        setup_balance::<T>(&relayer);
+
+        let chain_id = setup_chain::<T>(&handler)?;
+        ensure_fee_payment_possible::<T>(chain_id, &handler)?;
+        ChallengePeriod::<T>::insert(chain_id, BlockNumberFor::<T>::from(100u32));
+        append_linked_checkpoint::<T>(chain_id, &handler, 0, CheckpointLink { parent: None, height: 1 });
+
+        let checkpoint = H256::from([0u8; 32]);
+        let origin_id = 42u64;
+        let link = CheckpointLink { parent: Some(0), height: 2 };
+        let nonce = Nonces::<T>::get(chain_id);
+
+        let payload = encode_signed_submit_linked_checkpoint_params::<T>(
+            &relayer,
+            &handler,
+            &checkpoint,
+            chain_id,
+            nonce,
+            &origin_id,
+            &link,
+        );
+        let signature = signer_pair.sign(&payload).ok_or("Error signing proof")?;
+        let proof = create_proof::<T>(signature.into(), handler.clone(), relayer);
+    }: _(RawOrigin::Signed(handler.clone()), proof, handler.clone(), checkpoint, origin_id, link)
+    verify {
+        assert_eq!(Checkpoints::<T>::get(chain_id, 1), Some(CheckpointData { hash: checkpoint, origin_id }));
+        assert_eq!(ChainHeads::<T>::get(chain_id), Some(ChainHead { checkpoint_id: 1, height: 2 }));
+    }
+
+    submit_checkpoints {
+        let n in 1 .. T::MaxCheckpointsPerBatch::get();
+
+        let handler: T::AccountId = create_account_id::<T>(0);
+        let chain_id = setup_chain::<T>(&handler)?;
+        setup_balance::<T>(&handler);
+        ensure_fee_payment_possible::<T>(chain_id, &handler)?;
+        ChallengePeriod::<T>::insert(chain_id, BlockNumberFor::<T>::from(100u32));
+
+        let checkpoints = checkpoint_batch::<T>(n);
+        let initial_balance = T::Currency::free_balance(&handler);
+    }: _(RawOrigin::Signed(handler.clone()), checkpoints)
+    verify {
+        assert_eq!(NextCheckpointId::<T>::get(chain_id), n as CheckpointId);
+        assert!(T::Currency::free_balance(&handler) < initial_balance, "Fee was not deducted");
+    }
+
+    signed_submit_checkpoints {
+        let n in 1 .. T::MaxCheckpointsPerBatch::get();
+
+        let signer_pair = SignerId::generate_pair(None);
+        let handler: T::AccountId = T::AccountId::decode(&mut Encode::encode(&signer_pair).as_slice())
+            .expect("valid account id");
+        let relayer: T::AccountId = create_account_id::<T>(1);
+
+        setup_balance::<T>(&handler);
+        setup_balance::<T>(&relayer);
+
+        let chain_id = setup_chain::<T>(&handler)?;
+        ensure_fee_payment_possible::<T>(chain_id, &handler)?;
+        ChallengePeriod::<T>::insert(chain_id, BlockNumberFor::<T>::from(100u32));
+
+        let checkpoints = checkpoint_batch::<T>(n);
+        let nonce = Nonces::<T>::get(chain_id);
+        let payload = encode_signed_submit_checkpoints_params::<T>(
+            &relayer,
+            &handler,
+            &checkpoints,
+            chain_id,
+            nonce,
+        );
+        let signature = signer_pair.sign(&payload).ok_or("Error signing proof")?;
+        let proof = create_proof::<T>(signature.into(), handler.clone(), relayer);
+    }: _(RawOrigin::Signed(handler.clone()), proof, handler.clone(), checkpoints)
+    verify {
+        assert_eq!(NextCheckpointId::<T>::get(chain_id), n as CheckpointId);
+        assert_eq!(Nonces::<T>::get(chain_id), nonce + 1);
+    }
+
+    set_batch_fee_curve {
+        let curve = BatchFeeCurve {
+            discount_per_checkpoint: Perbill::from_percent(1),
+            max_discount: Perbill::from_percent(50),
+        };
+    }: _(RawOrigin::Root, 0, curve)
+    verify {
+        assert_eq!(BatchFeeCurves::<T>::get(0), curve);
+    }
+
+    prune_published_checkpoint {
+        let chain_id: ChainId = 0;
+        let published_at = BlockNumberFor::<T>::from(1u32);
+        CheckpointsToPublish::<T>::insert(published_at, (chain_id, 0), ());
+        PublicationBlocks::<T>::insert(chain_id, 0, published_at);
+        PublishedUpTo::<T>::put(published_at);
+        let now = published_at.saturating_add(T::PublishedCheckpointRetention::get());
+        let mut meter = WeightMeter::with_limit(T::WeightInfo::prune_published_checkpoint());
+    }: {
+        Pallet::<T>::prune_published_checkpoints(now, &mut meter);
+    }
+    verify {
+        assert_eq!(PublicationBlocks::<T>::get(chain_id, 0), None);
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime);
@@ -53,6 +53,16 @@ pub trait WeightInfo {
 	fn disable_appchain() -> Weight;
 	fn deregister_appchain() -> Weight;
 	fn on_reward_period_completed() -> Weight;
+	fn set_challenge_period() -> Weight;
+	fn challenge_checkpoint() -> Weight;
+	fn resolve_challenge() -> Weight;
+	fn set_linked_checkpoints() -> Weight;
+	fn submit_linked_checkpoint() -> Weight;
+	fn signed_submit_linked_checkpoint() -> Weight;
+	fn submit_checkpoints(n: u32, ) -> Weight;
+	fn signed_submit_checkpoints(n: u32, ) -> Weight;
+	fn set_batch_fee_curve() -> Weight;
+	fn prune_published_checkpoint() -> Weight;
 }
 
 /// Weights for pallet_avn_anchor using the Substrate node and recommended hardware.
@@ -84,6 +94,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
 	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
 	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
@@ -94,14 +106,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
 	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn submit_checkpoint_with_identity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `699`
 		//  Estimated: `6196`
 		// Minimum execution time: 86_943_000 picoseconds.
 		Weight::from_parts(92_912_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	fn signed_register_chain_handler() -> Weight {
 		// Proof Size summary in bytes:
@@ -131,6 +151,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
 	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
 	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
@@ -141,14 +163,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
 	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn signed_submit_checkpoint_with_identity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `665`
 		//  Estimated: `6196`
 		// Minimum execution time: 186_633_000 picoseconds.
 		Weight::from_parts(212_628_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:1)
 	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -392,6 +422,279 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(27_u64))
 			.saturating_add(T::DbWeight::get().writes(26_u64))
 	}
+	/// Storage: `AvnAnchor::NextChainId` (r:1 w:0)
+	/// Proof: `AvnAnchor::NextChainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:0 w:1)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_challenge_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_412_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnAnchor::Checkpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Challenges` (r:1 w:1)
+	/// Proof: `AvnAnchor::Challenges` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:0)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointVerification` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointVerification` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:1 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn challenge_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_205_000 picoseconds.
+		Weight::from_parts(63_870_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AvnAnchor::Challenges` (r:1 w:1)
+	/// Proof: `AvnAnchor::Challenges` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::RejectedCheckpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::RejectedCheckpoints` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:1 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointLinks` (r:2 w:1)
+	/// Proof: `AvnAnchor::CheckpointLinks` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn resolve_challenge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6196`
+		// Minimum execution time: 66_102_000 picoseconds.
+		Weight::from_parts(69_417_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AvnAnchor::NextChainId` (r:1 w:0)
+	/// Proof: `AvnAnchor::NextChainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:0 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::BranchRoots` (r:0 w:1)
+	/// Proof: `AvnAnchor::BranchRoots` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_linked_checkpoints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1489`
+		// Minimum execution time: 10_114_000 picoseconds.
+		Weight::from_parts(10_689_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:1 w:1)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:1 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointLinks` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointLinks` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn submit_linked_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6196`
+		// Minimum execution time: 94_208_000 picoseconds.
+		Weight::from_parts(100_371_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:1 w:1)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:1 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointLinks` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointLinks` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn signed_submit_linked_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `778`
+		//  Estimated: `6196`
+		// Minimum execution time: 194_810_000 picoseconds.
+		Weight::from_parts(221_455_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:64 w:64)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::BatchFeeCurves` (r:1 w:0)
+	/// Proof: `AvnAnchor::BatchFeeCurves` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:64)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:64)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:64)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:64)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn submit_checkpoints(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `664 + n * (4 ±0)`
+		//  Estimated: `6196 + n * (2527 ±0)`
+		// Minimum execution time: 90_377_000 picoseconds.
+		Weight::from_parts(72_914_306, 6196)
+			// Standard Error: 11_408
+			.saturating_add(Weight::from_parts(14_206_711, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:64 w:64)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::BatchFeeCurves` (r:1 w:0)
+	/// Proof: `AvnAnchor::BatchFeeCurves` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:64)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:64)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:64)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:64)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn signed_submit_checkpoints(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `630 + n * (4 ±0)`
+		//  Estimated: `6196 + n * (2527 ±0)`
+		// Minimum execution time: 193_152_000 picoseconds.
+		Weight::from_parts(176_203_884, 6196)
+			// Standard Error: 11_408
+			.saturating_add(Weight::from_parts(14_488_025, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `AvnAnchor::BatchFeeCurves` (r:0 w:1)
+	/// Proof: `AvnAnchor::BatchFeeCurves` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_batch_fee_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_302_000 picoseconds.
+		Weight::from_parts(7_731_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:1 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn prune_published_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3501`
+		// Minimum execution time: 9_104_000 picoseconds.
+		Weight::from_parts(9_587_000, 3501)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -422,6 +725,8 @@ impl WeightInfo for () {
 	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
 	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
 	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
@@ -432,14 +737,22 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
 	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn submit_checkpoint_with_identity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `699`
 		//  Estimated: `6196`
 		// Minimum execution time: 86_943_000 picoseconds.
 		Weight::from_parts(92_912_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	fn signed_register_chain_handler() -> Weight {
 		// Proof Size summary in bytes:
@@ -469,6 +782,8 @@ impl WeightInfo for () {
 	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
 	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
 	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
@@ -479,14 +794,22 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
 	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn signed_submit_checkpoint_with_identity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `665`
 		//  Estimated: `6196`
 		// Minimum execution time: 186_633_000 picoseconds.
 		Weight::from_parts(212_628_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:1)
 	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -730,4 +1053,277 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(27_u64))
 			.saturating_add(RocksDbWeight::get().writes(26_u64))
 	}
+	/// Storage: `AvnAnchor::NextChainId` (r:1 w:0)
+	/// Proof: `AvnAnchor::NextChainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:0 w:1)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_challenge_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1489`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_412_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnAnchor::Checkpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Challenges` (r:1 w:1)
+	/// Proof: `AvnAnchor::Challenges` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:0)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointVerification` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointVerification` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:1 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn challenge_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_205_000 picoseconds.
+		Weight::from_parts(63_870_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AvnAnchor::Challenges` (r:1 w:1)
+	/// Proof: `AvnAnchor::Challenges` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::RejectedCheckpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::RejectedCheckpoints` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:1 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointLinks` (r:2 w:1)
+	/// Proof: `AvnAnchor::CheckpointLinks` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn resolve_challenge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6196`
+		// Minimum execution time: 66_102_000 picoseconds.
+		Weight::from_parts(69_417_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AvnAnchor::NextChainId` (r:1 w:0)
+	/// Proof: `AvnAnchor::NextChainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:0 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::BranchRoots` (r:0 w:1)
+	/// Proof: `AvnAnchor::BranchRoots` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_linked_checkpoints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1489`
+		// Minimum execution time: 10_114_000 picoseconds.
+		Weight::from_parts(10_689_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:1 w:1)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:1 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointLinks` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointLinks` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn submit_linked_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `6196`
+		// Minimum execution time: 94_208_000 picoseconds.
+		Weight::from_parts(100_371_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:1 w:1)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChainHeads` (r:1 w:1)
+	/// Proof: `AvnAnchor::ChainHeads` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:1 w:1)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:1)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointLinks` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointLinks` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn signed_submit_linked_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `778`
+		//  Estimated: `6196`
+		// Minimum execution time: 194_810_000 picoseconds.
+		Weight::from_parts(221_455_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:64 w:64)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::BatchFeeCurves` (r:1 w:0)
+	/// Proof: `AvnAnchor::BatchFeeCurves` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:64)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:64)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:64)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:64)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn submit_checkpoints(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `664 + n * (4 ±0)`
+		//  Estimated: `6196 + n * (2527 ±0)`
+		// Minimum execution time: 90_377_000 picoseconds.
+		Weight::from_parts(72_914_306, 6196)
+			// Standard Error: 11_408
+			.saturating_add(Weight::from_parts(14_206_711, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `AvnAnchor::ChainHandlers` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChainHandlers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::LinkedCheckpoints` (r:1 w:0)
+	/// Proof: `AvnAnchor::LinkedCheckpoints` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::OriginIdToCheckpoint` (r:64 w:64)
+	/// Proof: `AvnAnchor::OriginIdToCheckpoint` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::NextCheckpointId` (r:1 w:1)
+	/// Proof: `AvnAnchor::NextCheckpointId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::ChallengePeriod` (r:1 w:0)
+	/// Proof: `AvnAnchor::ChallengePeriod` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Nonces` (r:1 w:1)
+	/// Proof: `AvnAnchor::Nonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointFee` (r:1 w:0)
+	/// Proof: `AvnAnchor::CheckpointFee` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::BatchFeeCurves` (r:1 w:0)
+	/// Proof: `AvnAnchor::BatchFeeCurves` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Proof: `TokenManager::AVTTokenContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::LocationToAssetId` (r:1 w:0)
+	/// Proof: `AssetRegistry::LocationToAssetId` (`max_values`: None, `max_size`: Some(624), added: 3099, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::Checkpoints` (r:0 w:64)
+	/// Proof: `AvnAnchor::Checkpoints` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::DisputeWindows` (r:0 w:64)
+	/// Proof: `AvnAnchor::DisputeWindows` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:0 w:64)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:64)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn signed_submit_checkpoints(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `630 + n * (4 ±0)`
+		//  Estimated: `6196 + n * (2527 ±0)`
+		// Minimum execution time: 193_152_000 picoseconds.
+		Weight::from_parts(176_203_884, 6196)
+			// Standard Error: 11_408
+			.saturating_add(Weight::from_parts(14_488_025, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2527).saturating_mul(n.into()))
+	}
+	/// Storage: `AvnAnchor::BatchFeeCurves` (r:0 w:1)
+	/// Proof: `AvnAnchor::BatchFeeCurves` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn set_batch_fee_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_302_000 picoseconds.
+		Weight::from_parts(7_731_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnAnchor::CheckpointsToPublish` (r:1 w:1)
+	/// Proof: `AvnAnchor::CheckpointsToPublish` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AvnAnchor::PublicationBlocks` (r:0 w:1)
+	/// Proof: `AvnAnchor::PublicationBlocks` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn prune_published_checkpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180`
+		//  Estimated: `3501`
+		// Minimum execution time: 9_104_000 picoseconds.
+		Weight::from_parts(9_587_000, 3501)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
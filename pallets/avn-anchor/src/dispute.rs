@@ -0,0 +1,214 @@
+// Copyright 2026 Aventus DAO Ltd
+
+//! Checkpoint dispute windows and challenges.
+//!
+//! Checkpoints of chains with a `ChallengePeriod` stay `Pending` for that many blocks. During the
+//! window anyone can challenge a checkpoint with the hash they claim is correct, bonding
+//! `ChallengeBond`; the same bond is reserved from the submitter if they can cover it. The
+//! loser's bond is paid to the winner.
+//!
+//! Watchtowers verify checkpoints against the submitter's own endpoint, through `CheckpointHooks`
+//! and the verification status they report. A checkpoint stays `Pending` while its verification is
+//! in progress and is rejected if the verification fails, which also decides an open challenge for
+//! the challenger. A successful verification does not uphold a challenge, since the submitter
+//! controls the endpoint, so challenges are otherwise decided by root.
+
+use crate::*;
+use frame_support::traits::{BalanceStatus, Get, ReservableCurrency};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+    /// The dispute status of a checkpoint, `None` if it does not exist. Only `Finalised`
+    /// checkpoints should be trusted.
+    pub fn checkpoint_status(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+    ) -> Option<CheckpointStatus> {
+        if !Checkpoints::<T>::contains_key(chain_id, checkpoint_id) {
+            return None
+        }
+
+        if RejectedCheckpoints::<T>::contains_key(chain_id, checkpoint_id) {
+            return Some(CheckpointStatus::Rejected)
+        }
+
+        if Challenges::<T>::contains_key(chain_id, checkpoint_id) {
+            return Some(CheckpointStatus::Challenged)
+        }
+
+        if Self::verification_in_progress(chain_id, checkpoint_id) {
+            return Some(CheckpointStatus::Pending)
+        }
+
+        match DisputeWindows::<T>::get(chain_id, checkpoint_id) {
+            Some(window) if !Self::window_closed(&window) => Some(CheckpointStatus::Pending),
+            _ => Some(CheckpointStatus::Finalised),
+        }
+    }
+
+    /// Open the dispute window of a new checkpoint and return the block it becomes final at. A
+    /// linked checkpoint's window never closes before its parent's, so checkpoints of a branch are
+    /// finalised in order.
+    pub(crate) fn open_dispute_window(
+        submitter: &T::AccountId,
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        parent: Option<CheckpointId>,
+    ) -> BlockNumberFor<T> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let mut closes_at = now.saturating_add(ChallengePeriod::<T>::get(chain_id));
+        if let Some(parent_window) = parent.and_then(|p| DisputeWindows::<T>::get(chain_id, p)) {
+            closes_at = closes_at.max(parent_window.closes_at);
+        }
+        if closes_at <= now {
+            return now
+        }
+
+        DisputeWindows::<T>::insert(
+            chain_id,
+            checkpoint_id,
+            DisputeWindow { submitter: submitter.clone(), closes_at },
+        );
+        closes_at
+    }
+
+    pub(crate) fn do_challenge_checkpoint(
+        challenger: T::AccountId,
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        counter_hash: H256,
+    ) -> DispatchResult {
+        let checkpoint =
+            Checkpoints::<T>::get(chain_id, checkpoint_id).ok_or(Error::<T>::CheckpointNotFound)?;
+        ensure!(
+            !Challenges::<T>::contains_key(chain_id, checkpoint_id),
+            Error::<T>::CheckpointAlreadyChallenged
+        );
+
+        let window = DisputeWindows::<T>::get(chain_id, checkpoint_id)
+            .filter(|window| !Self::window_closed(window))
+            .ok_or(Error::<T>::ChallengePeriodClosed)?;
+        ensure!(challenger != window.submitter, Error::<T>::CannotChallengeOwnCheckpoint);
+        ensure!(counter_hash != checkpoint.hash, Error::<T>::InvalidCounterClaim);
+
+        let bond = T::ChallengeBond::get();
+        T::Currency::reserve(&challenger, bond)?;
+        // The submitter cannot avoid a challenge by emptying its account, it just has nothing to
+        // lose.
+        let defender_bond = match T::Currency::reserve(&window.submitter, bond) {
+            Ok(()) => bond,
+            Err(_) => Zero::zero(),
+        };
+
+        Self::remove_from_publication(chain_id, checkpoint_id);
+        Challenges::<T>::insert(
+            chain_id,
+            checkpoint_id,
+            Challenge {
+                challenger: challenger.clone(),
+                counter_hash,
+                challenger_bond: bond,
+                defender_bond,
+            },
+        );
+
+        Self::deposit_event(Event::CheckpointChallenged {
+            chain_id,
+            checkpoint_id,
+            challenger,
+            counter_hash,
+            bond,
+        });
+
+        T::CheckpointHooks::on_checkpoint_challenged(
+            chain_id,
+            checkpoint_id,
+            &checkpoint,
+            counter_hash,
+        );
+
+        Ok(())
+    }
+
+    pub(crate) fn do_resolve_challenge(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        checkpoint_upheld: bool,
+    ) -> DispatchResult {
+        let challenge =
+            Challenges::<T>::take(chain_id, checkpoint_id).ok_or(Error::<T>::ChallengeNotFound)?;
+        let window = DisputeWindows::<T>::take(chain_id, checkpoint_id)
+            .ok_or(Error::<T>::ChallengeNotFound)?;
+
+        let (winner, winner_bond, loser, loser_bond) = if checkpoint_upheld {
+            (
+                window.submitter,
+                challenge.defender_bond,
+                challenge.challenger,
+                challenge.challenger_bond,
+            )
+        } else {
+            (
+                challenge.challenger,
+                challenge.challenger_bond,
+                window.submitter,
+                challenge.defender_bond,
+            )
+        };
+
+        T::Currency::unreserve(&winner, winner_bond);
+        let not_slashed =
+            T::Currency::repatriate_reserved(&loser, &winner, loser_bond, BalanceStatus::Free)?;
+
+        if checkpoint_upheld {
+            Self::release_for_publication(chain_id, checkpoint_id);
+        } else {
+            RejectedCheckpoints::<T>::insert(chain_id, checkpoint_id, challenge.counter_hash);
+            Self::drop_from_branch(chain_id, checkpoint_id);
+        }
+
+        Self::deposit_event(Event::ChallengeResolved {
+            chain_id,
+            checkpoint_id,
+            checkpoint_upheld,
+            winner,
+            slashed: loser_bond.saturating_sub(not_slashed),
+        });
+
+        Ok(())
+    }
+
+    /// Act on the verification status reported by watchtowers: hold the checkpoint while it is
+    /// verified, reject it if the verification fails and release it for publication otherwise.
+    pub(crate) fn apply_verification_status(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        status: CheckpointVerificationStatus,
+    ) {
+        match status {
+            CheckpointVerificationStatus::InProgress =>
+                Self::remove_from_publication(chain_id, checkpoint_id),
+            CheckpointVerificationStatus::Rejected => {
+                if !Checkpoints::<T>::contains_key(chain_id, checkpoint_id) ||
+                    RejectedCheckpoints::<T>::contains_key(chain_id, checkpoint_id)
+                {
+                    return
+                }
+                Self::reject_checkpoint(chain_id, checkpoint_id);
+                Self::drop_from_branch(chain_id, checkpoint_id);
+            },
+            CheckpointVerificationStatus::Verified | CheckpointVerificationStatus::Disputed =>
+                Self::release_for_publication(chain_id, checkpoint_id),
+        }
+    }
+
+    pub(crate) fn window_closed(window: &DisputeWindow<T::AccountId, BlockNumberFor<T>>) -> bool {
+        frame_system::Pallet::<T>::block_number() >= window.closes_at
+    }
+
+    pub(crate) fn verification_in_progress(chain_id: ChainId, checkpoint_id: CheckpointId) -> bool {
+        CheckpointVerification::<T>::get(chain_id, checkpoint_id) ==
+            Some(CheckpointVerificationStatus::InProgress)
+    }
+}
@@ -8,17 +8,18 @@ use alloc::string::String;
 use frame_support::{
     dispatch::DispatchResult,
     ensure,
-    traits::{Currency, OnRuntimeUpgrade, StorageVersion},
+    traits::{Currency, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+    weights::Weight,
 };
 
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 pub use sp_avn_common::{node::Moment, CallDecoder, RewardPeriodIndex};
 use sp_core::{ConstU32, Get, H256};
-use sp_runtime::{BoundedVec, Perquintill};
+use sp_runtime::{BoundedVec, Perbill, Perquintill};
 use sp_std::prelude::*;
 
 #[cfg(test)]
@@ -27,8 +28,12 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+mod batch;
 pub mod benchmarking;
+mod dispute;
+mod linked;
 pub mod migration;
+mod publication;
 mod reward;
 
 pub type MaximumHandlersBound = ConstU32<256>;
@@ -37,6 +42,8 @@ pub type ChainNameLimit = ConstU32<32>;
 
 pub const UPDATE_CHAIN_HANDLER: &'static [u8] = b"update_chain_handler";
 pub const SUBMIT_CHECKPOINT: &'static [u8] = b"submit_checkpoint";
+pub const SUBMIT_LINKED_CHECKPOINT: &'static [u8] = b"submit_linked_checkpoint";
+pub const SUBMIT_CHECKPOINTS: &'static [u8] = b"submit_checkpoints";
 
 const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
@@ -75,6 +82,68 @@ impl<AssetId, AccountId> AppChainRewardEligibility<AssetId, AccountId> for () {
     }
 }
 
+/// Notified whenever a checkpoint is stored, e.g. to have it verified by watchtowers.
+pub trait CheckpointHooks {
+    fn on_checkpoint_submitted(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        checkpoint: &CheckpointData,
+    );
+
+    /// The maximum weight consumed by `on_checkpoint_submitted`.
+    fn on_checkpoint_submitted_weight() -> Weight;
+
+    /// Called when a checkpoint is challenged with the `counter_hash` claimed by the challenger.
+    fn on_checkpoint_challenged(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        checkpoint: &CheckpointData,
+        counter_hash: H256,
+    );
+
+    /// The maximum weight consumed by `on_checkpoint_challenged`.
+    fn on_checkpoint_challenged_weight() -> Weight;
+}
+
+impl CheckpointHooks for () {
+    fn on_checkpoint_submitted(
+        _chain_id: ChainId,
+        _checkpoint_id: CheckpointId,
+        _checkpoint: &CheckpointData,
+    ) {
+    }
+
+    fn on_checkpoint_submitted_weight() -> Weight {
+        Weight::zero()
+    }
+
+    fn on_checkpoint_challenged(
+        _chain_id: ChainId,
+        _checkpoint_id: CheckpointId,
+        _checkpoint: &CheckpointData,
+        _counter_hash: H256,
+    ) {
+    }
+
+    fn on_checkpoint_challenged_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Access to app chain checkpoints for pallets that verify them.
+pub trait CheckpointInterface<AccountId> {
+    /// The chain registered to `handler`, if any.
+    fn chain_id(handler: &AccountId) -> Option<ChainId>;
+
+    fn checkpoint(chain_id: ChainId, checkpoint_id: CheckpointId) -> Option<CheckpointData>;
+
+    fn set_verification_status(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        status: CheckpointVerificationStatus,
+    );
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -86,12 +155,15 @@ pub mod pallet {
         AssetMetadata as RegistryAssetMetadata, AvnAssetLocation, AvnAssetMetadata,
         Inspect as AssetRegistryInspect, Mutate as AssetRegistryMutate,
     };
-    use sp_avn_common::{verify_signature, InnerCallValidator, PaymentHandler, Proof};
+    use sp_avn_common::{
+        eth::EthereumId, verify_signature, InnerCallValidator, PaymentHandler, Proof,
+    };
     use sp_core::H160;
     use sp_runtime::traits::{Dispatchable, IdentifyAccount, Verify, Zero};
 
     pub type ChainId = u32;
     pub type CheckpointId = u64;
+    pub type AppChainHeight = u64;
 
     #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct CheckpointData {
@@ -99,6 +171,142 @@ pub mod pallet {
         pub origin_id: OriginId,
     }
 
+    /// The outcome of verifying a checkpoint. Checkpoints of chains without a verifier have no
+    /// status.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum CheckpointVerificationStatus {
+        /// Watchtowers are checking the checkpoint against the app chain.
+        InProgress,
+        /// Watchtowers agreed the checkpoint matches the app chain.
+        Verified,
+        /// Watchtowers could not reach a decision on the checkpoint.
+        Disputed,
+        /// Watchtowers agreed the checkpoint does not match the app chain.
+        Rejected,
+    }
+
+    /// Where a checkpoint is in its dispute window. Checkpoints of chains without a challenge
+    /// period are final as soon as they are stored.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum CheckpointStatus {
+        /// The checkpoint can still be challenged.
+        Pending,
+        /// The checkpoint was challenged and the dispute is not resolved yet.
+        Challenged,
+        /// The checkpoint can be trusted.
+        Finalised,
+        /// A challenge against the checkpoint succeeded.
+        Rejected,
+    }
+
+    /// The submitter of a checkpoint and the block its challenge period ends.
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct DisputeWindow<AccountId, BlockNumber> {
+        pub submitter: AccountId,
+        pub closes_at: BlockNumber,
+    }
+
+    /// An open challenge against a checkpoint.
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Challenge<AccountId, Balance> {
+        pub challenger: AccountId,
+        /// The checkpoint hash the challenger claims is correct.
+        pub counter_hash: H256,
+        pub challenger_bond: Balance,
+        /// The bond reserved from the submitter, zero if it could not be covered.
+        pub defender_bond: Balance,
+    }
+
+    /// The position a checkpoint of a linked chain declares in the app chain's history.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct CheckpointLink {
+        /// The checkpoint this one extends, `None` for the first checkpoint of the chain.
+        pub parent: Option<CheckpointId>,
+        /// The app chain height the checkpoint was taken at.
+        pub height: AppChainHeight,
+    }
+
+    /// The tip of a linked chain's checkpoints.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ChainHead {
+        pub checkpoint_id: CheckpointId,
+        pub height: AppChainHeight,
+    }
+
+    /// How the fee of a batch of checkpoints is discounted. Every checkpoint after the first
+    /// adds `discount_per_checkpoint` to the discount on the whole batch, up to `max_discount`.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct BatchFeeCurve {
+        pub discount_per_checkpoint: Perbill,
+        pub max_discount: Perbill,
+    }
+
+    /// A summary root of the checkpoint summary instance, as published to Ethereum.
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct SummaryRoot<BlockNumber> {
+        pub from_block: BlockNumber,
+        pub to_block: BlockNumber,
+        pub root_hash: H256,
+        /// The Ethereum transaction publishing the root, if it has been sent.
+        pub tx_id: Option<EthereumId>,
+        pub is_finalised: bool,
+    }
+
+    /// The proof that a checkpoint is a leaf of a summary root.
+    #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct CheckpointProof<BlockNumber> {
+        /// The ABI encoded checkpoint.
+        pub leaf: Vec<u8>,
+        /// The sibling hashes from the leaf up to the root.
+        pub merkle_path: Vec<H256>,
+        pub root: SummaryRoot<BlockNumber>,
+    }
+
     /// A node's accrued, unpaid app-chain reward for a single reward period.
     /// The owner is snapshotted at accrual time because nodes can be transferred afterwards.
     #[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -132,8 +340,8 @@ pub mod pallet {
 
         type WeightInfo: WeightInfo;
 
-        /// Currency type for processing fee payment
-        type Currency: Currency<Self::AccountId>;
+        /// Currency type for processing fee payment and challenge bonds
+        type Currency: ReservableCurrency<Self::AccountId>;
 
         /// The type of token identifier
         /// (a H160 because this is an Ethereum address)
@@ -178,6 +386,10 @@ pub mod pallet {
         #[pallet::constant]
         type MaxPeriodsPerPayout: Get<u32>;
 
+        /// The maximum number of checkpoints submitted in a single `submit_checkpoints` call.
+        #[pallet::constant]
+        type MaxCheckpointsPerBatch: Get<u32>;
+
         /// Per-`(app chain, node)` eligibility check applied at payout time. A node only receives
         /// an app chain's reward for a period when this returns `true`. Use `()` to make
         /// every node eligible.
@@ -185,6 +397,19 @@ pub mod pallet {
             Self::AppChainAssetId,
             Self::AccountId,
         >;
+
+        /// Hooks notified of new checkpoints. Use `()` if checkpoints are not verified.
+        type CheckpointHooks: CheckpointHooks;
+
+        /// The bond reserved from both a challenger and the checkpoint submitter when a
+        /// checkpoint is challenged. The loser's bond is paid to the winner.
+        #[pallet::constant]
+        type ChallengeBond: Get<BalanceOf<Self>>;
+
+        /// The number of blocks after its publication block that a checkpoint's proof remains
+        /// available, once Ethereum confirmed the summary root it was published under.
+        #[pallet::constant]
+        type PublishedCheckpointRetention: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
@@ -197,10 +422,12 @@ pub mod pallet {
             migration::v2::Migration::<T>::on_runtime_upgrade()
         }
 
-        /// Pays outstanding app-chain rewards with leftover block weight.
-        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+        /// Pays outstanding app-chain rewards and prunes published checkpoints with leftover block
+        /// weight.
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
             let mut meter = WeightMeter::with_limit(remaining_weight);
             Self::sweep(&mut meter, T::MaxPeriodsPerPayout::get());
+            Self::prune_published_checkpoints(n, &mut meter);
             meter.consumed()
         }
     }
@@ -260,6 +487,57 @@ pub mod pallet {
 
         /// An app chain was fully deregistered (routing state removed, asset marked non-native).
         AppChainDeregistered { chain_id: ChainId, asset_id: T::AppChainAssetId },
+
+        /// The verification status of a checkpoint was updated.
+        CheckpointVerificationUpdated {
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            status: CheckpointVerificationStatus,
+        },
+
+        /// The challenge period of a chain's new checkpoints was updated.
+        ChallengePeriodUpdated { chain_id: ChainId, period: BlockNumberFor<T> },
+
+        /// A checkpoint was challenged with a counter-claim.
+        CheckpointChallenged {
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            challenger: T::AccountId,
+            counter_hash: H256,
+            bond: BalanceOf<T>,
+        },
+
+        /// A checkpoint challenge was resolved and the loser's bond paid to the winner.
+        ChallengeResolved {
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            checkpoint_upheld: bool,
+            winner: T::AccountId,
+            slashed: BalanceOf<T>,
+        },
+
+        /// Linked checkpoints were enabled or disabled for a chain.
+        LinkedCheckpointsUpdated { chain_id: ChainId, enabled: bool },
+
+        /// A linked checkpoint was rejected, so the chain head moved back to its parent.
+        ChainHeadRolledBack {
+            chain_id: ChainId,
+            rejected_checkpoint_id: CheckpointId,
+            head: Option<ChainHead>,
+        },
+
+        /// A batch of checkpoints was submitted. Their ids are consecutive, starting at
+        /// `first_checkpoint_id`, in the order of `hashes`.
+        CheckpointsSubmitted {
+            handler: T::AccountId,
+            chain_id: ChainId,
+            first_checkpoint_id: CheckpointId,
+            hashes: Vec<H256>,
+            fee: BalanceOf<T>,
+        },
+
+        /// The batch fee curve of a chain was updated.
+        BatchFeeCurveUpdated { chain_id: ChainId, curve: BatchFeeCurve },
     }
 
     #[pallet::error]
@@ -310,6 +588,32 @@ pub mod pallet {
         AppChainNotDisabled,
         /// The app chain is already disabled or deregistered.
         AppChainNotActive,
+        /// The checkpoint does not exist.
+        CheckpointNotFound,
+        /// The checkpoint is not in its challenge period.
+        ChallengePeriodClosed,
+        /// The checkpoint has already been challenged.
+        CheckpointAlreadyChallenged,
+        /// The submitter of a checkpoint cannot challenge it.
+        CannotChallengeOwnCheckpoint,
+        /// The counter-claim is the same as the checkpoint.
+        InvalidCounterClaim,
+        /// The checkpoint has no open challenge.
+        ChallengeNotFound,
+        /// The chain only accepts checkpoints linked to their parent.
+        LinkedCheckpointRequired,
+        /// The chain does not accept linked checkpoints.
+        LinkedCheckpointsNotEnabled,
+        /// The parent of the linked checkpoint does not exist.
+        UnknownParentCheckpoint,
+        /// The linked checkpoint does not extend the chain head.
+        CheckpointForksChain,
+        /// The linked checkpoint is not higher than the chain head.
+        CheckpointHeightRegression,
+        /// The checkpoint batch is empty.
+        EmptyCheckpointBatch,
+        /// The same origin id appears more than once in the checkpoint batch.
+        DuplicateOriginInBatch,
     }
 
     #[pallet::storage]
@@ -317,6 +621,12 @@ pub mod pallet {
     pub type CheckpointFee<T: Config> =
         StorageMap<_, Blake2_128Concat, ChainId, BalanceOf<T>, ValueQuery, T::DefaultCheckpointFee>;
 
+    /// Discounts applied to checkpoint batches. No discount by default.
+    #[pallet::storage]
+    #[pallet::getter(fn batch_fee_curve)]
+    pub type BatchFeeCurves<T: Config> =
+        StorageMap<_, Blake2_128Concat, ChainId, BatchFeeCurve, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn nonces)]
     pub type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, ChainId, u64, ValueQuery>;
@@ -353,6 +663,133 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Verification status of checkpoints, for chains whose checkpoints are verified.
+    #[pallet::storage]
+    #[pallet::getter(fn checkpoint_verification)]
+    pub type CheckpointVerification<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        CheckpointId,
+        CheckpointVerificationStatus,
+        OptionQuery,
+    >;
+
+    /// Number of blocks new checkpoints of a chain can be challenged for. Zero (the default)
+    /// makes checkpoints final as soon as they are stored.
+    #[pallet::storage]
+    #[pallet::getter(fn challenge_period)]
+    pub type ChallengePeriod<T: Config> =
+        StorageMap<_, Blake2_128Concat, ChainId, BlockNumberFor<T>, ValueQuery>;
+
+    /// Dispute windows of checkpoints that are not final yet. Kept after the window closes unless
+    /// a challenge is resolved.
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_window)]
+    pub type DisputeWindows<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        CheckpointId,
+        DisputeWindow<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn challenges)]
+    pub type Challenges<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        CheckpointId,
+        Challenge<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Checkpoints rejected by a successful challenge, with the winning counter-claim. Linked
+    /// checkpoints built on a rejected one are rejected with it, with a zero counter-claim unless
+    /// they were challenged themselves.
+    #[pallet::storage]
+    #[pallet::getter(fn rejected_checkpoints)]
+    pub type RejectedCheckpoints<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        CheckpointId,
+        H256,
+        OptionQuery,
+    >;
+
+    /// Chains whose checkpoints must be linked to their parent.
+    #[pallet::storage]
+    #[pallet::getter(fn linked_checkpoints)]
+    pub type LinkedCheckpoints<T: Config> =
+        StorageMap<_, Blake2_128Concat, ChainId, bool, ValueQuery>;
+
+    /// The links of the checkpoints on a linked chain's branch. Removed when a rejected
+    /// checkpoint drops them from the branch.
+    #[pallet::storage]
+    #[pallet::getter(fn checkpoint_links)]
+    pub type CheckpointLinks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        CheckpointId,
+        CheckpointLink,
+        OptionQuery,
+    >;
+
+    /// The latest checkpoint of each linked chain, final or not. See `finalised_head`.
+    #[pallet::storage]
+    #[pallet::getter(fn chain_heads)]
+    pub type ChainHeads<T: Config> =
+        StorageMap<_, Blake2_128Concat, ChainId, ChainHead, OptionQuery>;
+
+    /// The first checkpoint of each linked chain's current branch.
+    #[pallet::storage]
+    pub type BranchRoots<T: Config> =
+        StorageMap<_, Blake2_128Concat, ChainId, CheckpointId, OptionQuery>;
+
+    /// Checkpoints that become leaves of the checkpoint summary of the block they are keyed by.
+    #[pallet::storage]
+    pub type CheckpointsToPublish<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        (ChainId, CheckpointId),
+        (),
+        OptionQuery,
+    >;
+
+    /// The block whose checkpoint summary includes a checkpoint.
+    #[pallet::storage]
+    #[pallet::getter(fn publication_block)]
+    pub type PublicationBlocks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ChainId,
+        Blake2_128Concat,
+        CheckpointId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// The last block covered by a summary root that Ethereum confirmed.
+    #[pallet::storage]
+    pub type PublishedUpTo<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// The last `CheckpointsToPublish` entry examined for pruning. The next pass resumes strictly
+    /// after it. `None` restarts the pass from the beginning.
+    #[pallet::storage]
+    pub type PruneCursor<T: Config> =
+        StorageValue<_, (BlockNumberFor<T>, (ChainId, CheckpointId)), OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn next_checkpoint_id)]
     pub type NextCheckpointId<T> =
@@ -461,7 +898,8 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_checkpoint_with_identity())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_checkpoint_with_identity()
+            .saturating_add(T::CheckpointHooks::on_checkpoint_submitted_weight()))]
         #[pallet::call_index(2)]
         pub fn submit_checkpoint_with_identity(
             origin: OriginFor<T>,
@@ -473,7 +911,7 @@ pub mod pallet {
             let chain_id =
                 ChainHandlers::<T>::get(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
 
-            Self::do_submit_checkpoint(&handler, checkpoint, chain_id, origin_id)?;
+            Self::do_submit_checkpoint(&handler, checkpoint, chain_id, origin_id, None)?;
             Ok(())
         }
 
@@ -527,7 +965,8 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_submit_checkpoint_with_identity())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_submit_checkpoint_with_identity()
+            .saturating_add(T::CheckpointHooks::on_checkpoint_submitted_weight()))]
         #[pallet::call_index(5)]
         pub fn signed_submit_checkpoint_with_identity(
             origin: OriginFor<T>,
@@ -558,7 +997,7 @@ pub mod pallet {
                 Error::<T>::UnauthorizedSignedTransaction
             );
 
-            Self::do_submit_checkpoint(&handler, checkpoint, chain_id, origin_id)?;
+            Self::do_submit_checkpoint(&handler, checkpoint, chain_id, origin_id, None)?;
 
             Ok(())
         }
@@ -761,6 +1200,199 @@ pub mod pallet {
             Self::try_reclaim_period(period);
             Ok(())
         }
+
+        /// Set the number of blocks new checkpoints of `chain_id` stay `Pending` and can be
+        /// challenged. Existing checkpoints keep their dispute window.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_challenge_period())]
+        #[pallet::call_index(14)]
+        pub fn set_challenge_period(
+            origin: OriginFor<T>,
+            chain_id: ChainId,
+            period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(chain_id < NextChainId::<T>::get(), Error::<T>::ChainNotRegistered);
+
+            ChallengePeriod::<T>::insert(chain_id, period);
+            Self::deposit_event(Event::ChallengePeriodUpdated { chain_id, period });
+
+            Ok(())
+        }
+
+        /// Challenge a pending checkpoint, claiming `counter_hash` is the correct checkpoint.
+        /// Reserves `ChallengeBond` from the sender and, if it can cover it, the submitter.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::challenge_checkpoint()
+            .saturating_add(T::CheckpointHooks::on_checkpoint_challenged_weight()))]
+        #[pallet::call_index(15)]
+        pub fn challenge_checkpoint(
+            origin: OriginFor<T>,
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            counter_hash: H256,
+        ) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+            Self::do_challenge_checkpoint(challenger, chain_id, checkpoint_id, counter_hash)
+        }
+
+        /// Resolve a challenge, e.g. when watchtowers could not decide it. The loser's bond is
+        /// paid to the winner.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_challenge())]
+        #[pallet::call_index(16)]
+        pub fn resolve_challenge(
+            origin: OriginFor<T>,
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            checkpoint_upheld: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_resolve_challenge(chain_id, checkpoint_id, checkpoint_upheld)
+        }
+
+        /// Require checkpoints of `chain_id` to be linked to their parent. Disabling drops the
+        /// chain head, so a re-enabled chain starts a new branch.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_linked_checkpoints())]
+        #[pallet::call_index(17)]
+        pub fn set_linked_checkpoints(
+            origin: OriginFor<T>,
+            chain_id: ChainId,
+            enabled: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(chain_id < NextChainId::<T>::get(), Error::<T>::ChainNotRegistered);
+
+            if enabled {
+                LinkedCheckpoints::<T>::insert(chain_id, true);
+            } else {
+                LinkedCheckpoints::<T>::remove(chain_id);
+                ChainHeads::<T>::remove(chain_id);
+                BranchRoots::<T>::remove(chain_id);
+            }
+            Self::deposit_event(Event::LinkedCheckpointsUpdated { chain_id, enabled });
+
+            Ok(())
+        }
+
+        /// Submit a checkpoint that extends the head of a linked chain.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_linked_checkpoint()
+            .saturating_add(T::CheckpointHooks::on_checkpoint_submitted_weight()))]
+        #[pallet::call_index(18)]
+        pub fn submit_linked_checkpoint(
+            origin: OriginFor<T>,
+            checkpoint: H256,
+            origin_id: OriginId,
+            link: CheckpointLink,
+        ) -> DispatchResult {
+            let handler = ensure_signed(origin)?;
+
+            let chain_id =
+                ChainHandlers::<T>::get(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
+
+            Self::do_submit_checkpoint(&handler, checkpoint, chain_id, origin_id, Some(link))
+        }
+
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_submit_linked_checkpoint()
+            .saturating_add(T::CheckpointHooks::on_checkpoint_submitted_weight()))]
+        #[pallet::call_index(19)]
+        pub fn signed_submit_linked_checkpoint(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            handler: T::AccountId,
+            checkpoint: H256,
+            origin_id: OriginId,
+            link: CheckpointLink,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == handler, Error::<T>::SenderNotValid);
+
+            let chain_id =
+                ChainHandlers::<T>::get(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
+            let nonce = Self::nonces(chain_id);
+
+            let signed_payload = encode_signed_submit_linked_checkpoint_params::<T>(
+                &proof.relayer,
+                &handler,
+                &checkpoint,
+                chain_id,
+                nonce,
+                &origin_id,
+                &link,
+            );
+
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            Self::do_submit_checkpoint(&handler, checkpoint, chain_id, origin_id, Some(link))
+        }
+
+        /// Submit a batch of checkpoints for a single, discounted fee. The batch is rejected as a
+        /// whole if any of its origin ids is repeated or already anchored.
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::submit_checkpoints(checkpoints.len() as u32)
+            .saturating_add(T::CheckpointHooks::on_checkpoint_submitted_weight()
+                .saturating_mul(checkpoints.len() as u64)))]
+        #[pallet::call_index(20)]
+        pub fn submit_checkpoints(
+            origin: OriginFor<T>,
+            checkpoints: BoundedVec<(H256, OriginId), T::MaxCheckpointsPerBatch>,
+        ) -> DispatchResult {
+            let handler = ensure_signed(origin)?;
+
+            let chain_id =
+                ChainHandlers::<T>::get(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
+
+            Self::do_submit_checkpoints(&handler, chain_id, checkpoints)
+        }
+
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_submit_checkpoints(checkpoints.len() as u32)
+            .saturating_add(T::CheckpointHooks::on_checkpoint_submitted_weight()
+                .saturating_mul(checkpoints.len() as u64)))]
+        #[pallet::call_index(21)]
+        pub fn signed_submit_checkpoints(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            handler: T::AccountId,
+            checkpoints: BoundedVec<(H256, OriginId), T::MaxCheckpointsPerBatch>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == handler, Error::<T>::SenderNotValid);
+
+            let chain_id =
+                ChainHandlers::<T>::get(&handler).ok_or(Error::<T>::ChainNotRegistered)?;
+            let nonce = Self::nonces(chain_id);
+
+            let signed_payload = encode_signed_submit_checkpoints_params::<T>(
+                &proof.relayer,
+                &handler,
+                &checkpoints,
+                chain_id,
+                nonce,
+            );
+
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedTransaction
+            );
+
+            Self::do_submit_checkpoints(&handler, chain_id, checkpoints)
+        }
+
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_batch_fee_curve())]
+        #[pallet::call_index(22)]
+        pub fn set_batch_fee_curve(
+            origin: OriginFor<T>,
+            chain_id: ChainId,
+            curve: BatchFeeCurve,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            BatchFeeCurves::<T>::insert(chain_id, curve);
+            Self::deposit_event(Event::BatchFeeCurveUpdated { chain_id, curve });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -823,19 +1455,16 @@ pub mod pallet {
             checkpoint: H256,
             chain_id: ChainId,
             origin_id: OriginId,
+            link: Option<CheckpointLink>,
         ) -> DispatchResult {
             ensure!(
                 !Self::has_checkpoint_origin(chain_id, origin_id),
                 Error::<T>::CheckpointOriginAlreadyExists
             );
-
-            let checkpoint_id = Self::get_next_checkpoint_id(chain_id)?;
+            Self::ensure_valid_link(chain_id, link.as_ref())?;
 
             let checkpoint_data = CheckpointData { hash: checkpoint, origin_id };
-
-            Checkpoints::<T>::insert(chain_id, checkpoint_id, checkpoint_data.clone());
-
-            OriginIdToCheckpoint::<T>::insert(chain_id, origin_id, checkpoint_id);
+            let checkpoint_id = Self::store_checkpoint(handler, chain_id, &checkpoint_data, link)?;
 
             Self::deposit_event(Event::CheckpointSubmitted(
                 handler.clone(),
@@ -846,9 +1475,39 @@ pub mod pallet {
 
             <Nonces<T>>::mutate(chain_id, |n| *n += 1);
             Self::charge_fee(handler.clone(), chain_id)?;
+
+            T::CheckpointHooks::on_checkpoint_submitted(chain_id, checkpoint_id, &checkpoint_data);
             Ok(())
         }
 
+        /// Store a checkpoint that passed validation and open its dispute window.
+        pub(crate) fn store_checkpoint(
+            handler: &T::AccountId,
+            chain_id: ChainId,
+            checkpoint_data: &CheckpointData,
+            link: Option<CheckpointLink>,
+        ) -> Result<CheckpointId, DispatchError> {
+            let checkpoint_id = Self::get_next_checkpoint_id(chain_id)?;
+
+            Checkpoints::<T>::insert(chain_id, checkpoint_id, checkpoint_data.clone());
+
+            OriginIdToCheckpoint::<T>::insert(chain_id, checkpoint_data.origin_id, checkpoint_id);
+
+            if let Some(link) = link {
+                Self::append_to_branch(chain_id, checkpoint_id, link);
+            }
+
+            let finalised_at = Self::open_dispute_window(
+                handler,
+                chain_id,
+                checkpoint_id,
+                link.and_then(|link| link.parent),
+            );
+            Self::queue_for_publication(chain_id, checkpoint_id, finalised_at);
+
+            Ok(checkpoint_id)
+        }
+
         pub fn has_checkpoint_origin(chain_id: ChainId, origin_id: OriginId) -> bool {
             OriginIdToCheckpoint::<T>::contains_key(chain_id, origin_id)
         }
@@ -915,6 +1574,46 @@ pub mod pallet {
 
                     Some((proof, encoded_data))
                 },
+                Call::signed_submit_linked_checkpoint {
+                    ref proof,
+                    ref handler,
+                    ref checkpoint,
+                    ref origin_id,
+                    ref link,
+                } => {
+                    let chain_id = ChainHandlers::<T>::get(handler.clone())
+                        .ok_or(Error::<T>::ChainNotRegistered)
+                        .ok()?;
+
+                    let nonce = Self::nonces(chain_id);
+                    let encoded_data = encode_signed_submit_linked_checkpoint_params::<T>(
+                        &proof.relayer,
+                        handler,
+                        checkpoint,
+                        chain_id,
+                        nonce,
+                        origin_id,
+                        link,
+                    );
+
+                    Some((proof, encoded_data))
+                },
+                Call::signed_submit_checkpoints { ref proof, ref handler, ref checkpoints } => {
+                    let chain_id = ChainHandlers::<T>::get(handler.clone())
+                        .ok_or(Error::<T>::ChainNotRegistered)
+                        .ok()?;
+
+                    let nonce = Self::nonces(chain_id);
+                    let encoded_data = encode_signed_submit_checkpoints_params::<T>(
+                        &proof.relayer,
+                        handler,
+                        checkpoints,
+                        chain_id,
+                        nonce,
+                    );
+
+                    Some((proof, encoded_data))
+                },
                 _ => None,
             }
         }
@@ -942,6 +1641,31 @@ pub mod pallet {
         }
     }
 
+    impl<T: Config> CheckpointInterface<T::AccountId> for Pallet<T> {
+        fn chain_id(handler: &T::AccountId) -> Option<ChainId> {
+            ChainHandlers::<T>::get(handler)
+        }
+
+        fn checkpoint(chain_id: ChainId, checkpoint_id: CheckpointId) -> Option<CheckpointData> {
+            Checkpoints::<T>::get(chain_id, checkpoint_id)
+        }
+
+        fn set_verification_status(
+            chain_id: ChainId,
+            checkpoint_id: CheckpointId,
+            status: CheckpointVerificationStatus,
+        ) {
+            CheckpointVerification::<T>::insert(chain_id, checkpoint_id, status);
+            Self::deposit_event(Event::CheckpointVerificationUpdated {
+                chain_id,
+                checkpoint_id,
+                status,
+            });
+
+            Self::apply_verification_status(chain_id, checkpoint_id, status);
+        }
+    }
+
     impl<T: Config> CallDecoder for Pallet<T> {
         type AccountId = T::AccountId;
         type Signature = T::Signature;
@@ -959,6 +1683,8 @@ pub mod pallet {
             match call {
                 Call::signed_update_chain_handler { proof, .. } => Ok(proof.clone()),
                 Call::signed_submit_checkpoint_with_identity { proof, .. } => Ok(proof.clone()),
+                Call::signed_submit_linked_checkpoint { proof, .. } => Ok(proof.clone()),
+                Call::signed_submit_checkpoints { proof, .. } => Ok(proof.clone()),
                 _ => Err(Error::<T>::TransactionNotSupported),
             }
         }
@@ -1001,3 +1727,35 @@ pub fn encode_signed_submit_checkpoint_params<T: Config>(
 ) -> Vec<u8> {
     (SUBMIT_CHECKPOINT, relayer.clone(), handler, checkpoint, chain_id, nonce, *origin_id).encode()
 }
+
+pub fn encode_signed_submit_linked_checkpoint_params<T: Config>(
+    relayer: &T::AccountId,
+    handler: &T::AccountId,
+    checkpoint: &H256,
+    chain_id: ChainId,
+    nonce: u64,
+    origin_id: &OriginId,
+    link: &CheckpointLink,
+) -> Vec<u8> {
+    (
+        SUBMIT_LINKED_CHECKPOINT,
+        relayer.clone(),
+        handler,
+        checkpoint,
+        chain_id,
+        nonce,
+        *origin_id,
+        link,
+    )
+        .encode()
+}
+
+pub fn encode_signed_submit_checkpoints_params<T: Config>(
+    relayer: &T::AccountId,
+    handler: &T::AccountId,
+    checkpoints: &BoundedVec<(H256, OriginId), T::MaxCheckpointsPerBatch>,
+    chain_id: ChainId,
+    nonce: u64,
+) -> Vec<u8> {
+    (SUBMIT_CHECKPOINTS, relayer.clone(), handler, checkpoints, chain_id, nonce).encode()
+}
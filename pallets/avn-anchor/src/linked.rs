@@ -0,0 +1,170 @@
+// Copyright 2026 Aventus DAO Ltd
+
+//! Linked checkpoint chains.
+//!
+//! Chains with `LinkedCheckpoints` enabled only accept checkpoints that declare their parent and
+//! the app chain height they were taken at. Each checkpoint must extend the chain head with a
+//! greater height, so the accepted checkpoints form a single ordered branch that light clients can
+//! follow. When a challenge rejects a checkpoint of the branch, the head moves back to its parent
+//! and the checkpoints built on top of it are rejected with it.
+
+use crate::*;
+use frame_support::storage::with_storage_layer;
+
+impl<T: Config> Pallet<T> {
+    /// The latest checkpoint of a linked chain that is final along with all its ancestors, `None`
+    /// if the chain is not linked or nothing is final yet.
+    pub fn finalised_head(chain_id: ChainId) -> Option<ChainHead> {
+        let mut head = ChainHeads::<T>::get(chain_id)?;
+
+        // Dispute windows close in branch order, so once we find a closed one all the ancestors
+        // are closed too. Watchtowers decide verifications one at a time in submission order, so
+        // the same holds for verifications in progress.
+        while Self::in_dispute_window(chain_id, head.checkpoint_id) ||
+            Self::verification_in_progress(chain_id, head.checkpoint_id)
+        {
+            head = Self::parent_head(chain_id, head.checkpoint_id)?;
+        }
+
+        // An open challenge holds back everything built on top of the challenged checkpoint.
+        let branch_root = BranchRoots::<T>::get(chain_id)?;
+        let lowest_challenged = Challenges::<T>::iter_key_prefix(chain_id)
+            .filter(|id| {
+                *id >= branch_root &&
+                    *id <= head.checkpoint_id &&
+                    CheckpointLinks::<T>::contains_key(chain_id, id)
+            })
+            .min();
+
+        match lowest_challenged {
+            Some(checkpoint_id) => Self::parent_head(chain_id, checkpoint_id),
+            None => Some(head),
+        }
+    }
+
+    /// Check a new checkpoint against the chain's mode and, if linked, its head.
+    pub(crate) fn ensure_valid_link(
+        chain_id: ChainId,
+        link: Option<&CheckpointLink>,
+    ) -> DispatchResult {
+        let linked = LinkedCheckpoints::<T>::get(chain_id);
+        let link = match link {
+            Some(link) => link,
+            None => {
+                ensure!(!linked, Error::<T>::LinkedCheckpointRequired);
+                return Ok(())
+            },
+        };
+        ensure!(linked, Error::<T>::LinkedCheckpointsNotEnabled);
+
+        match (ChainHeads::<T>::get(chain_id), link.parent) {
+            (None, None) => Ok(()),
+            (Some(head), Some(parent)) if parent == head.checkpoint_id => {
+                ensure!(link.height > head.height, Error::<T>::CheckpointHeightRegression);
+                Ok(())
+            },
+            (_, Some(parent)) if !Checkpoints::<T>::contains_key(chain_id, parent) =>
+                Err(Error::<T>::UnknownParentCheckpoint.into()),
+            _ => Err(Error::<T>::CheckpointForksChain.into()),
+        }
+    }
+
+    pub(crate) fn append_to_branch(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        link: CheckpointLink,
+    ) {
+        if link.parent.is_none() {
+            BranchRoots::<T>::insert(chain_id, checkpoint_id);
+        }
+        CheckpointLinks::<T>::insert(chain_id, checkpoint_id, link);
+        ChainHeads::<T>::insert(chain_id, ChainHead { checkpoint_id, height: link.height });
+    }
+
+    /// Drop a rejected checkpoint and its descendants from the branch and move the head back to
+    /// its parent. The descendants are rejected too, so they never finalise or get published.
+    /// Walks back from the head, so the cost grows with the checkpoints submitted since the
+    /// rejected one.
+    pub(crate) fn drop_from_branch(chain_id: ChainId, rejected_checkpoint_id: CheckpointId) {
+        let Some(head) = ChainHeads::<T>::get(chain_id) else { return };
+        let Some(rejected_link) = CheckpointLinks::<T>::get(chain_id, rejected_checkpoint_id)
+        else {
+            return
+        };
+
+        // Checkpoint ids decrease towards the root, so we can stop once we pass the rejected one.
+        let mut dropped = Vec::new();
+        let mut cursor = Some(head.checkpoint_id);
+        while let Some(checkpoint_id) = cursor.filter(|id| *id >= rejected_checkpoint_id) {
+            dropped.push(checkpoint_id);
+            if checkpoint_id == rejected_checkpoint_id {
+                break
+            }
+            cursor = CheckpointLinks::<T>::get(chain_id, checkpoint_id).and_then(|l| l.parent);
+        }
+
+        if dropped.last() != Some(&rejected_checkpoint_id) {
+            // The checkpoint is not on the current branch.
+            return
+        }
+
+        for checkpoint_id in &dropped {
+            CheckpointLinks::<T>::remove(chain_id, checkpoint_id);
+        }
+        for checkpoint_id in dropped.into_iter().filter(|id| *id != rejected_checkpoint_id) {
+            Self::reject_checkpoint(chain_id, checkpoint_id);
+        }
+
+        let new_head = rejected_link.parent.and_then(|parent| {
+            CheckpointLinks::<T>::get(chain_id, parent)
+                .map(|link| ChainHead { checkpoint_id: parent, height: link.height })
+        });
+        match new_head {
+            Some(new_head) => ChainHeads::<T>::insert(chain_id, new_head),
+            None => {
+                ChainHeads::<T>::remove(chain_id);
+                BranchRoots::<T>::remove(chain_id);
+            },
+        }
+
+        Self::deposit_event(Event::ChainHeadRolledBack {
+            chain_id,
+            rejected_checkpoint_id,
+            head: new_head,
+        });
+    }
+
+    /// Reject a checkpoint that failed verification or was built on a rejected one. It has no
+    /// counter-claim of its own, unless it is challenged, in which case the challenger wins since
+    /// the checkpoint is invalid anyway.
+    pub(crate) fn reject_checkpoint(chain_id: ChainId, checkpoint_id: CheckpointId) {
+        if Challenges::<T>::contains_key(chain_id, checkpoint_id) {
+            let result =
+                with_storage_layer(|| Self::do_resolve_challenge(chain_id, checkpoint_id, false));
+            match result {
+                Ok(()) => return,
+                Err(e) => log::error!(
+                    "Failed to resolve challenge of rejected checkpoint {:?} of chain {:?}: {:?}",
+                    checkpoint_id,
+                    chain_id,
+                    e
+                ),
+            }
+        }
+
+        RejectedCheckpoints::<T>::insert(chain_id, checkpoint_id, H256::zero());
+        DisputeWindows::<T>::remove(chain_id, checkpoint_id);
+        Self::remove_from_publication(chain_id, checkpoint_id);
+    }
+
+    fn parent_head(chain_id: ChainId, checkpoint_id: CheckpointId) -> Option<ChainHead> {
+        let parent = CheckpointLinks::<T>::get(chain_id, checkpoint_id)?.parent?;
+        let link = CheckpointLinks::<T>::get(chain_id, parent)?;
+        Some(ChainHead { checkpoint_id: parent, height: link.height })
+    }
+
+    fn in_dispute_window(chain_id: ChainId, checkpoint_id: CheckpointId) -> bool {
+        DisputeWindows::<T>::get(chain_id, checkpoint_id)
+            .map_or(false, |window| !Self::window_closed(&window))
+    }
+}
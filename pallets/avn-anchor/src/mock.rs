@@ -138,6 +138,7 @@ parameter_types! {
     pub const Offset: u64 = 0;
     pub const DisabledValidatorsThreshold: Perbill = Perbill::from_percent(33);
     pub const DefaultCheckpointFee: Balance = 1_000_000_000;
+    pub const ChallengeBond: Balance = 5_000_000_000;
 }
 
 impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for TestRuntime
@@ -339,13 +340,17 @@ impl Config for TestRuntime {
     type Token = sp_core::H160;
     type Currency = Balances;
     type DefaultCheckpointFee = DefaultCheckpointFee;
+    type ChallengeBond = ChallengeBond;
     type MaxRegisteredAppChains = ConstU32<256>;
     type AppChainAssetId = CurrencyId;
     type AssetRegistryStringLimit = ConstU32<1024>;
     type AssetRegistry = AssetRegistry;
     type RewardPot = RewardPotAccount;
     type MaxPeriodsPerPayout = ConstU32<100>;
+    type MaxCheckpointsPerBatch = ConstU32<10>;
+    type PublishedCheckpointRetention = ConstU64<100>;
     type AppChainRewardEligibility = ();
+    type CheckpointHooks = ();
 }
 
 pub fn reward_pot_account() -> AccountId {
@@ -465,6 +470,13 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for TestAvnProxyConfig {
             RuntimeCall::AvnAnchor(avn_anchor::Call::signed_submit_checkpoint_with_identity {
                 proof,
                 ..
+            }) |
+            RuntimeCall::AvnAnchor(avn_anchor::Call::signed_submit_linked_checkpoint {
+                proof,
+                ..
+            }) |
+            RuntimeCall::AvnAnchor(avn_anchor::Call::signed_submit_checkpoints {
+                proof, ..
             }) => Some(proof.clone()),
             _ => None,
         }
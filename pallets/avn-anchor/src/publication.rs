@@ -0,0 +1,168 @@
+// Copyright 2026 Aventus DAO Ltd
+
+//! Publishing checkpoints to Ethereum.
+//!
+//! Checkpoints are the leaves of a dedicated summary instance, which publishes their root to
+//! Ethereum. A checkpoint is queued for the block its dispute window closes at, or the current
+//! block if it has no window, and only becomes a leaf of the summary covering that block. A
+//! challenge or a verification in progress takes it out of the queue, and it is queued again once
+//! the checkpoint is upheld or verified. Checkpoints
+//! are never queued for a past block, so the leaves of a range cannot change once it is summarised.
+//! Once Ethereum confirms a root, its checkpoints are pruned after `PublishedCheckpointRetention`
+//! blocks, and their proofs are no longer available.
+
+use crate::*;
+use frame_support::weights::WeightMeter;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_avn_common::{eth::encode_anchor_checkpoint_leaf, merkle, SummaryLeafProvider};
+use sp_runtime::traits::{One, Saturating};
+
+impl<T: Config> Pallet<T> {
+    /// The checkpoints that are leaves of the summary of `[from_block, to_block]`, in leaf order.
+    pub fn checkpoints_published_between(
+        from_block: BlockNumberFor<T>,
+        to_block: BlockNumberFor<T>,
+    ) -> Vec<(ChainId, CheckpointId)> {
+        let mut checkpoints = Vec::new();
+        let mut block_number = from_block;
+        while block_number <= to_block {
+            checkpoints.extend(CheckpointsToPublish::<T>::iter_key_prefix(block_number));
+            if block_number == to_block {
+                break
+            }
+            block_number = block_number.saturating_add(One::one());
+        }
+
+        checkpoints.sort();
+        checkpoints
+    }
+
+    /// The proof that a checkpoint is a leaf of `root`, `None` if the checkpoint is not published
+    /// under it.
+    pub fn checkpoint_proof(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        root: SummaryRoot<BlockNumberFor<T>>,
+    ) -> Option<CheckpointProof<BlockNumberFor<T>>> {
+        let checkpoints = Self::checkpoints_published_between(root.from_block, root.to_block);
+        let leaf_index = checkpoints.iter().position(|c| *c == (chain_id, checkpoint_id))?;
+        let leaves = Self::checkpoint_leaves(&checkpoints);
+        if merkle::generate_tree_root(&leaves)? != root.root_hash {
+            return None
+        }
+
+        let merkle_path = merkle::generate_merkle_path(&leaves, leaf_index)?;
+        Some(CheckpointProof { leaf: leaves[leaf_index].clone(), merkle_path, root })
+    }
+
+    pub(crate) fn queue_for_publication(
+        chain_id: ChainId,
+        checkpoint_id: CheckpointId,
+        block_number: BlockNumberFor<T>,
+    ) {
+        CheckpointsToPublish::<T>::insert(block_number, (chain_id, checkpoint_id), ());
+        PublicationBlocks::<T>::insert(chain_id, checkpoint_id, block_number);
+    }
+
+    /// Queue a checkpoint held back by a challenge or its verification once neither holds it
+    /// any more, for the block its dispute window closes at or the current block if later.
+    pub(crate) fn release_for_publication(chain_id: ChainId, checkpoint_id: CheckpointId) {
+        if !Checkpoints::<T>::contains_key(chain_id, checkpoint_id) ||
+            PublicationBlocks::<T>::contains_key(chain_id, checkpoint_id) ||
+            Challenges::<T>::contains_key(chain_id, checkpoint_id) ||
+            RejectedCheckpoints::<T>::contains_key(chain_id, checkpoint_id) ||
+            Self::verification_in_progress(chain_id, checkpoint_id)
+        {
+            return
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let block_number = DisputeWindows::<T>::get(chain_id, checkpoint_id)
+            .map_or(now, |window| window.closes_at.max(now));
+        Self::queue_for_publication(chain_id, checkpoint_id, block_number);
+    }
+
+    pub(crate) fn remove_from_publication(chain_id: ChainId, checkpoint_id: CheckpointId) {
+        if let Some(block_number) = PublicationBlocks::<T>::take(chain_id, checkpoint_id) {
+            CheckpointsToPublish::<T>::remove(block_number, (chain_id, checkpoint_id));
+        }
+    }
+
+    /// Removes the checkpoints of confirmed summary roots once they are older than
+    /// `PublishedCheckpointRetention`, resuming from `PruneCursor` and within the `meter` weight.
+    pub fn prune_published_checkpoints(now: BlockNumberFor<T>, meter: &mut WeightMeter) {
+        let Some(published_up_to) = PublishedUpTo::<T>::get() else { return };
+        let prune_up_to =
+            published_up_to.min(now.saturating_sub(T::PublishedCheckpointRetention::get()));
+        let unit = <T as Config>::WeightInfo::prune_published_checkpoint();
+
+        let mut iter = match PruneCursor::<T>::get() {
+            Some((block_number, checkpoint)) => CheckpointsToPublish::<T>::iter_keys_from(
+                CheckpointsToPublish::<T>::hashed_key_for(block_number, checkpoint),
+            ),
+            None => CheckpointsToPublish::<T>::iter_keys(),
+        };
+
+        // Collect the batch up front so we don't mutate `CheckpointsToPublish` while iterating it.
+        let mut to_prune = Vec::new();
+        let mut last = None;
+        let mut exhausted = false;
+        loop {
+            if !meter.can_consume(unit) {
+                break
+            }
+            match iter.next() {
+                Some((block_number, checkpoint)) => {
+                    meter.consume(unit);
+                    last = Some((block_number, checkpoint));
+                    if block_number <= prune_up_to {
+                        to_prune.push((block_number, checkpoint));
+                    }
+                },
+                None => {
+                    exhausted = true;
+                    break
+                },
+            }
+        }
+
+        if exhausted {
+            PruneCursor::<T>::kill();
+        } else if let Some(last) = last {
+            PruneCursor::<T>::put(last);
+        }
+
+        for (block_number, (chain_id, checkpoint_id)) in to_prune {
+            CheckpointsToPublish::<T>::remove(block_number, (chain_id, checkpoint_id));
+            PublicationBlocks::<T>::remove(chain_id, checkpoint_id);
+        }
+    }
+
+    fn checkpoint_leaves(checkpoints: &[(ChainId, CheckpointId)]) -> Vec<Vec<u8>> {
+        checkpoints
+            .iter()
+            .filter_map(|(chain_id, checkpoint_id)| {
+                Checkpoints::<T>::get(chain_id, checkpoint_id).map(|checkpoint| {
+                    encode_anchor_checkpoint_leaf(
+                        *chain_id,
+                        *checkpoint_id,
+                        checkpoint.hash,
+                        checkpoint.origin_id,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T: Config> SummaryLeafProvider<BlockNumberFor<T>> for Pallet<T> {
+    fn leaves(from_block: BlockNumberFor<T>, to_block: BlockNumberFor<T>) -> Option<Vec<Vec<u8>>> {
+        Some(Self::checkpoint_leaves(&Self::checkpoints_published_between(from_block, to_block)))
+    }
+
+    fn on_leaves_published(_from_block: BlockNumberFor<T>, to_block: BlockNumberFor<T>) {
+        PublishedUpTo::<T>::mutate(|published_up_to| {
+            *published_up_to = Some(published_up_to.map_or(to_block, |block| block.max(to_block)))
+        });
+    }
+}
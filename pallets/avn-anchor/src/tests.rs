@@ -1,7 +1,8 @@
 use crate::{
     encode_signed_submit_checkpoint_params, mock::*, tests::RuntimeCall, AssetIdToChainId,
-    ChainHandlers, CheckpointData, CheckpointId, Error, Event, NextCheckpointId, Nonces,
-    RegisteredAppchains, SUBMIT_CHECKPOINT, UPDATE_CHAIN_HANDLER,
+    ChainHandlers, CheckpointData, CheckpointId, CheckpointInterface, CheckpointVerificationStatus,
+    Error, Event, NextCheckpointId, Nonces, RegisteredAppchains, SUBMIT_CHECKPOINT,
+    UPDATE_CHAIN_HANDLER,
 };
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok, BoundedVec};
@@ -198,6 +199,40 @@ fn submit_checkpoint_with_identity_works() {
     });
 }
 
+#[test]
+fn checkpoint_verification_status_can_be_updated() {
+    new_test_ext().execute_with(|| {
+        let handler = create_account_id(1);
+        let chain_id = setup_chain(handler);
+
+        assert_ok!(AvnAnchor::submit_checkpoint_with_identity(
+            RuntimeOrigin::signed(handler),
+            H256::random(),
+            42u64
+        ));
+        assert_eq!(AvnAnchor::checkpoint_verification(chain_id, 0), None);
+        assert_eq!(
+            <AvnAnchor as CheckpointInterface<AccountId>>::chain_id(&handler),
+            Some(chain_id)
+        );
+
+        AvnAnchor::set_verification_status(chain_id, 0, CheckpointVerificationStatus::Verified);
+
+        assert_eq!(
+            AvnAnchor::checkpoint_verification(chain_id, 0),
+            Some(CheckpointVerificationStatus::Verified)
+        );
+        System::assert_last_event(
+            Event::CheckpointVerificationUpdated {
+                chain_id,
+                checkpoint_id: 0,
+                status: CheckpointVerificationStatus::Verified,
+            }
+            .into(),
+        );
+    });
+}
+
 #[test]
 fn submit_checkpoint_with_identity_fails_for_unregistered_handler() {
     new_test_ext().execute_with(|| {
@@ -1883,3 +1918,1278 @@ mod app_chain_rewards {
         });
     }
 }
+
+mod checkpoint_challenges {
+    use super::*;
+    use crate::CheckpointStatus;
+
+    const PERIOD: u64 = 10;
+
+    struct Context {
+        submitter: AccountId,
+        challenger: AccountId,
+        chain_id: u32,
+        hash: H256,
+        counter_hash: H256,
+    }
+
+    fn setup() -> Context {
+        let submitter = create_account_id(1);
+        let chain_id = setup_chain(submitter);
+        assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+
+        let hash = H256::repeat_byte(1);
+        assert_ok!(AvnAnchor::submit_checkpoint_with_identity(
+            RuntimeOrigin::signed(submitter),
+            hash,
+            42u64
+        ));
+
+        Context {
+            submitter,
+            challenger: create_account_id(2),
+            chain_id,
+            hash,
+            counter_hash: H256::repeat_byte(2),
+        }
+    }
+
+    fn challenge(context: &Context) {
+        assert_ok!(AvnAnchor::challenge_checkpoint(
+            RuntimeOrigin::signed(context.challenger),
+            context.chain_id,
+            0,
+            context.counter_hash
+        ));
+    }
+
+    #[test]
+    fn set_challenge_period_works() {
+        new_test_ext().execute_with(|| {
+            let chain_id = setup_chain(create_account_id(1));
+
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+
+            assert_eq!(AvnAnchor::challenge_period(chain_id), PERIOD);
+            System::assert_last_event(
+                Event::ChallengePeriodUpdated { chain_id, period: PERIOD }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn set_challenge_period_fails_for_non_root_or_unknown_chain() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+
+            assert_noop!(
+                AvnAnchor::set_challenge_period(RuntimeOrigin::signed(handler), chain_id, PERIOD),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id + 1, PERIOD),
+                Error::<TestRuntime>::ChainNotRegistered
+            );
+        });
+    }
+
+    #[test]
+    fn checkpoints_without_challenge_period_are_final() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+
+            assert_ok!(AvnAnchor::submit_checkpoint_with_identity(
+                RuntimeOrigin::signed(handler),
+                H256::random(),
+                42u64
+            ));
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(chain_id, 0),
+                Some(CheckpointStatus::Finalised)
+            );
+            assert_eq!(AvnAnchor::checkpoint_status(chain_id, 1), None);
+        });
+    }
+
+    #[test]
+    fn checkpoints_are_finalised_after_the_dispute_window() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            let submitted_at = System::block_number();
+            assert_eq!(
+                AvnAnchor::dispute_window(context.chain_id, 0).map(|w| w.closes_at),
+                Some(submitted_at + PERIOD)
+            );
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Pending)
+            );
+
+            System::set_block_number(submitted_at + PERIOD);
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Finalised)
+            );
+            assert_noop!(
+                AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(context.challenger),
+                    context.chain_id,
+                    0,
+                    context.counter_hash
+                ),
+                Error::<TestRuntime>::ChallengePeriodClosed
+            );
+        });
+    }
+
+    #[test]
+    fn challenge_reserves_both_bonds() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            let bond = ChallengeBond::get();
+
+            challenge(&context);
+
+            assert_eq!(Balances::reserved_balance(&context.challenger), bond);
+            assert_eq!(Balances::reserved_balance(&context.submitter), bond);
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Challenged)
+            );
+            System::assert_last_event(
+                Event::CheckpointChallenged {
+                    chain_id: context.chain_id,
+                    checkpoint_id: 0,
+                    challenger: context.challenger,
+                    counter_hash: context.counter_hash,
+                    bond,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn invalid_challenges_are_rejected() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+
+            assert_noop!(
+                AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(context.challenger),
+                    context.chain_id,
+                    1,
+                    context.counter_hash
+                ),
+                Error::<TestRuntime>::CheckpointNotFound
+            );
+            assert_noop!(
+                AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(context.submitter),
+                    context.chain_id,
+                    0,
+                    context.counter_hash
+                ),
+                Error::<TestRuntime>::CannotChallengeOwnCheckpoint
+            );
+            assert_noop!(
+                AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(context.challenger),
+                    context.chain_id,
+                    0,
+                    context.hash
+                ),
+                Error::<TestRuntime>::InvalidCounterClaim
+            );
+
+            challenge(&context);
+            assert_noop!(
+                AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(create_account_id(3)),
+                    context.chain_id,
+                    0,
+                    context.counter_hash
+                ),
+                Error::<TestRuntime>::CheckpointAlreadyChallenged
+            );
+        });
+    }
+
+    #[test]
+    fn upheld_checkpoint_pays_challenger_bond_to_submitter() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            challenge(&context);
+            let bond = ChallengeBond::get();
+            let submitter_balance = Balances::free_balance(&context.submitter);
+            let challenger_balance = Balances::free_balance(&context.challenger);
+
+            assert_ok!(AvnAnchor::resolve_challenge(
+                RuntimeOrigin::root(),
+                context.chain_id,
+                0,
+                true
+            ));
+
+            assert_eq!(Balances::free_balance(&context.submitter), submitter_balance + 2 * bond);
+            assert_eq!(Balances::free_balance(&context.challenger), challenger_balance);
+            assert_eq!(Balances::reserved_balance(&context.challenger), 0);
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Finalised)
+            );
+            System::assert_last_event(
+                Event::ChallengeResolved {
+                    chain_id: context.chain_id,
+                    checkpoint_id: 0,
+                    checkpoint_upheld: true,
+                    winner: context.submitter,
+                    slashed: bond,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn rejected_checkpoint_pays_submitter_bond_to_challenger() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            challenge(&context);
+            let bond = ChallengeBond::get();
+            let submitter_balance = Balances::free_balance(&context.submitter);
+            let challenger_balance = Balances::free_balance(&context.challenger);
+
+            assert_ok!(AvnAnchor::resolve_challenge(
+                RuntimeOrigin::root(),
+                context.chain_id,
+                0,
+                false
+            ));
+
+            assert_eq!(Balances::free_balance(&context.challenger), challenger_balance + 2 * bond);
+            assert_eq!(Balances::free_balance(&context.submitter), submitter_balance);
+            assert_eq!(Balances::reserved_balance(&context.submitter), 0);
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Rejected)
+            );
+            assert_eq!(
+                AvnAnchor::rejected_checkpoints(context.chain_id, 0),
+                Some(context.counter_hash)
+            );
+        });
+    }
+
+    #[test]
+    fn resolve_challenge_fails_for_non_root_or_unchallenged_checkpoint() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+
+            assert_noop!(
+                AvnAnchor::resolve_challenge(RuntimeOrigin::root(), context.chain_id, 0, true),
+                Error::<TestRuntime>::ChallengeNotFound
+            );
+
+            challenge(&context);
+            assert_noop!(
+                AvnAnchor::resolve_challenge(
+                    RuntimeOrigin::signed(context.challenger),
+                    context.chain_id,
+                    0,
+                    true
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn watchtower_verification_resolves_the_challenge() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            challenge(&context);
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::Rejected,
+            );
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Rejected)
+            );
+            assert_eq!(Balances::reserved_balance(&context.challenger), 0);
+            assert_eq!(Balances::reserved_balance(&context.submitter), 0);
+        });
+    }
+
+    #[test]
+    fn successful_verification_leaves_the_challenge_for_root() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            challenge(&context);
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::Verified,
+            );
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Challenged)
+            );
+            assert_eq!(AvnAnchor::publication_block(context.chain_id, 0), None);
+            assert_eq!(Balances::reserved_balance(&context.challenger), ChallengeBond::get());
+            assert_eq!(Balances::reserved_balance(&context.submitter), ChallengeBond::get());
+        });
+    }
+
+    #[test]
+    fn checkpoints_are_pending_while_their_verification_is_in_progress() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::InProgress,
+            );
+            assert_eq!(AvnAnchor::publication_block(context.chain_id, 0), None);
+
+            System::set_block_number(1 + PERIOD);
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Pending)
+            );
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::Verified,
+            );
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Finalised)
+            );
+            assert_eq!(AvnAnchor::publication_block(context.chain_id, 0), Some(1 + PERIOD));
+        });
+    }
+
+    #[test]
+    fn verified_checkpoints_are_published_when_their_window_closes() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::InProgress,
+            );
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::Verified,
+            );
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Pending)
+            );
+            assert_eq!(AvnAnchor::publication_block(context.chain_id, 0), Some(1 + PERIOD));
+        });
+    }
+
+    #[test]
+    fn failed_verification_rejects_the_checkpoint() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::Rejected,
+            );
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Rejected)
+            );
+            assert_eq!(AvnAnchor::rejected_checkpoints(context.chain_id, 0), Some(H256::zero()));
+            assert_eq!(AvnAnchor::dispute_window(context.chain_id, 0), None);
+            assert_eq!(AvnAnchor::publication_block(context.chain_id, 0), None);
+            assert_eq!(Balances::reserved_balance(&context.submitter), 0);
+        });
+    }
+
+    #[test]
+    fn inconclusive_verification_leaves_the_challenge_open() {
+        new_test_ext().execute_with(|| {
+            let context = setup();
+            challenge(&context);
+
+            AvnAnchor::set_verification_status(
+                context.chain_id,
+                0,
+                CheckpointVerificationStatus::Disputed,
+            );
+
+            assert_eq!(
+                AvnAnchor::checkpoint_status(context.chain_id, 0),
+                Some(CheckpointStatus::Challenged)
+            );
+            assert_eq!(Balances::reserved_balance(&context.challenger), ChallengeBond::get());
+        });
+    }
+}
+
+mod linked_checkpoints {
+    use super::*;
+    use crate::{
+        encode_signed_submit_linked_checkpoint_params, ChainHead, CheckpointLink, CheckpointStatus,
+    };
+
+    const PERIOD: u64 = 10;
+
+    fn link(parent: Option<CheckpointId>, height: u64) -> CheckpointLink {
+        CheckpointLink { parent, height }
+    }
+
+    fn setup_linked_chain(handler: AccountId) -> u32 {
+        let chain_id = setup_chain(handler);
+        assert_ok!(AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id, true));
+        chain_id
+    }
+
+    fn submit(
+        handler: AccountId,
+        origin_id: u64,
+        link: CheckpointLink,
+    ) -> frame_support::dispatch::DispatchResult {
+        AvnAnchor::submit_linked_checkpoint(
+            RuntimeOrigin::signed(handler),
+            H256::repeat_byte(origin_id as u8),
+            origin_id,
+            link,
+        )
+    }
+
+    fn head(checkpoint_id: CheckpointId, height: u64) -> Option<ChainHead> {
+        Some(ChainHead { checkpoint_id, height })
+    }
+
+    fn reject(chain_id: u32, checkpoint_id: CheckpointId) {
+        assert_ok!(AvnAnchor::challenge_checkpoint(
+            RuntimeOrigin::signed(create_account_id(2)),
+            chain_id,
+            checkpoint_id,
+            H256::zero()
+        ));
+        assert_ok!(AvnAnchor::resolve_challenge(
+            RuntimeOrigin::root(),
+            chain_id,
+            checkpoint_id,
+            false
+        ));
+    }
+
+    #[test]
+    fn set_linked_checkpoints_works() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+
+            assert_noop!(
+                AvnAnchor::set_linked_checkpoints(RuntimeOrigin::signed(handler), chain_id, true),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id + 1, true),
+                Error::<TestRuntime>::ChainNotRegistered
+            );
+
+            assert_ok!(AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id, true));
+
+            assert!(AvnAnchor::linked_checkpoints(chain_id));
+            System::assert_last_event(
+                Event::LinkedCheckpointsUpdated { chain_id, enabled: true }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn submission_must_match_the_chain_mode() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+
+            assert_noop!(
+                submit(handler, 1, link(None, 1)),
+                Error::<TestRuntime>::LinkedCheckpointsNotEnabled
+            );
+
+            assert_ok!(AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id, true));
+            assert_noop!(
+                AvnAnchor::submit_checkpoint_with_identity(
+                    RuntimeOrigin::signed(handler),
+                    H256::random(),
+                    1
+                ),
+                Error::<TestRuntime>::LinkedCheckpointRequired
+            );
+        });
+    }
+
+    #[test]
+    fn linked_checkpoints_extend_the_chain_head() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(0, 100));
+
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(1, 150));
+            assert_eq!(AvnAnchor::checkpoint_links(chain_id, 1), Some(link(Some(0), 150)));
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(1, 150));
+        });
+    }
+
+    #[test]
+    fn forks_gaps_and_height_regressions_are_rejected() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+
+            assert_noop!(
+                submit(handler, 3, link(Some(5), 200)),
+                Error::<TestRuntime>::UnknownParentCheckpoint
+            );
+            assert_noop!(
+                submit(handler, 3, link(Some(0), 200)),
+                Error::<TestRuntime>::CheckpointForksChain
+            );
+            assert_noop!(
+                submit(handler, 3, link(None, 200)),
+                Error::<TestRuntime>::CheckpointForksChain
+            );
+            assert_noop!(
+                submit(handler, 3, link(Some(1), 150)),
+                Error::<TestRuntime>::CheckpointHeightRegression
+            );
+            assert_noop!(
+                submit(handler, 3, link(Some(1), 120)),
+                Error::<TestRuntime>::CheckpointHeightRegression
+            );
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(1, 150));
+        });
+    }
+
+    #[test]
+    fn signed_submit_linked_checkpoint_works() {
+        new_test_ext().execute_with(|| {
+            let handler_pair = create_account_pair(1);
+            let handler = handler_pair.public();
+            let relayer = create_account_id(2);
+            let checkpoint = H256::random();
+            let origin_id = 42u64;
+            let chain_id = setup_linked_chain(handler);
+
+            let payload = encode_signed_submit_linked_checkpoint_params::<TestRuntime>(
+                &relayer,
+                &handler,
+                &checkpoint,
+                chain_id,
+                AvnAnchor::nonces(chain_id),
+                &origin_id,
+                &link(None, 1),
+            );
+            let proof = create_proof(&handler_pair, &relayer, &payload);
+
+            let call = Box::new(RuntimeCall::AvnAnchor(
+                super::Call::<TestRuntime>::signed_submit_linked_checkpoint {
+                    proof,
+                    handler,
+                    checkpoint,
+                    origin_id,
+                    link: link(None, 1),
+                },
+            ));
+            assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(relayer), call, None));
+
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(0, 1));
+        });
+    }
+
+    #[test]
+    fn signed_submit_linked_checkpoint_fails_for_a_different_link() {
+        new_test_ext().execute_with(|| {
+            let handler_pair = create_account_pair(1);
+            let handler = handler_pair.public();
+            let relayer = create_account_id(2);
+            let checkpoint = H256::random();
+            let chain_id = setup_linked_chain(handler);
+
+            let payload = encode_signed_submit_linked_checkpoint_params::<TestRuntime>(
+                &relayer,
+                &handler,
+                &checkpoint,
+                chain_id,
+                AvnAnchor::nonces(chain_id),
+                &42u64,
+                &link(None, 1),
+            );
+            let proof = create_proof(&handler_pair, &relayer, &payload);
+
+            assert_noop!(
+                AvnAnchor::signed_submit_linked_checkpoint(
+                    RuntimeOrigin::signed(handler),
+                    proof,
+                    handler,
+                    checkpoint,
+                    42u64,
+                    link(None, 2)
+                ),
+                Error::<TestRuntime>::UnauthorizedSignedTransaction
+            );
+        });
+    }
+
+    #[test]
+    fn finalised_head_follows_the_dispute_windows() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            System::set_block_number(5);
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+            assert_eq!(AvnAnchor::finalised_head(chain_id), None);
+
+            System::set_block_number(1 + PERIOD);
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(0, 100));
+
+            System::set_block_number(5 + PERIOD);
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(1, 150));
+        });
+    }
+
+    #[test]
+    fn dispute_windows_never_close_before_the_parent() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+            assert_ok!(submit(handler, 1, link(None, 100)));
+
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, 0));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+
+            assert_eq!(AvnAnchor::checkpoint_status(chain_id, 1), Some(CheckpointStatus::Pending));
+            assert_eq!(AvnAnchor::finalised_head(chain_id), None);
+        });
+    }
+
+    #[test]
+    fn open_challenges_hold_back_the_finalised_head() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+            assert_ok!(submit(handler, 3, link(Some(1), 200)));
+            assert_ok!(AvnAnchor::challenge_checkpoint(
+                RuntimeOrigin::signed(create_account_id(2)),
+                chain_id,
+                1,
+                H256::zero()
+            ));
+
+            System::set_block_number(1 + PERIOD);
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(0, 100));
+
+            assert_ok!(AvnAnchor::resolve_challenge(RuntimeOrigin::root(), chain_id, 1, true));
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(2, 200));
+        });
+    }
+
+    #[test]
+    fn rejected_checkpoints_roll_back_the_chain_head() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+            assert_ok!(submit(handler, 3, link(Some(1), 200)));
+
+            reject(chain_id, 1);
+
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(0, 100));
+            assert_eq!(AvnAnchor::checkpoint_links(chain_id, 1), None);
+            assert_eq!(AvnAnchor::checkpoint_links(chain_id, 2), None);
+            System::assert_has_event(
+                Event::ChainHeadRolledBack {
+                    chain_id,
+                    rejected_checkpoint_id: 1,
+                    head: head(0, 100),
+                }
+                .into(),
+            );
+
+            // The dropped checkpoints can no longer be extended, the parent of the rejected one
+            // can.
+            assert_noop!(
+                submit(handler, 4, link(Some(2), 250)),
+                Error::<TestRuntime>::CheckpointForksChain
+            );
+            assert_ok!(submit(handler, 4, link(Some(0), 150)));
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(3, 150));
+
+            // Dropped checkpoints are rejected with their ancestor and cannot be challenged.
+            assert_noop!(
+                AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(create_account_id(2)),
+                    chain_id,
+                    2,
+                    H256::zero()
+                ),
+                Error::<TestRuntime>::ChallengePeriodClosed
+            );
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(3, 150));
+        });
+    }
+
+    #[test]
+    fn descendants_of_a_rejected_checkpoint_are_not_published() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+            assert_ok!(submit(handler, 3, link(Some(1), 200)));
+            assert!(AvnAnchor::publication_block(chain_id, 2).is_some());
+
+            reject(chain_id, 1);
+
+            assert_eq!(AvnAnchor::rejected_checkpoints(chain_id, 2), Some(H256::zero()));
+            assert_eq!(AvnAnchor::dispute_window(chain_id, 2), None);
+            assert_eq!(AvnAnchor::publication_block(chain_id, 2), None);
+
+            System::set_block_number(1 + PERIOD);
+            assert_eq!(AvnAnchor::checkpoint_status(chain_id, 1), Some(CheckpointStatus::Rejected));
+            assert_eq!(AvnAnchor::checkpoint_status(chain_id, 2), Some(CheckpointStatus::Rejected));
+            assert_eq!(
+                AvnAnchor::checkpoints_published_between(0, 1 + PERIOD),
+                vec![(chain_id, 0)]
+            );
+        });
+    }
+
+    #[test]
+    fn challenged_descendants_of_a_rejected_checkpoint_are_decided_for_the_challenger() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let challenger = create_account_id(3);
+            let counter_hash = H256::repeat_byte(9);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+            assert_ok!(AvnAnchor::challenge_checkpoint(
+                RuntimeOrigin::signed(challenger),
+                chain_id,
+                1,
+                counter_hash
+            ));
+
+            reject(chain_id, 0);
+
+            assert_eq!(AvnAnchor::challenges(chain_id, 1), None);
+            assert_eq!(AvnAnchor::rejected_checkpoints(chain_id, 1), Some(counter_hash));
+            assert_eq!(Balances::reserved_balance(&challenger), 0);
+            assert_eq!(AvnAnchor::checkpoint_status(chain_id, 1), Some(CheckpointStatus::Rejected));
+            assert_eq!(AvnAnchor::chain_heads(chain_id), None);
+        });
+    }
+
+    #[test]
+    fn failed_verification_rolls_back_the_chain_head() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+
+            AvnAnchor::set_verification_status(chain_id, 1, CheckpointVerificationStatus::Rejected);
+
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(0, 100));
+            assert_eq!(AvnAnchor::checkpoint_links(chain_id, 1), None);
+            assert_eq!(AvnAnchor::checkpoint_status(chain_id, 1), Some(CheckpointStatus::Rejected));
+            assert_eq!(AvnAnchor::publication_block(chain_id, 1), None);
+        });
+    }
+
+    #[test]
+    fn checkpoints_under_verification_hold_back_the_finalised_head() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(submit(handler, 1, link(None, 100)));
+            assert_ok!(submit(handler, 2, link(Some(0), 150)));
+
+            AvnAnchor::set_verification_status(
+                chain_id,
+                1,
+                CheckpointVerificationStatus::InProgress,
+            );
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(0, 100));
+
+            AvnAnchor::set_verification_status(chain_id, 1, CheckpointVerificationStatus::Verified);
+            assert_eq!(AvnAnchor::finalised_head(chain_id), head(1, 150));
+        });
+    }
+
+    #[test]
+    fn disabling_linked_checkpoints_drops_the_chain_head() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_linked_chain(handler);
+            assert_ok!(submit(handler, 1, link(None, 100)));
+
+            assert_ok!(AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id, false));
+            assert_eq!(AvnAnchor::chain_heads(chain_id), None);
+            assert_eq!(AvnAnchor::finalised_head(chain_id), None);
+
+            assert_ok!(AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id, true));
+            assert_ok!(submit(handler, 2, link(None, 10)));
+            assert_eq!(AvnAnchor::chain_heads(chain_id), head(1, 10));
+        });
+    }
+}
+
+mod checkpoint_batches {
+    use super::*;
+    use crate::{encode_signed_submit_checkpoints_params, BatchFeeCurve, OriginId};
+    use sp_runtime::Perbill;
+
+    type Batch = BoundedVec<(H256, OriginId), ConstU32<10>>;
+
+    fn batch(origin_ids: &[OriginId]) -> Batch {
+        let checkpoints: Vec<(H256, OriginId)> =
+            origin_ids.iter().map(|id| (H256::repeat_byte(*id as u8), *id)).collect();
+        BoundedVec::try_from(checkpoints).unwrap()
+    }
+
+    fn set_curve(chain_id: u32, discount_per_checkpoint: u32, max_discount: u32) {
+        assert_ok!(AvnAnchor::set_batch_fee_curve(
+            RuntimeOrigin::root(),
+            chain_id,
+            BatchFeeCurve {
+                discount_per_checkpoint: Perbill::from_percent(discount_per_checkpoint),
+                max_discount: Perbill::from_percent(max_discount),
+            }
+        ));
+    }
+
+    #[test]
+    fn submit_checkpoints_stores_the_batch() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+            let fee = 3 * DefaultCheckpointFee::get();
+            let balance_before = get_balance(&handler);
+
+            assert_ok!(AvnAnchor::submit_checkpoints(
+                RuntimeOrigin::signed(handler),
+                batch(&[10, 11, 12])
+            ));
+
+            for (checkpoint_id, origin_id) in [(0, 10), (1, 11), (2, 12)] {
+                assert_eq!(
+                    AvnAnchor::origin_id_to_checkpoint(chain_id, origin_id),
+                    Some(checkpoint_id)
+                );
+                assert_eq!(
+                    AvnAnchor::checkpoints(chain_id, checkpoint_id),
+                    Some(CheckpointData { hash: H256::repeat_byte(origin_id as u8), origin_id })
+                );
+            }
+            assert_eq!(AvnAnchor::next_checkpoint_id(chain_id), 3);
+            assert_eq!(AvnAnchor::nonces(chain_id), 1);
+            assert_eq!(get_balance(&handler), balance_before - fee);
+            System::assert_last_event(
+                Event::CheckpointsSubmitted {
+                    handler,
+                    chain_id,
+                    first_checkpoint_id: 0,
+                    hashes: vec![
+                        H256::repeat_byte(10),
+                        H256::repeat_byte(11),
+                        H256::repeat_byte(12),
+                    ],
+                    fee,
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn batch_fee_follows_the_fee_curve() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+            let fee = DefaultCheckpointFee::get();
+            assert_eq!(AvnAnchor::batch_fee(chain_id, 4), 4 * fee);
+
+            set_curve(chain_id, 10, 25);
+
+            assert_eq!(AvnAnchor::batch_fee(chain_id, 0), 0);
+            assert_eq!(AvnAnchor::batch_fee(chain_id, 1), fee);
+            assert_eq!(AvnAnchor::batch_fee(chain_id, 2), 2 * fee * 90 / 100);
+            assert_eq!(AvnAnchor::batch_fee(chain_id, 3), 3 * fee * 80 / 100);
+            assert_eq!(AvnAnchor::batch_fee(chain_id, 5), 5 * fee * 75 / 100);
+
+            let balance_before = get_balance(&handler);
+            assert_ok!(AvnAnchor::submit_checkpoints(
+                RuntimeOrigin::signed(handler),
+                batch(&[1, 2, 3])
+            ));
+            assert_eq!(get_balance(&handler), balance_before - 3 * fee * 80 / 100);
+        });
+    }
+
+    #[test]
+    fn set_batch_fee_curve_fails_for_non_root() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+
+            assert_noop!(
+                AvnAnchor::set_batch_fee_curve(
+                    RuntimeOrigin::signed(handler),
+                    chain_id,
+                    BatchFeeCurve::default()
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn batches_with_duplicate_origins_are_rejected() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            setup_chain(handler);
+            assert_ok!(AvnAnchor::submit_checkpoint_with_identity(
+                RuntimeOrigin::signed(handler),
+                H256::random(),
+                5
+            ));
+
+            assert_noop!(
+                AvnAnchor::submit_checkpoints(RuntimeOrigin::signed(handler), batch(&[1, 2, 1])),
+                Error::<TestRuntime>::DuplicateOriginInBatch
+            );
+            assert_noop!(
+                AvnAnchor::submit_checkpoints(RuntimeOrigin::signed(handler), batch(&[1, 2, 5])),
+                Error::<TestRuntime>::CheckpointOriginAlreadyExists
+            );
+        });
+    }
+
+    #[test]
+    fn empty_batches_are_rejected() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            setup_chain(handler);
+
+            assert_noop!(
+                AvnAnchor::submit_checkpoints(RuntimeOrigin::signed(handler), batch(&[])),
+                Error::<TestRuntime>::EmptyCheckpointBatch
+            );
+        });
+    }
+
+    #[test]
+    fn linked_chains_cannot_submit_batches() {
+        new_test_ext().execute_with(|| {
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+            assert_ok!(AvnAnchor::set_linked_checkpoints(RuntimeOrigin::root(), chain_id, true));
+
+            assert_noop!(
+                AvnAnchor::submit_checkpoints(RuntimeOrigin::signed(handler), batch(&[1])),
+                Error::<TestRuntime>::LinkedCheckpointRequired
+            );
+        });
+    }
+
+    #[test]
+    fn signed_submit_checkpoints_works() {
+        new_test_ext().execute_with(|| {
+            let handler_pair = create_account_pair(1);
+            let handler = handler_pair.public();
+            let relayer = create_account_id(2);
+            let chain_id = setup_chain(handler);
+            let checkpoints = batch(&[1, 2]);
+
+            let payload = encode_signed_submit_checkpoints_params::<TestRuntime>(
+                &relayer,
+                &handler,
+                &checkpoints,
+                chain_id,
+                AvnAnchor::nonces(chain_id),
+            );
+            let proof = create_proof(&handler_pair, &relayer, &payload);
+
+            let call = Box::new(RuntimeCall::AvnAnchor(
+                super::Call::<TestRuntime>::signed_submit_checkpoints {
+                    proof,
+                    handler,
+                    checkpoints,
+                },
+            ));
+            assert_ok!(AvnProxy::proxy(RuntimeOrigin::signed(relayer), call, None));
+
+            assert_eq!(AvnAnchor::next_checkpoint_id(chain_id), 2);
+            assert_eq!(AvnAnchor::nonces(chain_id), 1);
+        });
+    }
+
+    #[test]
+    fn signed_submit_checkpoints_fails_for_a_different_batch() {
+        new_test_ext().execute_with(|| {
+            let handler_pair = create_account_pair(1);
+            let handler = handler_pair.public();
+            let relayer = create_account_id(2);
+            let chain_id = setup_chain(handler);
+
+            let payload = encode_signed_submit_checkpoints_params::<TestRuntime>(
+                &relayer,
+                &handler,
+                &batch(&[1, 2]),
+                chain_id,
+                AvnAnchor::nonces(chain_id),
+            );
+            let proof = create_proof(&handler_pair, &relayer, &payload);
+
+            assert_noop!(
+                AvnAnchor::signed_submit_checkpoints(
+                    RuntimeOrigin::signed(handler),
+                    proof,
+                    handler,
+                    batch(&[1, 3])
+                ),
+                Error::<TestRuntime>::UnauthorizedSignedTransaction
+            );
+        });
+    }
+}
+
+mod checkpoint_publication {
+    use super::*;
+    use crate::{CheckpointProof, Config, SummaryRoot};
+    use frame_support::{
+        traits::{Get, Hooks},
+        weights::Weight,
+    };
+    use sp_avn_common::{eth::encode_anchor_checkpoint_leaf, merkle, SummaryLeafProvider};
+    use sp_io::hashing::keccak_256;
+
+    const PERIOD: u64 = 10;
+
+    fn submit(handler: AccountId, origin_id: u64) {
+        assert_ok!(AvnAnchor::submit_checkpoint_with_identity(
+            RuntimeOrigin::signed(handler),
+            H256::repeat_byte(origin_id as u8),
+            origin_id
+        ));
+    }
+
+    fn leaf(chain_id: u32, checkpoint_id: CheckpointId, origin_id: u64) -> Vec<u8> {
+        encode_anchor_checkpoint_leaf(
+            chain_id,
+            checkpoint_id,
+            H256::repeat_byte(origin_id as u8),
+            origin_id,
+        )
+    }
+
+    fn summary_root(from_block: u64, to_block: u64) -> SummaryRoot<u64> {
+        let leaves = AvnAnchor::leaves(from_block, to_block).unwrap();
+        SummaryRoot {
+            from_block,
+            to_block,
+            root_hash: merkle::generate_tree_root(&leaves).unwrap_or_default(),
+            tx_id: Some(1),
+            is_finalised: true,
+        }
+    }
+
+    fn root_from_proof(proof: &CheckpointProof<u64>) -> H256 {
+        proof.merkle_path.iter().fold(H256(keccak_256(&proof.leaf)), |node, sibling| {
+            let (first, second) =
+                if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+            H256(keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+        })
+    }
+
+    #[test]
+    fn checkpoints_are_published_when_they_become_final() {
+        new_test_ext().execute_with(|| {
+            let now = System::block_number();
+            let final_handler = create_account_id(1);
+            let final_chain_id = setup_chain(final_handler);
+            let disputed_handler = create_account_id(2);
+            let disputed_chain_id = setup_chain(disputed_handler);
+            assert_ok!(AvnAnchor::set_challenge_period(
+                RuntimeOrigin::root(),
+                disputed_chain_id,
+                PERIOD
+            ));
+
+            submit(final_handler, 1);
+            submit(disputed_handler, 2);
+
+            assert_eq!(AvnAnchor::publication_block(final_chain_id, 0), Some(now));
+            assert_eq!(AvnAnchor::publication_block(disputed_chain_id, 0), Some(now + PERIOD));
+            assert_eq!(
+                AvnAnchor::checkpoints_published_between(now, now + PERIOD - 1),
+                vec![(final_chain_id, 0)]
+            );
+            assert_eq!(
+                AvnAnchor::checkpoints_published_between(now + 1, now + PERIOD),
+                vec![(disputed_chain_id, 0)]
+            );
+        });
+    }
+
+    #[test]
+    fn challenged_checkpoints_are_published_once_upheld() {
+        new_test_ext().execute_with(|| {
+            let now = System::block_number();
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+            assert_ok!(AvnAnchor::set_challenge_period(RuntimeOrigin::root(), chain_id, PERIOD));
+            submit(handler, 1);
+            submit(handler, 2);
+
+            for checkpoint_id in [0, 1] {
+                assert_ok!(AvnAnchor::challenge_checkpoint(
+                    RuntimeOrigin::signed(create_account_id(2)),
+                    chain_id,
+                    checkpoint_id,
+                    H256::repeat_byte(3)
+                ));
+                assert_eq!(AvnAnchor::publication_block(chain_id, checkpoint_id), None);
+            }
+            assert!(AvnAnchor::checkpoints_published_between(now, now + PERIOD).is_empty());
+
+            let resolved_at = now + PERIOD + 5;
+            System::set_block_number(resolved_at);
+            assert_ok!(AvnAnchor::resolve_challenge(RuntimeOrigin::root(), chain_id, 0, true));
+            assert_ok!(AvnAnchor::resolve_challenge(RuntimeOrigin::root(), chain_id, 1, false));
+
+            assert_eq!(AvnAnchor::publication_block(chain_id, 0), Some(resolved_at));
+            assert_eq!(AvnAnchor::publication_block(chain_id, 1), None);
+            assert_eq!(
+                AvnAnchor::checkpoints_published_between(now, resolved_at),
+                vec![(chain_id, 0)]
+            );
+        });
+    }
+
+    #[test]
+    fn summary_leaves_are_ordered_abi_encoded_checkpoints() {
+        new_test_ext().execute_with(|| {
+            let now = System::block_number();
+            let first_handler = create_account_id(1);
+            let first_chain_id = setup_chain(first_handler);
+            let second_handler = create_account_id(2);
+            let second_chain_id = setup_chain(second_handler);
+
+            submit(second_handler, 20);
+            submit(first_handler, 10);
+            submit(first_handler, 11);
+
+            assert_eq!(
+                AvnAnchor::leaves(now, now),
+                Some(vec![
+                    leaf(first_chain_id, 0, 10),
+                    leaf(first_chain_id, 1, 11),
+                    leaf(second_chain_id, 0, 20),
+                ])
+            );
+            assert_eq!(AvnAnchor::leaves(now + 1, now + 5), Some(vec![]));
+        });
+    }
+
+    #[test]
+    fn checkpoint_proof_leads_to_the_summary_root() {
+        new_test_ext().execute_with(|| {
+            let now = System::block_number();
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+            for origin_id in 1..=3 {
+                submit(handler, origin_id);
+            }
+            System::set_block_number(now + 1);
+            submit(handler, 4);
+
+            let root = summary_root(now, now);
+            let proof = AvnAnchor::checkpoint_proof(chain_id, 1, root.clone()).unwrap();
+
+            assert_eq!(proof.leaf, leaf(chain_id, 1, 2));
+            assert_eq!(proof.root, root);
+            assert_eq!(root_from_proof(&proof), root.root_hash);
+
+            // The checkpoint is not a leaf of the root.
+            assert_eq!(AvnAnchor::checkpoint_proof(chain_id, 3, root.clone()), None);
+            // The root was not built from the published checkpoints.
+            let wrong_root = SummaryRoot { root_hash: H256::repeat_byte(1), ..root };
+            assert_eq!(AvnAnchor::checkpoint_proof(chain_id, 1, wrong_root), None);
+        });
+    }
+
+    #[test]
+    fn confirmed_checkpoints_are_pruned_after_the_retention() {
+        new_test_ext().execute_with(|| {
+            let now = System::block_number();
+            let retention = <TestRuntime as Config>::PublishedCheckpointRetention::get();
+            let handler = create_account_id(1);
+            let chain_id = setup_chain(handler);
+            submit(handler, 1);
+            System::set_block_number(now + 1);
+            submit(handler, 2);
+
+            // Nothing is pruned until Ethereum confirms a root.
+            AvnAnchor::on_idle(now + retention, Weight::MAX);
+            assert_eq!(AvnAnchor::publication_block(chain_id, 0), Some(now));
+
+            AvnAnchor::on_leaves_published(now, now);
+            AvnAnchor::on_idle(now + retention - 1, Weight::MAX);
+            assert_eq!(AvnAnchor::publication_block(chain_id, 0), Some(now));
+
+            AvnAnchor::on_idle(now + retention, Weight::MAX);
+            assert_eq!(AvnAnchor::publication_block(chain_id, 0), None);
+            assert!(AvnAnchor::checkpoints_published_between(now, now).is_empty());
+            // The checkpoint of the unconfirmed block is kept.
+            assert_eq!(AvnAnchor::publication_block(chain_id, 1), Some(now + 1));
+        });
+    }
+}
@@ -122,7 +122,7 @@ impl<T: Config> OnOffenceHandler<T::AccountId, IdentificationTuple<T>, Weight> f
     // This function must not error because failed offences will be retried forever.
     fn on_offence(
         offenders: &[OffenceDetails<T::AccountId, IdentificationTuple<T>>], /* A list containing both current offenders and previous offenders */
-        _slash_fraction: &[Perbill],
+        slash_fraction: &[Perbill],
         _session: SessionIndex,
     ) -> Weight {
         let mut consumed_weight: Weight = Weight::from_parts(0 as u64, 0);
@@ -135,8 +135,9 @@ impl<T: Config> OnOffenceHandler<T::AccountId, IdentificationTuple<T>, Weight> f
 
         offenders
             .iter()
-            .filter(|&detail| !<ReportedOffenders<T>>::contains_key(&detail.offender.0))
-            .for_each(|detail| {
+            .zip(slash_fraction.iter().copied().chain(sp_std::iter::repeat(Perbill::zero())))
+            .filter(|&(detail, _)| !<ReportedOffenders<T>>::contains_key(&detail.offender.0))
+            .for_each(|(detail, fraction)| {
                 let offender_account_id = &detail.offender.0;
                 Self::deposit_event(Event::<T>::ReportedOffence {
                     offender: offender_account_id.clone(),
@@ -147,7 +148,8 @@ impl<T: Config> OnOffenceHandler<T::AccountId, IdentificationTuple<T>, Weight> f
                 // [Read]: can_slash
                 add_db_reads_writes(1, 0);
                 if Self::can_slash() {
-                    result = T::Enforcer::slash_validator(&offender_account_id.clone()).is_ok();
+                    result = T::Enforcer::slash_validator(&offender_account_id.clone(), fraction)
+                        .is_ok();
                 }
 
                 <ReportedOffenders<T>>::insert(offender_account_id.clone(), result);
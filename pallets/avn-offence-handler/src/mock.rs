@@ -81,10 +81,14 @@ impl session::Config for TestRuntime {
 }
 
 impl Enforcer<ValidatorId> for TestRuntime {
-    fn slash_validator(slashed_validator_id: &ValidatorId) -> DispatchResult {
+    fn slash_validator(
+        slashed_validator_id: &ValidatorId,
+        slash_fraction: Perbill,
+    ) -> DispatchResult {
         if slashed_validator_id == &VALIDATOR_ID_CAN_CAUSE_SLASH_ERROR {
             return Err(DispatchError::Other("Slash validator failed"))
         }
+        SLASHED_VALIDATORS.with(|s| s.borrow_mut().push((*slashed_validator_id, slash_fraction)));
         Ok(())
     }
 }
@@ -97,6 +101,14 @@ thread_local! {
     ]));
 }
 
+thread_local! {
+    static SLASHED_VALIDATORS: RefCell<Vec<(ValidatorId, Perbill)>> = RefCell::new(vec![]);
+}
+
+pub fn slashed_validators() -> Vec<(ValidatorId, Perbill)> {
+    return SLASHED_VALIDATORS.with(|s| s.borrow().clone())
+}
+
 pub struct ExtBuilder {
     pub storage: sp_runtime::Storage,
 }
@@ -62,6 +62,31 @@ mod on_offence {
         mod with_slashing_enable {
             use super::*;
 
+            #[test]
+            fn implies_the_slash_fraction_of_each_offender_is_enforced() {
+                let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+
+                ext.execute_with(|| {
+                    let mut context = Context::default(vec![VALIDATOR_ID_1, VALIDATOR_ID_2]);
+                    context.slash_fraction =
+                        vec![Perbill::from_percent(10), Perbill::from_percent(20)];
+
+                    AvnOffenceHandler::on_offence(
+                        &context.offenders,
+                        &context.slash_fraction,
+                        context.session_index,
+                    );
+
+                    assert_eq!(
+                        slashed_validators(),
+                        vec![
+                            (VALIDATOR_ID_1, Perbill::from_percent(10)),
+                            (VALIDATOR_ID_2, Perbill::from_percent(20))
+                        ]
+                    );
+                });
+            }
+
             #[test]
             fn implies_slashed_validator_is_recorded() {
                 let mut ext = ExtBuilder::build_default().with_validators().as_externality();
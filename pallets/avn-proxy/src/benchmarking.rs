@@ -7,10 +7,22 @@
 
 use super::*;
 use crate::Pallet as AvnProxy;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_system::RawOrigin;
 use hex_literal::hex;
 use sp_avn_common::benchmarking::convert_sr25519_signature;
-use sp_core::{sr25519, ByteArray, H256};
+use sp_core::{crypto::KeyTypeId, sr25519, ByteArray, H256};
+use sp_runtime::RuntimeAppPublic;
+
+pub const BENCH_KEY_TYPE_ID: KeyTypeId = KeyTypeId(*b"test");
+
+mod app_sr25519 {
+    use super::BENCH_KEY_TYPE_ID;
+    use sp_runtime::app_crypto::{app_crypto, sr25519};
+    app_crypto!(sr25519, BENCH_KEY_TYPE_ID);
+}
+
+type SignerId = app_sr25519::Public;
 
 fn get_proof<T: Config>(
     signer: T::AccountId,
@@ -75,6 +87,49 @@ fn get_inner_call_proof<T: Config>(
     return (proof, payment_authorisation, signer)
 }
 
+fn avt_token() -> H160 {
+    #[cfg(test)]
+    return crate::mock::AVT_TOKEN_CONTRACT;
+    // Make sure this matched the chainspec value
+    #[cfg(not(test))]
+    return H160(hex!("dB1Cff52f66195f0a5Bd3db91137db98cfc54AE6"));
+}
+
+fn get_sponsorship_terms<T: Config>() -> SponsorshipTerms<T> {
+    let fee_per_call: BalanceOf<T> = 10u32.into();
+    let call_types = BoundedVec::truncate_from(vec![CallType { pallet_index: 0, call_index: 0 }]);
+    return SponsorshipTerms {
+        token: avt_token().into(),
+        budget: fee_per_call * 100u32.into(),
+        fee_per_call,
+        beneficiary_cap: fee_per_call * 10u32.into(),
+        call_types,
+    }
+}
+
+fn setup_sponsorship<T: Config>(sponsor: &T::AccountId, beneficiary_count: u32) -> SponsorshipId {
+    let sponsorship_id = <NextSponsorshipId<T>>::get();
+    <NextSponsorshipId<T>>::put(sponsorship_id + 1);
+    <Sponsorships<T>>::insert(
+        sponsorship_id,
+        Sponsorship {
+            sponsor: sponsor.clone(),
+            terms: get_sponsorship_terms::<T>(),
+            beneficiary_count,
+        },
+    );
+    for beneficiary in get_beneficiaries::<T>(beneficiary_count) {
+        <SponsoredBeneficiaries<T>>::insert(sponsorship_id, &beneficiary, true);
+        <BeneficiarySpend<T>>::insert(sponsorship_id, &beneficiary, PeriodSpend::<T>::default());
+    }
+
+    return sponsorship_id
+}
+
+fn get_beneficiaries<T: Config>(count: u32) -> Vec<T::AccountId> {
+    return (0..count).map(|i| account("beneficiary", i, 0)).collect()
+}
+
 benchmarks! {
     charge_fee {
         let recipient: T::AccountId = whitelisted_caller();
@@ -120,6 +175,89 @@ benchmarks! {
     verify {
         assert_eq!(previous_payment_nonce + 1, <PaymentNonces::<T>>::get(&signer));
     }
+
+    create_sponsorship {
+        let sponsor: T::AccountId = whitelisted_caller();
+        let terms = get_sponsorship_terms::<T>();
+        let sponsorship_id = <NextSponsorshipId<T>>::get();
+    }: _(RawOrigin::Signed(sponsor.clone()), terms)
+    verify {
+        assert_eq!(<Sponsorships<T>>::get(sponsorship_id).unwrap().sponsor, sponsor);
+    }
+
+    update_sponsorship {
+        let sponsor: T::AccountId = whitelisted_caller();
+        let sponsorship_id = setup_sponsorship::<T>(&sponsor, 0);
+        let mut terms = get_sponsorship_terms::<T>();
+        terms.budget = terms.budget * 2u32.into();
+        let budget = terms.budget;
+    }: _(RawOrigin::Signed(sponsor), sponsorship_id, terms)
+    verify {
+        assert_eq!(<Sponsorships<T>>::get(sponsorship_id).unwrap().terms.budget, budget);
+    }
+
+    add_sponsored_beneficiaries {
+        let b in 1 .. T::MaxSponsoredBeneficiaries::get();
+        let sponsor: T::AccountId = whitelisted_caller();
+        let sponsorship_id = setup_sponsorship::<T>(&sponsor, 0);
+        let beneficiaries = BoundedVec::truncate_from(get_beneficiaries::<T>(b));
+    }: _(RawOrigin::Signed(sponsor), sponsorship_id, beneficiaries)
+    verify {
+        assert_eq!(<Sponsorships<T>>::get(sponsorship_id).unwrap().beneficiary_count, b);
+    }
+
+    remove_sponsored_beneficiaries {
+        let b in 1 .. T::MaxSponsoredBeneficiaries::get();
+        let sponsor: T::AccountId = whitelisted_caller();
+        let sponsorship_id = setup_sponsorship::<T>(&sponsor, b);
+        let beneficiaries = BoundedVec::truncate_from(get_beneficiaries::<T>(b));
+    }: _(RawOrigin::Signed(sponsor), sponsorship_id, beneficiaries)
+    verify {
+        assert_eq!(<Sponsorships<T>>::get(sponsorship_id).unwrap().beneficiary_count, 0);
+    }
+
+    close_sponsorship {
+        let b in 1 .. T::MaxSponsoredBeneficiaries::get();
+        let sponsor: T::AccountId = whitelisted_caller();
+        let sponsorship_id = setup_sponsorship::<T>(&sponsor, b);
+    }: _(RawOrigin::Signed(sponsor), sponsorship_id)
+    verify {
+        assert!(<Sponsorships<T>>::get(sponsorship_id).is_none());
+    }
+
+    charge_sponsored_fee {
+        let sponsor: T::AccountId = whitelisted_caller();
+        let relayer: T::AccountId = account("relayer", 0, 0);
+        let sponsorship_id = setup_sponsorship::<T>(&sponsor, 1);
+        let beneficiary = get_beneficiaries::<T>(1)[0].clone();
+        let terms = get_sponsorship_terms::<T>();
+        setup_balances::<T>(sponsor.clone(), terms.budget);
+    }: {
+        sponsorship::charge_sponsored_fee::<T>(sponsorship_id, &beneficiary, &relayer)?;
+    }
+    verify {
+        assert_eq!(T::Currency::free_balance(&relayer), terms.fee_per_call);
+    }
+
+    relay_meta_transaction {
+        let signer_key = SignerId::generate_pair(None);
+        let signer: T::AccountId = T::AccountId::decode(&mut signer_key.encode().as_slice())
+            .expect("valid account id");
+        let relayer: T::AccountId = whitelisted_caller();
+        let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let nonce = <MetaTransactionNonces<T>>::get(&signer);
+        let mortality = <frame_system::Pallet<T>>::block_number() + 10u32.into();
+
+        let payload = meta_transaction::encode_meta_transaction_params::<T>(&relayer, &call, &signer, nonce, mortality);
+        let signature: sr25519::Signature = signer_key.sign(&payload).ok_or("Error signing meta transaction")?.into();
+        let signature = convert_sr25519_signature::<T::Signature>(signature);
+    }: {
+        meta_transaction::verify_meta_transaction::<T>(&relayer, &call, &signer, nonce, mortality, &signature)?;
+        <MetaTransactionNonces<T>>::mutate(&signer, |n| *n += 1);
+    }
+    verify {
+        assert_eq!(nonce + 1, <MetaTransactionNonces<T>>::get(&signer));
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -39,6 +39,13 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn charge_fee() -> Weight;
 	fn charge_fee_in_token() -> Weight;
+	fn create_sponsorship() -> Weight;
+	fn update_sponsorship() -> Weight;
+	fn add_sponsored_beneficiaries(b: u32, ) -> Weight;
+	fn remove_sponsored_beneficiaries(b: u32, ) -> Weight;
+	fn close_sponsorship(b: u32, ) -> Weight;
+	fn charge_sponsored_fee() -> Weight;
+	fn relay_meta_transaction() -> Weight;
 }
 
 /// Weights for pallet_avn_proxy using the Substrate node and recommended hardware.
@@ -74,6 +81,97 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `AvnProxy::NextSponsorshipId` (r:1 w:1)
+	/// Storage: `AvnProxy::Sponsorships` (r:0 w:1)
+	fn create_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 21_157_000 picoseconds.
+		Weight::from_parts(21_480_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	fn update_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `3728`
+		// Minimum execution time: 22_586_000 picoseconds.
+		Weight::from_parts(22_930_000, 3728)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:100 w:100)
+	/// The range of component `b` is `[1, 100]`.
+	fn add_sponsored_beneficiaries(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `3728`
+		// Minimum execution time: 23_758_000 picoseconds.
+		Weight::from_parts(24_120_000, 3728)
+			.saturating_add(Weight::from_parts(6_830_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:100 w:100)
+	/// Storage: `AvnProxy::BeneficiarySpend` (r:0 w:100)
+	/// The range of component `b` is `[1, 100]`.
+	fn remove_sponsored_beneficiaries(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `3728`
+		// Minimum execution time: 23_393_000 picoseconds.
+		Weight::from_parts(23_750_000, 3728)
+			.saturating_add(Weight::from_parts(8_410_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:0 w:100)
+	/// Storage: `AvnProxy::BeneficiarySpend` (r:0 w:100)
+	/// The range of component `b` is `[1, 100]`.
+	fn close_sponsorship(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `3728`
+		// Minimum execution time: 27_195_000 picoseconds.
+		Weight::from_parts(27_610_000, 3728)
+			.saturating_add(Weight::from_parts(2_950_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:1 w:0)
+	/// Storage: `AvnProxy::BeneficiarySpend` (r:1 w:1)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn charge_sponsored_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 116_564_000 picoseconds.
+		Weight::from_parts(118_340_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AvnProxy::MetaTransactionNonces` (r:1 w:1)
+	fn relay_meta_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `56`
+		//  Estimated: `3521`
+		// Minimum execution time: 51_631_000 picoseconds.
+		Weight::from_parts(52_418_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -108,4 +206,95 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `AvnProxy::NextSponsorshipId` (r:1 w:1)
+	/// Storage: `AvnProxy::Sponsorships` (r:0 w:1)
+	fn create_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1489`
+		// Minimum execution time: 21_157_000 picoseconds.
+		Weight::from_parts(21_480_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	fn update_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `3728`
+		// Minimum execution time: 22_586_000 picoseconds.
+		Weight::from_parts(22_930_000, 3728)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:100 w:100)
+	/// The range of component `b` is `[1, 100]`.
+	fn add_sponsored_beneficiaries(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `3728`
+		// Minimum execution time: 23_758_000 picoseconds.
+		Weight::from_parts(24_120_000, 3728)
+			.saturating_add(Weight::from_parts(6_830_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:100 w:100)
+	/// Storage: `AvnProxy::BeneficiarySpend` (r:0 w:100)
+	/// The range of component `b` is `[1, 100]`.
+	fn remove_sponsored_beneficiaries(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `3728`
+		// Minimum execution time: 23_393_000 picoseconds.
+		Weight::from_parts(23_750_000, 3728)
+			.saturating_add(Weight::from_parts(8_410_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:0 w:100)
+	/// Storage: `AvnProxy::BeneficiarySpend` (r:0 w:100)
+	/// The range of component `b` is `[1, 100]`.
+	fn close_sponsorship(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296`
+		//  Estimated: `3728`
+		// Minimum execution time: 27_195_000 picoseconds.
+		Weight::from_parts(27_610_000, 3728)
+			.saturating_add(Weight::from_parts(2_950_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+	}
+	/// Storage: `AvnProxy::Sponsorships` (r:1 w:1)
+	/// Storage: `AvnProxy::SponsoredBeneficiaries` (r:1 w:0)
+	/// Storage: `AvnProxy::BeneficiarySpend` (r:1 w:1)
+	/// Storage: `TokenManager::AVTTokenContract` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn charge_sponsored_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 116_564_000 picoseconds.
+		Weight::from_parts(118_340_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AvnProxy::MetaTransactionNonces` (r:1 w:1)
+	fn relay_meta_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `56`
+		//  Estimated: `3521`
+		// Minimum execution time: 51_631_000 picoseconds.
+		Weight::from_parts(52_418_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
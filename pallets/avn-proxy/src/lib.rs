@@ -12,9 +12,11 @@ use frame_support::{
     dispatch::{DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo},
     ensure,
     pallet_prelude::ValueQuery,
-    traits::{Currency, IsSubType},
+    traits::{Contains, Currency, IsSubType},
+    BoundedVec,
 };
 use frame_system::{self as system, ensure_signed};
+pub use sp_avn_common::CallType;
 use sp_avn_common::{verify_multi_signature, InnerCallValidator, PaymentHandler, Proof};
 
 use core::convert::TryInto;
@@ -22,12 +24,16 @@ pub use pallet::*;
 use sp_core::{MaxEncodedLen, H160};
 use sp_runtime::{
     scale_info::TypeInfo,
-    traits::{Dispatchable, Hash, IdentifyAccount, Member, Verify},
+    traits::{Dispatchable, Hash, IdentifyAccount, Member, Saturating, Verify},
 };
 use sp_std::prelude::*;
 
 pub const PAYMENT_AUTH_CONTEXT: &'static [u8] = b"authorization for proxy payment";
 
+pub mod meta_transaction;
+pub mod sponsorship;
+pub use sponsorship::{PeriodSpend, Sponsorship, SponsorshipId, SponsorshipTerms};
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -78,6 +84,21 @@ pub mod pallet {
             TokenBalance = <Self::Currency as Currency<Self::AccountId>>::Balance,
             Error = DispatchError,
         >;
+
+        /// The maximum number of call types a sponsorship can cover
+        #[pallet::constant]
+        type MaxSponsoredCallTypes: Get<u32>;
+
+        /// The maximum number of beneficiaries of a sponsorship
+        #[pallet::constant]
+        type MaxSponsoredBeneficiaries: Get<u32>;
+
+        /// The length of the period, in blocks, the spend cap of each beneficiary applies to
+        #[pallet::constant]
+        type SponsorshipPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The calls that can be relayed as meta transactions
+        type MetaTransactionFilter: Contains<<Self as Config>::RuntimeCall>;
     }
 
     #[pallet::pallet]
@@ -86,8 +107,38 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        CallDispatched { relayer: T::AccountId, hash: T::Hash },
-        InnerCallFailed { relayer: T::AccountId, hash: T::Hash, dispatch_error: DispatchError },
+        CallDispatched {
+            relayer: T::AccountId,
+            hash: T::Hash,
+        },
+        InnerCallFailed {
+            relayer: T::AccountId,
+            hash: T::Hash,
+            dispatch_error: DispatchError,
+        },
+        SponsorshipCreated {
+            sponsorship_id: SponsorshipId,
+            sponsor: T::AccountId,
+            token: T::Token,
+            budget: BalanceOf<T>,
+        },
+        SponsorshipUpdated {
+            sponsorship_id: SponsorshipId,
+            budget: BalanceOf<T>,
+        },
+        SponsoredBeneficiariesUpdated {
+            sponsorship_id: SponsorshipId,
+            beneficiary_count: u32,
+        },
+        SponsorshipClosed {
+            sponsorship_id: SponsorshipId,
+        },
+        SponsoredFeePaid {
+            sponsorship_id: SponsorshipId,
+            beneficiary: T::AccountId,
+            relayer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -95,6 +146,21 @@ pub mod pallet {
         TransactionNotSupported,
         UnauthorizedFee,
         UnauthorizedProxyTransaction,
+        /// The fee must not be zero, the beneficiary cap must cover at least one call and at
+        /// least one call type must be sponsored
+        InvalidSponsorshipTerms,
+        SponsorshipNotFound,
+        SenderIsNotSponsor,
+        TooManySponsoredBeneficiaries,
+        NotASponsoredBeneficiary,
+        CallTypeNotSponsored,
+        SponsorshipBudgetExhausted,
+        /// The beneficiary has reached its spend cap for the current period
+        BeneficiaryCapReached,
+        MetaTransactionCallNotAllowed,
+        MetaTransactionExpired,
+        InvalidMetaTransactionNonce,
+        UnauthorizedMetaTransaction,
     }
 
     #[pallet::storage]
@@ -104,6 +170,48 @@ pub mod pallet {
     pub type PaymentNonces<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
 
+    #[pallet::storage]
+    /// The id that will be used for the next sponsorship
+    pub type NextSponsorshipId<T: Config> = StorageValue<_, SponsorshipId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn sponsorships)]
+    /// Fee budgets registered by sponsors
+    pub type Sponsorships<T: Config> =
+        StorageMap<_, Blake2_128Concat, SponsorshipId, Sponsorship<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn is_sponsored_beneficiary)]
+    /// The accounts whose proxied calls are paid for by a sponsorship
+    pub type SponsoredBeneficiaries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SponsorshipId,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn beneficiary_spend)]
+    /// The amount a sponsorship has spent on each beneficiary in the latest period it was used
+    pub type BeneficiarySpend<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SponsorshipId,
+        Blake2_128Concat,
+        T::AccountId,
+        PeriodSpend<T>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn meta_transaction_nonces)]
+    /// The number of meta transactions relayed for each signer
+    pub type MetaTransactionNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -159,25 +267,224 @@ pub mod pallet {
                 return Ok(Some(final_weight).into())
             }
 
-            let sender: T::RuntimeOrigin =
-                frame_system::RawOrigin::Signed(proof.signer.clone()).into();
-
-            let dispatch_result = call.dispatch(sender).map(|_| ()).map_err(|e| e.error);
-            match dispatch_result {
-                Ok(_) => {
-                    Self::deposit_event(Event::<T>::CallDispatched { relayer, hash: call_hash });
-                },
-                Err(dispatch_error) => {
-                    Self::deposit_event(Event::<T>::InnerCallFailed {
-                        relayer,
-                        hash: call_hash,
-                        dispatch_error,
-                    });
-                },
+            Self::dispatch_inner_call(relayer, proof.signer, *call, call_hash);
+
+            Ok(Some(final_weight).into())
+        }
+
+        /// Register a budget that pays relayer fees for the proxied calls of beneficiaries
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::create_sponsorship())]
+        pub fn create_sponsorship(
+            origin: OriginFor<T>,
+            terms: SponsorshipTerms<T>,
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+            sponsorship::validate_terms::<T>(&terms)?;
+
+            let sponsorship_id = <NextSponsorshipId<T>>::get();
+            <NextSponsorshipId<T>>::put(sponsorship_id.saturating_add(1));
+
+            let (token, budget) = (terms.token, terms.budget);
+            <Sponsorships<T>>::insert(
+                sponsorship_id,
+                Sponsorship { sponsor: sponsor.clone(), terms, beneficiary_count: 0 },
+            );
+
+            Self::deposit_event(Event::<T>::SponsorshipCreated {
+                sponsorship_id,
+                sponsor,
+                token,
+                budget,
+            });
+
+            Ok(())
+        }
+
+        /// Replace the terms of a sponsorship. This can be used to top up its budget.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::update_sponsorship())]
+        pub fn update_sponsorship(
+            origin: OriginFor<T>,
+            sponsorship_id: SponsorshipId,
+            terms: SponsorshipTerms<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let mut sponsorship =
+                sponsorship::try_get_sponsorship_for::<T>(&sender, sponsorship_id)?;
+            sponsorship::validate_terms::<T>(&terms)?;
+
+            let budget = terms.budget;
+            sponsorship.terms = terms;
+            <Sponsorships<T>>::insert(sponsorship_id, sponsorship);
+
+            Self::deposit_event(Event::<T>::SponsorshipUpdated { sponsorship_id, budget });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_sponsored_beneficiaries(beneficiaries.len() as u32))]
+        pub fn add_sponsored_beneficiaries(
+            origin: OriginFor<T>,
+            sponsorship_id: SponsorshipId,
+            beneficiaries: BoundedVec<T::AccountId, T::MaxSponsoredBeneficiaries>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let mut sponsorship =
+                sponsorship::try_get_sponsorship_for::<T>(&sender, sponsorship_id)?;
+
+            for beneficiary in beneficiaries.iter() {
+                if <SponsoredBeneficiaries<T>>::get(sponsorship_id, beneficiary) == false {
+                    <SponsoredBeneficiaries<T>>::insert(sponsorship_id, beneficiary, true);
+                    sponsorship.beneficiary_count.saturating_inc();
+                }
+            }
+            ensure!(
+                sponsorship.beneficiary_count <= T::MaxSponsoredBeneficiaries::get(),
+                Error::<T>::TooManySponsoredBeneficiaries
+            );
+
+            let beneficiary_count = sponsorship.beneficiary_count;
+            <Sponsorships<T>>::insert(sponsorship_id, sponsorship);
+            Self::deposit_event(Event::<T>::SponsoredBeneficiariesUpdated {
+                sponsorship_id,
+                beneficiary_count,
+            });
+
+            Ok(())
+        }
+
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_sponsored_beneficiaries(beneficiaries.len() as u32))]
+        pub fn remove_sponsored_beneficiaries(
+            origin: OriginFor<T>,
+            sponsorship_id: SponsorshipId,
+            beneficiaries: BoundedVec<T::AccountId, T::MaxSponsoredBeneficiaries>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let mut sponsorship =
+                sponsorship::try_get_sponsorship_for::<T>(&sender, sponsorship_id)?;
+
+            for beneficiary in beneficiaries.iter() {
+                if <SponsoredBeneficiaries<T>>::take(sponsorship_id, beneficiary) {
+                    <BeneficiarySpend<T>>::remove(sponsorship_id, beneficiary);
+                    sponsorship.beneficiary_count.saturating_dec();
+                }
+            }
+
+            let beneficiary_count = sponsorship.beneficiary_count;
+            <Sponsorships<T>>::insert(sponsorship_id, sponsorship);
+            Self::deposit_event(Event::<T>::SponsoredBeneficiariesUpdated {
+                sponsorship_id,
+                beneficiary_count,
+            });
+
+            Ok(())
+        }
+
+        /// Close a sponsorship and remove all its beneficiaries
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::close_sponsorship(T::MaxSponsoredBeneficiaries::get()))]
+        pub fn close_sponsorship(
+            origin: OriginFor<T>,
+            sponsorship_id: SponsorshipId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            sponsorship::try_get_sponsorship_for::<T>(&sender, sponsorship_id)?;
+
+            let limit = T::MaxSponsoredBeneficiaries::get();
+            let _ = <SponsoredBeneficiaries<T>>::clear_prefix(sponsorship_id, limit, None);
+            let _ = <BeneficiarySpend<T>>::clear_prefix(sponsorship_id, limit, None);
+            <Sponsorships<T>>::remove(sponsorship_id);
+
+            Self::deposit_event(Event::<T>::SponsorshipClosed { sponsorship_id });
+
+            Ok(())
+        }
+
+        /// Proxy a call whose relayer fee is paid by a sponsorship of the signer.
+        /// The fee is only charged when the inner call succeeds, so the inner call signature
+        /// must be valid for the transaction to be accepted.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            <T as pallet::Config>::WeightInfo::charge_sponsored_fee()
+            .saturating_add(call.get_dispatch_info().call_weight)
+            .saturating_add(Weight::from_parts(50_000 as u64, 0)))
+        ]
+        pub fn sponsored_proxy(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+            sponsorship_id: SponsorshipId,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            let final_weight = T::WeightInfo::charge_sponsored_fee()
+                .saturating_add(call.get_dispatch_info().call_weight)
+                .saturating_add(Weight::from_parts(50_000 as u64, 0));
+
+            let proof = <T as Config>::ProxyConfig::get_proof(&call)
+                .ok_or(Error::<T>::TransactionNotSupported)?;
+            ensure!(relayer == proof.relayer, Error::<T>::UnauthorizedProxyTransaction);
+
+            sponsorship::validate_sponsored_call::<T>(sponsorship_id, &proof.signer, &call)?;
+            Self::validate_inner_call_signature(&call)?;
+
+            let call_hash: T::Hash = T::Hashing::hash_of(&call);
+            if Self::dispatch_inner_call(relayer.clone(), proof.signer.clone(), *call, call_hash) {
+                // If the sponsor cannot pay, the whole transaction is reverted
+                sponsorship::charge_sponsored_fee::<T>(sponsorship_id, &proof.signer, &relayer)?;
             }
 
             Ok(Some(final_weight).into())
         }
+
+        /// Relay a call signed by `signer` and dispatch it with a `Signed(signer)` origin.
+        /// The signature covers the relayer, the call, the signer, its meta transaction nonce and
+        /// the last block the transaction is valid in. If a payment authorisation is provided, the
+        /// relayer fee is charged regardless of the outcome of the call.
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            <T as pallet::Config>::WeightInfo::relay_meta_transaction()
+            .saturating_add(<T as pallet::Config>::WeightInfo::charge_fee())
+            .saturating_add(call.get_dispatch_info().call_weight)
+            .saturating_add(Weight::from_parts(50_000 as u64, 0)))
+        ]
+        pub fn relay_meta_transaction(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+            signer: T::AccountId,
+            nonce: u64,
+            mortality: BlockNumberFor<T>,
+            signature: T::Signature,
+            payment_info: Option<
+                Box<PaymentInfo<T::AccountId, BalanceOf<T>, T::Signature, T::Token>>,
+            >,
+        ) -> DispatchResultWithPostInfo {
+            let relayer = ensure_signed(origin)?;
+            let mut final_weight = T::WeightInfo::relay_meta_transaction()
+                .saturating_add(call.get_dispatch_info().call_weight)
+                .saturating_add(Weight::from_parts(50_000 as u64, 0));
+
+            ensure!(
+                T::MetaTransactionFilter::contains(&call),
+                Error::<T>::MetaTransactionCallNotAllowed
+            );
+            meta_transaction::verify_meta_transaction::<T>(
+                &relayer, &call, &signer, nonce, mortality, &signature,
+            )?;
+            <MetaTransactionNonces<T>>::mutate(&signer, |n| *n += 1);
+
+            if let Some(payment_info) = payment_info {
+                final_weight = final_weight.saturating_add(T::WeightInfo::charge_fee());
+                let proof = Proof { signer: signer.clone(), relayer: relayer.clone(), signature };
+                Self::charge_fee(&proof, *payment_info)?;
+            }
+
+            let call_hash: T::Hash = T::Hashing::hash_of(&call);
+            Self::dispatch_inner_call(relayer, signer, *call, call_hash);
+
+            Ok(Some(final_weight).into())
+        }
     }
 }
 
@@ -185,6 +492,33 @@ pub(crate) type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 impl<T: Config> Pallet<T> {
+    /// Dispatches `call` as `signer` and reports the outcome with an event. Returns true if the
+    /// call succeeded.
+    fn dispatch_inner_call(
+        relayer: T::AccountId,
+        signer: T::AccountId,
+        call: <T as Config>::RuntimeCall,
+        call_hash: T::Hash,
+    ) -> bool {
+        let sender: T::RuntimeOrigin = frame_system::RawOrigin::Signed(signer).into();
+
+        let dispatch_result = call.dispatch(sender).map(|_| ()).map_err(|e| e.error);
+        match dispatch_result {
+            Ok(_) => {
+                Self::deposit_event(Event::<T>::CallDispatched { relayer, hash: call_hash });
+                return true
+            },
+            Err(dispatch_error) => {
+                Self::deposit_event(Event::<T>::InnerCallFailed {
+                    relayer,
+                    hash: call_hash,
+                    dispatch_error,
+                });
+                return false
+            },
+        }
+    }
+
     fn validate_inner_call_signature(call: &Box<<T as Config>::RuntimeCall>) -> DispatchResult {
         let inner_call_sig_valid = <T as Config>::ProxyConfig::signature_is_valid(call);
         if inner_call_sig_valid == false {
@@ -271,6 +605,14 @@ pub mod proxy_tests_no_fees;
 #[path = "tests/proxy_tests_with_fees.rs"]
 pub mod proxy_tests_with_fees;
 
+#[cfg(test)]
+#[path = "tests/sponsorship_tests.rs"]
+pub mod sponsorship_tests;
+
+#[cfg(test)]
+#[path = "tests/meta_transaction_tests.rs"]
+pub mod meta_transaction_tests;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
@@ -0,0 +1,55 @@
+// Copyright 2026 Aventus DAO Ltd
+
+//! A meta transaction is a call signed by its sender and submitted by a relayer. The signed
+//! envelope `(call, signer, nonce, mortality)` authorises the call itself, so any call allowed by
+//! `MetaTransactionFilter` can be relayed without a dedicated `signed_*` extrinsic. The call is
+//! dispatched with a `Signed(signer)` origin.
+
+use crate::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+pub const META_TRANSACTION_CONTEXT: &'static [u8] = b"authorization for meta transaction";
+
+pub fn encode_meta_transaction_params<T: Config>(
+    relayer: &T::AccountId,
+    call: &<T as Config>::RuntimeCall,
+    signer: &T::AccountId,
+    nonce: u64,
+    mortality: BlockNumberFor<T>,
+) -> Vec<u8> {
+    return (META_TRANSACTION_CONTEXT, relayer, call, signer, nonce, mortality).encode()
+}
+
+/// Checks that the envelope has been signed by `signer` for this relayer, with the current nonce of
+/// the signer, and that `mortality` has not expired
+pub fn verify_meta_transaction<T: Config>(
+    relayer: &T::AccountId,
+    call: &<T as Config>::RuntimeCall,
+    signer: &T::AccountId,
+    nonce: u64,
+    mortality: BlockNumberFor<T>,
+    signature: &T::Signature,
+) -> DispatchResult {
+    ensure!(
+        <frame_system::Pallet<T>>::block_number() <= mortality,
+        Error::<T>::MetaTransactionExpired
+    );
+    ensure!(
+        nonce == <MetaTransactionNonces<T>>::get(signer),
+        Error::<T>::InvalidMetaTransactionNonce
+    );
+
+    let encoded_payload =
+        encode_meta_transaction_params::<T>(relayer, call, signer, nonce, mortality);
+    ensure!(
+        verify_multi_signature::<T::Signature, T::AccountId>(
+            signer,
+            signature,
+            &encoded_payload.as_slice()
+        )
+        .is_ok(),
+        Error::<T>::UnauthorizedMetaTransaction
+    );
+
+    Ok(())
+}
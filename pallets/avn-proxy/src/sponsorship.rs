@@ -0,0 +1,179 @@
+// Copyright 2026 Aventus DAO Ltd
+
+//! Sponsors can register budgets that pay the relayer fee of proxied calls on behalf of a set of
+//! beneficiaries. Unlike `PaymentInfo`, the beneficiary does not need to sign a payment
+//! authorisation and does not need to hold any funds. The sponsor limits its exposure with:
+//!  - a total budget, reduced by every fee paid
+//!  - a fixed fee paid to the relayer per call
+//!  - the call types it is willing to pay for
+//!  - a cap on the amount spent on each beneficiary per `SponsorshipPeriod`
+
+use crate::*;
+use frame_support::{
+    BoundedVec, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{Saturating, Zero};
+
+pub type SponsorshipId = u64;
+
+#[derive(
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct SponsorshipTerms<T: Config> {
+    pub token: T::Token,
+    /// The amount left to pay fees with
+    pub budget: BalanceOf<T>,
+    /// The amount paid to the relayer for each sponsored call
+    pub fee_per_call: BalanceOf<T>,
+    /// The maximum amount spent on a single beneficiary per sponsorship period
+    pub beneficiary_cap: BalanceOf<T>,
+    /// The calls the sponsor pays for
+    pub call_types: BoundedVec<CallType, T::MaxSponsoredCallTypes>,
+}
+
+#[derive(
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct Sponsorship<T: Config> {
+    pub sponsor: T::AccountId,
+    pub terms: SponsorshipTerms<T>,
+    pub beneficiary_count: u32,
+}
+
+/// The amount spent on a beneficiary in a sponsorship period
+#[derive(
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+    DefaultNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PeriodSpend<T: Config> {
+    pub period: BlockNumberFor<T>,
+    pub spent: BalanceOf<T>,
+}
+
+pub fn validate_terms<T: Config>(terms: &SponsorshipTerms<T>) -> DispatchResult {
+    ensure!(terms.fee_per_call.is_zero() == false, Error::<T>::InvalidSponsorshipTerms);
+    ensure!(terms.beneficiary_cap >= terms.fee_per_call, Error::<T>::InvalidSponsorshipTerms);
+    ensure!(terms.call_types.is_empty() == false, Error::<T>::InvalidSponsorshipTerms);
+
+    Ok(())
+}
+
+pub fn try_get_sponsorship_for<T: Config>(
+    sponsor: &T::AccountId,
+    sponsorship_id: SponsorshipId,
+) -> Result<Sponsorship<T>, Error<T>> {
+    let sponsorship =
+        <Sponsorships<T>>::get(sponsorship_id).ok_or(Error::<T>::SponsorshipNotFound)?;
+    ensure!(sponsorship.sponsor == *sponsor, Error::<T>::SenderIsNotSponsor);
+
+    return Ok(sponsorship)
+}
+
+fn current_period<T: Config>() -> BlockNumberFor<T> {
+    let period_length = T::SponsorshipPeriod::get().max(1u32.into());
+    return <frame_system::Pallet<T>>::block_number() / period_length
+}
+
+/// Returns the spend of `beneficiary` in the current period, including the fee of this call
+fn next_period_spend<T: Config>(
+    sponsorship_id: SponsorshipId,
+    beneficiary: &T::AccountId,
+    fee: BalanceOf<T>,
+) -> PeriodSpend<T> {
+    let period = current_period::<T>();
+    let previous_spend = <BeneficiarySpend<T>>::get(sponsorship_id, beneficiary);
+    let spent_in_period =
+        if previous_spend.period == period { previous_spend.spent } else { Zero::zero() };
+
+    return PeriodSpend { period, spent: spent_in_period.saturating_add(fee) }
+}
+
+/// Checks that the sponsorship covers `call` for `beneficiary` and has enough budget left to pay
+/// for it
+pub fn validate_sponsored_call<T: Config>(
+    sponsorship_id: SponsorshipId,
+    beneficiary: &T::AccountId,
+    call: &<T as Config>::RuntimeCall,
+) -> DispatchResult {
+    let sponsorship =
+        <Sponsorships<T>>::get(sponsorship_id).ok_or(Error::<T>::SponsorshipNotFound)?;
+    ensure!(
+        <SponsoredBeneficiaries<T>>::get(sponsorship_id, beneficiary),
+        Error::<T>::NotASponsoredBeneficiary
+    );
+
+    let call_type = CallType::of(call).ok_or(Error::<T>::CallTypeNotSponsored)?;
+    ensure!(sponsorship.terms.call_types.contains(&call_type), Error::<T>::CallTypeNotSponsored);
+
+    let fee = sponsorship.terms.fee_per_call;
+    ensure!(sponsorship.terms.budget >= fee, Error::<T>::SponsorshipBudgetExhausted);
+    ensure!(
+        next_period_spend::<T>(sponsorship_id, beneficiary, fee).spent <=
+            sponsorship.terms.beneficiary_cap,
+        Error::<T>::BeneficiaryCapReached
+    );
+
+    Ok(())
+}
+
+/// Pays the relayer from the sponsor's account and records the spend against the budget
+pub fn charge_sponsored_fee<T: Config>(
+    sponsorship_id: SponsorshipId,
+    beneficiary: &T::AccountId,
+    relayer: &T::AccountId,
+) -> DispatchResult {
+    // Read the sponsorship again in case the sponsored call changed it
+    let mut sponsorship =
+        <Sponsorships<T>>::get(sponsorship_id).ok_or(Error::<T>::SponsorshipNotFound)?;
+    let fee = sponsorship.terms.fee_per_call;
+    ensure!(sponsorship.terms.budget >= fee, Error::<T>::SponsorshipBudgetExhausted);
+    T::PaymentHandler::pay_recipient(
+        &sponsorship.terms.token,
+        &fee,
+        &sponsorship.sponsor,
+        relayer,
+    )?;
+
+    <BeneficiarySpend<T>>::insert(
+        sponsorship_id,
+        beneficiary,
+        next_period_spend::<T>(sponsorship_id, beneficiary, fee),
+    );
+    sponsorship.terms.budget = sponsorship.terms.budget.saturating_sub(fee);
+    <Sponsorships<T>>::insert(sponsorship_id, sponsorship);
+
+    <Pallet<T>>::deposit_event(Event::<T>::SponsoredFeePaid {
+        sponsorship_id,
+        beneficiary: beneficiary.clone(),
+        relayer: relayer.clone(),
+        amount: fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+use crate::{meta_transaction::encode_meta_transaction_params, mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::Hash;
+
+const MORTALITY: u64 = 10;
+
+struct MetaTransactionContext {
+    signer: TestAccount,
+    relayer: TestAccount,
+    call: Box<RuntimeCall>,
+    nonce: u64,
+    mortality: u64,
+}
+
+impl Default for MetaTransactionContext {
+    fn default() -> Self {
+        let proxy_context: ProxyContext = Default::default();
+        MetaTransactionContext {
+            signer: proxy_context.signer,
+            relayer: proxy_context.relayer,
+            call: proxy_context.create_valid_inner_call(),
+            nonce: 0,
+            mortality: MORTALITY,
+        }
+    }
+}
+
+impl MetaTransactionContext {
+    fn sign(&self, signer: &TestAccount) -> Signature {
+        let payload = encode_meta_transaction_params::<TestRuntime>(
+            &self.relayer.account_id(),
+            &self.call,
+            &self.signer.account_id(),
+            self.nonce,
+            self.mortality,
+        );
+        return signer.sign(&payload)
+    }
+
+    fn relay_with_signature(&self, signature: Signature) -> DispatchResultWithPostInfo {
+        return AvnProxy::relay_meta_transaction(
+            RuntimeOrigin::signed(self.relayer.account_id()),
+            self.call.clone(),
+            self.signer.account_id(),
+            self.nonce,
+            self.mortality,
+            signature,
+            None,
+        )
+    }
+
+    fn relay(&self) -> DispatchResultWithPostInfo {
+        return self.relay_with_signature(self.sign(&self.signer))
+    }
+
+    fn call_hash(&self) -> <TestRuntime as system::Config>::Hash {
+        return Hashing::hash_of(&self.call)
+    }
+}
+
+fn transfer_call(amount: u128) -> Box<RuntimeCall> {
+    return Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+        dest: TestAccount::new([30u8; 32]).account_id(),
+        value: amount,
+    }))
+}
+
+mod relay_meta_transaction {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn envelope_is_valid() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: MetaTransactionContext = Default::default();
+
+                assert_ok!(context.relay());
+
+                assert_eq!(
+                    true,
+                    proxy_event_emitted(context.relayer.account_id(), context.call_hash())
+                );
+                assert_eq!(AvnProxy::meta_transaction_nonces(context.signer.account_id()), 1);
+            })
+        }
+
+        #[test]
+        fn call_is_dispatched_as_the_signer() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let mut context: MetaTransactionContext = Default::default();
+                context.call = transfer_call(ONE_AVT);
+                let recipient = TestAccount::new([30u8; 32]).account_id();
+
+                let signer_balance = Balances::free_balance(context.signer.account_id());
+                let relayer_balance = Balances::free_balance(context.relayer.account_id());
+
+                assert_ok!(context.relay());
+
+                assert_eq!(
+                    signer_balance - ONE_AVT,
+                    Balances::free_balance(context.signer.account_id())
+                );
+                assert_eq!(ONE_AVT, Balances::free_balance(recipient));
+                assert_eq!(relayer_balance, Balances::free_balance(context.relayer.account_id()));
+            })
+        }
+
+        #[test]
+        fn transactions_are_relayed_in_nonce_order() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let mut context: MetaTransactionContext = Default::default();
+
+                assert_ok!(context.relay());
+                context.nonce = 1;
+                assert_ok!(context.relay());
+
+                assert_eq!(AvnProxy::meta_transaction_nonces(context.signer.account_id()), 2);
+            })
+        }
+
+        #[test]
+        fn relayer_fee_is_paid() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: MetaTransactionContext = Default::default();
+                let signature = context.sign(&context.signer);
+                let proof = Proof {
+                    signer: context.signer.account_id(),
+                    relayer: context.relayer.account_id(),
+                    signature: signature.clone(),
+                };
+                let payment_signature = context.signer.sign(
+                    &(
+                        PAYMENT_AUTH_CONTEXT,
+                        &proof,
+                        &context.relayer.account_id(),
+                        &ONE_AVT,
+                        AVT_TOKEN_CONTRACT,
+                        0u64,
+                    )
+                        .encode(),
+                );
+                let payment_info = PaymentInfo {
+                    payer: context.signer.account_id(),
+                    recipient: context.relayer.account_id(),
+                    amount: ONE_AVT,
+                    signature: payment_signature,
+                    token: AVT_TOKEN_CONTRACT,
+                };
+
+                let signer_balance = Balances::free_balance(context.signer.account_id());
+                let relayer_balance = Balances::free_balance(context.relayer.account_id());
+
+                assert_ok!(AvnProxy::relay_meta_transaction(
+                    RuntimeOrigin::signed(context.relayer.account_id()),
+                    context.call.clone(),
+                    context.signer.account_id(),
+                    context.nonce,
+                    context.mortality,
+                    signature,
+                    Some(Box::new(payment_info)),
+                ));
+
+                assert_eq!(
+                    signer_balance - ONE_AVT,
+                    Balances::free_balance(context.signer.account_id())
+                );
+                assert_eq!(
+                    relayer_balance + ONE_AVT,
+                    Balances::free_balance(context.relayer.account_id())
+                );
+                assert_eq!(AvnProxy::payment_nonces(context.signer.account_id()), 1);
+            })
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn call_is_not_allowed() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let mut context: MetaTransactionContext = Default::default();
+                context.call =
+                    Box::new(RuntimeCall::System(SystemCall::remark_with_event { remark: vec![] }));
+
+                assert_noop!(context.relay(), Error::<TestRuntime>::MetaTransactionCallNotAllowed);
+            })
+        }
+
+        #[test]
+        fn envelope_has_expired() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: MetaTransactionContext = Default::default();
+                System::set_block_number(MORTALITY + 1);
+
+                assert_noop!(context.relay(), Error::<TestRuntime>::MetaTransactionExpired);
+            })
+        }
+
+        #[test]
+        fn nonce_is_not_the_next_nonce() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let mut context: MetaTransactionContext = Default::default();
+                context.nonce = 1;
+
+                assert_noop!(context.relay(), Error::<TestRuntime>::InvalidMetaTransactionNonce);
+            })
+        }
+
+        #[test]
+        fn envelope_is_replayed() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: MetaTransactionContext = Default::default();
+
+                assert_ok!(context.relay());
+                assert_noop!(context.relay(), Error::<TestRuntime>::InvalidMetaTransactionNonce);
+            })
+        }
+
+        #[test]
+        fn envelope_is_not_signed_by_the_signer() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: MetaTransactionContext = Default::default();
+                let signature = context.sign(&TestAccount::new([40u8; 32]));
+
+                assert_noop!(
+                    context.relay_with_signature(signature),
+                    Error::<TestRuntime>::UnauthorizedMetaTransaction
+                );
+            })
+        }
+
+        #[test]
+        fn envelope_is_relayed_by_another_relayer() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: MetaTransactionContext = Default::default();
+                let signature = context.sign(&context.signer);
+
+                assert_noop!(
+                    AvnProxy::relay_meta_transaction(
+                        RuntimeOrigin::signed(TestAccount::new([40u8; 32]).account_id()),
+                        context.call.clone(),
+                        context.signer.account_id(),
+                        context.nonce,
+                        context.mortality,
+                        signature,
+                        None,
+                    ),
+                    Error::<TestRuntime>::UnauthorizedMetaTransaction
+                );
+            })
+        }
+    }
+
+    #[test]
+    fn nonce_is_used_when_the_inner_call_fails() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let mut context: MetaTransactionContext = Default::default();
+            context.call = transfer_call(HUNDRED_AVT + 1);
+
+            assert_ok!(context.relay());
+
+            assert_eq!(
+                true,
+                inner_call_failed_event_emitted(context.relayer.account_id(), context.call_hash())
+            );
+            assert_eq!(AvnProxy::meta_transaction_nonces(context.signer.account_id()), 1);
+        })
+    }
+}
@@ -6,7 +6,7 @@ use frame_support::{
     derive_impl,
     pallet_prelude::{DispatchClass, Weight},
     parameter_types,
-    traits::{EqualPrivilegeOnly, Everything},
+    traits::{ConstBool, EqualPrivilegeOnly, Everything},
     PalletId,
 };
 use frame_system::{self as system, limits::BlockWeights, EnsureRoot};
@@ -106,6 +106,10 @@ impl Config for TestRuntime {
     type WeightInfo = ();
     type PaymentHandler = TokenManager;
     type Token = H160;
+    type MaxSponsoredCallTypes = ConstU32<4>;
+    type MaxSponsoredBeneficiaries = ConstU32<10>;
+    type SponsorshipPeriod = ConstU64<10>;
+    type MetaTransactionFilter = TestMetaTransactionFilter;
 }
 
 pub type AvnProxyCall = super::Call<TestRuntime>;
@@ -153,6 +157,16 @@ impl pallet_nft_manager::Config for TestRuntime {
     type Signature = Signature;
     type WeightInfo = ();
     type BatchBound = ConstU32<10>;
+    type ReleaseExternalRefOnBurn = ConstBool<false>;
+    type Token = H160;
+    type TokenBalance = u128;
+    type PaymentHandler = TokenManager;
+    type EscrowPalletId = NftEscrowPalletId;
+    type FiatSettlementOrigin = frame_system::EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+    pub const NftEscrowPalletId: PalletId = PalletId(*b"nft/escr");
 }
 
 #[derive_impl(pallet_avn::config_preludes::TestDefaultConfig as pallet_avn::DefaultConfig)]
@@ -396,6 +410,17 @@ impl InnerCallValidator for TestAvnProxyConfig {
     }
 }
 
+pub struct TestMetaTransactionFilter;
+impl Contains<RuntimeCall> for TestMetaTransactionFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::System(system::Call::remark { .. }) |
+                RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+        )
+    }
+}
+
 // ==============================================================================================
 
 pub struct ExtBuilder {
@@ -0,0 +1,455 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::traits::Hash;
+
+const SPONSORSHIP_ID: SponsorshipId = 0;
+
+struct SponsorshipContext {
+    sponsor: TestAccount,
+    beneficiary: TestAccount,
+    relayer: TestAccount,
+}
+
+impl Default for SponsorshipContext {
+    fn default() -> Self {
+        let proxy_context: ProxyContext = Default::default();
+        SponsorshipContext {
+            sponsor: TestAccount::new([20u8; 32]),
+            beneficiary: proxy_context.signer,
+            relayer: proxy_context.relayer,
+        }
+    }
+}
+
+impl SponsorshipContext {
+    fn terms(&self) -> SponsorshipTerms<TestRuntime> {
+        let remark = ProxyContext::default().create_valid_inner_call();
+        return SponsorshipTerms {
+            token: AVT_TOKEN_CONTRACT,
+            budget: 10 * ONE_AVT,
+            fee_per_call: ONE_AVT,
+            beneficiary_cap: 2 * ONE_AVT,
+            call_types: vec![CallType::of(&remark).unwrap()].try_into().unwrap(),
+        }
+    }
+
+    fn setup(&self) {
+        Balances::make_free_balance_be(&self.sponsor.account_id(), HUNDRED_AVT);
+        assert_ok!(AvnProxy::create_sponsorship(
+            RuntimeOrigin::signed(self.sponsor.account_id()),
+            self.terms()
+        ));
+        assert_ok!(AvnProxy::add_sponsored_beneficiaries(
+            RuntimeOrigin::signed(self.sponsor.account_id()),
+            SPONSORSHIP_ID,
+            vec![self.beneficiary.account_id()].try_into().unwrap()
+        ));
+    }
+
+    fn sponsored_proxy(&self, call: Box<RuntimeCall>) -> DispatchResultWithPostInfo {
+        return AvnProxy::sponsored_proxy(
+            RuntimeOrigin::signed(self.relayer.account_id()),
+            call,
+            SPONSORSHIP_ID,
+        )
+    }
+
+    fn remaining_budget(&self) -> u128 {
+        return AvnProxy::sponsorships(SPONSORSHIP_ID).unwrap().terms.budget
+    }
+}
+
+fn sponsored_fee_paid_event_emitted(context: &SponsorshipContext) -> bool {
+    return System::events().iter().any(|a| {
+        a.event ==
+            RuntimeEvent::AvnProxy(crate::Event::<TestRuntime>::SponsoredFeePaid {
+                sponsorship_id: SPONSORSHIP_ID,
+                beneficiary: context.beneficiary.account_id(),
+                relayer: context.relayer.account_id(),
+                amount: ONE_AVT,
+            })
+    })
+}
+
+mod managing_sponsorships {
+    use super::*;
+
+    #[test]
+    fn create_sponsorship_succeeds() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+
+            let sponsorship = AvnProxy::sponsorships(SPONSORSHIP_ID).unwrap();
+            assert_eq!(sponsorship.sponsor, context.sponsor.account_id());
+            assert_eq!(sponsorship.terms, context.terms());
+            assert_eq!(sponsorship.beneficiary_count, 1);
+            assert_eq!(NextSponsorshipId::<TestRuntime>::get(), SPONSORSHIP_ID + 1);
+            assert!(AvnProxy::is_sponsored_beneficiary(
+                SPONSORSHIP_ID,
+                context.beneficiary.account_id()
+            ));
+            System::assert_has_event(
+                crate::Event::<TestRuntime>::SponsorshipCreated {
+                    sponsorship_id: SPONSORSHIP_ID,
+                    sponsor: context.sponsor.account_id(),
+                    token: AVT_TOKEN_CONTRACT,
+                    budget: 10 * ONE_AVT,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn create_sponsorship_fails_with_invalid_terms() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            let sponsor = RuntimeOrigin::signed(context.sponsor.account_id());
+
+            let mut terms = context.terms();
+            terms.fee_per_call = 0;
+            assert_noop!(
+                AvnProxy::create_sponsorship(sponsor.clone(), terms),
+                Error::<TestRuntime>::InvalidSponsorshipTerms
+            );
+
+            let mut terms = context.terms();
+            terms.beneficiary_cap = ONE_AVT - 1;
+            assert_noop!(
+                AvnProxy::create_sponsorship(sponsor.clone(), terms),
+                Error::<TestRuntime>::InvalidSponsorshipTerms
+            );
+
+            let mut terms = context.terms();
+            terms.call_types = Default::default();
+            assert_noop!(
+                AvnProxy::create_sponsorship(sponsor, terms),
+                Error::<TestRuntime>::InvalidSponsorshipTerms
+            );
+        })
+    }
+
+    #[test]
+    fn update_sponsorship_tops_up_the_budget() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+
+            let mut terms = context.terms();
+            terms.budget = 50 * ONE_AVT;
+            assert_ok!(AvnProxy::update_sponsorship(
+                RuntimeOrigin::signed(context.sponsor.account_id()),
+                SPONSORSHIP_ID,
+                terms
+            ));
+
+            assert_eq!(context.remaining_budget(), 50 * ONE_AVT);
+            System::assert_last_event(
+                crate::Event::<TestRuntime>::SponsorshipUpdated {
+                    sponsorship_id: SPONSORSHIP_ID,
+                    budget: 50 * ONE_AVT,
+                }
+                .into(),
+            );
+        })
+    }
+
+    #[test]
+    fn only_the_sponsor_can_manage_a_sponsorship() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+            let other = RuntimeOrigin::signed(context.relayer.account_id());
+
+            assert_noop!(
+                AvnProxy::update_sponsorship(other.clone(), SPONSORSHIP_ID, context.terms()),
+                Error::<TestRuntime>::SenderIsNotSponsor
+            );
+            assert_noop!(
+                AvnProxy::remove_sponsored_beneficiaries(
+                    other.clone(),
+                    SPONSORSHIP_ID,
+                    vec![context.beneficiary.account_id()].try_into().unwrap()
+                ),
+                Error::<TestRuntime>::SenderIsNotSponsor
+            );
+            assert_noop!(
+                AvnProxy::close_sponsorship(other.clone(), SPONSORSHIP_ID),
+                Error::<TestRuntime>::SenderIsNotSponsor
+            );
+            assert_noop!(
+                AvnProxy::close_sponsorship(other, SPONSORSHIP_ID + 1),
+                Error::<TestRuntime>::SponsorshipNotFound
+            );
+        })
+    }
+
+    #[test]
+    fn beneficiaries_can_be_added_and_removed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+            let sponsor = RuntimeOrigin::signed(context.sponsor.account_id());
+            let new_beneficiary = TestAccount::new([21u8; 32]).account_id();
+
+            // Adding an existing beneficiary again does not change the count
+            assert_ok!(AvnProxy::add_sponsored_beneficiaries(
+                sponsor.clone(),
+                SPONSORSHIP_ID,
+                vec![context.beneficiary.account_id(), new_beneficiary].try_into().unwrap()
+            ));
+            assert_eq!(AvnProxy::sponsorships(SPONSORSHIP_ID).unwrap().beneficiary_count, 2);
+
+            assert_ok!(AvnProxy::remove_sponsored_beneficiaries(
+                sponsor,
+                SPONSORSHIP_ID,
+                vec![context.beneficiary.account_id()].try_into().unwrap()
+            ));
+            assert_eq!(AvnProxy::sponsorships(SPONSORSHIP_ID).unwrap().beneficiary_count, 1);
+            assert_eq!(
+                false,
+                AvnProxy::is_sponsored_beneficiary(
+                    SPONSORSHIP_ID,
+                    context.beneficiary.account_id()
+                )
+            );
+            assert!(AvnProxy::is_sponsored_beneficiary(SPONSORSHIP_ID, new_beneficiary));
+        })
+    }
+
+    #[test]
+    fn adding_too_many_beneficiaries_fails() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+
+            let max_beneficiaries = <TestRuntime as Config>::MaxSponsoredBeneficiaries::get();
+            let beneficiaries: Vec<AccountId> = (0..max_beneficiaries)
+                .map(|i| TestAccount::new([100u8 + i as u8; 32]).account_id())
+                .collect();
+
+            assert_noop!(
+                AvnProxy::add_sponsored_beneficiaries(
+                    RuntimeOrigin::signed(context.sponsor.account_id()),
+                    SPONSORSHIP_ID,
+                    beneficiaries.try_into().unwrap()
+                ),
+                Error::<TestRuntime>::TooManySponsoredBeneficiaries
+            );
+        })
+    }
+
+    #[test]
+    fn close_sponsorship_removes_all_beneficiaries() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+
+            assert_ok!(AvnProxy::close_sponsorship(
+                RuntimeOrigin::signed(context.sponsor.account_id()),
+                SPONSORSHIP_ID
+            ));
+
+            assert_eq!(AvnProxy::sponsorships(SPONSORSHIP_ID), None);
+            assert_eq!(
+                false,
+                AvnProxy::is_sponsored_beneficiary(
+                    SPONSORSHIP_ID,
+                    context.beneficiary.account_id()
+                )
+            );
+            System::assert_last_event(
+                crate::Event::<TestRuntime>::SponsorshipClosed { sponsorship_id: SPONSORSHIP_ID }
+                    .into(),
+            );
+        })
+    }
+}
+
+mod sponsored_proxy {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn call_is_covered_by_the_sponsorship() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: SponsorshipContext = Default::default();
+                context.setup();
+                let inner_call = ProxyContext::default().create_valid_inner_call();
+                let call_hash = Hashing::hash_of(&inner_call);
+
+                let beneficiary_balance = Balances::free_balance(context.beneficiary.account_id());
+                let sponsor_balance = Balances::free_balance(context.sponsor.account_id());
+                let relayer_balance = Balances::free_balance(context.relayer.account_id());
+
+                assert_ok!(context.sponsored_proxy(inner_call));
+
+                assert_eq!(true, proxy_event_emitted(context.relayer.account_id(), call_hash));
+                assert_eq!(true, sponsored_fee_paid_event_emitted(&context));
+
+                // The sponsor pays the relayer, the beneficiary pays nothing
+                assert_eq!(
+                    beneficiary_balance,
+                    Balances::free_balance(context.beneficiary.account_id())
+                );
+                assert_eq!(
+                    sponsor_balance - ONE_AVT,
+                    Balances::free_balance(context.sponsor.account_id())
+                );
+                assert_eq!(
+                    relayer_balance + ONE_AVT,
+                    Balances::free_balance(context.relayer.account_id())
+                );
+                assert_eq!(context.remaining_budget(), 9 * ONE_AVT);
+                assert_eq!(
+                    AvnProxy::beneficiary_spend(SPONSORSHIP_ID, context.beneficiary.account_id())
+                        .spent,
+                    ONE_AVT
+                );
+            })
+        }
+
+        #[test]
+        fn beneficiary_cap_resets_in_a_new_period() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: SponsorshipContext = Default::default();
+                context.setup();
+                let inner_call = ProxyContext::default().create_valid_inner_call();
+
+                assert_ok!(context.sponsored_proxy(inner_call.clone()));
+                assert_ok!(context.sponsored_proxy(inner_call.clone()));
+                assert_noop!(
+                    context.sponsored_proxy(inner_call.clone()),
+                    Error::<TestRuntime>::BeneficiaryCapReached
+                );
+
+                System::set_block_number(<TestRuntime as Config>::SponsorshipPeriod::get());
+                assert_ok!(context.sponsored_proxy(inner_call));
+                assert_eq!(context.remaining_budget(), 7 * ONE_AVT);
+            })
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn sender_is_not_a_sponsored_beneficiary() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: SponsorshipContext = Default::default();
+                context.setup();
+                assert_ok!(AvnProxy::remove_sponsored_beneficiaries(
+                    RuntimeOrigin::signed(context.sponsor.account_id()),
+                    SPONSORSHIP_ID,
+                    vec![context.beneficiary.account_id()].try_into().unwrap()
+                ));
+
+                assert_noop!(
+                    context.sponsored_proxy(ProxyContext::default().create_valid_inner_call()),
+                    Error::<TestRuntime>::NotASponsoredBeneficiary
+                );
+            })
+        }
+
+        #[test]
+        fn call_type_is_not_sponsored() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: SponsorshipContext = Default::default();
+                context.setup();
+                let inner_call = create_signed_mint_single_nft_call(&ProxyContext::default());
+
+                assert_noop!(
+                    context.sponsored_proxy(inner_call),
+                    Error::<TestRuntime>::CallTypeNotSponsored
+                );
+            })
+        }
+
+        #[test]
+        fn sponsorship_budget_is_exhausted() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: SponsorshipContext = Default::default();
+                context.setup();
+                let mut terms = context.terms();
+                terms.budget = ONE_AVT - 1;
+                assert_ok!(AvnProxy::update_sponsorship(
+                    RuntimeOrigin::signed(context.sponsor.account_id()),
+                    SPONSORSHIP_ID,
+                    terms
+                ));
+
+                assert_noop!(
+                    context.sponsored_proxy(ProxyContext::default().create_valid_inner_call()),
+                    Error::<TestRuntime>::SponsorshipBudgetExhausted
+                );
+            })
+        }
+
+        #[test]
+        fn sponsorship_does_not_exist() {
+            let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+            ext.execute_with(|| {
+                let context: SponsorshipContext = Default::default();
+
+                assert_noop!(
+                    context.sponsored_proxy(ProxyContext::default().create_valid_inner_call()),
+                    Error::<TestRuntime>::SponsorshipNotFound
+                );
+            })
+        }
+    }
+
+    #[test]
+    fn sponsor_is_not_charged_when_the_inner_call_fails() {
+        let mut ext = ExtBuilder::build_default().with_balances().as_externality();
+        ext.execute_with(|| {
+            let context: SponsorshipContext = Default::default();
+            context.setup();
+            let proxy_context: ProxyContext = Default::default();
+            let mut single_nft_data: SingleNftContext = Default::default();
+
+            // This will fail the call because unique_external_ref is mandatory
+            single_nft_data.unique_external_ref = String::from("").into_bytes();
+            let proxy_proof = get_mint_single_nft_proxy_proof(&proxy_context, &single_nft_data);
+            let inner_call = get_signed_mint_single_nft_call(&single_nft_data, &proxy_proof);
+            let call_hash = Hashing::hash_of(&inner_call);
+
+            let mut terms = context.terms();
+            terms.call_types.try_push(CallType::of(&inner_call).unwrap()).unwrap();
+            assert_ok!(AvnProxy::update_sponsorship(
+                RuntimeOrigin::signed(context.sponsor.account_id()),
+                SPONSORSHIP_ID,
+                terms
+            ));
+            let sponsor_balance = Balances::free_balance(context.sponsor.account_id());
+
+            assert_ok!(context.sponsored_proxy(inner_call));
+
+            assert_eq!(
+                true,
+                inner_call_failed_event_emitted(context.relayer.account_id(), call_hash)
+            );
+            assert_eq!(false, sponsored_fee_paid_event_emitted(&context));
+            assert_eq!(sponsor_balance, Balances::free_balance(context.sponsor.account_id()));
+            assert_eq!(context.remaining_budget(), 10 * ONE_AVT);
+        })
+    }
+}
@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::traits::MaybeDisplay;
+
+sp_api::decl_runtime_apis! {
+    pub trait AvnTransactionPaymentApi<Balance> where
+        Balance: Codec + MaybeDisplay,
+    {
+        /// Returns the fee the sender of `uxt` pays once its fee adjustments are applied. The tip
+        /// is not included.
+        fn query_adjusted_fee(uxt: Block::Extrinsic, len: u32) -> Balance;
+    }
+}
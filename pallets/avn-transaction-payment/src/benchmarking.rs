@@ -4,9 +4,12 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_system::{EventRecord, RawOrigin};
-use sp_runtime::traits::Bounded;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, RawOrigin};
+use orml_traits::asset_registry::{AssetMetadata, AvnAssetLocation, AvnAssetMetadata};
+use sp_core::H160;
+use sp_runtime::traits::{Bounded, One, Zero};
 
 use crate::Pallet as AvnTransactionPayment;
 
@@ -24,6 +27,21 @@ fn add_known_sender<T: Config>(known_sender: &T::AccountId) {
     <KnownSenders<T>>::insert(known_sender, adjustment_config);
 }
 
+fn register_fee_asset<T: Config>() -> CurrencyId {
+    let asset = Asset::ForeignAsset(1);
+    let metadata = AssetMetadata {
+        decimals: 18,
+        name: Default::default(),
+        symbol: Default::default(),
+        existential_deposit: Default::default(),
+        location: Some(AvnAssetLocation::Ethereum(H160::from([1u8; 20]))),
+        additional: AvnAssetMetadata { appchain_native: false },
+    };
+    T::AssetRegistry::register_asset(Some(asset), metadata).expect("Asset can be registered");
+
+    return asset
+}
+
 benchmarks! {
     set_known_sender {
         let known_sender: T::AccountId = account("known_sender", 1, 1);
@@ -60,6 +78,87 @@ benchmarks! {
             Event::<T>::KnownSenderRemoved{ known_sender }.into()
         );
     }
+
+    set_fee_asset_rate {
+        let asset = register_fee_asset::<T>();
+        let rate = FixedU128::from_rational(3, 2);
+        let origin = T::FeeAssetRateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        AvnTransactionPayment::<T>::set_fee_asset_rate(origin, asset, Some(rate))?;
+    }
+    verify {
+        assert_eq!(<FeeAssetRates<T>>::get(asset), Some(rate));
+        assert_last_event::<T>(Event::<T>::FeeAssetRateSet{ asset, rate }.into());
+    }
+
+    set_fee_asset {
+        let who: T::AccountId = account("who", 1, 1);
+        let asset = register_fee_asset::<T>();
+        <FeeAssetRates<T>>::insert(asset, FixedU128::from_rational(3, 2));
+    }: {
+        AvnTransactionPayment::<T>::set_fee_asset(RawOrigin::Signed(who.clone()).into(), Some(asset))?;
+    }
+    verify {
+        assert_eq!(<FeeAssets<T>>::get(&who), Some(asset));
+        assert_last_event::<T>(Event::<T>::FeeAssetSet{ who, asset: Some(asset) }.into());
+    }
+
+    set_call_fee_rule {
+        let call_type = CallType { pallet_index: 1, call_index: 1 };
+        let target = FeeRuleTarget::Sender(account("sender", 1, 1));
+        let fee_type = FeeType::PercentageFee(PercentageFeeConfig { percentage: 100, _marker: Default::default() });
+        <CallFeeRules<T>>::insert(call_type, &target, fee_type);
+    }: {
+        AvnTransactionPayment::<T>::set_call_fee_rule(RawOrigin::Root.into(), call_type, target.clone(), None)?;
+    }
+    verify {
+        assert_eq!(<CallFeeRules<T>>::contains_key(call_type, &target), false);
+        assert_last_event::<T>(Event::<T>::CallFeeRuleRemoved{ call_type, target }.into());
+    }
+
+    set_volume_tiers {
+        let tiers: BoundedVec<VolumeTier, T::MaxVolumeTiers> = (1..=T::MaxVolumeTiers::get())
+            .map(|i| VolumeTier { threshold: i * 10, percentage: i.min(100) })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("Tiers are bounded");
+    }: {
+        AvnTransactionPayment::<T>::set_volume_tiers(RawOrigin::Root.into(), tiers.clone())?;
+    }
+    verify {
+        assert_eq!(<VolumeTiers<T>>::get(), tiers);
+        assert_last_event::<T>(Event::<T>::VolumeTiersSet{ tiers }.into());
+    }
+
+    record_transaction {
+        let who: T::AccountId = account("who", 1, 1);
+        let tiers: BoundedVec<VolumeTier, T::MaxVolumeTiers> =
+            vec![VolumeTier { threshold: 1, percentage: 10 }].try_into().expect("Tiers are bounded");
+        <VolumeTiers<T>>::put(tiers);
+        <TransactionVolume<T>>::insert(BlockNumberFor::<T>::zero(), &who, 1);
+    }: {
+        AvnTransactionPayment::<T>::record_transaction(&who);
+    }
+    verify {
+        assert_eq!(AvnTransactionPayment::<T>::transaction_volume_of(&who), 2);
+    }
+
+    prune_transaction_volumes {
+        let n in 0 .. fee_rules::MAX_PRUNED_VOLUMES_PER_BLOCK;
+        for i in 0..n {
+            let who: T::AccountId = account("who", i, i);
+            <TransactionVolume<T>>::insert(BlockNumberFor::<T>::zero(), &who, 1);
+        }
+        frame_system::Pallet::<T>::set_block_number(T::VolumeTierPeriod::get());
+        let mut meter = WeightMeter::new();
+    }: {
+        AvnTransactionPayment::<T>::prune_transaction_volumes(&mut meter);
+    }
+    verify {
+        assert_eq!(<TransactionVolume<T>>::iter_prefix(BlockNumberFor::<T>::zero()).count(), 0);
+        assert_eq!(<OldestVolumePeriod<T>>::get(), One::one());
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime,);
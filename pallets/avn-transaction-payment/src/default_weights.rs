@@ -38,6 +38,12 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_known_sender() -> Weight;
 	fn remove_known_sender() -> Weight;
+	fn set_fee_asset_rate() -> Weight;
+	fn set_fee_asset() -> Weight;
+	fn set_call_fee_rule() -> Weight;
+	fn set_volume_tiers() -> Weight;
+	fn record_transaction() -> Weight;
+	fn prune_transaction_volumes(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_avn_transaction_payment using the Substrate node and recommended hardware.
@@ -65,6 +71,79 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRegistry::Metadata` (r:1 w:0)
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:0 w:1)
+	fn set_fee_asset_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `210`
+		//  Estimated: `6163`
+		// Minimum execution time: 21_157_000 picoseconds.
+		Weight::from_parts(21_480_000, 6163)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:1 w:0)
+	/// Storage: `AvnTransactionPayment::FeeAssets` (r:0 w:1)
+	fn set_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3514`
+		// Minimum execution time: 17_661_000 picoseconds.
+		Weight::from_parts(17_930_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::CallFeeRules` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::CallFeeRules` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn set_call_fee_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112`
+		//  Estimated: `3549`
+		// Minimum execution time: 18_120_000 picoseconds.
+		Weight::from_parts(18_640_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::VolumeTiers` (r:0 w:1)
+	fn set_volume_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_041_000 picoseconds.
+		Weight::from_parts(11_210_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::VolumeTiers` (r:1 w:0)
+	/// Proof: `AvnTransactionPayment::VolumeTiers` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// Storage: `AvnTransactionPayment::TransactionVolume` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::TransactionVolume` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn record_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `3537`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_230_000, 3537)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::OldestVolumePeriod` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::OldestVolumePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnTransactionPayment::TransactionVolume` (r:0 w:100)
+	/// Proof: `AvnTransactionPayment::TransactionVolume` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn prune_transaction_volumes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `73 + n * (58 ±0)`
+		//  Estimated: `1489 + n * (2547 ±0)`
+		// Minimum execution time: 6_130_000 picoseconds.
+		Weight::from_parts(6_420_000, 1489)
+			// Standard Error: 2_051
+			.saturating_add(Weight::from_parts(1_184_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -91,4 +170,77 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRegistry::Metadata` (r:1 w:0)
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:0 w:1)
+	fn set_fee_asset_rate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `210`
+		//  Estimated: `6163`
+		// Minimum execution time: 21_157_000 picoseconds.
+		Weight::from_parts(21_480_000, 6163)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::FeeAssetRates` (r:1 w:0)
+	/// Storage: `AvnTransactionPayment::FeeAssets` (r:0 w:1)
+	fn set_fee_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3514`
+		// Minimum execution time: 17_661_000 picoseconds.
+		Weight::from_parts(17_930_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::CallFeeRules` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::CallFeeRules` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn set_call_fee_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112`
+		//  Estimated: `3549`
+		// Minimum execution time: 18_120_000 picoseconds.
+		Weight::from_parts(18_640_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::VolumeTiers` (r:0 w:1)
+	fn set_volume_tiers() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_041_000 picoseconds.
+		Weight::from_parts(11_210_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::VolumeTiers` (r:1 w:0)
+	/// Proof: `AvnTransactionPayment::VolumeTiers` (`max_values`: Some(1), `max_size`: Some(81), added: 576, mode: `MaxEncodedLen`)
+	/// Storage: `AvnTransactionPayment::TransactionVolume` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::TransactionVolume` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn record_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `3537`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_230_000, 3537)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AvnTransactionPayment::OldestVolumePeriod` (r:1 w:1)
+	/// Proof: `AvnTransactionPayment::OldestVolumePeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AvnTransactionPayment::TransactionVolume` (r:0 w:100)
+	/// Proof: `AvnTransactionPayment::TransactionVolume` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn prune_transaction_volumes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `73 + n * (58 ±0)`
+		//  Estimated: `1489 + n * (2547 ±0)`
+		// Minimum execution time: 6_130_000 picoseconds.
+		Weight::from_parts(6_420_000, 1489)
+			// Standard Error: 2_051
+			.saturating_add(Weight::from_parts(1_184_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2547).saturating_mul(n.into()))
+	}
 }
\ No newline at end of file
@@ -376,7 +376,7 @@ impl<T: Config> Debug for AdjustmentInput<T> {
     }
 }
 
-fn calculate_fee<T: Config>(
+pub(crate) fn calculate_fee<T: Config>(
     original_fee: BalanceOf<T>,
     fee_type: &FeeType<T>,
 ) -> Result<BalanceOf<T>, Error<T>> {
@@ -0,0 +1,288 @@
+//! Accounts can pay transaction fees in a registered asset other than AVT. A signed transaction
+//! chooses its fee asset with the `ChargeFeeAsset` extension, so an account holding no AVT can pay
+//! for its first transaction. Transactions that don't choose one pay with the asset nominated using
+//! `set_fee_asset`, or in AVT. The AVT fee is converted with the rate set for the asset by
+//! `FeeAssetRateOrigin`, withdrawn through `AssetManager` and the part of the fee that is not used
+//! is refunded in the same asset after dispatch.
+
+use crate::*;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::{
+    dispatch::DispatchInfo,
+    pallet_prelude::{TransactionSource, Weight},
+    traits::{ExistenceRequirement, Get},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{
+        AsSystemOriginSigner, DispatchOriginOf, Implication, TransactionExtension, ValidateResult,
+    },
+    transaction_validity::ValidTransaction,
+    FixedPointNumber, FixedPointOperand, FixedU128,
+};
+
+/// What has been withdrawn from the payer to pay for a transaction
+pub enum FeePayment<NativeLiquidity, Balance> {
+    /// The fee has been paid in AVT
+    Native(NativeLiquidity),
+    /// The fee has been paid in `asset`. `avt_fee` is the AVT fee that was converted with `rate`.
+    Asset {
+        asset: CurrencyId,
+        rate: FixedU128,
+        avt_fee: Balance,
+        paid: Balance,
+        call_type: Option<CallType>,
+    },
+}
+
+impl<NativeLiquidity: Default, Balance> Default for FeePayment<NativeLiquidity, Balance> {
+    fn default() -> Self {
+        FeePayment::Native(Default::default())
+    }
+}
+
+/// Converts an AVT amount to an amount of the fee asset. The rate is the number of units of the
+/// asset that are worth one unit of AVT.
+pub fn convert_fee<Balance: FixedPointOperand>(rate: FixedU128, avt_fee: Balance) -> Balance {
+    return rate.saturating_mul_int(avt_fee)
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the asset `who` pays fees with, and its rate, if the transaction being applied
+    /// chose a supported asset or `who` nominated one
+    pub fn fee_asset_for(who: &T::AccountId) -> Option<(CurrencyId, FixedU128)> {
+        let asset = <TransactionFeeAsset<T>>::get().or_else(|| <FeeAssets<T>>::get(who))?;
+        let rate = <FeeAssetRates<T>>::get(asset)?;
+        return Some((asset, rate))
+    }
+}
+
+/// Chooses the asset a signed transaction pays its fee with. `None` keeps the asset nominated by
+/// the sender. It must come before `ChargeTransactionPayment` in the transaction extensions, which
+/// charges the fee through `MultiAssetFeeAdapter`.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFeeAsset<T: Config>(pub Option<CurrencyId>, PhantomData<T>);
+
+impl<T: Config> ChargeFeeAsset<T> {
+    pub fn new(asset: Option<CurrencyId>) -> Self {
+        Self(asset, PhantomData)
+    }
+}
+
+impl<T: Config> From<Option<CurrencyId>> for ChargeFeeAsset<T> {
+    fn from(asset: Option<CurrencyId>) -> Self {
+        Self::new(asset)
+    }
+}
+
+impl<T: Config> core::fmt::Debug for ChargeFeeAsset<T> {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ChargeFeeAsset({:?})", self.0)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+    for ChargeFeeAsset<T>
+where
+    <T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+    DispatchOriginOf<<T as frame_system::Config>::RuntimeCall>:
+        AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    const IDENTIFIER: &'static str = "ChargeFeeAsset";
+    type Implicit = ();
+    type Val = bool;
+    type Pre = bool;
+
+    fn weight(&self, _call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+        match self.0 {
+            // Read the rate, record the asset and remove it after dispatch
+            Some(_) => T::DbWeight::get().reads_writes(1, 2),
+            None => Weight::zero(),
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: DispatchOriginOf<<T as frame_system::Config>::RuntimeCall>,
+        _call: &<T as frame_system::Config>::RuntimeCall,
+        _info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Implication,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
+        let asset = match self.0 {
+            Some(asset) if origin.as_system_origin_signer().is_some() => asset,
+            _ => return Ok((ValidTransaction::default(), false, origin)),
+        };
+
+        if !<FeeAssetRates<T>>::contains_key(asset) {
+            return Err(InvalidTransaction::Payment.into())
+        }
+
+        // Recorded before `ChargeTransactionPayment` checks and withdraws the fee
+        <TransactionFeeAsset<T>>::put(asset);
+        Ok((ValidTransaction::default(), true, origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &DispatchOriginOf<<T as frame_system::Config>::RuntimeCall>,
+        _call: &<T as frame_system::Config>::RuntimeCall,
+        _info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        Ok(val)
+    }
+
+    fn post_dispatch_details(
+        asset_chosen: Self::Pre,
+        _info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        _post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        _len: usize,
+        _result: &DispatchResult,
+    ) -> Result<Weight, TransactionValidityError> {
+        if asset_chosen {
+            <TransactionFeeAsset<T>>::kill();
+        }
+        Ok(Weight::zero())
+    }
+}
+
+/// Charges fees in the asset nominated by the payer, or in AVT using `AvnGasFeeAdapter` if the
+/// payer did not nominate a supported asset.
+pub struct MultiAssetFeeAdapter<F, OU>(PhantomData<(F, OU)>);
+
+impl<T, F, OU> OnChargeTransaction<T> for MultiAssetFeeAdapter<F, OU>
+where
+    T: Config + pallet::Config<Currency = F>,
+    F: Balanced<T::AccountId>,
+    OU: OnUnbalanced<Credit<T::AccountId, F>>,
+{
+    type LiquidityInfo = FeePayment<ChargedFee<Option<Credit<T::AccountId, F>>>, Self::Balance>;
+    type Balance = <F as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    fn withdraw_fee(
+        who: &<T as frame_system::Config>::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+        info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        if let Some((asset, rate)) = Pallet::<T>::fee_asset_for(who) {
+            if fee.is_zero() {
+                return Ok(FeePayment::Native(ChargedFee {
+                    liquidity: None,
+                    call_type: CallType::of(call),
+                }))
+            }
+
+            let asset_fee = convert_fee(rate, fee);
+            T::AssetManager::withdraw(asset, who, asset_fee, ExistenceRequirement::KeepAlive)
+                .map_err(|_| InvalidTransaction::Payment)?;
+
+            return Ok(FeePayment::Asset {
+                asset,
+                rate,
+                avt_fee: fee,
+                paid: asset_fee,
+                call_type: CallType::of(call),
+            })
+        }
+
+        return AvnGasFeeAdapter::<F, OU>::withdraw_fee(who, call, info, fee, tip)
+            .map(FeePayment::Native)
+    }
+
+    fn can_withdraw_fee(
+        who: &<T as frame_system::Config>::AccountId,
+        call: &<T as frame_system::Config>::RuntimeCall,
+        dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        fee: Self::Balance,
+        tip: Self::Balance,
+    ) -> Result<(), TransactionValidityError> {
+        if let Some((asset, rate)) = Pallet::<T>::fee_asset_for(who) {
+            if fee.is_zero() {
+                return Ok(())
+            }
+
+            return T::AssetManager::ensure_can_withdraw(asset, who, convert_fee(rate, fee))
+                .map_err(|_| InvalidTransaction::Payment.into())
+        }
+
+        return AvnGasFeeAdapter::<F, OU>::can_withdraw_fee(who, call, dispatch_info, fee, tip)
+    }
+
+    /// Refunds the unused part of the fee in the asset it was paid with and pays the rest to
+    /// `AssetFeeRecipient`. Fee adjustments are applied to the AVT fee before it is
+    /// converted.
+    fn correct_and_deposit_fee(
+        who: &<T as frame_system::Config>::AccountId,
+        dispatch_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        post_info: &PostDispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+        corrected_fee: Self::Balance,
+        tip: Self::Balance,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Result<(), TransactionValidityError> {
+        let (asset, rate, avt_fee, paid, call_type) = match already_withdrawn {
+            FeePayment::Native(paid) =>
+                return AvnGasFeeAdapter::<F, OU>::correct_and_deposit_fee(
+                    who,
+                    dispatch_info,
+                    post_info,
+                    corrected_fee,
+                    tip,
+                    paid,
+                ),
+            FeePayment::Asset { asset, rate, avt_fee, paid, call_type } =>
+                (asset, rate, avt_fee, paid, call_type),
+        };
+
+        let (_, avt_refund) =
+            Pallet::<T>::calculate_refund_amount(who, &avt_fee, corrected_fee, tip, call_type);
+        Pallet::<T>::record_transaction(who);
+        let fee = convert_fee(rate, avt_fee.saturating_sub(avt_refund)).min(paid);
+
+        let refund = paid.saturating_sub(fee);
+        if refund > Zero::zero() {
+            if let Err(e) = T::AssetManager::deposit(asset, who, refund) {
+                log::error!(
+                    "💔 Failed to refund {:?} of asset {:?} to {:?}: {:?}",
+                    refund,
+                    asset,
+                    who,
+                    e
+                );
+            }
+        }
+
+        if let Err(e) = T::AssetManager::deposit(asset, &T::AssetFeeRecipient::get(), fee) {
+            log::error!("💔 Failed to deposit a fee of {:?} in asset {:?}: {:?}", fee, asset, e);
+        }
+
+        Pallet::<T>::deposit_event(Event::<T>::AssetTransactionFeePaid {
+            who: who.clone(),
+            asset,
+            fee,
+        });
+
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn endow_account(_who: &T::AccountId, _amount: Self::Balance) {
+        unimplemented!()
+    }
+    #[cfg(feature = "runtime-benchmarks")]
+    fn minimum_balance() -> Self::Balance {
+        unimplemented!()
+    }
+}
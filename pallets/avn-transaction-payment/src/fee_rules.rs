@@ -0,0 +1,211 @@
+//! Fee rules that do not depend on a known sender config:
+//!  - call fee rules adjust the fee of a specific call, for every sender or for a single sender.
+//!    When a call fee rule matches, it is used instead of the known sender config.
+//!  - volume tiers reduce the fee of an account once it has sent a number of transactions in the
+//!    current `VolumeTierPeriod`. The discount is applied on top of any other adjustment. The
+//!    transaction volumes of past periods are removed with leftover block weight.
+
+use crate::*;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{dispatch::DispatchClass, traits::Get, weights::WeightMeter, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{scale_info::TypeInfo, traits::One, Perbill, RuntimeDebug};
+
+/// The maximum number of transaction volumes removed in a block
+pub const MAX_PRUNED_VOLUMES_PER_BLOCK: u32 = 100;
+
+/// The senders a call fee rule applies to
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub enum FeeRuleTarget<AccountId> {
+    AllSenders,
+    Sender(AccountId),
+}
+
+/// Once an account has sent `threshold` transactions in a period, the fee of its following
+/// transactions is reduced by `percentage`
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct VolumeTier {
+    pub threshold: u32,
+    pub percentage: u32,
+}
+
+/// What has been withdrawn to pay for a transaction, and the type of the call it paid for
+#[derive(Default)]
+pub struct ChargedFee<Liquidity> {
+    pub liquidity: Liquidity,
+    pub call_type: Option<CallType>,
+}
+
+pub fn is_valid_fee_rule<T: Config>(fee_type: &FeeType<T>) -> bool {
+    return match fee_type {
+        FeeType::FixedFee(_) => true,
+        FeeType::PercentageFee(p) => p.is_valid(),
+        FeeType::None => false,
+    }
+}
+
+/// Tiers must have strictly increasing thresholds and a percentage between 1 and 100
+pub fn validate_volume_tiers<T: Config>(tiers: &BoundedVec<VolumeTier, T::MaxVolumeTiers>) -> bool {
+    let valid_percentages =
+        tiers.iter().all(|tier| tier.percentage > 0 && tier.percentage <= 100u32);
+    let increasing_thresholds = tiers.windows(2).all(|pair| pair[0].threshold < pair[1].threshold);
+
+    return valid_percentages && increasing_thresholds
+}
+
+impl<T: Config> Pallet<T> {
+    /// A rule set for `who` takes precedence over a rule set for all senders
+    pub fn call_fee_rule_for(who: &T::AccountId, call_type: CallType) -> Option<FeeType<T>> {
+        return <CallFeeRules<T>>::get(call_type, FeeRuleTarget::Sender(who.clone()))
+            .or_else(|| <CallFeeRules<T>>::get(call_type, FeeRuleTarget::AllSenders))
+    }
+
+    fn current_volume_period() -> BlockNumberFor<T> {
+        let period_length = T::VolumeTierPeriod::get().max(1u32.into());
+        return <frame_system::Pallet<T>>::block_number() / period_length
+    }
+
+    /// The number of transactions `who` has sent in the current period
+    pub fn transaction_volume_of(who: &T::AccountId) -> u32 {
+        return <TransactionVolume<T>>::get(Self::current_volume_period(), who)
+    }
+
+    /// The discount of the highest volume tier reached by `who`
+    pub fn volume_discount(who: &T::AccountId) -> Option<u32> {
+        let tiers = <VolumeTiers<T>>::get();
+        if tiers.is_empty() {
+            return None
+        }
+
+        let volume = Self::transaction_volume_of(who);
+        return tiers
+            .iter()
+            .rev()
+            .find(|tier| volume >= tier.threshold)
+            .map(|tier| tier.percentage)
+    }
+
+    /// Counts a fee paying transaction of `who` towards its volume tier. Nothing is recorded if
+    /// there are no volume tiers.
+    pub fn record_transaction(who: &T::AccountId) {
+        // Fees are settled outside of the call, so its weight doesn't cover this
+        <frame_system::Pallet<T>>::register_extra_weight_unchecked(
+            <T as Config>::WeightInfo::record_transaction(),
+            DispatchClass::Mandatory,
+        );
+
+        if <VolumeTiers<T>>::decode_len().unwrap_or_default() == 0 {
+            return
+        }
+
+        <TransactionVolume<T>>::mutate(Self::current_volume_period(), who, |count| {
+            *count = count.saturating_add(1)
+        });
+    }
+
+    /// Removes the transaction volumes of the oldest past period, if `meter` has enough weight
+    /// left
+    pub fn prune_transaction_volumes(meter: &mut WeightMeter) {
+        let max_weight =
+            <T as Config>::WeightInfo::prune_transaction_volumes(MAX_PRUNED_VOLUMES_PER_BLOCK);
+        if !meter.can_consume(max_weight) {
+            return
+        }
+
+        let oldest_period = <OldestVolumePeriod<T>>::get();
+        if oldest_period >= Self::current_volume_period() {
+            meter.consume(T::DbWeight::get().reads(1));
+            return
+        }
+
+        let result =
+            <TransactionVolume<T>>::clear_prefix(oldest_period, MAX_PRUNED_VOLUMES_PER_BLOCK, None);
+        if result.maybe_cursor.is_none() {
+            <OldestVolumePeriod<T>>::put(oldest_period + One::one());
+        }
+
+        meter.consume(<T as Config>::WeightInfo::prune_transaction_volumes(result.loops));
+    }
+
+    /// Applies the fee rules of `fee_payer` to a `fee` that does not include the tip. Returns
+    /// whether an adjustment has been applied, and the adjusted fee.
+    pub fn adjusted_fee(
+        fee_payer: &T::AccountId,
+        call_type: Option<CallType>,
+        fee: BalanceOf<T>,
+    ) -> (bool, BalanceOf<T>) {
+        let mut has_adjustment = false;
+        let mut adjusted_fee = fee;
+
+        match call_type.and_then(|call_type| Self::call_fee_rule_for(fee_payer, call_type)) {
+            Some(fee_type) => {
+                has_adjustment = true;
+                match calculate_fee::<T>(fee, &fee_type) {
+                    Ok(rule_fee) => adjusted_fee = rule_fee,
+                    Err(e) => log::error!(
+                        "💔 Failed to apply a call fee rule for: {:?}, call type: {:?}, fee type: {:?}, error: {:?}",
+                        fee_payer,
+                        call_type,
+                        fee_type,
+                        e
+                    ),
+                }
+            },
+            None => {
+                let fee_adjustment_config = <KnownSenders<T>>::get(fee_payer);
+                if fee_adjustment_config.is_active() {
+                    has_adjustment = true;
+                    match fee_adjustment_config.get_fee(fee) {
+                        Ok(known_sender_fee) => adjusted_fee = known_sender_fee,
+                        Err(e) => log::error!(
+                            "💔 Failed to apply an adjustment for known sender: {:?}, adjustment config: {:?}, error: {:?}",
+                            fee_payer,
+                            fee_adjustment_config,
+                            e
+                        ),
+                    }
+                }
+            },
+        }
+
+        if let Some(percentage) = Self::volume_discount(fee_payer) {
+            has_adjustment = true;
+            adjusted_fee =
+                adjusted_fee.saturating_sub(Perbill::from_percent(percentage) * adjusted_fee);
+        }
+
+        return (has_adjustment, adjusted_fee)
+    }
+
+    /// Previews the fee `who` pays for `call`, given the `fee` computed by transaction payment
+    /// without the tip. Refunds never charge more than the withdrawn fee, so neither does the
+    /// preview when a fixed fee is higher.
+    pub fn preview_adjusted_fee<Call: Encode>(
+        who: &T::AccountId,
+        call: &Call,
+        fee: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        return Self::adjusted_fee(who, CallType::of(call), fee).1.min(fee)
+    }
+}
@@ -11,17 +11,28 @@ use frame_support::{
     traits::{
         fungible::{Balanced, Credit, Debt, Inspect},
         tokens::{Precision, WithdrawConsequence},
-        Imbalance, OnUnbalanced,
+        Imbalance, OnUnbalanced, TypedGet,
     },
     unsigned::TransactionValidityError,
+    weights::WeightMeter,
 };
 use frame_system::{self as system};
+use orml_traits::{
+    asset_registry::{
+        AvnAssetLocation, AvnAssetMetadata, Inspect as AssetRegistryInspect,
+        Mutate as AssetRegistryMutate,
+    },
+    MultiCurrency,
+};
+pub use sp_avn_common::CallType;
+use sp_avn_common::{primitives::CurrencyId, Asset};
 
 use core::convert::TryInto;
 pub use pallet::*;
 use sp_runtime::{
     traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, Saturating, Zero},
     transaction_validity::InvalidTransaction,
+    FixedU128,
 };
 
 use pallet_transaction_payment::OnChargeTransaction;
@@ -33,6 +44,12 @@ use fee_adjustment_config::{
     *,
 };
 
+pub mod fee_asset;
+pub use fee_asset::{ChargeFeeAsset, FeePayment, MultiAssetFeeAdapter};
+
+pub mod fee_rules;
+pub use fee_rules::{ChargedFee, FeeRuleTarget, VolumeTier};
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -59,11 +76,56 @@ pub mod pallet {
         type KnownUserOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         type WeightInfo: WeightInfo;
+
+        /// Manages the registered assets that fees can be paid with
+        type AssetManager: MultiCurrency<
+            Self::AccountId,
+            CurrencyId = CurrencyId,
+            Balance = BalanceOf<Self>,
+        >;
+
+        /// String size limit of the asset registry
+        #[pallet::constant]
+        type AssetRegistryStringLimit: Get<u32>;
+
+        /// Provides information about registered assets
+        type AssetRegistry: AssetRegistryMutate<
+            AvnAssetLocation,
+            AssetId = CurrencyId,
+            Balance = BalanceOf<Self>,
+            CustomMetadata = AvnAssetMetadata,
+            StringLimit = Self::AssetRegistryStringLimit,
+        >;
+
+        /// The origin that is allowed to set the conversion rate of fee assets. This can be
+        /// governance or an origin fed by a price oracle.
+        type FeeAssetRateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The account that receives the fees paid in assets other than AVT
+        type AssetFeeRecipient: TypedGet<Type = Self::AccountId>;
+
+        /// The maximum number of volume tiers
+        #[pallet::constant]
+        type MaxVolumeTiers: Get<u32>;
+
+        /// The number of blocks over which transactions are counted towards a volume tier
+        #[pallet::constant]
+        type VolumeTierPeriod: Get<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Removes the transaction volumes of past periods with leftover block weight
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            Self::prune_transaction_volumes(&mut meter);
+            meter.consumed()
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub fn deposit_event)]
     pub enum Event<T: Config> {
@@ -86,6 +148,41 @@ pub mod pallet {
             who: T::AccountId,
             fee: BalanceOf<T>,
         },
+        /// Fees can be paid in `asset`, converting AVT fees with `rate`
+        FeeAssetRateSet {
+            asset: CurrencyId,
+            rate: FixedU128,
+        },
+        /// Fees can no longer be paid in `asset`
+        FeeAssetRateRemoved {
+            asset: CurrencyId,
+        },
+        /// `who` has nominated the asset it pays fees with. `None` means AVT.
+        FeeAssetSet {
+            who: T::AccountId,
+            asset: Option<CurrencyId>,
+        },
+        /// A transaction fee of `fee` has been paid in `asset` by `who`
+        AssetTransactionFeePaid {
+            who: T::AccountId,
+            asset: CurrencyId,
+            fee: BalanceOf<T>,
+        },
+        /// The fee of `call_type` sent by `target` is adjusted with `fee_type`
+        CallFeeRuleSet {
+            call_type: CallType,
+            target: FeeRuleTarget<T::AccountId>,
+            fee_type: FeeType<T>,
+        },
+        /// The fee rule of `call_type` sent by `target` has been removed
+        CallFeeRuleRemoved {
+            call_type: CallType,
+            target: FeeRuleTarget<T::AccountId>,
+        },
+        /// The volume tiers have been replaced
+        VolumeTiersSet {
+            tiers: BoundedVec<VolumeTier, T::MaxVolumeTiers>,
+        },
     }
 
     #[pallet::error]
@@ -94,6 +191,14 @@ pub mod pallet {
         InvalidFeeType,
         KnownSenderMustMatchAccount,
         KnownSenderMissing,
+        /// AVT cannot be set as a fee asset because it is always supported
+        InvalidFeeAsset,
+        InvalidFeeAssetRate,
+        FeeAssetNotRegistered,
+        FeeAssetNotSupported,
+        InvalidCallFeeRule,
+        CallFeeRuleMissing,
+        InvalidVolumeTiers,
     }
 
     #[pallet::storage]
@@ -102,6 +207,60 @@ pub mod pallet {
     pub type KnownSenders<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, FeeAdjustmentConfig<T>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn fee_asset_rates)]
+    /// The number of units of an asset that are worth one unit of AVT, for the assets fees can be
+    /// paid with
+    pub type FeeAssetRates<T: Config> =
+        StorageMap<_, Blake2_128Concat, CurrencyId, FixedU128, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn fee_assets)]
+    /// The asset each account pays its transaction fees with, if it is not AVT
+    pub type FeeAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CurrencyId, OptionQuery>;
+
+    #[pallet::storage]
+    /// The asset the transaction being applied pays its fee with, as chosen with
+    /// `ChargeFeeAsset`. It is removed once the transaction has been dispatched.
+    pub type TransactionFeeAsset<T: Config> = StorageValue<_, CurrencyId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn call_fee_rules)]
+    /// Fee adjustments applied to a call, for all senders or for a single sender
+    pub type CallFeeRules<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CallType,
+        Blake2_128Concat,
+        FeeRuleTarget<T::AccountId>,
+        FeeType<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn volume_tiers)]
+    /// Discounts applied once an account has sent a number of transactions in a period, ordered
+    /// by threshold
+    pub type VolumeTiers<T: Config> =
+        StorageValue<_, BoundedVec<VolumeTier, T::MaxVolumeTiers>, ValueQuery>;
+
+    #[pallet::storage]
+    /// The number of transactions each account has sent in a volume tier period
+    pub type TransactionVolume<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    /// The oldest volume tier period that may still have transaction volumes to remove
+    pub type OldestVolumePeriod<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -181,6 +340,118 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set the rate used to convert AVT fees to `asset`, or stop accepting `asset` for fees if
+        /// `rate` is `None`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_asset_rate())]
+        pub fn set_fee_asset_rate(
+            origin: OriginFor<T>,
+            asset: CurrencyId,
+            rate: Option<FixedU128>,
+        ) -> DispatchResult {
+            T::FeeAssetRateOrigin::ensure_origin(origin)?;
+            ensure!(asset != Asset::Avt, Error::<T>::InvalidFeeAsset);
+
+            match rate {
+                Some(rate) => {
+                    ensure!(rate.is_zero() == false, Error::<T>::InvalidFeeAssetRate);
+                    ensure!(
+                        T::AssetRegistry::metadata(&asset).is_some(),
+                        Error::<T>::FeeAssetNotRegistered
+                    );
+
+                    <FeeAssetRates<T>>::insert(asset, rate);
+                    Self::deposit_event(Event::<T>::FeeAssetRateSet { asset, rate });
+                },
+                None => {
+                    ensure!(
+                        <FeeAssetRates<T>>::contains_key(asset),
+                        Error::<T>::FeeAssetNotSupported
+                    );
+
+                    <FeeAssetRates<T>>::remove(asset);
+                    Self::deposit_event(Event::<T>::FeeAssetRateRemoved { asset });
+                },
+            }
+
+            Ok(())
+        }
+
+        /// Nominate the asset the transaction fees of the sender are paid with when a transaction
+        /// does not choose one with `ChargeFeeAsset`. `None` pays fees in AVT. If the rate of the
+        /// asset is removed, fees are paid in AVT.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_fee_asset())]
+        pub fn set_fee_asset(origin: OriginFor<T>, asset: Option<CurrencyId>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            match asset {
+                Some(asset) => {
+                    ensure!(
+                        <FeeAssetRates<T>>::contains_key(asset),
+                        Error::<T>::FeeAssetNotSupported
+                    );
+                    <FeeAssets<T>>::insert(&who, asset);
+                },
+                None => <FeeAssets<T>>::remove(&who),
+            }
+
+            Self::deposit_event(Event::<T>::FeeAssetSet { who, asset });
+
+            Ok(())
+        }
+
+        /// Set the fee rule of `call_type` for `target`, or remove it if `fee_type` is `None`
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_call_fee_rule())]
+        pub fn set_call_fee_rule(
+            origin: OriginFor<T>,
+            call_type: CallType,
+            target: FeeRuleTarget<T::AccountId>,
+            fee_type: Option<FeeType<T>>,
+        ) -> DispatchResult {
+            T::KnownUserOrigin::ensure_origin(origin)?;
+
+            match fee_type {
+                Some(fee_type) => {
+                    ensure!(
+                        fee_rules::is_valid_fee_rule::<T>(&fee_type),
+                        Error::<T>::InvalidCallFeeRule
+                    );
+
+                    <CallFeeRules<T>>::insert(call_type, &target, fee_type);
+                    Self::deposit_event(Event::<T>::CallFeeRuleSet { call_type, target, fee_type });
+                },
+                None => {
+                    ensure!(
+                        <CallFeeRules<T>>::contains_key(call_type, &target),
+                        Error::<T>::CallFeeRuleMissing
+                    );
+
+                    <CallFeeRules<T>>::remove(call_type, &target);
+                    Self::deposit_event(Event::<T>::CallFeeRuleRemoved { call_type, target });
+                },
+            }
+
+            Ok(())
+        }
+
+        /// Replace the volume tiers. An empty list disables volume discounts.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_volume_tiers())]
+        pub fn set_volume_tiers(
+            origin: OriginFor<T>,
+            tiers: BoundedVec<VolumeTier, T::MaxVolumeTiers>,
+        ) -> DispatchResult {
+            T::KnownUserOrigin::ensure_origin(origin)?;
+            ensure!(fee_rules::validate_volume_tiers::<T>(&tiers), Error::<T>::InvalidVolumeTiers);
+
+            <VolumeTiers<T>>::put(&tiers);
+            Self::deposit_event(Event::<T>::VolumeTiersSet { tiers });
+
+            Ok(())
+        }
     }
 }
 
@@ -193,30 +464,19 @@ impl<T: Config> Pallet<T> {
         amount_paid: &BalanceOf<T>,
         corrected_fee: BalanceOf<T>,
         tip: BalanceOf<T>,
+        call_type: Option<CallType>,
     ) -> (bool, BalanceOf<T>) {
         // Calculate how much refund we should return
-        let fee_adjustment_config = <KnownSenders<T>>::get(fee_payer);
-        // calling is_active does some computation so cache it here
-        let has_active_config = fee_adjustment_config.is_active();
+        let (has_adjustment, network_fee) =
+            Self::adjusted_fee(fee_payer, call_type, corrected_fee.saturating_sub(tip));
 
         let mut fee_to_pay = corrected_fee.clone();
-        if has_active_config {
-            let network_fee_only = corrected_fee.saturating_sub(tip);
-            match fee_adjustment_config.get_fee(network_fee_only) {
-                Ok(fee) => fee_to_pay = fee.saturating_add(tip),
-                Err(e) => {
-                    log::error!(
-                        "💔 Failed to apply an adjustment for known sender: {:?}, adjustment config: {:?}, error: {:?}",
-                        fee_payer,
-                        fee_adjustment_config,
-                        e
-                    );
-                },
-            }
+        if has_adjustment {
+            fee_to_pay = network_fee.saturating_add(tip);
         }
 
         let refund_amount = amount_paid.saturating_sub(fee_to_pay);
-        return (has_active_config, refund_amount)
+        return (has_adjustment, refund_amount)
     }
 }
 
@@ -234,7 +494,7 @@ where
     F: Balanced<T::AccountId>,
     OU: OnUnbalanced<Credit<T::AccountId, F>>,
 {
-    type LiquidityInfo = Option<Credit<T::AccountId, F>>;
+    type LiquidityInfo = ChargedFee<Option<Credit<T::AccountId, F>>>;
     type Balance = <F as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Withdraw the predicted fee from the transaction origin.
@@ -242,13 +502,14 @@ where
     /// Note: The `fee` already includes the `tip`.
     fn withdraw_fee(
         who: &<T as frame_system::Config>::AccountId,
-        _call: &<T as frame_system::Config>::RuntimeCall,
+        call: &<T as frame_system::Config>::RuntimeCall,
         _info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
         fee: Self::Balance,
         _tip: Self::Balance,
     ) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+        let call_type = CallType::of(call);
         if fee.is_zero() {
-            return Ok(None)
+            return Ok(ChargedFee { liquidity: None, call_type })
         }
 
         match F::withdraw(
@@ -258,7 +519,7 @@ where
             frame_support::traits::tokens::Preservation::Preserve,
             frame_support::traits::tokens::Fortitude::Polite,
         ) {
-            Ok(imbalance) => Ok(Some(imbalance)),
+            Ok(imbalance) => Ok(ChargedFee { liquidity: Some(imbalance), call_type }),
             Err(_) => Err(InvalidTransaction::Payment.into()),
         }
     }
@@ -296,11 +557,17 @@ where
         tip: Self::Balance,
         already_withdrawn: Self::LiquidityInfo,
     ) -> Result<(), TransactionValidityError> {
-        if let Some(paid) = already_withdrawn {
+        if let Some(paid) = already_withdrawn.liquidity {
             // Calculate how much refund we should return
             let amount_paid = paid.peek();
-            let (has_active_adjustment, refund_amount) =
-                Pallet::<T>::calculate_refund_amount(who, &amount_paid, corrected_fee, tip);
+            let (has_active_adjustment, refund_amount) = Pallet::<T>::calculate_refund_amount(
+                who,
+                &amount_paid,
+                corrected_fee,
+                tip,
+                already_withdrawn.call_type,
+            );
+            Pallet::<T>::record_transaction(who);
 
             // refund to the account that paid the fees. If this fails, the
             // account might have dropped below the existential balance. In
@@ -463,6 +730,14 @@ pub mod set_known_sender_tests;
 #[path = "tests/adjustment_fee_tests.rs"]
 pub mod adjustment_fee_tests;
 
+#[cfg(test)]
+#[path = "tests/fee_asset_tests.rs"]
+pub mod fee_asset_tests;
+
+#[cfg(test)]
+#[path = "tests/fee_rules_tests.rs"]
+pub mod fee_rules_tests;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
@@ -0,0 +1,470 @@
+use super::*;
+use crate::mock::{
+    event_emitted, new_test_ext, AccountId, AssetFeeRecipient, AssetManager, AssetRegistry,
+    AvnTransactionPayment, Balances, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, TestAccount,
+    TestRuntime, BASE_FEE,
+};
+
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::Weight,
+    traits::{Currency, ExistenceRequirement},
+};
+use orml_traits::asset_registry::AssetMetadata;
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_core::H160;
+use sp_runtime::{
+    traits::{transaction_extension::TxBaseImplication, One, TransactionExtension},
+    transaction_validity::TransactionSource,
+    DispatchError, FixedPointNumber,
+};
+
+pub const TX_LEN: usize = 1;
+pub const WEIGHT_FEE: u64 = 100;
+pub const INITIAL_ASSET_BALANCE: u128 = 1000;
+pub const INITIAL_AVT_BALANCE: u128 = 200;
+pub const RATE: u128 = 2;
+
+fn fee_asset() -> CurrencyId {
+    return Asset::ForeignAsset(1)
+}
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn avt_fee(weight: u64) -> u128 {
+    return BASE_FEE.saturating_add(weight as u128).saturating_add(TX_LEN as u128)
+}
+
+fn register_fee_asset() {
+    register_asset(fee_asset(), H160::from([1u8; 20]));
+}
+
+fn register_asset(asset: CurrencyId, token: H160) {
+    let metadata = AssetMetadata {
+        decimals: 18,
+        name: b"Fee asset".to_vec().try_into().unwrap(),
+        symbol: b"FEE".to_vec().try_into().unwrap(),
+        existential_deposit: 1,
+        location: Some(AvnAssetLocation::Ethereum(token)),
+        additional: AvnAssetMetadata { appchain_native: false },
+    };
+    assert_ok!(AssetRegistry::do_register_asset(metadata, Some(asset)));
+}
+
+fn setup_fee_asset_payer(payer: &AccountId) {
+    register_fee_asset();
+    assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+        RuntimeOrigin::root(),
+        fee_asset(),
+        Some(FixedU128::saturating_from_integer(RATE))
+    ));
+    assert_ok!(AvnTransactionPayment::set_fee_asset(
+        RuntimeOrigin::signed(*payer),
+        Some(fee_asset())
+    ));
+
+    Balances::make_free_balance_be(payer, INITIAL_AVT_BALANCE);
+    assert_ok!(<AssetManager as MultiCurrency<AccountId>>::deposit(
+        fee_asset(),
+        payer,
+        INITIAL_ASSET_BALANCE
+    ));
+}
+
+fn asset_balance(who: &AccountId) -> u128 {
+    return <AssetManager as MultiCurrency<AccountId>>::free_balance(fee_asset(), who)
+}
+
+fn pay_fee_and_call_remark(
+    sender: &AccountId,
+    post_dispatch_weight: Option<Weight>,
+) -> Result<(), TransactionValidityError> {
+    pay_fee_in_and_call_remark(sender, None, post_dispatch_weight)
+}
+
+fn pay_fee_in_and_call_remark(
+    sender: &AccountId,
+    asset: Option<CurrencyId>,
+    post_dispatch_weight: Option<Weight>,
+) -> Result<(), TransactionValidityError> {
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    let info =
+        DispatchInfo { call_weight: Weight::from_parts(WEIGHT_FEE, 0), ..Default::default() };
+    let ext = (
+        ChargeFeeAsset::<TestRuntime>::new(asset),
+        ChargeTransactionPayment::<TestRuntime>::from(0),
+    );
+    let implicit = ext.implicit().expect("implicit data available");
+    let implication = TxBaseImplication(call.clone());
+    let origin = RuntimeOrigin::signed(*sender);
+    let (_valid, val, origin) = ext.validate(
+        origin,
+        &call,
+        &info,
+        TX_LEN,
+        implicit,
+        &implication,
+        TransactionSource::Local,
+    )?;
+    let pre = ext.prepare(val, &origin, &call, &info, TX_LEN)?;
+
+    assert_ok!(System::remark(RuntimeOrigin::signed(*sender), vec![]));
+
+    assert_ok!(
+        <(ChargeFeeAsset<TestRuntime>, ChargeTransactionPayment<TestRuntime>)>::post_dispatch(
+            pre,
+            &info,
+            &mut PostDispatchInfo {
+                actual_weight: post_dispatch_weight,
+                pays_fee: Default::default()
+            },
+            TX_LEN,
+            &Ok(())
+        )
+    );
+
+    Ok(())
+}
+
+mod set_fee_asset_rate {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn a_rate_is_set_for_a_registered_asset() {
+            new_test_ext().execute_with(|| {
+                register_fee_asset();
+                let rate = FixedU128::from_rational(3, 2);
+
+                assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                    RuntimeOrigin::root(),
+                    fee_asset(),
+                    Some(rate)
+                ));
+
+                assert_eq!(AvnTransactionPayment::fee_asset_rates(fee_asset()), Some(rate));
+                assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                    TestRuntime,
+                >::FeeAssetRateSet {
+                    asset: fee_asset(),
+                    rate
+                })));
+            });
+        }
+
+        #[test]
+        fn a_rate_is_removed() {
+            new_test_ext().execute_with(|| {
+                register_fee_asset();
+                assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                    RuntimeOrigin::root(),
+                    fee_asset(),
+                    Some(FixedU128::one())
+                ));
+
+                assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                    RuntimeOrigin::root(),
+                    fee_asset(),
+                    None
+                ));
+
+                assert_eq!(AvnTransactionPayment::fee_asset_rates(fee_asset()), None);
+                assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                    TestRuntime,
+                >::FeeAssetRateRemoved {
+                    asset: fee_asset()
+                })));
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn origin_is_not_allowed() {
+            new_test_ext().execute_with(|| {
+                register_fee_asset();
+
+                assert_noop!(
+                    AvnTransactionPayment::set_fee_asset_rate(
+                        RuntimeOrigin::signed(to_acc_id(1)),
+                        fee_asset(),
+                        Some(FixedU128::one())
+                    ),
+                    DispatchError::BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn asset_is_avt() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    AvnTransactionPayment::set_fee_asset_rate(
+                        RuntimeOrigin::root(),
+                        Asset::Avt,
+                        Some(FixedU128::one())
+                    ),
+                    Error::<TestRuntime>::InvalidFeeAsset
+                );
+            });
+        }
+
+        #[test]
+        fn rate_is_zero() {
+            new_test_ext().execute_with(|| {
+                register_fee_asset();
+
+                assert_noop!(
+                    AvnTransactionPayment::set_fee_asset_rate(
+                        RuntimeOrigin::root(),
+                        fee_asset(),
+                        Some(FixedU128::zero())
+                    ),
+                    Error::<TestRuntime>::InvalidFeeAssetRate
+                );
+            });
+        }
+
+        #[test]
+        fn asset_is_not_registered() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    AvnTransactionPayment::set_fee_asset_rate(
+                        RuntimeOrigin::root(),
+                        fee_asset(),
+                        Some(FixedU128::one())
+                    ),
+                    Error::<TestRuntime>::FeeAssetNotRegistered
+                );
+            });
+        }
+
+        #[test]
+        fn removed_rate_does_not_exist() {
+            new_test_ext().execute_with(|| {
+                register_fee_asset();
+
+                assert_noop!(
+                    AvnTransactionPayment::set_fee_asset_rate(
+                        RuntimeOrigin::root(),
+                        fee_asset(),
+                        None
+                    ),
+                    Error::<TestRuntime>::FeeAssetNotSupported
+                );
+            });
+        }
+    }
+}
+
+mod set_fee_asset {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_asset_is_supported() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+
+            assert_eq!(AvnTransactionPayment::fee_assets(payer), Some(fee_asset()));
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                TestRuntime,
+            >::FeeAssetSet {
+                who: payer,
+                asset: Some(fee_asset())
+            })));
+        });
+    }
+
+    #[test]
+    fn succeeds_when_avt_is_restored() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+
+            assert_ok!(AvnTransactionPayment::set_fee_asset(RuntimeOrigin::signed(payer), None));
+
+            assert_eq!(AvnTransactionPayment::fee_assets(payer), None);
+        });
+    }
+
+    #[test]
+    fn fails_when_asset_is_not_supported() {
+        new_test_ext().execute_with(|| {
+            register_fee_asset();
+
+            assert_noop!(
+                AvnTransactionPayment::set_fee_asset(
+                    RuntimeOrigin::signed(to_acc_id(1)),
+                    Some(fee_asset())
+                ),
+                Error::<TestRuntime>::FeeAssetNotSupported
+            );
+        });
+    }
+}
+
+mod paying_fees_in_an_asset {
+    use super::*;
+
+    #[test]
+    fn converts_the_fee_with_the_rate() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+            let expected_fee = avt_fee(WEIGHT_FEE) * RATE;
+
+            assert_ok!(pay_fee_and_call_remark(&payer, None));
+
+            assert_eq!(asset_balance(&payer), INITIAL_ASSET_BALANCE - expected_fee);
+            assert_eq!(asset_balance(&AssetFeeRecipient::get()), expected_fee);
+            assert_eq!(Balances::free_balance(payer), INITIAL_AVT_BALANCE);
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                TestRuntime,
+            >::AssetTransactionFeePaid {
+                who: payer,
+                asset: fee_asset(),
+                fee: expected_fee,
+            })));
+        });
+    }
+
+    #[test]
+    fn refunds_unused_weight_in_the_asset() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+            let actual_weight = WEIGHT_FEE / 2;
+            let expected_fee = avt_fee(actual_weight) * RATE;
+
+            assert_ok!(pay_fee_and_call_remark(&payer, Some(Weight::from_parts(actual_weight, 0))));
+
+            assert_eq!(asset_balance(&payer), INITIAL_ASSET_BALANCE - expected_fee);
+            assert_eq!(asset_balance(&AssetFeeRecipient::get()), expected_fee);
+        });
+    }
+
+    #[test]
+    fn fails_when_asset_balance_is_too_low() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+            assert_ok!(<AssetManager as MultiCurrency<AccountId>>::withdraw(
+                fee_asset(),
+                &payer,
+                INITIAL_ASSET_BALANCE - 10,
+                ExistenceRequirement::AllowDeath
+            ));
+
+            assert_eq!(
+                pay_fee_and_call_remark(&payer, None),
+                Err(InvalidTransaction::Payment.into())
+            );
+            assert_eq!(Balances::free_balance(payer), INITIAL_AVT_BALANCE);
+        });
+    }
+
+    #[test]
+    fn falls_back_to_avt_when_the_rate_is_removed() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+            assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                RuntimeOrigin::root(),
+                fee_asset(),
+                None
+            ));
+
+            assert_ok!(pay_fee_and_call_remark(&payer, None));
+
+            assert_eq!(asset_balance(&payer), INITIAL_ASSET_BALANCE);
+            assert_eq!(Balances::free_balance(payer), INITIAL_AVT_BALANCE - avt_fee(WEIGHT_FEE));
+        });
+    }
+}
+
+mod choosing_the_fee_asset_per_transaction {
+    use super::*;
+
+    fn setup_fee_asset() {
+        register_fee_asset();
+        assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+            RuntimeOrigin::root(),
+            fee_asset(),
+            Some(FixedU128::saturating_from_integer(RATE))
+        ));
+    }
+
+    #[test]
+    fn pays_with_the_chosen_asset_without_holding_avt() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset();
+            assert_ok!(<AssetManager as MultiCurrency<AccountId>>::deposit(
+                fee_asset(),
+                &payer,
+                INITIAL_ASSET_BALANCE
+            ));
+            let expected_fee = avt_fee(WEIGHT_FEE) * RATE;
+
+            assert_ok!(pay_fee_in_and_call_remark(&payer, Some(fee_asset()), None));
+
+            assert_eq!(asset_balance(&payer), INITIAL_ASSET_BALANCE - expected_fee);
+            assert_eq!(asset_balance(&AssetFeeRecipient::get()), expected_fee);
+            assert!(Balances::free_balance(payer).is_zero());
+            // The choice only applies to this transaction
+            assert_eq!(TransactionFeeAsset::<TestRuntime>::get(), None);
+            assert_eq!(AvnTransactionPayment::fee_assets(payer), None);
+        });
+    }
+
+    #[test]
+    fn overrides_the_nominated_asset() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            setup_fee_asset_payer(&payer);
+            let other_asset = Asset::ForeignAsset(2);
+            register_asset(other_asset, H160::from([2u8; 20]));
+            assert_ok!(AvnTransactionPayment::set_fee_asset_rate(
+                RuntimeOrigin::root(),
+                other_asset,
+                Some(FixedU128::one())
+            ));
+            assert_ok!(<AssetManager as MultiCurrency<AccountId>>::deposit(
+                other_asset,
+                &payer,
+                INITIAL_ASSET_BALANCE
+            ));
+
+            assert_ok!(pay_fee_in_and_call_remark(&payer, Some(other_asset), None));
+
+            assert_eq!(asset_balance(&payer), INITIAL_ASSET_BALANCE);
+            assert_eq!(
+                <AssetManager as MultiCurrency<AccountId>>::free_balance(other_asset, &payer),
+                INITIAL_ASSET_BALANCE - avt_fee(WEIGHT_FEE)
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_asset_has_no_rate() {
+        new_test_ext().execute_with(|| {
+            let payer = to_acc_id(1);
+            register_fee_asset();
+            Balances::make_free_balance_be(&payer, INITIAL_AVT_BALANCE);
+
+            assert_eq!(
+                pay_fee_in_and_call_remark(&payer, Some(fee_asset()), None),
+                Err(InvalidTransaction::Payment.into())
+            );
+            assert_eq!(Balances::free_balance(payer), INITIAL_AVT_BALANCE);
+        });
+    }
+}
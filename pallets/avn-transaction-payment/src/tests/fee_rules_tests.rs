@@ -0,0 +1,499 @@
+use super::*;
+use crate::mock::{
+    event_emitted, new_test_ext, AccountId, AvnTransactionPayment, Balances, RuntimeCall,
+    RuntimeEvent, RuntimeOrigin, System, TestAccount, TestRuntime, VolumeTierPeriod, BASE_FEE,
+};
+
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::{DispatchInfo, PostDispatchInfo},
+    pallet_prelude::Weight,
+    traits::{ConstU32, Currency},
+    weights::WeightMeter,
+    BoundedVec,
+};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::{
+    traits::{transaction_extension::TxBaseImplication, TransactionExtension},
+    transaction_validity::TransactionSource,
+    DispatchError,
+};
+
+pub const TX_LEN: usize = 1;
+pub const WEIGHT_FEE: u64 = 100;
+pub const INITIAL_SENDER_BALANCE: u128 = 1000;
+pub const FIXED_FEE: u128 = 10;
+
+fn to_acc_id(id: u64) -> AccountId {
+    return TestAccount::new(id).account_id()
+}
+
+fn expected_fee() -> u128 {
+    return BASE_FEE.saturating_add(WEIGHT_FEE as u128).saturating_add(TX_LEN as u128)
+}
+
+fn remark_call() -> RuntimeCall {
+    return RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn remark_with_event_call() -> RuntimeCall {
+    return RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+}
+
+fn remark_call_type() -> CallType {
+    return CallType::of(&remark_call()).expect("Call has a type")
+}
+
+fn percentage_fee(percentage: u32) -> FeeType<TestRuntime> {
+    return FeeType::PercentageFee(PercentageFeeConfig {
+        percentage,
+        _marker: sp_std::marker::PhantomData::<TestRuntime>,
+    })
+}
+
+fn fixed_fee(fee: u128) -> FeeType<TestRuntime> {
+    return FeeType::FixedFee(FixedFeeConfig { fee })
+}
+
+fn set_call_fee_rule(target: FeeRuleTarget<AccountId>, fee_type: FeeType<TestRuntime>) {
+    assert_ok!(AvnTransactionPayment::set_call_fee_rule(
+        RuntimeOrigin::root(),
+        remark_call_type(),
+        target,
+        Some(fee_type)
+    ));
+}
+
+fn volume_tiers(tiers: Vec<(u32, u32)>) -> BoundedVec<VolumeTier, ConstU32<4>> {
+    return tiers
+        .into_iter()
+        .map(|(threshold, percentage)| VolumeTier { threshold, percentage })
+        .collect::<Vec<_>>()
+        .try_into()
+        .expect("Tiers are bounded")
+}
+
+/// Pays the fee of `call` sent by `sender` and returns the amount paid
+fn pay_fee_and_dispatch(sender: &AccountId, call: RuntimeCall) -> u128 {
+    let balance_before = Balances::free_balance(sender);
+    let info =
+        DispatchInfo { call_weight: Weight::from_parts(WEIGHT_FEE, 0), ..Default::default() };
+    let ext = ChargeTransactionPayment::<TestRuntime>::from(0);
+    let implicit = ext.implicit().expect("implicit data available");
+    let implication = TxBaseImplication(call.clone());
+    let (_valid, val, origin) = ext
+        .validate(
+            RuntimeOrigin::signed(*sender),
+            &call,
+            &info,
+            TX_LEN,
+            implicit,
+            &implication,
+            TransactionSource::Local,
+        )
+        .expect("validation should succeed");
+    let pre = ext
+        .prepare(val, &origin, &call, &info, TX_LEN)
+        .expect("prepare should charge the fee");
+
+    assert_ok!(call.dispatch(RuntimeOrigin::signed(*sender)));
+
+    assert_ok!(ChargeTransactionPayment::<TestRuntime>::post_dispatch(
+        pre,
+        &info,
+        &mut PostDispatchInfo { actual_weight: None, pays_fee: Default::default() },
+        TX_LEN,
+        &Ok(())
+    ));
+
+    return balance_before.saturating_sub(Balances::free_balance(sender))
+}
+
+fn setup_sender(id: u64) -> AccountId {
+    let sender = to_acc_id(id);
+    Balances::make_free_balance_be(&sender, INITIAL_SENDER_BALANCE);
+    return sender
+}
+
+mod set_call_fee_rule {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn a_rule_is_set_for_all_senders() {
+            new_test_ext().execute_with(|| {
+                let fee_type = percentage_fee(100);
+
+                set_call_fee_rule(FeeRuleTarget::AllSenders, fee_type);
+
+                assert_eq!(
+                    AvnTransactionPayment::call_fee_rules(
+                        remark_call_type(),
+                        FeeRuleTarget::AllSenders
+                    ),
+                    Some(fee_type)
+                );
+                assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                    TestRuntime,
+                >::CallFeeRuleSet {
+                    call_type: remark_call_type(),
+                    target: FeeRuleTarget::AllSenders,
+                    fee_type,
+                })));
+            });
+        }
+
+        #[test]
+        fn a_rule_is_removed() {
+            new_test_ext().execute_with(|| {
+                let target = FeeRuleTarget::Sender(to_acc_id(1));
+                set_call_fee_rule(target.clone(), fixed_fee(FIXED_FEE));
+
+                assert_ok!(AvnTransactionPayment::set_call_fee_rule(
+                    RuntimeOrigin::root(),
+                    remark_call_type(),
+                    target.clone(),
+                    None
+                ));
+
+                assert_eq!(
+                    AvnTransactionPayment::call_fee_rules(remark_call_type(), target.clone()),
+                    None
+                );
+                assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                    TestRuntime,
+                >::CallFeeRuleRemoved {
+                    call_type: remark_call_type(),
+                    target,
+                })));
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn origin_is_not_allowed() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    AvnTransactionPayment::set_call_fee_rule(
+                        RuntimeOrigin::signed(to_acc_id(1)),
+                        remark_call_type(),
+                        FeeRuleTarget::AllSenders,
+                        Some(percentage_fee(100))
+                    ),
+                    DispatchError::BadOrigin
+                );
+            });
+        }
+
+        #[test]
+        fn fee_type_is_invalid() {
+            new_test_ext().execute_with(|| {
+                for fee_type in [FeeType::None, percentage_fee(0), percentage_fee(101)] {
+                    assert_noop!(
+                        AvnTransactionPayment::set_call_fee_rule(
+                            RuntimeOrigin::root(),
+                            remark_call_type(),
+                            FeeRuleTarget::AllSenders,
+                            Some(fee_type)
+                        ),
+                        Error::<TestRuntime>::InvalidCallFeeRule
+                    );
+                }
+            });
+        }
+
+        #[test]
+        fn removed_rule_does_not_exist() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    AvnTransactionPayment::set_call_fee_rule(
+                        RuntimeOrigin::root(),
+                        remark_call_type(),
+                        FeeRuleTarget::AllSenders,
+                        None
+                    ),
+                    Error::<TestRuntime>::CallFeeRuleMissing
+                );
+            });
+        }
+    }
+}
+
+mod set_volume_tiers {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_increasing_thresholds() {
+        new_test_ext().execute_with(|| {
+            let tiers = volume_tiers(vec![(2, 10), (5, 50)]);
+
+            assert_ok!(AvnTransactionPayment::set_volume_tiers(
+                RuntimeOrigin::root(),
+                tiers.clone()
+            ));
+
+            assert_eq!(AvnTransactionPayment::volume_tiers(), tiers);
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(crate::Event::<
+                TestRuntime,
+            >::VolumeTiersSet {
+                tiers
+            })));
+        });
+    }
+
+    #[test]
+    fn fails_when_origin_is_not_allowed() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                AvnTransactionPayment::set_volume_tiers(
+                    RuntimeOrigin::signed(to_acc_id(1)),
+                    volume_tiers(vec![(2, 10)])
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_tiers_are_invalid() {
+        new_test_ext().execute_with(|| {
+            for tiers in
+                [vec![(5, 10), (2, 50)], vec![(2, 10), (2, 50)], vec![(2, 0)], vec![(2, 101)]]
+            {
+                assert_noop!(
+                    AvnTransactionPayment::set_volume_tiers(
+                        RuntimeOrigin::root(),
+                        volume_tiers(tiers)
+                    ),
+                    Error::<TestRuntime>::InvalidVolumeTiers
+                );
+            }
+        });
+    }
+}
+
+mod call_fee_rules {
+    use super::*;
+
+    #[test]
+    fn apply_to_the_matching_call() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_call_fee_rule(FeeRuleTarget::AllSenders, percentage_fee(100));
+
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), 0);
+            assert!(event_emitted(&RuntimeEvent::AvnTransactionPayment(
+                crate::Event::<TestRuntime>::AdjustedTransactionFeePaid { who: sender, fee: 0 }
+            )));
+        });
+    }
+
+    #[test]
+    fn do_not_apply_to_other_calls() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_call_fee_rule(FeeRuleTarget::AllSenders, percentage_fee(100));
+
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_with_event_call()), expected_fee());
+        });
+    }
+
+    #[test]
+    fn for_a_sender_take_precedence_over_rules_for_all_senders() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            let other_sender = setup_sender(2);
+            set_call_fee_rule(FeeRuleTarget::AllSenders, percentage_fee(100));
+            set_call_fee_rule(FeeRuleTarget::Sender(sender), fixed_fee(FIXED_FEE));
+
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), FIXED_FEE);
+            assert_eq!(pay_fee_and_dispatch(&other_sender, remark_call()), 0);
+        });
+    }
+
+    #[test]
+    fn take_precedence_over_known_sender_configs() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            assert_ok!(AvnTransactionPayment::set_known_sender(
+                RuntimeOrigin::root(),
+                sender,
+                AdjustmentInput::<TestRuntime> {
+                    fee_type: fixed_fee(1),
+                    adjustment_type: AdjustmentType::None,
+                },
+            ));
+            set_call_fee_rule(FeeRuleTarget::AllSenders, fixed_fee(FIXED_FEE));
+
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), FIXED_FEE);
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_with_event_call()), 1);
+        });
+    }
+}
+
+mod volume_tiers {
+    use super::*;
+
+    fn set_volume_tiers(tiers: Vec<(u32, u32)>) {
+        assert_ok!(AvnTransactionPayment::set_volume_tiers(
+            RuntimeOrigin::root(),
+            volume_tiers(tiers)
+        ));
+    }
+
+    fn discounted_fee(fee: u128, percentage: u32) -> u128 {
+        return fee - fee * percentage as u128 / 100
+    }
+
+    #[test]
+    fn lower_fees_once_a_threshold_is_reached() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_volume_tiers(vec![(2, 10), (3, 50)]);
+
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), expected_fee());
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), expected_fee());
+            assert_eq!(
+                pay_fee_and_dispatch(&sender, remark_call()),
+                discounted_fee(expected_fee(), 10)
+            );
+            assert_eq!(
+                pay_fee_and_dispatch(&sender, remark_call()),
+                discounted_fee(expected_fee(), 50)
+            );
+            assert_eq!(AvnTransactionPayment::transaction_volume_of(&sender), 4);
+        });
+    }
+
+    #[test]
+    fn are_reset_in_a_new_period() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_volume_tiers(vec![(1, 50)]);
+
+            pay_fee_and_dispatch(&sender, remark_call());
+            assert_eq!(
+                pay_fee_and_dispatch(&sender, remark_call()),
+                discounted_fee(expected_fee(), 50)
+            );
+
+            System::set_block_number(System::block_number() + VolumeTierPeriod::get());
+
+            assert_eq!(AvnTransactionPayment::transaction_volume_of(&sender), 0);
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), expected_fee());
+        });
+    }
+
+    #[test]
+    fn apply_on_top_of_call_fee_rules() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_volume_tiers(vec![(1, 50)]);
+            set_call_fee_rule(FeeRuleTarget::AllSenders, fixed_fee(FIXED_FEE));
+
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), FIXED_FEE);
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), discounted_fee(FIXED_FEE, 50));
+        });
+    }
+
+    #[test]
+    fn are_not_recorded_when_there_are_no_tiers() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+
+            pay_fee_and_dispatch(&sender, remark_call());
+
+            assert_eq!(AvnTransactionPayment::transaction_volume_of(&sender), 0);
+        });
+    }
+
+    #[test]
+    fn are_pruned_once_their_period_has_passed() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_volume_tiers(vec![(1, 50)]);
+            pay_fee_and_dispatch(&sender, remark_call());
+            let period = System::block_number() / VolumeTierPeriod::get();
+
+            AvnTransactionPayment::prune_transaction_volumes(&mut WeightMeter::new());
+            assert_eq!(TransactionVolume::<TestRuntime>::get(period, &sender), 1);
+
+            System::set_block_number(System::block_number() + VolumeTierPeriod::get());
+            for _ in 0..=period {
+                AvnTransactionPayment::prune_transaction_volumes(&mut WeightMeter::new());
+            }
+
+            assert!(!TransactionVolume::<TestRuntime>::contains_key(period, &sender));
+            assert_eq!(OldestVolumePeriod::<TestRuntime>::get(), period + 1);
+        });
+    }
+
+    #[test]
+    fn are_not_pruned_without_enough_weight() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_volume_tiers(vec![(1, 50)]);
+            pay_fee_and_dispatch(&sender, remark_call());
+            let period = System::block_number() / VolumeTierPeriod::get();
+            System::set_block_number(System::block_number() + VolumeTierPeriod::get());
+
+            let mut meter = WeightMeter::with_limit(Weight::zero());
+            AvnTransactionPayment::prune_transaction_volumes(&mut meter);
+
+            assert_eq!(TransactionVolume::<TestRuntime>::get(period, &sender), 1);
+            assert_eq!(meter.consumed(), Weight::zero());
+        });
+    }
+}
+
+mod preview_adjusted_fee {
+    use super::*;
+
+    #[test]
+    fn returns_the_fee_with_all_rules_applied() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_call_fee_rule(FeeRuleTarget::AllSenders, fixed_fee(FIXED_FEE));
+
+            assert_eq!(
+                AvnTransactionPayment::preview_adjusted_fee(
+                    &sender,
+                    &remark_call(),
+                    expected_fee()
+                ),
+                FIXED_FEE
+            );
+            assert_eq!(
+                AvnTransactionPayment::preview_adjusted_fee(
+                    &sender,
+                    &remark_with_event_call(),
+                    expected_fee()
+                ),
+                expected_fee()
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_exceed_the_fee_when_a_fixed_fee_is_higher() {
+        new_test_ext().execute_with(|| {
+            let sender = setup_sender(1);
+            set_call_fee_rule(FeeRuleTarget::AllSenders, fixed_fee(expected_fee() * 2));
+
+            assert_eq!(
+                AvnTransactionPayment::preview_adjusted_fee(
+                    &sender,
+                    &remark_call(),
+                    expected_fee()
+                ),
+                expected_fee()
+            );
+            assert_eq!(pay_fee_and_dispatch(&sender, remark_call()), expected_fee());
+        });
+    }
+}
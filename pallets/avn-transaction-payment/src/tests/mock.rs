@@ -1,20 +1,28 @@
 use crate::{
-    self as pallet_avn_transaction_payment, system::limits, AvnGasFeeAdapter, KnownSenders,
+    self as pallet_avn_transaction_payment, system::limits, KnownSenders, MultiAssetFeeAdapter,
 };
 use codec::{Decode, Encode};
 use frame_support::{
     derive_impl,
     pallet_prelude::DispatchClass,
     parameter_types,
-    traits::{ConstU8, Imbalance, OnFinalize, OnInitialize, OnUnbalanced},
+    traits::{ConstU32, ConstU8, Everything, Imbalance, OnFinalize, OnInitialize, OnUnbalanced},
     weights::{Weight, WeightToFee as WeightToFeeT},
 };
-use frame_system::{self as system, DefaultConfig};
+use frame_system::{self as system, DefaultConfig, EnsureRoot};
+use orml_traits::{
+    asset_registry::{AssetProcessor, AvnAssetLocation, AvnAssetMetadata},
+    parameter_type_with_key,
+};
 use pallet_balances;
+use sp_avn_common::{
+    primitives::{Amount, Balance, CurrencyId},
+    Asset,
+};
 use sp_core::{sr25519, Pair};
 use sp_runtime::{
     traits::{IdentityLookup, Verify},
-    BuildStorage, Perbill, SaturatedConversion,
+    BuildStorage, DispatchError, Perbill, SaturatedConversion,
 };
 
 pub type AccountId = <Signature as Verify>::Signer;
@@ -35,7 +43,10 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>, Config<T>},
-        AvnTransactionPayment: pallet_avn_transaction_payment::{Pallet, Call, Storage, Event<T>}
+        AvnTransactionPayment: pallet_avn_transaction_payment::{Pallet, Call, Storage, Event<T>},
+        AssetRegistry: orml_asset_registry,
+        AssetManager: orml_currencies,
+        Tokens: orml_tokens,
     }
 );
 
@@ -75,6 +86,18 @@ impl pallet_avn_transaction_payment::Config for TestRuntime {
     type Currency = Balances;
     type KnownUserOrigin = frame_system::EnsureRoot<AccountId>;
     type WeightInfo = pallet_avn_transaction_payment::default_weights::SubstrateWeight<TestRuntime>;
+    type AssetManager = AssetManager;
+    type AssetRegistryStringLimit = ConstU32<1024>;
+    type AssetRegistry = AssetRegistry;
+    type FeeAssetRateOrigin = EnsureRoot<AccountId>;
+    type AssetFeeRecipient = AssetFeeRecipient;
+    type MaxVolumeTiers = ConstU32<4>;
+    type VolumeTierPeriod = VolumeTierPeriod;
+}
+
+parameter_types! {
+    pub AssetFeeRecipient: AccountId = TestAccount::new(999).account_id();
+    pub const VolumeTierPeriod: u64 = 10;
 }
 
 impl WeightToFeeT for WeightToFee {
@@ -124,7 +147,7 @@ impl
 
 impl pallet_transaction_payment::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = AvnGasFeeAdapter<Balances, DealWithFees>;
+    type OnChargeTransaction = MultiAssetFeeAdapter<Balances, DealWithFees>;
     type LengthToFee = TransactionByteFee;
     type WeightToFee = WeightToFee;
     type FeeMultiplierUpdate = ();
@@ -143,6 +166,76 @@ impl pallet_balances::Config for TestRuntime {
     type AccountStore = System;
 }
 
+type AssetMetadata = orml_traits::asset_registry::AssetMetadata<
+    Balance,
+    AvnAssetMetadata,
+    AvnAssetLocation,
+    ConstU32<1024>,
+>;
+type BasicCurrencyAdapter<R, B> = orml_currencies::BasicCurrencyAdapter<R, B, Amount, Balance>;
+
+pub struct NoopAssetProcessor {}
+impl AssetProcessor<CurrencyId, AssetMetadata> for NoopAssetProcessor {
+    fn pre_register(
+        id: Option<CurrencyId>,
+        asset_metadata: AssetMetadata,
+    ) -> Result<(CurrencyId, AssetMetadata), DispatchError> {
+        assert!(id.is_some(), "Id must be set");
+        Ok((id.unwrap(), asset_metadata))
+    }
+}
+
+parameter_types! {
+    pub const GetNativeCurrencyId: CurrencyId = Asset::Avt;
+}
+
+impl orml_currencies::Config for TestRuntime {
+    type GetNativeCurrencyId = GetNativeCurrencyId;
+    type MultiCurrency = Tokens;
+    type NativeCurrency = BasicCurrencyAdapter<TestRuntime, Balances>;
+    type WeightInfo = ();
+}
+
+impl orml_asset_registry::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type CustomMetadata = AvnAssetMetadata;
+    type AssetId = CurrencyId;
+    type AuthorityOrigin = EnsureRoot<AccountId>;
+    type Balance = Balance;
+    type StringLimit = ConstU32<1024>;
+    type AssetProcessor = NoopAssetProcessor;
+    type AssetLocation = AvnAssetLocation;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MaxLocks: u32 = 50;
+    pub const MaxReserves: u32 = 50;
+}
+
+parameter_type_with_key! {
+    pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
+        match currency_id {
+            Asset::Avt => EXISTENTIAL_DEPOSIT.into(),
+            _ => 1
+        }
+    };
+}
+
+impl orml_tokens::Config for TestRuntime {
+    type Amount = Amount;
+    type Balance = Balance;
+    type CurrencyId = CurrencyId;
+    type DustRemovalWhitelist = Everything;
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposits = ExistentialDeposits;
+    type MaxLocks = MaxLocks;
+    type MaxReserves = MaxReserves;
+    type CurrencyHooks = ();
+    type ReserveIdentifier = [u8; 8];
+    type WeightInfo = ();
+}
+
 impl AvnTransactionPayment {
     pub fn is_known_sender(account_id: <TestRuntime as frame_system::Config>::AccountId) -> bool {
         KnownSenders::<TestRuntime>::contains_key(account_id)
@@ -47,7 +47,7 @@ use sp_runtime::{
         Duration,
     },
     traits::Member,
-    BoundedVec, DispatchError, WeakBoundedVec,
+    BoundedVec, DispatchError, Perbill, WeakBoundedVec,
 };
 use sp_std::{fmt::Debug, prelude::*};
 
@@ -707,11 +707,18 @@ impl<ValidatorId: Member> ValidatorRegistrationNotifier<ValidatorId> for () {
 }
 
 pub trait Enforcer<ValidatorId: Member> {
-    fn slash_validator(slashed_validator_id: &ValidatorId) -> DispatchResult;
+    /// Punish `slashed_validator_id` by removing `slash_fraction` of its stake
+    fn slash_validator(
+        slashed_validator_id: &ValidatorId,
+        slash_fraction: Perbill,
+    ) -> DispatchResult;
 }
 
 impl<ValidatorId: Member> Enforcer<ValidatorId> for () {
-    fn slash_validator(_slashed_validator_id: &ValidatorId) -> DispatchResult {
+    fn slash_validator(
+        _slashed_validator_id: &ValidatorId,
+        _slash_fraction: Perbill,
+    ) -> DispatchResult {
         Ok(())
     }
 }
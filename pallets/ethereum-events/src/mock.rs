@@ -419,6 +419,10 @@ impl pallet_avn_proxy::Config for TestRuntime {
     type WeightInfo = ();
     type PaymentHandler = Self;
     type Token = sp_core::H160;
+    type MaxSponsoredCallTypes = ConstU32<4>;
+    type MaxSponsoredBeneficiaries = ConstU32<10>;
+    type SponsorshipPeriod = sp_core::ConstU64<10>;
+    type MetaTransactionFilter = frame_support::traits::Nothing;
 }
 
 // Test Avn proxy configuration logic
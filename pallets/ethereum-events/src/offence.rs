@@ -19,6 +19,16 @@ pub enum EthereumLogOffenceType {
 }
 use crate::*;
 
+impl EthereumLogOffenceType {
+    /// The fraction of stake slashed for the offence.
+    pub fn slash_fraction(&self) -> Perbill {
+        match self {
+            EthereumLogOffenceType::IncorrectValidationResultSubmitted => Perbill::from_percent(5),
+            EthereumLogOffenceType::ChallengeAttemptedOnValidResult => Perbill::from_percent(1),
+        }
+    }
+}
+
 #[derive(PartialEq, Clone, Debug, Encode, Decode)]
 pub struct InvalidEthereumLogOffence<Offender> {
     /// The current session index in which we report the validators that submitted an invalid
@@ -53,8 +63,7 @@ impl<Offender: Clone> Offence<Offender> for InvalidEthereumLogOffence<Offender>
     }
 
     fn slash_fraction(&self, _offenders_count: u32) -> Perbill {
-        // We don't implement fraction slashes at the moment.
-        Perbill::from_percent(100)
+        self.offence_type.slash_fraction()
     }
 }
 
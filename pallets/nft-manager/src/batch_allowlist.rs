@@ -0,0 +1,182 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Allowlist drops let a batch creator publish the Merkle root of `(unique_external_ref,
+//! recipient)` entries so recipients can mint their own nft from the batch by presenting a proof.
+//! Leaves are `keccak256(abi.encodePacked(unique_external_ref, recipient))` and proofs use
+//! keccak256 with sorted pairs, so trees built with standard Ethereum tooling can be used as they
+//! are. Allowlist mints take their sale index from a range reserved for them, starting at
+//! [`ALLOWLIST_SALE_INDEX_START`], so they never collide with indexes used by other mints.
+
+use crate::{
+    batch_nft::{get_nft_info_for_batch, mint_batch_nft},
+    keccak_256, AllowlistMintCount, AllowlistMinted, AllowlistProofBound, BatchAllowlistOf,
+    BatchAllowlists, BatchInfoId, BatchOpenForSale, BlockNumberFor, Config, DispatchResult, Encode,
+    Error, Event, NftBatchId, NftExternalRefBound, NftSaleType, Pallet, Proof, Vec, H256,
+};
+use frame_support::{ensure, BoundedVec};
+
+pub const SIGNED_SET_BATCH_ALLOWLIST_CONTEXT: &'static [u8] =
+    b"authorization for set batch allowlist operation";
+pub const SIGNED_MINT_FROM_ALLOWLIST_CONTEXT: &'static [u8] =
+    b"authorization for mint from allowlist operation";
+/// Sale indexes from this value upwards are reserved for allowlist mints
+pub const ALLOWLIST_SALE_INDEX_START: u64 = 1 << 63;
+
+pub fn encode_set_batch_allowlist_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    batch_id: &NftBatchId,
+    allowlist: &BatchAllowlistOf<T>,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (SIGNED_SET_BATCH_ALLOWLIST_CONTEXT, &proof.relayer, batch_id, allowlist, nonce).encode()
+}
+
+pub fn encode_mint_from_allowlist_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    batch_id: &NftBatchId,
+    unique_external_ref: &Vec<u8>,
+    merkle_proof: &BoundedVec<H256, AllowlistProofBound>,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (
+        SIGNED_MINT_FROM_ALLOWLIST_CONTEXT,
+        &proof.relayer,
+        batch_id,
+        unique_external_ref,
+        merkle_proof,
+        nonce,
+    )
+        .encode()
+}
+
+/// The leaf of the allowlist tree for an entry, hashed as
+/// `keccak256(abi.encodePacked(unique_external_ref, recipient))`
+pub fn allowlist_leaf<T: Config>(unique_external_ref: &[u8], recipient: &T::AccountId) -> H256 {
+    let mut data_to_hash = unique_external_ref.to_vec();
+    data_to_hash.extend_from_slice(&recipient.encode());
+
+    return H256(keccak_256(&data_to_hash))
+}
+
+pub fn is_allowlist_sale_index(sale_index: u64) -> bool {
+    return sale_index >= ALLOWLIST_SALE_INDEX_START
+}
+
+fn hash_sorted_pair(a: &H256, b: &H256) -> H256 {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data_to_hash = first.as_bytes().to_vec();
+    data_to_hash.extend_from_slice(second.as_bytes());
+
+    return H256(keccak_256(&data_to_hash))
+}
+
+pub fn compute_allowlist_root(leaf: H256, merkle_proof: &[H256]) -> H256 {
+    return merkle_proof.iter().fold(leaf, |node, sibling| hash_sorted_pair(&node, sibling))
+}
+
+pub fn validate_set_batch_allowlist_request<T: Config>(
+    sender: &T::AccountId,
+    batch_id: &NftBatchId,
+    allowlist: &BatchAllowlistOf<T>,
+) -> DispatchResult {
+    ensure!(batch_id.is_zero() == false, Error::<T>::BatchIdIsMandatory);
+    ensure!(<BatchInfoId<T>>::contains_key(batch_id), Error::<T>::BatchDoesNotExist);
+    let nft_info = get_nft_info_for_batch::<T>(batch_id)?;
+    ensure!(nft_info.creator == Some(sender.clone()), Error::<T>::SenderIsNotBatchCreator);
+    ensure!(allowlist.root.is_zero() == false, Error::<T>::AllowlistRootIsMandatory);
+
+    if let Some(mint_end) = allowlist.mint_end {
+        ensure!(
+            mint_end >= <frame_system::Pallet<T>>::block_number(),
+            Error::<T>::InvalidMintWindow
+        );
+        if let Some(mint_start) = allowlist.mint_start {
+            ensure!(mint_start <= mint_end, Error::<T>::InvalidMintWindow);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn set_batch_allowlist<T: Config>(batch_id: NftBatchId, allowlist: BatchAllowlistOf<T>) {
+    <BatchAllowlists<T>>::insert(batch_id, &allowlist);
+
+    <Pallet<T>>::deposit_event(Event::<T>::BatchAllowlistSet {
+        batch_nft_id: batch_id,
+        root: allowlist.root,
+        mint_start: allowlist.mint_start,
+        mint_end: allowlist.mint_end,
+        max_per_account: allowlist.max_per_account,
+    });
+}
+
+fn validate_mint_window<T: Config>(allowlist: &BatchAllowlistOf<T>) -> DispatchResult {
+    let now: BlockNumberFor<T> = <frame_system::Pallet<T>>::block_number();
+
+    if let Some(mint_start) = allowlist.mint_start {
+        ensure!(now >= mint_start, Error::<T>::AllowlistMintNotStarted);
+    }
+    if let Some(mint_end) = allowlist.mint_end {
+        ensure!(now <= mint_end, Error::<T>::AllowlistMintEnded);
+    }
+
+    Ok(())
+}
+
+pub fn validate_mint_from_allowlist_request<T: Config>(
+    recipient: &T::AccountId,
+    batch_id: &NftBatchId,
+    unique_external_ref: &BoundedVec<u8, NftExternalRefBound>,
+    merkle_proof: &BoundedVec<H256, AllowlistProofBound>,
+) -> DispatchResult {
+    let allowlist = <BatchAllowlists<T>>::get(batch_id).ok_or(Error::<T>::BatchHasNoAllowlist)?;
+    ensure!(
+        <BatchOpenForSale<T>>::get(batch_id) == NftSaleType::Fiat,
+        Error::<T>::BatchNotListedForFiatSale
+    );
+    validate_mint_window::<T>(&allowlist)?;
+
+    if let Some(max_per_account) = allowlist.max_per_account {
+        ensure!(
+            <AllowlistMintCount<T>>::get(batch_id, recipient) < max_per_account,
+            Error::<T>::AllowlistMintLimitReached
+        );
+    }
+
+    let leaf = allowlist_leaf::<T>(unique_external_ref, recipient);
+    ensure!(
+        compute_allowlist_root(leaf, merkle_proof) == allowlist.root,
+        Error::<T>::InvalidAllowlistProof
+    );
+
+    Ok(())
+}
+
+pub fn mint_from_allowlist<T: Config>(
+    recipient: T::AccountId,
+    batch_id: NftBatchId,
+    unique_external_ref: BoundedVec<u8, NftExternalRefBound>,
+) -> DispatchResult {
+    // Allowlist entries are not tied to a sale index, so take the next one from the reserved range.
+    // The batch total supply bounds the number of mints, so the range can't be exhausted.
+    let sale_index = ALLOWLIST_SALE_INDEX_START.saturating_add(<AllowlistMinted<T>>::get(batch_id));
+    mint_batch_nft::<T>(batch_id, recipient.clone(), sale_index, unique_external_ref)?;
+    <AllowlistMinted<T>>::mutate(batch_id, |minted| *minted += 1);
+    <AllowlistMintCount<T>>::mutate(batch_id, &recipient, |count| *count += 1);
+
+    Ok(())
+}
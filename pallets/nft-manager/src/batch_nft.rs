@@ -15,11 +15,11 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    keccak_256, BatchInfoId, BatchOpenForSale, Config, Decode, DispatchResult, Encode, Error,
-    EthEventId, Event, Nft, NftBatchId, NftBatches, NftEndBatchListingData, NftExternalRefBound,
-    NftInfo, NftInfoId, NftInfos, NftRoyaltiesBound, NftSaleType, NftUniqueId, Nfts, Pallet,
-    ProcessedEventsChecker, Proof, Royalty, Vec, BATCH_ID_CONTEXT, BATCH_NFT_ID_CONTEXT, H160,
-    U256,
+    batch_allowlist::is_allowlist_sale_index, keccak_256, BatchInfoId, BatchOpenForSale,
+    BurnedNfts, Config, Decode, DispatchResult, Encode, Error, EthEventId, Event, Nft, NftBatchId,
+    NftBatches, NftEndBatchListingData, NftExternalRefBound, NftInfo, NftInfoId, NftInfos,
+    NftRoyaltiesBound, NftSaleType, NftUniqueId, Nfts, Pallet, ProcessedEventsChecker, Proof,
+    Royalty, Vec, BATCH_ID_CONTEXT, BATCH_NFT_ID_CONTEXT, H160, U256,
 };
 use frame_support::ensure;
 use sp_avn_common::event_types::NftMintData;
@@ -81,9 +81,9 @@ pub fn create_batch<T: Config>(
     <NftInfos<T>>::insert(info.info_id, info);
 }
 
-pub fn encode_create_batch_params<T: Config>(
+pub fn encode_create_batch_params<T: Config, R: Encode>(
     proof: &Proof<T::Signature, T::AccountId>,
-    royalties: &Vec<Royalty>,
+    royalties: &R,
     t1_authority: &H160,
     total_supply: &u64,
     nonce: &u64,
@@ -146,6 +146,7 @@ pub fn process_mint_batch_nft_event<T: Config>(
         <BatchOpenForSale<T>>::get(&data.batch_id) == NftSaleType::Ethereum,
         Error::<T>::BatchNotListedForEthereumSale
     );
+    ensure!(is_allowlist_sale_index(data.sale_index) == false, Error::<T>::SaleIndexReserved);
 
     let owner = T::AccountId::decode(&mut data.t2_owner_public_key.as_bytes())
         .expect("32 bytes will always decode into an AccountId");
@@ -186,6 +187,7 @@ pub fn mint_batch_nft<T: Config>(
     let nft_info = validate_mint_batch_nft_request::<T>(batch_id, &unique_external_ref)?;
     let nft_id = generate_batch_nft_id::<T>(&batch_id, &sale_index);
     ensure!(<Nfts<T>>::contains_key(&nft_id) == false, Error::<T>::NftAlreadyExists);
+    ensure!(<BurnedNfts<T>>::get(&nft_id) == false, Error::<T>::NftAlreadyBurned);
 
     let nft = Nft::new(nft_id, nft_info.info_id, unique_external_ref, owner.clone());
     Pallet::<T>::add_nft(&nft);
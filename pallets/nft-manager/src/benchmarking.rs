@@ -21,7 +21,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, BenchmarkError};
 use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use pallet_avn::{self as avn};
@@ -142,7 +142,9 @@ impl<T: Config> MintSingleNft<T> {
         let mut royalties: Vec<Royalty> = Vec::new();
         for _r in 0..number_of_royalties {
             royalties.push(Royalty {
-                recipient_t1_address: H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134")),
+                recipient: RoyaltyRecipient::T1(H160(hex!(
+                    "afdf36201bf70F1232111b5c6a9a424558755134"
+                ))),
                 rate: RoyaltyRate { parts_per_million: 1u32 },
             });
         }
@@ -388,7 +390,9 @@ impl<T: Config> CreateBatch<T> {
         let mut royalties: Vec<Royalty> = Vec::new();
         for _r in 0..number_of_royalties {
             royalties.push(Royalty {
-                recipient_t1_address: H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134")),
+                recipient: RoyaltyRecipient::T1(H160(hex!(
+                    "afdf36201bf70F1232111b5c6a9a424558755134"
+                ))),
                 rate: RoyaltyRate { parts_per_million: 1u32 },
             });
         }
@@ -588,6 +592,138 @@ impl<T: Config> EndBatchSale<T> {
     }
 }
 
+struct NftLifecycle<T: Config> {
+    relayer: T::AccountId,
+    nft_owner_key_pair: <T as avn::Config>::AuthorityId,
+    nft_owner: T::AccountId,
+    nft_id: NftId,
+    info_id: NftInfoId,
+    nft: Nft<T::AccountId>,
+}
+
+impl<T: Config> NftLifecycle<T> {
+    fn new() -> Self {
+        let relayer_account_id = get_relayer::<T>();
+        let (nft_owner_key_pair, nft_owner_account_id) = get_user_account::<T>();
+
+        let nft_id = U256::from(1u8);
+        let info_id = U256::one();
+        let nft =
+            Nft::new(nft_id, info_id, bounded_unique_external_ref(), nft_owner_account_id.clone());
+
+        return NftLifecycle {
+            relayer: relayer_account_id,
+            nft_owner_key_pair,
+            nft_owner: nft_owner_account_id,
+            nft_id,
+            info_id,
+            nft,
+        }
+    }
+
+    fn setup(self) -> Self {
+        let info = NftInfo::<T::AccountId>::new(
+            self.info_id,
+            bounded_royalties(vec![]),
+            H160(hex!("0000000000000000000000000000000000000001")),
+        );
+        <NftInfos<T>>::insert(self.info_id, info);
+        Pallet::<T>::add_nft(&self.nft);
+        return self
+    }
+
+    fn setup_locked(self) -> Self {
+        let context = self.setup();
+        <Nfts<T>>::mutate(context.nft_id, |maybe_nft| {
+            maybe_nft.as_mut().map(|nft| nft.is_locked = true)
+        });
+        return context
+    }
+
+    // Fill the nft and its info with attributes so burning has to remove the maximum amount
+    fn setup_attributes(self) -> Self {
+        for target in [AttributeTarget::Nft(self.nft_id), AttributeTarget::NftInfo(self.info_id)] {
+            for i in 0..MAX_NUMBER_OF_ATTRIBUTES {
+                let key = NftAttributeKey::try_from(i.encode()).expect("key is within bounds");
+                nft_lifecycle::set_attribute::<T>(target, key, attribute_value())
+                    .expect("attribute can be set");
+            }
+        }
+        return self
+    }
+
+    fn proof(&self, signed_payload: Vec<u8>) -> Proof<T::Signature, T::AccountId> {
+        let signature = self.nft_owner_key_pair.sign(&signed_payload.as_slice()).unwrap().encode();
+        return get_proof::<T>(self.nft_owner.clone(), self.relayer.clone(), &signature)
+    }
+}
+
+struct AllowlistMint<T: Config> {
+    relayer: T::AccountId,
+    key_pair: <T as avn::Config>::AuthorityId,
+    // The batch creator is also on the allowlist
+    account: T::AccountId,
+    batch_id: NftBatchId,
+    unique_external_ref: BoundedVec<u8, NftExternalRefBound>,
+    merkle_proof: BoundedVec<H256, AllowlistProofBound>,
+    allowlist: BatchAllowlistOf<T>,
+}
+
+impl<T: Config> AllowlistMint<T> {
+    fn new(proof_length: u32) -> Self {
+        let (key_pair, account) = get_user_account::<T>();
+        let batch: CreateBatch<T> = CreateBatch::new(T::BatchBound::get());
+        let batch_id = batch.create_batch_for_setup();
+        <BatchOpenForSale<T>>::insert(batch_id, NftSaleType::Fiat);
+
+        let unique_external_ref = bounded_unique_external_ref();
+        let merkle_proof = BoundedVec::truncate_from(
+            (0..proof_length).map(|i| H256::repeat_byte(i as u8 + 1)).collect::<Vec<H256>>(),
+        );
+        let leaf = allowlist_leaf::<T>(&unique_external_ref, &account);
+        let allowlist = BatchAllowlist {
+            root: compute_allowlist_root(leaf, &merkle_proof),
+            mint_start: Some(0u32.into()),
+            mint_end: Some(100u32.into()),
+            max_per_account: Some(1),
+        };
+
+        return AllowlistMint {
+            relayer: get_relayer::<T>(),
+            key_pair,
+            account,
+            batch_id,
+            unique_external_ref,
+            merkle_proof,
+            allowlist,
+        }
+    }
+
+    fn setup(self) -> Self {
+        <BatchAllowlists<T>>::insert(self.batch_id, self.allowlist.clone());
+        return self
+    }
+
+    fn proof(&self, signed_payload: Vec<u8>) -> Proof<T::Signature, T::AccountId> {
+        let signature = self.key_pair.sign(&signed_payload.as_slice()).unwrap().encode();
+        return get_proof::<T>(self.account.clone(), self.relayer.clone(), &signature)
+    }
+
+    fn minted_nft_id(&self) -> NftId {
+        return generate_batch_nft_id::<T>(&self.batch_id, &0u64)
+    }
+}
+
+fn attribute_key() -> NftAttributeKey {
+    NftAttributeKey::try_from(vec![1u8; NftAttributeKeyBound::get() as usize])
+        .expect("key is within bounds")
+}
+
+fn attribute_value() -> NftAttributeValue {
+    NftAttributeValue::try_from(vec![2u8; NftAttributeValueBound::get() as usize])
+        .expect("value is within bounds")
+}
+
 benchmarks! {
     mint_single_nft {
         let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
@@ -749,6 +885,24 @@ benchmarks! {
         }.into());
     }
 
+    record_fiat_settlement {
+        let cancel_list_fiat_nft: CancelListFiatNft<T> = CancelListFiatNft::new().setup();
+        let origin = T::FiatSettlementOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let token: T::Token = H160(hex!("0000000000000000000000000000000000000002")).into();
+        let amount: T::TokenBalance = 1_000u32.into();
+    }: _<T::RuntimeOrigin>(origin, cancel_list_fiat_nft.nft_id, token, amount)
+    verify {
+        let settlement = FiatSettlements::<T>::get(&cancel_list_fiat_nft.nft_id).unwrap();
+        assert_eq!(settlement, FiatSettlement { token, amount, op_id: cancel_list_fiat_nft.op_id });
+        assert_last_event::<T>(Event::<T>::FiatSaleSettled {
+            nft_id: cancel_list_fiat_nft.nft_id,
+            token,
+            amount,
+            op_id: cancel_list_fiat_nft.op_id
+        }.into());
+    }
+
     proxy_signed_mint_single_nft {
         let r in 1 .. MAX_NUMBER_OF_ROYALTIES;
         let mint_nft: MintSingleNft<T> = MintSingleNft::new(r).setup();
@@ -908,6 +1062,194 @@ benchmarks! {
         assert_last_event::<T>(Event::<T>::CallDispatched{ relayer: context.relayer.clone(), hash: call_hash }.into());
         assert_last_nth_event::<T>(Event::<T>::BatchSaleEnded{ batch_nft_id: context.batch_id, sale_type: context.market }.into(), 2);
     }
+
+    lock_nft {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup();
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), context.nft_id)
+    verify {
+        assert_eq!(true, Nfts::<T>::get(&context.nft_id).unwrap().is_locked);
+        assert_last_event::<T>(Event::<T>::NftLocked { nft_id: context.nft_id, by: context.nft_owner }.into());
+    }
+
+    signed_lock_nft {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup();
+        let proof = context.proof(
+            (SIGNED_LOCK_NFT_CONTEXT, &context.relayer, context.nft_id, 0u64).encode()
+        );
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), proof, context.nft_id)
+    verify {
+        assert_eq!(true, Nfts::<T>::get(&context.nft_id).unwrap().is_locked);
+        assert_last_event::<T>(Event::<T>::NftLocked { nft_id: context.nft_id, by: context.nft_owner }.into());
+    }
+
+    unlock_nft {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup_locked();
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), context.nft_id)
+    verify {
+        assert_eq!(false, Nfts::<T>::get(&context.nft_id).unwrap().is_locked);
+        assert_last_event::<T>(Event::<T>::NftUnlocked { nft_id: context.nft_id, by: context.nft_owner }.into());
+    }
+
+    signed_unlock_nft {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup_locked();
+        let proof = context.proof(
+            (SIGNED_UNLOCK_NFT_CONTEXT, &context.relayer, context.nft_id, 0u64).encode()
+        );
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), proof, context.nft_id)
+    verify {
+        assert_eq!(false, Nfts::<T>::get(&context.nft_id).unwrap().is_locked);
+        assert_last_event::<T>(Event::<T>::NftUnlocked { nft_id: context.nft_id, by: context.nft_owner }.into());
+    }
+
+    burn_nft {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup().setup_attributes();
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), context.nft_id)
+    verify {
+        assert_eq!(false, Nfts::<T>::contains_key(&context.nft_id));
+        assert_eq!(true, BurnedNfts::<T>::get(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftBurned {
+            nft_id: context.nft_id,
+            owner: context.nft_owner,
+            external_ref_released: T::ReleaseExternalRefOnBurn::get(),
+        }.into());
+    }
+
+    signed_burn_nft {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup().setup_attributes();
+        let proof = context.proof(
+            (SIGNED_BURN_NFT_CONTEXT, &context.relayer, context.nft_id, 0u64).encode()
+        );
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), proof, context.nft_id)
+    verify {
+        assert_eq!(false, Nfts::<T>::contains_key(&context.nft_id));
+        assert_eq!(true, BurnedNfts::<T>::get(&context.nft_id));
+        assert_last_event::<T>(Event::<T>::NftBurned {
+            nft_id: context.nft_id,
+            owner: context.nft_owner,
+            external_ref_released: T::ReleaseExternalRefOnBurn::get(),
+        }.into());
+    }
+
+    set_attribute {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup();
+        let target = AttributeTarget::Nft(context.nft_id);
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()),
+        target,
+        attribute_key().to_vec(),
+        attribute_value().to_vec()
+    )
+    verify {
+        assert_eq!(Some(attribute_value()), Attributes::<T>::get(&target, attribute_key()));
+        assert_last_event::<T>(Event::<T>::AttributeSet { target, key: attribute_key(), value: attribute_value() }.into());
+    }
+
+    signed_set_attribute {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup();
+        let target = AttributeTarget::Nft(context.nft_id);
+        let proof = context.proof((
+            SIGNED_SET_ATTRIBUTE_CONTEXT,
+            &context.relayer,
+            target,
+            attribute_key().to_vec(),
+            attribute_value().to_vec(),
+            0u64
+        ).encode());
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()),
+        proof,
+        target,
+        attribute_key().to_vec(),
+        attribute_value().to_vec()
+    )
+    verify {
+        assert_eq!(Some(attribute_value()), Attributes::<T>::get(&target, attribute_key()));
+        assert_last_event::<T>(Event::<T>::AttributeSet { target, key: attribute_key(), value: attribute_value() }.into());
+    }
+
+    clear_attribute {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup();
+        let target = AttributeTarget::Nft(context.nft_id);
+        nft_lifecycle::set_attribute::<T>(target, attribute_key(), attribute_value())?;
+    }: _(RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()), target, attribute_key().to_vec())
+    verify {
+        assert_eq!(None, Attributes::<T>::get(&target, attribute_key()));
+        assert_last_event::<T>(Event::<T>::AttributeCleared { target, key: attribute_key() }.into());
+    }
+
+    signed_clear_attribute {
+        let context: NftLifecycle<T> = NftLifecycle::new().setup();
+        let target = AttributeTarget::Nft(context.nft_id);
+        nft_lifecycle::set_attribute::<T>(target, attribute_key(), attribute_value())?;
+        let proof = context.proof(
+            (SIGNED_CLEAR_ATTRIBUTE_CONTEXT, &context.relayer, target, attribute_key().to_vec(), 0u64).encode()
+        );
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.nft_owner.clone()),
+        proof,
+        target,
+        attribute_key().to_vec()
+    )
+    verify {
+        assert_eq!(None, Attributes::<T>::get(&target, attribute_key()));
+        assert_last_event::<T>(Event::<T>::AttributeCleared { target, key: attribute_key() }.into());
+    }
+
+    set_batch_allowlist {
+        let context: AllowlistMint<T> = AllowlistMint::new(MAX_ALLOWLIST_PROOF_LENGTH);
+    }: _(RawOrigin::<T::AccountId>::Signed(context.account.clone()), context.batch_id, context.allowlist.clone())
+    verify {
+        assert_eq!(Some(context.allowlist), BatchAllowlists::<T>::get(context.batch_id));
+    }
+
+    signed_set_batch_allowlist {
+        let context: AllowlistMint<T> = AllowlistMint::new(MAX_ALLOWLIST_PROOF_LENGTH);
+        let nonce = <BatchNonces<T>>::get(&context.account);
+        let proof = context.proof(
+            (SIGNED_SET_BATCH_ALLOWLIST_CONTEXT, &context.relayer, context.batch_id, &context.allowlist, nonce).encode()
+        );
+    }: _(RawOrigin::<T::AccountId>::Signed(context.account.clone()), proof, context.batch_id, context.allowlist.clone())
+    verify {
+        assert_eq!(Some(context.allowlist), BatchAllowlists::<T>::get(context.batch_id));
+    }
+
+    mint_from_allowlist {
+        let p in 1 .. MAX_ALLOWLIST_PROOF_LENGTH;
+        let context: AllowlistMint<T> = AllowlistMint::new(p).setup();
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.account.clone()),
+        context.batch_id,
+        context.unique_external_ref.to_vec(),
+        context.merkle_proof.clone()
+    )
+    verify {
+        assert_eq!(Some(context.account.clone()), Nfts::<T>::get(&context.minted_nft_id()).map(|nft| nft.owner));
+        assert_eq!(1, AllowlistMintCount::<T>::get(context.batch_id, &context.account));
+    }
+
+    signed_mint_from_allowlist {
+        let p in 1 .. MAX_ALLOWLIST_PROOF_LENGTH;
+        let context: AllowlistMint<T> = AllowlistMint::new(p).setup();
+        let nonce = <BatchNonces<T>>::get(&context.account);
+        let proof = context.proof((
+            SIGNED_MINT_FROM_ALLOWLIST_CONTEXT,
+            &context.relayer,
+            context.batch_id,
+            context.unique_external_ref.to_vec(),
+            &context.merkle_proof,
+            nonce
+        ).encode());
+    }: _(
+        RawOrigin::<T::AccountId>::Signed(context.account.clone()),
+        proof,
+        context.batch_id,
+        context.unique_external_ref.to_vec(),
+        context.merkle_proof.clone()
+    )
+    verify {
+        assert_eq!(Some(context.account.clone()), Nfts::<T>::get(&context.minted_nft_id()).map(|nft| nft.owner));
+        assert_eq!(nonce + 1, <BatchNonces<T>>::get(&context.account));
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -50,6 +50,30 @@ pub trait WeightInfo {
 	fn proxy_signed_mint_batch_nft() -> Weight;
 	fn proxy_signed_list_batch_for_sale() -> Weight;
 	fn proxy_signed_end_batch_sale() -> Weight;
+	fn lock_nft() -> Weight;
+	fn signed_lock_nft() -> Weight;
+	fn unlock_nft() -> Weight;
+	fn signed_unlock_nft() -> Weight;
+	fn burn_nft() -> Weight;
+	fn signed_burn_nft() -> Weight;
+	fn set_attribute() -> Weight;
+	fn signed_set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn signed_clear_attribute() -> Weight;
+	fn list_nft_for_native_sale() -> Weight;
+	fn cancel_native_listing() -> Weight;
+	fn buy_nft(r: u32, ) -> Weight;
+	fn make_offer() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn accept_offer(r: u32, ) -> Weight;
+	fn signed_transfer_fiat_nft_with_settlement(r: u32, ) -> Weight;
+	fn record_fiat_settlement() -> Weight;
+	fn claim_royalties() -> Weight;
+	fn claim_t1_royalties() -> Weight;
+	fn set_batch_allowlist() -> Weight;
+	fn signed_set_batch_allowlist() -> Weight;
+	fn mint_from_allowlist(p: u32, ) -> Weight;
+	fn signed_mint_from_allowlist(p: u32, ) -> Weight;
 }
 
 /// Weights for pallet_nft_manager using the Substrate node and recommended hardware.
@@ -142,6 +166,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `NftManager::Nfts` (r:1 w:1)
 	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FiatSettlements` (r:0 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn signed_cancel_list_fiat_nft() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `320`
@@ -149,7 +175,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 155_284_000 picoseconds.
 		Weight::from_parts(157_193_000, 4644)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
 	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
@@ -203,6 +229,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `NftManager::Nfts` (r:1 w:1)
 	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FiatSettlements` (r:0 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn proxy_signed_cancel_list_fiat_nft() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `320`
@@ -210,7 +238,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 164_614_000 picoseconds.
 		Weight::from_parts(165_823_000, 4644)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
 	/// Proof: `NftManager::BatchNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
@@ -289,6 +317,354 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	fn lock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 31_009_000 picoseconds.
+		Weight::from_parts(31_482_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_lock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `4644`
+		// Minimum execution time: 140_773_000 picoseconds.
+		Weight::from_parts(142_917_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	fn unlock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 30_738_000 picoseconds.
+		Weight::from_parts(31_207_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_unlock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `4644`
+		// Minimum execution time: 140_464_000 picoseconds.
+		Weight::from_parts(142_604_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:1)
+	/// Storage: `NftManager::Attributes` (r:64 w:64)
+	/// Storage: `NftManager::AttributesCount` (r:0 w:2)
+	/// Storage: `NftManager::BurnedNfts` (r:0 w:1)
+	/// Storage: `NftManager::UsedExternalReferences` (r:0 w:1)
+	fn burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3216`
+		//  Estimated: `168990`
+		// Minimum execution time: 70_315_000 picoseconds.
+		Weight::from_parts(71_386_000, 168990)
+			.saturating_add(T::DbWeight::get().reads(67_u64))
+			.saturating_add(T::DbWeight::get().writes(70_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:1)
+	/// Storage: `NftManager::Attributes` (r:64 w:64)
+	/// Storage: `NftManager::AttributesCount` (r:0 w:2)
+	/// Storage: `NftManager::BurnedNfts` (r:0 w:1)
+	/// Storage: `NftManager::UsedExternalReferences` (r:0 w:1)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3272`
+		//  Estimated: `168990`
+		// Minimum execution time: 180_276_000 picoseconds.
+		Weight::from_parts(183_022_000, 168990)
+			.saturating_add(T::DbWeight::get().reads(68_u64))
+			.saturating_add(T::DbWeight::get().writes(71_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	fn set_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 36_357_000 picoseconds.
+		Weight::from_parts(36_911_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_set_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `4644`
+		// Minimum execution time: 147_141_000 picoseconds.
+		Weight::from_parts(149_382_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `4644`
+		// Minimum execution time: 35_107_000 picoseconds.
+		Weight::from_parts(35_642_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_clear_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `787`
+		//  Estimated: `4644`
+		// Minimum execution time: 145_730_000 picoseconds.
+		Weight::from_parts(147_950_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:0 w:1)
+	fn list_nft_for_native_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `4644`
+		// Minimum execution time: 40_631_000 picoseconds.
+		Weight::from_parts(41_250_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	fn cancel_native_listing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 43_211_000 picoseconds.
+		Weight::from_parts(43_870_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	/// Storage: `NftManager::PendingRoyalties` (r:16 w:16)
+	/// The range of component `r` is `[1, 16]`.
+	fn buy_nft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4644`
+		// Minimum execution time: 96_933_000 picoseconds.
+		Weight::from_parts(98_410_000, 4644)
+			.saturating_add(Weight::from_parts(21_370_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `NftManager::NativeOffers` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn make_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_986_000 picoseconds.
+		Weight::from_parts(62_930_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NativeOffers` (r:1 w:1)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `6196`
+		// Minimum execution time: 53_347_000 picoseconds.
+		Weight::from_parts(54_160_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NativeOffers` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	/// Storage: `NftManager::PendingRoyalties` (r:16 w:16)
+	/// The range of component `r` is `[1, 16]`.
+	fn accept_offer(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `6196`
+		// Minimum execution time: 95_801_000 picoseconds.
+		Weight::from_parts(97_260_000, 6196)
+			.saturating_add(Weight::from_parts(18_940_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	/// Storage: `NftManager::RoyaltyBalances` (r:16 w:16)
+	/// Storage: `NftManager::FiatSettlements` (r:1 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn signed_transfer_fiat_nft_with_settlement(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `4644`
+		// Minimum execution time: 174_806_000 picoseconds.
+		Weight::from_parts(177_412_000, 4644)
+			.saturating_add(Weight::from_parts(20_480_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FiatSettlements` (r:0 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn record_fiat_settlement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `4644`
+		// Minimum execution time: 24_917_000 picoseconds.
+		Weight::from_parts(25_630_000, 4644)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::RoyaltyBalances` (r:1 w:1)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `446`
+		//  Estimated: `6196`
+		// Minimum execution time: 51_682_000 picoseconds.
+		Weight::from_parts(52_470_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::RoyaltyClaimNonces` (r:1 w:1)
+	/// Storage: `NftManager::RoyaltyBalances` (r:1 w:1)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn claim_t1_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `6196`
+		// Minimum execution time: 103_287_000 picoseconds.
+		Weight::from_parts(104_860_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::BatchAllowlists` (r:0 w:1)
+	fn set_batch_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `3617`
+		// Minimum execution time: 23_945_000 picoseconds.
+		Weight::from_parts(24_310_000, 3617)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Storage: `NftManager::BatchAllowlists` (r:0 w:1)
+	fn signed_set_batch_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418`
+		//  Estimated: `3617`
+		// Minimum execution time: 70_762_000 picoseconds.
+		Weight::from_parts(71_840_000, 3617)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::BatchAllowlists` (r:1 w:0)
+	/// Storage: `NftManager::BatchOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::AllowlistMintCount` (r:1 w:1)
+	/// Storage: `NftManager::NftBatches` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::BurnedNfts` (r:1 w:0)
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// The range of component `p` is `[1, 32]`.
+	fn mint_from_allowlist(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `589`
+		//  Estimated: `3854`
+		// Minimum execution time: 60_350_000 picoseconds.
+		Weight::from_parts(61_270_000, 3854)
+			.saturating_add(Weight::from_parts(1_710_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Storage: `NftManager::BatchAllowlists` (r:1 w:0)
+	/// Storage: `NftManager::BatchOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::AllowlistMintCount` (r:1 w:1)
+	/// Storage: `NftManager::NftBatches` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::BurnedNfts` (r:1 w:0)
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// The range of component `p` is `[1, 32]`.
+	fn signed_mint_from_allowlist(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `655`
+		//  Estimated: `3854`
+		// Minimum execution time: 107_887_000 picoseconds.
+		Weight::from_parts(109_530_000, 3854)
+			.saturating_add(Weight::from_parts(1_760_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -380,6 +756,8 @@ impl WeightInfo for () {
 	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `NftManager::Nfts` (r:1 w:1)
 	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FiatSettlements` (r:0 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn signed_cancel_list_fiat_nft() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `320`
@@ -387,7 +765,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 155_284_000 picoseconds.
 		Weight::from_parts(157_193_000, 4644)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
 	/// Proof: `NftManager::UsedExternalReferences` (`max_values`: None, `max_size`: Some(1043), added: 3518, mode: `MaxEncodedLen`)
@@ -441,6 +819,8 @@ impl WeightInfo for () {
 	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `NftManager::Nfts` (r:1 w:1)
 	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FiatSettlements` (r:0 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	fn proxy_signed_cancel_list_fiat_nft() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `320`
@@ -448,7 +828,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 164_614_000 picoseconds.
 		Weight::from_parts(165_823_000, 4644)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
 	/// Proof: `NftManager::BatchNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
@@ -527,4 +907,352 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	fn lock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 31_009_000 picoseconds.
+		Weight::from_parts(31_482_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_lock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `4644`
+		// Minimum execution time: 140_773_000 picoseconds.
+		Weight::from_parts(142_917_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	fn unlock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 30_738_000 picoseconds.
+		Weight::from_parts(31_207_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_unlock_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `4644`
+		// Minimum execution time: 140_464_000 picoseconds.
+		Weight::from_parts(142_604_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:1)
+	/// Storage: `NftManager::Attributes` (r:64 w:64)
+	/// Storage: `NftManager::AttributesCount` (r:0 w:2)
+	/// Storage: `NftManager::BurnedNfts` (r:0 w:1)
+	/// Storage: `NftManager::UsedExternalReferences` (r:0 w:1)
+	fn burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3216`
+		//  Estimated: `168990`
+		// Minimum execution time: 70_315_000 picoseconds.
+		Weight::from_parts(71_386_000, 168990)
+			.saturating_add(RocksDbWeight::get().reads(67_u64))
+			.saturating_add(RocksDbWeight::get().writes(70_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:1)
+	/// Storage: `NftManager::Attributes` (r:64 w:64)
+	/// Storage: `NftManager::AttributesCount` (r:0 w:2)
+	/// Storage: `NftManager::BurnedNfts` (r:0 w:1)
+	/// Storage: `NftManager::UsedExternalReferences` (r:0 w:1)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_burn_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3272`
+		//  Estimated: `168990`
+		// Minimum execution time: 180_276_000 picoseconds.
+		Weight::from_parts(183_022_000, 168990)
+			.saturating_add(RocksDbWeight::get().reads(68_u64))
+			.saturating_add(RocksDbWeight::get().writes(71_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	fn set_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 36_357_000 picoseconds.
+		Weight::from_parts(36_911_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_set_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `4644`
+		// Minimum execution time: 147_141_000 picoseconds.
+		Weight::from_parts(149_382_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `4644`
+		// Minimum execution time: 35_107_000 picoseconds.
+		Weight::from_parts(35_642_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::Attributes` (r:1 w:1)
+	/// Storage: `NftManager::AttributesCount` (r:1 w:1)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	fn signed_clear_attribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `787`
+		//  Estimated: `4644`
+		// Minimum execution time: 145_730_000 picoseconds.
+		Weight::from_parts(147_950_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:0 w:1)
+	fn list_nft_for_native_sale() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `4644`
+		// Minimum execution time: 40_631_000 picoseconds.
+		Weight::from_parts(41_250_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	fn cancel_native_listing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4644`
+		// Minimum execution time: 43_211_000 picoseconds.
+		Weight::from_parts(43_870_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	/// Storage: `NftManager::PendingRoyalties` (r:16 w:16)
+	/// The range of component `r` is `[1, 16]`.
+	fn buy_nft(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640`
+		//  Estimated: `4644`
+		// Minimum execution time: 96_933_000 picoseconds.
+		Weight::from_parts(98_410_000, 4644)
+			.saturating_add(Weight::from_parts(21_370_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `NftManager::NativeOffers` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn make_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `6196`
+		// Minimum execution time: 61_986_000 picoseconds.
+		Weight::from_parts(62_930_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NativeOffers` (r:1 w:1)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn cancel_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `531`
+		//  Estimated: `6196`
+		// Minimum execution time: 53_347_000 picoseconds.
+		Weight::from_parts(54_160_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::NativeOffers` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::NativeListings` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	/// Storage: `NftManager::PendingRoyalties` (r:16 w:16)
+	/// The range of component `r` is `[1, 16]`.
+	fn accept_offer(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `6196`
+		// Minimum execution time: 95_801_000 picoseconds.
+		Weight::from_parts(97_260_000, 6196)
+			.saturating_add(Weight::from_parts(18_940_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	/// Storage: `NftManager::RoyaltyBalances` (r:16 w:16)
+	/// Storage: `NftManager::FiatSettlements` (r:1 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 16]`.
+	fn signed_transfer_fiat_nft_with_settlement(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `4644`
+		// Minimum execution time: 174_806_000 picoseconds.
+		Weight::from_parts(177_412_000, 4644)
+			.saturating_add(Weight::from_parts(20_480_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `NftManager::NftOpenForSale` (r:1 w:0)
+	/// Proof: `NftManager::NftOpenForSale` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::Nfts` (r:1 w:0)
+	/// Proof: `NftManager::Nfts` (`max_values`: None, `max_size`: Some(1179), added: 3654, mode: `MaxEncodedLen`)
+	/// Storage: `NftManager::FiatSettlements` (r:0 w:1)
+	/// Proof: `NftManager::FiatSettlements` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	fn record_fiat_settlement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `320`
+		//  Estimated: `4644`
+		// Minimum execution time: 24_917_000 picoseconds.
+		Weight::from_parts(25_630_000, 4644)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::RoyaltyBalances` (r:1 w:1)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn claim_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `446`
+		//  Estimated: `6196`
+		// Minimum execution time: 51_682_000 picoseconds.
+		Weight::from_parts(52_470_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `NftManager::RoyaltyClaimNonces` (r:1 w:1)
+	/// Storage: `NftManager::RoyaltyBalances` (r:1 w:1)
+	/// Storage: `TokenManager::Balances` (r:2 w:2)
+	fn claim_t1_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `6196`
+		// Minimum execution time: 103_287_000 picoseconds.
+		Weight::from_parts(104_860_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::BatchAllowlists` (r:0 w:1)
+	fn set_batch_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352`
+		//  Estimated: `3617`
+		// Minimum execution time: 23_945_000 picoseconds.
+		Weight::from_parts(24_310_000, 3617)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Storage: `NftManager::BatchAllowlists` (r:0 w:1)
+	fn signed_set_batch_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418`
+		//  Estimated: `3617`
+		// Minimum execution time: 70_762_000 picoseconds.
+		Weight::from_parts(71_840_000, 3617)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NftManager::BatchAllowlists` (r:1 w:0)
+	/// Storage: `NftManager::BatchOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::AllowlistMintCount` (r:1 w:1)
+	/// Storage: `NftManager::NftBatches` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::BurnedNfts` (r:1 w:0)
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// The range of component `p` is `[1, 32]`.
+	fn mint_from_allowlist(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `589`
+		//  Estimated: `3854`
+		// Minimum execution time: 60_350_000 picoseconds.
+		Weight::from_parts(61_270_000, 3854)
+			.saturating_add(Weight::from_parts(1_710_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `NftManager::BatchNonces` (r:1 w:1)
+	/// Storage: `NftManager::BatchAllowlists` (r:1 w:0)
+	/// Storage: `NftManager::BatchOpenForSale` (r:1 w:0)
+	/// Storage: `NftManager::AllowlistMintCount` (r:1 w:1)
+	/// Storage: `NftManager::NftBatches` (r:1 w:1)
+	/// Storage: `NftManager::Nfts` (r:1 w:1)
+	/// Storage: `NftManager::BurnedNfts` (r:1 w:0)
+	/// Storage: `NftManager::BatchInfoId` (r:1 w:0)
+	/// Storage: `NftManager::NftInfos` (r:1 w:0)
+	/// Storage: `NftManager::UsedExternalReferences` (r:1 w:1)
+	/// The range of component `p` is `[1, 32]`.
+	fn signed_mint_from_allowlist(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `655`
+		//  Estimated: `3854`
+		// Minimum execution time: 107_887_000 picoseconds.
+		Weight::from_parts(109_530_000, 3854)
+			.saturating_add(Weight::from_parts(1_760_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
\ No newline at end of file
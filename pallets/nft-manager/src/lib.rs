@@ -28,23 +28,23 @@ use frame_support::{
     pallet_prelude::StorageVersion,
     traits::{Get, IsSubType},
     weights::Weight,
-    Parameter,
+    PalletId, Parameter,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
 use pallet_avn::{self as avn, BridgeInterfaceNotification, ProcessedEventsChecker};
 use sp_avn_common::{
     event_types::{
         EthEvent, EthEventId, EventData, NftCancelListingData, NftEndBatchListingData,
         NftTransferToData, ProcessedEventHandler,
     },
-    verify_signature, CallDecoder, InnerCallValidator, Proof,
+    verify_signature, CallDecoder, InnerCallValidator, PaymentHandler, Proof,
 };
-use sp_core::{ConstU32, H160, H256, U256};
+use sp_core::{ecdsa, ConstU32, H160, H256, U256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{
     scale_info::TypeInfo,
-    traits::{Dispatchable, Hash, IdentifyAccount, Member, Verify},
-    BoundedVec,
+    traits::{AtLeast32BitUnsigned, Dispatchable, Hash, IdentifyAccount, Member, Verify},
+    BoundedVec, DispatchError,
 };
 use sp_std::prelude::*;
 
@@ -56,6 +56,19 @@ use crate::nft_data::*;
 pub mod batch_nft;
 use crate::batch_nft::*;
 
+pub mod nft_lifecycle;
+use crate::nft_lifecycle::*;
+
+pub mod native_sale;
+
+pub mod batch_allowlist;
+use crate::batch_allowlist::*;
+
+pub mod royalties;
+use crate::royalties::*;
+
+pub mod migration;
+
 pub mod default_weights;
 pub use default_weights::WeightInfo;
 
@@ -72,11 +85,20 @@ pub const SIGNED_CANCEL_LIST_FIAT_NFT_CONTEXT: &'static [u8] =
     b"authorization for cancel list fiat nft for sale operation";
 pub const SIGNED_MINT_BATCH_NFT_CONTEXT: &'static [u8] =
     b"authorization for mint batch nft operation";
+pub const SIGNED_TRANSFER_FIAT_NFT_WITH_SETTLEMENT_CONTEXT: &'static [u8] =
+    b"authorization for transfer fiat nft with settlement operation";
 
 const MAX_NUMBER_OF_ROYALTIES: u32 = 16;
 /// Bound used for number of Royalties an NFTs that can have
 pub(crate) type NftRoyaltiesBound = ConstU32<MAX_NUMBER_OF_ROYALTIES>;
 
+/// Maximum number of attributes that can be attached to a single NftInfo or Nft
+pub const MAX_NUMBER_OF_ATTRIBUTES: u32 = 32;
+
+/// Maximum number of hashes in the Merkle proof of an allowlist entry
+pub const MAX_ALLOWLIST_PROOF_LENGTH: u32 = 32;
+pub type AllowlistProofBound = ConstU32<MAX_ALLOWLIST_PROOF_LENGTH>;
+
 pub type NftId = U256;
 pub type NftInfoId = U256;
 pub type NftBatchId = U256;
@@ -85,6 +107,17 @@ pub type NftUniqueId = U256;
 /// Suggested bound to use in runtime for number of NFTs that can exist in a single Batch
 pub type BatchNftBound = ConstU32<16384>;
 
+pub type NativeListingOf<T> = NativeListing<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::Token,
+    <T as Config>::TokenBalance,
+    BlockNumberFor<T>,
+>;
+pub type NativeOfferOf<T> =
+    NativeOffer<<T as Config>::Token, <T as Config>::TokenBalance, BlockNumberFor<T>>;
+pub type BatchAllowlistOf<T> = BatchAllowlist<BlockNumberFor<T>>;
+pub type FiatSettlementOf<T> = FiatSettlement<<T as Config>::Token, <T as Config>::TokenBalance>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -115,6 +148,37 @@ pub mod pallet {
 
         #[pallet::constant]
         type BatchBound: Get<u32>;
+
+        /// If true, burning an nft frees its external reference so it can be minted again
+        #[pallet::constant]
+        type ReleaseExternalRefOnBurn: Get<bool>;
+
+        /// The type of token identifier used by the native market
+        /// (a H160 because this is an Ethereum address)
+        type Token: Parameter + Default + Copy + From<H160> + Into<H160> + MaxEncodedLen;
+
+        /// The type of token balance used by the native market
+        type TokenBalance: Parameter
+            + Member
+            + AtLeast32BitUnsigned
+            + Default
+            + Copy
+            + MaxEncodedLen;
+
+        /// A handler to move tokens between buyers, sellers and the escrow account
+        type PaymentHandler: PaymentHandler<
+            AccountId = Self::AccountId,
+            Token = Self::Token,
+            TokenBalance = Self::TokenBalance,
+            Error = DispatchError,
+        >;
+
+        /// Id used to derive the account holding offered funds and accrued royalties
+        #[pallet::constant]
+        type EscrowPalletId: Get<PalletId>;
+
+        /// Origin that records the amount fiat sales were settled for
+        type FiatSettlementOrigin: EnsureOrigin<Self::RuntimeOrigin>;
     }
 
     #[pallet::genesis_config]
@@ -212,6 +276,97 @@ pub mod pallet {
             batch_nft_id: NftBatchId,
             sale_type: NftSaleType,
         },
+        /// nft_id, account that locked the nft
+        NftLocked {
+            nft_id: NftId,
+            by: T::AccountId,
+        },
+        /// nft_id, account that unlocked the nft
+        NftUnlocked {
+            nft_id: NftId,
+            by: T::AccountId,
+        },
+        /// nft_id, owner, external_ref_released
+        NftBurned {
+            nft_id: NftId,
+            owner: T::AccountId,
+            external_ref_released: bool,
+        },
+        /// target, key, value
+        AttributeSet {
+            target: AttributeTarget,
+            key: NftAttributeKey,
+            value: NftAttributeValue,
+        },
+        /// target, key
+        AttributeCleared {
+            target: AttributeTarget,
+            key: NftAttributeKey,
+        },
+        /// nft_id, seller, token, price, expiry
+        NativeNftListed {
+            nft_id: NftId,
+            seller: T::AccountId,
+            token: T::Token,
+            price: T::TokenBalance,
+            expiry: Option<BlockNumberFor<T>>,
+        },
+        /// nft_id, op_id
+        CancelNativeNftListing {
+            nft_id: NftId,
+            op_id: u64,
+        },
+        /// nft_id, seller, buyer, token, price, op_id
+        NativeNftSold {
+            nft_id: NftId,
+            seller: T::AccountId,
+            buyer: T::AccountId,
+            token: T::Token,
+            price: T::TokenBalance,
+            op_id: u64,
+        },
+        /// nft_id, recipient, token, amount
+        RoyaltyAccrued {
+            nft_id: NftId,
+            recipient: RoyaltyRecipient,
+            token: T::Token,
+            amount: T::TokenBalance,
+        },
+        /// nft_id, token, amount, op_id
+        FiatSaleSettled {
+            nft_id: NftId,
+            token: T::Token,
+            amount: T::TokenBalance,
+            op_id: u64,
+        },
+        /// recipient, token, amount, destination
+        RoyaltiesClaimed {
+            recipient: RoyaltyRecipient,
+            token: T::Token,
+            amount: T::TokenBalance,
+            destination: T::AccountId,
+        },
+        /// nft_id, buyer, token, amount, expiry
+        OfferMade {
+            nft_id: NftId,
+            buyer: T::AccountId,
+            token: T::Token,
+            amount: T::TokenBalance,
+            expiry: Option<BlockNumberFor<T>>,
+        },
+        /// nft_id, buyer
+        OfferCancelled {
+            nft_id: NftId,
+            buyer: T::AccountId,
+        },
+        /// batch_id, root, mint_start, mint_end, max_per_account
+        BatchAllowlistSet {
+            batch_nft_id: NftBatchId,
+            root: H256,
+            mint_start: Option<BlockNumberFor<T>>,
+            mint_end: Option<BlockNumberFor<T>>,
+            max_per_account: Option<u32>,
+        },
     }
 
     #[pallet::error]
@@ -269,6 +424,66 @@ pub mod pallet {
         RoyaltiesOutOfBounds,
         /// Batch size is out of bounds
         BatchOutOfBounds,
+        /// Only the owner of the nft or the creator of its info can perform this operation
+        SenderIsNotOwnerOrCreator,
+        /// Only the creator of the info can perform this operation
+        SenderIsNotCreator,
+        NftIsNotLocked,
+        /// The nft is listed for sale and cannot be modified
+        NftIsListed,
+        /// The nft has been burned and cannot be minted again
+        NftAlreadyBurned,
+        UnauthorizedSignedLockNftTransaction,
+        UnauthorizedSignedUnlockNftTransaction,
+        UnauthorizedSignedBurnNftTransaction,
+        UnauthorizedSignedSetAttributeTransaction,
+        UnauthorizedSignedClearAttributeTransaction,
+        AttributeKeyIsMandatory,
+        /// Attribute key size is out of bounds
+        AttributeKeyOutOfBounds,
+        /// Attribute value size is out of bounds
+        AttributeValueOutOfBounds,
+        /// The maximum number of attributes for this target has been reached
+        TooManyAttributes,
+        AttributeNotFound,
+        PriceIsZero,
+        /// The expiry block is in the past
+        InvalidExpiry,
+        NftNotListedForNativeSale,
+        /// The token or price does not match the listing
+        PriceMismatch,
+        ListingExpired,
+        /// The owner of an nft cannot buy it or make an offer for it
+        BuyerIsOwner,
+        /// The buyer already has an open offer for this nft
+        OfferAlreadyExists,
+        OfferNotFound,
+        OfferExpired,
+        /// Nfts with royalties can only be transferred with a signed or recorded fiat sale
+        /// settlement
+        FiatSettlementRequired,
+        /// The token or amount does not match the recorded settlement
+        FiatSettlementMismatch,
+        UnauthorizedSignedTransferFiatNftWithSettlementTransaction,
+        /// The account cannot be used as a royalty recipient
+        InvalidRoyaltyRecipient,
+        NoRoyaltiesToClaim,
+        /// The claim was not signed by the T1 royalty recipient
+        UnauthorizedRoyaltyClaim,
+        UnauthorizedSignedSetBatchAllowlistTransaction,
+        UnauthorizedSignedMintFromAllowlistTransaction,
+        /// The mint window ends before it starts or has already ended
+        InvalidMintWindow,
+        AllowlistRootIsMandatory,
+        BatchHasNoAllowlist,
+        AllowlistMintNotStarted,
+        AllowlistMintEnded,
+        /// The account has minted the maximum number of nfts allowed from this batch
+        AllowlistMintLimitReached,
+        /// The entry is not part of the batch allowlist
+        InvalidAllowlistProof,
+        /// The sale index is reserved for allowlist mints
+        SaleIndexReserved,
     }
 
     /// A mapping between NFT Id and data
@@ -330,6 +545,97 @@ pub mod pallet {
     pub type BatchOpenForSale<T: Config> =
         StorageMap<_, Blake2_128Concat, NftBatchId, NftSaleType, ValueQuery>;
 
+    /// A mapping between an NFT Id and a flag to show that it has been burned
+    #[pallet::storage]
+    #[pallet::getter(fn is_nft_burned)]
+    pub type BurnedNfts<T: Config> = StorageMap<_, Blake2_128Concat, NftId, bool, ValueQuery>;
+
+    /// Key/value attributes attached to an NftInfo or an Nft
+    #[pallet::storage]
+    #[pallet::getter(fn attributes)]
+    pub type Attributes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AttributeTarget,
+        Blake2_128Concat,
+        NftAttributeKey,
+        NftAttributeValue,
+        OptionQuery,
+    >;
+
+    /// The number of attributes attached to an NftInfo or an Nft
+    #[pallet::storage]
+    #[pallet::getter(fn attributes_count)]
+    pub type AttributesCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, AttributeTarget, u32, ValueQuery>;
+
+    /// The terms of nfts listed for sale on the native market
+    #[pallet::storage]
+    #[pallet::getter(fn native_listings)]
+    pub type NativeListings<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftId, NativeListingOf<T>, OptionQuery>;
+
+    /// Open offers per nft and buyer. The offered amounts are held by the escrow account
+    #[pallet::storage]
+    #[pallet::getter(fn native_offers)]
+    pub type NativeOffers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        NftId,
+        Blake2_128Concat,
+        T::AccountId,
+        NativeOfferOf<T>,
+        OptionQuery,
+    >;
+
+    /// Royalties accrued by each recipient on T2 sales, held by the escrow account
+    #[pallet::storage]
+    #[pallet::getter(fn royalty_balances)]
+    pub type RoyaltyBalances<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RoyaltyRecipient,
+        Blake2_128Concat,
+        T::Token,
+        T::TokenBalance,
+        ValueQuery,
+    >;
+
+    /// A nonce per T1 royalty recipient that protects its claim signatures from replays
+    #[pallet::storage]
+    #[pallet::getter(fn royalty_claim_nonce)]
+    pub type RoyaltyClaimNonces<T: Config> = StorageMap<_, Blake2_128Concat, H160, u64, ValueQuery>;
+
+    /// The settlement recorded for nfts sold on the fiat market, used to pay their royalties
+    #[pallet::storage]
+    #[pallet::getter(fn fiat_settlements)]
+    pub type FiatSettlements<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftId, FiatSettlementOf<T>, OptionQuery>;
+
+    /// The allowlist recipients can mint a batch from
+    #[pallet::storage]
+    #[pallet::getter(fn batch_allowlists)]
+    pub type BatchAllowlists<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftBatchId, BatchAllowlistOf<T>, OptionQuery>;
+
+    /// The number of nfts each account has minted from a batch allowlist
+    #[pallet::storage]
+    #[pallet::getter(fn allowlist_mint_count)]
+    pub type AllowlistMintCount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        NftBatchId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// The number of nfts minted from a batch allowlist, used to pick the next reserved sale index
+    #[pallet::storage]
+    pub type AllowlistMinted<T: Config> =
+        StorageMap<_, Blake2_128Concat, NftBatchId, u64, ValueQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Mint a single NFT
@@ -404,9 +710,16 @@ pub mod pallet {
                 &royalties,
                 &t1_authority,
             );
+            let legacy_payload = legacy_royalties(&royalties).map(|legacy| {
+                Self::encode_mint_single_nft_params(
+                    &proof,
+                    &unique_external_ref,
+                    &legacy,
+                    &t1_authority,
+                )
+            });
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                royalty_payload_is_signed::<T>(&proof, &signed_payload, legacy_payload),
                 Error::<T>::UnauthorizedSignedMintSingleNftTransaction
             );
 
@@ -476,9 +789,11 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Transfer a nft open for sale on fiat market to a new owner by a relayer
+        /// Transfer a nft open for sale on fiat market to a new owner by a relayer. The royalties
+        /// of the nft are paid from the seller's balance on the recorded fiat settlement.
         #[pallet::call_index(4)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft()
+            .max(<T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft_with_settlement(MAX_NUMBER_OF_ROYALTIES)))]
         pub fn signed_transfer_fiat_nft(
             origin: OriginFor<T>,
             proof: Proof<T::Signature, T::AccountId>,
@@ -502,6 +817,18 @@ pub mod pallet {
                 Error::<T>::UnauthorizedSignedTransferFiatNftTransaction
             );
 
+            if has_royalties::<T>(&nft.info_id) {
+                let settlement = take_recorded_fiat_settlement::<T>(&nft)
+                    .ok_or(Error::<T>::FiatSettlementRequired)?;
+                pay_royalties::<T>(
+                    &sender,
+                    &nft_id,
+                    &nft.info_id,
+                    &settlement.token,
+                    &settlement.amount,
+                )?;
+            }
+
             let new_nft_owner = T::AccountId::decode(&mut t2_transfer_to_public_key.as_bytes())
                 .expect("32 bytes will always decode into an AccountId");
             let market = Self::get_nft_open_for_sale_on(nft_id);
@@ -541,6 +868,7 @@ pub mod pallet {
             let market = Self::get_nft_open_for_sale_on(nft_id);
 
             Self::unlist_nft_for_sale(nft_id)?;
+            <FiatSettlements<T>>::remove(nft_id);
             Self::deposit_event(Event::<T>::CancelSingleFiatNftListing {
                 nft_id,
                 sale_type: market,
@@ -559,7 +887,11 @@ pub mod pallet {
         #[pallet::weight(<T as pallet::Config>::WeightInfo::proxy_signed_list_nft_open_for_sale()
             .max(<T as pallet::Config>::WeightInfo::proxy_signed_mint_single_nft(MAX_NUMBER_OF_ROYALTIES))
             .max(<T as pallet::Config>::WeightInfo::proxy_signed_transfer_fiat_nft())
-            .max(<T as pallet::Config>::WeightInfo::proxy_signed_cancel_list_fiat_nft()))]
+            .max(<T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft_with_settlement(MAX_NUMBER_OF_ROYALTIES))
+            .max(<T as pallet::Config>::WeightInfo::proxy_signed_cancel_list_fiat_nft())
+            .max(<T as pallet::Config>::WeightInfo::signed_burn_nft())
+            .max(<T as pallet::Config>::WeightInfo::signed_set_attribute())
+            .max(<T as pallet::Config>::WeightInfo::signed_mint_from_allowlist(MAX_ALLOWLIST_PROOF_LENGTH)))]
         pub fn proxy(
             origin: OriginFor<T>,
             call: Box<<T as Config>::RuntimeCall>,
@@ -598,16 +930,24 @@ pub mod pallet {
             Self::validate_royalties(&royalties)?;
 
             let sender_nonce = Self::batch_nonce(&sender);
-            let signed_payload = encode_create_batch_params::<T>(
+            let signed_payload = encode_create_batch_params::<T, _>(
                 &proof,
                 &royalties,
                 &t1_authority,
                 &total_supply,
                 &sender_nonce,
             );
+            let legacy_payload = legacy_royalties(&royalties).map(|legacy| {
+                encode_create_batch_params::<T, _>(
+                    &proof,
+                    &legacy,
+                    &t1_authority,
+                    &total_supply,
+                    &sender_nonce,
+                )
+            });
             ensure!(
-                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
-                    .is_ok(),
+                royalty_payload_is_signed::<T>(&proof, &signed_payload, legacy_payload),
                 Error::<T>::UnauthorizedSignedCreateBatchTransaction
             );
 
@@ -653,6 +993,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            ensure!(is_allowlist_sale_index(index) == false, Error::<T>::SaleIndexReserved);
 
             let bounded_unique_external_ref =
                 BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
@@ -696,6 +1037,7 @@ pub mod pallet {
             ensure!(batch_id.is_zero() == false, Error::<T>::BatchIdIsMandatory);
             ensure!(<BatchInfoId<T>>::contains_key(&batch_id), Error::<T>::BatchDoesNotExist);
             ensure!(market != NftSaleType::Unknown, Error::<T>::UnsupportedMarket);
+            ensure!(market != NftSaleType::Native, Error::<T>::UnsupportedMarket);
 
             let sender_nonce = Self::batch_nonce(&sender);
             let nft_info = get_nft_info_for_batch::<T>(&batch_id)?;
@@ -763,6 +1105,534 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Lock an nft so it cannot be transferred, listed or burned.
+        /// Can be called by the owner of the nft or the creator of its info.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::lock_nft())]
+        pub fn lock_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            validate_lock_request::<T>(&sender, &nft_id, true)?;
+            set_lock::<T>(sender, nft_id, true);
+
+            Ok(())
+        }
+
+        /// Lock an nft by a relayer
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_lock_nft())]
+        pub fn signed_lock_nft(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nft_id: NftId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            validate_lock_request::<T>(&sender, &nft_id, true)?;
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload = encode_lock_nft_params::<T>(&proof, &nft_id, &sender_nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedLockNftTransaction
+            );
+
+            set_lock::<T>(sender.clone(), nft_id, true);
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Unlock a previously locked nft.
+        /// Can be called by the owner of the nft or the creator of its info.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unlock_nft())]
+        pub fn unlock_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            validate_lock_request::<T>(&sender, &nft_id, false)?;
+            set_lock::<T>(sender, nft_id, false);
+
+            Ok(())
+        }
+
+        /// Unlock an nft by a relayer
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_unlock_nft())]
+        pub fn signed_unlock_nft(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nft_id: NftId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            validate_lock_request::<T>(&sender, &nft_id, false)?;
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload = encode_unlock_nft_params::<T>(&proof, &nft_id, &sender_nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedUnlockNftTransaction
+            );
+
+            set_lock::<T>(sender.clone(), nft_id, false);
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Burn an nft. Only the owner can burn an nft and it must not be locked or listed.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::burn_nft())]
+        pub fn burn_nft(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let nft = validate_burn_request::<T>(&sender, &nft_id)?;
+            nft_lifecycle::burn_nft::<T>(nft);
+
+            Ok(())
+        }
+
+        /// Burn an nft by a relayer
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_burn_nft())]
+        pub fn signed_burn_nft(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nft_id: NftId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            let nft = validate_burn_request::<T>(&sender, &nft_id)?;
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload = encode_burn_nft_params::<T>(&proof, &nft_id, &sender_nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedBurnNftTransaction
+            );
+
+            nft_lifecycle::burn_nft::<T>(nft);
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Set an attribute on an NftInfo (by its creator) or an Nft (by its owner or creator).
+        /// An existing value for the same key is overwritten.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_attribute())]
+        pub fn set_attribute(
+            origin: OriginFor<T>,
+            target: AttributeTarget,
+            key: Vec<u8>,
+            value: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let (bounded_key, bounded_value) = Self::bound_attribute(key, value)?;
+            validate_attribute_request::<T>(&sender, &target)?;
+            nft_lifecycle::set_attribute::<T>(target, bounded_key, bounded_value)?;
+
+            Ok(())
+        }
+
+        /// Set an attribute by a relayer
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_set_attribute())]
+        pub fn signed_set_attribute(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            target: AttributeTarget,
+            key: Vec<u8>,
+            value: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            validate_attribute_request::<T>(&sender, &target)?;
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload =
+                encode_set_attribute_params::<T>(&proof, &target, &key, &value, &sender_nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedSetAttributeTransaction
+            );
+
+            let (bounded_key, bounded_value) = Self::bound_attribute(key, value)?;
+            nft_lifecycle::set_attribute::<T>(target, bounded_key, bounded_value)?;
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Remove an attribute from an NftInfo or an Nft
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::clear_attribute())]
+        pub fn clear_attribute(
+            origin: OriginFor<T>,
+            target: AttributeTarget,
+            key: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let bounded_key =
+                NftAttributeKey::try_from(key).map_err(|_| Error::<T>::AttributeKeyOutOfBounds)?;
+            validate_attribute_request::<T>(&sender, &target)?;
+            nft_lifecycle::clear_attribute::<T>(target, bounded_key)?;
+
+            Ok(())
+        }
+
+        /// Remove an attribute by a relayer
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_clear_attribute())]
+        pub fn signed_clear_attribute(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            target: AttributeTarget,
+            key: Vec<u8>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            validate_attribute_request::<T>(&sender, &target)?;
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload =
+                encode_clear_attribute_params::<T>(&proof, &target, &key, &sender_nonce);
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedClearAttributeTransaction
+            );
+
+            let bounded_key =
+                NftAttributeKey::try_from(key).map_err(|_| Error::<T>::AttributeKeyOutOfBounds)?;
+            nft_lifecycle::clear_attribute::<T>(target, bounded_key)?;
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// List an nft for sale on the native market at a fixed price.
+        /// An expiry of None means the listing stays open until it is cancelled.
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::list_nft_for_native_sale())]
+        pub fn list_nft_for_native_sale(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            token: T::Token,
+            price: T::TokenBalance,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            native_sale::validate_native_listing_request::<T>(&sender, &nft_id, &price, &expiry)?;
+            native_sale::list_for_native_sale::<T>(
+                nft_id,
+                NativeListing { seller: sender, token, price, expiry },
+            );
+
+            Ok(())
+        }
+
+        /// Cancel a native listing. Expired listings can be cancelled by anyone.
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_native_listing())]
+        pub fn cancel_native_listing(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            native_sale::cancel_native_listing::<T>(&sender, nft_id)
+        }
+
+        /// Buy an nft listed on the native market. `token` and `price` must match the listing.
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::buy_nft(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn buy_nft(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            token: T::Token,
+            price: T::TokenBalance,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            native_sale::buy_nft::<T>(sender, nft_id, token, price)
+        }
+
+        /// Offer to buy an nft. The offered amount is moved to escrow until the offer is accepted
+        /// or cancelled.
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::make_offer())]
+        pub fn make_offer(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            token: T::Token,
+            amount: T::TokenBalance,
+            expiry: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            native_sale::make_offer::<T>(sender, nft_id, token, amount, expiry)
+        }
+
+        /// Cancel an offer and return the escrowed amount to the buyer
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_offer())]
+        pub fn cancel_offer(origin: OriginFor<T>, nft_id: NftId) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            native_sale::cancel_offer::<T>(sender, nft_id)
+        }
+
+        /// Accept an offer made by `buyer`. Can only be called by the owner of the nft.
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::accept_offer(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn accept_offer(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            native_sale::accept_offer::<T>(sender, nft_id, buyer)
+        }
+
+        /// Transfer a nft open for sale on fiat market by a relayer, paying the royalties due on
+        /// the fiat settlement signed by the seller from the seller's `token` balance. If a
+        /// settlement was recorded for the listing, the signed one must match it.
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft_with_settlement(MAX_NUMBER_OF_ROYALTIES))]
+        pub fn signed_transfer_fiat_nft_with_settlement(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            nft_id: NftId,
+            t2_transfer_to_public_key: H256,
+            token: T::Token,
+            settlement_amount: T::TokenBalance,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            ensure!(
+                t2_transfer_to_public_key.is_zero() == false,
+                Error::<T>::TransferToIsMandatory
+            );
+            Self::validate_nft_open_for_fiat_sale(sender.clone(), nft_id)?;
+
+            let nft = Self::try_get_nft(&nft_id)?;
+            let signed_payload = encode_transfer_fiat_nft_with_settlement_params::<T>(
+                &proof,
+                &nft_id,
+                &t2_transfer_to_public_key,
+                &token,
+                &settlement_amount,
+                nft.nonce,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedTransferFiatNftWithSettlementTransaction
+            );
+
+            // The settlement signed by the seller is used, unless one was recorded for the listing
+            if let Some(settlement) = take_recorded_fiat_settlement::<T>(&nft) {
+                ensure!(
+                    settlement.token == token && settlement.amount == settlement_amount,
+                    Error::<T>::FiatSettlementMismatch
+                );
+            }
+
+            // The buyer paid the seller in fiat, so the seller pays the royalties on T2
+            pay_royalties::<T>(&sender, &nft_id, &nft.info_id, &token, &settlement_amount)?;
+
+            let new_nft_owner = T::AccountId::decode(&mut t2_transfer_to_public_key.as_bytes())
+                .expect("32 bytes will always decode into an AccountId");
+            let market = Self::get_nft_open_for_sale_on(nft_id);
+
+            Self::transfer_nft(&nft_id, &new_nft_owner)?;
+            Self::deposit_event(Event::<T>::FiatNftTransfer {
+                nft_id,
+                sender,
+                new_owner: new_nft_owner,
+                sale_type: market,
+                op_id: nft.nonce,
+            });
+
+            Ok(())
+        }
+
+        /// Claim the royalties in `token` owed to the sender
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_royalties())]
+        pub fn claim_royalties(origin: OriginFor<T>, token: T::Token) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let recipient = t2_recipient::<T>(&sender)?;
+            royalties::claim_royalties::<T>(recipient, token, sender)
+        }
+
+        /// Claim the royalties in `token` owed to a T1 address. The claim must be signed by the
+        /// T1 address and the royalties are paid to the sender.
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::claim_t1_royalties())]
+        pub fn claim_t1_royalties(
+            origin: OriginFor<T>,
+            t1_recipient: H160,
+            token: T::Token,
+            signature: ecdsa::Signature,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            verify_t1_claim_signature::<T>(&t1_recipient, &token, &sender, &signature)?;
+
+            royalties::claim_royalties::<T>(RoyaltyRecipient::T1(t1_recipient), token, sender)?;
+            <RoyaltyClaimNonces<T>>::mutate(&t1_recipient, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Publish the allowlist recipients can mint the batch from. Replaces any existing
+        /// allowlist. Can only be called by the batch creator.
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::set_batch_allowlist())]
+        pub fn set_batch_allowlist(
+            origin: OriginFor<T>,
+            batch_id: NftBatchId,
+            allowlist: BatchAllowlistOf<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            validate_set_batch_allowlist_request::<T>(&sender, &batch_id, &allowlist)?;
+            batch_allowlist::set_batch_allowlist::<T>(batch_id, allowlist);
+
+            Ok(())
+        }
+
+        /// Publish a batch allowlist by a relayer
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_set_batch_allowlist())]
+        pub fn signed_set_batch_allowlist(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            batch_id: NftBatchId,
+            allowlist: BatchAllowlistOf<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+            validate_set_batch_allowlist_request::<T>(&sender, &batch_id, &allowlist)?;
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload = encode_set_batch_allowlist_params::<T>(
+                &proof,
+                &batch_id,
+                &allowlist,
+                &sender_nonce,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedSetBatchAllowlistTransaction
+            );
+
+            batch_allowlist::set_batch_allowlist::<T>(batch_id, allowlist);
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Mint the allowlist entry of the sender from a batch listed for fiat sale
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::mint_from_allowlist(merkle_proof.len() as u32))]
+        pub fn mint_from_allowlist(
+            origin: OriginFor<T>,
+            batch_id: NftBatchId,
+            unique_external_ref: Vec<u8>,
+            merkle_proof: BoundedVec<H256, AllowlistProofBound>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            let bounded_unique_external_ref =
+                BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
+                    .map_err(|_| Error::<T>::ExternalRefOutOfBounds)?;
+            validate_mint_from_allowlist_request::<T>(
+                &sender,
+                &batch_id,
+                &bounded_unique_external_ref,
+                &merkle_proof,
+            )?;
+
+            batch_allowlist::mint_from_allowlist::<T>(sender, batch_id, bounded_unique_external_ref)
+        }
+
+        /// Mint an allowlist entry by a relayer. The proof must be signed by the recipient of the
+        /// entry.
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::signed_mint_from_allowlist(merkle_proof.len() as u32))]
+        pub fn signed_mint_from_allowlist(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            batch_id: NftBatchId,
+            unique_external_ref: Vec<u8>,
+            merkle_proof: BoundedVec<H256, AllowlistProofBound>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(sender == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let sender_nonce = Self::batch_nonce(&sender);
+            let signed_payload = encode_mint_from_allowlist_params::<T>(
+                &proof,
+                &batch_id,
+                &unique_external_ref,
+                &merkle_proof,
+                &sender_nonce,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedMintFromAllowlistTransaction
+            );
+
+            let bounded_unique_external_ref =
+                BoundedVec::<u8, NftExternalRefBound>::try_from(unique_external_ref)
+                    .map_err(|_| Error::<T>::ExternalRefOutOfBounds)?;
+            validate_mint_from_allowlist_request::<T>(
+                &sender,
+                &batch_id,
+                &bounded_unique_external_ref,
+                &merkle_proof,
+            )?;
+
+            batch_allowlist::mint_from_allowlist::<T>(
+                sender.clone(),
+                batch_id,
+                bounded_unique_external_ref,
+            )?;
+            <BatchNonces<T>>::mutate(&sender, |n| *n += 1);
+
+            Ok(())
+        }
+
+        /// Record the amount the fiat sale of an nft was settled for. The royalties of the nft
+        /// are paid on this amount when it is transferred to the buyer, and a settlement signed by
+        /// the seller must match it.
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::record_fiat_settlement())]
+        pub fn record_fiat_settlement(
+            origin: OriginFor<T>,
+            nft_id: NftId,
+            token: T::Token,
+            amount: T::TokenBalance,
+        ) -> DispatchResult {
+            T::FiatSettlementOrigin::ensure_origin(origin)?;
+            ensure!(
+                Self::get_nft_open_for_sale_on(nft_id) == NftSaleType::Fiat,
+                Error::<T>::NftNotListedForFiatSale
+            );
+
+            let nft = Self::try_get_nft(&nft_id)?;
+            <FiatSettlements<T>>::insert(
+                nft_id,
+                FiatSettlement { token, amount, op_id: nft.nonce },
+            );
+
+            Self::deposit_event(Event::<T>::FiatSaleSettled {
+                nft_id,
+                token,
+                amount,
+                op_id: nft.nonce,
+            });
+
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
@@ -808,6 +1678,18 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    fn bound_attribute(
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(NftAttributeKey, NftAttributeValue), Error<T>> {
+        let bounded_key =
+            NftAttributeKey::try_from(key).map_err(|_| Error::<T>::AttributeKeyOutOfBounds)?;
+        let bounded_value = NftAttributeValue::try_from(value)
+            .map_err(|_| Error::<T>::AttributeValueOutOfBounds)?;
+
+        Ok((bounded_key, bounded_value))
+    }
+
     fn validate_royalties(royalties: &Vec<Royalty>) -> DispatchResult {
         // TODO: Review this comment https://github.com/Aventus-Network-Services/avn-tier2/pull/763#discussion_r617360380
         let invalid_rates_found = royalties.iter().any(|r| !r.rate.is_valid());
@@ -826,6 +1708,8 @@ impl<T: Config> Pallet<T> {
         market: NftSaleType,
     ) -> DispatchResult {
         ensure!(market != NftSaleType::Unknown, Error::<T>::UnsupportedMarket);
+        // Native listings need a price, see `list_nft_for_native_sale`
+        ensure!(market != NftSaleType::Native, Error::<T>::UnsupportedMarket);
         ensure!(<Nfts<T>>::contains_key(&nft_id) == true, Error::<T>::NftIdDoesNotExist);
         ensure!(<NftOpenForSale<T>>::contains_key(&nft_id) == false, Error::<T>::NftAlreadyListed);
 
@@ -916,6 +1800,7 @@ impl<T: Config> Pallet<T> {
         let nft = Self::try_get_nft(&data.nft_id)?;
 
         ensure!(data.op_id == nft.nonce, Error::<T>::NftNonceMismatch);
+        ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
         ensure!(
             T::ProcessedEventsChecker::processed_event_exists(event_id),
             Error::<T>::NoTier1EventForNftOperation
@@ -1004,9 +1889,33 @@ impl<T: Config> Pallet<T> {
                     Call::signed_list_nft_open_for_sale { .. } =>
                         <T as pallet::Config>::WeightInfo::proxy_signed_list_nft_open_for_sale(),
                     Call::signed_transfer_fiat_nft { .. } =>
-                        <T as pallet::Config>::WeightInfo::proxy_signed_transfer_fiat_nft(),
+                        <T as pallet::Config>::WeightInfo::proxy_signed_transfer_fiat_nft().max(
+                            <T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft_with_settlement(
+                                MAX_NUMBER_OF_ROYALTIES,
+                            ),
+                        ),
+                    Call::signed_transfer_fiat_nft_with_settlement { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_transfer_fiat_nft_with_settlement(
+                            MAX_NUMBER_OF_ROYALTIES,
+                        ),
                     Call::signed_cancel_list_fiat_nft { .. } =>
                         <T as pallet::Config>::WeightInfo::proxy_signed_cancel_list_fiat_nft(),
+                    Call::signed_lock_nft { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_lock_nft(),
+                    Call::signed_unlock_nft { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_unlock_nft(),
+                    Call::signed_burn_nft { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_burn_nft(),
+                    Call::signed_set_attribute { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_set_attribute(),
+                    Call::signed_clear_attribute { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_clear_attribute(),
+                    Call::signed_set_batch_allowlist { .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_set_batch_allowlist(),
+                    Call::signed_mint_from_allowlist { merkle_proof, .. } =>
+                        <T as pallet::Config>::WeightInfo::signed_mint_from_allowlist(
+                            merkle_proof.len() as u32,
+                        ),
                     _ => <T as pallet::Config>::WeightInfo::proxy_signed_list_nft_open_for_sale()
                         .max(<T as pallet::Config>::WeightInfo::proxy_signed_mint_single_nft(
                             MAX_NUMBER_OF_ROYALTIES,
@@ -1024,10 +1933,10 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    fn encode_mint_single_nft_params(
+    fn encode_mint_single_nft_params<R: Encode>(
         proof: &Proof<T::Signature, T::AccountId>,
         unique_external_ref: &Vec<u8>,
-        royalties: &Vec<Royalty>,
+        royalties: &R,
         t1_authority: &H160,
     ) -> Vec<u8> {
         return (
@@ -1116,6 +2025,31 @@ impl<T: Config> Pallet<T> {
 
                 return Some((proof, encoded_data.expect("checked for none")))
             },
+            Call::signed_transfer_fiat_nft_with_settlement {
+                proof,
+                nft_id,
+                t2_transfer_to_public_key,
+                token,
+                settlement_amount,
+            } => {
+                let nft = Self::try_get_nft(nft_id);
+                if nft.is_err() {
+                    return None
+                }
+
+                let nonce = nft.expect("checked for none").nonce;
+                return Some((
+                    proof,
+                    encode_transfer_fiat_nft_with_settlement_params::<T>(
+                        proof,
+                        nft_id,
+                        t2_transfer_to_public_key,
+                        token,
+                        settlement_amount,
+                        nonce,
+                    ),
+                ))
+            },
             Call::signed_cancel_list_fiat_nft { proof, nft_id } => {
                 let encoded_data = Self::encode_cancel_list_fiat_nft_params(proof, nft_id);
                 if encoded_data.is_err() {
@@ -1128,7 +2062,7 @@ impl<T: Config> Pallet<T> {
                 let sender_nonce = Self::batch_nonce(&proof.signer);
                 return Some((
                     proof,
-                    encode_create_batch_params::<T>(
+                    encode_create_batch_params::<T, _>(
                         proof,
                         royalties,
                         t1_authority,
@@ -1162,10 +2096,99 @@ impl<T: Config> Pallet<T> {
                     encode_end_batch_sale_params::<T>(proof, batch_id, &sender_nonce),
                 ))
             },
+            Call::signed_lock_nft { proof, nft_id } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((proof, encode_lock_nft_params::<T>(proof, nft_id, &sender_nonce)))
+            },
+            Call::signed_unlock_nft { proof, nft_id } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((proof, encode_unlock_nft_params::<T>(proof, nft_id, &sender_nonce)))
+            },
+            Call::signed_burn_nft { proof, nft_id } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((proof, encode_burn_nft_params::<T>(proof, nft_id, &sender_nonce)))
+            },
+            Call::signed_set_attribute { proof, target, key, value } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((
+                    proof,
+                    encode_set_attribute_params::<T>(proof, target, key, value, &sender_nonce),
+                ))
+            },
+            Call::signed_clear_attribute { proof, target, key } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((
+                    proof,
+                    encode_clear_attribute_params::<T>(proof, target, key, &sender_nonce),
+                ))
+            },
+            Call::signed_set_batch_allowlist { proof, batch_id, allowlist } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((
+                    proof,
+                    encode_set_batch_allowlist_params::<T>(
+                        proof,
+                        batch_id,
+                        allowlist,
+                        &sender_nonce,
+                    ),
+                ))
+            },
+            Call::signed_mint_from_allowlist {
+                proof,
+                batch_id,
+                unique_external_ref,
+                merkle_proof,
+            } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                return Some((
+                    proof,
+                    encode_mint_from_allowlist_params::<T>(
+                        proof,
+                        batch_id,
+                        unique_external_ref,
+                        merkle_proof,
+                        &sender_nonce,
+                    ),
+                ))
+            },
             _ => return None,
         }
     }
 
+    /// The payload of calls that sign royalties, encoded as clients signed it before royalties
+    /// could be paid to T2 accounts
+    fn get_legacy_encoded_call_param(call: &<T as Config>::RuntimeCall) -> Option<Vec<u8>> {
+        match call.is_sub_type()? {
+            Call::signed_mint_single_nft {
+                proof,
+                unique_external_ref,
+                royalties,
+                t1_authority,
+            } => legacy_royalties(royalties).map(|legacy| {
+                Self::encode_mint_single_nft_params(
+                    proof,
+                    unique_external_ref,
+                    &legacy,
+                    t1_authority,
+                )
+            }),
+            Call::signed_create_batch { proof, total_supply, royalties, t1_authority } => {
+                let sender_nonce = Self::batch_nonce(&proof.signer);
+                legacy_royalties(royalties).map(|legacy| {
+                    encode_create_batch_params::<T, _>(
+                        proof,
+                        &legacy,
+                        t1_authority,
+                        total_supply,
+                        &sender_nonce,
+                    )
+                })
+            },
+            _ => None,
+        }
+    }
+
     fn try_get_nft(nft_id: &NftId) -> Result<Nft<T::AccountId>, Error<T>> {
         let maybe_nft = Self::nfts(nft_id);
 
@@ -1224,11 +2247,20 @@ impl<T: Config> CallDecoder for Pallet<T> {
             Call::signed_mint_single_nft { proof, .. } => return Ok(proof.clone()),
             Call::signed_list_nft_open_for_sale { proof, .. } => return Ok(proof.clone()),
             Call::signed_transfer_fiat_nft { proof, .. } => return Ok(proof.clone()),
+            Call::signed_transfer_fiat_nft_with_settlement { proof, .. } =>
+                return Ok(proof.clone()),
             Call::signed_cancel_list_fiat_nft { proof, .. } => return Ok(proof.clone()),
             Call::signed_create_batch { proof, .. } => return Ok(proof.clone()),
             Call::signed_mint_batch_nft { proof, .. } => return Ok(proof.clone()),
             Call::signed_list_batch_for_sale { proof, .. } => return Ok(proof.clone()),
             Call::signed_end_batch_sale { proof, .. } => return Ok(proof.clone()),
+            Call::signed_lock_nft { proof, .. } => return Ok(proof.clone()),
+            Call::signed_unlock_nft { proof, .. } => return Ok(proof.clone()),
+            Call::signed_burn_nft { proof, .. } => return Ok(proof.clone()),
+            Call::signed_set_attribute { proof, .. } => return Ok(proof.clone()),
+            Call::signed_clear_attribute { proof, .. } => return Ok(proof.clone()),
+            Call::signed_set_batch_allowlist { proof, .. } => return Ok(proof.clone()),
+            Call::signed_mint_from_allowlist { proof, .. } => return Ok(proof.clone()),
             _ => return Err(Error::TransactionNotSupported),
         }
     }
@@ -1239,18 +2271,18 @@ impl<T: Config> InnerCallValidator for Pallet<T> {
 
     fn signature_is_valid(call: &Box<Self::Call>) -> bool {
         if let Some((proof, signed_payload)) = Self::get_encoded_call_param(call) {
-            return verify_signature::<T::Signature, T::AccountId>(
+            return royalty_payload_is_signed::<T>(
                 &proof,
-                &signed_payload.as_slice(),
+                &signed_payload,
+                Self::get_legacy_encoded_call_param(call),
             )
-            .is_ok()
         }
 
         return false
     }
 }
 
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 #[cfg(test)]
 #[path = "tests/mock.rs"]
@@ -1292,4 +2324,20 @@ pub mod cancel_single_nft_listing_tests;
 #[path = "tests/batch_nft_tests.rs"]
 pub mod batch_nft_tests;
 
+#[cfg(test)]
+#[path = "tests/nft_lifecycle_tests.rs"]
+pub mod nft_lifecycle_tests;
+
+#[cfg(test)]
+#[path = "tests/native_sale_tests.rs"]
+pub mod native_sale_tests;
+
+#[cfg(test)]
+#[path = "tests/royalties_tests.rs"]
+pub mod royalties_tests;
+
+#[cfg(test)]
+#[path = "tests/batch_allowlist_tests.rs"]
+pub mod batch_allowlist_tests;
+
 mod benchmarking;
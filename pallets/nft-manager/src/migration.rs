@@ -0,0 +1,140 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Config, NftInfo, NftInfos, NftRoyaltiesBound, Pallet, Royalty, RoyaltyRate, RoyaltyRecipient,
+    STORAGE_VERSION,
+};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+    weights::Weight,
+    BoundedVec,
+};
+
+#[cfg(feature = "try-runtime")]
+use crate::Vec;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+mod v4 {
+    use crate::{NftBatchId, NftInfoId, NftRoyaltiesBound, RoyaltyRate, H160};
+    use frame_support::BoundedVec;
+
+    /// V4 type for [`crate::Royalty`]. Royalties could only be paid to T1 addresses.
+    #[derive(codec::Encode, codec::Decode, Clone, PartialEq, Debug)]
+    pub struct Royalty {
+        pub recipient_t1_address: H160,
+        pub rate: RoyaltyRate,
+    }
+
+    /// V4 type for [`crate::NftInfo`]
+    #[derive(codec::Encode, codec::Decode, Clone, PartialEq, Debug)]
+    pub struct NftInfo<AccountId> {
+        pub info_id: NftInfoId,
+        pub batch_id: Option<NftBatchId>,
+        pub royalties: BoundedVec<Royalty, NftRoyaltiesBound>,
+        pub total_supply: u64,
+        pub t1_authority: H160,
+        pub creator: Option<AccountId>,
+    }
+}
+
+fn translate_royalties(
+    v4_royalties: BoundedVec<v4::Royalty, NftRoyaltiesBound>,
+) -> BoundedVec<Royalty, NftRoyaltiesBound> {
+    let royalties = v4_royalties
+        .into_iter()
+        .map(|r| Royalty {
+            recipient: RoyaltyRecipient::T1(r.recipient_t1_address),
+            rate: RoyaltyRate { parts_per_million: r.rate.parts_per_million },
+        })
+        .collect::<crate::Vec<Royalty>>();
+
+    // Both vectors share the same bound so this cannot fail
+    return BoundedVec::truncate_from(royalties)
+}
+
+pub fn migrate_to_v5<T: Config>() -> Weight {
+    let mut consumed_weight: Weight = Weight::zero();
+    let mut add_weight = |reads, writes, weight: Weight| {
+        consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
+        consumed_weight += weight;
+    };
+
+    log::info!("🚧 🚧 Running migration to translate NftInfos royalties to royalty recipients");
+
+    <NftInfos<T>>::translate::<v4::NftInfo<T::AccountId>, _>(|_info_id, v4_info| {
+        add_weight(1, 1, Weight::zero());
+        Some(NftInfo {
+            info_id: v4_info.info_id,
+            batch_id: v4_info.batch_id,
+            royalties: translate_royalties(v4_info.royalties),
+            total_supply: v4_info.total_supply,
+            t1_authority: v4_info.t1_authority,
+            creator: v4_info.creator,
+        })
+    });
+
+    add_weight(0, 1, Weight::zero());
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    log::info!("✅ NftInfos royalties translated successfully");
+
+    // add a bit extra as safety margin for computation
+    return consumed_weight + Weight::from_parts(25_000_000 as u64, 0)
+}
+
+pub struct NftManagerMigrations<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for NftManagerMigrations<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::in_code_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        let mut total_weight = Weight::zero();
+
+        if onchain < 5 {
+            log::info!(
+                "💽 Running Nft manager migration with current storage version {:?} / onchain {:?}",
+                current,
+                onchain
+            );
+            total_weight += migrate_to_v5::<T>();
+        }
+
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        use codec::Encode;
+
+        Ok((<NftInfos<T>>::iter_keys().count() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        use codec::Decode;
+
+        let infos_before: u64 =
+            Decode::decode(&mut input.as_slice()).expect("Initial info count is invalid");
+        let infos_after = <NftInfos<T>>::iter_values().count() as u64;
+        assert_eq!(infos_before, infos_after);
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+
+        Ok(())
+    }
+}
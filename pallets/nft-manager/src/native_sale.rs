@@ -0,0 +1,237 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Native market: nfts are sold on T2 for AVT or any other token managed by token-manager.
+//! Buyers either pay the listed price directly or make an offer whose funds are held in escrow
+//! until the owner accepts it or the buyer cancels it.
+
+use crate::{
+    royalties::pay_royalties, Config, DispatchResult, Error, Event, Get, NativeListingOf,
+    NativeListings, NativeOfferOf, NativeOffers, Nft, NftId, NftOpenForSale, NftSaleType, Pallet,
+};
+use frame_support::{ensure, PalletId};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_avn_common::PaymentHandler;
+use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+/// The account that holds offered funds and accrued royalties
+pub fn escrow_account<T: Config>() -> T::AccountId {
+    let pallet_id: PalletId = T::EscrowPalletId::get();
+    pallet_id.into_account_truncating()
+}
+
+fn is_expired<T: Config>(expiry: &Option<BlockNumberFor<T>>) -> bool {
+    match expiry {
+        Some(expiry) => <frame_system::Pallet<T>>::block_number() > *expiry,
+        None => false,
+    }
+}
+
+fn validate_expiry<T: Config>(expiry: &Option<BlockNumberFor<T>>) -> DispatchResult {
+    ensure!(is_expired::<T>(expiry) == false, Error::<T>::InvalidExpiry);
+    Ok(())
+}
+
+pub fn validate_native_listing_request<T: Config>(
+    sender: &T::AccountId,
+    nft_id: &NftId,
+    price: &T::TokenBalance,
+    expiry: &Option<BlockNumberFor<T>>,
+) -> DispatchResult {
+    ensure!(price.is_zero() == false, Error::<T>::PriceIsZero);
+    validate_expiry::<T>(expiry)?;
+    ensure!(<NftOpenForSale<T>>::contains_key(nft_id) == false, Error::<T>::NftAlreadyListed);
+
+    let nft = Pallet::<T>::try_get_nft(nft_id)?;
+    ensure!(nft.owner == *sender, Error::<T>::SenderIsNotOwner);
+    ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+
+    Ok(())
+}
+
+pub fn list_for_native_sale<T: Config>(nft_id: NftId, listing: NativeListingOf<T>) {
+    Pallet::<T>::open_nft_for_sale(&nft_id, &NftSaleType::Native);
+    <NativeListings<T>>::insert(nft_id, &listing);
+
+    <Pallet<T>>::deposit_event(Event::<T>::NativeNftListed {
+        nft_id,
+        seller: listing.seller,
+        token: listing.token,
+        price: listing.price,
+        expiry: listing.expiry,
+    });
+}
+
+pub fn try_get_native_listing<T: Config>(nft_id: &NftId) -> Result<NativeListingOf<T>, Error<T>> {
+    ensure!(
+        <NftOpenForSale<T>>::get(nft_id) == NftSaleType::Native,
+        Error::<T>::NftNotListedForNativeSale
+    );
+    <NativeListings<T>>::get(nft_id).ok_or(Error::<T>::NftNotListedForNativeSale)
+}
+
+/// The seller can always cancel a listing. Anyone can clean up an expired listing.
+pub fn cancel_native_listing<T: Config>(sender: &T::AccountId, nft_id: NftId) -> DispatchResult {
+    let listing = try_get_native_listing::<T>(&nft_id)?;
+    ensure!(
+        listing.seller == *sender || is_expired::<T>(&listing.expiry),
+        Error::<T>::SenderIsNotOwner
+    );
+
+    let nft = Pallet::<T>::try_get_nft(&nft_id)?;
+    <NativeListings<T>>::remove(nft_id);
+    Pallet::<T>::unlist_nft_for_sale(nft_id)?;
+
+    <Pallet<T>>::deposit_event(Event::<T>::CancelNativeNftListing { nft_id, op_id: nft.nonce });
+
+    Ok(())
+}
+
+pub fn buy_nft<T: Config>(
+    buyer: T::AccountId,
+    nft_id: NftId,
+    token: T::Token,
+    price: T::TokenBalance,
+) -> DispatchResult {
+    let listing = try_get_native_listing::<T>(&nft_id)?;
+    // Protects the buyer from a price change between signing and execution
+    ensure!(listing.token == token && listing.price == price, Error::<T>::PriceMismatch);
+    ensure!(is_expired::<T>(&listing.expiry) == false, Error::<T>::ListingExpired);
+
+    let nft = Pallet::<T>::try_get_nft(&nft_id)?;
+    ensure!(nft.owner == listing.seller, Error::<T>::SenderIsNotOwner);
+    ensure!(nft.owner != buyer, Error::<T>::BuyerIsOwner);
+    ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+
+    pay_sale_proceeds::<T>(&buyer, &nft, &listing.token, &listing.price)?;
+
+    <NativeListings<T>>::remove(nft_id);
+    Pallet::<T>::transfer_nft(&nft_id, &buyer)?;
+
+    <Pallet<T>>::deposit_event(Event::<T>::NativeNftSold {
+        nft_id,
+        seller: nft.owner,
+        buyer,
+        token: listing.token,
+        price: listing.price,
+        op_id: nft.nonce,
+    });
+
+    Ok(())
+}
+
+pub fn make_offer<T: Config>(
+    buyer: T::AccountId,
+    nft_id: NftId,
+    token: T::Token,
+    amount: T::TokenBalance,
+    expiry: Option<BlockNumberFor<T>>,
+) -> DispatchResult {
+    ensure!(amount.is_zero() == false, Error::<T>::PriceIsZero);
+    validate_expiry::<T>(&expiry)?;
+    ensure!(
+        <NativeOffers<T>>::contains_key(&nft_id, &buyer) == false,
+        Error::<T>::OfferAlreadyExists
+    );
+
+    let nft = Pallet::<T>::try_get_nft(&nft_id)?;
+    ensure!(nft.owner != buyer, Error::<T>::BuyerIsOwner);
+    ensure_not_listed_on_other_market::<T>(&nft_id)?;
+
+    T::PaymentHandler::pay_recipient(&token, &amount, &buyer, &escrow_account::<T>())?;
+
+    let offer = NativeOfferOf::<T> { token, amount, expiry };
+    <NativeOffers<T>>::insert(&nft_id, &buyer, &offer);
+
+    <Pallet<T>>::deposit_event(Event::<T>::OfferMade {
+        nft_id,
+        buyer,
+        token: offer.token,
+        amount: offer.amount,
+        expiry: offer.expiry,
+    });
+
+    Ok(())
+}
+
+/// Returns the escrowed funds to the buyer. Works even if the nft has been burned since.
+pub fn cancel_offer<T: Config>(buyer: T::AccountId, nft_id: NftId) -> DispatchResult {
+    let offer = <NativeOffers<T>>::get(&nft_id, &buyer).ok_or(Error::<T>::OfferNotFound)?;
+
+    T::PaymentHandler::pay_recipient(&offer.token, &offer.amount, &escrow_account::<T>(), &buyer)?;
+    <NativeOffers<T>>::remove(&nft_id, &buyer);
+
+    <Pallet<T>>::deposit_event(Event::<T>::OfferCancelled { nft_id, buyer });
+
+    Ok(())
+}
+
+pub fn accept_offer<T: Config>(
+    seller: T::AccountId,
+    nft_id: NftId,
+    buyer: T::AccountId,
+) -> DispatchResult {
+    let offer = <NativeOffers<T>>::get(&nft_id, &buyer).ok_or(Error::<T>::OfferNotFound)?;
+    ensure!(is_expired::<T>(&offer.expiry) == false, Error::<T>::OfferExpired);
+
+    let nft = Pallet::<T>::try_get_nft(&nft_id)?;
+    ensure!(nft.owner == seller, Error::<T>::SenderIsNotOwner);
+    ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+    ensure_not_listed_on_other_market::<T>(&nft_id)?;
+
+    pay_sale_proceeds::<T>(&escrow_account::<T>(), &nft, &offer.token, &offer.amount)?;
+    <NativeOffers<T>>::remove(&nft_id, &buyer);
+
+    // Accepting an offer supersedes any native listing of the nft
+    if <NativeListings<T>>::take(nft_id).is_some() {
+        <NftOpenForSale<T>>::remove(nft_id);
+    }
+    Pallet::<T>::update_owner_for_transfer(&nft_id, &buyer);
+
+    <Pallet<T>>::deposit_event(Event::<T>::NativeNftSold {
+        nft_id,
+        seller,
+        buyer,
+        token: offer.token,
+        price: offer.amount,
+        op_id: nft.nonce,
+    });
+
+    Ok(())
+}
+
+/// Nfts listed on Ethereum or for fiat are settled elsewhere, so they cannot be sold natively
+fn ensure_not_listed_on_other_market<T: Config>(nft_id: &NftId) -> DispatchResult {
+    let market = <NftOpenForSale<T>>::get(nft_id);
+    ensure!(
+        market == NftSaleType::Unknown || market == NftSaleType::Native,
+        Error::<T>::NftAlreadyListed
+    );
+    Ok(())
+}
+
+/// Pays the royalties of the nft out of `amount` and sends the remainder to the owner of the nft.
+pub fn pay_sale_proceeds<T: Config>(
+    payer: &T::AccountId,
+    nft: &Nft<T::AccountId>,
+    token: &T::Token,
+    amount: &T::TokenBalance,
+) -> DispatchResult {
+    let royalties_paid = pay_royalties::<T>(payer, &nft.nft_id, &nft.info_id, token, amount)?;
+    let remainder = amount.saturating_sub(royalties_paid);
+
+    T::PaymentHandler::pay_recipient(token, &remainder, payer, &nft.owner)
+}
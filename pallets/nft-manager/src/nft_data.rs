@@ -21,14 +21,46 @@ use sp_runtime::traits::Member;
 pub(crate) use sp_avn_common::bounds::NftExternalRefBound;
 pub const ROYALTY_RATE_DENOMINATOR: u32 = 1_000_000;
 
+/// Bound used for the key of an NFT attribute
+pub type NftAttributeKeyBound = ConstU32<64>;
+/// Bound used for the value of an NFT attribute
+pub type NftAttributeValueBound = ConstU32<256>;
+
+pub type NftAttributeKey = BoundedVec<u8, NftAttributeKeyBound>;
+pub type NftAttributeValue = BoundedVec<u8, NftAttributeValueBound>;
+
 #[derive(
     Encode, Decode, Default, Debug, Clone, PartialEq, MaxEncodedLen, TypeInfo, DecodeWithMemTracking,
 )]
 pub struct Royalty {
-    pub recipient_t1_address: H160,
+    pub recipient: RoyaltyRecipient,
     pub rate: RoyaltyRate,
 }
 
+/// The account royalties are owed to. T2 accounts are identified by their public key.
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    TypeInfo,
+    DecodeWithMemTracking,
+)]
+pub enum RoyaltyRecipient {
+    T1(H160),
+    T2(H256),
+}
+
+impl Default for RoyaltyRecipient {
+    fn default() -> Self {
+        return RoyaltyRecipient::T1(H160::zero())
+    }
+}
+
 /// Royalty Rate Examples:
 ///     - 1%: { parts_per_million = 10000 }
 ///     - 0.03%: { parts_per_million = 300 }
@@ -151,6 +183,7 @@ pub enum NftSaleType {
     Unknown, // value used by Default interface. Needed for Maps default value.
     Ethereum,
     Fiat,
+    Native,
 }
 
 impl Default for NftSaleType {
@@ -158,3 +191,71 @@ impl Default for NftSaleType {
         return NftSaleType::Unknown
     }
 }
+
+/// Terms of an nft listed for sale on the native market
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct NativeListing<AccountId, Token, Balance, BlockNumber> {
+    /// The owner of the nft at the time of listing
+    pub seller: AccountId,
+    /// The token the price is expressed in
+    pub token: Token,
+    pub price: Balance,
+    /// The last block the nft can be bought at. None means the listing does not expire
+    pub expiry: Option<BlockNumber>,
+}
+
+/// The amount a fiat sale was settled for, as recorded by the settlement origin
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct FiatSettlement<Token, Balance> {
+    /// The token the royalties are paid in
+    pub token: Token,
+    pub amount: Balance,
+    /// The nft nonce when the sale was settled. The settlement is void once the nonce changes
+    pub op_id: u64,
+}
+
+/// An offer to buy an nft. The offered amount is held in escrow until the offer is accepted or
+/// cancelled
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct NativeOffer<Token, Balance, BlockNumber> {
+    pub token: Token,
+    pub amount: Balance,
+    /// The last block the offer can be accepted at. None means the offer does not expire
+    pub expiry: Option<BlockNumber>,
+}
+
+/// The item an attribute is attached to
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    MaxEncodedLen,
+    TypeInfo,
+    DecodeWithMemTracking,
+)]
+pub enum AttributeTarget {
+    /// Attributes shared by every NFT that references this info. Managed by the info creator.
+    NftInfo(NftInfoId),
+    /// Attributes of an individual NFT. Managed by the owner or the info creator.
+    Nft(NftId),
+}
+
+/// An allowlist published by a batch creator. Each leaf of the Merkle tree is the hash of a
+/// `(unique_external_ref, recipient)` pair that the recipient can mint from the batch.
+#[derive(
+    Encode, Decode, Clone, Debug, PartialEq, Eq, MaxEncodedLen, TypeInfo, DecodeWithMemTracking,
+)]
+pub struct BatchAllowlist<BlockNumber> {
+    /// Root of the Merkle tree of allowed entries
+    pub root: H256,
+    /// The first block entries can be minted at. None means minting is open immediately
+    pub mint_start: Option<BlockNumber>,
+    /// The last block entries can be minted at. None means minting does not close
+    pub mint_end: Option<BlockNumber>,
+    /// The maximum number of nfts a single account can mint. None means no limit
+    pub max_per_account: Option<u32>,
+}
@@ -0,0 +1,203 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AttributeTarget, Attributes, AttributesCount, BurnedNfts, Config, DispatchResult, Encode,
+    Error, Event, Get, Nft, NftAttributeKey, NftAttributeValue, NftId, NftInfos, NftOpenForSale,
+    Nfts, Pallet, Proof, UsedExternalReferences, Vec, MAX_NUMBER_OF_ATTRIBUTES,
+};
+use frame_support::ensure;
+
+pub const SIGNED_LOCK_NFT_CONTEXT: &'static [u8] = b"authorization for lock nft operation";
+pub const SIGNED_UNLOCK_NFT_CONTEXT: &'static [u8] = b"authorization for unlock nft operation";
+pub const SIGNED_BURN_NFT_CONTEXT: &'static [u8] = b"authorization for burn nft operation";
+pub const SIGNED_SET_ATTRIBUTE_CONTEXT: &'static [u8] =
+    b"authorization for set nft attribute operation";
+pub const SIGNED_CLEAR_ATTRIBUTE_CONTEXT: &'static [u8] =
+    b"authorization for clear nft attribute operation";
+
+pub fn encode_lock_nft_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    nft_id: &NftId,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (SIGNED_LOCK_NFT_CONTEXT, &proof.relayer, nft_id, nonce).encode()
+}
+
+pub fn encode_unlock_nft_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    nft_id: &NftId,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (SIGNED_UNLOCK_NFT_CONTEXT, &proof.relayer, nft_id, nonce).encode()
+}
+
+pub fn encode_burn_nft_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    nft_id: &NftId,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (SIGNED_BURN_NFT_CONTEXT, &proof.relayer, nft_id, nonce).encode()
+}
+
+pub fn encode_set_attribute_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    target: &AttributeTarget,
+    key: &Vec<u8>,
+    value: &Vec<u8>,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (SIGNED_SET_ATTRIBUTE_CONTEXT, &proof.relayer, target, key, value, nonce).encode()
+}
+
+pub fn encode_clear_attribute_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    target: &AttributeTarget,
+    key: &Vec<u8>,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (SIGNED_CLEAR_ATTRIBUTE_CONTEXT, &proof.relayer, target, key, nonce).encode()
+}
+
+/// Returns true if `account` owns the nft or created the info the nft belongs to
+pub fn is_owner_or_creator<T: Config>(account: &T::AccountId, nft: &Nft<T::AccountId>) -> bool {
+    if nft.owner == *account {
+        return true
+    }
+
+    return <NftInfos<T>>::get(nft.info_id)
+        .map_or(false, |info| info.creator.as_ref() == Some(account))
+}
+
+pub fn validate_lock_request<T: Config>(
+    sender: &T::AccountId,
+    nft_id: &NftId,
+    lock: bool,
+) -> DispatchResult {
+    let nft = Pallet::<T>::try_get_nft(nft_id)?;
+    ensure!(is_owner_or_creator::<T>(sender, &nft), Error::<T>::SenderIsNotOwnerOrCreator);
+    // A listed nft can be sold on Ethereum at any time so it cannot change its lock status
+    ensure!(<NftOpenForSale<T>>::contains_key(nft_id) == false, Error::<T>::NftIsListed);
+
+    if lock {
+        ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+    } else {
+        ensure!(nft.is_locked == true, Error::<T>::NftIsNotLocked);
+    }
+
+    Ok(())
+}
+
+pub fn set_lock<T: Config>(sender: T::AccountId, nft_id: NftId, lock: bool) {
+    <Nfts<T>>::mutate(nft_id, |maybe_nft| maybe_nft.as_mut().map(|nft| nft.is_locked = lock));
+
+    if lock {
+        <Pallet<T>>::deposit_event(Event::<T>::NftLocked { nft_id, by: sender });
+    } else {
+        <Pallet<T>>::deposit_event(Event::<T>::NftUnlocked { nft_id, by: sender });
+    }
+}
+
+pub fn validate_burn_request<T: Config>(
+    sender: &T::AccountId,
+    nft_id: &NftId,
+) -> Result<Nft<T::AccountId>, Error<T>> {
+    let nft = Pallet::<T>::try_get_nft(nft_id)?;
+    ensure!(nft.owner == *sender, Error::<T>::SenderIsNotOwner);
+    ensure!(nft.is_locked == false, Error::<T>::NftIsLocked);
+    ensure!(<NftOpenForSale<T>>::contains_key(nft_id) == false, Error::<T>::NftIsListed);
+
+    Ok(nft)
+}
+
+pub fn burn_nft<T: Config>(nft: Nft<T::AccountId>) {
+    <Nfts<T>>::remove(nft.nft_id);
+    <BurnedNfts<T>>::insert(nft.nft_id, true);
+    clear_all_attributes::<T>(&AttributeTarget::Nft(nft.nft_id));
+
+    // Infos of single nfts are not shared so they go away with the nft
+    if let Some(info) = <NftInfos<T>>::get(nft.info_id) {
+        if info.batch_id.is_none() {
+            <NftInfos<T>>::remove(nft.info_id);
+            clear_all_attributes::<T>(&AttributeTarget::NftInfo(nft.info_id));
+        }
+    }
+
+    let external_ref_released = T::ReleaseExternalRefOnBurn::get();
+    if external_ref_released {
+        <UsedExternalReferences<T>>::remove(&nft.unique_external_ref);
+    }
+
+    <Pallet<T>>::deposit_event(Event::<T>::NftBurned {
+        nft_id: nft.nft_id,
+        owner: nft.owner,
+        external_ref_released,
+    });
+}
+
+pub fn validate_attribute_request<T: Config>(
+    sender: &T::AccountId,
+    target: &AttributeTarget,
+) -> DispatchResult {
+    match target {
+        AttributeTarget::NftInfo(info_id) => {
+            let info = <NftInfos<T>>::get(info_id).ok_or(Error::<T>::NftInfoMissing)?;
+            ensure!(info.creator.as_ref() == Some(sender), Error::<T>::SenderIsNotCreator);
+        },
+        AttributeTarget::Nft(nft_id) => {
+            let nft = Pallet::<T>::try_get_nft(nft_id)?;
+            ensure!(is_owner_or_creator::<T>(sender, &nft), Error::<T>::SenderIsNotOwnerOrCreator);
+        },
+    }
+
+    Ok(())
+}
+
+pub fn set_attribute<T: Config>(
+    target: AttributeTarget,
+    key: NftAttributeKey,
+    value: NftAttributeValue,
+) -> DispatchResult {
+    ensure!(!key.is_empty(), Error::<T>::AttributeKeyIsMandatory);
+
+    if <Attributes<T>>::contains_key(&target, &key) == false {
+        let count = <AttributesCount<T>>::get(&target);
+        ensure!(count < MAX_NUMBER_OF_ATTRIBUTES, Error::<T>::TooManyAttributes);
+        <AttributesCount<T>>::insert(&target, count + 1);
+    }
+
+    <Attributes<T>>::insert(&target, &key, &value);
+    <Pallet<T>>::deposit_event(Event::<T>::AttributeSet { target, key, value });
+
+    Ok(())
+}
+
+pub fn clear_attribute<T: Config>(target: AttributeTarget, key: NftAttributeKey) -> DispatchResult {
+    ensure!(<Attributes<T>>::contains_key(&target, &key), Error::<T>::AttributeNotFound);
+
+    <Attributes<T>>::remove(&target, &key);
+    <AttributesCount<T>>::mutate_exists(&target, |maybe_count| {
+        *maybe_count = maybe_count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+    });
+    <Pallet<T>>::deposit_event(Event::<T>::AttributeCleared { target, key });
+
+    Ok(())
+}
+
+fn clear_all_attributes<T: Config>(target: &AttributeTarget) {
+    let _ = <Attributes<T>>::clear_prefix(target, MAX_NUMBER_OF_ATTRIBUTES, None);
+    <AttributesCount<T>>::remove(target);
+}
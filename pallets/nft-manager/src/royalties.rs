@@ -0,0 +1,205 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Royalties owed on T2 sales are moved to the escrow account and credited to the recipient in
+//! `RoyaltyBalances`. Recipients claim their balance later: T2 accounts directly and T1 addresses
+//! by signing the claim with their Ethereum key.
+
+use crate::{
+    native_sale::escrow_account, Config, DispatchResult, Encode, Error, Event, FiatSettlementOf,
+    FiatSettlements, Nft, NftId, NftInfoId, NftInfos, Pallet, Proof, Royalty, RoyaltyBalances,
+    RoyaltyClaimNonces, RoyaltyRate, RoyaltyRecipient, Vec, H160, H256,
+    SIGNED_TRANSFER_FIAT_NFT_WITH_SETTLEMENT_CONTEXT,
+};
+use frame_support::ensure;
+use sp_avn_common::{
+    recover_ethereum_address_from_ecdsa_signature, verify_signature, HashMessageFormat,
+    PaymentHandler,
+};
+use sp_core::ecdsa;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    DispatchError, Permill,
+};
+
+pub const CLAIM_T1_ROYALTIES_CONTEXT: &'static [u8] = b"authorization for claim nft royalties";
+
+pub fn encode_transfer_fiat_nft_with_settlement_params<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    nft_id: &NftId,
+    recipient: &H256,
+    token: &T::Token,
+    settlement_amount: &T::TokenBalance,
+    nonce: u64,
+) -> Vec<u8> {
+    return (
+        SIGNED_TRANSFER_FIAT_NFT_WITH_SETTLEMENT_CONTEXT,
+        &proof.relayer,
+        nft_id,
+        recipient,
+        token,
+        settlement_amount,
+        nonce,
+    )
+        .encode()
+}
+
+/// The message a T1 recipient signs to let `destination` claim its royalties
+pub fn encode_claim_t1_royalties_params<T: Config>(
+    t1_recipient: &H160,
+    token: &T::Token,
+    destination: &T::AccountId,
+    nonce: &u64,
+) -> Vec<u8> {
+    return (CLAIM_T1_ROYALTIES_CONTEXT, t1_recipient, token, destination, nonce).encode()
+}
+
+/// Encoding of a [`Royalty`] before royalties could be paid to T2 accounts
+#[derive(Encode)]
+pub struct LegacyRoyalty {
+    pub recipient_t1_address: H160,
+    pub rate: RoyaltyRate,
+}
+
+/// Royalties encoded as clients signed them before T2 recipients were supported. Returns `None`
+/// if a royalty is paid to a T2 account, which the legacy encoding cannot express.
+pub fn legacy_royalties(royalties: &Vec<Royalty>) -> Option<Vec<LegacyRoyalty>> {
+    royalties
+        .iter()
+        .map(|royalty| match royalty.recipient {
+            RoyaltyRecipient::T1(address) =>
+                Some(LegacyRoyalty { recipient_t1_address: address, rate: royalty.rate.clone() }),
+            RoyaltyRecipient::T2(_) => None,
+        })
+        .collect()
+}
+
+/// Checks the signature of a payload that contains royalties. Payloads signed with the legacy
+/// royalty encoding are accepted until clients have moved to the new one.
+pub fn royalty_payload_is_signed<T: Config>(
+    proof: &Proof<T::Signature, T::AccountId>,
+    signed_payload: &Vec<u8>,
+    legacy_payload: Option<Vec<u8>>,
+) -> bool {
+    if verify_signature::<T::Signature, T::AccountId>(proof, signed_payload.as_slice()).is_ok() {
+        return true
+    }
+
+    return legacy_payload.map_or(false, |payload| {
+        verify_signature::<T::Signature, T::AccountId>(proof, payload.as_slice()).is_ok()
+    })
+}
+
+/// Returns the royalty recipient that identifies a T2 account
+pub fn t2_recipient<T: Config>(account: &T::AccountId) -> Result<RoyaltyRecipient, Error<T>> {
+    let encoded_account = account.encode();
+    ensure!(encoded_account.len() == H256::len_bytes(), Error::<T>::InvalidRoyaltyRecipient);
+
+    return Ok(RoyaltyRecipient::T2(H256::from_slice(&encoded_account)))
+}
+
+pub fn has_royalties<T: Config>(info_id: &NftInfoId) -> bool {
+    return <NftInfos<T>>::get(info_id)
+        .map_or(false, |info| info.royalties.iter().any(|r| r.rate.parts_per_million > 0))
+}
+
+/// Takes the royalties due on a sale of `amount` from `payer`, holds them in escrow and credits
+/// each recipient. Returns the total amount of royalties taken.
+pub fn pay_royalties<T: Config>(
+    payer: &T::AccountId,
+    nft_id: &NftId,
+    info_id: &NftInfoId,
+    token: &T::Token,
+    amount: &T::TokenBalance,
+) -> Result<T::TokenBalance, DispatchError> {
+    let royalties = <NftInfos<T>>::get(info_id).map(|info| info.royalties).unwrap_or_default();
+    let escrow = escrow_account::<T>();
+    let mut total_paid = T::TokenBalance::zero();
+
+    for royalty in royalties {
+        let royalty_amount = Permill::from_parts(royalty.rate.parts_per_million).mul_floor(*amount);
+        if royalty_amount.is_zero() {
+            continue
+        }
+
+        if *payer != escrow {
+            T::PaymentHandler::pay_recipient(token, &royalty_amount, payer, &escrow)?;
+        }
+        <RoyaltyBalances<T>>::mutate(&royalty.recipient, token, |balance| {
+            *balance = balance.saturating_add(royalty_amount)
+        });
+        total_paid = total_paid.saturating_add(royalty_amount);
+
+        <Pallet<T>>::deposit_event(Event::<T>::RoyaltyAccrued {
+            nft_id: *nft_id,
+            recipient: royalty.recipient,
+            token: *token,
+            amount: royalty_amount,
+        });
+    }
+
+    Ok(total_paid)
+}
+
+/// Takes the fiat settlement recorded for the current listing of `nft`, if any
+pub fn take_recorded_fiat_settlement<T: Config>(
+    nft: &Nft<T::AccountId>,
+) -> Option<FiatSettlementOf<T>> {
+    return <FiatSettlements<T>>::take(nft.nft_id).filter(|settlement| settlement.op_id == nft.nonce)
+}
+
+pub fn verify_t1_claim_signature<T: Config>(
+    t1_recipient: &H160,
+    token: &T::Token,
+    destination: &T::AccountId,
+    signature: &ecdsa::Signature,
+) -> DispatchResult {
+    let nonce = <RoyaltyClaimNonces<T>>::get(t1_recipient);
+    let signed_payload =
+        encode_claim_t1_royalties_params::<T>(t1_recipient, token, destination, &nonce);
+
+    let signer = recover_ethereum_address_from_ecdsa_signature(
+        signature,
+        &signed_payload,
+        HashMessageFormat::String,
+    )
+    .map_err(|_| Error::<T>::UnauthorizedRoyaltyClaim)?;
+    ensure!(H160::from_slice(&signer) == *t1_recipient, Error::<T>::UnauthorizedRoyaltyClaim);
+
+    Ok(())
+}
+
+/// Pays the whole balance of `recipient` in `token` from escrow to `destination`
+pub fn claim_royalties<T: Config>(
+    recipient: RoyaltyRecipient,
+    token: T::Token,
+    destination: T::AccountId,
+) -> DispatchResult {
+    let amount = <RoyaltyBalances<T>>::get(&recipient, &token);
+    ensure!(amount.is_zero() == false, Error::<T>::NoRoyaltiesToClaim);
+
+    T::PaymentHandler::pay_recipient(&token, &amount, &escrow_account::<T>(), &destination)?;
+    <RoyaltyBalances<T>>::remove(&recipient, &token);
+
+    <Pallet<T>>::deposit_event(Event::<T>::RoyaltiesClaimed {
+        recipient,
+        token,
+        amount,
+        destination,
+    });
+
+    Ok(())
+}
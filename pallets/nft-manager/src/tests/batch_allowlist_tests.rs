@@ -0,0 +1,539 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+use super::*;
+use crate::mock::{
+    AccountId, RuntimeCall as MockCall, RuntimeEvent as Event, RuntimeOrigin as Origin, *,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_core::sr25519::Pair;
+
+const TOTAL_SUPPLY: u64 = 5;
+
+struct Context {
+    creator: AccountId,
+    creator_key_pair: Pair,
+    relayer: AccountId,
+    recipients: Vec<(AccountId, Pair)>,
+    batch_id: NftBatchId,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        let creator = TestAccount::new([1u8; 32]);
+        let recipients = (10u8..14)
+            .map(|seed| {
+                let account = TestAccount::new([seed; 32]);
+                (account.account_id(), account.key_pair())
+            })
+            .collect();
+
+        Context {
+            creator: creator.account_id(),
+            creator_key_pair: creator.key_pair(),
+            relayer: TestAccount::new([2u8; 32]).account_id(),
+            recipients,
+            batch_id: generate_batch_id::<TestRuntime>(U256::zero()),
+        }
+    }
+}
+
+impl Context {
+    fn setup(&self) {
+        create_batch::<TestRuntime>(
+            U256::zero(),
+            self.batch_id,
+            BoundedVec::default(),
+            TOTAL_SUPPLY,
+            H160(hex!("0000000000000000000000000000000000000001")),
+            self.creator,
+        );
+        <BatchOpenForSale<TestRuntime>>::insert(self.batch_id, NftSaleType::Fiat);
+    }
+
+    fn recipient(&self, index: usize) -> AccountId {
+        return self.recipients[index].0
+    }
+
+    fn external_ref(&self, index: usize) -> Vec<u8> {
+        return format!("Offchain location of NFT {}", index).into_bytes()
+    }
+
+    fn leaves(&self) -> Vec<H256> {
+        return (0..self.recipients.len())
+            .map(|i| allowlist_leaf::<TestRuntime>(&self.external_ref(i), &self.recipient(i)))
+            .collect()
+    }
+
+    /// Proof of entry `index` in a tree of four leaves
+    fn merkle_proof(&self, index: usize) -> BoundedVec<H256, AllowlistProofBound> {
+        let leaves = self.leaves();
+        let sibling = leaves[index ^ 1];
+        let other_pair = if index < 2 { [leaves[2], leaves[3]] } else { [leaves[0], leaves[1]] };
+        let other_node = compute_allowlist_root(other_pair[0], &[other_pair[1]]);
+
+        return BoundedVec::try_from(vec![sibling, other_node]).unwrap()
+    }
+
+    fn root(&self) -> H256 {
+        return compute_allowlist_root(self.leaves()[0], &self.merkle_proof(0))
+    }
+
+    fn allowlist(&self) -> BatchAllowlistOf<TestRuntime> {
+        return BatchAllowlist {
+            root: self.root(),
+            mint_start: None,
+            mint_end: None,
+            max_per_account: None,
+        }
+    }
+
+    fn set_allowlist(&self, allowlist: BatchAllowlistOf<TestRuntime>) {
+        assert_ok!(NftManager::set_batch_allowlist(
+            Origin::signed(self.creator),
+            self.batch_id,
+            allowlist
+        ));
+    }
+
+    fn mint(&self, index: usize) -> DispatchResult {
+        return NftManager::mint_from_allowlist(
+            Origin::signed(self.recipient(index)),
+            self.batch_id,
+            self.external_ref(index),
+            self.merkle_proof(index),
+        )
+    }
+
+    fn signed_mint_call(
+        &self,
+        index: usize,
+        nonce: u64,
+    ) -> Box<<TestRuntime as Config>::RuntimeCall> {
+        let (recipient, key_pair) = &self.recipients[index];
+        let unique_external_ref = self.external_ref(index);
+        let merkle_proof = self.merkle_proof(index);
+        let data_to_sign = (
+            SIGNED_MINT_FROM_ALLOWLIST_CONTEXT,
+            self.relayer,
+            self.batch_id,
+            &unique_external_ref,
+            &merkle_proof,
+            nonce,
+        );
+        let proof = Proof {
+            signer: *recipient,
+            relayer: self.relayer,
+            signature: sign(key_pair, &data_to_sign.encode()),
+        };
+
+        return Box::new(MockCall::NftManager(
+            super::Call::<TestRuntime>::signed_mint_from_allowlist {
+                proof,
+                batch_id: self.batch_id,
+                unique_external_ref,
+                merkle_proof,
+            },
+        ))
+    }
+
+    fn nft_owner(&self, sale_index: u64) -> Option<AccountId> {
+        let nft_id = generate_batch_nft_id::<TestRuntime>(&self.batch_id, &sale_index);
+        return NftManager::nfts(nft_id).map(|nft| nft.owner)
+    }
+
+    fn minted_nft_owner(&self, allowlist_mint: u64) -> Option<AccountId> {
+        return self.nft_owner(ALLOWLIST_SALE_INDEX_START + allowlist_mint)
+    }
+}
+
+mod set_batch_allowlist {
+    use super::*;
+
+    #[test]
+    fn succeeds_for_the_batch_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            let allowlist = BatchAllowlist { mint_end: Some(10), ..context.allowlist() };
+
+            context.set_allowlist(allowlist.clone());
+
+            assert_eq!(Some(allowlist), NftManager::batch_allowlists(context.batch_id));
+            System::assert_last_event(Event::NftManager(crate::Event::BatchAllowlistSet {
+                batch_nft_id: context.batch_id,
+                root: context.root(),
+                mint_start: None,
+                mint_end: Some(10),
+                max_per_account: None,
+            }));
+        });
+    }
+
+    #[test]
+    fn succeeds_when_signed_by_the_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            let allowlist = context.allowlist();
+            let data_to_sign = (
+                SIGNED_SET_BATCH_ALLOWLIST_CONTEXT,
+                context.relayer,
+                context.batch_id,
+                &allowlist,
+                0u64,
+            );
+            let proof = Proof {
+                signer: context.creator,
+                relayer: context.relayer,
+                signature: sign(&context.creator_key_pair, &data_to_sign.encode()),
+            };
+            let call = Box::new(MockCall::NftManager(
+                super::Call::<TestRuntime>::signed_set_batch_allowlist {
+                    proof,
+                    batch_id: context.batch_id,
+                    allowlist: allowlist.clone(),
+                },
+            ));
+
+            assert_ok!(NftManager::proxy(Origin::signed(context.relayer), call));
+
+            assert_eq!(Some(allowlist), NftManager::batch_allowlists(context.batch_id));
+            assert_eq!(1, NftManager::batch_nonce(context.creator));
+        });
+    }
+
+    #[test]
+    fn fails_when_sender_is_not_the_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::set_batch_allowlist(
+                    Origin::signed(context.recipient(0)),
+                    context.batch_id,
+                    context.allowlist()
+                ),
+                Error::<TestRuntime>::SenderIsNotBatchCreator
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_batch_does_not_exist() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+
+            assert_noop!(
+                NftManager::set_batch_allowlist(
+                    Origin::signed(context.creator),
+                    context.batch_id,
+                    context.allowlist()
+                ),
+                Error::<TestRuntime>::BatchDoesNotExist
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_mint_window_is_invalid() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            System::set_block_number(5);
+
+            for (mint_start, mint_end) in [(Some(9), Some(8)), (None, Some(4))] {
+                assert_noop!(
+                    NftManager::set_batch_allowlist(
+                        Origin::signed(context.creator),
+                        context.batch_id,
+                        BatchAllowlist { mint_start, mint_end, ..context.allowlist() }
+                    ),
+                    Error::<TestRuntime>::InvalidMintWindow
+                );
+            }
+        });
+    }
+}
+
+mod mint_from_allowlist {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_a_valid_proof() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+
+            assert_ok!(context.mint(2));
+            assert_ok!(context.mint(0));
+
+            assert_eq!(Some(context.recipient(2)), context.minted_nft_owner(0));
+            assert_eq!(Some(context.recipient(0)), context.minted_nft_owner(1));
+            assert_eq!(2, NftManager::nft_batches(context.batch_id).len());
+            assert_eq!(1, NftManager::allowlist_mint_count(context.batch_id, context.recipient(2)));
+        });
+    }
+
+    #[test]
+    fn succeeds_when_submitted_by_a_relayer() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+
+            assert_ok!(NftManager::proxy(
+                Origin::signed(context.relayer),
+                context.signed_mint_call(1, 0)
+            ));
+
+            assert_eq!(Some(context.recipient(1)), context.minted_nft_owner(0));
+            assert_eq!(1, NftManager::batch_nonce(context.recipient(1)));
+        });
+    }
+
+    #[test]
+    fn hashes_leaves_abi_packed() {
+        let context = Context::default();
+        let mut packed = context.external_ref(0);
+        packed.extend_from_slice(context.recipient(0).as_ref());
+
+        assert_eq!(
+            H256(keccak_256(&packed)),
+            allowlist_leaf::<TestRuntime>(&context.external_ref(0), &context.recipient(0))
+        );
+    }
+
+    #[test]
+    fn uses_sale_indexes_reserved_for_the_allowlist() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+            assert_ok!(mint_batch_nft::<TestRuntime>(
+                context.batch_id,
+                context.creator,
+                1,
+                BoundedVec::try_from(b"creator nft".to_vec()).unwrap(),
+            ));
+
+            assert_ok!(context.mint(0));
+            assert_ok!(context.mint(1));
+
+            assert_eq!(Some(context.creator), context.nft_owner(1));
+            assert_eq!(Some(context.recipient(0)), context.minted_nft_owner(0));
+            assert_eq!(Some(context.recipient(1)), context.minted_nft_owner(1));
+            assert_eq!(None, context.nft_owner(2));
+        });
+    }
+
+    #[test]
+    fn creator_cannot_mint_at_a_reserved_sale_index() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+            let unique_external_ref = b"creator nft".to_vec();
+            let data_to_sign = (
+                SIGNED_MINT_BATCH_NFT_CONTEXT,
+                context.relayer,
+                context.batch_id,
+                ALLOWLIST_SALE_INDEX_START,
+                &unique_external_ref,
+                context.creator,
+            );
+            let proof = Proof {
+                signer: context.creator,
+                relayer: context.relayer,
+                signature: sign(&context.creator_key_pair, &data_to_sign.encode()),
+            };
+
+            assert_noop!(
+                NftManager::signed_mint_batch_nft(
+                    Origin::signed(context.creator),
+                    proof,
+                    context.batch_id,
+                    ALLOWLIST_SALE_INDEX_START,
+                    context.creator,
+                    unique_external_ref,
+                ),
+                Error::<TestRuntime>::SaleIndexReserved
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_proof_does_not_match_the_sender() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+
+            assert_noop!(
+                NftManager::mint_from_allowlist(
+                    Origin::signed(context.recipient(1)),
+                    context.batch_id,
+                    context.external_ref(0),
+                    context.merkle_proof(0),
+                ),
+                Error::<TestRuntime>::InvalidAllowlistProof
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_entry_has_already_been_minted() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+            assert_ok!(context.mint(0));
+
+            assert_noop!(context.mint(0), Error::<TestRuntime>::ExternalRefIsAlreadyInUse);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_batch_has_no_allowlist() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(context.mint(0), Error::<TestRuntime>::BatchHasNoAllowlist);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_batch_is_not_listed_for_fiat_sale() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+            <BatchOpenForSale<TestRuntime>>::insert(context.batch_id, NftSaleType::Ethereum);
+
+            assert_noop!(context.mint(0), Error::<TestRuntime>::BatchNotListedForFiatSale);
+        });
+    }
+
+    #[test]
+    fn fails_outside_the_mint_window() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(BatchAllowlist {
+                mint_start: Some(5),
+                mint_end: Some(10),
+                ..context.allowlist()
+            });
+
+            assert_noop!(context.mint(0), Error::<TestRuntime>::AllowlistMintNotStarted);
+
+            System::set_block_number(10);
+            assert_ok!(context.mint(0));
+
+            System::set_block_number(11);
+            assert_noop!(context.mint(1), Error::<TestRuntime>::AllowlistMintEnded);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_account_limit_is_reached() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context
+                .set_allowlist(BatchAllowlist { max_per_account: Some(1), ..context.allowlist() });
+            assert_ok!(context.mint(0));
+
+            // Allow recipient 0 to mint a second entry
+            let second_ref = b"second entry".to_vec();
+            let leaf = allowlist_leaf::<TestRuntime>(&second_ref, &context.recipient(0));
+            let other_leaf =
+                allowlist_leaf::<TestRuntime>(&context.external_ref(1), &context.recipient(1));
+            context.set_allowlist(BatchAllowlist {
+                root: compute_allowlist_root(leaf, &[other_leaf]),
+                max_per_account: Some(1),
+                ..context.allowlist()
+            });
+
+            assert_noop!(
+                NftManager::mint_from_allowlist(
+                    Origin::signed(context.recipient(0)),
+                    context.batch_id,
+                    second_ref,
+                    BoundedVec::try_from(vec![other_leaf]).unwrap(),
+                ),
+                Error::<TestRuntime>::AllowlistMintLimitReached
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_relayed_signature_is_replayed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+            let call = context.signed_mint_call(1, 0);
+            assert_ok!(NftManager::proxy(Origin::signed(context.relayer), call.clone()));
+
+            assert_noop!(
+                NftManager::proxy(Origin::signed(context.relayer), call),
+                Error::<TestRuntime>::UnauthorizedSignedMintFromAllowlistTransaction
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_total_supply_is_exceeded() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.set_allowlist(context.allowlist());
+            for i in 0..TOTAL_SUPPLY {
+                assert_ok!(mint_batch_nft::<TestRuntime>(
+                    context.batch_id,
+                    context.creator,
+                    i + 10,
+                    BoundedVec::try_from(format!("creator nft {}", i).into_bytes()).unwrap(),
+                ));
+            }
+
+            assert_noop!(context.mint(0), Error::<TestRuntime>::TotalSupplyExceeded);
+        });
+    }
+}
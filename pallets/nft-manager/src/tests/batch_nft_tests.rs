@@ -79,11 +79,15 @@ impl Default for CreateBatchContext {
         let relayer = TestAccount::new([2u8; 32]);
         let royalties = vec![
             Royalty {
-                recipient_t1_address: H160(hex!("0000000000000000000000000000000000000002")),
+                recipient: RoyaltyRecipient::T1(H160(hex!(
+                    "0000000000000000000000000000000000000002"
+                ))),
                 rate: RoyaltyRate { parts_per_million: 1_000u32 },
             },
             Royalty {
-                recipient_t1_address: H160(hex!("0000000000000000000000000000000000000003")),
+                recipient: RoyaltyRecipient::T1(H160(hex!(
+                    "0000000000000000000000000000000000000003"
+                ))),
                 rate: RoyaltyRate { parts_per_million: 500u32 },
             },
         ];
@@ -243,9 +247,9 @@ mod signed_create_batch {
 
                 context.royalties = vec![
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000002"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: 1_000u32 },
                     };
                     out_of_bounds_royalties as usize
@@ -285,15 +289,15 @@ mod signed_create_batch {
                 let mut context = CreateBatchContext::default();
                 context.royalties = vec![
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000002"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: 1 },
                     },
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000003"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: ROYALTY_RATE_DENOMINATOR + 1 },
                     },
                 ];
@@ -315,15 +319,15 @@ mod signed_create_batch {
                 let mut context = CreateBatchContext::default();
                 context.royalties = vec![
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000002"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: 1 },
                     },
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000003"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: ROYALTY_RATE_DENOMINATOR },
                     },
                 ];
@@ -441,9 +445,9 @@ mod signed_create_batch {
                     let nonce = <BatchNonces<TestRuntime>>::get(context.creator_account);
 
                     let other_royalties = vec![Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000001"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: 1 },
                     }];
                     let data_to_sign = (
@@ -16,15 +16,15 @@
 
 #![cfg(test)]
 
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, PalletId};
 use frame_system::{self as system, DefaultConfig};
 use sp_core::{sr25519, ConstU32, Pair};
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::{
     traits::{IdentityLookup, Verify},
-    BuildStorage,
+    BuildStorage, DispatchError,
 };
-use std::cell::RefCell;
+use std::{cell::RefCell, collections::HashMap};
 
 use crate::{self as nft_manager, *};
 pub use std::sync::Arc;
@@ -56,10 +56,18 @@ impl Config for TestRuntime {
     type Signature = Signature;
     type WeightInfo = ();
     type BatchBound = MockNftBatchBound;
+    type ReleaseExternalRefOnBurn = ReleaseExternalRefOnBurn;
+    type Token = H160;
+    type TokenBalance = u128;
+    type PaymentHandler = TestRuntime;
+    type EscrowPalletId = NftEscrowPalletId;
+    type FiatSettlementOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
+    pub static ReleaseExternalRefOnBurn: bool = false;
+    pub const NftEscrowPalletId: PalletId = PalletId(*b"nft/escr");
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -111,6 +119,45 @@ impl ProcessedEventsChecker for TestRuntime {
     }
 }
 
+thread_local! {
+    static TOKEN_BALANCES: RefCell<HashMap<(H160, AccountId), u128>> = RefCell::new(HashMap::new());
+}
+
+pub fn set_token_balance(token: &H160, account: &AccountId, amount: u128) {
+    TOKEN_BALANCES.with(|b| b.borrow_mut().insert((*token, account.clone()), amount));
+}
+
+pub fn token_balance(token: &H160, account: &AccountId) -> u128 {
+    return TOKEN_BALANCES.with(|b| *b.borrow().get(&(*token, account.clone())).unwrap_or(&0))
+}
+
+impl PaymentHandler for TestRuntime {
+    type AccountId = AccountId;
+    type Token = H160;
+    type TokenBalance = u128;
+    type Error = DispatchError;
+
+    fn pay_recipient(
+        token: &H160,
+        amount: &u128,
+        payer: &AccountId,
+        recipient: &AccountId,
+    ) -> Result<(), DispatchError> {
+        let payer_balance = token_balance(token, payer);
+        if payer_balance < *amount {
+            return Err(DispatchError::Other("Insufficient token balance"))
+        }
+
+        set_token_balance(token, payer, payer_balance - amount);
+        set_token_balance(token, recipient, token_balance(token, recipient) + amount);
+        Ok(())
+    }
+
+    fn pay_treasury(_amount: &u128, _payer: &AccountId) -> Result<(), DispatchError> {
+        Ok(())
+    }
+}
+
 pub struct TestAccount {
     pub seed: [u8; 32],
 }
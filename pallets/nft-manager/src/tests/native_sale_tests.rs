@@ -0,0 +1,513 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+use super::*;
+use crate::{
+    mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *},
+    native_sale::escrow_account,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+
+const PRICE: u128 = 1_000_000;
+const ROYALTY_RECIPIENT: H160 = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+
+struct Context {
+    owner: AccountId,
+    buyer: AccountId,
+    other: AccountId,
+    nft_id: NftId,
+    info_id: NftInfoId,
+    token: H160,
+    unique_external_ref: BoundedVec<u8, NftExternalRefBound>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context {
+            owner: TestAccount::new([1u8; 32]).account_id(),
+            buyer: TestAccount::new([5u8; 32]).account_id(),
+            other: TestAccount::new([6u8; 32]).account_id(),
+            nft_id: U256::from(1u8),
+            info_id: U256::zero(),
+            token: H160(hex!("97d9b397189e8b771ffac3cb04cf26c780a93431")),
+            unique_external_ref: BoundedVec::try_from(
+                String::from("Offchain location of NFT").into_bytes(),
+            )
+            .expect("Unique external reference bound was exceeded."),
+        }
+    }
+}
+
+impl Context {
+    /// Mints an nft with a 10% royalty and funds the buyer
+    fn setup(&self) {
+        let royalties = BoundedVec::try_from(vec![Royalty {
+            recipient: RoyaltyRecipient::T1(ROYALTY_RECIPIENT),
+            rate: RoyaltyRate { parts_per_million: 100_000 },
+        }])
+        .unwrap();
+        let info = NftInfo::new(
+            self.info_id,
+            royalties,
+            H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB")),
+        );
+        <NftInfos<TestRuntime>>::insert(self.info_id, info);
+        NftManager::add_nft(&Nft::new(
+            self.nft_id,
+            self.info_id,
+            self.unique_external_ref.clone(),
+            self.owner,
+        ));
+
+        set_token_balance(&self.token, &self.buyer, 10 * PRICE);
+        set_token_balance(&self.token, &self.owner, 0);
+        set_token_balance(&self.token, &escrow_account::<TestRuntime>(), 0);
+    }
+
+    fn list(&self, expiry: Option<u64>) -> DispatchResult {
+        NftManager::list_nft_for_native_sale(
+            Origin::signed(self.owner),
+            self.nft_id,
+            self.token,
+            PRICE,
+            expiry,
+        )
+    }
+
+    fn offer(&self, expiry: Option<u64>) -> DispatchResult {
+        NftManager::make_offer(Origin::signed(self.buyer), self.nft_id, self.token, PRICE, expiry)
+    }
+
+    fn event_emitted(&self, event: crate::Event<TestRuntime>) -> bool {
+        return System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+    }
+
+    fn assert_sold_to_buyer(&self) {
+        assert_eq!(NftManager::nfts(self.nft_id).unwrap().owner, self.buyer);
+        assert_eq!(token_balance(&self.token, &self.owner), PRICE * 9 / 10);
+        assert_eq!(
+            NftManager::royalty_balances(RoyaltyRecipient::T1(ROYALTY_RECIPIENT), self.token),
+            PRICE / 10
+        );
+        assert_eq!(token_balance(&self.token, &escrow_account::<TestRuntime>()), PRICE / 10);
+        assert_eq!(<NftOpenForSale<TestRuntime>>::contains_key(self.nft_id), false);
+        assert_eq!(<NativeListings<TestRuntime>>::contains_key(self.nft_id), false);
+    }
+}
+
+mod list_nft_for_native_sale {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_called_by_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_ok!(context.list(Some(10)));
+
+            assert_eq!(NftManager::get_nft_open_for_sale_on(context.nft_id), NftSaleType::Native);
+            assert_eq!(NftManager::nfts(context.nft_id).unwrap().nonce, 1);
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::NativeNftListed {
+                nft_id: context.nft_id,
+                seller: context.owner,
+                token: context.token,
+                price: PRICE,
+                expiry: Some(10),
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_price_is_zero() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::list_nft_for_native_sale(
+                    Origin::signed(context.owner),
+                    context.nft_id,
+                    context.token,
+                    0,
+                    None,
+                ),
+                Error::<TestRuntime>::PriceIsZero
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_expiry_is_in_the_past() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            System::set_block_number(5);
+
+            assert_noop!(context.list(Some(4)), Error::<TestRuntime>::InvalidExpiry);
+        });
+    }
+
+    #[test]
+    fn fails_when_called_by_other_account() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::list_nft_for_native_sale(
+                    Origin::signed(context.other),
+                    context.nft_id,
+                    context.token,
+                    PRICE,
+                    None,
+                ),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+
+    #[test]
+    fn native_market_cannot_be_used_without_a_price() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(context.owner),
+                    context.nft_id,
+                    NftSaleType::Native,
+                ),
+                Error::<TestRuntime>::UnsupportedMarket
+            );
+        });
+    }
+}
+
+mod cancel_native_listing {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_called_by_seller() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(None));
+
+            assert_ok!(NftManager::cancel_native_listing(
+                Origin::signed(context.owner),
+                context.nft_id
+            ));
+
+            assert_eq!(<NftOpenForSale<TestRuntime>>::contains_key(context.nft_id), false);
+            assert_eq!(<NativeListings<TestRuntime>>::contains_key(context.nft_id), false);
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::CancelNativeNftListing {
+                nft_id: context.nft_id,
+                op_id: 1,
+            }));
+        });
+    }
+
+    #[test]
+    fn expired_listing_can_be_cancelled_by_anyone() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(Some(3)));
+
+            assert_noop!(
+                NftManager::cancel_native_listing(Origin::signed(context.other), context.nft_id),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+
+            System::set_block_number(4);
+            assert_ok!(NftManager::cancel_native_listing(
+                Origin::signed(context.other),
+                context.nft_id
+            ));
+        });
+    }
+}
+
+mod buy_nft {
+    use super::*;
+
+    #[test]
+    fn succeeds_and_pays_seller_and_royalties() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(Some(10)));
+
+            assert_ok!(NftManager::buy_nft(
+                Origin::signed(context.buyer),
+                context.nft_id,
+                context.token,
+                PRICE
+            ));
+
+            context.assert_sold_to_buyer();
+            assert_eq!(token_balance(&context.token, &context.buyer), 9 * PRICE);
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::RoyaltyAccrued {
+                nft_id: context.nft_id,
+                recipient: RoyaltyRecipient::T1(ROYALTY_RECIPIENT),
+                token: context.token,
+                amount: PRICE / 10,
+            }));
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::NativeNftSold {
+                nft_id: context.nft_id,
+                seller: context.owner,
+                buyer: context.buyer,
+                token: context.token,
+                price: PRICE,
+                op_id: 1,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_price_does_not_match_listing() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(None));
+
+            assert_noop!(
+                NftManager::buy_nft(
+                    Origin::signed(context.buyer),
+                    context.nft_id,
+                    context.token,
+                    PRICE - 1
+                ),
+                Error::<TestRuntime>::PriceMismatch
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_listing_has_expired() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(Some(3)));
+            System::set_block_number(4);
+
+            assert_noop!(
+                NftManager::buy_nft(
+                    Origin::signed(context.buyer),
+                    context.nft_id,
+                    context.token,
+                    PRICE
+                ),
+                Error::<TestRuntime>::ListingExpired
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_nft_is_listed_for_fiat() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(NftManager::list_nft_open_for_sale(
+                Origin::signed(context.owner),
+                context.nft_id,
+                NftSaleType::Fiat,
+            ));
+
+            assert_noop!(
+                NftManager::buy_nft(
+                    Origin::signed(context.buyer),
+                    context.nft_id,
+                    context.token,
+                    PRICE
+                ),
+                Error::<TestRuntime>::NftNotListedForNativeSale
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_buyer_cannot_pay() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(None));
+            set_token_balance(&context.token, &context.buyer, PRICE - 1);
+
+            assert!(NftManager::buy_nft(
+                Origin::signed(context.buyer),
+                context.nft_id,
+                context.token,
+                PRICE
+            )
+            .is_err());
+            assert_eq!(NftManager::nfts(context.nft_id).unwrap().owner, context.owner);
+        });
+    }
+}
+
+mod offers {
+    use super::*;
+
+    #[test]
+    fn make_offer_moves_funds_to_escrow() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_ok!(context.offer(Some(10)));
+
+            assert_eq!(token_balance(&context.token, &context.buyer), 9 * PRICE);
+            assert_eq!(token_balance(&context.token, &escrow_account::<TestRuntime>()), PRICE);
+            assert_eq!(
+                NftManager::native_offers(context.nft_id, context.buyer),
+                Some(NativeOffer { token: context.token, amount: PRICE, expiry: Some(10) })
+            );
+        });
+    }
+
+    #[test]
+    fn make_offer_fails_when_offer_already_exists() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.offer(None));
+
+            assert_noop!(context.offer(None), Error::<TestRuntime>::OfferAlreadyExists);
+        });
+    }
+
+    #[test]
+    fn make_offer_fails_when_called_by_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::make_offer(
+                    Origin::signed(context.owner),
+                    context.nft_id,
+                    context.token,
+                    PRICE,
+                    None
+                ),
+                Error::<TestRuntime>::BuyerIsOwner
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_offer_refunds_buyer() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.offer(None));
+
+            assert_ok!(NftManager::cancel_offer(Origin::signed(context.buyer), context.nft_id));
+
+            assert_eq!(token_balance(&context.token, &context.buyer), 10 * PRICE);
+            assert_eq!(token_balance(&context.token, &escrow_account::<TestRuntime>()), 0);
+            assert_eq!(NftManager::native_offers(context.nft_id, context.buyer), None);
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::OfferCancelled {
+                nft_id: context.nft_id,
+                buyer: context.buyer,
+            }));
+        });
+    }
+
+    #[test]
+    fn accept_offer_transfers_nft_and_pays_seller() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.list(None));
+            assert_ok!(context.offer(None));
+
+            assert_ok!(NftManager::accept_offer(
+                Origin::signed(context.owner),
+                context.nft_id,
+                context.buyer
+            ));
+
+            context.assert_sold_to_buyer();
+            assert_eq!(NftManager::native_offers(context.nft_id, context.buyer), None);
+        });
+    }
+
+    #[test]
+    fn accept_offer_fails_when_offer_has_expired() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.offer(Some(3)));
+            System::set_block_number(4);
+
+            assert_noop!(
+                NftManager::accept_offer(
+                    Origin::signed(context.owner),
+                    context.nft_id,
+                    context.buyer
+                ),
+                Error::<TestRuntime>::OfferExpired
+            );
+
+            // The buyer can still get their funds back
+            assert_ok!(NftManager::cancel_offer(Origin::signed(context.buyer), context.nft_id));
+            assert_eq!(token_balance(&context.token, &context.buyer), 10 * PRICE);
+        });
+    }
+
+    #[test]
+    fn accept_offer_fails_when_called_by_other_account() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            assert_ok!(context.offer(None));
+
+            assert_noop!(
+                NftManager::accept_offer(
+                    Origin::signed(context.other),
+                    context.nft_id,
+                    context.buyer
+                ),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+}
@@ -0,0 +1,640 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+use super::*;
+use crate::mock::{
+    AccountId, RuntimeCall as MockCall, RuntimeEvent as Event, RuntimeOrigin as Origin, *,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_avn_common::event_types::{EthEventId, ValidEvents};
+use sp_core::{sr25519::Pair, H256};
+
+struct Context {
+    owner: AccountId,
+    owner_key_pair: Pair,
+    creator: AccountId,
+    other: AccountId,
+    relayer: AccountId,
+    nft_id: NftId,
+    info_id: NftInfoId,
+    unique_external_ref: BoundedVec<u8, NftExternalRefBound>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        let owner = TestAccount::new([1u8; 32]);
+        Context {
+            owner: owner.account_id(),
+            owner_key_pair: owner.key_pair(),
+            creator: TestAccount::new([3u8; 32]).account_id(),
+            other: TestAccount::new([4u8; 32]).account_id(),
+            relayer: TestAccount::new([2u8; 32]).account_id(),
+            nft_id: U256::from(1u8),
+            info_id: U256::zero(),
+            unique_external_ref: BoundedVec::try_from(
+                String::from("Offchain location of NFT").into_bytes(),
+            )
+            .expect("Unique external reference bound was exceeded."),
+        }
+    }
+}
+
+impl Context {
+    fn setup_single_nft(&self) {
+        let info = NftInfo::new(
+            self.info_id,
+            BoundedVec::default(),
+            H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB")),
+        );
+        <NftInfos<TestRuntime>>::insert(self.info_id, info);
+        NftManager::add_nft(&Nft::new(
+            self.nft_id,
+            self.info_id,
+            self.unique_external_ref.clone(),
+            self.owner,
+        ));
+    }
+
+    fn setup_batch_nft(&self) {
+        let batch_id = U256::from(7u8);
+        create_batch::<TestRuntime>(
+            self.info_id,
+            batch_id,
+            BoundedVec::default(),
+            2,
+            H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB")),
+            self.creator,
+        );
+        NftManager::add_nft(&Nft::new(
+            self.nft_id,
+            self.info_id,
+            self.unique_external_ref.clone(),
+            self.owner,
+        ));
+    }
+
+    fn key(&self) -> Vec<u8> {
+        b"colour".to_vec()
+    }
+
+    fn value(&self) -> Vec<u8> {
+        b"blue".to_vec()
+    }
+
+    fn event_emitted(&self, event: crate::Event<TestRuntime>) -> bool {
+        return System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+    }
+
+    fn create_signed_lock_nft_call(&self) -> Box<<TestRuntime as Config>::RuntimeCall> {
+        let nonce = NftManager::batch_nonce(&self.owner);
+        let data_to_sign = (SIGNED_LOCK_NFT_CONTEXT, self.relayer, self.nft_id, nonce).encode();
+        let proof = Proof {
+            signer: self.owner,
+            relayer: self.relayer,
+            signature: sign(&self.owner_key_pair, &data_to_sign),
+        };
+
+        return Box::new(MockCall::NftManager(super::Call::<TestRuntime>::signed_lock_nft {
+            proof,
+            nft_id: self.nft_id,
+        }))
+    }
+
+    fn create_signed_set_attribute_call(&self) -> Box<<TestRuntime as Config>::RuntimeCall> {
+        let nonce = NftManager::batch_nonce(&self.owner);
+        let target = AttributeTarget::Nft(self.nft_id);
+        let data_to_sign =
+            (SIGNED_SET_ATTRIBUTE_CONTEXT, self.relayer, target, self.key(), self.value(), nonce)
+                .encode();
+        let proof = Proof {
+            signer: self.owner,
+            relayer: self.relayer,
+            signature: sign(&self.owner_key_pair, &data_to_sign),
+        };
+
+        return Box::new(MockCall::NftManager(super::Call::<TestRuntime>::signed_set_attribute {
+            proof,
+            target,
+            key: self.key(),
+            value: self.value(),
+        }))
+    }
+}
+
+mod lock_nft {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_called_by_owner() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+
+            assert_ok!(NftManager::lock_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_eq!(true, NftManager::nfts(context.nft_id).unwrap().is_locked);
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::NftLocked {
+                nft_id: context.nft_id,
+                by: context.owner,
+            }));
+        });
+    }
+
+    #[test]
+    fn succeeds_when_called_by_batch_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+
+            assert_ok!(NftManager::lock_nft(Origin::signed(context.creator), context.nft_id));
+
+            assert_eq!(true, NftManager::nfts(context.nft_id).unwrap().is_locked);
+        });
+    }
+
+    #[test]
+    fn fails_when_called_by_other_account() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+
+            assert_noop!(
+                NftManager::lock_nft(Origin::signed(context.other), context.nft_id),
+                Error::<TestRuntime>::SenderIsNotOwnerOrCreator
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_nft_is_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            NftManager::open_nft_for_sale(&context.nft_id, &NftSaleType::Ethereum);
+
+            assert_noop!(
+                NftManager::lock_nft(Origin::signed(context.owner), context.nft_id),
+                Error::<TestRuntime>::NftIsListed
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_nft_is_already_locked() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            assert_ok!(NftManager::lock_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_noop!(
+                NftManager::lock_nft(Origin::signed(context.owner), context.nft_id),
+                Error::<TestRuntime>::NftIsLocked
+            );
+        });
+    }
+
+    #[test]
+    fn locked_nft_cannot_be_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            assert_ok!(NftManager::lock_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_noop!(
+                NftManager::list_nft_open_for_sale(
+                    Origin::signed(context.owner),
+                    context.nft_id,
+                    NftSaleType::Fiat
+                ),
+                Error::<TestRuntime>::NftIsLocked
+            );
+        });
+    }
+
+    #[test]
+    fn locked_nft_is_not_transferred_by_ethereum_event() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            NftManager::open_nft_for_sale(&context.nft_id, &NftSaleType::Ethereum);
+            // Simulate a lock that happened before the listing
+            <Nfts<TestRuntime>>::mutate(context.nft_id, |nft| {
+                nft.as_mut().map(|nft| nft.is_locked = true)
+            });
+
+            let event_id = EthEventId {
+                signature: ValidEvents::NftTransferTo.signature(),
+                transaction_hash: H256::from([1u8; 32]),
+            };
+            insert_to_mock_processed_events(&event_id);
+            let data = NftTransferToData {
+                nft_id: context.nft_id,
+                t2_transfer_to_public_key: H256::from([5u8; 32]),
+                op_id: 1,
+            };
+
+            assert_noop!(
+                NftManager::transfer_eth_nft(&event_id, &data),
+                Error::<TestRuntime>::NftIsLocked
+            );
+        });
+    }
+
+    #[test]
+    fn signed_lock_nft_via_proxy_succeeds() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let call = context.create_signed_lock_nft_call();
+
+            assert_ok!(NftManager::proxy(Origin::signed(context.relayer), call));
+
+            assert_eq!(true, NftManager::nfts(context.nft_id).unwrap().is_locked);
+            assert_eq!(1, NftManager::batch_nonce(&context.owner));
+        });
+    }
+
+    #[test]
+    fn signed_lock_nft_fails_when_replayed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let call = context.create_signed_lock_nft_call();
+            assert_ok!(NftManager::proxy(Origin::signed(context.relayer), call.clone()));
+            assert_ok!(NftManager::unlock_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_noop!(
+                NftManager::proxy(Origin::signed(context.relayer), call),
+                Error::<TestRuntime>::UnauthorizedSignedLockNftTransaction
+            );
+        });
+    }
+}
+
+mod unlock_nft {
+    use super::*;
+
+    #[test]
+    fn succeeds_when_nft_is_locked() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+            assert_ok!(NftManager::lock_nft(Origin::signed(context.creator), context.nft_id));
+
+            assert_ok!(NftManager::unlock_nft(Origin::signed(context.creator), context.nft_id));
+
+            assert_eq!(false, NftManager::nfts(context.nft_id).unwrap().is_locked);
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::NftUnlocked {
+                nft_id: context.nft_id,
+                by: context.creator,
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_when_nft_is_not_locked() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+
+            assert_noop!(
+                NftManager::unlock_nft(Origin::signed(context.owner), context.nft_id),
+                Error::<TestRuntime>::NftIsNotLocked
+            );
+        });
+    }
+}
+
+mod burn_nft {
+    use super::*;
+
+    #[test]
+    fn succeeds_and_removes_nft_and_its_attributes() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let target = AttributeTarget::Nft(context.nft_id);
+            assert_ok!(NftManager::set_attribute(
+                Origin::signed(context.owner),
+                target,
+                context.key(),
+                context.value()
+            ));
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_eq!(false, <Nfts<TestRuntime>>::contains_key(context.nft_id));
+            assert_eq!(false, <NftInfos<TestRuntime>>::contains_key(context.info_id));
+            assert_eq!(true, NftManager::is_nft_burned(context.nft_id));
+            assert_eq!(0, NftManager::attributes_count(target));
+            assert_eq!(
+                None,
+                NftManager::attributes(target, NftAttributeKey::try_from(context.key()).unwrap())
+            );
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::NftBurned {
+                nft_id: context.nft_id,
+                owner: context.owner,
+                external_ref_released: false,
+            }));
+        });
+    }
+
+    #[test]
+    fn keeps_external_ref_reserved_by_default() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_eq!(true, NftManager::is_external_ref_used(&context.unique_external_ref));
+        });
+    }
+
+    #[test]
+    fn releases_external_ref_when_configured() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            ReleaseExternalRefOnBurn::set(true);
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(context.owner), context.nft_id));
+
+            ReleaseExternalRefOnBurn::set(false);
+            assert_eq!(false, NftManager::is_external_ref_used(&context.unique_external_ref));
+        });
+    }
+
+    #[test]
+    fn keeps_batch_info() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+
+            assert_ok!(NftManager::burn_nft(Origin::signed(context.owner), context.nft_id));
+
+            assert_eq!(true, <NftInfos<TestRuntime>>::contains_key(context.info_id));
+        });
+    }
+
+    #[test]
+    fn fails_when_called_by_creator() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+
+            assert_noop!(
+                NftManager::burn_nft(Origin::signed(context.creator), context.nft_id),
+                Error::<TestRuntime>::SenderIsNotOwner
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_nft_is_locked() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+            assert_ok!(NftManager::lock_nft(Origin::signed(context.creator), context.nft_id));
+
+            assert_noop!(
+                NftManager::burn_nft(Origin::signed(context.owner), context.nft_id),
+                Error::<TestRuntime>::NftIsLocked
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_nft_is_listed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            NftManager::open_nft_for_sale(&context.nft_id, &NftSaleType::Fiat);
+
+            assert_noop!(
+                NftManager::burn_nft(Origin::signed(context.owner), context.nft_id),
+                Error::<TestRuntime>::NftIsListed
+            );
+        });
+    }
+}
+
+mod attributes {
+    use super::*;
+
+    #[test]
+    fn owner_can_set_and_clear_nft_attribute() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let target = AttributeTarget::Nft(context.nft_id);
+            let key = NftAttributeKey::try_from(context.key()).unwrap();
+
+            assert_ok!(NftManager::set_attribute(
+                Origin::signed(context.owner),
+                target,
+                context.key(),
+                context.value()
+            ));
+            assert_eq!(
+                Some(NftAttributeValue::try_from(context.value()).unwrap()),
+                NftManager::attributes(target, &key)
+            );
+            assert_eq!(1, NftManager::attributes_count(target));
+
+            assert_ok!(NftManager::clear_attribute(
+                Origin::signed(context.owner),
+                target,
+                context.key()
+            ));
+            assert_eq!(None, NftManager::attributes(target, &key));
+            assert_eq!(0, NftManager::attributes_count(target));
+            assert!(context
+                .event_emitted(crate::Event::<TestRuntime>::AttributeCleared { target, key }));
+        });
+    }
+
+    #[test]
+    fn overwriting_an_attribute_does_not_increase_count() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let target = AttributeTarget::Nft(context.nft_id);
+
+            for value in [b"blue".to_vec(), b"red".to_vec()] {
+                assert_ok!(NftManager::set_attribute(
+                    Origin::signed(context.owner),
+                    target,
+                    context.key(),
+                    value
+                ));
+            }
+
+            assert_eq!(1, NftManager::attributes_count(target));
+        });
+    }
+
+    #[test]
+    fn creator_can_set_info_attribute() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+            let target = AttributeTarget::NftInfo(context.info_id);
+
+            assert_ok!(NftManager::set_attribute(
+                Origin::signed(context.creator),
+                target,
+                context.key(),
+                context.value()
+            ));
+
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::AttributeSet {
+                target,
+                key: NftAttributeKey::try_from(context.key()).unwrap(),
+                value: NftAttributeValue::try_from(context.value()).unwrap(),
+            }));
+        });
+    }
+
+    #[test]
+    fn owner_cannot_set_info_attribute() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_batch_nft();
+
+            assert_noop!(
+                NftManager::set_attribute(
+                    Origin::signed(context.owner),
+                    AttributeTarget::NftInfo(context.info_id),
+                    context.key(),
+                    context.value()
+                ),
+                Error::<TestRuntime>::SenderIsNotCreator
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_key_is_too_long() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+
+            assert_noop!(
+                NftManager::set_attribute(
+                    Origin::signed(context.owner),
+                    AttributeTarget::Nft(context.nft_id),
+                    vec![1u8; NftAttributeKeyBound::get() as usize + 1],
+                    context.value()
+                ),
+                Error::<TestRuntime>::AttributeKeyOutOfBounds
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_too_many_attributes_are_set() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let target = AttributeTarget::Nft(context.nft_id);
+
+            for i in 0..MAX_NUMBER_OF_ATTRIBUTES {
+                assert_ok!(NftManager::set_attribute(
+                    Origin::signed(context.owner),
+                    target,
+                    i.encode(),
+                    context.value()
+                ));
+            }
+
+            assert_noop!(
+                NftManager::set_attribute(
+                    Origin::signed(context.owner),
+                    target,
+                    context.key(),
+                    context.value()
+                ),
+                Error::<TestRuntime>::TooManyAttributes
+            );
+        });
+    }
+
+    #[test]
+    fn clear_fails_when_attribute_does_not_exist() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+
+            assert_noop!(
+                NftManager::clear_attribute(
+                    Origin::signed(context.owner),
+                    AttributeTarget::Nft(context.nft_id),
+                    context.key()
+                ),
+                Error::<TestRuntime>::AttributeNotFound
+            );
+        });
+    }
+
+    #[test]
+    fn signed_set_attribute_via_proxy_succeeds() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup_single_nft();
+            let call = context.create_signed_set_attribute_call();
+
+            assert_ok!(NftManager::proxy(Origin::signed(context.relayer), call));
+
+            assert_eq!(
+                Some(NftAttributeValue::try_from(context.value()).unwrap()),
+                NftManager::attributes(
+                    AttributeTarget::Nft(context.nft_id),
+                    NftAttributeKey::try_from(context.key()).unwrap()
+                )
+            );
+            assert_eq!(1, NftManager::batch_nonce(&context.owner));
+        });
+    }
+}
@@ -43,7 +43,9 @@ mod open_for_sale {
                 origin: Origin::signed(nft_owner.account_id()),
                 nft_owner: nft_owner.account_id(),
                 royalties: vec![Royalty {
-                    recipient_t1_address: H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD")),
+                    recipient: RoyaltyRecipient::T1(H160(hex!(
+                        "33333CCCCC44444DDDDD33333CCCCC44444DDDDD"
+                    ))),
                     rate: RoyaltyRate { parts_per_million: 100 },
                 }],
                 unique_id: NftManager::next_unique_id(),
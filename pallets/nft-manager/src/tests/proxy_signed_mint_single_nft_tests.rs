@@ -56,11 +56,15 @@ impl Default for Context {
         let relayer = TestAccount::new([2u8; 32]);
         let royalties = vec![
             Royalty {
-                recipient_t1_address: H160(hex!("0000000000000000000000000000000000000002")),
+                recipient: RoyaltyRecipient::T1(H160(hex!(
+                    "0000000000000000000000000000000000000002"
+                ))),
                 rate: RoyaltyRate { parts_per_million: 1_000u32 },
             },
             Royalty {
-                recipient_t1_address: H160(hex!("0000000000000000000000000000000000000003")),
+                recipient: RoyaltyRecipient::T1(H160(hex!(
+                    "0000000000000000000000000000000000000003"
+                ))),
                 rate: RoyaltyRate { parts_per_million: 500u32 },
             },
         ];
@@ -350,15 +354,15 @@ mod proxy_signed_mint_single_nft {
                 let mut context = Context::default();
                 context.royalties = vec![
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000002"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: 1 },
                     },
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000003"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: ROYALTY_RATE_DENOMINATOR + 1 },
                     },
                 ];
@@ -379,15 +383,15 @@ mod proxy_signed_mint_single_nft {
                 let mut context = Context::default();
                 context.royalties = vec![
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000002"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: 1 },
                     },
                     Royalty {
-                        recipient_t1_address: H160(hex!(
+                        recipient: RoyaltyRecipient::T1(H160(hex!(
                             "0000000000000000000000000000000000000003"
-                        )),
+                        ))),
                         rate: RoyaltyRate { parts_per_million: ROYALTY_RATE_DENOMINATOR },
                     },
                 ];
@@ -578,7 +582,9 @@ mod proxy_signed_mint_single_nft {
                 context.setup();
 
                 let other_royalties = vec![Royalty {
-                    recipient_t1_address: H160(hex!("0000000000000000000000000000000000000001")),
+                    recipient: RoyaltyRecipient::T1(H160(hex!(
+                        "0000000000000000000000000000000000000001"
+                    ))),
                     rate: RoyaltyRate { parts_per_million: 1 },
                 }];
                 let data_to_sign = (
@@ -893,15 +899,15 @@ mod signed_mint_single_nft {
                         context.unique_external_ref.clone(),
                         vec![
                             Royalty {
-                                recipient_t1_address: H160(hex!(
+                                recipient: RoyaltyRecipient::T1(H160(hex!(
                                     "0000000000000000000000000000000000000002"
-                                )),
+                                ))),
                                 rate: RoyaltyRate { parts_per_million: 1 },
                             },
                             Royalty {
-                                recipient_t1_address: H160(hex!(
+                                recipient: RoyaltyRecipient::T1(H160(hex!(
                                     "0000000000000000000000000000000000000003"
-                                )),
+                                ))),
                                 rate: RoyaltyRate {
                                     parts_per_million: ROYALTY_RATE_DENOMINATOR + 1
                                 },
@@ -929,15 +935,15 @@ mod signed_mint_single_nft {
                         context.unique_external_ref.clone(),
                         vec![
                             Royalty {
-                                recipient_t1_address: H160(hex!(
+                                recipient: RoyaltyRecipient::T1(H160(hex!(
                                     "0000000000000000000000000000000000000002"
-                                )),
+                                ))),
                                 rate: RoyaltyRate { parts_per_million: 1 },
                             },
                             Royalty {
-                                recipient_t1_address: H160(hex!(
+                                recipient: RoyaltyRecipient::T1(H160(hex!(
                                     "0000000000000000000000000000000000000003"
-                                )),
+                                ))),
                                 rate: RoyaltyRate { parts_per_million: ROYALTY_RATE_DENOMINATOR },
                             }
                         ],
@@ -1117,7 +1123,9 @@ mod signed_mint_single_nft {
                 context.setup();
 
                 let other_royalties = vec![Royalty {
-                    recipient_t1_address: H160(hex!("0000000000000000000000000000000000000001")),
+                    recipient: RoyaltyRecipient::T1(H160(hex!(
+                        "0000000000000000000000000000000000000001"
+                    ))),
                     rate: RoyaltyRate { parts_per_million: 1 },
                 }];
                 let data_to_sign = (
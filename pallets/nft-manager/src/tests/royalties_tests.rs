@@ -0,0 +1,720 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+use super::*;
+use crate::{
+    mock::{AccountId, RuntimeEvent as Event, RuntimeOrigin as Origin, *},
+    native_sale::escrow_account,
+};
+use frame_support::{assert_noop, assert_ok};
+use hex_literal::hex;
+use sp_avn_common::{
+    hash_string_data_with_ethereum_prefix, recover_ethereum_address_from_ecdsa_signature,
+    HashMessageFormat,
+};
+use sp_core::{ecdsa, sr25519::Pair, Pair as PairT};
+
+const PRICE: u128 = 1_000_000;
+
+struct Context {
+    owner: AccountId,
+    owner_key_pair: Pair,
+    buyer: AccountId,
+    relayer: AccountId,
+    t2_recipient: AccountId,
+    t1_recipient_key_pair: ecdsa::Pair,
+    nft_id: NftId,
+    info_id: NftInfoId,
+    token: H160,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        let owner = TestAccount::new([1u8; 32]);
+        Context {
+            owner: owner.account_id(),
+            owner_key_pair: owner.key_pair(),
+            buyer: TestAccount::new([5u8; 32]).account_id(),
+            relayer: TestAccount::new([2u8; 32]).account_id(),
+            t2_recipient: TestAccount::new([8u8; 32]).account_id(),
+            t1_recipient_key_pair: ecdsa::Pair::from_seed(&[9u8; 32]),
+            nft_id: U256::from(1u8),
+            info_id: U256::zero(),
+            token: H160(hex!("97d9b397189e8b771ffac3cb04cf26c780a93431")),
+        }
+    }
+}
+
+impl Context {
+    /// Mints an nft paying 10% to a T2 account and 5% to a T1 address
+    fn setup(&self) {
+        let royalties = BoundedVec::try_from(vec![
+            Royalty {
+                recipient: self.t2_royalty_recipient(),
+                rate: RoyaltyRate { parts_per_million: 100_000 },
+            },
+            Royalty {
+                recipient: RoyaltyRecipient::T1(self.t1_recipient()),
+                rate: RoyaltyRate { parts_per_million: 50_000 },
+            },
+        ])
+        .unwrap();
+        let info = NftInfo::new(
+            self.info_id,
+            royalties,
+            H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB")),
+        );
+        <NftInfos<TestRuntime>>::insert(self.info_id, info);
+        NftManager::add_nft(&Nft::new(
+            self.nft_id,
+            self.info_id,
+            BoundedVec::try_from(String::from("Offchain location of NFT").into_bytes()).unwrap(),
+            self.owner,
+        ));
+
+        set_token_balance(&self.token, &self.owner, PRICE);
+        set_token_balance(&self.token, &self.buyer, PRICE);
+        set_token_balance(&self.token, &self.t2_recipient, 0);
+        set_token_balance(&self.token, &escrow_account::<TestRuntime>(), 0);
+    }
+
+    fn t2_royalty_recipient(&self) -> RoyaltyRecipient {
+        return RoyaltyRecipient::T2(H256::from_slice(&self.t2_recipient.encode()))
+    }
+
+    fn t1_recipient(&self) -> H160 {
+        let message = b"any message";
+        let signature = self.t1_recipient_key_pair.sign_prehashed(
+            &hash_string_data_with_ethereum_prefix(message).expect("hashing should succeed"),
+        );
+        let address = recover_ethereum_address_from_ecdsa_signature(
+            &signature,
+            message,
+            HashMessageFormat::String,
+        )
+        .expect("signature is valid");
+        return H160::from_slice(&address)
+    }
+
+    fn sign_t1_claim(&self, key_pair: &ecdsa::Pair, destination: &AccountId) -> ecdsa::Signature {
+        let nonce = NftManager::royalty_claim_nonce(self.t1_recipient());
+        let message = encode_claim_t1_royalties_params::<TestRuntime>(
+            &self.t1_recipient(),
+            &self.token,
+            destination,
+            &nonce,
+        );
+        return key_pair.sign_prehashed(
+            &hash_string_data_with_ethereum_prefix(&message).expect("hashing should succeed"),
+        )
+    }
+
+    fn sell_natively(&self) {
+        assert_ok!(NftManager::list_nft_for_native_sale(
+            Origin::signed(self.owner),
+            self.nft_id,
+            self.token,
+            PRICE,
+            None,
+        ));
+        assert_ok!(NftManager::buy_nft(Origin::signed(self.buyer), self.nft_id, self.token, PRICE));
+    }
+
+    fn fiat_settlement_proof(&self, settlement_amount: u128) -> Proof<Signature, AccountId> {
+        let nonce = NftManager::nfts(self.nft_id).unwrap().nonce;
+        let data_to_sign = (
+            SIGNED_TRANSFER_FIAT_NFT_WITH_SETTLEMENT_CONTEXT,
+            self.relayer,
+            self.nft_id,
+            self.buyer_public_key(),
+            self.token,
+            settlement_amount,
+            nonce,
+        )
+            .encode();
+        return Proof {
+            signer: self.owner,
+            relayer: self.relayer,
+            signature: sign(&self.owner_key_pair, &data_to_sign),
+        }
+    }
+
+    fn record_settlement(&self, amount: u128) {
+        assert_ok!(NftManager::record_fiat_settlement(
+            Origin::root(),
+            self.nft_id,
+            self.token,
+            amount
+        ));
+    }
+
+    fn buyer_public_key(&self) -> H256 {
+        return H256::from_slice(&self.buyer.encode())
+    }
+
+    fn event_emitted(&self, event: crate::Event<TestRuntime>) -> bool {
+        return System::events().iter().any(|a| a.event == Event::NftManager(event.clone()))
+    }
+}
+
+mod native_sales {
+    use super::*;
+
+    #[test]
+    fn credit_t1_and_t2_recipients() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            context.sell_natively();
+
+            assert_eq!(
+                NftManager::royalty_balances(context.t2_royalty_recipient(), context.token),
+                PRICE / 10
+            );
+            assert_eq!(
+                NftManager::royalty_balances(
+                    RoyaltyRecipient::T1(context.t1_recipient()),
+                    context.token
+                ),
+                PRICE / 20
+            );
+            assert_eq!(token_balance(&context.token, &context.owner), PRICE + PRICE * 85 / 100);
+            assert_eq!(
+                token_balance(&context.token, &escrow_account::<TestRuntime>()),
+                PRICE * 15 / 100
+            );
+        });
+    }
+}
+
+mod fiat_sales {
+    use super::*;
+
+    fn fiat_transfer_proof(context: &Context) -> Proof<Signature, AccountId> {
+        let data_to_sign = (
+            SIGNED_TRANSFER_FIAT_NFT_CONTEXT,
+            context.relayer,
+            context.nft_id,
+            context.buyer_public_key(),
+            NftManager::nfts(context.nft_id).unwrap().nonce,
+        )
+            .encode();
+        return Proof {
+            signer: context.owner,
+            relayer: context.relayer,
+            signature: sign(&context.owner_key_pair, &data_to_sign),
+        }
+    }
+
+    #[test]
+    fn settlement_is_recorded_by_the_settlement_origin() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+
+            assert_noop!(
+                NftManager::record_fiat_settlement(
+                    Origin::signed(context.owner),
+                    context.nft_id,
+                    context.token,
+                    PRICE
+                ),
+                sp_runtime::DispatchError::BadOrigin
+            );
+
+            context.record_settlement(PRICE);
+
+            assert_eq!(
+                NftManager::fiat_settlements(context.nft_id),
+                Some(FiatSettlement { token: context.token, amount: PRICE, op_id: 0 })
+            );
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::FiatSaleSettled {
+                nft_id: context.nft_id,
+                token: context.token,
+                amount: PRICE,
+                op_id: 0,
+            }));
+        });
+    }
+
+    #[test]
+    fn settlement_cannot_be_recorded_for_nfts_not_listed_for_fiat_sale() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::record_fiat_settlement(
+                    Origin::root(),
+                    context.nft_id,
+                    context.token,
+                    PRICE
+                ),
+                Error::<TestRuntime>::NftNotListedForFiatSale
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_without_settlement_pays_royalties_on_the_recorded_settlement() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            context.record_settlement(PRICE);
+
+            assert_ok!(NftManager::signed_transfer_fiat_nft(
+                Origin::signed(context.owner),
+                fiat_transfer_proof(&context),
+                context.nft_id,
+                context.buyer_public_key(),
+            ));
+
+            assert_eq!(NftManager::nfts(context.nft_id).unwrap().owner, context.buyer);
+            assert_eq!(token_balance(&context.token, &context.owner), PRICE * 85 / 100);
+            assert_eq!(NftManager::fiat_settlements(context.nft_id), None);
+        });
+    }
+
+    #[test]
+    fn transfer_without_settlement_fails_when_nft_has_royalties() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            let proof = fiat_transfer_proof(&context);
+
+            assert_noop!(
+                NftManager::signed_transfer_fiat_nft(
+                    Origin::signed(context.owner),
+                    proof,
+                    context.nft_id,
+                    context.buyer_public_key(),
+                ),
+                Error::<TestRuntime>::FiatSettlementRequired
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_with_settlement_charges_royalties_to_seller() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            context.record_settlement(PRICE);
+            let proof = context.fiat_settlement_proof(PRICE);
+
+            assert_ok!(NftManager::signed_transfer_fiat_nft_with_settlement(
+                Origin::signed(context.owner),
+                proof,
+                context.nft_id,
+                context.buyer_public_key(),
+                context.token,
+                PRICE,
+            ));
+
+            assert_eq!(NftManager::nfts(context.nft_id).unwrap().owner, context.buyer);
+            assert_eq!(token_balance(&context.token, &context.owner), PRICE * 85 / 100);
+            assert_eq!(
+                NftManager::royalty_balances(context.t2_royalty_recipient(), context.token),
+                PRICE / 10
+            );
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::RoyaltyAccrued {
+                nft_id: context.nft_id,
+                recipient: context.t2_royalty_recipient(),
+                token: context.token,
+                amount: PRICE / 10,
+            }));
+        });
+    }
+
+    #[test]
+    fn transfer_with_settlement_uses_the_signed_settlement_when_none_is_recorded() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            let proof = context.fiat_settlement_proof(PRICE);
+
+            assert_ok!(NftManager::signed_transfer_fiat_nft_with_settlement(
+                Origin::signed(context.owner),
+                proof,
+                context.nft_id,
+                context.buyer_public_key(),
+                context.token,
+                PRICE,
+            ));
+
+            assert_eq!(NftManager::nfts(context.nft_id).unwrap().owner, context.buyer);
+            assert_eq!(
+                NftManager::royalty_balances(context.t2_royalty_recipient(), context.token),
+                PRICE / 10
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_with_settlement_fails_when_amount_was_not_signed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            context.record_settlement(PRICE);
+            let proof = context.fiat_settlement_proof(PRICE);
+
+            assert_noop!(
+                NftManager::signed_transfer_fiat_nft_with_settlement(
+                    Origin::signed(context.owner),
+                    proof,
+                    context.nft_id,
+                    context.buyer_public_key(),
+                    context.token,
+                    1,
+                ),
+                Error::<TestRuntime>::UnauthorizedSignedTransferFiatNftWithSettlementTransaction
+            );
+        });
+    }
+
+    #[test]
+    fn transfer_with_settlement_fails_when_amount_differs_from_the_recorded_one() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            context.record_settlement(PRICE);
+            let proof = context.fiat_settlement_proof(1);
+
+            assert_noop!(
+                NftManager::signed_transfer_fiat_nft_with_settlement(
+                    Origin::signed(context.owner),
+                    proof,
+                    context.nft_id,
+                    context.buyer_public_key(),
+                    context.token,
+                    1,
+                ),
+                Error::<TestRuntime>::FiatSettlementMismatch
+            );
+        });
+    }
+
+    #[test]
+    fn settlement_of_a_previous_listing_is_ignored() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            <NftOpenForSale<TestRuntime>>::insert(context.nft_id, NftSaleType::Fiat);
+            context.record_settlement(PRICE);
+            <Nfts<TestRuntime>>::mutate(context.nft_id, |nft| nft.as_mut().unwrap().nonce += 1);
+            let proof = context.fiat_settlement_proof(PRICE / 2);
+
+            assert_ok!(NftManager::signed_transfer_fiat_nft_with_settlement(
+                Origin::signed(context.owner),
+                proof,
+                context.nft_id,
+                context.buyer_public_key(),
+                context.token,
+                PRICE / 2,
+            ));
+
+            assert_eq!(
+                NftManager::royalty_balances(context.t2_royalty_recipient(), context.token),
+                PRICE / 20
+            );
+        });
+    }
+}
+
+mod legacy_royalty_payloads {
+    use super::*;
+
+    fn t1_authority() -> H160 {
+        return H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB"))
+    }
+
+    fn mint_proof<R: Encode>(context: &Context, royalties: &R) -> Proof<Signature, AccountId> {
+        let data_to_sign = (
+            SIGNED_MINT_SINGLE_NFT_CONTEXT,
+            context.relayer,
+            b"legacy external ref".to_vec(),
+            royalties,
+            t1_authority(),
+        )
+            .encode();
+        return Proof {
+            signer: context.owner,
+            relayer: context.relayer,
+            signature: sign(&context.owner_key_pair, &data_to_sign),
+        }
+    }
+
+    fn mint_call(proof: Proof<Signature, AccountId>, royalties: Vec<Royalty>) -> Box<RuntimeCall> {
+        return Box::new(RuntimeCall::NftManager(crate::Call::signed_mint_single_nft {
+            proof,
+            unique_external_ref: b"legacy external ref".to_vec(),
+            royalties,
+            t1_authority: t1_authority(),
+        }))
+    }
+
+    #[test]
+    fn mint_signed_with_legacy_royalties_is_accepted() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            let royalties = vec![Royalty {
+                recipient: RoyaltyRecipient::T1(context.t1_recipient()),
+                rate: RoyaltyRate { parts_per_million: 50_000 },
+            }];
+            let proof = mint_proof(&context, &legacy_royalties(&royalties).unwrap());
+
+            assert!(NftManager::signature_is_valid(&mint_call(proof.clone(), royalties.clone())));
+            assert_ok!(NftManager::signed_mint_single_nft(
+                Origin::signed(context.owner),
+                proof,
+                b"legacy external ref".to_vec(),
+                royalties,
+                t1_authority(),
+            ));
+        });
+    }
+
+    #[test]
+    fn legacy_royalties_cannot_pay_t2_recipients() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            let t1_royalty = Royalty {
+                recipient: RoyaltyRecipient::T1(context.t1_recipient()),
+                rate: RoyaltyRate { parts_per_million: 50_000 },
+            };
+            let royalties = vec![
+                t1_royalty.clone(),
+                Royalty {
+                    recipient: context.t2_royalty_recipient(),
+                    rate: RoyaltyRate { parts_per_million: 100_000 },
+                },
+            ];
+            assert!(legacy_royalties(&royalties).is_none());
+
+            let proof = mint_proof(&context, &legacy_royalties(&vec![t1_royalty]).unwrap());
+
+            assert!(!NftManager::signature_is_valid(&mint_call(proof.clone(), royalties.clone())));
+            assert_noop!(
+                NftManager::signed_mint_single_nft(
+                    Origin::signed(context.owner),
+                    proof,
+                    b"legacy external ref".to_vec(),
+                    royalties,
+                    t1_authority(),
+                ),
+                Error::<TestRuntime>::UnauthorizedSignedMintSingleNftTransaction
+            );
+        });
+    }
+}
+
+mod claims {
+    use super::*;
+
+    #[test]
+    fn t2_recipient_can_claim() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.sell_natively();
+
+            assert_ok!(NftManager::claim_royalties(
+                Origin::signed(context.t2_recipient),
+                context.token
+            ));
+
+            assert_eq!(token_balance(&context.token, &context.t2_recipient), PRICE / 10);
+            assert_eq!(
+                NftManager::royalty_balances(context.t2_royalty_recipient(), context.token),
+                0
+            );
+            assert!(context.event_emitted(crate::Event::<TestRuntime>::RoyaltiesClaimed {
+                recipient: context.t2_royalty_recipient(),
+                token: context.token,
+                amount: PRICE / 10,
+                destination: context.t2_recipient,
+            }));
+        });
+    }
+
+    #[test]
+    fn claim_fails_when_there_is_nothing_to_claim() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+
+            assert_noop!(
+                NftManager::claim_royalties(Origin::signed(context.t2_recipient), context.token),
+                Error::<TestRuntime>::NoRoyaltiesToClaim
+            );
+        });
+    }
+
+    #[test]
+    fn t1_recipient_can_claim_to_a_t2_account() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.sell_natively();
+            let signature = context.sign_t1_claim(&context.t1_recipient_key_pair, &context.buyer);
+
+            assert_ok!(NftManager::claim_t1_royalties(
+                Origin::signed(context.buyer),
+                context.t1_recipient(),
+                context.token,
+                signature,
+            ));
+
+            assert_eq!(token_balance(&context.token, &context.buyer), PRICE / 20);
+            assert_eq!(NftManager::royalty_claim_nonce(context.t1_recipient()), 1);
+        });
+    }
+
+    #[test]
+    fn t1_claim_cannot_be_replayed() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.sell_natively();
+            let signature = context.sign_t1_claim(&context.t1_recipient_key_pair, &context.buyer);
+            assert_ok!(NftManager::claim_t1_royalties(
+                Origin::signed(context.buyer),
+                context.t1_recipient(),
+                context.token,
+                signature.clone(),
+            ));
+
+            // More royalties accrue after the first claim
+            <RoyaltyBalances<TestRuntime>>::insert(
+                RoyaltyRecipient::T1(context.t1_recipient()),
+                context.token,
+                1,
+            );
+
+            assert_noop!(
+                NftManager::claim_t1_royalties(
+                    Origin::signed(context.buyer),
+                    context.t1_recipient(),
+                    context.token,
+                    signature,
+                ),
+                Error::<TestRuntime>::UnauthorizedRoyaltyClaim
+            );
+        });
+    }
+
+    #[test]
+    fn t1_claim_fails_when_signed_by_other_key() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            context.setup();
+            context.sell_natively();
+            let other_key_pair = ecdsa::Pair::from_seed(&[10u8; 32]);
+            let signature = context.sign_t1_claim(&other_key_pair, &context.buyer);
+
+            assert_noop!(
+                NftManager::claim_t1_royalties(
+                    Origin::signed(context.buyer),
+                    context.t1_recipient(),
+                    context.token,
+                    signature,
+                ),
+                Error::<TestRuntime>::UnauthorizedRoyaltyClaim
+            );
+        });
+    }
+}
+
+mod migration {
+    use super::*;
+    use frame_support::{storage::unhashed, traits::StorageVersion};
+
+    #[derive(Encode)]
+    struct V4Royalty {
+        recipient_t1_address: H160,
+        rate: RoyaltyRate,
+    }
+
+    #[derive(Encode)]
+    struct V4NftInfo {
+        info_id: NftInfoId,
+        batch_id: Option<NftBatchId>,
+        royalties: Vec<V4Royalty>,
+        total_supply: u64,
+        t1_authority: H160,
+        creator: Option<AccountId>,
+    }
+
+    #[test]
+    fn v4_royalties_are_translated_to_t1_recipients() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let info_id = U256::from(3u8);
+            let recipient = H160(hex!("afdf36201bf70F1232111b5c6a9a424558755134"));
+            let v4_info = V4NftInfo {
+                info_id,
+                batch_id: None,
+                royalties: vec![V4Royalty {
+                    recipient_t1_address: recipient,
+                    rate: RoyaltyRate { parts_per_million: 1_000 },
+                }],
+                total_supply: 1,
+                t1_authority: H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB")),
+                creator: None,
+            };
+            unhashed::put(&<NftInfos<TestRuntime>>::hashed_key_for(info_id), &v4_info);
+            StorageVersion::new(4).put::<NftManager>();
+
+            crate::migration::migrate_to_v5::<TestRuntime>();
+
+            let info = NftManager::nft_infos(info_id).unwrap();
+            assert_eq!(
+                info.royalties.into_inner(),
+                vec![Royalty {
+                    recipient: RoyaltyRecipient::T1(recipient),
+                    rate: RoyaltyRate { parts_per_million: 1_000 },
+                }]
+            );
+            assert_eq!(StorageVersion::get::<NftManager>(), StorageVersion::new(5));
+        });
+    }
+}
@@ -45,7 +45,9 @@ mod mint_single_nft {
                 unique_external_ref: String::from("Offchain location of NFT").into_bytes(),
                 owner: default_minter.account_id(),
                 royalties: vec![Royalty {
-                    recipient_t1_address: H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD")),
+                    recipient: RoyaltyRecipient::T1(H160(hex!(
+                        "33333CCCCC44444DDDDD33333CCCCC44444DDDDD"
+                    ))),
                     rate: RoyaltyRate { parts_per_million: 100 },
                 }],
                 t1_authority: H160(hex!("11111AAAAA22222BBBBB11111AAAAA22222BBBBB")),
@@ -221,7 +223,9 @@ mod mint_single_nft {
                 assert_eq!(false, context.event_emitted_with_single_nft_minted());
 
                 context.royalties = vec![Royalty {
-                    recipient_t1_address: H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD")),
+                    recipient: RoyaltyRecipient::T1(H160(hex!(
+                        "33333CCCCC44444DDDDD33333CCCCC44444DDDDD"
+                    ))),
                     rate: RoyaltyRate { parts_per_million: ROYALTY_RATE_DENOMINATOR + 1 },
                 }];
 
@@ -46,7 +46,9 @@ mod transfer_eth_nft {
                 nft_owner: nft_owner.account_id(),
                 new_nft_owner: H256::from([1u8; 32]),
                 royalties: vec![Royalty {
-                    recipient_t1_address: H160(hex!("33333CCCCC44444DDDDD33333CCCCC44444DDDDD")),
+                    recipient: RoyaltyRecipient::T1(H160(hex!(
+                        "33333CCCCC44444DDDDD33333CCCCC44444DDDDD"
+                    ))),
                     rate: RoyaltyRate { parts_per_million: 100 },
                 }],
                 unique_id: NftManager::next_unique_id(),
@@ -7,7 +7,7 @@ use super::*;
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_system::{EventRecord, RawOrigin};
 use sp_avn_common::{benchmarking::convert_sr25519_signature, Proof};
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{traits::Hash, SaturatedConversion};
 
 // Macro for comparing fixed point u128.
 #[allow(unused_macros)]
@@ -74,7 +74,7 @@ fn create_heartbeat<T: Config>(node: NodeId<T>, reward_period_index: RewardPerio
     let uptime = 1u64;
     let node_info = <NodeRegistry<T>>::get(&node).unwrap();
     let single_hb_weight =
-        Pallet::<T>::effective_heartbeat_weight(&node_info, Pallet::<T>::time_now_sec());
+        Pallet::<T>::effective_heartbeat_weight(&node, &node_info, Pallet::<T>::time_now_sec());
     let weight = single_hb_weight.saturating_mul(uptime.into());
 
     <NodeUptime<T>>::mutate(&reward_period_index, &node, |maybe_info| {
@@ -124,6 +124,20 @@ fn create_nodes_and_heartbeat<T: Config>(
     registered_nodes
 }
 
+// Delegate to `node` and credit the delegators with a reward of the next period, which activates
+// the delegated stake, so the next change has to settle the stake and pay the reward out.
+fn setup_delegation_with_reward<T: Config>(
+    delegator: &T::AccountId,
+    node: &NodeId<T>,
+    amount: BalanceOf<T>,
+) {
+    T::Currency::make_free_balance_be(delegator, 1_000_000u32.into());
+    Pallet::<T>::do_delegate_stake(delegator, node, amount).unwrap();
+    let pool = <DelegationPools<T>>::get(node).unwrap();
+    let next_period = <RewardPeriod<T>>::get().current.saturating_add(1);
+    Pallet::<T>::credit_delegators(&next_period, node, pool, 50u32.into());
+}
+
 fn set_max_batch_size<T: Config>(batch_size: u32) {
     <MaxBatchSize<T>>::set(batch_size);
 }
@@ -323,6 +337,28 @@ benchmarks! {
         assert!(<GenesisBonus25<T>>::get() == new_range);
     }
 
+    set_admin_config_unverified_heartbeat_weight {
+        let new_weight = Perbill::from_percent(25);
+        let config = AdminConfig::UnverifiedHeartbeatWeight(new_weight);
+    }: set_admin_config(RawOrigin::Root, config)
+    verify {
+        assert!(<UnverifiedHeartbeatWeight<T>>::get() == new_weight);
+    }
+
+    set_admin_config_participation_policy {
+        let policy = ParticipationPolicy {
+            min_participation: Perbill::from_percent(50),
+            absence_weight: Perbill::from_percent(50),
+            max_dissents: 3,
+            dissent_weight: Perbill::from_percent(75),
+            slash_percentage: Perbill::from_percent(1),
+        };
+        let config = AdminConfig::WatchtowerParticipationPolicy(Some(policy));
+    }: set_admin_config(RawOrigin::Root, config)
+    verify {
+        assert!(<WatchtowerParticipationPolicy<T>>::get() == Some(policy));
+    }
+
     on_initialise_with_new_reward_period {
         let reward_period = <RewardPeriod<T>>::get();
         let block_number: BlockNumberFor<T> = reward_period.first + BlockNumberFor::<T>::from(reward_period.length) + 1u32.into();
@@ -369,15 +405,24 @@ benchmarks! {
             frame_system::Pallet::<T>::block_number() + <NextHeartbeatPeriod<T>>::get().into() + 1u32.into()
         );
 
+        // Worst case: the referenced block hash is checked and does not match, so the reduced
+        // weight is read as well.
+        let evidence = Some(HeartbeatEvidence {
+            block_number: frame_system::Pallet::<T>::block_number()
+                .saturating_sub(HEARTBEAT_EVIDENCE_FINALITY_DEPTH.into()),
+            block_hash: T::Hashing::hash(b"not the block hash"),
+            work: None,
+        });
+
         let heartbeat_count = 1u64;
         let signature = signing_key.sign(
-            &(HEARTBEAT_CONTEXT, heartbeat_count, reward_period_index).encode()
+            &(HEARTBEAT_CONTEXT, heartbeat_count, reward_period_index, &evidence).encode()
         ).expect("Error signing");
-    }: offchain_submit_heartbeat(RawOrigin::None, node.clone(), reward_period_index, heartbeat_count, signature)
+    }: offchain_submit_heartbeat(RawOrigin::None, node.clone(), reward_period_index, heartbeat_count, evidence, signature)
     verify {
         let uptime_info = <NodeUptime<T>>::get(reward_period_index, &node).expect("No uptime info");
         assert!(uptime_info.count == heartbeat_count + 1);
-        assert_last_event::<T>(Event::HeartbeatReceived {reward_period_index, node}.into());
+        assert_last_event::<T>(Event::HeartbeatReceived {reward_period_index, node, verified: false}.into());
     }
 
     offchain_pay_nodes {
@@ -850,6 +895,90 @@ benchmarks! {
             stake: stake_per_node,
         }.into());
     }
+    delegate_stake {
+        enable_rewards::<T>();
+        fund_reward_pot::<T>();
+
+        let reward_period_index = <RewardPeriod<T>>::get().current;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let delegator: T::AccountId = account("delegator", 1, 1);
+        let nodes = create_nodes_and_heartbeat::<T>(owner.clone(), reward_period_index, 1);
+        let node_id = nodes.first().cloned().unwrap();
+        setup_delegation_with_reward::<T>(&delegator, &node_id, 100u32.into());
+    }: delegate_stake(RawOrigin::Signed(delegator.clone()), node_id.clone(), 100u32.into())
+    verify {
+        let delegation = <Delegations<T>>::get(&node_id, &delegator).expect("Delegation must exist");
+        assert!(delegation.stake.amount == 200u32.into());
+        assert_last_event::<T>(Event::StakeDelegated { delegator, node_id, reward_period: reward_period_index, amount: 100u32.into(), new_total: delegation.stake.amount }.into());
+    }
+
+    undelegate_stake {
+        enable_rewards::<T>();
+        fund_reward_pot::<T>();
+        UnstakePeriodSec::<T>::put(1_000u64);
+
+        let reward_period_index = <RewardPeriod<T>>::get().current;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let delegator: T::AccountId = account("delegator", 1, 1);
+        let nodes = create_nodes_and_heartbeat::<T>(owner.clone(), reward_period_index, 1);
+        let node_id = nodes.first().cloned().unwrap();
+        setup_delegation_with_reward::<T>(&delegator, &node_id, 100u32.into());
+        // Go forward in time to make the stake available for unstaking
+        pallet_timestamp::Pallet::<T>::set_timestamp(10_000 * 12_000);
+    }: undelegate_stake(RawOrigin::Signed(delegator.clone()), node_id.clone(), Some(10u32.into()))
+    verify {
+        let delegation = <Delegations<T>>::get(&node_id, &delegator).expect("Delegation must exist");
+        assert!(delegation.stake.amount == (100u32 - 10u32).into());
+        assert_last_event::<T>(Event::StakeUndelegated { delegator, node_id, reward_period: reward_period_index, amount: 10u32.into(), new_total: delegation.stake.amount }.into());
+    }
+
+    set_node_commission {
+        let owner: T::AccountId = account("owner", 1, 1);
+        let node_id: NodeId<T> = account("node", 2, 2);
+        register_new_node::<T>(node_id.clone(), owner.clone(), 0u32);
+        // An increase is the worst case, it schedules a commission request
+        let commission = T::MaxNodeCommission::get();
+        let now = RewardPeriod::<T>::get().current;
+        let execute_period = now.saturating_add(T::NodeCommissionIncreaseDelay::get());
+    }: set_node_commission(RawOrigin::Signed(owner.clone()), node_id.clone(), commission)
+    verify {
+        let pool = <DelegationPools<T>>::get(&node_id).unwrap();
+        assert_eq!(pool.effective_commission(execute_period), commission);
+        assert_last_event::<T>(Event::NodeCommissionIncreaseScheduled { owner, node_id, commission, execute_period }.into());
+    }
+
+    claim_delegation_reward {
+        enable_rewards::<T>();
+        fund_reward_pot::<T>();
+
+        let reward_period_index = <RewardPeriod<T>>::get().current;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let delegator: T::AccountId = account("delegator", 1, 1);
+        let nodes = create_nodes_and_heartbeat::<T>(owner.clone(), reward_period_index, 1);
+        let node_id = nodes.first().cloned().unwrap();
+        setup_delegation_with_reward::<T>(&delegator, &node_id, 100u32.into());
+    }: claim_delegation_reward(RawOrigin::Signed(delegator.clone()), node_id.clone())
+    verify {
+        assert!(<UnclaimedDelegationRewards<T>>::get().is_zero());
+        assert_last_event::<T>(Event::DelegationRewardClaimed { delegator, node_id, amount: 50u32.into() }.into());
+    }
+
+    prune_watchtower_record {
+        let period: RewardPeriodIndex = 0;
+        let proposal_id = ProposalId::repeat_byte(1);
+        ResolvedProposalIds::<T>::insert(period, proposal_id, ());
+        WatchtowerOutcomes::<T>::insert(proposal_id, (period, true));
+        WatchtowerPruneCursor::<T>::put(period);
+        OldestUnpaidRewardPeriodIndex::<T>::put(period + 1);
+        let mut meter = WeightMeter::with_limit(
+            T::DbWeight::get().reads(2).saturating_add(<T as Config>::WeightInfo::prune_watchtower_record())
+        );
+    }: {
+        Pallet::<T>::prune_watchtower_records(&mut meter);
+    }
+    verify {
+        assert!(!WatchtowerOutcomes::<T>::contains_key(proposal_id));
+    }
 }
 
 impl_benchmark_test_suite!(
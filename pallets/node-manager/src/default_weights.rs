@@ -53,6 +53,8 @@ pub trait WeightInfo {
 	fn set_admin_config_num_periods_to_mint() -> Weight;
 	fn set_admin_config_genesis_bonus_50() -> Weight;
 	fn set_admin_config_genesis_bonus_25() -> Weight;
+	fn set_admin_config_unverified_heartbeat_weight() -> Weight;
+	fn set_admin_config_participation_policy() -> Weight;
 	fn on_initialise_with_new_reward_period() -> Weight;
 	fn on_initialise_no_reward_period() -> Weight;
 	fn offchain_submit_heartbeat() -> Weight;
@@ -70,6 +72,11 @@ pub trait WeightInfo {
 	fn move_nodes(b: u32, ) -> Weight;
 	fn move_stake(b: u32, ) -> Weight;
 	fn move_nodes_with_stake(b: u32, ) -> Weight;
+	fn delegate_stake() -> Weight;
+	fn undelegate_stake() -> Weight;
+	fn set_node_commission() -> Weight;
+	fn claim_delegation_reward() -> Weight;
+	fn prune_watchtower_record() -> Weight;
 }
 
 /// Weights for pallet_node_manager using the Substrate node and recommended hardware.
@@ -93,13 +100,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NodeManager::TotalRegisteredNodes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::OwnedNodes` (r:0 w:1)
 	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	fn register_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `433`
 		//  Estimated: `3656`
 		// Minimum execution time: 67_881_000 picoseconds.
 		Weight::from_parts(84_332_000, 3656)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -122,13 +131,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NodeManager::NextBonusNodeSerialNumber` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::OwnedNodes` (r:0 w:1)
 	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	fn register_bonus_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `433`
 		//  Estimated: `3656`
 		// Minimum execution time: 68_472_000 picoseconds.
 		Weight::from_parts(69_671_000, 3656)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:1)
@@ -291,6 +302,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(10_670_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `NodeManager::UnverifiedHeartbeatWeight` (r:0 w:1)
+	/// Proof: `NodeManager::UnverifiedHeartbeatWeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_admin_config_unverified_heartbeat_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_170_000 picoseconds.
+		Weight::from_parts(10_480_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NodeManager::WatchtowerParticipationPolicy` (r:0 w:1)
+	/// Proof: `NodeManager::WatchtowerParticipationPolicy` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	fn set_admin_config_participation_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_250_000 picoseconds.
+		Weight::from_parts(10_590_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `NodeManager::RewardEnabled` (r:1 w:0)
 	/// Proof: `NodeManager::RewardEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::RewardPeriod` (r:1 w:1)
@@ -342,13 +373,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::TotalUptime` (r:1 w:1)
 	/// Proof: `NodeManager::TotalUptime` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnverifiedHeartbeatWeight` (r:1 w:0)
+	/// Proof: `NodeManager::UnverifiedHeartbeatWeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn offchain_submit_heartbeat() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `892`
 		//  Estimated: `3656`
-		// Minimum execution time: 60_641_000 picoseconds.
-		Weight::from_parts(61_272_000, 3656)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 63_812_000 picoseconds.
+		Weight::from_parts(64_507_000, 3656)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
@@ -375,6 +412,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NodeManager::RewardFeePercentage` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1000 w:1000)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:0 w:1000)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerParticipationPolicy` (r:1 w:0)
+	/// Proof: `NodeManager::WatchtowerParticipationPolicy` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::ResolvedProposals` (r:1 w:1)
+	/// Proof: `NodeManager::ResolvedProposals` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerParticipation` (r:1000 w:1000)
+	/// Proof: `NodeManager::WatchtowerParticipation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::PendingWatchtowerVotes` (r:1000 w:1000)
+	/// Proof: `NodeManager::PendingWatchtowerVotes` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerOutcomes` (r:1000 w:0)
+	/// Proof: `NodeManager::WatchtowerOutcomes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::TotalStake` (r:1000 w:1000)
+	/// Proof: `NodeManager::TotalStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 1000]`.
 	fn offchain_pay_nodes(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -384,10 +437,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_172_591_156, 6196)
 			// Standard Error: 522_066
 			.saturating_add(Weight::from_parts(52_651_652, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(13_u64))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 2666).saturating_mul(b.into()))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -408,13 +461,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NodeManager::TotalRegisteredNodes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::OwnedNodes` (r:0 w:1)
 	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	fn signed_register_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `505`
 		//  Estimated: `3656`
 		// Minimum execution time: 164_424_000 picoseconds.
 		Weight::from_parts(165_764_000, 3656)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -435,6 +490,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NodeManager::TotalStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::GenesisOverrides` (r:0 w:64)
 	/// Proof: `NodeManager::GenesisOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:64 w:64)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 64]`.
 	fn deregister_nodes(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -445,9 +502,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 158_337
 			.saturating_add(Weight::from_parts(29_821_597, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 2666).saturating_mul(b.into()))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -468,6 +525,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `NodeManager::TotalStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::GenesisOverrides` (r:0 w:64)
 	/// Proof: `NodeManager::GenesisOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:64 w:64)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 64]`.
 	fn signed_deregister_nodes(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -478,9 +537,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 49_995
 			.saturating_add(Weight::from_parts(32_432_347, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 2666).saturating_mul(b.into()))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -720,6 +779,117 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(12_u64))
 			.saturating_add(Weight::from_parts(0, 85).saturating_mul(n.into()))
 	}
+	/// Storage: `NodeManager::NodeRegistry` (r:1 w:0)
+	/// Proof: `NodeManager::NodeRegistry` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RewardPeriod` (r:1 w:0)
+	/// Proof: `NodeManager::RewardPeriod` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::Delegations` (r:1 w:1)
+	/// Proof: `NodeManager::Delegations` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnclaimedDelegationRewards` (r:1 w:1)
+	/// Proof: `NodeManager::UnclaimedDelegationRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::MaxUnstakePercentage` (r:1 w:0)
+	/// Proof: `NodeManager::MaxUnstakePercentage` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RestrictedUnstakeDurationSec` (r:1 w:0)
+	/// Proof: `NodeManager::RestrictedUnstakeDurationSec` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnstakePeriodSec` (r:1 w:0)
+	/// Proof: `NodeManager::UnstakePeriodSec` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
+	/// Proof: `NodeManager::OldestUnpaidRewardPeriodIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:1 w:1)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn delegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `6196`
+		// Minimum execution time: 118_372_000 picoseconds.
+		Weight::from_parts(121_504_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NodeManager::Delegations` (r:1 w:1)
+	/// Proof: `NodeManager::Delegations` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RewardPeriod` (r:1 w:0)
+	/// Proof: `NodeManager::RewardPeriod` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnclaimedDelegationRewards` (r:1 w:1)
+	/// Proof: `NodeManager::UnclaimedDelegationRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::NodeRegistry` (r:1 w:0)
+	/// Proof: `NodeManager::NodeRegistry` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnstakePeriodSec` (r:1 w:0)
+	/// Proof: `NodeManager::UnstakePeriodSec` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
+	/// Proof: `NodeManager::OldestUnpaidRewardPeriodIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:1 w:1)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn undelegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1487`
+		//  Estimated: `6196`
+		// Minimum execution time: 112_845_000 picoseconds.
+		Weight::from_parts(115_263_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NodeManager::OwnedNodes` (r:1 w:0)
+	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RewardPeriod` (r:1 w:0)
+	/// Proof: `NodeManager::RewardPeriod` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	fn set_node_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `3562`
+		// Minimum execution time: 23_104_000 picoseconds.
+		Weight::from_parts(23_912_000, 3562)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NodeManager::Delegations` (r:1 w:1)
+	/// Proof: `NodeManager::Delegations` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnclaimedDelegationRewards` (r:1 w:1)
+	/// Proof: `NodeManager::UnclaimedDelegationRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
+	/// Proof: `NodeManager::OldestUnpaidRewardPeriodIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:1 w:1)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn claim_delegation_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1104`
+		//  Estimated: `6196`
+		// Minimum execution time: 72_118_000 picoseconds.
+		Weight::from_parts(74_052_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NodeManager::ResolvedProposalIds` (r:1 w:1)
+	/// Proof: `NodeManager::ResolvedProposalIds` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerOutcomes` (r:0 w:1)
+	/// Proof: `NodeManager::WatchtowerOutcomes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn prune_watchtower_record() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3537`
+		// Minimum execution time: 11_042_000 picoseconds.
+		Weight::from_parts(11_618_000, 3537)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -742,13 +912,15 @@ impl WeightInfo for () {
 	/// Proof: `NodeManager::TotalRegisteredNodes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::OwnedNodes` (r:0 w:1)
 	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	fn register_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `433`
 		//  Estimated: `3656`
 		// Minimum execution time: 67_881_000 picoseconds.
 		Weight::from_parts(84_332_000, 3656)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -771,13 +943,15 @@ impl WeightInfo for () {
 	/// Proof: `NodeManager::NextBonusNodeSerialNumber` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::OwnedNodes` (r:0 w:1)
 	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	fn register_bonus_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `433`
 		//  Estimated: `3656`
 		// Minimum execution time: 68_472_000 picoseconds.
 		Weight::from_parts(69_671_000, 3656)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:1)
@@ -940,6 +1114,26 @@ impl WeightInfo for () {
 		Weight::from_parts(10_670_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `NodeManager::UnverifiedHeartbeatWeight` (r:0 w:1)
+	/// Proof: `NodeManager::UnverifiedHeartbeatWeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_admin_config_unverified_heartbeat_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_170_000 picoseconds.
+		Weight::from_parts(10_480_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NodeManager::WatchtowerParticipationPolicy` (r:0 w:1)
+	/// Proof: `NodeManager::WatchtowerParticipationPolicy` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	fn set_admin_config_participation_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_250_000 picoseconds.
+		Weight::from_parts(10_590_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `NodeManager::RewardEnabled` (r:1 w:0)
 	/// Proof: `NodeManager::RewardEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::RewardPeriod` (r:1 w:1)
@@ -991,13 +1185,19 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::TotalUptime` (r:1 w:1)
 	/// Proof: `NodeManager::TotalUptime` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnverifiedHeartbeatWeight` (r:1 w:0)
+	/// Proof: `NodeManager::UnverifiedHeartbeatWeight` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn offchain_submit_heartbeat() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `892`
 		//  Estimated: `3656`
-		// Minimum execution time: 60_641_000 picoseconds.
-		Weight::from_parts(61_272_000, 3656)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 63_812_000 picoseconds.
+		Weight::from_parts(64_507_000, 3656)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
@@ -1024,6 +1224,22 @@ impl WeightInfo for () {
 	/// Proof: `NodeManager::RewardFeePercentage` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1000 w:1000)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:0 w:1000)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerParticipationPolicy` (r:1 w:0)
+	/// Proof: `NodeManager::WatchtowerParticipationPolicy` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::ResolvedProposals` (r:1 w:1)
+	/// Proof: `NodeManager::ResolvedProposals` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerParticipation` (r:1000 w:1000)
+	/// Proof: `NodeManager::WatchtowerParticipation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::PendingWatchtowerVotes` (r:1000 w:1000)
+	/// Proof: `NodeManager::PendingWatchtowerVotes` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerOutcomes` (r:1000 w:0)
+	/// Proof: `NodeManager::WatchtowerOutcomes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::TotalStake` (r:1000 w:1000)
+	/// Proof: `NodeManager::TotalStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 1000]`.
 	fn offchain_pay_nodes(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1033,10 +1249,10 @@ impl WeightInfo for () {
 		Weight::from_parts(3_172_591_156, 6196)
 			// Standard Error: 522_066
 			.saturating_add(Weight::from_parts(52_651_652, 0).saturating_mul(b.into()))
-			.saturating_add(RocksDbWeight::get().reads(13_u64))
-			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(b.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 2666).saturating_mul(b.into()))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -1057,13 +1273,15 @@ impl WeightInfo for () {
 	/// Proof: `NodeManager::TotalRegisteredNodes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::OwnedNodes` (r:0 w:1)
 	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:0)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	fn signed_register_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `505`
 		//  Estimated: `3656`
 		// Minimum execution time: 164_424_000 picoseconds.
 		Weight::from_parts(165_764_000, 3656)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -1084,6 +1302,8 @@ impl WeightInfo for () {
 	/// Proof: `NodeManager::TotalStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::GenesisOverrides` (r:0 w:64)
 	/// Proof: `NodeManager::GenesisOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:64 w:64)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 64]`.
 	fn deregister_nodes(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1094,9 +1314,9 @@ impl WeightInfo for () {
 			// Standard Error: 158_337
 			.saturating_add(Weight::from_parts(29_821_597, 0).saturating_mul(b.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 2666).saturating_mul(b.into()))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -1117,6 +1337,8 @@ impl WeightInfo for () {
 	/// Proof: `NodeManager::TotalStake` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `NodeManager::GenesisOverrides` (r:0 w:64)
 	/// Proof: `NodeManager::GenesisOverrides` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:64 w:64)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 64]`.
 	fn signed_deregister_nodes(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1127,9 +1349,9 @@ impl WeightInfo for () {
 			// Standard Error: 49_995
 			.saturating_add(Weight::from_parts(32_432_347, 0).saturating_mul(b.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 2666).saturating_mul(b.into()))
 	}
 	/// Storage: `NodeManager::NodeRegistrar` (r:1 w:0)
@@ -1369,4 +1591,115 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(12_u64))
 			.saturating_add(Weight::from_parts(0, 85).saturating_mul(n.into()))
 	}
+	/// Storage: `NodeManager::NodeRegistry` (r:1 w:0)
+	/// Proof: `NodeManager::NodeRegistry` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RewardPeriod` (r:1 w:0)
+	/// Proof: `NodeManager::RewardPeriod` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::Delegations` (r:1 w:1)
+	/// Proof: `NodeManager::Delegations` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnclaimedDelegationRewards` (r:1 w:1)
+	/// Proof: `NodeManager::UnclaimedDelegationRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::MaxUnstakePercentage` (r:1 w:0)
+	/// Proof: `NodeManager::MaxUnstakePercentage` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RestrictedUnstakeDurationSec` (r:1 w:0)
+	/// Proof: `NodeManager::RestrictedUnstakeDurationSec` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnstakePeriodSec` (r:1 w:0)
+	/// Proof: `NodeManager::UnstakePeriodSec` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
+	/// Proof: `NodeManager::OldestUnpaidRewardPeriodIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:1 w:1)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn delegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `6196`
+		// Minimum execution time: 118_372_000 picoseconds.
+		Weight::from_parts(121_504_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NodeManager::Delegations` (r:1 w:1)
+	/// Proof: `NodeManager::Delegations` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RewardPeriod` (r:1 w:0)
+	/// Proof: `NodeManager::RewardPeriod` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnclaimedDelegationRewards` (r:1 w:1)
+	/// Proof: `NodeManager::UnclaimedDelegationRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::NodeRegistry` (r:1 w:0)
+	/// Proof: `NodeManager::NodeRegistry` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnstakePeriodSec` (r:1 w:0)
+	/// Proof: `NodeManager::UnstakePeriodSec` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
+	/// Proof: `NodeManager::OldestUnpaidRewardPeriodIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:1 w:1)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn undelegate_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1487`
+		//  Estimated: `6196`
+		// Minimum execution time: 112_845_000 picoseconds.
+		Weight::from_parts(115_263_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NodeManager::OwnedNodes` (r:1 w:0)
+	/// Proof: `NodeManager::OwnedNodes` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::RewardPeriod` (r:1 w:0)
+	/// Proof: `NodeManager::RewardPeriod` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	fn set_node_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `548`
+		//  Estimated: `3562`
+		// Minimum execution time: 23_104_000 picoseconds.
+		Weight::from_parts(23_912_000, 3562)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NodeManager::Delegations` (r:1 w:1)
+	/// Proof: `NodeManager::Delegations` (`max_values`: None, `max_size`: Some(203), added: 2678, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::DelegationPools` (r:1 w:1)
+	/// Proof: `NodeManager::DelegationPools` (`max_values`: None, `max_size`: Some(290), added: 2765, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::UnclaimedDelegationRewards` (r:1 w:1)
+	/// Proof: `NodeManager::UnclaimedDelegationRewards` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::OldestUnpaidRewardPeriodIndex` (r:1 w:0)
+	/// Proof: `NodeManager::OldestUnpaidRewardPeriodIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::StakeActivations` (r:1 w:1)
+	/// Proof: `NodeManager::StakeActivations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn claim_delegation_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1104`
+		//  Estimated: `6196`
+		// Minimum execution time: 72_118_000 picoseconds.
+		Weight::from_parts(74_052_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `NodeManager::ResolvedProposalIds` (r:1 w:1)
+	/// Proof: `NodeManager::ResolvedProposalIds` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `NodeManager::WatchtowerOutcomes` (r:0 w:1)
+	/// Proof: `NodeManager::WatchtowerOutcomes` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+	fn prune_watchtower_record() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3537`
+		// Minimum execution time: 11_042_000 picoseconds.
+		Weight::from_parts(11_618_000, 3537)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -0,0 +1,321 @@
+// Copyright 2026 Aventus DAO Ltd
+
+use crate::*;
+use sp_runtime::traits::UniqueSaturatedInto;
+
+impl<T: Config> Pallet<T> {
+    /// Stake delegated to `node_id` by accounts other than its owner.
+    pub fn delegated_stake(node_id: &NodeId<T>) -> BalanceOf<T> {
+        DelegationPools::<T>::get(node_id)
+            .map(|pool| pool.total_stake)
+            .unwrap_or_else(Zero::zero)
+    }
+
+    /// Reward earned by `delegator` from its delegation to `node_id` that has not been claimed yet.
+    pub fn delegation_reward(node_id: &NodeId<T>, delegator: &T::AccountId) -> BalanceOf<T> {
+        let (Some(pool), Some(mut delegation)) =
+            (DelegationPools::<T>::get(node_id), Delegations::<T>::get(node_id, delegator))
+        else {
+            return Zero::zero()
+        };
+
+        if let Some(activation) = delegation
+            .pending
+            .and_then(|pending| StakeActivations::<T>::get(node_id, pending.period))
+        {
+            delegation.activate_pending_stake(&activation);
+        }
+
+        delegation.pending_reward(&pool).unique_saturated_into()
+    }
+
+    pub fn do_delegate_stake(
+        delegator: &T::AccountId,
+        node_id: &NodeId<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+        let node_info = NodeRegistry::<T>::get(node_id).ok_or(Error::<T>::NodeNotRegistered)?;
+        ensure!(node_info.owner != *delegator, Error::<T>::OwnerCannotDelegate);
+
+        let now_sec = Self::time_now_sec();
+        let current_period = RewardPeriod::<T>::get().current;
+        let mut pool = DelegationPools::<T>::get(node_id).unwrap_or_default();
+        let mut delegation = Delegations::<T>::get(node_id, delegator).unwrap_or_default();
+
+        // Pay out what was earned at the old stake before changing it.
+        Self::settle_pending_stake(node_id, &mut pool, &mut delegation);
+        Self::pay_delegation_reward(delegator, node_id, &pool, &mut delegation)?;
+
+        let new_total = delegation
+            .stake
+            .amount
+            .checked_add(&amount)
+            .ok_or(Error::<T>::BalanceOverflow)?;
+
+        // New capital restarts the restricted window so it can't be withdrawn faster than
+        // `MaxUnstakePercentage` per `UnstakePeriodSec`.
+        delegation.stake.amount = new_total;
+        delegation.stake.restriction = UnstakeRestriction::Periodic {
+            per_period_allowance: <MaxUnstakePercentage<T>>::get() * new_total,
+            expires_sec: now_sec.saturating_add(<RestrictedUnstakeDurationSec<T>>::get()),
+        };
+        if delegation.stake.next_unstake_time_sec.is_none() {
+            delegation.stake.next_unstake_time_sec =
+                Some(now_sec.saturating_add(<UnstakePeriodSec<T>>::get()));
+        }
+
+        pool.total_stake =
+            pool.total_stake.checked_add(&amount).ok_or(Error::<T>::BalanceOverflow)?;
+
+        // New stake was not delegated for the whole of the current period, so it only earns from
+        // the next one. Stake still waiting for an earlier period waits with it.
+        let pending_amount = match delegation.pending.take() {
+            Some(pending) => {
+                pool.remove_pending_stake(pending.period, pending.amount);
+                pending.amount.checked_add(&amount).ok_or(Error::<T>::BalanceOverflow)?
+            },
+            None => amount,
+        };
+        pool.add_pending_stake::<T>(current_period, pending_amount)?;
+        delegation.pending = Some(PendingStake { period: current_period, amount: pending_amount });
+        delegation.reward_debt = pool.accrued_reward(delegation.earning_stake());
+
+        Self::update_reserves(delegator, amount, StakeOperation::Add)?;
+
+        Delegations::<T>::insert(node_id, delegator, delegation);
+        DelegationPools::<T>::insert(node_id, pool);
+
+        Ok(new_total)
+    }
+
+    pub fn do_undelegate_stake(
+        delegator: &T::AccountId,
+        node_id: &NodeId<T>,
+        maybe_amount: Option<BalanceOf<T>>,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let mut delegation =
+            Delegations::<T>::get(node_id, delegator).ok_or(Error::<T>::DelegationNotFound)?;
+        let mut pool = DelegationPools::<T>::get(node_id).ok_or(Error::<T>::DelegationNotFound)?;
+
+        Self::settle_pending_stake(node_id, &mut pool, &mut delegation);
+        Self::pay_delegation_reward(delegator, node_id, &pool, &mut delegation)?;
+
+        let now_sec = Self::time_now_sec();
+        let node_registered = NodeRegistry::<T>::contains_key(node_id);
+
+        let (available, next_unstake) = if node_registered {
+            delegation
+                .stake
+                .available_to_unstake(now_sec, <UnstakePeriodSec<T>>::get(), now_sec)
+                .map_err(|e| match e {
+                    DispatchError::Arithmetic(_) => Error::<T>::BalanceOverflow.into(),
+                    other => other,
+                })?
+        } else {
+            // The node has been deregistered so its delegators are free to leave.
+            (delegation.stake.amount, None)
+        };
+
+        let amount = match maybe_amount {
+            Some(requested) => {
+                ensure!(!requested.is_zero(), Error::<T>::ZeroAmount);
+                ensure!(
+                    delegation.stake.amount >= requested,
+                    Error::<T>::InsufficientStakedBalance
+                );
+                ensure!(requested <= available, Error::<T>::NoAvailableStakeToUnstake);
+                requested
+            },
+            None => {
+                ensure!(available > Zero::zero(), Error::<T>::NoAvailableStakeToUnstake);
+                available
+            },
+        };
+
+        let new_total = delegation
+            .stake
+            .amount
+            .checked_sub(&amount)
+            .ok_or(Error::<T>::InsufficientStakedBalance)?;
+
+        delegation.stake.amount = new_total;
+        delegation.stake.next_unstake_time_sec = next_unstake;
+        delegation.stake.unlocked_stake =
+            available.checked_sub(&amount).ok_or(Error::<T>::BalanceUnderflow)?;
+
+        // Stake that is not earning yet is withdrawn first
+        if let Some(mut pending) = delegation.pending {
+            let withdrawn = pending.amount.min(amount);
+            pool.remove_pending_stake(pending.period, withdrawn);
+            pending.amount = pending.amount.saturating_sub(withdrawn);
+            delegation.pending = Some(pending).filter(|pending| !pending.amount.is_zero());
+        }
+
+        pool.total_stake =
+            pool.total_stake.checked_sub(&amount).ok_or(Error::<T>::BalanceUnderflow)?;
+        delegation.reward_debt = pool.accrued_reward(delegation.earning_stake());
+
+        Self::update_reserves(delegator, amount, StakeOperation::Remove)?;
+
+        if new_total.is_zero() {
+            Delegations::<T>::remove(node_id, delegator);
+        } else {
+            Delegations::<T>::insert(node_id, delegator, delegation);
+        }
+
+        if pool.total_stake.is_zero() && !node_registered {
+            DelegationPools::<T>::remove(node_id);
+        } else {
+            DelegationPools::<T>::insert(node_id, pool);
+        }
+
+        Ok((amount, new_total))
+    }
+
+    pub fn do_claim_delegation_reward(
+        delegator: &T::AccountId,
+        node_id: &NodeId<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let mut delegation =
+            Delegations::<T>::get(node_id, delegator).ok_or(Error::<T>::DelegationNotFound)?;
+        let mut pool = DelegationPools::<T>::get(node_id).ok_or(Error::<T>::DelegationNotFound)?;
+
+        Self::settle_pending_stake(node_id, &mut pool, &mut delegation);
+        let amount = Self::pay_delegation_reward(delegator, node_id, &pool, &mut delegation)?;
+        ensure!(!amount.is_zero(), Error::<T>::NoDelegationRewardToClaim);
+
+        Delegations::<T>::insert(node_id, delegator, delegation);
+        DelegationPools::<T>::insert(node_id, pool);
+        Ok(amount)
+    }
+
+    /// Start earning with the stake delegated to `node_id` during reward periods before `period`.
+    /// The rewards of those periods have been credited, so the stake joins at the pool's current
+    /// `reward_per_stake`.
+    pub(crate) fn activate_pending_stake(
+        node_id: &NodeId<T>,
+        pool: &mut DelegationPool<BalanceOf<T>>,
+        period: RewardPeriodIndex,
+    ) {
+        for pending in pool.pending_stake.iter().filter(|pending| pending.period < period) {
+            StakeActivations::<T>::insert(
+                node_id,
+                pending.period,
+                StakeActivation { reward_per_stake: pool.reward_per_stake, amount: pending.amount },
+            );
+        }
+        pool.pending_stake.retain(|pending| pending.period >= period);
+    }
+
+    /// Move the pending stake of `delegation` to its earning stake once it has been activated. The
+    /// caller is responsible for storing the updated `pool` and `delegation`.
+    fn settle_pending_stake(
+        node_id: &NodeId<T>,
+        pool: &mut DelegationPool<BalanceOf<T>>,
+        delegation: &mut Delegation<BalanceOf<T>>,
+    ) {
+        // Periods before the oldest unpaid one will not be credited anymore
+        Self::activate_pending_stake(node_id, pool, OldestUnpaidRewardPeriodIndex::<T>::get());
+
+        let Some(pending) = delegation.pending else { return };
+        let Some(mut activation) = StakeActivations::<T>::get(node_id, pending.period) else {
+            return
+        };
+
+        delegation.activate_pending_stake(&activation);
+
+        activation.amount = activation.amount.saturating_sub(pending.amount);
+        if activation.amount.is_zero() {
+            StakeActivations::<T>::remove(node_id, pending.period);
+        } else {
+            StakeActivations::<T>::insert(node_id, pending.period, activation);
+        }
+    }
+
+    /// Transfer any unclaimed reward of `delegation` from the reward pot to the delegator. The
+    /// caller is responsible for storing the updated `delegation`.
+    fn pay_delegation_reward(
+        delegator: &T::AccountId,
+        node_id: &NodeId<T>,
+        pool: &DelegationPool<BalanceOf<T>>,
+        delegation: &mut Delegation<BalanceOf<T>>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let pending: BalanceOf<T> = delegation.pending_reward(pool).unique_saturated_into();
+        if pending.is_zero() {
+            return Ok(pending)
+        }
+
+        T::Currency::transfer(
+            &Self::compute_reward_account_id(),
+            delegator,
+            pending,
+            ExistenceRequirement::KeepAlive,
+        )?;
+
+        UnclaimedDelegationRewards::<T>::mutate(|unclaimed| {
+            *unclaimed = unclaimed.saturating_sub(pending);
+        });
+        delegation.reward_debt = pool.accrued_reward(delegation.earning_stake());
+
+        Self::deposit_event(Event::DelegationRewardClaimed {
+            delegator: delegator.clone(),
+            node_id: node_id.clone(),
+            amount: pending,
+        });
+
+        Ok(pending)
+    }
+
+    /// Part of a node's reward for `period` earned by its delegators, after the owner's commission.
+    /// The stake multiplier is `1 + (owner stake + delegated stake) / VirtualNodeStake`, so the
+    /// delegated stake contributed `delegated / (VirtualNodeStake + owner stake + delegated)` of
+    /// the node's reward weight. Only stake delegated for the whole of `period` counts. The
+    /// genesis bonus scales every term equally.
+    pub fn delegators_reward_share(
+        period: RewardPeriodIndex,
+        node_info: &NodeInfo<T::SignerId, T::AccountId, BalanceOf<T>>,
+        pool: &DelegationPool<BalanceOf<T>>,
+        reward: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let delegated: u128 = pool.earning_stake(period).unique_saturated_into();
+        let step: u128 = T::VirtualNodeStake::get().unique_saturated_into();
+
+        if delegated.is_zero() || step.is_zero() {
+            return Zero::zero()
+        }
+
+        let owner_stake: u128 = node_info.stake.amount.unique_saturated_into();
+        let ratio = Perquintill::from_rational(
+            delegated,
+            step.saturating_add(owner_stake).saturating_add(delegated),
+        );
+        let reward_u128: u128 = reward.saturated_into();
+        let gross: BalanceOf<T> = ratio.mul_floor(reward_u128).saturated_into();
+
+        gross.saturating_sub(pool.effective_commission(period).mul_floor(gross))
+    }
+
+    /// Credit `amount` of a paid reward to the delegators of `node_id` that delegated for the whole
+    /// of `period`. The funds stay in the reward pot until each delegator claims them.
+    pub(crate) fn credit_delegators(
+        period: &RewardPeriodIndex,
+        node_id: &NodeId<T>,
+        mut pool: DelegationPool<BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) {
+        Self::activate_pending_stake(node_id, &mut pool, *period);
+        pool.credit_reward(*period, amount);
+        DelegationPools::<T>::insert(node_id, pool);
+        UnclaimedDelegationRewards::<T>::mutate(|unclaimed| {
+            *unclaimed = unclaimed.saturating_add(amount);
+        });
+
+        Self::deposit_event(Event::DelegatorRewardCredited {
+            reward_period: *period,
+            node: node_id.clone(),
+            amount,
+        });
+    }
+}
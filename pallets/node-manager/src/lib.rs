@@ -15,8 +15,10 @@ use frame_support::{
     pallet_prelude::*,
     storage::{generator::StorageDoubleMap as StorageDoubleMapTrait, PrefixIterator},
     traits::{
-        Currency, ExistenceRequirement, IsSubType, ReservableCurrency, StorageVersion, UnixTime,
+        BalanceStatus, Currency, ExistenceRequirement, IsSubType, NamedReservableCurrency,
+        StorageVersion, UnixTime,
     },
+    weights::WeightMeter,
     PalletId,
 };
 use frame_system::{
@@ -33,7 +35,7 @@ use sp_avn_common::{
     AppChainInterface, BridgeContractMethod, PaymentHandler, RewardPeriodIndex,
     REGISTERED_NODE_KEY,
 };
-use sp_core::{MaxEncodedLen, H160};
+use sp_core::{MaxEncodedLen, H160, H256};
 use sp_runtime::{
     offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
     scale_info::TypeInfo,
@@ -47,8 +49,12 @@ use sp_runtime::{
     },
     DispatchError, Perbill, Perquintill, RuntimeDebug, Saturating,
 };
+use sp_watchtower::ProposalId;
 
+pub mod delegation;
+pub mod migration;
 pub mod offchain;
+pub mod participation;
 pub mod reward;
 pub mod stake;
 pub mod types;
@@ -69,9 +75,15 @@ mod test_admin;
 #[path = "tests/test_auto_stake_preference.rs"]
 mod test_auto_stake_preference;
 #[cfg(test)]
+#[path = "tests/test_delegation.rs"]
+mod test_delegation;
+#[cfg(test)]
 #[path = "tests/test_heartbeat.rs"]
 mod test_heartbeat;
 #[cfg(test)]
+#[path = "tests/test_migration.rs"]
+mod test_migration;
+#[cfg(test)]
 #[path = "tests/test_move_nodes.rs"]
 mod test_move_nodes;
 #[cfg(test)]
@@ -86,6 +98,9 @@ mod test_reward_payment;
 #[cfg(test)]
 #[path = "tests/test_stake_weight.rs"]
 mod test_stake_weight;
+#[cfg(test)]
+#[path = "tests/test_watchtower_participation.rs"]
+mod test_watchtower_participation;
 
 // Definition of the crypto to use for signing
 pub mod sr25519 {
@@ -103,13 +118,22 @@ use sp_std::prelude::*;
 const PAYOUT_REWARD_CONTEXT: &'static [u8] = b"NodeManager_RewardPayout";
 const MINT_REWARDS_CONTEXT: &'static [u8] = b"NodeManager_MintRewards";
 const HEARTBEAT_CONTEXT: &'static [u8] = b"NodeManager_heartbeat";
+// Oldest block, relative to the current one, a heartbeat can reference as evidence. This must stay
+// well below `frame_system::Config::BlockHashCount` so the referenced hash is still in storage.
+const HEARTBEAT_EVIDENCE_MAX_AGE: u32 = 64;
+// Newest block, relative to the current one, a heartbeat can reference as evidence, so nodes prove
+// they follow the finalised chain rather than a fork.
+const HEARTBEAT_EVIDENCE_FINALITY_DEPTH: u32 = 10;
 const MAX_BATCH_SIZE: u32 = 1_000;
 const MINT_SAFETY_CAP_MULTIPLIER: u32 = 4;
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+/// Identifies the balance reserved for node and delegation stake
+pub const STAKE_RESERVE_ID: [u8; 8] = *b"nodestak";
 pub const SIGNED_REGISTER_NODE_CONTEXT: &[u8] = b"register_node";
 pub const SIGNED_DEREGISTER_NODE_CONTEXT: &[u8] = b"deregister_node";
 pub const MAX_NODES: u32 = 64;
 pub const MAX_STAKE_CHANGES_PER_PERIOD: u32 = 256;
+pub const MAX_PENDING_STAKE_PERIODS: u32 = 8;
 
 const PALLET_ID: &'static [u8; 12] = b"node-manager";
 
@@ -143,6 +167,8 @@ pub(crate) type NodeId<T> = <T as frame_system::Config>::AccountId;
 pub type MaxNodes = ConstU32<MAX_NODES>;
 /// Max stake changes per period
 pub type MaxStakeChangesPerPeriod = ConstU32<MAX_STAKE_CHANGES_PER_PERIOD>;
+/// Max unpaid reward periods in which stake was delegated to a node
+pub type MaxPendingStakePeriods = ConstU32<MAX_PENDING_STAKE_PERIODS>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -330,6 +356,98 @@ pub mod pallet {
     pub type GenesisOverrides<T: Config> =
         StorageMap<_, Blake2_128Concat, u32, GenesisBonus, OptionQuery>;
 
+    /// Commission, delegated stake and reward accumulator by node
+    #[pallet::storage]
+    pub type DelegationPools<T: Config> =
+        StorageMap<_, Blake2_128Concat, NodeId<T>, DelegationPool<BalanceOf<T>>, OptionQuery>;
+
+    /// Stake delegated by an account to a node
+    #[pallet::storage]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        NodeId<T>,
+        Blake2_128Concat,
+        T::AccountId, // Delegator
+        Delegation<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Stake delegated to a node during a reward period that now earns rewards, keyed by the
+    /// period it was delegated in
+    #[pallet::storage]
+    pub type StakeActivations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        NodeId<T>,
+        Blake2_128Concat,
+        RewardPeriodIndex,
+        StakeActivation<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Rewards credited to delegators that are still held in the reward pot
+    #[pallet::storage]
+    pub type UnclaimedDelegationRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultUnverifiedHeartbeatWeight() -> Perbill {
+        Perbill::from_percent(50)
+    }
+
+    /// Share of the normal weight given to heartbeats without valid evidence of work
+    #[pallet::storage]
+    pub type UnverifiedHeartbeatWeight<T: Config> =
+        StorageValue<_, Perbill, ValueQuery, DefaultUnverifiedHeartbeatWeight>;
+
+    /// Watchtower proposals resolved in a reward period
+    #[pallet::storage]
+    pub type ResolvedProposals<T: Config> =
+        StorageMap<_, Blake2_128Concat, RewardPeriodIndex, u32, ValueQuery>;
+
+    /// Watchtower proposals resolved in a reward period, kept so their outcomes can be pruned
+    #[pallet::storage]
+    pub type ResolvedProposalIds<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RewardPeriodIndex,
+        Blake2_128Concat,
+        ProposalId,
+        (),
+        OptionQuery,
+    >;
+
+    /// The reward period a watchtower proposal resolved in and whether it passed
+    #[pallet::storage]
+    pub type WatchtowerOutcomes<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProposalId, (RewardPeriodIndex, bool), OptionQuery>;
+
+    /// The latest watchtower vote of each node that has not yet been counted in its participation
+    #[pallet::storage]
+    pub type PendingWatchtowerVotes<T: Config> =
+        StorageMap<_, Blake2_128Concat, NodeId<T>, (ProposalId, bool), OptionQuery>;
+
+    /// Watchtower voting record of each node in a reward period
+    #[pallet::storage]
+    pub type WatchtowerParticipation<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        RewardPeriodIndex,
+        Blake2_128Concat,
+        NodeId<T>,
+        ParticipationInfo,
+        ValueQuery,
+    >;
+
+    /// The oldest paid reward period whose watchtower records have not all been pruned yet
+    #[pallet::storage]
+    pub type WatchtowerPruneCursor<T: Config> = StorageValue<_, RewardPeriodIndex, OptionQuery>;
+
+    /// Penalties for nodes that miss watchtower votes. No penalties are applied if unset
+    #[pallet::storage]
+    pub type WatchtowerParticipationPolicy<T: Config> =
+        StorageValue<_, ParticipationPolicy, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub _phantom: sp_std::marker::PhantomData<T>,
@@ -447,8 +565,12 @@ pub mod pallet {
         BatchSizeSet { new_size: u32 },
         /// Heartbeat period set
         NextHeartbeatPeriodSet { new_heartbeat_period: u32 },
-        /// Heartbeat received
-        HeartbeatReceived { reward_period_index: RewardPeriodIndex, node: NodeId<T> },
+        /// Heartbeat received. `verified` is false if it was counted at reduced weight
+        HeartbeatReceived {
+            reward_period_index: RewardPeriodIndex,
+            node: NodeId<T>,
+            verified: bool,
+        },
         /// Reward amount per period set
         NextRewardAmountPerPeriodSet { new_amount: BalanceOf<T> },
         /// Number of periods to mint set
@@ -514,6 +636,56 @@ pub mod pallet {
         },
         /// Stake moved from multiple source nodes into a single destination node
         StakeMoved { owner: T::AccountId, to_node: NodeId<T>, total_amount: BalanceOf<T> },
+        /// Stake delegated to a node
+        StakeDelegated {
+            delegator: T::AccountId,
+            node_id: NodeId<T>,
+            reward_period: RewardPeriodIndex,
+            amount: BalanceOf<T>,
+            new_total: BalanceOf<T>,
+        },
+        /// Delegated stake withdrawn from a node
+        StakeUndelegated {
+            delegator: T::AccountId,
+            node_id: NodeId<T>,
+            reward_period: RewardPeriodIndex,
+            amount: BalanceOf<T>,
+            new_total: BalanceOf<T>,
+        },
+        /// Node commission set
+        NodeCommissionSet { owner: T::AccountId, node_id: NodeId<T>, commission: Perbill },
+        /// Node commission increase scheduled
+        NodeCommissionIncreaseScheduled {
+            owner: T::AccountId,
+            node_id: NodeId<T>,
+            commission: Perbill,
+            execute_period: RewardPeriodIndex,
+        },
+        /// Delegators' share of a node reward credited
+        DelegatorRewardCredited {
+            reward_period: RewardPeriodIndex,
+            node: NodeId<T>,
+            amount: BalanceOf<T>,
+        },
+        /// Delegation reward claimed
+        DelegationRewardClaimed {
+            delegator: T::AccountId,
+            node_id: NodeId<T>,
+            amount: BalanceOf<T>,
+        },
+        /// Weight of heartbeats without valid evidence set
+        UnverifiedHeartbeatWeightSet { weight: Perbill },
+        /// Watchtower participation policy set
+        WatchtowerParticipationPolicySet { policy: Option<ParticipationPolicy> },
+        /// Node penalised for its watchtower voting record
+        NodeParticipationPenalised {
+            reward_period: RewardPeriodIndex,
+            node: NodeId<T>,
+            participation: ParticipationInfo,
+            resolved_proposals: u32,
+            weight_kept: Perbill,
+            slashed: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -624,6 +796,18 @@ pub mod pallet {
         DuplicateNodeInList,
         /// Auto-stake window has expired for this node
         AutoStakeExpired,
+        /// Node owners must use add_stake instead of delegating to their own node
+        OwnerCannotDelegate,
+        /// No delegation found for this account and node
+        DelegationNotFound,
+        /// No delegation reward to claim
+        NoDelegationRewardToClaim,
+        /// Node still has delegated stake from a previous registration
+        NodeHasDelegations,
+        /// Commission is above the maximum allowed
+        CommissionTooHigh,
+        /// Too many unpaid reward periods with stake delegated to the node
+        TooManyPendingStakePeriods,
     }
 
     #[pallet::config]
@@ -643,7 +827,8 @@ pub mod pallet {
             + IsSubType<Call<Self>>
             + From<Call<Self>>;
         /// Currency used by this pallet
-        type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+        type Currency: Currency<Self::AccountId>
+            + NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
         // The identifier type for an offchain transaction signer.
         type SignerId: Member
             + Parameter
@@ -685,6 +870,14 @@ pub mod pallet {
         type AppChainInterface: AppChainInterface<AccountId = Self::AccountId>;
         #[pallet::constant]
         type BonusNodeSerialStart: Get<u32>;
+        /// Maximum commission a node owner can keep from its delegators' reward
+        #[pallet::constant]
+        type MaxNodeCommission: Get<Perbill>;
+        /// Number of reward periods to wait before a commission increase takes effect
+        #[pallet::constant]
+        type NodeCommissionIncreaseDelay: Get<RewardPeriodIndex>;
+        /// Provides and verifies the work evidence attached to heartbeats
+        type WorkVerifier: NodeWorkVerifier<Self::AccountId>;
     }
 
     #[pallet::call]
@@ -726,6 +919,8 @@ pub mod pallet {
             .max(<T as Config>::WeightInfo::set_admin_config_reward_fee_percentage())
             .max(<T as Config>::WeightInfo::set_admin_config_genesis_bonus_50())
             .max(<T as Config>::WeightInfo::set_admin_config_genesis_bonus_25())
+            .max(<T as Config>::WeightInfo::set_admin_config_unverified_heartbeat_weight())
+            .max(<T as Config>::WeightInfo::set_admin_config_participation_policy())
         )]
         pub fn set_admin_config(
             origin: OriginFor<T>,
@@ -850,6 +1045,20 @@ pub mod pallet {
                     Self::deposit_event(Event::GenesisBonus25Set { range });
                     Ok(Some(<T as Config>::WeightInfo::set_admin_config_genesis_bonus_25()).into())
                 },
+                AdminConfig::UnverifiedHeartbeatWeight(weight) => {
+                    <UnverifiedHeartbeatWeight<T>>::put(weight);
+                    Self::deposit_event(Event::UnverifiedHeartbeatWeightSet { weight });
+                    Ok(Some(
+                        <T as Config>::WeightInfo::set_admin_config_unverified_heartbeat_weight(),
+                    )
+                    .into())
+                },
+                AdminConfig::WatchtowerParticipationPolicy(policy) => {
+                    <WatchtowerParticipationPolicy<T>>::set(policy);
+                    Self::deposit_event(Event::WatchtowerParticipationPolicySet { policy });
+                    Ok(Some(<T as Config>::WeightInfo::set_admin_config_participation_policy())
+                        .into())
+                },
             }
         }
 
@@ -917,13 +1126,16 @@ pub mod pallet {
                 },
             }
 
+            let participation_policy = WatchtowerParticipationPolicy::<T>::get();
+            let resolved_proposals = ResolvedProposals::<T>::get(oldest_period);
+
             let pay = |node: &NodeId<T>,
                        uptime: UptimeInfo<BlockNumberFor<T>>|
              -> Result<Weight, DispatchError> {
-                let node_info =
+                let mut node_info =
                     NodeRegistry::<T>::get(node).ok_or(Error::<T>::NodeNotRegistered)?;
 
-                let node_weight = Self::calculate_node_weight(
+                let mut node_weight = Self::calculate_node_weight(
                     node,
                     uptime,
                     &node_info,
@@ -931,6 +1143,18 @@ pub mod pallet {
                     reward_pot.reward_end_time,
                 );
 
+                if let Some(policy) = participation_policy.as_ref() {
+                    Self::settle_watchtower_vote(node);
+                    node_weight = Self::apply_participation_penalty(
+                        &oldest_period,
+                        node,
+                        &mut node_info,
+                        policy,
+                        resolved_proposals,
+                        node_weight,
+                    );
+                }
+
                 let (reward_amount, reward_percentage) =
                     Self::calculate_reward(node_weight, &total_uptime.total_weight, &total_reward)?;
 
@@ -978,15 +1202,20 @@ pub mod pallet {
             .into())
         }
 
-        /// Offchain call: Submit heartbeat to show node is still alive
+        /// Offchain call: Submit heartbeat to show node is still alive.
+        /// Heartbeats without valid `evidence` are counted at `UnverifiedHeartbeatWeight`.
         #[pallet::call_index(3)]
-        #[pallet::weight(<T as Config>::WeightInfo::offchain_submit_heartbeat())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::offchain_submit_heartbeat()
+                .saturating_add(T::WorkVerifier::verify_work_weight())
+        )]
         pub fn offchain_submit_heartbeat(
             origin: OriginFor<T>,
             node: NodeId<T>,
             reward_period_index: RewardPeriodIndex,
             // This helps prevent signature re-use
             heartbeat_count: u64,
+            evidence: Option<HeartbeatEvidence<BlockNumberFor<T>, T::Hash>>,
             _signature: <T::SignerId as RuntimeAppPublic>::Signature,
         ) -> DispatchResult {
             ensure_none(origin)?;
@@ -997,6 +1226,7 @@ pub mod pallet {
             // if we pass validation we have a registered node but double check
             let node_info = NodeRegistry::<T>::get(&node).ok_or(Error::<T>::NodeNotRegistered)?;
             let now = frame_system::Pallet::<T>::block_number();
+            let verified = Self::heartbeat_evidence_is_valid(&node, &evidence);
 
             let weight = <NodeUptime<T>>::mutate(&current_reward_period, &node, |maybe_info| {
                 let info = maybe_info.get_or_insert_with(|| UptimeInfo {
@@ -1005,8 +1235,11 @@ pub mod pallet {
                     weight: 0,
                 });
 
-                let node_weight =
-                    Self::effective_heartbeat_weight(&node_info, Self::time_now_sec());
+                let mut node_weight =
+                    Self::effective_heartbeat_weight(&node, &node_info, Self::time_now_sec());
+                if !verified {
+                    node_weight = <UnverifiedHeartbeatWeight<T>>::get().mul_floor(node_weight);
+                }
 
                 info.count = info.count.saturating_add(1);
                 info.last_reported = now;
@@ -1024,6 +1257,7 @@ pub mod pallet {
             Self::deposit_event(Event::HeartbeatReceived {
                 reward_period_index: current_reward_period,
                 node,
+                verified,
             });
 
             Ok(())
@@ -1397,6 +1631,100 @@ pub mod pallet {
 
             Self::do_move_nodes_with_stake(&current_owner, &new_owner, &nodes, total_stake_to_move)
         }
+
+        /// Delegate stake to a node owned by another account. The delegated stake counts towards
+        /// the node's stake bonus and earns a share of its reward, minus the owner's commission.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::delegate_stake())]
+        pub fn delegate_stake(
+            origin: OriginFor<T>,
+            node_id: NodeId<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let reward_period = RewardPeriod::<T>::get().current;
+            let new_total = Self::do_delegate_stake(&delegator, &node_id, amount)?;
+
+            Self::deposit_event(Event::StakeDelegated {
+                delegator,
+                node_id,
+                reward_period,
+                amount,
+                new_total,
+            });
+            Ok(())
+        }
+
+        /// Withdraw delegated stake. Unstaking is limited to `MaxUnstakePercentage` of the
+        /// delegation per `UnstakePeriodSec` until the restricted window ends. If `None`, all
+        /// currently available stake is withdrawn.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::undelegate_stake())]
+        pub fn undelegate_stake(
+            origin: OriginFor<T>,
+            node_id: NodeId<T>,
+            maybe_amount: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            let reward_period = RewardPeriod::<T>::get().current;
+            let (amount, new_total) =
+                Self::do_undelegate_stake(&delegator, &node_id, maybe_amount)?;
+
+            Self::deposit_event(Event::StakeUndelegated {
+                delegator,
+                node_id,
+                reward_period,
+                amount,
+                new_total,
+            });
+            Ok(())
+        }
+
+        /// Set the share of the delegators' reward kept by the node owner
+        /// - decreases apply immediately
+        /// - increases apply to the rewards of periods starting `NodeCommissionIncreaseDelay`
+        ///   periods from now
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_node_commission())]
+        pub fn set_node_commission(
+            origin: OriginFor<T>,
+            node_id: NodeId<T>,
+            commission: Perbill,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(
+                <OwnedNodes<T>>::contains_key(&owner, &node_id),
+                Error::<T>::NodeNotOwnedByOwner
+            );
+
+            let now = RewardPeriod::<T>::get().current;
+            let execute_period = <DelegationPools<T>>::try_mutate(&node_id, |maybe_pool| {
+                maybe_pool
+                    .get_or_insert_with(Default::default)
+                    .set_commission::<T>(commission, now)
+            })?;
+
+            if execute_period > now {
+                Self::deposit_event(Event::NodeCommissionIncreaseScheduled {
+                    owner,
+                    node_id,
+                    commission,
+                    execute_period,
+                });
+            } else {
+                Self::deposit_event(Event::NodeCommissionSet { owner, node_id, commission });
+            }
+            Ok(())
+        }
+
+        /// Claim the reward earned by stake delegated to a node
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_delegation_reward())]
+        pub fn claim_delegation_reward(origin: OriginFor<T>, node_id: NodeId<T>) -> DispatchResult {
+            let delegator = ensure_signed(origin)?;
+            Self::do_claim_delegation_reward(&delegator, &node_id)?;
+            Ok(())
+        }
     }
 
     #[pallet::hooks]
@@ -1466,6 +1794,12 @@ pub mod pallet {
                 .saturating_add(hook_weight)
         }
 
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            Self::prune_watchtower_records(&mut meter);
+            meter.consumed()
+        }
+
         fn offchain_worker(n: BlockNumberFor<T>) {
             log::info!("🛠️  OCW for node manager");
 
@@ -1536,6 +1870,7 @@ pub mod pallet {
                     node,
                     reward_period_index,
                     heartbeat_count,
+                    evidence,
                     signature,
                 } => {
                     let node_info = NodeRegistry::<T>::get(&node);
@@ -1553,7 +1888,12 @@ pub mod pallet {
                             }
 
                             if !Self::offchain_signature_is_valid(
-                                &(HEARTBEAT_CONTEXT, heartbeat_count, reward_period_index),
+                                &(
+                                    HEARTBEAT_CONTEXT,
+                                    heartbeat_count,
+                                    reward_period_index,
+                                    evidence,
+                                ),
                                 &info.signing_key,
                                 signature,
                             ) {
@@ -1640,6 +1980,34 @@ pub mod pallet {
             Ok(())
         }
 
+        /// A heartbeat is verified if it references a recent finalised block by its correct hash
+        /// and proves work confirmed by `T::WorkVerifier`.
+        pub fn heartbeat_evidence_is_valid(
+            node: &NodeId<T>,
+            evidence: &Option<HeartbeatEvidence<BlockNumberFor<T>, T::Hash>>,
+        ) -> bool {
+            let Some(evidence) = evidence else { return false };
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let finalised =
+                now.saturating_sub(BlockNumberFor::<T>::from(HEARTBEAT_EVIDENCE_FINALITY_DEPTH));
+            if evidence.block_number > finalised ||
+                now.saturating_sub(evidence.block_number) >
+                    BlockNumberFor::<T>::from(HEARTBEAT_EVIDENCE_MAX_AGE)
+            {
+                return false
+            }
+
+            if frame_system::Pallet::<T>::block_hash(evidence.block_number) != evidence.block_hash {
+                return false
+            }
+
+            evidence
+                .work
+                .as_ref()
+                .map_or(false, |work| T::WorkVerifier::verify_work(node, work))
+        }
+
         fn do_deregister_nodes(
             owner: &T::AccountId,
             nodes: &BoundedVec<NodeId<T>, MaxNodes>,
@@ -1663,6 +2031,11 @@ pub mod pallet {
                     <TotalRegisteredBonusNodes<T>>::mutate(|n| *n = n.saturating_sub(1));
                 }
 
+                // Keep the pool while delegators still have stake to withdraw
+                if Self::delegated_stake(node).is_zero() {
+                    <DelegationPools<T>>::remove(node);
+                }
+
                 // Unreserve stake for this node if there is any
                 if !info.stake.amount.is_zero() {
                     Self::update_reserves(owner, info.stake.amount, StakeOperation::Remove)?;
@@ -1694,11 +2067,12 @@ pub mod pallet {
             to: &T::AccountId,
             amount: BalanceOf<T>,
         ) -> DispatchResult {
-            let leftover = T::Currency::unreserve(from, amount);
+            let leftover = T::Currency::unreserve_named(&STAKE_RESERVE_ID, from, amount);
             ensure!(leftover.is_zero(), Error::<T>::InsufficientStakedBalance);
             T::Currency::transfer(from, to, amount, ExistenceRequirement::AllowDeath)
                 .map_err(|_| Error::<T>::InsufficientFreeBalance)?;
-            T::Currency::reserve(to, amount).map_err(|_| Error::<T>::ReserveFailed)?;
+            T::Currency::reserve_named(&STAKE_RESERVE_ID, to, amount)
+                .map_err(|_| Error::<T>::ReserveFailed)?;
             Ok(())
         }
 
@@ -1874,6 +2248,7 @@ pub mod pallet {
             is_bonus: bool,
         ) -> DispatchResult {
             ensure!(!<NodeRegistry<T>>::contains_key(&node), Error::<T>::DuplicateNode);
+            ensure!(!<DelegationPools<T>>::contains_key(&node), Error::<T>::NodeHasDelegations);
             ensure!(
                 !SigningKeyToNodeId::<T>::contains_key(&signing_key),
                 Error::<T>::SigningKeyAlreadyInUse
@@ -0,0 +1,113 @@
+// Copyright 2026 Aventus DAO Ltd
+
+use crate::{
+    BalanceOf, Config, Delegations, Pallet, TotalStake, STAKE_RESERVE_ID, STORAGE_VERSION,
+};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{
+        Get, GetStorageVersion, NamedReservableCurrency, OnRuntimeUpgrade, ReservableCurrency,
+    },
+    weights::Weight,
+};
+use sp_runtime::{traits::Zero, Saturating};
+use sp_std::collections::btree_map::BTreeMap;
+
+#[cfg(feature = "try-runtime")]
+use crate::{DelegationPools, Vec};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+// Stake reserved by every owner and delegator, as tracked by this pallet
+fn tracked_stake<T: Config>() -> BTreeMap<T::AccountId, BalanceOf<T>> {
+    let mut stake = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+    for (owner, total) in <TotalStake<T>>::iter() {
+        stake.entry(owner).or_insert_with(Zero::zero).saturating_accrue(total);
+    }
+    for (_node, delegator, delegation) in <Delegations<T>>::iter() {
+        stake
+            .entry(delegator)
+            .or_insert_with(Zero::zero)
+            .saturating_accrue(delegation.stake.amount);
+    }
+    stake
+}
+
+pub fn migrate_to_v1<T: Config>() -> Weight {
+    let mut consumed_weight: Weight = Weight::zero();
+    let mut add_weight = |reads, writes, weight: Weight| {
+        consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
+        consumed_weight += weight;
+    };
+
+    log::info!("🚧 🚧 Running migration to move node and delegation stake to a named reserve");
+
+    let stake = tracked_stake::<T>();
+    add_weight(stake.len() as u64, 0, Weight::zero());
+
+    for (account, amount) in stake {
+        let not_unreserved = <T::Currency as ReservableCurrency<_>>::unreserve(&account, amount);
+        let moved = amount.saturating_sub(not_unreserved);
+        if let Err(e) = T::Currency::reserve_named(&STAKE_RESERVE_ID, &account, moved) {
+            log::error!("💔 Failed to move stake of {:?} to a named reserve: {:?}", account, e);
+        }
+        add_weight(2, 2, Weight::zero());
+    }
+
+    add_weight(0, 1, Weight::zero());
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    log::info!("✅ Node and delegation stake moved to a named reserve successfully");
+
+    // add a bit extra as safety margin for computation
+    return consumed_weight + Weight::from_parts(25_000_000 as u64, 0)
+}
+
+pub struct NodeManagerMigrations<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for NodeManagerMigrations<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::in_code_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        let mut total_weight = Weight::zero();
+
+        if onchain < 1 {
+            log::info!(
+                "💽 Running Node manager migration with current storage version {:?} / onchain {:?}",
+                current,
+                onchain
+            );
+            total_weight += migrate_to_v1::<T>();
+        } else if onchain < current {
+            // No storage layout changed since v1
+            STORAGE_VERSION.put::<Pallet<T>>();
+            total_weight += T::DbWeight::get().writes(1);
+        }
+
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        use codec::Encode;
+
+        let stake = tracked_stake::<T>().into_iter().collect::<Vec<_>>();
+        let pool_count = <DelegationPools<T>>::iter_keys().count() as u32;
+        Ok((stake, pool_count).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        use codec::Decode;
+
+        let (stake_before, pool_count): (Vec<(T::AccountId, BalanceOf<T>)>, u32) =
+            Decode::decode(&mut input.as_slice()).expect("Initial stake is invalid");
+        for (account, amount) in stake_before {
+            assert_eq!(T::Currency::reserved_balance_named(&STAKE_RESERVE_ID, &account), amount);
+        }
+        assert_eq!(<DelegationPools<T>>::iter().count() as u32, pool_count);
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+
+        Ok(())
+    }
+}
@@ -102,8 +102,11 @@ impl<T: Config> Pallet<T> {
                     block_number
                 );
 
-                let signature = signing_key
-                    .sign(&(HEARTBEAT_CONTEXT, heartbeat_count, current_reward_period).encode());
+                let evidence = Self::heartbeat_evidence(&node, block_number);
+                let signature = signing_key.sign(
+                    &(HEARTBEAT_CONTEXT, heartbeat_count, current_reward_period, &evidence)
+                        .encode(),
+                );
 
                 match signature {
                     Some(signature) => {
@@ -112,6 +115,7 @@ impl<T: Config> Pallet<T> {
                                 node,
                                 reward_period_index: current_reward_period,
                                 heartbeat_count,
+                                evidence,
                                 signature,
                             }
                             .into(),
@@ -145,6 +149,24 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    // Reference the block `HEARTBEAT_EVIDENCE_FINALITY_DEPTH` behind the one being processed, which
+    // is final by the time the heartbeat is included, and any work this node can prove.
+    fn heartbeat_evidence(
+        node: &NodeId<T>,
+        block_number: BlockNumberFor<T>,
+    ) -> Option<HeartbeatEvidence<BlockNumberFor<T>, T::Hash>> {
+        if block_number.is_zero() {
+            return None
+        }
+
+        let finalised_block = block_number.saturating_sub(HEARTBEAT_EVIDENCE_FINALITY_DEPTH.into());
+        Some(HeartbeatEvidence {
+            block_number: finalised_block,
+            block_hash: frame_system::Pallet::<T>::block_hash(finalised_block),
+            work: T::WorkVerifier::work_evidence(node),
+        })
+    }
+
     pub fn try_get_node_author(block_number: BlockNumberFor<T>) -> Option<(Author<T>, bool)> {
         let setup_result = AVN::<T>::pre_run_setup(block_number, OCW_ID.to_vec());
         if let Ok((this_author, _)) = setup_result {
@@ -0,0 +1,151 @@
+// Copyright 2026 Aventus DAO Ltd
+
+use crate::*;
+use sp_watchtower::{ProposalId, ProposalStatusEnum, WatchtowerHooks};
+
+impl<T: Config> Pallet<T> {
+    /// Record a node's vote on a watchtower proposal. The vote is counted once the proposal
+    /// resolves, and votes from accounts that are not registered nodes are ignored.
+    pub fn record_watchtower_vote(voter: &T::AccountId, proposal_id: ProposalId, in_favor: bool) {
+        if !NodeRegistry::<T>::contains_key(voter) {
+            return
+        }
+
+        // Proposals are voted on one at a time, so any earlier vote has either been decided or
+        // will never be
+        Self::settle_watchtower_vote(voter);
+        PendingWatchtowerVotes::<T>::insert(voter, (proposal_id, in_favor));
+    }
+
+    /// Record the outcome of a resolved watchtower proposal against the current reward period.
+    pub fn record_watchtower_outcome(proposal_id: ProposalId, passed: bool) {
+        let period = RewardPeriod::<T>::get().current;
+        ResolvedProposals::<T>::mutate(period, |resolved| *resolved = resolved.saturating_add(1));
+        ResolvedProposalIds::<T>::insert(period, proposal_id, ());
+        WatchtowerOutcomes::<T>::insert(proposal_id, (period, passed));
+    }
+
+    /// Count the node's pending vote in its participation if the proposal it voted on has
+    /// resolved. Votes on proposals that resolved in an already paid period are discarded. Only
+    /// votes against a proposal that passed are dissents: a proposal can fail for lack of votes in
+    /// favour, so voting for it is not a sign of a faulty node.
+    pub(crate) fn settle_watchtower_vote(node_id: &NodeId<T>) {
+        let Some((proposal_id, in_favor)) = PendingWatchtowerVotes::<T>::get(node_id) else {
+            return
+        };
+        let Some((period, passed)) = WatchtowerOutcomes::<T>::get(proposal_id) else { return };
+
+        PendingWatchtowerVotes::<T>::remove(node_id);
+        if period < OldestUnpaidRewardPeriodIndex::<T>::get() {
+            return
+        }
+
+        WatchtowerParticipation::<T>::mutate(period, node_id, |participation| {
+            participation.votes = participation.votes.saturating_add(1);
+            if passed && !in_favor {
+                participation.dissents = participation.dissents.saturating_add(1);
+            }
+        });
+    }
+
+    /// Reduce `weight` if the node's voting record in `period` breaches `policy`, slashing its
+    /// stake if the policy requires it. `node_info` is updated with any slashed stake.
+    pub(crate) fn apply_participation_penalty(
+        period: &RewardPeriodIndex,
+        node_id: &NodeId<T>,
+        node_info: &mut NodeInfo<T::SignerId, T::AccountId, BalanceOf<T>>,
+        policy: &ParticipationPolicy,
+        resolved_proposals: u32,
+        weight: u128,
+    ) -> u128 {
+        let participation = WatchtowerParticipation::<T>::get(period, node_id);
+        let weight_kept = policy.weight_kept(&participation, resolved_proposals);
+        if weight_kept == Perbill::one() {
+            return weight
+        }
+
+        let slashed = Self::slash_node_stake(node_id, node_info, policy.slash_percentage);
+
+        Self::deposit_event(Event::NodeParticipationPenalised {
+            reward_period: *period,
+            node: node_id.clone(),
+            participation,
+            resolved_proposals,
+            weight_kept,
+            slashed,
+        });
+
+        weight_kept.mul_floor(weight)
+    }
+
+    // Move `percentage` of the node's stake from its owner's stake reserve to the reward pot, where
+    // it funds future rewards. Balance reserved by other pallets is never touched. Returns the
+    // amount moved.
+    fn slash_node_stake(
+        node_id: &NodeId<T>,
+        node_info: &mut NodeInfo<T::SignerId, T::AccountId, BalanceOf<T>>,
+        percentage: Perbill,
+    ) -> BalanceOf<T> {
+        let amount = percentage * node_info.stake.amount;
+        if amount.is_zero() {
+            return amount
+        }
+
+        let owner = node_info.owner.clone();
+        let not_moved = match T::Currency::repatriate_reserved_named(
+            &STAKE_RESERVE_ID,
+            &owner,
+            &Self::compute_reward_account_id(),
+            amount,
+            BalanceStatus::Free,
+        ) {
+            Ok(not_moved) => not_moved,
+            Err(e) => {
+                log::error!("💔 Failed to slash stake of node {:?}. Error: {:?}", node_id, e);
+                return Zero::zero()
+            },
+        };
+
+        let slashed = amount.saturating_sub(not_moved);
+        node_info.stake.amount = node_info.stake.amount.saturating_sub(slashed);
+        node_info.stake.unlocked_stake = node_info.stake.unlocked_stake.min(node_info.stake.amount);
+        NodeRegistry::<T>::insert(node_id, node_info.clone());
+
+        TotalStake::<T>::mutate(&owner, |maybe_total| {
+            if let Some(total) = maybe_total {
+                *total = total.saturating_sub(slashed);
+            }
+        });
+
+        slashed
+    }
+}
+
+impl<T: Config, P> WatchtowerHooks<P, T::AccountId> for Pallet<T> {
+    fn on_proposal_submitted(_proposal_id: ProposalId, _proposal: P) -> DispatchResult {
+        Ok(())
+    }
+
+    fn on_vote_submitted(proposal_id: ProposalId, voter: &T::AccountId, in_favor: bool) {
+        Self::record_watchtower_vote(voter, proposal_id, in_favor);
+    }
+
+    fn on_vote_submitted_weight() -> Weight {
+        // NodeRegistry, PendingWatchtowerVotes, WatchtowerOutcomes, OldestUnpaidRewardPeriodIndex
+        // and WatchtowerParticipation are read. The pending vote and participation are written.
+        T::DbWeight::get().reads_writes(5, 2)
+    }
+
+    fn on_voting_completed(
+        proposal_id: ProposalId,
+        _external_ref: &H256,
+        result: &ProposalStatusEnum,
+    ) {
+        // Expired proposals never reached consensus, so there is nothing to measure votes against
+        if let ProposalStatusEnum::Resolved { passed } = result {
+            Self::record_watchtower_outcome(proposal_id, *passed);
+        }
+    }
+
+    fn on_cancelled(_proposal_id: ProposalId, _external_ref: &H256) {}
+}
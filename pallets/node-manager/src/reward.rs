@@ -25,7 +25,7 @@ impl<T: Config> Pallet<T> {
             // genesis bonus for all heartbeats. This is ok because we are in this
             // situation because the node managed to send more heartbeats than it should.
             let single_node_weight =
-                Self::effective_heartbeat_weight(node_info, reward_period_end_time);
+                Self::effective_heartbeat_weight(node_id, node_info, reward_period_end_time);
             single_node_weight.saturating_mul(u128::from(uptime_threshold))
         } else {
             weight
@@ -88,21 +88,36 @@ impl<T: Config> Pallet<T> {
         let reward_fee = Self::calculate_reward_fee(amount);
         let net_reward = amount.saturating_sub(reward_fee);
 
+        // Split the reward between the owner and the node's delegators.
+        let maybe_pool = DelegationPools::<T>::get(&node_id);
+        let delegators_reward = maybe_pool
+            .as_ref()
+            .map(|pool| Self::delegators_reward_share(*period, node_info, pool, net_reward))
+            .unwrap_or_else(Zero::zero);
+        let owner_reward = net_reward.saturating_sub(delegators_reward);
+
         // First pay the owner, this is the most important step here.
-        T::Currency::transfer(
-            &reward_pot_account_id,
-            &node_owner,
-            net_reward,
-            ExistenceRequirement::KeepAlive,
-        )?;
+        if !owner_reward.is_zero() {
+            T::Currency::transfer(
+                &reward_pot_account_id,
+                &node_owner,
+                owner_reward,
+                ExistenceRequirement::KeepAlive,
+            )?;
+        }
 
         Self::deposit_event(Event::RewardPaid {
             reward_period: *period,
             owner: node_owner.clone(),
             node: node_id.clone(),
-            amount: net_reward,
+            amount: owner_reward,
         });
 
+        if let Some(pool) = maybe_pool.filter(|_| !delegators_reward.is_zero()) {
+            // Delegators' share stays in the reward pot until claimed.
+            Self::credit_delegators(period, &node_id, pool, delegators_reward);
+        }
+
         if reward_fee > Zero::zero() {
             // Pay the fee to the treasury
             if let Err(e) = T::RewardFeeHandler::pay_treasury(&reward_fee, &reward_pot_account_id) {
@@ -110,25 +125,25 @@ impl<T: Config> Pallet<T> {
             }
         }
 
-        if net_reward <= Zero::zero() {
+        if owner_reward <= Zero::zero() {
             return Ok(hook_weight)
         }
 
         if Self::time_now_sec() < node_info.auto_stake_expiry || node_info.auto_stake_rewards {
             // Best-effort auto-stake. Failure is tolerated because funds are already in free
             // balance.
-            let r = Self::do_add_stake(&node_owner, &node_id, net_reward);
+            let r = Self::do_add_stake(&node_owner, &node_id, owner_reward);
             match r {
                 Ok(_) => {
                     Self::deposit_event(Event::RewardAutoStaked {
                         reward_period: *period,
                         owner: node_owner,
                         node: node_id,
-                        amount: net_reward,
+                        amount: owner_reward,
                     });
                 },
                 Err(e) =>
-                    log::error!("💔 Failed to auto-stake reward for node {:?}. Period: {:?}, amount: {:?}. Error: {:?}", node_id, period, net_reward, e),
+                    log::error!("💔 Failed to auto-stake reward for node {:?}. Period: {:?}, amount: {:?}. Error: {:?}", node_id, period, owner_reward, e),
             }
         }
 
@@ -143,6 +158,7 @@ impl<T: Config> Pallet<T> {
         // it
         for node in paid_nodes_to_remove {
             NodeUptime::<T>::remove(period_index, node);
+            WatchtowerParticipation::<T>::remove(period_index, node);
         }
     }
 
@@ -161,6 +177,12 @@ impl<T: Config> Pallet<T> {
         LastPaidPointer::<T>::kill();
         <TotalUptime<T>>::remove(period_index);
         <RewardPot<T>>::remove(period_index);
+        <ResolvedProposals<T>>::remove(period_index);
+        // The watchtower records of the period can be too many for one block, so they are pruned
+        // when blocks have spare weight
+        if !WatchtowerPruneCursor::<T>::exists() {
+            WatchtowerPruneCursor::<T>::put(period_index);
+        }
 
         // Notify app chains that the reward period has been completed
         let hook_weight = T::AppChainInterface::on_reward_period_completed(&period_index);
@@ -169,6 +191,33 @@ impl<T: Config> Pallet<T> {
         hook_weight
     }
 
+    /// Removes the watchtower outcomes and participation of paid reward periods, resuming from
+    /// `WatchtowerPruneCursor` and within the `meter` weight. This includes the participation of
+    /// nodes that voted but were not paid because they never sent a heartbeat.
+    pub fn prune_watchtower_records(meter: &mut WeightMeter) {
+        if meter.try_consume(T::DbWeight::get().reads(2)).is_err() {
+            return
+        }
+        let Some(mut period) = WatchtowerPruneCursor::<T>::get() else { return };
+        let unpaid_period = OldestUnpaidRewardPeriodIndex::<T>::get();
+        let unit = <T as Config>::WeightInfo::prune_watchtower_record();
+
+        while period < unpaid_period {
+            if meter.try_consume(unit).is_err() {
+                WatchtowerPruneCursor::<T>::put(period);
+                return
+            }
+
+            if let Some((proposal_id, _)) = ResolvedProposalIds::<T>::drain_prefix(period).next() {
+                WatchtowerOutcomes::<T>::remove(proposal_id);
+            } else if WatchtowerParticipation::<T>::drain_prefix(period).next().is_none() {
+                period = period.saturating_add(1);
+            }
+        }
+
+        WatchtowerPruneCursor::<T>::kill();
+    }
+
     pub fn update_last_paid_pointer(
         period_index: RewardPeriodIndex,
         last_node_paid: Option<T::AccountId>,
@@ -183,11 +232,12 @@ impl<T: Config> Pallet<T> {
         T::RewardPotId::get().into_account_truncating()
     }
 
-    /// The total amount of funds stored in this pallet
+    /// The total amount of funds stored in this pallet, excluding rewards owed to delegators
     pub fn reward_pot_balance() -> BalanceOf<T> {
         // Must never be less than 0 but better be safe.
         <T as pallet::Config>::Currency::free_balance(&Self::compute_reward_account_id())
             .saturating_sub(<T as pallet::Config>::Currency::minimum_balance())
+            .saturating_sub(UnclaimedDelegationRewards::<T>::get())
     }
 
     pub fn get_iterator_from_last_paid(
@@ -22,11 +22,16 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    // Use linear bonus calculation.
+    // Use linear bonus calculation. Stake delegated to the node counts towards the bonus.
     fn calculate_stake_bonus(
+        node_id: &NodeId<T>,
         node_info: &NodeInfo<T::SignerId, T::AccountId, BalanceOf<T>>,
     ) -> FixedU128 {
-        let stake_u128: u128 = node_info.stake.amount.unique_saturated_into();
+        let stake_u128: u128 = node_info
+            .stake
+            .amount
+            .saturating_add(Self::delegated_stake(node_id))
+            .unique_saturated_into();
         let step_u128: u128 = T::VirtualNodeStake::get().unique_saturated_into();
 
         if stake_u128.is_zero() || step_u128.is_zero() {
@@ -38,19 +43,21 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn compute_reward_weight(
+        node_id: &NodeId<T>,
         node_info: &NodeInfo<T::SignerId, T::AccountId, BalanceOf<T>>,
         reward_period_end_time: Duration,
     ) -> RewardWeight {
         let genesis_bonus = Self::calculate_genesis_bonus(node_info, reward_period_end_time);
-        let stake_bonus: FixedU128 = Self::calculate_stake_bonus(node_info);
+        let stake_bonus: FixedU128 = Self::calculate_stake_bonus(node_id, node_info);
         RewardWeight { genesis_bonus, stake_multiplier: stake_bonus }
     }
 
     pub fn effective_heartbeat_weight(
+        node_id: &NodeId<T>,
         node_info: &NodeInfo<T::SignerId, T::AccountId, BalanceOf<T>>,
         reward_period_end_time: Duration,
     ) -> u128 {
-        let weight_factor = Self::compute_reward_weight(node_info, reward_period_end_time);
+        let weight_factor = Self::compute_reward_weight(node_id, node_info, reward_period_end_time);
         weight_factor.to_heartbeat_weight()
     }
 
@@ -181,11 +188,11 @@ impl<T: Config> Pallet<T> {
         op: StakeOperation,
     ) -> DispatchResult {
         match op {
-            StakeOperation::Add => T::Currency::reserve(owner, amount)
+            StakeOperation::Add => T::Currency::reserve_named(&STAKE_RESERVE_ID, owner, amount)
                 .map_err(|_| Error::<T>::InsufficientFreeBalance.into()),
 
             StakeOperation::Remove => {
-                let leftover = T::Currency::unreserve(owner, amount);
+                let leftover = T::Currency::unreserve_named(&STAKE_RESERVE_ID, owner, amount);
                 ensure!(leftover.is_zero(), Error::<T>::InsufficientStakedBalance);
                 Ok(())
             },
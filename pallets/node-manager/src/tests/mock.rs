@@ -52,6 +52,8 @@ parameter_types! {
     pub const RewardPotId: PalletId = NODE_MANAGER_PALLET_ID;
     pub const VirtualNodeStake: u128 = 2000 * AVT;
     pub const BonusNodeSerialStart: u32 = 20_000;
+    pub const MaxNodeCommission: Perbill = Perbill::from_percent(50);
+    pub const NodeCommissionIncreaseDelay: u64 = 2;
 }
 
 pub struct TestBridgeInterface;
@@ -97,6 +99,26 @@ impl pallet_avn::ProcessedEventsChecker for TestProcessedEventsChecker {
     }
 }
 
+/// Work every node in the mock can prove.
+pub fn verified_work() -> WorkEvidence {
+    WorkEvidence::WatchtowerVote { proposal_id: H256::repeat_byte(1) }
+}
+
+pub struct TestWorkVerifier;
+impl NodeWorkVerifier<AccountId> for TestWorkVerifier {
+    fn work_evidence(_node: &AccountId) -> Option<WorkEvidence> {
+        Some(verified_work())
+    }
+
+    fn verify_work(_node: &AccountId, evidence: &WorkEvidence) -> bool {
+        *evidence == verified_work()
+    }
+
+    fn verify_work_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 impl Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -115,6 +137,9 @@ impl Config for TestRuntime {
     type ProcessedEventsChecker = TestProcessedEventsChecker;
     type AppChainInterface = Self;
     type BonusNodeSerialStart = BonusNodeSerialStart;
+    type MaxNodeCommission = MaxNodeCommission;
+    type NodeCommissionIncreaseDelay = NodeCommissionIncreaseDelay;
+    type WorkVerifier = TestWorkVerifier;
 }
 
 parameter_types! {
@@ -195,6 +220,7 @@ impl pallet_balances::Config for TestRuntime {
     type Balance = u128;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
+    type ReserveIdentifier = [u8; 8];
 }
 
 impl pallet_timestamp::Config for TestRuntime {
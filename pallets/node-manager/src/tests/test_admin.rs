@@ -406,6 +406,33 @@ mod genesis_bonus_25 {
     }
 }
 
+mod unverified_heartbeat_weight {
+    use super::*;
+
+    #[test]
+    fn can_be_set() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            let new_weight = Perbill::from_percent(20);
+
+            let config = AdminConfig::UnverifiedHeartbeatWeight(new_weight);
+            assert_ok!(NodeManager::set_admin_config(RawOrigin::Root.into(), config));
+            assert_eq!(UnverifiedHeartbeatWeight::<TestRuntime>::get(), new_weight);
+            System::assert_last_event(
+                Event::UnverifiedHeartbeatWeightSet { weight: new_weight }.into(),
+            );
+        });
+    }
+
+    #[test]
+    fn returns_default_when_not_set() {
+        let mut ext = ExtBuilder::build_default().with_genesis_config().as_externality();
+        ext.execute_with(|| {
+            assert_eq!(UnverifiedHeartbeatWeight::<TestRuntime>::get(), Perbill::from_percent(50));
+        });
+    }
+}
+
 mod min_uptime_threshold {
     use super::*;
 
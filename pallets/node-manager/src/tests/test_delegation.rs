@@ -0,0 +1,737 @@
+// Copyright 2026 Aventus DAO.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+
+const WEEK_SEC: u64 = 7 * 24 * 60 * 60;
+
+#[derive(Clone)]
+struct Context {
+    registrar: AccountId,
+    owner: AccountId,
+    node_id: AccountId,
+    delegator: AccountId,
+    delegator_2: AccountId,
+    signing_key: <mock::TestRuntime as pallet::Config>::SignerId,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        let registrar = TestAccount::new([1u8; 32]).account_id();
+        <NodeRegistrar<TestRuntime>>::set(Some(registrar.clone()));
+
+        let context = Context {
+            registrar,
+            owner: TestAccount::new([101u8; 32]).account_id(),
+            node_id: TestAccount::new([202u8; 32]).account_id(),
+            delegator: TestAccount::new([150u8; 32]).account_id(),
+            delegator_2: TestAccount::new([151u8; 32]).account_id(),
+            signing_key: UintAuthorityId(7),
+        };
+
+        Balances::make_free_balance_be(&context.delegator, 10_000 * AVT);
+        Balances::make_free_balance_be(&context.delegator_2, 10_000 * AVT);
+        context
+    }
+}
+
+fn register_node(context: &Context) {
+    assert_ok!(NodeManager::register_node(
+        RuntimeOrigin::signed(context.registrar.clone()),
+        context.node_id.clone(),
+        context.owner.clone(),
+        context.signing_key.clone(),
+    ));
+}
+
+fn delegate(context: &Context, delegator: &AccountId, amount: u128) {
+    assert_ok!(NodeManager::delegate_stake(
+        RuntimeOrigin::signed(delegator.clone()),
+        context.node_id.clone(),
+        amount,
+    ));
+}
+
+fn set_timestamp_sec(now_sec: u64) {
+    Timestamp::set_timestamp(now_sec * 1000);
+}
+
+// Pay the first period in which stake delegated at genesis earns
+fn pay_node(context: &Context, amount: u128) {
+    pay_node_for_period(context, 1, amount);
+}
+
+fn pay_node_for_period(context: &Context, period: RewardPeriodIndex, amount: u128) {
+    // A period is paid once it has ended
+    RewardPeriod::<TestRuntime>::mutate(|info| info.current = info.current.max(period + 1));
+    Balances::make_free_balance_be(&NodeManager::compute_reward_account_id(), 1_000 * AVT);
+    let node_info = NodeRegistry::<TestRuntime>::get(&context.node_id).unwrap();
+    assert_ok!(NodeManager::pay_reward(
+        &period,
+        context.node_id.clone(),
+        &node_info,
+        amount,
+        Perquintill::one(),
+    ));
+}
+
+mod delegate_stake {
+    use super::*;
+
+    mod succeeds {
+        use super::*;
+
+        #[test]
+        fn when_delegating_to_a_registered_node() {
+            ExtBuilder::build_default()
+                .with_genesis_config()
+                .as_externality()
+                .execute_with(|| {
+                    let context = Context::default();
+                    register_node(&context);
+
+                    delegate(&context, &context.delegator, 100 * AVT);
+
+                    let delegation =
+                        Delegations::<TestRuntime>::get(&context.node_id, &context.delegator)
+                            .unwrap();
+                    assert_eq!(delegation.stake.amount, 100 * AVT);
+                    assert_eq!(
+                        delegation.stake.restriction,
+                        UnstakeRestriction::Periodic {
+                            per_period_allowance: 10 * AVT,
+                            expires_sec: 10 * WEEK_SEC,
+                        }
+                    );
+                    assert_eq!(NodeManager::delegated_stake(&context.node_id), 100 * AVT);
+                    assert_eq!(Balances::reserved_balance(&context.delegator), 100 * AVT);
+                    // The owner's stake is untouched
+                    assert!(NodeRegistry::<TestRuntime>::get(&context.node_id)
+                        .unwrap()
+                        .stake
+                        .amount
+                        .is_zero());
+
+                    System::assert_last_event(
+                        Event::StakeDelegated {
+                            delegator: context.delegator,
+                            node_id: context.node_id,
+                            reward_period: 0,
+                            amount: 100 * AVT,
+                            new_total: 100 * AVT,
+                        }
+                        .into(),
+                    );
+                });
+        }
+
+        #[test]
+        fn delegated_stake_counts_towards_the_stake_bonus() {
+            ExtBuilder::build_default()
+                .with_genesis_config()
+                .as_externality()
+                .execute_with(|| {
+                    let context = Context::default();
+                    register_node(&context);
+                    let node_info = NodeRegistry::<TestRuntime>::get(&context.node_id).unwrap();
+                    let now_sec = NodeManager::time_now_sec();
+
+                    assert_eq!(
+                        NodeManager::effective_heartbeat_weight(
+                            &context.node_id,
+                            &node_info,
+                            now_sec
+                        ),
+                        100_000_000u128
+                    );
+
+                    // VirtualNodeStake is 2_000 AVT => (1 + 1) = 2x
+                    delegate(&context, &context.delegator, 2_000 * AVT);
+
+                    assert_eq!(
+                        NodeManager::effective_heartbeat_weight(
+                            &context.node_id,
+                            &node_info,
+                            now_sec
+                        ),
+                        200_000_000u128
+                    );
+                });
+        }
+    }
+
+    mod fails {
+        use super::*;
+
+        #[test]
+        fn when_owner_delegates_to_own_node() {
+            ExtBuilder::build_default()
+                .with_genesis_config()
+                .as_externality()
+                .execute_with(|| {
+                    let context = Context::default();
+                    register_node(&context);
+                    Balances::make_free_balance_be(&context.owner, 1_000 * AVT);
+
+                    assert_noop!(
+                        NodeManager::delegate_stake(
+                            RuntimeOrigin::signed(context.owner.clone()),
+                            context.node_id.clone(),
+                            100 * AVT,
+                        ),
+                        Error::<TestRuntime>::OwnerCannotDelegate
+                    );
+                });
+        }
+
+        #[test]
+        fn when_node_is_not_registered() {
+            ExtBuilder::build_default()
+                .with_genesis_config()
+                .as_externality()
+                .execute_with(|| {
+                    let context = Context::default();
+
+                    assert_noop!(
+                        NodeManager::delegate_stake(
+                            RuntimeOrigin::signed(context.delegator.clone()),
+                            context.node_id.clone(),
+                            100 * AVT,
+                        ),
+                        Error::<TestRuntime>::NodeNotRegistered
+                    );
+                });
+        }
+
+        #[test]
+        fn when_amount_is_zero() {
+            ExtBuilder::build_default()
+                .with_genesis_config()
+                .as_externality()
+                .execute_with(|| {
+                    let context = Context::default();
+                    register_node(&context);
+
+                    assert_noop!(
+                        NodeManager::delegate_stake(
+                            RuntimeOrigin::signed(context.delegator.clone()),
+                            context.node_id.clone(),
+                            0,
+                        ),
+                        Error::<TestRuntime>::ZeroAmount
+                    );
+                });
+        }
+
+        #[test]
+        fn when_delegator_has_insufficient_balance() {
+            ExtBuilder::build_default()
+                .with_genesis_config()
+                .as_externality()
+                .execute_with(|| {
+                    let context = Context::default();
+                    register_node(&context);
+
+                    assert_noop!(
+                        NodeManager::delegate_stake(
+                            RuntimeOrigin::signed(context.delegator.clone()),
+                            context.node_id.clone(),
+                            20_000 * AVT,
+                        ),
+                        Error::<TestRuntime>::InsufficientFreeBalance
+                    );
+                });
+        }
+    }
+}
+
+mod undelegate_stake {
+    use super::*;
+
+    #[test]
+    fn follows_the_unstake_schedule() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                delegate(&context, &context.delegator, 100 * AVT);
+
+                // Nothing is unlocked until the first unstake period has passed
+                assert_noop!(
+                    NodeManager::undelegate_stake(
+                        RuntimeOrigin::signed(context.delegator.clone()),
+                        context.node_id.clone(),
+                        None,
+                    ),
+                    Error::<TestRuntime>::NoAvailableStakeToUnstake
+                );
+
+                // One period unlocks MaxUnstakePercentage of the delegation
+                set_timestamp_sec(WEEK_SEC);
+                assert_noop!(
+                    NodeManager::undelegate_stake(
+                        RuntimeOrigin::signed(context.delegator.clone()),
+                        context.node_id.clone(),
+                        Some(11 * AVT),
+                    ),
+                    Error::<TestRuntime>::NoAvailableStakeToUnstake
+                );
+                assert_ok!(NodeManager::undelegate_stake(
+                    RuntimeOrigin::signed(context.delegator.clone()),
+                    context.node_id.clone(),
+                    None,
+                ));
+                System::assert_last_event(
+                    Event::StakeUndelegated {
+                        delegator: context.delegator.clone(),
+                        node_id: context.node_id.clone(),
+                        reward_period: 0,
+                        amount: 10 * AVT,
+                        new_total: 90 * AVT,
+                    }
+                    .into(),
+                );
+                assert_eq!(Balances::reserved_balance(&context.delegator), 90 * AVT);
+                assert_eq!(NodeManager::delegated_stake(&context.node_id), 90 * AVT);
+
+                // Once the restricted window ends everything can be withdrawn
+                set_timestamp_sec(10 * WEEK_SEC);
+                assert_ok!(NodeManager::undelegate_stake(
+                    RuntimeOrigin::signed(context.delegator.clone()),
+                    context.node_id.clone(),
+                    None,
+                ));
+                assert!(
+                    Delegations::<TestRuntime>::get(&context.node_id, &context.delegator).is_none()
+                );
+                assert!(Balances::reserved_balance(&context.delegator).is_zero());
+                // The pool is kept for the commission while the node is registered
+                assert!(DelegationPools::<TestRuntime>::contains_key(&context.node_id));
+            });
+    }
+
+    #[test]
+    fn all_stake_is_free_once_the_node_is_deregistered() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                delegate(&context, &context.delegator, 100 * AVT);
+
+                assert_ok!(NodeManager::deregister_nodes(
+                    RuntimeOrigin::signed(context.registrar.clone()),
+                    context.owner.clone(),
+                    BoundedVec::truncate_from(vec![context.node_id.clone()]),
+                ));
+                // The pool is kept until the delegators have left
+                assert!(DelegationPools::<TestRuntime>::contains_key(&context.node_id));
+                assert_noop!(
+                    NodeManager::register_node(
+                        RuntimeOrigin::signed(context.registrar.clone()),
+                        context.node_id.clone(),
+                        context.owner.clone(),
+                        context.signing_key.clone(),
+                    ),
+                    Error::<TestRuntime>::NodeHasDelegations
+                );
+
+                assert_ok!(NodeManager::undelegate_stake(
+                    RuntimeOrigin::signed(context.delegator.clone()),
+                    context.node_id.clone(),
+                    None,
+                ));
+                assert!(Balances::reserved_balance(&context.delegator).is_zero());
+                assert!(!DelegationPools::<TestRuntime>::contains_key(&context.node_id));
+
+                register_node(&context);
+            });
+    }
+
+    #[test]
+    fn fails_when_there_is_no_delegation() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                assert_noop!(
+                    NodeManager::undelegate_stake(
+                        RuntimeOrigin::signed(context.delegator.clone()),
+                        context.node_id.clone(),
+                        None,
+                    ),
+                    Error::<TestRuntime>::DelegationNotFound
+                );
+            });
+    }
+}
+
+mod set_node_commission {
+    use super::*;
+
+    #[test]
+    fn increases_are_scheduled() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                assert_ok!(NodeManager::set_node_commission(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    Perbill::from_percent(10),
+                ));
+
+                let pool = DelegationPools::<TestRuntime>::get(&context.node_id).unwrap();
+                assert_eq!(pool.commission, Perbill::zero());
+                assert_eq!(pool.effective_commission(1), Perbill::zero());
+                assert_eq!(pool.effective_commission(2), Perbill::from_percent(10));
+                System::assert_last_event(
+                    Event::NodeCommissionIncreaseScheduled {
+                        owner: context.owner,
+                        node_id: context.node_id,
+                        commission: Perbill::from_percent(10),
+                        execute_period: 2,
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn decreases_apply_immediately() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                DelegationPools::<TestRuntime>::insert(
+                    &context.node_id,
+                    DelegationPool {
+                        commission: Perbill::from_percent(20),
+                        commission_request: Some(NodeCommissionChangeRequest {
+                            commission: Perbill::from_percent(30),
+                            when_executable: 2,
+                        }),
+                        ..Default::default()
+                    },
+                );
+
+                // A decrease also cancels the pending increase
+                assert_ok!(NodeManager::set_node_commission(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    Perbill::from_percent(10),
+                ));
+
+                let pool = DelegationPools::<TestRuntime>::get(&context.node_id).unwrap();
+                assert_eq!(pool.commission, Perbill::from_percent(10));
+                assert_eq!(pool.commission_request, None);
+                System::assert_last_event(
+                    Event::NodeCommissionSet {
+                        owner: context.owner,
+                        node_id: context.node_id,
+                        commission: Perbill::from_percent(10),
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn due_increases_are_applied_before_a_new_change() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                assert_ok!(NodeManager::set_node_commission(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    Perbill::from_percent(20),
+                ));
+                RewardPeriod::<TestRuntime>::mutate(|info| info.current = 2);
+
+                assert_ok!(NodeManager::set_node_commission(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    Perbill::from_percent(15),
+                ));
+
+                let pool = DelegationPools::<TestRuntime>::get(&context.node_id).unwrap();
+                assert_eq!(pool.commission, Perbill::from_percent(15));
+                assert_eq!(pool.commission_request, None);
+            });
+    }
+
+    #[test]
+    fn fails_when_above_the_maximum() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                assert_noop!(
+                    NodeManager::set_node_commission(
+                        RuntimeOrigin::signed(context.owner.clone()),
+                        context.node_id.clone(),
+                        Perbill::from_percent(51),
+                    ),
+                    Error::<TestRuntime>::CommissionTooHigh
+                );
+            });
+    }
+
+    #[test]
+    fn fails_when_called_by_non_owner() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                assert_noop!(
+                    NodeManager::set_node_commission(
+                        RuntimeOrigin::signed(context.delegator.clone()),
+                        context.node_id.clone(),
+                        Perbill::from_percent(10),
+                    ),
+                    Error::<TestRuntime>::NodeNotOwnedByOwner
+                );
+            });
+    }
+}
+
+mod reward_split {
+    use super::*;
+
+    #[test]
+    fn reward_is_split_by_contributing_stake_and_commission() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                DelegationPools::<TestRuntime>::insert(
+                    &context.node_id,
+                    DelegationPool { commission: Perbill::from_percent(10), ..Default::default() },
+                );
+                // Stake multiplier is 1 + 2_000 / 2_000 so delegators contributed half the weight
+                delegate(&context, &context.delegator, 2_000 * AVT);
+
+                pay_node(&context, 100 * AVT);
+
+                // Delegators earn 50 AVT, 10% of which goes to the owner as commission
+                System::assert_has_event(
+                    Event::RewardPaid {
+                        reward_period: 1,
+                        owner: context.owner.clone(),
+                        node: context.node_id.clone(),
+                        amount: 55 * AVT,
+                    }
+                    .into(),
+                );
+                System::assert_has_event(
+                    Event::DelegatorRewardCredited {
+                        reward_period: 1,
+                        node: context.node_id.clone(),
+                        amount: 45 * AVT,
+                    }
+                    .into(),
+                );
+                assert_eq!(UnclaimedDelegationRewards::<TestRuntime>::get(), 45 * AVT);
+                // Funds owed to delegators are not available for new reward periods
+                assert_eq!(NodeManager::reward_pot_balance(), (1_000 - 100) * AVT);
+
+                assert_ok!(NodeManager::claim_delegation_reward(
+                    RuntimeOrigin::signed(context.delegator.clone()),
+                    context.node_id.clone(),
+                ));
+                assert_eq!(Balances::free_balance(&context.delegator), (8_000 + 45) * AVT);
+                assert!(UnclaimedDelegationRewards::<TestRuntime>::get().is_zero());
+                System::assert_last_event(
+                    Event::DelegationRewardClaimed {
+                        delegator: context.delegator,
+                        node_id: context.node_id,
+                        amount: 45 * AVT,
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn commission_increase_applies_to_rewards_of_later_periods() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                delegate(&context, &context.delegator, 2_000 * AVT);
+                assert_ok!(NodeManager::set_node_commission(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    Perbill::from_percent(10),
+                ));
+
+                pay_node_for_period(&context, 1, 100 * AVT);
+                System::assert_has_event(
+                    Event::DelegatorRewardCredited {
+                        reward_period: 1,
+                        node: context.node_id.clone(),
+                        amount: 50 * AVT,
+                    }
+                    .into(),
+                );
+
+                pay_node_for_period(&context, 2, 100 * AVT);
+                System::assert_has_event(
+                    Event::DelegatorRewardCredited {
+                        reward_period: 2,
+                        node: context.node_id.clone(),
+                        amount: 45 * AVT,
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn delegators_share_pro_rata_to_their_stake() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                delegate(&context, &context.delegator, 1_500 * AVT);
+                delegate(&context, &context.delegator_2, 500 * AVT);
+
+                pay_node(&context, 100 * AVT);
+
+                let pending = |delegator: &AccountId| {
+                    NodeManager::delegation_reward(&context.node_id, delegator)
+                };
+                assert_eq!(pending(&context.delegator), 37_500_000_000_000_000_000u128);
+                assert_eq!(pending(&context.delegator_2), 12_500_000_000_000_000_000u128);
+
+                // Changing the delegation pays out the pending reward first
+                delegate(&context, &context.delegator_2, 100 * AVT);
+                System::assert_has_event(
+                    Event::DelegationRewardClaimed {
+                        delegator: context.delegator_2.clone(),
+                        node_id: context.node_id.clone(),
+                        amount: 12_500_000_000_000_000_000u128,
+                    }
+                    .into(),
+                );
+                assert_eq!(pending(&context.delegator_2), 0);
+            });
+    }
+
+    #[test]
+    fn stake_only_earns_from_the_period_after_it_was_delegated() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                delegate(&context, &context.delegator, 2_000 * AVT);
+                RewardPeriod::<TestRuntime>::mutate(|info| info.current = 1);
+                delegate(&context, &context.delegator_2, 2_000 * AVT);
+
+                // Only the stake delegated before period 1 counts towards its reward
+                pay_node_for_period(&context, 1, 100 * AVT);
+                System::assert_has_event(
+                    Event::DelegatorRewardCredited {
+                        reward_period: 1,
+                        node: context.node_id.clone(),
+                        amount: 50 * AVT,
+                    }
+                    .into(),
+                );
+                assert_eq!(
+                    NodeManager::delegation_reward(&context.node_id, &context.delegator),
+                    50 * AVT
+                );
+                assert!(NodeManager::delegation_reward(&context.node_id, &context.delegator_2)
+                    .is_zero());
+
+                // Both delegations earn the reward of period 2 equally
+                pay_node_for_period(&context, 2, 100 * AVT);
+                assert_eq!(
+                    NodeManager::delegation_reward(&context.node_id, &context.delegator),
+                    NodeManager::delegation_reward(&context.node_id, &context.delegator_2) +
+                        50 * AVT
+                );
+            });
+    }
+
+    #[test]
+    fn owner_stake_reduces_the_delegators_share() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                Balances::make_free_balance_be(&context.owner, 10_000 * AVT);
+                assert_ok!(NodeManager::add_stake(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    2_000 * AVT,
+                ));
+                delegate(&context, &context.delegator, 4_000 * AVT);
+
+                pay_node(&context, 100 * AVT);
+
+                // 4_000 / (2_000 + 2_000 + 4_000) of the weight came from delegated stake
+                System::assert_has_event(
+                    Event::DelegatorRewardCredited {
+                        reward_period: 1,
+                        node: context.node_id.clone(),
+                        amount: 50 * AVT,
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn claim_fails_when_there_is_nothing_to_claim() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                delegate(&context, &context.delegator, 100 * AVT);
+
+                assert_noop!(
+                    NodeManager::claim_delegation_reward(
+                        RuntimeOrigin::signed(context.delegator.clone()),
+                        context.node_id.clone(),
+                    ),
+                    Error::<TestRuntime>::NoDelegationRewardToClaim
+                );
+            });
+    }
+}
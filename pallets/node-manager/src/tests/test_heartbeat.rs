@@ -114,6 +114,7 @@ mod given_a_reward_period {
                     node: _,
                     reward_period_index: _,
                     heartbeat_count: _,
+                    evidence: _,
                     signature: _,
                 })
             ));
@@ -133,6 +134,7 @@ mod given_a_reward_period {
                 Event::HeartbeatReceived {
                     reward_period_index: reward_period,
                     node: context.node_id,
+                    verified: true,
                 }
                 .into(),
             );
@@ -162,6 +164,7 @@ mod given_a_reward_period {
                     node: _,
                     reward_period_index: _,
                     heartbeat_count: _,
+                    evidence: _,
                     signature: _,
                 })
             ));
@@ -254,6 +257,7 @@ mod given_a_reward_period {
                 Event::HeartbeatReceived {
                     reward_period_index: reward_period,
                     node: context.node_id,
+                    verified: true,
                 }
                 .into(),
             );
@@ -429,6 +433,7 @@ mod given_a_reward_period {
                     node: _,
                     reward_period_index: _,
                     heartbeat_count: _,
+                    evidence: _,
                     signature: _,
                 })
             ));
@@ -464,6 +469,7 @@ mod given_a_reward_period {
                 Event::HeartbeatReceived {
                     reward_period_index: reward_period,
                     node: context.node_id,
+                    verified: true,
                 }
                 .into(),
             );
@@ -505,6 +511,7 @@ mod given_a_reward_period {
                     node: _,
                     reward_period_index: _,
                     heartbeat_count: _,
+                    evidence: _,
                     signature: _,
                 })
             ));
@@ -523,6 +530,7 @@ mod given_a_reward_period {
                 Event::HeartbeatReceived {
                     reward_period_index: reward_period,
                     node: context.node_id,
+                    verified: true,
                 }
                 .into(),
             );
@@ -583,6 +591,7 @@ mod across_multiple_reward_periods {
                 Event::HeartbeatReceived {
                     reward_period_index: new_reward_period,
                     node: context.node_id,
+                    verified: true,
                 }
                 .into(),
             );
@@ -590,6 +599,245 @@ mod across_multiple_reward_periods {
     }
 }
 
+mod heartbeat_evidence {
+    use super::*;
+
+    fn submit_heartbeat(context: &Context, evidence: Option<HeartbeatEvidence<u64, H256>>) {
+        let reward_period = <RewardPeriod<TestRuntime>>::get().current;
+        let signature = context.signing_key.sign(&("DummyProof").encode()).expect("Error signing");
+        assert_ok!(NodeManager::offchain_submit_heartbeat(
+            RawOrigin::None.into(),
+            context.node_id,
+            reward_period,
+            0u64,
+            evidence,
+            signature
+        ));
+    }
+
+    fn recent_block_evidence() -> HeartbeatEvidence<u64, H256> {
+        let block_number = System::block_number() - HEARTBEAT_EVIDENCE_FINALITY_DEPTH as u64;
+        HeartbeatEvidence {
+            block_number,
+            block_hash: System::block_hash(block_number),
+            work: Some(verified_work()),
+        }
+    }
+
+    fn assert_heartbeat_weight(context: &Context, expected_weight: u128, verified: bool) {
+        let reward_period = <RewardPeriod<TestRuntime>>::get().current;
+        let uptime_info = <NodeUptime<TestRuntime>>::get(reward_period, &context.node_id).unwrap();
+
+        assert_eq!(uptime_info.count, 1);
+        assert_eq!(uptime_info.weight, expected_weight);
+        assert_eq!(<TotalUptime<TestRuntime>>::get(&reward_period).total_weight, expected_weight);
+        System::assert_last_event(
+            Event::HeartbeatReceived {
+                reward_period_index: reward_period,
+                node: context.node_id,
+                verified,
+            }
+            .into(),
+        );
+    }
+
+    #[test]
+    fn ocw_references_a_finalised_block() {
+        let (mut ext, pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let context = Context::default();
+            register_node(&context);
+
+            System::set_block_number(15);
+            let finalised_hash = H256::repeat_byte(5);
+            frame_system::BlockHash::<TestRuntime>::insert(5, finalised_hash);
+
+            NodeManager::offchain_worker(System::block_number());
+            let tx = pop_tx_from_mempool(pool_state);
+
+            let expected_evidence = Some(HeartbeatEvidence {
+                block_number: 5,
+                block_hash: finalised_hash,
+                work: Some(verified_work()),
+            });
+            assert!(matches!(
+                &tx.function,
+                RuntimeCall::NodeManager(crate::Call::offchain_submit_heartbeat {
+                    evidence,
+                    ..
+                }) if *evidence == expected_evidence
+            ));
+
+            assert_ok!(tx.function.clone().dispatch(frame_system::RawOrigin::None.into()));
+            assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT, true);
+        });
+    }
+
+    #[test]
+    fn valid_evidence_gets_full_weight() {
+        let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+            .with_genesis_config()
+            .for_offchain_worker()
+            .as_externality_with_state();
+        ext.execute_with(|| {
+            let context = Context::default();
+            register_node(&context);
+
+            System::set_block_number(20);
+            frame_system::BlockHash::<TestRuntime>::insert(10, H256::repeat_byte(10));
+
+            submit_heartbeat(&context, Some(recent_block_evidence()));
+            assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT, true);
+        });
+    }
+
+    mod reduces_weight_when {
+        use super::*;
+
+        #[test]
+        fn evidence_is_missing() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                submit_heartbeat(&context, None);
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 2, false);
+            });
+        }
+
+        #[test]
+        fn block_hash_does_not_match() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                System::set_block_number(20);
+                frame_system::BlockHash::<TestRuntime>::insert(10, H256::repeat_byte(10));
+
+                let mut evidence = recent_block_evidence();
+                evidence.block_hash = H256::repeat_byte(1);
+
+                submit_heartbeat(&context, Some(evidence));
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 2, false);
+            });
+        }
+
+        #[test]
+        fn block_is_not_finalised() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                System::set_block_number(20);
+                let block_number = System::block_number() - 1;
+                let evidence = HeartbeatEvidence {
+                    block_number,
+                    block_hash: System::block_hash(block_number),
+                    work: Some(verified_work()),
+                };
+
+                submit_heartbeat(&context, Some(evidence));
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 2, false);
+            });
+        }
+
+        #[test]
+        fn block_is_too_old() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                let old_block = 10u64;
+                frame_system::BlockHash::<TestRuntime>::insert(old_block, H256::repeat_byte(10));
+                System::set_block_number(old_block + HEARTBEAT_EVIDENCE_MAX_AGE as u64 + 1);
+
+                let evidence = HeartbeatEvidence {
+                    block_number: old_block,
+                    block_hash: H256::repeat_byte(10),
+                    work: Some(verified_work()),
+                };
+
+                submit_heartbeat(&context, Some(evidence));
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 2, false);
+            });
+        }
+
+        #[test]
+        fn work_is_not_verified() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                System::set_block_number(20);
+                let mut evidence = recent_block_evidence();
+                evidence.work =
+                    Some(WorkEvidence::WatchtowerVote { proposal_id: H256::repeat_byte(7) });
+
+                submit_heartbeat(&context, Some(evidence));
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 2, false);
+            });
+        }
+
+        #[test]
+        fn work_is_missing() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+
+                System::set_block_number(20);
+                let mut evidence = recent_block_evidence();
+                evidence.work = None;
+
+                submit_heartbeat(&context, Some(evidence));
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 2, false);
+            });
+        }
+
+        #[test]
+        fn using_the_configured_weight() {
+            let (mut ext, _pool_state, _offchain_state) = ExtBuilder::build_default()
+                .with_genesis_config()
+                .for_offchain_worker()
+                .as_externality_with_state();
+            ext.execute_with(|| {
+                let context = Context::default();
+                register_node(&context);
+                UnverifiedHeartbeatWeight::<TestRuntime>::put(Perbill::from_percent(10));
+
+                submit_heartbeat(&context, None);
+                assert_heartbeat_weight(&context, HEARTBEAT_BASE_WEIGHT / 10, false);
+            });
+        }
+    }
+}
+
 mod fails_when {
     use super::*;
 
@@ -616,6 +864,7 @@ mod fails_when {
                     context.node_id,
                     reward_period,
                     1u64,
+                    None,
                     signature
                 ),
                 Error::<TestRuntime>::DuplicateHeartbeat
@@ -643,6 +892,7 @@ mod fails_when {
                     context.node_id,
                     bad_reward_period,
                     1u64,
+                    None,
                     signature
                 ),
                 Error::<TestRuntime>::InvalidHeartbeat
@@ -670,6 +920,7 @@ mod fails_when {
                     bad_node,
                     reward_period,
                     1u64,
+                    None,
                     signature
                 ),
                 Error::<TestRuntime>::NodeNotRegistered
@@ -697,6 +948,7 @@ mod fails_when {
                     context.node_id,
                     reward_period,
                     bad_uptime_count,
+                    None,
                     signature
                 ),
                 Error::<TestRuntime>::InvalidHeartbeat
@@ -749,6 +1001,7 @@ mod fails_when {
                 node: context.node_id,
                 reward_period_index: 1u64,
                 heartbeat_count: 1u64,
+                evidence: None,
                 signature: context
                     .signing_key
                     .sign(&("DummyProof").encode())
@@ -786,6 +1039,7 @@ mod fails_when {
                     context.node_id,
                     reward_period.current,
                     min_heartbeats.into(),
+                    None,
                     context.signing_key.sign(&("DummyProof").encode()).expect("Error signing")
                 ),
                 Error::<TestRuntime>::HeartbeatThresholdReached
@@ -814,9 +1068,13 @@ mod fails_when {
                 node: context.node_id,
                 reward_period_index: bad_reward_period,
                 heartbeat_count: 0u64,
+                evidence: None,
                 signature: context
                     .signing_key
-                    .sign(&(HEARTBEAT_CONTEXT, 0u64, 0u64).encode())
+                    .sign(
+                        &(HEARTBEAT_CONTEXT, 0u64, 0u64, None::<HeartbeatEvidence<u64, H256>>)
+                            .encode(),
+                    )
                     .expect("Error signing"),
             };
 
@@ -833,9 +1091,13 @@ mod fails_when {
                 node: context.node_id,
                 reward_period_index: 0u64,
                 heartbeat_count: bad_heartbeat_count,
+                evidence: None,
                 signature: context
                     .signing_key
-                    .sign(&(HEARTBEAT_CONTEXT, 0u64, 0u64).encode())
+                    .sign(
+                        &(HEARTBEAT_CONTEXT, 0u64, 0u64, None::<HeartbeatEvidence<u64, H256>>)
+                            .encode(),
+                    )
                     .expect("Error signing"),
             };
 
@@ -852,6 +1114,7 @@ mod fails_when {
                 node: context.node_id,
                 reward_period_index: 0u64,
                 heartbeat_count: 0u64,
+                evidence: None,
                 signature: context
                     .signing_key
                     .sign(&("DummyProof").encode())
@@ -871,9 +1134,13 @@ mod fails_when {
                 node: context.node_id,
                 reward_period_index: 0u64,
                 heartbeat_count: 0u64,
+                evidence: None,
                 signature: context
                     .signing_key
-                    .sign(&(HEARTBEAT_CONTEXT, 0u64, 0u64).encode())
+                    .sign(
+                        &(HEARTBEAT_CONTEXT, 0u64, 0u64, None::<HeartbeatEvidence<u64, H256>>)
+                            .encode(),
+                    )
                     .expect("Error signing"),
             };
 
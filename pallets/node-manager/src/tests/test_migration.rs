@@ -0,0 +1,72 @@
+// Copyright 2026 Aventus DAO.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{
+    assert_ok,
+    traits::{GetStorageVersion, NamedReservableCurrency, OnRuntimeUpgrade, ReservableCurrency},
+};
+
+#[test]
+fn migration_moves_stake_to_the_stake_reserve() {
+    ExtBuilder::build_default()
+        .with_genesis_config()
+        .as_externality()
+        .execute_with(|| {
+            let registrar = TestAccount::new([1u8; 32]).account_id();
+            let owner = TestAccount::new([101u8; 32]).account_id();
+            let node_id = TestAccount::new([202u8; 32]).account_id();
+            let delegator = TestAccount::new([150u8; 32]).account_id();
+            let (stake, delegated, reserved_elsewhere) = (100 * AVT, 50 * AVT, 10 * AVT);
+
+            <NodeRegistrar<TestRuntime>>::set(Some(registrar.clone()));
+            Balances::make_free_balance_be(&owner, stake + reserved_elsewhere);
+            Balances::make_free_balance_be(&delegator, delegated);
+            assert_ok!(NodeManager::register_node(
+                RuntimeOrigin::signed(registrar),
+                node_id.clone(),
+                owner.clone(),
+                UintAuthorityId(7),
+            ));
+            assert_ok!(NodeManager::add_stake(
+                RuntimeOrigin::signed(owner.clone()),
+                node_id.clone(),
+                stake
+            ));
+            assert_ok!(NodeManager::delegate_stake(
+                RuntimeOrigin::signed(delegator.clone()),
+                node_id.clone(),
+                delegated
+            ));
+
+            // Recreate the v0 layout, where stake was held in the anonymous reserve
+            for (account, amount) in [(&owner, stake), (&delegator, delegated)] {
+                Balances::unreserve_named(&STAKE_RESERVE_ID, account, amount);
+                assert_ok!(Balances::reserve(account, amount));
+            }
+            assert_ok!(Balances::reserve(&owner, reserved_elsewhere));
+            StorageVersion::new(0).put::<NodeManager>();
+
+            migration::NodeManagerMigrations::<TestRuntime>::on_runtime_upgrade();
+
+            assert_eq!(Balances::reserved_balance_named(&STAKE_RESERVE_ID, &owner), stake);
+            assert_eq!(Balances::reserved_balance_named(&STAKE_RESERVE_ID, &delegator), delegated);
+            assert_eq!(Balances::reserved_balance(&owner), stake + reserved_elsewhere);
+            assert_eq!(NodeManager::on_chain_storage_version(), STORAGE_VERSION);
+        });
+}
+
+#[test]
+fn migration_bumps_the_storage_version_from_v1() {
+    ExtBuilder::build_default()
+        .with_genesis_config()
+        .as_externality()
+        .execute_with(|| {
+            StorageVersion::new(1).put::<NodeManager>();
+
+            migration::NodeManagerMigrations::<TestRuntime>::on_runtime_upgrade();
+
+            assert_eq!(NodeManager::on_chain_storage_version(), STORAGE_VERSION);
+        });
+}
@@ -84,7 +84,7 @@ fn incr_heartbeats(reward_period: RewardPeriodIndex, nodes: Vec<NodeId<TestRunti
     for node in nodes {
         let node_info = <NodeRegistry<TestRuntime>>::get(&node).unwrap();
         let single_hb_weight =
-            NodeManager::effective_heartbeat_weight(&node_info, NodeManager::time_now_sec());
+            NodeManager::effective_heartbeat_weight(&node, &node_info, NodeManager::time_now_sec());
         let weight = single_hb_weight.saturating_mul(uptime.into());
 
         <NodeUptime<TestRuntime>>::mutate(&reward_period, &node, |maybe_info| {
@@ -68,7 +68,7 @@ mod stake_and_reward_weight_tests {
                 true,
                 stake_info,
             );
-            let w = NodeManager::effective_heartbeat_weight(&node_info, now_sec);
+            let w = NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec);
             assert_eq!(w, 150_000_000u128); // 1.5x base weight of 100_000_000
 
             // At expiry bonus does not apply
@@ -81,14 +81,14 @@ mod stake_and_reward_weight_tests {
                 true,
                 stake_info,
             );
-            let w = NodeManager::effective_heartbeat_weight(&node_info, now_sec);
+            let w = NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec);
             assert_eq!(w, 100_000_000u128); // 1.5x base weight of 100_000_000
 
             // After expiry bonus does not apply
             expiry = now_sec - 1;
             let node_info =
                 NodeInfo::new(owner.clone(), signing_key, node_serial, expiry, true, stake_info);
-            let w = NodeManager::effective_heartbeat_weight(&node_info, now_sec);
+            let w = NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec);
             assert_eq!(w, 100_000_000u128); // 1.5x base weight of 100_000_000
         });
     }
@@ -144,7 +144,7 @@ mod stake_and_reward_weight_tests {
                 true,
                 stake_info,
             );
-            let w = NodeManager::effective_heartbeat_weight(&node_info, now_sec);
+            let w = NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec);
 
             assert_eq!(w, 300_000_000u128);
 
@@ -1104,7 +1104,7 @@ mod stake_and_reward_weight_tests {
                     GenesisOverrides::<TestRuntime>::insert(serial, GenesisBonus::Genesis50);
                     let node_info = make_node_info_for_weight(serial, now_sec);
                     assert_eq!(
-                        NodeManager::effective_heartbeat_weight(&node_info, now_sec),
+                        NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec),
                         150_000_000u128
                     );
                 });
@@ -1121,7 +1121,7 @@ mod stake_and_reward_weight_tests {
                     GenesisOverrides::<TestRuntime>::insert(serial, GenesisBonus::Genesis25);
                     let node_info = make_node_info_for_weight(serial, now_sec);
                     assert_eq!(
-                        NodeManager::effective_heartbeat_weight(&node_info, now_sec),
+                        NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec),
                         125_000_000u128
                     );
                 });
@@ -1138,7 +1138,7 @@ mod stake_and_reward_weight_tests {
                     GenesisOverrides::<TestRuntime>::insert(serial, GenesisBonus::Excluded);
                     let node_info = make_node_info_for_weight(serial, now_sec);
                     assert_eq!(
-                        NodeManager::effective_heartbeat_weight(&node_info, now_sec),
+                        NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec),
                         100_000_000u128
                     );
                 });
@@ -1155,7 +1155,7 @@ mod stake_and_reward_weight_tests {
                     GenesisOverrides::<TestRuntime>::insert(serial, GenesisBonus::Genesis25);
                     let node_info = make_node_info_for_weight(serial, now_sec);
                     assert_eq!(
-                        NodeManager::effective_heartbeat_weight(&node_info, now_sec),
+                        NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec),
                         125_000_000u128
                     );
                 });
@@ -1173,13 +1173,13 @@ mod stake_and_reward_weight_tests {
 
                     GenesisOverrides::<TestRuntime>::insert(serial, GenesisBonus::Excluded);
                     assert_eq!(
-                        NodeManager::effective_heartbeat_weight(&node_info, now_sec),
+                        NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec),
                         100_000_000u128
                     );
 
                     GenesisOverrides::<TestRuntime>::remove(serial);
                     assert_eq!(
-                        NodeManager::effective_heartbeat_weight(&node_info, now_sec),
+                        NodeManager::effective_heartbeat_weight(&get_node(1), &node_info, now_sec),
                         150_000_000u128
                     );
                 });
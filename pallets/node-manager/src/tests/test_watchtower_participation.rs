@@ -0,0 +1,574 @@
+// Copyright 2026 Aventus DAO.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use core::sync::atomic::{AtomicU64, Ordering};
+use frame_support::{
+    assert_ok,
+    traits::{Hooks, NamedReservableCurrency, ReservableCurrency},
+};
+use sp_watchtower::{ProposalId, ProposalStatusEnum, WatchtowerHooks};
+
+const NODE_WEIGHT: u128 = 1_000;
+
+#[derive(Clone)]
+struct Context {
+    registrar: AccountId,
+    owner: AccountId,
+    node_id: AccountId,
+    other_node_id: AccountId,
+    not_a_node: AccountId,
+    policy: ParticipationPolicy,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        let registrar = TestAccount::new([1u8; 32]).account_id();
+        <NodeRegistrar<TestRuntime>>::set(Some(registrar.clone()));
+
+        let context = Context {
+            registrar,
+            owner: TestAccount::new([101u8; 32]).account_id(),
+            node_id: TestAccount::new([202u8; 32]).account_id(),
+            other_node_id: TestAccount::new([203u8; 32]).account_id(),
+            not_a_node: TestAccount::new([250u8; 32]).account_id(),
+            policy: ParticipationPolicy {
+                min_participation: Perbill::from_percent(50),
+                absence_weight: Perbill::from_percent(40),
+                max_dissents: 1,
+                dissent_weight: Perbill::from_percent(50),
+                slash_percentage: Perbill::zero(),
+            },
+        };
+
+        register_node(&context, &context.node_id, UintAuthorityId(7));
+        register_node(&context, &context.other_node_id, UintAuthorityId(8));
+        context
+    }
+}
+
+fn register_node(context: &Context, node_id: &AccountId, signing_key: UintAuthorityId) {
+    assert_ok!(NodeManager::register_node(
+        RuntimeOrigin::signed(context.registrar.clone()),
+        node_id.clone(),
+        context.owner.clone(),
+        signing_key,
+    ));
+}
+
+fn next_proposal_id() -> ProposalId {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    H256::from_low_u64_be(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+fn submit_votes(proposal_id: ProposalId, votes: &[(AccountId, bool)]) {
+    for (voter, in_favor) in votes {
+        <NodeManager as WatchtowerHooks<(), AccountId>>::on_vote_submitted(
+            proposal_id,
+            voter,
+            *in_favor,
+        );
+    }
+}
+
+fn resolve(proposal_id: ProposalId, result: ProposalStatusEnum) {
+    <NodeManager as WatchtowerHooks<(), AccountId>>::on_voting_completed(
+        proposal_id,
+        &H256::repeat_byte(1),
+        &result,
+    );
+}
+
+fn complete_voting(result: ProposalStatusEnum, votes: &[(AccountId, bool)]) {
+    let proposal_id = next_proposal_id();
+    submit_votes(proposal_id, votes);
+    resolve(proposal_id, result);
+}
+
+fn settle(nodes: &[&AccountId]) {
+    for node in nodes {
+        NodeManager::settle_watchtower_vote(node);
+    }
+}
+
+fn apply_penalty(context: &Context, policy: &ParticipationPolicy) -> u128 {
+    let period = RewardPeriod::<TestRuntime>::get().current;
+    settle(&[&context.node_id]);
+    let mut node_info = NodeRegistry::<TestRuntime>::get(&context.node_id).unwrap();
+    NodeManager::apply_participation_penalty(
+        &period,
+        &context.node_id,
+        &mut node_info,
+        policy,
+        ResolvedProposals::<TestRuntime>::get(period),
+        NODE_WEIGHT,
+    )
+}
+
+mod recording_votes {
+    use super::*;
+
+    #[test]
+    fn counts_votes_and_dissents_of_registered_nodes() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.node_id.clone(), true), (context.other_node_id.clone(), false)],
+                );
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: false },
+                    &[(context.node_id.clone(), true)],
+                );
+                settle(&[&context.node_id, &context.other_node_id]);
+
+                assert_eq!(ResolvedProposals::<TestRuntime>::get(period), 2);
+                // Voting for a proposal that failed is not a dissent
+                assert_eq!(
+                    WatchtowerParticipation::<TestRuntime>::get(period, &context.node_id),
+                    ParticipationInfo { votes: 2, dissents: 0 }
+                );
+                assert_eq!(
+                    WatchtowerParticipation::<TestRuntime>::get(period, &context.other_node_id),
+                    ParticipationInfo { votes: 1, dissents: 1 }
+                );
+            });
+    }
+
+    #[test]
+    fn ignores_accounts_that_are_not_nodes() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.not_a_node.clone(), true)],
+                );
+                settle(&[&context.not_a_node]);
+
+                assert_eq!(ResolvedProposals::<TestRuntime>::get(period), 1);
+                assert!(!PendingWatchtowerVotes::<TestRuntime>::contains_key(&context.not_a_node));
+                assert!(!WatchtowerParticipation::<TestRuntime>::contains_key(
+                    period,
+                    &context.not_a_node
+                ));
+            });
+    }
+
+    #[test]
+    fn ignores_proposals_that_did_not_resolve() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+
+                complete_voting(ProposalStatusEnum::Expired, &[(context.node_id.clone(), true)]);
+                settle(&[&context.node_id]);
+
+                assert_eq!(ResolvedProposals::<TestRuntime>::get(period), 0);
+                assert!(!WatchtowerParticipation::<TestRuntime>::contains_key(
+                    period,
+                    &context.node_id
+                ));
+
+                // A later vote replaces the one that will never be counted
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.node_id.clone(), true)],
+                );
+                settle(&[&context.node_id]);
+
+                assert_eq!(
+                    WatchtowerParticipation::<TestRuntime>::get(period, &context.node_id),
+                    ParticipationInfo { votes: 1, dissents: 0 }
+                );
+            });
+    }
+
+    #[test]
+    fn waits_for_the_proposal_to_resolve() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+                let proposal_id = next_proposal_id();
+
+                submit_votes(proposal_id, &[(context.node_id.clone(), false)]);
+                settle(&[&context.node_id]);
+
+                assert_eq!(
+                    PendingWatchtowerVotes::<TestRuntime>::get(&context.node_id),
+                    Some((proposal_id, false))
+                );
+                assert!(!WatchtowerParticipation::<TestRuntime>::contains_key(
+                    period,
+                    &context.node_id
+                ));
+
+                resolve(proposal_id, ProposalStatusEnum::Resolved { passed: true });
+                settle(&[&context.node_id]);
+
+                assert!(!PendingWatchtowerVotes::<TestRuntime>::contains_key(&context.node_id));
+                assert_eq!(
+                    WatchtowerParticipation::<TestRuntime>::get(period, &context.node_id),
+                    ParticipationInfo { votes: 1, dissents: 1 }
+                );
+            });
+    }
+
+    #[test]
+    fn counts_votes_in_the_period_the_proposal_resolved() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let voting_period = RewardPeriod::<TestRuntime>::get().current;
+                let proposal_id = next_proposal_id();
+
+                submit_votes(proposal_id, &[(context.node_id.clone(), true)]);
+                RewardPeriod::<TestRuntime>::mutate(|p| p.current += 1);
+                resolve(proposal_id, ProposalStatusEnum::Resolved { passed: true });
+                settle(&[&context.node_id]);
+
+                let resolving_period = voting_period + 1;
+                assert_eq!(
+                    WatchtowerOutcomes::<TestRuntime>::get(proposal_id),
+                    Some((resolving_period, true))
+                );
+                assert!(!WatchtowerParticipation::<TestRuntime>::contains_key(
+                    voting_period,
+                    &context.node_id
+                ));
+                assert_eq!(
+                    WatchtowerParticipation::<TestRuntime>::get(resolving_period, &context.node_id),
+                    ParticipationInfo { votes: 1, dissents: 0 }
+                );
+            });
+    }
+
+    #[test]
+    fn discards_votes_on_proposals_resolved_in_a_paid_period() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.node_id.clone(), true)],
+                );
+                OldestUnpaidRewardPeriodIndex::<TestRuntime>::put(period + 1);
+                settle(&[&context.node_id]);
+
+                assert!(!PendingWatchtowerVotes::<TestRuntime>::contains_key(&context.node_id));
+                assert!(!WatchtowerParticipation::<TestRuntime>::contains_key(
+                    period,
+                    &context.node_id
+                ));
+            });
+    }
+
+    #[test]
+    fn outcomes_are_pruned_when_the_period_is_paid() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let period = RewardPeriod::<TestRuntime>::get().current;
+                let proposal_id = next_proposal_id();
+                resolve(proposal_id, ProposalStatusEnum::Resolved { passed: false });
+
+                NodeManager::complete_reward_payout(period);
+                assert_eq!(WatchtowerPruneCursor::<TestRuntime>::get(), Some(period));
+
+                NodeManager::on_idle(System::block_number(), Weight::MAX);
+
+                assert_eq!(ResolvedProposals::<TestRuntime>::get(period), 0);
+                assert!(!ResolvedProposalIds::<TestRuntime>::contains_key(period, proposal_id));
+                assert!(!WatchtowerOutcomes::<TestRuntime>::contains_key(proposal_id));
+                assert_eq!(WatchtowerPruneCursor::<TestRuntime>::get(), None);
+            });
+    }
+
+    #[test]
+    fn pruning_resumes_where_the_previous_block_stopped() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+                let first_proposal = next_proposal_id();
+                resolve(first_proposal, ProposalStatusEnum::Resolved { passed: true });
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.node_id.clone(), true)],
+                );
+                settle(&[&context.node_id]);
+                NodeManager::complete_reward_payout(period);
+
+                // Enough weight for the cursor and one record per block
+                let block_weight = <TestRuntime as frame_system::Config>::DbWeight::get()
+                    .reads(2)
+                    .saturating_add(<TestRuntime as Config>::WeightInfo::prune_watchtower_record());
+                NodeManager::on_idle(System::block_number(), block_weight);
+
+                assert_eq!(ResolvedProposalIds::<TestRuntime>::iter_prefix(period).count(), 1);
+                assert_eq!(WatchtowerPruneCursor::<TestRuntime>::get(), Some(period));
+
+                for _ in 0..3 {
+                    NodeManager::on_idle(System::block_number(), block_weight);
+                }
+
+                assert_eq!(ResolvedProposalIds::<TestRuntime>::iter_prefix(period).count(), 0);
+                assert!(!WatchtowerOutcomes::<TestRuntime>::contains_key(first_proposal));
+                assert!(!WatchtowerParticipation::<TestRuntime>::contains_key(
+                    period,
+                    &context.node_id
+                ));
+                assert_eq!(WatchtowerPruneCursor::<TestRuntime>::get(), None);
+            });
+    }
+}
+
+mod penalties {
+    use super::*;
+
+    #[test]
+    fn are_not_applied_to_nodes_that_meet_the_policy() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.node_id.clone(), true)],
+                );
+                complete_voting(ProposalStatusEnum::Resolved { passed: true }, &[]);
+
+                assert_eq!(apply_penalty(&context, &context.policy), NODE_WEIGHT);
+                assert!(!System::events().iter().any(|r| matches!(
+                    r.event,
+                    mock::RuntimeEvent::NodeManager(Event::NodeParticipationPenalised { .. })
+                )));
+            });
+    }
+
+    #[test]
+    fn are_not_applied_when_no_proposals_resolved() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+
+                assert_eq!(apply_penalty(&context, &context.policy), NODE_WEIGHT);
+            });
+    }
+
+    #[test]
+    fn reduce_weight_of_absent_nodes() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let period = RewardPeriod::<TestRuntime>::get().current;
+                for _ in 0..3 {
+                    complete_voting(ProposalStatusEnum::Resolved { passed: true }, &[]);
+                }
+                complete_voting(
+                    ProposalStatusEnum::Resolved { passed: true },
+                    &[(context.node_id.clone(), true)],
+                );
+
+                assert_eq!(apply_penalty(&context, &context.policy), 400);
+                System::assert_last_event(
+                    Event::NodeParticipationPenalised {
+                        reward_period: period,
+                        node: context.node_id.clone(),
+                        participation: ParticipationInfo { votes: 1, dissents: 0 },
+                        resolved_proposals: 4,
+                        weight_kept: Perbill::from_percent(40),
+                        slashed: 0,
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn reduce_weight_of_nodes_that_repeatedly_dissent() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                for _ in 0..2 {
+                    complete_voting(
+                        ProposalStatusEnum::Resolved { passed: true },
+                        &[(context.node_id.clone(), false)],
+                    );
+                }
+
+                assert_eq!(apply_penalty(&context, &context.policy), 500);
+            });
+    }
+
+    #[test]
+    fn compound_when_a_node_is_absent_and_dissents() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                for _ in 0..2 {
+                    complete_voting(
+                        ProposalStatusEnum::Resolved { passed: true },
+                        &[(context.node_id.clone(), false)],
+                    );
+                }
+                for _ in 0..3 {
+                    complete_voting(ProposalStatusEnum::Resolved { passed: false }, &[]);
+                }
+
+                assert_eq!(apply_penalty(&context, &context.policy), 200);
+            });
+    }
+
+    #[test]
+    fn slash_stake_into_the_reward_pot() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let stake = 1_000 * AVT;
+                Balances::make_free_balance_be(&context.owner, stake);
+                assert_ok!(NodeManager::add_stake(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    stake,
+                ));
+                let pot_before = NodeManager::reward_pot_balance();
+                complete_voting(ProposalStatusEnum::Resolved { passed: true }, &[]);
+
+                let policy = ParticipationPolicy {
+                    slash_percentage: Perbill::from_percent(10),
+                    ..context.policy
+                };
+                assert_eq!(apply_penalty(&context, &policy), 400);
+
+                let slashed = 100 * AVT;
+                assert_eq!(
+                    NodeRegistry::<TestRuntime>::get(&context.node_id).unwrap().stake.amount,
+                    stake - slashed
+                );
+                assert_eq!(TotalStake::<TestRuntime>::get(&context.owner), Some(stake - slashed));
+                assert_eq!(
+                    Balances::reserved_balance_named(&STAKE_RESERVE_ID, &context.owner),
+                    stake - slashed
+                );
+                assert_eq!(NodeManager::reward_pot_balance(), pot_before + slashed);
+                System::assert_last_event(
+                    Event::NodeParticipationPenalised {
+                        reward_period: RewardPeriod::<TestRuntime>::get().current,
+                        node: context.node_id.clone(),
+                        participation: ParticipationInfo::default(),
+                        resolved_proposals: 1,
+                        weight_kept: Perbill::from_percent(40),
+                        slashed,
+                    }
+                    .into(),
+                );
+            });
+    }
+
+    #[test]
+    fn only_slash_the_stake_reserve() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+                let stake = 1_000 * AVT;
+                let reserved_elsewhere = 950 * AVT;
+                Balances::make_free_balance_be(&context.owner, stake);
+                assert_ok!(NodeManager::add_stake(
+                    RuntimeOrigin::signed(context.owner.clone()),
+                    context.node_id.clone(),
+                    stake,
+                ));
+                // Most of the owner's reserve now belongs to another pallet
+                Balances::unreserve_named(&STAKE_RESERVE_ID, &context.owner, reserved_elsewhere);
+                assert_ok!(Balances::reserve(&context.owner, reserved_elsewhere));
+                let pot_before = NodeManager::reward_pot_balance();
+                complete_voting(ProposalStatusEnum::Resolved { passed: true }, &[]);
+
+                let policy = ParticipationPolicy {
+                    slash_percentage: Perbill::from_percent(10),
+                    ..context.policy
+                };
+                apply_penalty(&context, &policy);
+
+                let slashed = stake - reserved_elsewhere;
+                assert_eq!(Balances::reserved_balance_named(&STAKE_RESERVE_ID, &context.owner), 0);
+                assert_eq!(Balances::reserved_balance(&context.owner), reserved_elsewhere);
+                assert_eq!(NodeManager::reward_pot_balance(), pot_before + slashed);
+                assert_eq!(
+                    NodeRegistry::<TestRuntime>::get(&context.node_id).unwrap().stake.amount,
+                    stake - slashed
+                );
+            });
+    }
+}
+
+mod participation_policy {
+    use super::*;
+
+    #[test]
+    fn can_be_set_and_cleared_by_root() {
+        ExtBuilder::build_default()
+            .with_genesis_config()
+            .as_externality()
+            .execute_with(|| {
+                let context = Context::default();
+
+                assert_ok!(NodeManager::set_admin_config(
+                    RuntimeOrigin::root(),
+                    AdminConfig::WatchtowerParticipationPolicy(Some(context.policy)),
+                ));
+                assert_eq!(
+                    WatchtowerParticipationPolicy::<TestRuntime>::get(),
+                    Some(context.policy)
+                );
+                System::assert_last_event(
+                    Event::WatchtowerParticipationPolicySet { policy: Some(context.policy) }.into(),
+                );
+
+                assert_ok!(NodeManager::set_admin_config(
+                    RuntimeOrigin::root(),
+                    AdminConfig::WatchtowerParticipationPolicy(None),
+                ));
+                assert_eq!(WatchtowerParticipationPolicy::<TestRuntime>::get(), None);
+            });
+    }
+}
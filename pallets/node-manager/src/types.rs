@@ -3,14 +3,17 @@
 use crate::*;
 use frame_support::traits::Get;
 use sp_runtime::{
-    traits::{AtLeast32BitUnsigned, Zero},
-    ArithmeticError, FixedPointNumber, FixedU128, Saturating,
+    helpers_128bit::multiply_by_rational_with_rounding,
+    traits::{AtLeast32BitUnsigned, UniqueSaturatedInto, Zero},
+    ArithmeticError, FixedPointNumber, FixedU128, Rounding, Saturating,
 };
 use sp_std::fmt::Debug;
 // This is used to scale a single heartbeat so we can preserve precision when applying the reward
 // weight.
 pub const HEARTBEAT_BASE_WEIGHT: u128 = 100_000_000;
 pub type Duration = u64;
+// Scale applied to the accumulated delegator reward per unit of stake to preserve precision.
+pub const REWARD_PER_STAKE_SCALE: u128 = 1_000_000_000_000;
 
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// The current era index and transition information
@@ -291,40 +294,83 @@ impl<
         now_sec: Duration,
         unstake_period: Duration,
     ) -> Result<(Balance, Option<Duration>), DispatchError> {
-        if self.stake.amount.is_zero() || unstake_period == 0 {
-            return Ok((Zero::zero(), self.stake.next_unstake_time_sec))
+        self.stake.available_to_unstake(now_sec, unstake_period, self.auto_stake_expiry)
+    }
+}
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct StakeInfo<Balance> {
+    /// The amount staked
+    pub amount: Balance,
+    /// Allowance carried over (how much they can withdraw right now).
+    pub unlocked_stake: Balance,
+    /// The timestamp (seconds) that represents the next unstaking period.
+    pub next_unstake_time_sec: Option<Duration>,
+    /// Unstake restriction state.
+    pub restriction: UnstakeRestriction<Balance>,
+}
+
+impl<Balance: Copy + Debug> StakeInfo<Balance> {
+    pub fn new(
+        amount: Balance,
+        unlocked_stake: Balance,
+        next_unstake_time_sec: Option<Duration>,
+        restriction: UnstakeRestriction<Balance>,
+    ) -> Self {
+        StakeInfo { amount, unlocked_stake, next_unstake_time_sec, restriction }
+    }
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy + Debug> StakeInfo<Balance> {
+    /// Amount that can be unstaked at `now_sec` and the start of the next unstake period.
+    /// `first_unstake_time_sec` is used as the period boundary when none has been recorded yet.
+    pub fn available_to_unstake(
+        &self,
+        now_sec: Duration,
+        unstake_period: Duration,
+        first_unstake_time_sec: Duration,
+    ) -> Result<(Balance, Option<Duration>), DispatchError> {
+        if self.amount.is_zero() || unstake_period == 0 {
+            return Ok((Zero::zero(), self.next_unstake_time_sec))
         }
 
-        match &self.stake.restriction {
+        match &self.restriction {
             UnstakeRestriction::Locked => Ok((Zero::zero(), None)),
-            UnstakeRestriction::Free => Ok((self.stake.amount, None)),
+            UnstakeRestriction::Free => Ok((self.amount, None)),
             UnstakeRestriction::Periodic { per_period_allowance, expires_sec } => {
                 // All restrictions lifted — treat as Free.
                 if now_sec >= *expires_sec {
-                    return Ok((self.stake.amount, None))
+                    return Ok((self.amount, None))
                 }
 
                 // Determine the boundary of the current unstake period.
-                let next_unstake =
-                    self.stake.next_unstake_time_sec.unwrap_or(self.auto_stake_expiry);
+                let next_unstake = self.next_unstake_time_sec.unwrap_or(first_unstake_time_sec);
 
                 // Still within the current period return already free allowance only.
                 if now_sec < next_unstake {
-                    return Ok((
-                        self.stake.unlocked_stake.min(self.stake.amount),
-                        Some(next_unstake),
-                    ))
+                    return Ok((self.unlocked_stake.min(self.amount), Some(next_unstake)))
                 }
 
                 let elapsed = now_sec.saturating_sub(next_unstake);
                 let periods = 1u64.saturating_add(elapsed / unstake_period);
                 let newly_unlocked = per_period_allowance.saturating_mul((periods as u32).into());
                 let available = self
-                    .stake
                     .unlocked_stake
                     .checked_add(&newly_unlocked)
                     .ok_or(ArithmeticError::Overflow)?
-                    .min(self.stake.amount);
+                    .min(self.amount);
 
                 let next = next_unstake
                     .checked_add(periods.saturating_mul(unstake_period))
@@ -341,7 +387,6 @@ impl<
     Decode,
     DecodeWithMemTracking,
     Clone,
-    Copy,
     PartialEq,
     Eq,
     RuntimeDebug,
@@ -349,25 +394,363 @@ impl<
     MaxEncodedLen,
     Default,
 )]
-pub struct StakeInfo<Balance> {
-    /// The amount staked
+pub struct DelegationPool<Balance> {
+    /// Share of the delegators' reward kept by the node owner
+    pub commission: Perbill,
+    /// Commission increase scheduled by the node owner
+    pub commission_request: Option<NodeCommissionChangeRequest>,
+    /// Total stake delegated to the node by third parties, including `pending_stake`
+    pub total_stake: Balance,
+    /// Accumulated delegator reward per unit of stake, scaled by `REWARD_PER_STAKE_SCALE`
+    pub reward_per_stake: u128,
+    /// Stake delegated during reward periods that have not been paid yet. It only earns the
+    /// rewards of the periods that follow the one it was delegated in.
+    pub pending_stake: BoundedVec<PendingStake<Balance>, MaxPendingStakePeriods>,
+}
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+/// Stake delegated during a reward period
+pub struct PendingStake<Balance> {
+    pub period: RewardPeriodIndex,
     pub amount: Balance,
-    /// Allowance carried over (how much they can withdraw right now).
-    pub unlocked_stake: Balance,
-    /// The timestamp (seconds) that represents the next unstaking period.
-    pub next_unstake_time_sec: Option<Duration>,
-    /// Unstake restriction state.
-    pub restriction: UnstakeRestriction<Balance>,
 }
 
-impl<Balance: Copy + Debug> StakeInfo<Balance> {
-    pub fn new(
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+/// Pending stake that started earning, kept until every delegation it belongs to has been updated
+pub struct StakeActivation<Balance> {
+    /// The pool's `reward_per_stake` when the stake started earning
+    pub reward_per_stake: u128,
+    /// Stake of the delegations that have not been updated yet
+    pub amount: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> StakeActivation<Balance> {
+    /// Reward `stake` would have earned from the pool's creation until it started earning.
+    pub fn accrued_reward(&self, stake: Balance) -> u128 {
+        accrued_reward(stake, self.reward_per_stake)
+    }
+}
+
+// Reward earned by `stake` at the given accumulated reward per unit of stake, in balance units.
+fn accrued_reward<Balance: UniqueSaturatedInto<u128>>(
+    stake: Balance,
+    reward_per_stake: u128,
+) -> u128 {
+    multiply_by_rational_with_rounding(
+        stake.unique_saturated_into(),
+        reward_per_stake,
+        REWARD_PER_STAKE_SCALE,
+        Rounding::Down,
+    )
+    .unwrap_or(u128::MAX)
+}
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+/// Request scheduled to increase a node commission
+pub struct NodeCommissionChangeRequest {
+    pub commission: Perbill,
+    pub when_executable: RewardPeriodIndex,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> DelegationPool<Balance> {
+    /// The commission in effect for rewards of `period`, including a pending increase that is due
+    pub fn effective_commission(&self, period: RewardPeriodIndex) -> Perbill {
+        match self.commission_request {
+            Some(request) if request.when_executable <= period => request.commission,
+            _ => self.commission,
+        }
+    }
+
+    /// Set the node commission. Decreases apply immediately, increases are scheduled to apply
+    /// after the commission increase delay.
+    /// Returns the reward period from which the new commission applies
+    pub fn set_commission<T: Config>(
+        &mut self,
+        commission: Perbill,
+        now: RewardPeriodIndex,
+    ) -> Result<RewardPeriodIndex, DispatchError> {
+        ensure!(commission <= T::MaxNodeCommission::get(), Error::<T>::CommissionTooHigh);
+        // apply a pending increase that is already due before comparing
+        self.commission = self.effective_commission(now);
+        self.commission_request = None;
+        if commission <= self.commission {
+            self.commission = commission;
+            return Ok(now)
+        }
+        let when_executable = now.saturating_add(T::NodeCommissionIncreaseDelay::get());
+        self.commission_request = Some(NodeCommissionChangeRequest { commission, when_executable });
+        Ok(when_executable)
+    }
+
+    /// Total reward earned by `stake` since the pool was created, in balance units.
+    pub fn accrued_reward(&self, stake: Balance) -> u128 {
+        accrued_reward(stake, self.reward_per_stake)
+    }
+
+    /// Stake that was delegated for the whole of `period`.
+    pub fn earning_stake(&self, period: RewardPeriodIndex) -> Balance {
+        self.pending_stake
+            .iter()
+            .filter(|pending| pending.period >= period)
+            .fold(self.total_stake, |stake, pending| stake.saturating_sub(pending.amount))
+    }
+
+    /// Spread the `reward` of `period` across the stake that earned it. Does nothing if there is no
+    /// such stake.
+    pub fn credit_reward(&mut self, period: RewardPeriodIndex, reward: Balance) {
+        let earning_stake: u128 = self.earning_stake(period).unique_saturated_into();
+        if earning_stake.is_zero() {
+            return
+        }
+
+        let increase = multiply_by_rational_with_rounding(
+            reward.unique_saturated_into(),
+            REWARD_PER_STAKE_SCALE,
+            earning_stake,
+            Rounding::Down,
+        )
+        .unwrap_or(u128::MAX);
+        self.reward_per_stake = self.reward_per_stake.saturating_add(increase);
+    }
+
+    /// Record `amount` of stake delegated during `period`.
+    pub fn add_pending_stake<T: Config>(
+        &mut self,
+        period: RewardPeriodIndex,
         amount: Balance,
-        unlocked_stake: Balance,
-        next_unstake_time_sec: Option<Duration>,
-        restriction: UnstakeRestriction<Balance>,
-    ) -> Self {
-        StakeInfo { amount, unlocked_stake, next_unstake_time_sec, restriction }
+    ) -> Result<(), DispatchError> {
+        match self.pending_stake.iter_mut().find(|pending| pending.period == period) {
+            Some(pending) => {
+                pending.amount =
+                    pending.amount.checked_add(&amount).ok_or(Error::<T>::BalanceOverflow)?;
+                Ok(())
+            },
+            None => self
+                .pending_stake
+                .try_push(PendingStake { period, amount })
+                .map_err(|_| Error::<T>::TooManyPendingStakePeriods.into()),
+        }
+    }
+
+    /// Remove `amount` of the stake delegated during `period` that is not earning yet.
+    pub fn remove_pending_stake(&mut self, period: RewardPeriodIndex, amount: Balance) {
+        for pending in self.pending_stake.iter_mut().filter(|pending| pending.period == period) {
+            pending.amount = pending.amount.saturating_sub(amount);
+        }
+        self.pending_stake.retain(|pending| !pending.amount.is_zero());
+    }
+}
+
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct Delegation<Balance> {
+    /// The delegated stake and its unstake schedule
+    pub stake: StakeInfo<Balance>,
+    /// Reward already accounted for, in balance units, at the pool's current `reward_per_stake`
+    pub reward_debt: u128,
+    /// Part of `stake` delegated during a reward period that has not been paid yet
+    pub pending: Option<PendingStake<Balance>>,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> Delegation<Balance> {
+    /// Part of the stake that earns rewards.
+    pub fn earning_stake(&self) -> Balance {
+        let pending = self.pending.map(|pending| pending.amount).unwrap_or_else(Zero::zero);
+        self.stake.amount.saturating_sub(pending)
+    }
+
+    /// Count the pending stake as earning from its `activation`. It only earns what was credited
+    /// after that.
+    pub fn activate_pending_stake(&mut self, activation: &StakeActivation<Balance>) {
+        if let Some(pending) = self.pending.take() {
+            self.reward_debt =
+                self.reward_debt.saturating_add(activation.accrued_reward(pending.amount));
+        }
+    }
+
+    /// Reward earned by this delegation that has not been claimed yet.
+    pub fn pending_reward(&self, pool: &DelegationPool<Balance>) -> u128 {
+        pool.accrued_reward(self.earning_stake()).saturating_sub(self.reward_debt)
+    }
+}
+
+/// Proof of work done for another pallet, attached to a heartbeat.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum WorkEvidence {
+    /// The node voted on this watchtower proposal, which requires recomputing the proposed root
+    WatchtowerVote { proposal_id: H256 },
+}
+
+/// Evidence carried by a heartbeat that the node is running a live client.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct HeartbeatEvidence<BlockNumber, Hash> {
+    /// A recent finalised block imported by the node
+    pub block_number: BlockNumber,
+    /// The hash of `block_number` as seen by the node
+    pub block_hash: Hash,
+    /// Proof of work done for another pallet. Heartbeats without it get a reduced weight
+    pub work: Option<WorkEvidence>,
+}
+
+/// Source and verifier of the work evidence nodes attach to their heartbeats.
+pub trait NodeWorkVerifier<AccountId> {
+    /// Work `node` can prove in its next heartbeat. Called from the offchain worker.
+    fn work_evidence(node: &AccountId) -> Option<WorkEvidence>;
+
+    /// Check that `node` did the work claimed in `evidence`.
+    fn verify_work(node: &AccountId, evidence: &WorkEvidence) -> bool;
+
+    /// Worst-case weight of a single `verify_work` call.
+    fn verify_work_weight() -> Weight;
+}
+
+impl<AccountId> NodeWorkVerifier<AccountId> for () {
+    fn work_evidence(_node: &AccountId) -> Option<WorkEvidence> {
+        None
+    }
+
+    fn verify_work(_node: &AccountId, _evidence: &WorkEvidence) -> bool {
+        false
+    }
+
+    fn verify_work_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Watchtower voting record of a node in a reward period.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct ParticipationInfo {
+    /// Resolved proposals the node voted on
+    pub votes: u32,
+    /// Votes against the outcome of a resolved proposal
+    pub dissents: u32,
+}
+
+/// Penalties for nodes that do not take part in watchtower voting.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ParticipationPolicy {
+    /// Minimum share of the period's resolved proposals a node must vote on
+    pub min_participation: Perbill,
+    /// Share of its reward weight kept by a node below `min_participation`
+    pub absence_weight: Perbill,
+    /// Dissenting votes tolerated in a period
+    pub max_dissents: u32,
+    /// Share of its reward weight kept by a node with more than `max_dissents` dissenting votes
+    pub dissent_weight: Perbill,
+    /// Share of a penalised node's stake moved to the reward pot
+    pub slash_percentage: Perbill,
+}
+
+impl ParticipationPolicy {
+    /// Share of its reward weight a node keeps, given its record and the number of proposals
+    /// resolved in the period.
+    pub fn weight_kept(
+        &self,
+        participation: &ParticipationInfo,
+        resolved_proposals: u32,
+    ) -> Perbill {
+        let mut kept = Perbill::one();
+
+        if resolved_proposals > 0 &&
+            Perbill::from_rational(participation.votes, resolved_proposals) <
+                self.min_participation
+        {
+            kept = kept * self.absence_weight;
+        }
+
+        if participation.dissents > self.max_dissents {
+            kept = kept * self.dissent_weight;
+        }
+
+        kept
     }
 }
 
@@ -388,6 +771,8 @@ pub enum AdminConfig<AccountId, Balance> {
     RewardFee(Perbill),
     GenesisBonus50(BonusRange),
     GenesisBonus25(BonusRange),
+    UnverifiedHeartbeatWeight(Perbill),
+    WatchtowerParticipationPolicy(Option<ParticipationPolicy>),
 }
 
 #[derive(
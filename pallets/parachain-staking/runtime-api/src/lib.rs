@@ -0,0 +1,36 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_parachain_staking::{
+    staking_info::{
+        CandidateApy, CandidateEraInfo, NominationUnlock, NominatorEraShare, PendingPayout,
+    },
+    EraIndex, NominationAction, RewardPoint,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait ParachainStakingApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Returns the current era
+        fn current_era() -> EraIndex;
+        /// Returns the stake and points of every collator selected for `era`, until the collator
+        /// has been paid for it.
+        fn candidate_era_info(era: EraIndex) -> Vec<CandidateEraInfo<AccountId, Balance>>;
+        /// Returns the total points awarded to collators in `era`
+        fn era_points(era: EraIndex) -> RewardPoint;
+        /// Returns the share of `nominator` in each collator it backed in `era`
+        fn nominator_era_shares(
+            nominator: AccountId,
+            era: EraIndex,
+        ) -> Vec<NominatorEraShare<AccountId, Balance>>;
+        /// Returns the era rewards that are still being paid out
+        fn pending_payouts() -> Vec<PendingPayout<Balance>>;
+        /// Returns the scheduled nomination requests of `nominator` and the eras they unlock in
+        fn nomination_unlocks(nominator: AccountId) -> Vec<NominationUnlock<AccountId, Balance>>;
+        /// Returns the projected yearly return on a nomination to each selected collator
+        fn projected_apys() -> Vec<CandidateApy<AccountId>>;
+    }
+}
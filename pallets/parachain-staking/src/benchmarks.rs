@@ -20,13 +20,16 @@
 use super::*;
 use crate::{
     encode_signed_bond_extra_params, encode_signed_candidate_bond_extra_params,
-    encode_signed_execute_candidate_unbond_params, encode_signed_execute_leave_nominators_params,
-    encode_signed_execute_nomination_request_params, encode_signed_nominate_params,
+    encode_signed_candidate_rebond_params, encode_signed_execute_candidate_unbond_params,
+    encode_signed_execute_leave_nominators_params, encode_signed_execute_nomination_request_params,
+    encode_signed_nominate_params, encode_signed_nominator_rebond_params,
     encode_signed_schedule_candidate_unbond_params, encode_signed_schedule_leave_nominators_params,
     encode_signed_schedule_nominator_unbond_params,
-    encode_signed_schedule_revoke_nomination_params, AdminSettings, AwardedPts, BalanceOf, Call,
-    CandidateBondLessRequest, Config, Delay, Era, MinCollatorStake, MinTotalNominatorStake,
-    NominationAction, Pallet, Points, Proof, ScheduledRequest,
+    encode_signed_schedule_revoke_nomination_params, encode_signed_set_auto_compound_params,
+    AdminSettings, AwardedPts, BalanceOf, Call, CandidateBondLessRequest, CollatorPerformance,
+    Config, Delay, Era, MaxMissedSlotRate, MinCollatorStake, MinTotalNominatorStake, NextPoolId,
+    NominationAction, Pallet, Points, PoolId, Proof, ScheduledRequest, SelectedCandidates,
+    UnappliedSlash, UnappliedSlashes,
 };
 use codec::{Decode, Encode};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
@@ -229,6 +232,16 @@ fn roll_to_and_author<T: Config>(era_delay: u32, author: T::AccountId) {
     }
 }
 
+/// Creates a pool nominating `collator`, bonding twice the minimum nominator stake
+fn create_funded_pool<T: Config>(collator: T::AccountId) -> Result<PoolId, &'static str> {
+    let bond = min_nominator_stk::<T>() + min_nominator_stk::<T>();
+    let (operator, _) = create_funded_user::<T>("operator", USER_SEED, bond);
+    let pool_id = <NextPoolId<T>>::get();
+    Pallet::<T>::create_pool(RawOrigin::Signed(operator).into(), vec![collator], bond)?;
+
+    Ok(pool_id)
+}
+
 fn get_collator_count<T: Config>() -> u32 {
     return Pallet::<T>::selected_candidates().len() as u32
 }
@@ -652,11 +665,11 @@ benchmarks! {
     verify {
         let state = Pallet::<T>::candidate_info(&caller).expect("request bonded less so exists");
         assert_eq!(
-            state.request,
-            Some(CandidateBondLessRequest {
+            state.requests.into_inner(),
+            vec![CandidateBondLessRequest {
                 amount: min_candidate_stk,
                 when_executable: 3,
-            })
+            }]
         );
     }
 
@@ -669,11 +682,11 @@ benchmarks! {
     verify {
         let state = Pallet::<T>::candidate_info(&caller).expect("request bonded less so exists");
         assert_eq!(
-            state.request,
-            Some(CandidateBondLessRequest {
+            state.requests.into_inner(),
+            vec![CandidateBondLessRequest {
                 amount: min_candidate_stk,
                 when_executable: 3,
-            })
+            }]
         );
     }
 
@@ -747,7 +760,7 @@ benchmarks! {
         )?;
     } verify {
         assert!(
-            Pallet::<T>::candidate_info(&caller).unwrap().request.is_none()
+            Pallet::<T>::candidate_info(&caller).unwrap().requests.is_empty()
         );
     }
 
@@ -803,7 +816,7 @@ benchmarks! {
     }: _(RawOrigin::Signed(caller.clone()))
     verify {
         assert!(
-            Pallet::<T>::nomination_scheduled_requests(&collator)
+            Pallet::<T>::nomination_scheduled_requests(&collator, &caller)
                 .iter()
                 .any(|r| r.nominator == caller && matches!(r.action, NominationAction::Revoke(_)))
         );
@@ -832,7 +845,7 @@ benchmarks! {
     }: _(RawOrigin::Signed(caller.clone()), proof)
     verify {
         assert!(
-            Pallet::<T>::nomination_scheduled_requests(&collator)
+            Pallet::<T>::nomination_scheduled_requests(&collator, &caller)
                 .iter()
                 .any(|r| r.nominator == caller && matches!(r.action, NominationAction::Revoke(_)))
         );
@@ -908,7 +921,7 @@ benchmarks! {
     }: _(RawOrigin::Signed(caller.clone()), collator.clone())
     verify {
         assert_eq!(
-            Pallet::<T>::nomination_scheduled_requests(&collator),
+            Pallet::<T>::nomination_scheduled_requests(&collator, &caller),
             vec![ScheduledRequest {
                 nominator: caller,
                 when_executable: 3,
@@ -940,7 +953,7 @@ benchmarks! {
     }: _(RawOrigin::Signed(caller.clone()), proof, collator.clone())
     verify {
         assert_eq!(
-            Pallet::<T>::nomination_scheduled_requests(&collator),
+            Pallet::<T>::nomination_scheduled_requests(&collator, &caller),
             vec![ScheduledRequest {
                 nominator: caller,
                 when_executable: 3,
@@ -1029,7 +1042,7 @@ benchmarks! {
         let state = Pallet::<T>::nominator_state(&caller)
             .expect("just request bonded less so exists");
         assert_eq!(
-            Pallet::<T>::nomination_scheduled_requests(&collator),
+            Pallet::<T>::nomination_scheduled_requests(&collator, &caller),
             vec![ScheduledRequest {
                 nominator: caller,
                 when_executable: 3,
@@ -1065,7 +1078,7 @@ benchmarks! {
         let state = Pallet::<T>::nominator_state(&caller)
             .expect("just request bonded less so exists");
         assert_eq!(
-            Pallet::<T>::nomination_scheduled_requests(&collator),
+            Pallet::<T>::nomination_scheduled_requests(&collator, &caller),
             vec![ScheduledRequest {
                 nominator: caller,
                 when_executable: 3,
@@ -1216,7 +1229,7 @@ benchmarks! {
         )?;
     } verify {
         assert!(
-            !Pallet::<T>::nomination_scheduled_requests(&collator)
+            !Pallet::<T>::nomination_scheduled_requests(&collator, &caller)
             .iter()
             .any(|x| &x.nominator == &caller)
         );
@@ -1252,7 +1265,7 @@ benchmarks! {
         )?;
     } verify {
         assert!(
-            !Pallet::<T>::nomination_scheduled_requests(&collator)
+            !Pallet::<T>::nomination_scheduled_requests(&collator, &caller)
                 .iter()
                 .any(|x| &x.nominator == &caller)
         );
@@ -1476,6 +1489,7 @@ benchmarks! {
             bond: 1_000u32.into(),
             nominations,
             total: 1_000_000u32.into(),
+            commission: Perbill::zero(),
         });
 
         <Points<T>>::insert(era_for_payout, 100);
@@ -1570,6 +1584,27 @@ benchmarks! {
         assert_eq!(20u32, <Points<T>>::get(now));
     }
 
+    record_collator_performance {
+        // x controls the number of collators selected in the era
+        let x in 1..<<T as Config>::MaxCandidates as Get<u32>>::get();
+
+        let era = <Era<T>>::get().current;
+        let mut collators: Vec<T::AccountId> =
+            (0..x).map(|i| account("collator", USER_SEED - i, 0)).collect();
+        collators.sort();
+        for collator in collators.iter() {
+            <AwardedPts<T>>::insert(era, collator, 20u32);
+        }
+        <Points<T>>::insert(era, 20u32 * x);
+        <SelectedCandidates<T>>::put(BoundedVec::truncate_from(collators));
+        // missing every slot scales the points of every collator, which is the worst case
+        <MaxMissedSlotRate<T>>::put(Perbill::zero());
+    }: { Pallet::<T>::record_collator_performance(era, 2 * x) }
+    verify {
+        assert_eq!(<CollatorPerformance<T>>::iter().count() as u32, x);
+        assert!(<Points<T>>::get(era).is_zero());
+    }
+
     set_admin_setting {
         let new_delay_value = <Delay<T>>::get() - 1;
         let new_delay_setting = AdminSettings::<BalanceOf<T>>::Delay(new_delay_value);
@@ -1577,6 +1612,344 @@ benchmarks! {
     verify {
         assert_eq!(new_delay_value, <Delay<T>>::get());
     }
+
+    set_auto_compound {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let nominator: T::AccountId = create_funded_nominator::<T>(
+            "nominator",
+            USER_SEED - 1,
+            0u32.into(),
+            collator.clone(),
+            true,
+            0u32,
+        )?;
+        let value = Percent::from_percent(50);
+    }: _(RawOrigin::Signed(nominator.clone()), collator.clone(), value)
+    verify {
+        assert_eq!(Pallet::<T>::auto_compound(&collator, &nominator), value);
+    }
+
+    signed_set_auto_compound {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+
+        let value = Percent::from_percent(50);
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_set_auto_compound_params::<T>(relayer, &collator, &value, nonce))?;
+        let bond = <MinTotalNominatorStake<T>>::get();
+        fund_account::<T>(&caller, bond * 2u32.into());
+
+        Pallet::<T>::nominate(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            bond,
+            0u32,
+            0u32
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), proof, collator.clone(), value)
+    verify {
+        assert_eq!(Pallet::<T>::auto_compound(&collator, &caller), value);
+    }
+
+    set_commission {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        // an increase is the worst case because it is scheduled
+        let commission = T::MaxCommission::get();
+    }: _(RawOrigin::Signed(caller.clone()), commission)
+    verify {
+        let execute_era = <Era<T>>::get().current + T::CommissionIncreaseDelay::get();
+        assert_eq!(
+            Pallet::<T>::candidate_info(&caller).unwrap().effective_commission(execute_era),
+            commission
+        );
+    }
+
+    cancel_deferred_slash {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let apply_era = <Era<T>>::get().current + T::SlashDeferDuration::get();
+        <UnappliedSlashes<T>>::insert(apply_era, &collator, UnappliedSlash {
+            own: min_candidate_stk::<T>(),
+            others: BoundedVec::default(),
+        });
+    }: _(RawOrigin::Root, apply_era, collator.clone())
+    verify {
+        assert!(!<UnappliedSlashes<T>>::contains_key(apply_era, &collator));
+    }
+
+    apply_slash {
+        // x controls the number of slashed nominations, its maximum is the max top nominations
+        let x in 0..<<T as Config>::MaxTopNominationsPerCandidate as Get<u32>>::get();
+
+        let fraction = Perbill::from_percent(50);
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+
+        let mut others: Vec<Bond<T::AccountId, BalanceOf<T>>> = Vec::new();
+        for i in 0..x {
+            let nominator = create_funded_nominator::<T>(
+                "nominator",
+                USER_SEED - 1 - i,
+                min_nominator_stk::<T>(),
+                collator.clone(),
+                false,
+                others.len() as u32,
+            )?;
+            let bond = Pallet::<T>::nominator_state(&nominator).unwrap().total();
+            others.push(Bond { owner: nominator, amount: fraction * bond });
+        }
+
+        // slashing a collator with the minimum bond takes it offline, which is the worst case
+        let slash = UnappliedSlash {
+            own: fraction * min_candidate_stk::<T>(),
+            others: BoundedVec::truncate_from(others),
+        };
+    }: { Pallet::<T>::apply_slash(&collator, slash) }
+    verify {
+        assert!(!Pallet::<T>::candidate_info(&collator).unwrap().is_active());
+    }
+
+    create_pool {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let bond = min_nominator_stk::<T>();
+        let (operator, _) = create_funded_user::<T>("operator", USER_SEED - 1, bond);
+        let pool_id = <NextPoolId<T>>::get();
+    }: _(RawOrigin::Signed(operator.clone()), vec![collator], bond)
+    verify {
+        assert_eq!(Pallet::<T>::pools(pool_id).unwrap().operator, operator);
+    }
+
+    set_pool_collators {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let new_collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED - 1,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let pool_id = create_funded_pool::<T>(collator)?;
+        let operator = Pallet::<T>::pools(pool_id).unwrap().operator;
+    }: _(RawOrigin::Signed(operator), pool_id, vec![new_collator.clone()])
+    verify {
+        assert_eq!(Pallet::<T>::pools(pool_id).unwrap().collators.to_vec(), vec![new_collator]);
+    }
+
+    join_pool {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let pool_id = create_funded_pool::<T>(collator)?;
+        let amount = min_nominator_stk::<T>();
+        let (member, _) = create_funded_user::<T>("member", USER_SEED - 1, amount);
+    }: _(RawOrigin::Signed(member.clone()), pool_id, amount)
+    verify {
+        assert_eq!(Pallet::<T>::pool_members(pool_id, &member).unwrap().shares, amount);
+    }
+
+    unbond_from_pool {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let pool_id = create_funded_pool::<T>(collator)?;
+        let amount = min_nominator_stk::<T>();
+        let (member, _) = create_funded_user::<T>("member", USER_SEED - 1, amount);
+        Pallet::<T>::join_pool(RawOrigin::Signed(member.clone()).into(), pool_id, amount)?;
+        // worst case: stake returned to the pool is bonded again first
+        T::Currency::deposit_creating(&Pallet::<T>::pool_account_id(pool_id), amount);
+        <Pools<T>>::mutate(pool_id, |pool| {
+            if let Some(pool) = pool {
+                pool.returned = amount;
+            }
+        });
+    }: _(RawOrigin::Signed(member.clone()), pool_id, amount)
+    verify {
+        assert_eq!(Pallet::<T>::pool_members(pool_id, &member).unwrap().unbonding.len(), 1);
+        assert!(Pallet::<T>::pools(pool_id).unwrap().returned.is_zero());
+    }
+
+    withdraw_from_pool {
+        // x controls the number of unbonding chunks withdrawn
+        let x in 1..<<T as Config>::MaxPoolUnbondingChunks as Get<u32>>::get();
+
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let pool_id = create_funded_pool::<T>(collator.clone())?;
+        let amount = min_nominator_stk::<T>();
+        let (member, _) = create_funded_user::<T>("member", USER_SEED - 1, amount);
+        Pallet::<T>::join_pool(RawOrigin::Signed(member.clone()).into(), pool_id, amount)?;
+
+        let chunk = amount / <<T as Config>::MaxPoolUnbondingChunks as Get<u32>>::get().into();
+        for _ in 0..x {
+            Pallet::<T>::unbond_from_pool(RawOrigin::Signed(member.clone()).into(), pool_id, chunk)?;
+        }
+        roll_to_and_author::<T>(<Delay<T>>::get(), collator);
+    }: _(RawOrigin::Signed(member.clone()), pool_id)
+    verify {
+        assert!(Pallet::<T>::pool_members(pool_id, &member).unwrap().unbonding.is_empty());
+    }
+
+    claim_pool_rewards {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>(),
+        )?;
+        let pool_id = create_funded_pool::<T>(collator)?;
+        let operator = Pallet::<T>::pools(pool_id).unwrap().operator;
+        let pool_account = Pallet::<T>::pool_account_id(pool_id);
+        let reward = min_nominator_stk::<T>();
+        T::Currency::deposit_creating(&pool_account, reward);
+        let balance_before = T::Currency::free_balance(&operator);
+    }: _(RawOrigin::Signed(operator.clone()), pool_id)
+    verify {
+        assert!(T::Currency::free_balance(&operator) > balance_before);
+    }
+
+    candidate_rebond {
+        let min_candidate_stk = min_candidate_stk::<T>();
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            min_candidate_stk,
+            false,
+            get_collator_count::<T>(),
+        )?;
+        Pallet::<T>::schedule_candidate_unbond(
+            RawOrigin::Signed(caller.clone()).into(),
+            min_candidate_stk
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), min_candidate_stk)
+    verify {
+        assert!(Pallet::<T>::candidate_info(&caller).unwrap().requests.is_empty());
+    }
+
+    signed_candidate_rebond {
+        let min_candidate_stk = min_candidate_stk::<T>();
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_candidate_rebond_params::<T>(relayer, &min_candidate_stk, nonce))?;
+        fund_account::<T>(&caller, min_candidate_stk * 2u32.into());
+        set_account_as_collator::<T>(&caller, min_candidate_stk, get_collator_count::<T>())?;
+        Pallet::<T>::schedule_candidate_unbond(
+            RawOrigin::Signed(caller.clone()).into(),
+            min_candidate_stk
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), proof, min_candidate_stk)
+    verify {
+        assert!(Pallet::<T>::candidate_info(&caller).unwrap().requests.is_empty());
+    }
+
+    nominator_rebond {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            get_collator_count::<T>()
+        )?;
+        let (caller, total) = create_funded_user::<T>("caller", USER_SEED, 0u32.into());
+        Pallet::<T>::nominate(RawOrigin::Signed(
+            caller.clone()).into(),
+            collator.clone(),
+            total,
+            0u32,
+            0u32
+        )?;
+        let bond_less = <MinTotalNominatorStake<T>>::get();
+        Pallet::<T>::schedule_nominator_unbond(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            bond_less
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), collator.clone(), bond_less)
+    verify {
+        assert!(!Pallet::<T>::nomination_request_exists(&collator, &caller));
+        assert!(Pallet::<T>::nominator_state(&caller).unwrap().less_total.is_zero());
+    }
+
+    signed_nominator_rebond {
+        let num_collators = get_collator_count::<T>() + 1;
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            num_collators
+        )?;
+
+        let bond_less = <MinTotalNominatorStake<T>>::get();
+        let (caller, proof) = get_caller::<T, _>(|relayer, nonce| encode_signed_nominator_rebond_params::<T>(relayer, &bond_less, nonce))?;
+        fund_account::<T>(&caller, bond_less * (num_collators * 3u32).into());
+
+        Pallet::<T>::nominate(RawOrigin::Signed(
+            caller.clone()).into(),
+            collator.clone(),
+            bond_less * num_collators.into() * 2u32.into(),
+            0u32,
+            0u32
+        )?;
+        Pallet::<T>::schedule_nominator_unbond(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            bond_less
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), proof, bond_less)
+    verify {
+        assert!(!Pallet::<T>::nomination_request_exists(&collator, &caller));
+        assert!(Pallet::<T>::nominator_state(&caller).unwrap().less_total.is_zero());
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +121,49 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    pub fn call_candidate_rebond(
+        collator: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let mut state = <CandidateInfo<T>>::get(collator).ok_or(Error::<T>::CandidateDNE)?;
+        let remaining_unbond = state.rebond::<T>(amount)?;
+        <CandidateInfo<T>>::insert(collator, state);
+
+        Self::deposit_event(Event::CandidateRebonded {
+            candidate: collator.clone(),
+            amount,
+            remaining_unbond,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn call_set_auto_compound(
+        account: &T::AccountId,
+        candidate: T::AccountId,
+        value: Percent,
+    ) -> DispatchResultWithPostInfo {
+        if *account == candidate {
+            ensure!(Self::is_candidate(account), Error::<T>::CandidateDNE);
+        } else {
+            let state = <NominatorState<T>>::get(account).ok_or(Error::<T>::NominatorDNE)?;
+            ensure!(
+                state.nominations.0.iter().any(|nomination| nomination.owner == candidate),
+                Error::<T>::NominationDNE
+            );
+        }
+
+        if value.is_zero() {
+            <AutoCompound<T>>::remove(&candidate, account);
+        } else {
+            <AutoCompound<T>>::insert(&candidate, account, value);
+        }
+
+        Self::deposit_event(Event::AutoCompoundSet { candidate, account: account.clone(), value });
+
+        Ok(().into())
+    }
+
     pub fn call_execute_candidate_unbond(candidate: &T::AccountId) -> DispatchResultWithPostInfo {
         let mut state = <CandidateInfo<T>>::get(candidate).ok_or(Error::<T>::CandidateDNE)?;
         state.execute_unbond::<T>(candidate.clone())?;
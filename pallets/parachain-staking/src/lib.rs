@@ -19,13 +19,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod calls;
+pub mod migration;
 mod nomination_requests;
+pub mod performance;
+pub mod pools;
 pub mod proxy_methods;
 pub mod session_handler;
 mod set;
+pub mod slashing;
+pub mod staking_info;
 pub mod types;
 pub mod weights;
 
+#[cfg(test)]
+#[path = "tests/auto_compound_tests.rs"]
+mod auto_compound_tests;
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 mod benchmarks;
 #[cfg(test)]
@@ -50,12 +58,30 @@ mod test_admin_settings;
 #[path = "tests/test_bounded_ordered_set.rs"]
 mod test_bounded_ordered_set;
 #[cfg(test)]
+#[path = "tests/test_commission.rs"]
+mod test_commission;
+#[cfg(test)]
 #[path = "tests/test_growth.rs"]
 mod test_growth;
 #[cfg(test)]
+#[path = "tests/test_performance.rs"]
+mod test_performance;
+#[cfg(test)]
+#[path = "tests/test_pools.rs"]
+mod test_pools;
+#[cfg(test)]
+#[path = "tests/test_rebond.rs"]
+mod test_rebond;
+#[cfg(test)]
 #[path = "tests/test_reward_payout.rs"]
 mod test_reward_payout;
 #[cfg(test)]
+#[path = "tests/test_slashing.rs"]
+mod test_slashing;
+#[cfg(test)]
+#[path = "tests/test_staking_info.rs"]
+mod test_staking_info;
+#[cfg(test)]
 #[path = "tests/test_staking_pot.rs"]
 mod test_staking_pot;
 #[cfg(test)]
@@ -99,9 +125,11 @@ pub mod pallet {
     pub use frame_support::{
         dispatch::{GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
+        storage::with_storage_layer,
         traits::{
             tokens::WithdrawReasons, Currency, ExistenceRequirement, Get, Imbalance, IsSubType,
-            LockIdentifier, LockableCurrency, ReservableCurrency, ValidatorRegistration,
+            LockIdentifier, LockableCurrency, OnUnbalanced, ReservableCurrency,
+            ValidatorRegistration,
         },
         transactional, PalletId,
     };
@@ -122,12 +150,12 @@ pub mod pallet {
     pub use sp_runtime::{
         traits::{
             AccountIdConversion, Bounded, CheckedAdd, CheckedDiv, CheckedSub, Dispatchable,
-            IdentifyAccount, Member, Saturating, StaticLookup, Verify, Zero,
+            IdentifyAccount, Member, SaturatedConversion, Saturating, StaticLookup, Verify, Zero,
         },
-        Perbill,
+        Perbill, Percent,
     };
     pub use sp_std::{collections::btree_map::BTreeMap, prelude::*};
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
     use sp_avn_common::eth::EthereumId;
 
     /// Pallet for parachain staking
@@ -143,6 +171,9 @@ pub mod pallet {
     pub type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
         <T as frame_system::Config>::AccountId,
     >>::PositiveImbalance;
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
 
     pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
     pub const NOMINATOR_LOCK_ID: LockIdentifier = *b"stkngnom";
@@ -223,6 +254,27 @@ pub mod pallet {
 
         #[pallet::constant]
         type GrowthEnabled: Get<bool>;
+
+        /// Maximum commission a candidate can take from its rewards
+        #[pallet::constant]
+        type MaxCommission: Get<Perbill>;
+        /// Number of eras to wait before a commission increase takes effect
+        #[pallet::constant]
+        type CommissionIncreaseDelay: Get<EraIndex>;
+        /// Handler for the funds slashed from collators and their nominators
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        /// Number of eras a reported slash waits before it is applied. A value of zero applies
+        /// slashes as soon as they are reported.
+        #[pallet::constant]
+        type SlashDeferDuration: Get<EraIndex>;
+        /// Whether the nominations of a slashed collator are slashed by the same fraction
+        #[pallet::constant]
+        type SlashNominators: Get<bool>;
+        /// Origin that can cancel a slash before it is applied
+        type SlashCancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Maximum number of unbonding requests a pool member can have at the same time
+        #[pallet::constant]
+        type MaxPoolUnbondingChunks: Get<u32>;
     }
 
     #[pallet::error]
@@ -281,6 +333,7 @@ pub mod pallet {
         UnauthorizedSignedExecuteLeaveNominatorsTransaction,
         UnauthorizedSignedExecuteNominationRequestTransaction,
         UnauthorizedSignedExecuteCandidateUnbondTransaction,
+        UnauthorizedSignedSetAutoCompoundTransaction,
         AdminSettingsValueIsNotValid,
         CandidateSessionKeysNotFound,
         FailedToWithdrawFullAmount,
@@ -290,6 +343,25 @@ pub mod pallet {
         Overflow,
         ErrorPublishingGrowth,
         StakingNotAllowed,
+        CommissionTooHigh,
+        UnappliedSlashDNE,
+        PoolDNE,
+        PoolMemberDNE,
+        NotPoolOperator,
+        PoolCollatorsEmpty,
+        TooManyPoolCollators,
+        PoolAmountZero,
+        InsufficientPoolShares,
+        NoPoolCollatorToBond,
+        NoPoolNominationToUnbond,
+        TooManyPoolUnbondingChunks,
+        NothingToWithdraw,
+        TooManyUnbondRequests,
+        RebondAmountZero,
+        RebondAmountExceedsPendingUnbond,
+        CannotRebondRevocation,
+        UnauthorizedSignedCandidateRebondTransaction,
+        UnauthorizedSignedRebondTransaction,
     }
 
     #[pallet::event]
@@ -443,6 +515,83 @@ pub mod pallet {
         AdminSettingsUpdated { value: AdminSettings<BalanceOf<T>> },
         /// Starting a new growth trigger for the specified period.
         TriggeringGrowth { growth_period: u32 },
+        /// Candidate has set a new commission.
+        CommissionSet { candidate: T::AccountId, commission: Perbill },
+        /// Candidate has scheduled a commission increase.
+        CommissionIncreaseScheduled {
+            candidate: T::AccountId,
+            commission: Perbill,
+            execute_era: EraIndex,
+        },
+        /// Collator has taken a commission from its era reward before it was split with its
+        /// nominators.
+        CommissionPaid { account: T::AccountId, era: EraIndex, amount: BalanceOf<T> },
+        /// The auto-compound preference of an account for a candidate has been set.
+        AutoCompoundSet { candidate: T::AccountId, account: T::AccountId, value: Percent },
+        /// Part of the staking reward of the account has been bonded back to the candidate.
+        Compounded { candidate: T::AccountId, account: T::AccountId, amount: BalanceOf<T> },
+        /// There was an error attempting to bond back part of the staking reward of the account.
+        ErrorCompoundingStakingReward {
+            candidate: T::AccountId,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// An offence has been reported for a collator. Its stake is slashed by `fraction` at
+        /// the start of `apply_era`.
+        SlashReported { collator: T::AccountId, fraction: Perbill, apply_era: EraIndex },
+        /// A slash that has not been applied yet has been cancelled.
+        SlashCancelled { collator: T::AccountId, apply_era: EraIndex },
+        /// The stake of the account has been slashed and the funds sent to the slash handler.
+        Slashed { account: T::AccountId, amount: BalanceOf<T> },
+        /// A nomination pool has been created.
+        PoolCreated { pool_id: PoolId, operator: T::AccountId, pool_account: T::AccountId },
+        /// The operator has changed the collators new pool stake is bonded to.
+        PoolCollatorsSet { pool_id: PoolId, collators: Vec<T::AccountId> },
+        /// An account has bonded funds to a nomination pool.
+        PoolBonded {
+            pool_id: PoolId,
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+            shares: BalanceOf<T>,
+        },
+        /// A pool member has unbonded funds, which can be withdrawn from `unlock_era`.
+        PoolUnbonded {
+            pool_id: PoolId,
+            member: T::AccountId,
+            amount: BalanceOf<T>,
+            shares: BalanceOf<T>,
+            unlock_era: EraIndex,
+        },
+        /// A pool member has withdrawn unbonded funds.
+        PoolWithdrawn { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
+        /// A pool member has been paid its share of the pool staking rewards.
+        PoolRewardPaid { pool_id: PoolId, member: T::AccountId, amount: BalanceOf<T> },
+        /// A collator missed some of the slots it was expected to fill in the era, and its
+        /// reward points have been scaled down.
+        CollatorMissedSlots {
+            era: EraIndex,
+            collator: T::AccountId,
+            expected_slots: u32,
+            missed_slots: u32,
+            points_removed: RewardPoint,
+        },
+        /// A candidate has been passed over in the selection for the era because it missed more
+        /// slots than `MaxMissedSlotRate` allows in the previous era.
+        CandidateDeprioritised { era: EraIndex, candidate: T::AccountId, missed_slot_rate: Perbill },
+        /// Candidate has re-staked part of the self bond decrease it scheduled.
+        CandidateRebonded {
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            remaining_unbond: BalanceOf<T>,
+        },
+        /// Nominator has re-staked part of the bond decrease it scheduled for the collator
+        /// candidate.
+        NominationRebonded {
+            nominator: T::AccountId,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            remaining_unbond: BalanceOf<T>,
+        },
     }
 
     #[pallet::hooks]
@@ -462,9 +611,9 @@ pub mod pallet {
 
             // add on_finalize weight
             weight = weight.saturating_add(
-                // read Author, Points, AwardedPts
-                // write Points, AwardedPts
-                T::DbWeight::get().reads(3).saturating_add(T::DbWeight::get().writes(2)),
+                // read Author, Points, AwardedPts, AuthoredBlocks
+                // write Points, AwardedPts, AuthoredBlocks
+                T::DbWeight::get().reads(4).saturating_add(T::DbWeight::get().writes(3)),
             );
             weight
         }
@@ -502,14 +651,16 @@ pub mod pallet {
     pub type CandidateInfo<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, CandidateMetadata<BalanceOf<T>>, OptionQuery>;
 
-    /// Stores outstanding nomination requests per collator.
+    /// Stores outstanding nomination requests per collator and nominator.
     #[pallet::storage]
     #[pallet::getter(fn nomination_scheduled_requests)]
-    pub(crate) type NominationScheduledRequests<T: Config> = StorageMap<
+    pub(crate) type NominationScheduledRequests<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
-        T::AccountId,
-        BoundedVec<ScheduledRequest<T::AccountId, BalanceOf<T>>, T::MaxNominationsPerNominator>,
+        T::AccountId, // collator
+        Blake2_128Concat,
+        T::AccountId, // nominator
+        BoundedVec<ScheduledRequest<T::AccountId, BalanceOf<T>>, MaxUnbondRequests>,
         ValueQuery,
     >;
 
@@ -568,6 +719,77 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn auto_compound)]
+    /// Percentage of the staking rewards an account earns with a candidate that is bonded back to
+    /// that candidate when it is paid. When the account is the candidate itself, it applies to the
+    /// candidate self bond.
+    pub type AutoCompound<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        Percent,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn unapplied_slashes)]
+    /// Slashes reported for collators that have not been applied yet, keyed by the era in which
+    /// they are applied
+    pub type UnappliedSlashes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Twox64Concat,
+        T::AccountId,
+        UnappliedSlash<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_pool_id)]
+    /// The id of the next nomination pool to be created
+    pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pools)]
+    /// Nomination pools
+    pub type Pools<T: Config> = StorageMap<_, Twox64Concat, PoolId, PoolInfo<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool_accounts)]
+    /// The pool of each pool account
+    pub type PoolAccounts<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, PoolId, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool_unbonding)]
+    /// The funds each pool is unbonding, by collator and unlock era
+    pub type PoolUnbonding<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Twox64Concat,
+        (T::AccountId, EraIndex),
+        PoolUnbondingBucket<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pool_members)]
+    /// The members of each nomination pool
+    pub type PoolMembers<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PoolId,
+        Twox64Concat,
+        T::AccountId,
+        PoolMember<T>,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn delayed_payouts)]
     /// Delayed payouts
@@ -597,6 +819,24 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn authored_blocks)]
+    /// Blocks authored by each collator in the current era
+    pub type AuthoredBlocks<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn collator_performance)]
+    /// Block production of the collators selected in the last ended era
+    pub type CollatorPerformance<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, SlotPerformance, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn max_missed_slot_rate)]
+    /// The share of its expected slots a collator can miss in an era before it is deprioritised
+    /// in the selection of the next era. Collator performance is not taken into account if unset.
+    pub type MaxMissedSlotRate<T: Config> = StorageValue<_, Perbill, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn locked_era_payout)]
     /// Total amount of payouts we are waiting to take out of this pallet's pot.
@@ -932,11 +1172,17 @@ pub mod pallet {
                 );
 
                 if let Some(remaining) = nominator.rm_nomination::<T>(&candidate) {
+                    let less_total = nominator.less_total;
                     Self::nomination_remove_request_with_state(
                         &candidate,
                         &bond.owner,
                         &mut nominator,
                     );
+                    Self::record_returned_pool_stake(
+                        &bond.owner,
+                        bond.amount,
+                        less_total.saturating_sub(nominator.less_total),
+                    );
 
                     if remaining.is_zero() {
                         // we do not remove the scheduled nomination requests from other collators
@@ -973,7 +1219,8 @@ pub mod pallet {
             // return stake to collator
             T::Currency::remove_lock(COLLATOR_LOCK_ID, &candidate);
             <CandidateInfo<T>>::remove(&candidate);
-            <NominationScheduledRequests<T>>::remove(&candidate);
+            let _ = <NominationScheduledRequests<T>>::clear_prefix(&candidate, u32::MAX, None);
+            let _ = <AutoCompound<T>>::clear_prefix(&candidate, u32::MAX, None);
             <TopNominations<T>>::remove(&candidate);
             <BottomNominations<T>>::remove(&candidate);
             let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
@@ -1043,6 +1290,7 @@ pub mod pallet {
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(!state.is_active(), Error::<T>::AlreadyActive);
             ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
+            ensure!(state.bond >= <MinCollatorStake<T>>::get(), Error::<T>::CandidateBondBelowMin);
             state.go_online();
             let mut candidates = <CandidatePool<T>>::get();
             let maybe_inserted_candidate = candidates
@@ -1589,14 +1837,10 @@ pub mod pallet {
             let state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
             for bond in state.nominations.0 {
                 let collator = bond.owner;
-                let scheduled_requests = &<NominationScheduledRequests<T>>::get(&collator);
-
-                let request_idx = scheduled_requests
-                    .iter()
-                    .position(|req| req.nominator == nominator)
-                    .ok_or(<Error<T>>::PendingNominationRequestDNE)?;
+                let requests = <NominationScheduledRequests<T>>::get(&collator, &nominator);
+                ensure!(!requests.is_empty(), <Error<T>>::PendingNominationRequestDNE);
 
-                if scheduled_requests[request_idx].when_executable <= now {
+                if requests.iter().any(|req| req.when_executable <= now) {
                     Self::nomination_execute_scheduled_request(collator, nominator.clone())?;
                 }
             }
@@ -1634,13 +1878,14 @@ pub mod pallet {
                     <Error<T>>::CandidateNotLeaving
                 );
                 ensure!(
-                    <NominationScheduledRequests<T>>::get(&candidate).is_empty(),
+                    <NominationScheduledRequests<T>>::iter_prefix_values(&candidate)
+                        .all(|requests| requests.is_empty()),
                     <Error<T>>::CandidateNotLeaving
                 );
             }
 
             for candidate in candidates {
-                <NominationScheduledRequests<T>>::remove(candidate);
+                let _ = <NominationScheduledRequests<T>>::clear_prefix(candidate, u32::MAX, None);
             }
 
             Ok(().into())
@@ -1659,12 +1904,307 @@ pub mod pallet {
                 AdminSettings::Delay(d) => <Delay<T>>::put(d),
                 AdminSettings::MinCollatorStake(s) => <MinCollatorStake<T>>::put(s),
                 AdminSettings::MinTotalNominatorStake(s) => <MinTotalNominatorStake<T>>::put(s),
+                AdminSettings::MaxMissedSlotRate(r) => <MaxMissedSlotRate<T>>::put(r),
             }
 
             Self::deposit_event(Event::AdminSettingsUpdated { value });
 
             Ok(())
         }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_commission())]
+        /// Set the share of the rewards taken by the collator candidate before they are split
+        /// with its nominators
+        /// - decreases apply immediately
+        /// - increases apply after `CommissionIncreaseDelay` eras
+        #[pallet::call_index(33)]
+        pub fn set_commission(
+            origin: OriginFor<T>,
+            commission: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+            let execute_era = state.set_commission::<T>(commission)?;
+            <CandidateInfo<T>>::insert(&collator, state);
+
+            if execute_era > <Era<T>>::get().current {
+                Self::deposit_event(Event::CommissionIncreaseScheduled {
+                    candidate: collator,
+                    commission,
+                    execute_era,
+                });
+            } else {
+                Self::deposit_event(Event::CommissionSet { candidate: collator, commission });
+            }
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+        /// Set the percentage of the staking rewards earned with `candidate` that is bonded back
+        /// to it when they are paid
+        /// - nominators set the preference for their nomination to `candidate`
+        /// - candidates set the preference for their self bond by passing their own account
+        #[pallet::call_index(34)]
+        pub fn set_auto_compound(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            value: Percent,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            return Self::call_set_auto_compound(&account, candidate, value)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::signed_set_auto_compound())]
+        #[transactional]
+        /// Signed request to set the percentage of the staking rewards earned with `candidate`
+        /// that is bonded back to it when they are paid
+        #[pallet::call_index(35)]
+        pub fn signed_set_auto_compound(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            candidate: T::AccountId,
+            value: Percent,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            ensure!(account == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let sender_nonce = Self::proxy_nonce(&account);
+            let signed_payload = encode_signed_set_auto_compound_params::<T>(
+                proof.relayer.clone(),
+                &candidate,
+                &value,
+                sender_nonce,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedSetAutoCompoundTransaction
+            );
+
+            Self::call_set_auto_compound(&account, candidate, value)?;
+
+            <ProxyNonces<T>>::mutate(&account, |n| *n += 1);
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_deferred_slash())]
+        /// Cancel a slash that has been reported for `collator` but not applied yet
+        /// - `apply_era` is the era in which the slash is due to be applied
+        #[pallet::call_index(36)]
+        pub fn cancel_deferred_slash(
+            origin: OriginFor<T>,
+            apply_era: EraIndex,
+            collator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::SlashCancelOrigin::ensure_origin(origin)?;
+            ensure!(
+                <UnappliedSlashes<T>>::contains_key(apply_era, &collator),
+                Error::<T>::UnappliedSlashDNE
+            );
+
+            <UnappliedSlashes<T>>::remove(apply_era, &collator);
+            Self::deposit_event(Event::SlashCancelled { collator, apply_era });
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+        /// Create a nomination pool operated by the caller, which becomes its first member
+        /// - `collators` are the collators the pool stake is bonded to
+        /// - `amount` is bonded to the first collator and must cover the minimum nominator stake
+        #[pallet::call_index(37)]
+        pub fn create_pool(
+            origin: OriginFor<T>,
+            collators: Vec<T::AccountId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let operator = ensure_signed(origin)?;
+            ensure!(is_staking_enabled(), Error::<T>::StakingNotAllowed);
+            Self::do_create_pool(operator, collators, amount)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::set_pool_collators())]
+        /// Set the collators new stake of the pool is bonded to. Existing nominations to
+        /// collators that are no longer in the set are unbonded first when members leave.
+        #[pallet::call_index(38)]
+        pub fn set_pool_collators(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            collators: Vec<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let operator = ensure_signed(origin)?;
+            Self::do_set_pool_collators(&operator, pool_id, collators)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::join_pool())]
+        /// Bond `amount` to a nomination pool in exchange for pool shares. Any amount can be
+        /// bonded once the pool exists.
+        #[pallet::call_index(39)]
+        pub fn join_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            ensure!(is_staking_enabled(), Error::<T>::StakingNotAllowed);
+            Self::do_join_pool(&member, pool_id, amount)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::unbond_from_pool())]
+        /// Unbond `amount` from a nomination pool. The pool schedules a nomination request for
+        /// the amount, and the funds can be withdrawn once that request can be executed.
+        #[pallet::call_index(40)]
+        pub fn unbond_from_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            Self::do_unbond_from_pool(&member, pool_id, amount)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_from_pool(
+            T::MaxPoolUnbondingChunks::get()
+        ))]
+        /// Withdraw the unbonded funds of the caller whose unlock era has been reached,
+        /// executing the pool nomination requests they depend on when needed
+        #[pallet::call_index(41)]
+        pub fn withdraw_from_pool(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            let withdrawn_chunks = Self::do_withdraw_from_pool(&member, pool_id)?;
+            Ok(Some(<T as Config>::WeightInfo::withdraw_from_pool(withdrawn_chunks)).into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::claim_pool_rewards())]
+        /// Pay the caller its share of the staking rewards earned by a nomination pool
+        #[pallet::call_index(42)]
+        pub fn claim_pool_rewards(
+            origin: OriginFor<T>,
+            pool_id: PoolId,
+        ) -> DispatchResultWithPostInfo {
+            let member = ensure_signed(origin)?;
+            Self::do_claim_pool_rewards(&member, pool_id)?;
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::candidate_rebond())]
+        /// Re-stake `amount` of the self bond decrease scheduled by the collator candidate. The
+        /// amount is taken from the requests that unlock last, the remainder stays scheduled.
+        #[pallet::call_index(43)]
+        pub fn candidate_rebond(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+            return Self::call_candidate_rebond(&collator, amount)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::signed_candidate_rebond())]
+        #[transactional]
+        /// Signed request by collator candidate to re-stake `amount` of its scheduled self bond
+        /// decrease
+        #[pallet::call_index(44)]
+        pub fn signed_candidate_rebond(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+
+            ensure!(collator == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let collator_nonce = Self::proxy_nonce(&collator);
+            let signed_payload = encode_signed_candidate_rebond_params::<T>(
+                proof.relayer.clone(),
+                &amount,
+                collator_nonce,
+            );
+
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedCandidateRebondTransaction
+            );
+
+            Self::call_candidate_rebond(&collator, amount)?;
+
+            <ProxyNonces<T>>::mutate(&collator, |n| *n += 1);
+
+            Ok(().into())
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::nominator_rebond())]
+        /// Re-stake `amount` of the bond decrease scheduled by the nominator wrt a specific
+        /// collator candidate. The amount is taken from the requests that unlock last, the
+        /// remainder stays scheduled.
+        #[pallet::call_index(45)]
+        pub fn nominator_rebond(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
+            Self::nomination_rebond(candidate, nominator, amount)
+        }
+
+        #[pallet::weight(<T as Config>::WeightInfo::signed_nominator_rebond())]
+        #[transactional]
+        /// Signed request by nominator to re-stake `amount` of the bond decreases it scheduled,
+        /// across the collator candidates it nominates
+        #[pallet::call_index(46)]
+        pub fn signed_nominator_rebond(
+            origin: OriginFor<T>,
+            proof: Proof<T::Signature, T::AccountId>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let nominator = ensure_signed(origin)?;
+
+            ensure!(nominator == proof.signer, Error::<T>::SenderIsNotSigner);
+
+            let nominator_nonce = Self::proxy_nonce(&nominator);
+            let signed_payload = encode_signed_nominator_rebond_params::<T>(
+                proof.relayer.clone(),
+                &amount,
+                nominator_nonce,
+            );
+            ensure!(
+                verify_signature::<T::Signature, T::AccountId>(&proof, &signed_payload.as_slice())
+                    .is_ok(),
+                Error::<T>::UnauthorizedSignedRebondTransaction
+            );
+            ensure!(!amount.is_zero(), Error::<T>::RebondAmountZero);
+
+            let state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
+            let mut outstanding_rebond = amount;
+            for bond in state.nominations.0 {
+                if outstanding_rebond.is_zero() {
+                    break
+                }
+
+                let pending_decrease = Self::nomination_pending_decrease(&bond.owner, &nominator);
+                let rebond = pending_decrease.min(outstanding_rebond);
+                if !rebond.is_zero() {
+                    Self::nomination_rebond(bond.owner, nominator.clone(), rebond)?;
+                    outstanding_rebond -= rebond;
+                }
+            }
+
+            // Make sure we have rebonded the full amount requested by the user
+            ensure!(outstanding_rebond.is_zero(), Error::<T>::RebondAmountExceedsPendingUnbond);
+
+            <ProxyNonces<T>>::mutate(&nominator, |n| *n += 1);
+
+            Ok(().into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -1672,9 +2212,18 @@ pub mod pallet {
             block_number: BlockNumberFor<T>,
             mut era: EraInfo<BlockNumberFor<T>>,
         ) -> (EraInfo<BlockNumberFor<T>>, Weight) {
+            // score the block production of the ending era before its points are paid out
+            let performance_weight = Self::record_collator_performance(
+                era.current,
+                block_number.saturating_sub(era.first).saturated_into(),
+            );
+
             // mutate era
             era.update(block_number);
 
+            // apply the slashes due this era before the stake is snapshotted
+            let slashing_weight = Self::apply_unapplied_slashes(era.current);
+
             if is_staking_enabled() {
                 // pay all stakers for T::RewardPaymentDelay eras ago
                 Self::prepare_staking_payouts(era.current);
@@ -1699,7 +2248,9 @@ pub mod pallet {
             let weight = <T as Config>::WeightInfo::era_transition_on_initialize(
                 collator_count,
                 nomination_count,
-            );
+            )
+            .saturating_add(slashing_weight)
+            .saturating_add(performance_weight);
             return (era, weight)
         }
 
@@ -1785,6 +2336,7 @@ pub mod pallet {
             <Total<T>>::put(new_total_locked);
             let new_total = state.total_counted;
             <CandidateInfo<T>>::insert(&candidate, state);
+            <AutoCompound<T>>::remove(&candidate, &nominator);
             Self::deposit_event(Event::NominatorLeftCandidate {
                 nominator,
                 candidate,
@@ -1884,7 +2436,7 @@ pub mod pallet {
             }
 
             let reward_pot_account_id = Self::compute_reward_pot_account_id();
-            let pay_reward = |amount: BalanceOf<T>, to: T::AccountId| {
+            let pay_reward = |amount: BalanceOf<T>, to: T::AccountId| -> bool {
                 let result = T::Currency::transfer(
                     &reward_pot_account_id,
                     &to,
@@ -1898,13 +2450,15 @@ pub mod pallet {
                     <LockedEraPayout<T>>::mutate(|p| {
                         *p = p.saturating_sub(amount.into());
                     });
-                } else {
-                    log::error!("💔 Error paying staking reward: {:?}", result);
-                    Self::deposit_event(Event::ErrorPayingStakingReward {
-                        payee: to.clone(),
-                        rewards: amount,
-                    });
+                    return true
                 }
+
+                log::error!("💔 Error paying staking reward: {:?}", result);
+                Self::deposit_event(Event::ErrorPayingStakingReward {
+                    payee: to.clone(),
+                    rewards: amount,
+                });
+                return false
             };
 
             if let Some((collator, pts)) = <AwardedPts<T>>::iter_prefix(paid_for_era).drain().next()
@@ -1916,23 +2470,47 @@ pub mod pallet {
                 let state = <AtStake<T>>::take(paid_for_era, &collator);
                 let num_nominators = state.nominations.len();
 
+                // the commission is taken before the reward is split pro-rata
+                let commission_reward = state.commission * total_reward_for_collator;
+                let reward_to_split = total_reward_for_collator.saturating_sub(commission_reward);
+                if !commission_reward.is_zero() {
+                    Self::deposit_event(Event::CommissionPaid {
+                        account: collator.clone(),
+                        era: paid_for_era,
+                        amount: commission_reward,
+                    });
+                }
+
                 // pay collator's due portion first
                 let collator_pct = Perbill::from_rational(state.bond, state.total);
-                let collator_reward = collator_pct * total_reward_for_collator;
-                pay_reward(collator_reward, collator.clone());
+                let collator_reward =
+                    commission_reward.saturating_add(collator_pct * reward_to_split);
+                let mut compound_weight = Weight::zero();
+                if pay_reward(collator_reward, collator.clone()) {
+                    compound_weight = compound_weight.saturating_add(Self::compound_reward(
+                        &collator,
+                        &collator,
+                        collator_reward,
+                    ));
+                }
 
                 // pay nominators due portion, if there are any
                 for Bond { owner, amount } in state.nominations {
                     let percent = Perbill::from_rational(amount, state.total);
-                    let nominator_reward = percent * total_reward_for_collator;
-                    if !nominator_reward.is_zero() {
-                        pay_reward(nominator_reward, owner.clone());
+                    let nominator_reward = percent * reward_to_split;
+                    if !nominator_reward.is_zero() && pay_reward(nominator_reward, owner.clone()) {
+                        compound_weight = compound_weight.saturating_add(Self::compound_reward(
+                            &collator,
+                            &owner,
+                            nominator_reward,
+                        ));
                     }
                 }
 
                 (
                     Some((collator, total_reward_for_collator)),
-                    <T as Config>::WeightInfo::pay_one_collator_reward(num_nominators as u32),
+                    <T as Config>::WeightInfo::pay_one_collator_reward(num_nominators as u32)
+                        .saturating_add(compound_weight),
                 )
             } else {
                 // Note that we don't clean up storage here; it is cleaned up in
@@ -1941,12 +2519,60 @@ pub mod pallet {
             }
         }
 
+        /// Bond back the share of `reward` that `account` has chosen to auto-compound with
+        /// `collator`. A failure is reported but does not stop the payout.
+        ///
+        /// Returns the weight consumed
+        fn compound_reward(
+            collator: &T::AccountId,
+            account: &T::AccountId,
+            reward: BalanceOf<T>,
+        ) -> Weight {
+            let amount = <AutoCompound<T>>::get(collator, account) * reward;
+            if amount.is_zero() {
+                return T::DbWeight::get().reads(1)
+            }
+
+            let (result, weight) = if account == collator {
+                (
+                    with_storage_layer(|| Self::call_candidate_bond_extra(collator, amount)),
+                    <T as Config>::WeightInfo::candidate_bond_extra(),
+                )
+            } else {
+                (
+                    with_storage_layer(|| Self::call_bond_extra(account, collator.clone(), amount)),
+                    <T as Config>::WeightInfo::bond_extra(),
+                )
+            };
+
+            match result {
+                Ok(_) => Self::deposit_event(Event::Compounded {
+                    candidate: collator.clone(),
+                    account: account.clone(),
+                    amount,
+                }),
+                Err(e) => {
+                    log::error!("💔 Error compounding staking reward: {:?}", e);
+                    Self::deposit_event(Event::ErrorCompoundingStakingReward {
+                        candidate: collator.clone(),
+                        account: account.clone(),
+                        amount,
+                    });
+                },
+            }
+
+            return weight.saturating_add(T::DbWeight::get().reads(1))
+        }
+
         /// Compute the top `TotalSelected` candidates in the CandidatePool and return
-        /// a vec of their AccountIds (in the order of selection)
+        /// a vec of their AccountIds (in the order of selection). Candidates that missed too many
+        /// slots in the last era are only selected if there are not enough other candidates.
         pub fn compute_top_candidates() -> Vec<T::AccountId> {
             let mut candidates = <CandidatePool<T>>::get().0;
-            // order candidates by stake (least to greatest so requires `rev()`)
-            candidates.sort_by(|a, b| a.amount.cmp(&b.amount));
+            // order candidates by performance then stake (least to greatest so requires `rev()`)
+            candidates.sort_by_cached_key(|c| {
+                (Self::excessive_missed_slot_rate(&c.owner).is_none(), c.amount)
+            });
             let top_n = <TotalSelected<T>>::get() as usize;
             // choose the top TotalSelected qualified candidates, ordered by stake
             let mut collators = candidates
@@ -2011,6 +2637,7 @@ pub mod pallet {
                     bond: state.bond,
                     nominations: rewardable_nominations,
                     total: total_counted,
+                    commission: state.effective_commission(now),
                 };
                 <AtStake<T>>::insert(now, account, snapshot);
                 Self::deposit_event(Event::CollatorChosen {
@@ -2019,6 +2646,18 @@ pub mod pallet {
                     total_exposed_amount: state.total_counted,
                 });
             }
+            for candidate in <CandidatePool<T>>::get().0 {
+                if collators.binary_search(&candidate.owner).is_ok() {
+                    continue
+                }
+                if let Some(missed_slot_rate) = Self::excessive_missed_slot_rate(&candidate.owner) {
+                    Self::deposit_event(Event::CandidateDeprioritised {
+                        era: now,
+                        candidate: candidate.owner,
+                        missed_slot_rate,
+                    });
+                }
+            }
             // insert canonical collator set
             <SelectedCandidates<T>>::put(
                 BoundedVec::try_from(collators)
@@ -2037,10 +2676,21 @@ pub mod pallet {
         ///
         /// The intended bond amounts will be used while calculating rewards.
         fn get_rewardable_nominators(collator: &T::AccountId) -> CountedNominations<T> {
-            let requests = <NominationScheduledRequests<T>>::get(collator)
-                .into_iter()
-                .map(|x| (x.nominator, x.action))
-                .collect::<BTreeMap<_, _>>();
+            // the decreases a nominator has scheduled towards the collator are counted together
+            let requests: BTreeMap<_, _> = <NominationScheduledRequests<T>>::iter_prefix(collator)
+                .filter_map(|(nominator, requests)| {
+                    let action = requests.into_iter().map(|request| request.action).reduce(
+                        |pending, action| match (pending, action) {
+                            (
+                                NominationAction::Decrease(pending),
+                                NominationAction::Decrease(amount),
+                            ) => NominationAction::Decrease(pending.saturating_add(amount)),
+                            (_, action) => action,
+                        },
+                    )?;
+                    Some((nominator, action))
+                })
+                .collect();
             let mut uncounted_stake = BalanceOf::<T>::zero();
             let rewardable_nominations_vec = <TopNominations<T>>::get(collator)
                 .expect("all members of CandidateQ must be candidates")
@@ -2470,6 +3120,7 @@ pub mod pallet {
         fn note_author(author: T::AccountId) {
             let now = <Era<T>>::get().current;
             let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(20);
+            Self::note_authored_block(now, &author);
             <AwardedPts<T>>::insert(now, author, score_plus_20);
             <Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
 
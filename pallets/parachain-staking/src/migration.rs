@@ -0,0 +1,226 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AtStake, BTreeMap, BalanceOf, CandidateInfo, CandidateMetadata, CollatorSnapshot, Config,
+    NominationScheduledRequests, Pallet, STORAGE_VERSION,
+};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+    weights::Weight,
+    BoundedVec,
+};
+use sp_runtime::Perbill;
+
+use crate::Vec;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+mod v3 {
+    use crate::{Bond, CandidateBondLessRequest, CapacityStatus, CollatorStatus, MaxNominations};
+    use frame_support::BoundedVec;
+
+    /// V3 type for [`crate::CandidateMetadata`]. Candidates could not take a commission and could
+    /// only have one pending request to decrease their self bond.
+    #[derive(codec::Encode, codec::Decode)]
+    pub struct CandidateMetadata<Balance> {
+        pub bond: Balance,
+        pub nomination_count: u32,
+        pub total_counted: Balance,
+        pub lowest_top_nomination_amount: Balance,
+        pub highest_bottom_nomination_amount: Balance,
+        pub lowest_bottom_nomination_amount: Balance,
+        pub top_capacity: CapacityStatus,
+        pub bottom_capacity: CapacityStatus,
+        pub request: Option<CandidateBondLessRequest<Balance>>,
+        pub status: CollatorStatus,
+    }
+
+    /// V3 type for [`crate::CollatorSnapshot`]
+    #[derive(codec::Encode, codec::Decode)]
+    pub struct CollatorSnapshot<AccountId, Balance> {
+        pub bond: Balance,
+        pub nominations: BoundedVec<Bond<AccountId, Balance>, MaxNominations>,
+        pub total: Balance,
+    }
+}
+
+mod v5 {
+    use crate::{BalanceOf, Config, Pallet, ScheduledRequest, Vec};
+    use frame_support::{storage_alias, Blake2_128Concat};
+
+    /// V5 layout of [`crate::NominationScheduledRequests`]. The requests of every nominator were
+    /// stored together under the collator.
+    #[storage_alias]
+    pub type NominationScheduledRequests<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Vec<ScheduledRequest<<T as frame_system::Config>::AccountId, BalanceOf<T>>>,
+    >;
+}
+
+pub fn migrate_to_v5<T: Config>() -> Weight {
+    let mut consumed_weight: Weight = Weight::zero();
+    let mut add_weight = |reads, writes, weight: Weight| {
+        consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
+        consumed_weight += weight;
+    };
+
+    log::info!(
+        "🚧 🚧 Running migration to add a commission and several unbond requests to candidates"
+    );
+
+    <CandidateInfo<T>>::translate::<v3::CandidateMetadata<BalanceOf<T>>, _>(|_candidate, v3| {
+        add_weight(1, 1, Weight::zero());
+        Some(CandidateMetadata {
+            bond: v3.bond,
+            nomination_count: v3.nomination_count,
+            total_counted: v3.total_counted,
+            lowest_top_nomination_amount: v3.lowest_top_nomination_amount,
+            highest_bottom_nomination_amount: v3.highest_bottom_nomination_amount,
+            lowest_bottom_nomination_amount: v3.lowest_bottom_nomination_amount,
+            top_capacity: v3.top_capacity,
+            bottom_capacity: v3.bottom_capacity,
+            requests: BoundedVec::truncate_from(v3.request.into_iter().collect()),
+            status: v3.status,
+            commission: Perbill::zero(),
+            commission_request: None,
+        })
+    });
+
+    <AtStake<T>>::translate::<v3::CollatorSnapshot<T::AccountId, BalanceOf<T>>, _>(
+        |_era, _collator, v3| {
+            add_weight(1, 1, Weight::zero());
+            Some(CollatorSnapshot {
+                bond: v3.bond,
+                nominations: v3.nominations,
+                total: v3.total,
+                commission: Perbill::zero(),
+            })
+        },
+    );
+
+    add_weight(0, 1, Weight::zero());
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    log::info!("✅ Candidates and collator snapshots migrated successfully");
+
+    // add a bit extra as safety margin for computation
+    return consumed_weight + Weight::from_parts(25_000_000 as u64, 0)
+}
+
+pub fn migrate_to_v6<T: Config>() -> Weight {
+    let mut consumed_weight: Weight = Weight::zero();
+    let mut add_weight = |reads, writes, weight: Weight| {
+        consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
+        consumed_weight += weight;
+    };
+
+    log::info!("🚧 🚧 Running migration to key nomination requests by collator and nominator");
+
+    // The new keys extend the old ones, so every old entry is removed before writing the new ones
+    let old_requests: Vec<_> = v5::NominationScheduledRequests::<T>::drain().collect();
+    for (collator, requests) in old_requests {
+        add_weight(1, 1, Weight::zero());
+
+        let mut requests_by_nominator = BTreeMap::new();
+        for request in requests {
+            requests_by_nominator
+                .entry(request.nominator.clone())
+                .or_insert_with(Vec::new)
+                .push(request);
+        }
+
+        for (nominator, requests) in requests_by_nominator {
+            add_weight(0, 1, Weight::zero());
+            <NominationScheduledRequests<T>>::insert(
+                &collator,
+                nominator,
+                BoundedVec::truncate_from(requests),
+            );
+        }
+    }
+
+    add_weight(0, 1, Weight::zero());
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    log::info!("✅ Nomination requests migrated successfully");
+
+    // add a bit extra as safety margin for computation
+    return consumed_weight + Weight::from_parts(25_000_000 as u64, 0)
+}
+
+pub struct ParachainStakingMigrations<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for ParachainStakingMigrations<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::in_code_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        let mut total_weight = Weight::zero();
+
+        if onchain < 5 {
+            log::info!(
+                "💽 Running Parachain staking migration with current storage version {:?} / onchain {:?}",
+                current,
+                onchain
+            );
+            total_weight += migrate_to_v5::<T>();
+        }
+
+        if onchain < 6 {
+            total_weight += migrate_to_v6::<T>();
+        }
+
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        use codec::Encode;
+
+        let candidates = <CandidateInfo<T>>::iter_keys().count() as u64;
+        let requests: u64 = if Pallet::<T>::on_chain_storage_version() < 6 {
+            v5::NominationScheduledRequests::<T>::iter_values()
+                .map(|requests| requests.len() as u64)
+                .sum()
+        } else {
+            <NominationScheduledRequests<T>>::iter_values()
+                .map(|requests| requests.len() as u64)
+                .sum()
+        };
+
+        Ok((candidates, requests).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        use codec::Decode;
+
+        let (candidates_before, requests_before): (u64, u64) =
+            Decode::decode(&mut input.as_slice()).expect("Initial counts are invalid");
+        let candidates_after = <CandidateInfo<T>>::iter_values().count() as u64;
+        assert_eq!(candidates_before, candidates_after);
+        let requests_after: u64 = <NominationScheduledRequests<T>>::iter_values()
+            .map(|requests| requests.len() as u64)
+            .sum();
+        assert_eq!(requests_before, requests_after);
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+
+        Ok(())
+    }
+}
@@ -22,10 +22,16 @@ use crate::{
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Saturating, BoundedVec, RuntimeDebug};
-use sp_std::vec;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    BoundedVec, RuntimeDebug,
+};
+use sp_std::{vec, vec::Vec};
 
 /// An action that can be performed upon a nomination
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(
     Clone,
     Eq,
@@ -88,58 +94,57 @@ impl<T: Config> Pallet<T> {
         nominator: T::AccountId,
     ) -> DispatchResultWithPostInfo {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
-        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
+        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator, &nominator);
 
-        ensure!(
-            !scheduled_requests.iter().any(|req| req.nominator == nominator),
-            <Error<T>>::PendingNominationRequestAlreadyExists,
-        );
+        ensure!(scheduled_requests.is_empty(), <Error<T>>::PendingNominationRequestAlreadyExists);
 
         let bonded_amount = state.get_bond_amount(&collator).ok_or(<Error<T>>::NominationDNE)?;
         let now = <Era<T>>::get().current;
         let when = now.saturating_add(<Delay<T>>::get());
-        match scheduled_requests.try_push(ScheduledRequest {
-            nominator: nominator.clone(),
-            action: NominationAction::Revoke(bonded_amount),
-            when_executable: when,
-        }) {
-            Ok(()) => {
-                state.less_total = state.less_total.saturating_add(bonded_amount);
-                <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
-                <NominatorState<T>>::insert(nominator.clone(), state);
-
-                Self::deposit_event(Event::NominationRevocationScheduled {
-                    era: now,
-                    nominator,
-                    candidate: collator,
-                    scheduled_exit: when,
-                });
-            },
-            Err(_) => {
-                ();
-            },
-        }
+        scheduled_requests
+            .try_push(ScheduledRequest {
+                nominator: nominator.clone(),
+                action: NominationAction::Revoke(bonded_amount),
+                when_executable: when,
+            })
+            .map_err(|_| <Error<T>>::TooManyUnbondRequests)?;
+
+        state.less_total = state.less_total.saturating_add(bonded_amount);
+        <NominationScheduledRequests<T>>::insert(&collator, &nominator, scheduled_requests);
+        <NominatorState<T>>::insert(nominator.clone(), state);
+
+        Self::deposit_event(Event::NominationRevocationScheduled {
+            era: now,
+            nominator,
+            candidate: collator,
+            scheduled_exit: when,
+        });
 
         Ok(().into())
     }
 
     /// Schedules a [NominationAction::Decrease] for the nominator, towards a given collator.
+    /// A decrease executable in the same era is extended rather than adding a new request.
     pub(crate) fn nomination_schedule_bond_decrease(
         collator: T::AccountId,
         nominator: T::AccountId,
         decrease_amount: BalanceOf<T>,
     ) -> DispatchResultWithPostInfo {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
-        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
+        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator, &nominator);
 
         ensure!(
-            !scheduled_requests.iter().any(|req| req.nominator == nominator),
+            !scheduled_requests
+                .iter()
+                .any(|req| matches!(req.action, NominationAction::Revoke(_))),
             <Error<T>>::PendingNominationRequestAlreadyExists,
         );
 
         let bonded_amount = state.get_bond_amount(&collator).ok_or(<Error<T>>::NominationDNE)?;
-        ensure!(bonded_amount > decrease_amount, <Error<T>>::NominatorBondBelowMin);
-        let new_amount: BalanceOf<T> = (bonded_amount - decrease_amount).into();
+        let remaining_amount =
+            bonded_amount.saturating_sub(Self::pending_nomination_decrease(&scheduled_requests));
+        ensure!(remaining_amount > decrease_amount, <Error<T>>::NominatorBondBelowMin);
+        let new_amount: BalanceOf<T> = (remaining_amount - decrease_amount).into();
         ensure!(new_amount >= T::MinNominationPerCollator::get(), <Error<T>>::NominationBelowMin);
 
         // Net Total is total after pending orders are executed
@@ -151,84 +156,239 @@ impl<T: Config> Pallet<T> {
 
         let now = <Era<T>>::get().current;
         let when = now.saturating_add(<Delay<T>>::get());
-        match scheduled_requests.try_push(ScheduledRequest {
-            nominator: nominator.clone(),
-            action: NominationAction::Decrease(decrease_amount),
-            when_executable: when,
-        }) {
-            Ok(()) => {
-                state.less_total = state.less_total.saturating_add(decrease_amount);
-                <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
-                <NominatorState<T>>::insert(nominator.clone(), state);
-
-                Self::deposit_event(Event::NominationDecreaseScheduled {
-                    nominator,
-                    candidate: collator,
-                    amount_to_decrease: decrease_amount,
-                    execute_era: when,
-                });
-            },
-            Err(_) => (),
+        let same_era_request =
+            scheduled_requests.iter_mut().find(|req| req.when_executable == when);
+        match same_era_request {
+            Some(request) =>
+                request.action = NominationAction::Decrease(
+                    request.action.amount().saturating_add(decrease_amount),
+                ),
+            None => scheduled_requests
+                .try_push(ScheduledRequest {
+                    nominator: nominator.clone(),
+                    action: NominationAction::Decrease(decrease_amount),
+                    when_executable: when,
+                })
+                .map_err(|_| <Error<T>>::TooManyUnbondRequests)?,
         }
+
+        state.less_total = state.less_total.saturating_add(decrease_amount);
+        <NominationScheduledRequests<T>>::insert(&collator, &nominator, scheduled_requests);
+        <NominatorState<T>>::insert(nominator.clone(), state);
+
+        Self::deposit_event(Event::NominationDecreaseScheduled {
+            nominator,
+            candidate: collator,
+            amount_to_decrease: decrease_amount,
+            execute_era: when,
+        });
         Ok(().into())
     }
 
-    /// Cancels the nominator's existing [ScheduledRequest] towards a given collator.
-    pub(crate) fn nomination_cancel_request(
+    /// Re-stakes `amount` of the nominator's scheduled [NominationAction::Decrease] requests
+    /// towards a given collator, taken from the requests that unlock last.
+    pub(crate) fn nomination_rebond(
         collator: T::AccountId,
         nominator: T::AccountId,
+        amount: BalanceOf<T>,
     ) -> DispatchResultWithPostInfo {
+        ensure!(!amount.is_zero(), <Error<T>>::RebondAmountZero);
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
-        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
+        let mut requests =
+            <NominationScheduledRequests<T>>::get(&collator, &nominator).into_inner();
 
-        let request =
-            Self::cancel_request_with_state(&nominator, &mut state, &mut scheduled_requests)
-                .ok_or(<Error<T>>::PendingNominationRequestDNE)?;
+        ensure!(!requests.is_empty(), <Error<T>>::PendingNominationRequestDNE);
+        ensure!(
+            !requests.iter().any(|req| matches!(req.action, NominationAction::Revoke(_))),
+            <Error<T>>::CannotRebondRevocation
+        );
+        let pending_amount = Self::pending_nomination_decrease(&requests);
+        ensure!(amount <= pending_amount, <Error<T>>::RebondAmountExceedsPendingUnbond);
 
-        <NominationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
+        Self::remove_latest_requests(&mut requests, amount);
+
+        state.less_total = state.less_total.saturating_sub(amount);
+        Self::put_nomination_requests(&collator, &nominator, requests);
         <NominatorState<T>>::insert(nominator.clone(), state);
 
-        Self::deposit_event(Event::CancelledNominationRequest {
+        Self::deposit_event(Event::NominationRebonded {
             nominator,
-            collator,
-            cancelled_request: request.into(),
+            candidate: collator,
+            amount,
+            remaining_unbond: pending_amount - amount,
         });
         Ok(().into())
     }
 
+    /// Trims the nominator's scheduled requests towards `collator` after its nomination was
+    /// slashed down to `bonded_amount`, so that executing them never unbonds more than is left. A
+    /// revocation is reduced to the remaining bond and decreases are trimmed from the requests that
+    /// unlock last, keeping the minimum nomination and nominator stake bonded.
+    pub(crate) fn nomination_trim_requests(
+        collator: &T::AccountId,
+        nominator: &T::AccountId,
+        state: &mut Nominator<T::AccountId, BalanceOf<T>>,
+        bonded_amount: BalanceOf<T>,
+    ) {
+        let mut requests = <NominationScheduledRequests<T>>::get(collator, nominator).into_inner();
+
+        let scheduled = requests
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |total, req| total.saturating_add(req.action.amount()));
+        // a revocation is always the only request of the nomination
+        let max_scheduled = match requests.first().map(|req| &req.action) {
+            None => return,
+            Some(NominationAction::Revoke(_)) => bonded_amount,
+            Some(NominationAction::Decrease(_)) => {
+                let other_pending = state.less_total.saturating_sub(scheduled);
+                let max_by_total = state
+                    .total()
+                    .saturating_sub(<MinTotalNominatorStake<T>>::get())
+                    .saturating_sub(other_pending);
+                bonded_amount
+                    .saturating_sub(T::MinNominationPerCollator::get())
+                    .min(max_by_total)
+            },
+        };
+        let excess = scheduled.saturating_sub(max_scheduled);
+        if excess.is_zero() {
+            return
+        }
+
+        Self::remove_latest_requests(&mut requests, excess);
+        state.less_total = state.less_total.saturating_sub(excess);
+        Self::put_nomination_requests(collator, nominator, requests);
+    }
+
+    /// Stores the nominator's remaining requests towards `collator`, removing the entry if there
+    /// are none left
+    pub(crate) fn put_nomination_requests(
+        collator: &T::AccountId,
+        nominator: &T::AccountId,
+        requests: Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>>,
+    ) {
+        if requests.is_empty() {
+            <NominationScheduledRequests<T>>::remove(collator, nominator);
+        } else {
+            // requests are only ever removed, so they still fit the bound
+            <NominationScheduledRequests<T>>::insert(
+                collator,
+                nominator,
+                BoundedVec::truncate_from(requests),
+            );
+        }
+    }
+
+    /// Removes `amount` from `requests`, starting from the ones that unlock last
+    fn remove_latest_requests(
+        requests: &mut Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>>,
+        amount: BalanceOf<T>,
+    ) {
+        requests.sort_by_key(|req| req.when_executable);
+        let mut to_remove = amount;
+        while !to_remove.is_zero() {
+            let Some(latest) = requests.last_mut() else { break };
+            let latest_amount = latest.action.amount();
+            if latest_amount > to_remove {
+                latest.action = match latest.action {
+                    NominationAction::Revoke(_) =>
+                        NominationAction::Revoke(latest_amount - to_remove),
+                    NominationAction::Decrease(_) =>
+                        NominationAction::Decrease(latest_amount - to_remove),
+                };
+                to_remove = Zero::zero();
+            } else {
+                to_remove -= latest_amount;
+                requests.pop();
+            }
+        }
+    }
+
+    /// Total amount of the [NominationAction::Decrease] requests in `scheduled_requests`
+    fn pending_nomination_decrease(
+        scheduled_requests: &[ScheduledRequest<T::AccountId, BalanceOf<T>>],
+    ) -> BalanceOf<T> {
+        scheduled_requests
+            .iter()
+            .filter_map(|req| match req.action {
+                NominationAction::Decrease(amount) => Some(amount),
+                NominationAction::Revoke(_) => None,
+            })
+            .fold(Zero::zero(), |total, amount| total.saturating_add(amount))
+    }
+
+    /// Total amount the nominator has scheduled to decrease its nomination to `collator` by
+    pub fn nomination_pending_decrease(
+        collator: &T::AccountId,
+        nominator: &T::AccountId,
+    ) -> BalanceOf<T> {
+        Self::pending_nomination_decrease(&<NominationScheduledRequests<T>>::get(
+            collator, nominator,
+        ))
+    }
+
+    /// Cancels all the nominator's existing [ScheduledRequest]s towards a given collator.
+    pub(crate) fn nomination_cancel_request(
+        collator: T::AccountId,
+        nominator: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
+
+        let requests = Self::cancel_request_with_state(&collator, &nominator, &mut state);
+        ensure!(!requests.is_empty(), <Error<T>>::PendingNominationRequestDNE);
+
+        <NominatorState<T>>::insert(nominator.clone(), state);
+
+        for request in requests {
+            Self::deposit_event(Event::CancelledNominationRequest {
+                nominator: nominator.clone(),
+                collator: collator.clone(),
+                cancelled_request: request.into(),
+            });
+        }
+        Ok(().into())
+    }
+
+    /// Removes the nominator's requests towards `collator` and releases their amount from
+    /// `state`, returning the cancelled requests.
     fn cancel_request_with_state(
+        collator: &T::AccountId,
         nominator: &T::AccountId,
         state: &mut Nominator<T::AccountId, BalanceOf<T>>,
-        scheduled_requests: &mut BoundedVec<
-            ScheduledRequest<T::AccountId, BalanceOf<T>>,
-            T::MaxNominationsPerNominator,
-        >,
-    ) -> Option<ScheduledRequest<T::AccountId, BalanceOf<T>>> {
-        let request_idx = scheduled_requests.iter().position(|req| &req.nominator == nominator)?;
-
-        let request = scheduled_requests.remove(request_idx);
-        let amount = request.action.amount();
-        state.less_total = state.less_total.saturating_sub(amount);
-        Some(request)
+    ) -> Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>> {
+        let cancelled = <NominationScheduledRequests<T>>::take(collator, nominator).into_inner();
+        for request in &cancelled {
+            state.less_total = state.less_total.saturating_sub(request.action.amount());
+        }
+        cancelled
     }
 
-    /// Executes the nominator's existing [ScheduledRequest] towards a given collator.
+    /// Executes the nominator's [ScheduledRequest]s towards a given collator that are due. Due
+    /// [NominationAction::Decrease] requests are applied together.
     pub(crate) fn nomination_execute_scheduled_request(
         collator: T::AccountId,
         nominator: T::AccountId,
     ) -> DispatchResultWithPostInfo {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
-        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
-        let request_idx = scheduled_requests
-            .iter()
-            .position(|req| req.nominator == nominator)
-            .ok_or(<Error<T>>::PendingNominationRequestDNE)?;
-        let request = &scheduled_requests[request_idx];
-
+        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator, &nominator);
         let now = <Era<T>>::get().current;
-        ensure!(request.when_executable <= now, <Error<T>>::PendingNominationRequestNotDueYet);
+        let (due, pending): (Vec<_>, Vec<_>) =
+            scheduled_requests.iter().partition(|req| req.when_executable <= now);
+
+        ensure!(!due.is_empty() || !pending.is_empty(), <Error<T>>::PendingNominationRequestDNE);
+        ensure!(!due.is_empty(), <Error<T>>::PendingNominationRequestNotDueYet);
+
+        // a revocation is always the only request of the nomination
+        let action = match due[0].action {
+            NominationAction::Revoke(amount) => NominationAction::Revoke(amount),
+            NominationAction::Decrease(_) => NominationAction::Decrease(
+                due.iter()
+                    .fold(Zero::zero(), |total, req| total.saturating_add(req.action.amount())),
+            ),
+        };
+        scheduled_requests.retain(|req| req.when_executable > now);
 
-        match request.action {
+        match action {
             NominationAction::Revoke(amount) => {
                 // revoking last nomination => leaving set of nominators
                 let leaving = if state.nominations.0.len() == 1usize {
@@ -243,7 +403,6 @@ impl<T: Config> Pallet<T> {
                 };
 
                 // remove from pending requests
-                let amount = scheduled_requests.remove(request_idx).action.amount();
                 state.less_total = state.less_total.saturating_sub(amount);
 
                 // remove nomination from nominator state
@@ -257,7 +416,11 @@ impl<T: Config> Pallet<T> {
                     unstaked_amount: amount,
                 });
 
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
+                Self::put_nomination_requests(
+                    &collator,
+                    &nominator,
+                    scheduled_requests.into_inner(),
+                );
                 if leaving {
                     <NominatorState<T>>::remove(&nominator);
                     Self::deposit_event(Event::NominatorLeft {
@@ -269,9 +432,8 @@ impl<T: Config> Pallet<T> {
                 }
                 Ok(().into())
             },
-            NominationAction::Decrease(_) => {
+            NominationAction::Decrease(amount) => {
                 // remove from pending requests
-                let amount = scheduled_requests.remove(request_idx).action.amount();
                 state.less_total = state.less_total.saturating_sub(amount);
 
                 // decrease nomination
@@ -308,9 +470,10 @@ impl<T: Config> Pallet<T> {
                             let new_total_staked = <Total<T>>::get().saturating_sub(amount);
                             <Total<T>>::put(new_total_staked);
 
-                            <NominationScheduledRequests<T>>::insert(
-                                collator.clone(),
-                                scheduled_requests,
+                            Self::put_nomination_requests(
+                                &collator,
+                                &nominator,
+                                scheduled_requests.into_inner(),
                             );
                             <NominatorState<T>>::insert(nominator.clone(), state);
                             Self::deposit_event(Event::NominationDecreased {
@@ -337,7 +500,7 @@ impl<T: Config> Pallet<T> {
         nominator: T::AccountId,
     ) -> DispatchResultWithPostInfo {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
-        let mut updated_scheduled_requests = vec![];
+        let mut revoke_requests = vec![];
         let now = <Era<T>>::get().current;
         let when = now.saturating_add(<Delay<T>>::get());
 
@@ -347,41 +510,35 @@ impl<T: Config> Pallet<T> {
         for bond in state.nominations.0.clone() {
             let collator = bond.owner;
             let bonded_amount = bond.amount;
-            let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
 
             // cancel any existing requests
-            let request =
-                Self::cancel_request_with_state(&nominator, &mut state, &mut scheduled_requests);
-            let request = match request {
-                Some(revoke_req) if matches!(revoke_req.action, NominationAction::Revoke(_)) => {
+            let requests = Self::cancel_request_with_state(&collator, &nominator, &mut state);
+            let revoke_request = requests
+                .into_iter()
+                .find(|req| matches!(req.action, NominationAction::Revoke(_)));
+            let request = match revoke_request {
+                Some(revoke_req) => {
                     existing_revoke_count += 1;
                     revoke_req // re-insert the same Revoke request
                 },
-                _ => ScheduledRequest {
+                None => ScheduledRequest {
                     nominator: nominator.clone(),
                     action: NominationAction::Revoke(bonded_amount.clone()),
                     when_executable: when,
                 },
             };
 
-            match scheduled_requests.try_push(request) {
-                Ok(()) => {
-                    state.less_total = state.less_total.saturating_add(bonded_amount);
-                    updated_scheduled_requests.push((collator, scheduled_requests));
-                },
-                Err(_) => (),
-            }
+            state.less_total = state.less_total.saturating_add(bonded_amount);
+            revoke_requests.push((collator, request));
         }
 
         if existing_revoke_count == state.nominations.0.len() {
             return Err(<Error<T>>::NominatorAlreadyLeaving.into())
         }
 
-        updated_scheduled_requests
-            .into_iter()
-            .for_each(|(collator, scheduled_requests)| {
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
-            });
+        revoke_requests.into_iter().for_each(|(collator, request)| {
+            Self::put_nomination_requests(&collator, &nominator, vec![request]);
+        });
 
         <NominatorState<T>>::insert(nominator.clone(), state);
         Self::deposit_event(Event::NominatorExitScheduled {
@@ -399,34 +556,20 @@ impl<T: Config> Pallet<T> {
         nominator: T::AccountId,
     ) -> DispatchResultWithPostInfo {
         let mut state = <NominatorState<T>>::get(&nominator).ok_or(<Error<T>>::NominatorDNE)?;
-        let mut updated_scheduled_requests = vec![];
 
         // pre-validate that all nominations have a Revoke request.
         for bond in &state.nominations.0 {
-            let collator = bond.owner.clone();
-            let scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
-            scheduled_requests
-                .iter()
-                .find(|req| {
-                    req.nominator == nominator && matches!(req.action, NominationAction::Revoke(_))
-                })
-                .ok_or(<Error<T>>::NominatorNotLeaving)?;
+            ensure!(
+                Self::nomination_request_revoke_exists(&bond.owner, &nominator),
+                <Error<T>>::NominatorNotLeaving
+            );
         }
 
         // cancel all requests
         for bond in state.nominations.0.clone() {
-            let collator = bond.owner.clone();
-            let mut scheduled_requests = <NominationScheduledRequests<T>>::get(&collator);
-            Self::cancel_request_with_state(&nominator, &mut state, &mut scheduled_requests);
-            updated_scheduled_requests.push((collator, scheduled_requests));
+            Self::cancel_request_with_state(&bond.owner, &nominator, &mut state);
         }
 
-        updated_scheduled_requests
-            .into_iter()
-            .for_each(|(collator, scheduled_requests)| {
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
-            });
-
         <NominatorState<T>>::insert(nominator.clone(), state);
         Self::deposit_event(Event::NominatorExitCancelled { nominator });
 
@@ -449,12 +592,10 @@ impl<T: Config> Pallet<T> {
         let mut validated_scheduled_requests = vec![];
         // pre-validate that all nominations have a Revoke request that can be executed now.
         for bond in &state.nominations.0 {
-            let scheduled_requests = <NominationScheduledRequests<T>>::get(&bond.owner);
+            let scheduled_requests = <NominationScheduledRequests<T>>::get(&bond.owner, &nominator);
             let request_idx = scheduled_requests
                 .iter()
-                .position(|req| {
-                    req.nominator == nominator && matches!(req.action, NominationAction::Revoke(_))
-                })
+                .position(|req| matches!(req.action, NominationAction::Revoke(_)))
                 .ok_or(<Error<T>>::NominatorNotLeaving)?;
             let request = &scheduled_requests[request_idx];
 
@@ -463,7 +604,6 @@ impl<T: Config> Pallet<T> {
             validated_scheduled_requests.push((bond.clone(), scheduled_requests, request_idx))
         }
 
-        let mut updated_scheduled_requests = vec![];
         // we do not update the nominator state, since the it will be completely removed
         for (bond, mut scheduled_requests, request_idx) in validated_scheduled_requests {
             let collator = bond.owner;
@@ -479,51 +619,33 @@ impl<T: Config> Pallet<T> {
             }
 
             // remove the scheduled request, since it is fulfilled
-            scheduled_requests.remove(request_idx).action.amount();
-            updated_scheduled_requests.push((collator, scheduled_requests));
+            scheduled_requests.remove(request_idx);
+            Self::put_nomination_requests(&collator, &nominator, scheduled_requests.into_inner());
         }
 
         // set state.total so that state.adjust_bond_lock will remove lock
         let unstaked_amount = state.total();
         state.total_sub::<T>(unstaked_amount)?;
 
-        updated_scheduled_requests
-            .into_iter()
-            .for_each(|(collator, scheduled_requests)| {
-                <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
-            });
-
         Self::deposit_event(Event::NominatorLeft { nominator: nominator.clone(), unstaked_amount });
         <NominatorState<T>>::remove(&nominator);
 
         Ok(().into())
     }
 
-    /// Removes the nominator's existing [ScheduledRequest] towards a given collator, if exists.
+    /// Removes the nominator's existing [ScheduledRequest]s towards a given collator, if any.
     /// The state needs to be persisted by the caller of this function.
     pub(crate) fn nomination_remove_request_with_state(
         collator: &T::AccountId,
         nominator: &T::AccountId,
         state: &mut Nominator<T::AccountId, BalanceOf<T>>,
     ) {
-        let mut scheduled_requests = <NominationScheduledRequests<T>>::get(collator);
-
-        let maybe_request_idx =
-            scheduled_requests.iter().position(|req| &req.nominator == nominator);
-
-        if let Some(request_idx) = maybe_request_idx {
-            let request = scheduled_requests.remove(request_idx);
-            let amount = request.action.amount();
-            state.less_total = state.less_total.saturating_sub(amount);
-            <NominationScheduledRequests<T>>::insert(collator, scheduled_requests);
-        }
+        Self::cancel_request_with_state(collator, nominator, state);
     }
 
     /// Returns true if a [ScheduledRequest] exists for a given nomination
     pub fn nomination_request_exists(collator: &T::AccountId, nominator: &T::AccountId) -> bool {
-        <NominationScheduledRequests<T>>::get(collator)
-            .iter()
-            .any(|req| &req.nominator == nominator)
+        !<NominationScheduledRequests<T>>::get(collator, nominator).is_empty()
     }
 
     /// Returns true if a [NominationAction::Revoke] [ScheduledRequest] exists for a given
@@ -532,9 +654,9 @@ impl<T: Config> Pallet<T> {
         collator: &T::AccountId,
         nominator: &T::AccountId,
     ) -> bool {
-        <NominationScheduledRequests<T>>::get(collator).iter().any(|req| {
-            &req.nominator == nominator && matches!(req.action, NominationAction::Revoke(_))
-        })
+        <NominationScheduledRequests<T>>::get(collator, nominator)
+            .iter()
+            .any(|req| matches!(req.action, NominationAction::Revoke(_)))
     }
 }
 
@@ -542,118 +664,144 @@ impl<T: Config> Pallet<T> {
 mod tests {
     use super::*;
     use crate::{
-        mock::{Test, TestAccount},
+        mock::{ExtBuilder, Test, TestAccount},
         set::BoundedOrderedSet,
         Bond,
     };
 
     #[test]
     fn test_cancel_request_with_state_removes_request_for_correct_nominator_and_updates_state() {
-        let nominator1_account_id = TestAccount::new(1u64).account_id();
-        let collator_account_id = TestAccount::new(2u64).account_id();
-
-        let mut state = Nominator {
-            id: nominator1_account_id,
-            nominations: BoundedOrderedSet::from(BoundedVec::truncate_from(vec![Bond {
-                amount: 100,
-                owner: collator_account_id,
-            }])),
-            total: 100,
-            less_total: 100,
-        };
-        let mut scheduled_requests = BoundedVec::truncate_from(vec![
-            ScheduledRequest {
-                nominator: nominator1_account_id,
-                when_executable: 1,
-                action: NominationAction::Revoke(100),
-            },
-            ScheduledRequest {
-                nominator: collator_account_id,
-                when_executable: 1,
-                action: NominationAction::Decrease(50),
-            },
-        ]);
-        let removed_request = <Pallet<Test>>::cancel_request_with_state(
-            &nominator1_account_id,
-            &mut state,
-            &mut scheduled_requests,
-        );
+        ExtBuilder::default().build().execute_with(|| {
+            let nominator1_account_id = TestAccount::new(1u64).account_id();
+            let collator_account_id = TestAccount::new(2u64).account_id();
+            let nominator2_account_id = TestAccount::new(3u64).account_id();
 
-        assert_eq!(
-            removed_request,
-            Some(ScheduledRequest {
-                nominator: nominator1_account_id,
-                when_executable: 1,
-                action: NominationAction::Revoke(100),
-            })
-        );
-        assert_eq!(
-            scheduled_requests,
-            vec![ScheduledRequest {
-                nominator: collator_account_id,
-                when_executable: 1,
-                action: NominationAction::Decrease(50),
-            },]
-        );
-        assert_eq!(
-            state,
-            Nominator {
+            let mut state = Nominator {
                 id: nominator1_account_id,
                 nominations: BoundedOrderedSet::from(BoundedVec::truncate_from(vec![Bond {
                     amount: 100,
-                    owner: collator_account_id
+                    owner: collator_account_id,
                 }])),
                 total: 100,
-                less_total: 0,
-            }
-        );
+                less_total: 100,
+            };
+            <NominationScheduledRequests<Test>>::insert(
+                collator_account_id,
+                nominator1_account_id,
+                BoundedVec::truncate_from(vec![ScheduledRequest {
+                    nominator: nominator1_account_id,
+                    when_executable: 1,
+                    action: NominationAction::Revoke(100),
+                }]),
+            );
+            <NominationScheduledRequests<Test>>::insert(
+                collator_account_id,
+                nominator2_account_id,
+                BoundedVec::truncate_from(vec![ScheduledRequest {
+                    nominator: nominator2_account_id,
+                    when_executable: 1,
+                    action: NominationAction::Decrease(50),
+                }]),
+            );
+            let removed_request = <Pallet<Test>>::cancel_request_with_state(
+                &collator_account_id,
+                &nominator1_account_id,
+                &mut state,
+            );
+
+            assert_eq!(
+                removed_request,
+                vec![ScheduledRequest {
+                    nominator: nominator1_account_id,
+                    when_executable: 1,
+                    action: NominationAction::Revoke(100),
+                }]
+            );
+            assert!(!<NominationScheduledRequests<Test>>::contains_key(
+                collator_account_id,
+                nominator1_account_id
+            ));
+            assert_eq!(
+                <NominationScheduledRequests<Test>>::get(
+                    collator_account_id,
+                    nominator2_account_id
+                ),
+                vec![ScheduledRequest {
+                    nominator: nominator2_account_id,
+                    when_executable: 1,
+                    action: NominationAction::Decrease(50),
+                },]
+            );
+            assert_eq!(
+                state,
+                Nominator {
+                    id: nominator1_account_id,
+                    nominations: BoundedOrderedSet::from(BoundedVec::truncate_from(vec![Bond {
+                        amount: 100,
+                        owner: collator_account_id
+                    }])),
+                    total: 100,
+                    less_total: 0,
+                }
+            );
+        });
     }
 
     #[test]
     fn test_cancel_request_with_state_does_nothing_when_request_does_not_exist() {
-        let nominator1_account_id = TestAccount::new(1u64).account_id();
-        let collator_account_id = TestAccount::new(2u64).account_id();
-
-        let mut state = Nominator {
-            id: nominator1_account_id,
-            nominations: BoundedOrderedSet::from(BoundedVec::truncate_from(vec![Bond {
-                amount: 100,
-                owner: collator_account_id,
-            }])),
-            total: 100,
-            less_total: 100,
-        };
-        let mut scheduled_requests = BoundedVec::truncate_from(vec![ScheduledRequest {
-            nominator: collator_account_id,
-            when_executable: 1,
-            action: NominationAction::Decrease(50),
-        }]);
-        let removed_request = <Pallet<Test>>::cancel_request_with_state(
-            &nominator1_account_id,
-            &mut state,
-            &mut scheduled_requests,
-        );
+        ExtBuilder::default().build().execute_with(|| {
+            let nominator1_account_id = TestAccount::new(1u64).account_id();
+            let collator_account_id = TestAccount::new(2u64).account_id();
+            let nominator2_account_id = TestAccount::new(3u64).account_id();
 
-        assert_eq!(removed_request, None,);
-        assert_eq!(
-            scheduled_requests,
-            vec![ScheduledRequest {
-                nominator: collator_account_id,
-                when_executable: 1,
-                action: NominationAction::Decrease(50),
-            },]
-        );
-        assert_eq!(
-            state,
-            Nominator {
+            let mut state = Nominator {
                 id: nominator1_account_id,
                 nominations: BoundedOrderedSet::from(BoundedVec::truncate_from(vec![Bond {
                     amount: 100,
-                    owner: collator_account_id
+                    owner: collator_account_id,
                 }])),
                 total: 100,
                 less_total: 100,
-            }
-        );
+            };
+            <NominationScheduledRequests<Test>>::insert(
+                collator_account_id,
+                nominator2_account_id,
+                BoundedVec::truncate_from(vec![ScheduledRequest {
+                    nominator: nominator2_account_id,
+                    when_executable: 1,
+                    action: NominationAction::Decrease(50),
+                }]),
+            );
+            let removed_request = <Pallet<Test>>::cancel_request_with_state(
+                &collator_account_id,
+                &nominator1_account_id,
+                &mut state,
+            );
+
+            assert_eq!(removed_request, vec![]);
+            assert_eq!(
+                <NominationScheduledRequests<Test>>::get(
+                    collator_account_id,
+                    nominator2_account_id
+                ),
+                vec![ScheduledRequest {
+                    nominator: nominator2_account_id,
+                    when_executable: 1,
+                    action: NominationAction::Decrease(50),
+                },]
+            );
+            assert_eq!(
+                state,
+                Nominator {
+                    id: nominator1_account_id,
+                    nominations: BoundedOrderedSet::from(BoundedVec::truncate_from(vec![Bond {
+                        amount: 100,
+                        owner: collator_account_id
+                    }])),
+                    total: 100,
+                    less_total: 100,
+                }
+            );
+        });
     }
 }
@@ -0,0 +1,78 @@
+//! Block production performance of the selected collators.
+//!
+//! The blocks authored by each collator are counted during the era, and compared at the end of the
+//! era with the slots it was expected to fill, assuming slots rotate evenly between the selected
+//! collators. Once `MaxMissedSlotRate` has been set by governance, the reward points of collators
+//! that missed slots are scaled down, and candidates that missed more slots than allowed are only
+//! selected for the next era if there are not enough other candidates.
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    /// Records the performance of the collators selected for `era`, which lasted `era_blocks`.
+    /// Only the performance of the last ended era is kept.
+    pub(crate) fn record_collator_performance(era: EraIndex, era_blocks: u32) -> Weight {
+        let selected = <SelectedCandidates<T>>::get();
+        let max_missed_slot_rate = <MaxMissedSlotRate<T>>::get();
+        let _ = <CollatorPerformance<T>>::clear(u32::MAX, None);
+
+        let expected_slots = era_blocks.checked_div(selected.len() as u32).unwrap_or_default();
+        for collator in selected.iter() {
+            let performance = SlotPerformance {
+                era,
+                expected_slots,
+                authored_slots: <AuthoredBlocks<T>>::take(era, collator),
+            };
+            <CollatorPerformance<T>>::insert(collator, performance);
+
+            if max_missed_slot_rate.is_some() && performance.missed_slots() > 0 {
+                let points_removed = Self::scale_collator_points(era, collator, &performance);
+                Self::deposit_event(Event::CollatorMissedSlots {
+                    era,
+                    collator: collator.clone(),
+                    expected_slots,
+                    missed_slots: performance.missed_slots(),
+                    points_removed,
+                });
+            }
+        }
+        // remove any block noted for an account that was not selected
+        let _ = <AuthoredBlocks<T>>::clear_prefix(era, u32::MAX, None);
+
+        return <T as Config>::WeightInfo::record_collator_performance(selected.len() as u32)
+    }
+
+    /// Scales the points of `collator` by the share of its expected slots it filled, and returns
+    /// the points removed.
+    fn scale_collator_points(
+        era: EraIndex,
+        collator: &T::AccountId,
+        performance: &SlotPerformance,
+    ) -> RewardPoint {
+        let points = <AwardedPts<T>>::get(era, collator);
+        let scaled_points = Perbill::one().saturating_sub(performance.missed_slot_rate()) * points;
+        let points_removed = points.saturating_sub(scaled_points);
+        if points_removed.is_zero() {
+            return 0
+        }
+
+        <AwardedPts<T>>::insert(era, collator, scaled_points);
+        <Points<T>>::mutate(era, |total| *total = total.saturating_sub(points_removed));
+
+        return points_removed
+    }
+
+    /// The share of its expected slots `candidate` missed in the last ended era, if it exceeds
+    /// `MaxMissedSlotRate`
+    pub fn excessive_missed_slot_rate(candidate: &T::AccountId) -> Option<Perbill> {
+        let max_missed_slot_rate = <MaxMissedSlotRate<T>>::get()?;
+        let missed_slot_rate = <CollatorPerformance<T>>::get(candidate)?.missed_slot_rate();
+
+        return (missed_slot_rate > max_missed_slot_rate).then_some(missed_slot_rate)
+    }
+
+    /// Counts the block towards the slots filled by `author` in the current era
+    pub(crate) fn note_authored_block(era: EraIndex, author: &T::AccountId) {
+        <AuthoredBlocks<T>>::mutate(era, author, |blocks| *blocks = blocks.saturating_add(1));
+    }
+}
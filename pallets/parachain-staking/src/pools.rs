@@ -0,0 +1,611 @@
+//! Nomination pools, which let accounts that can't meet the minimum nominator stake nominate
+//! together.
+//!
+//! The funds bonded to a pool are held and nominated by the pool account, to the collators chosen
+//! by the pool operator. Members own the pool stake through shares, and the staking rewards paid
+//! to the pool account are shared between them in proportion to their shares. Unbonding goes
+//! through the nomination requests of the pool account, so members can only withdraw their funds
+//! once the request they depend on can be executed. The funds unbonding from the same collator in
+//! the same era are pooled in a [`PoolUnbondingBucket`], so a slash of the pool nomination is
+//! shared pro-rata by the members still bonded and the members unbonding. Stake returned to the
+//! pool account when a collator leaves or kicks a pool nomination stays pool stake, and is bonded
+//! again the next time a member joins or unbonds.
+
+use crate::*;
+use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding, traits::SaturatedConversion,
+    FixedPointNumber, FixedU128, Rounding,
+};
+use sp_std::cmp::Reverse;
+
+const POOL_ACCOUNT_PREFIX: &[u8; 4] = b"pool";
+
+impl<T: Config> Pallet<T> {
+    /// The account that holds and nominates the stake of `pool_id`
+    pub fn pool_account_id(pool_id: PoolId) -> T::AccountId {
+        return T::RewardPotId::get().into_sub_account_truncating((POOL_ACCOUNT_PREFIX, pool_id))
+    }
+
+    /// The stake of a pool that is bonded and not scheduled to be unbonded
+    pub fn pool_active_stake(pool_account: &T::AccountId) -> BalanceOf<T> {
+        return <NominatorState<T>>::get(pool_account)
+            .map(|state| state.total().saturating_sub(state.less_total))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn do_create_pool(
+        operator: T::AccountId,
+        collators: Vec<T::AccountId>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let collators = Self::bounded_pool_collators(collators)?;
+        let pool_id = <NextPoolId<T>>::get();
+        let pool_account = Self::pool_account_id(pool_id);
+
+        T::Currency::transfer(&operator, &pool_account, amount, ExistenceRequirement::KeepAlive)?;
+        Self::nominate_for_pool(&pool_account, collators[0].clone(), amount)?;
+
+        <Pools<T>>::insert(
+            pool_id,
+            PoolInfo {
+                operator: operator.clone(),
+                collators,
+                total_shares: amount,
+                reward_per_share: FixedU128::zero(),
+                recorded_rewards: Zero::zero(),
+                unbonding: Zero::zero(),
+                returned: Zero::zero(),
+            },
+        );
+        <PoolMembers<T>>::insert(
+            pool_id,
+            &operator,
+            PoolMember {
+                shares: amount,
+                reward_per_share_paid: FixedU128::zero(),
+                unbonding: BoundedVec::default(),
+            },
+        );
+        <PoolAccounts<T>>::insert(&pool_account, pool_id);
+        <NextPoolId<T>>::put(pool_id.saturating_add(1));
+
+        Self::deposit_event(Event::PoolCreated {
+            pool_id,
+            operator: operator.clone(),
+            pool_account,
+        });
+        Self::deposit_event(Event::PoolBonded {
+            pool_id,
+            member: operator,
+            amount,
+            shares: amount,
+        });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_set_pool_collators(
+        operator: &T::AccountId,
+        pool_id: PoolId,
+        collators: Vec<T::AccountId>,
+    ) -> DispatchResult {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolDNE)?;
+        ensure!(&pool.operator == operator, Error::<T>::NotPoolOperator);
+
+        pool.collators = Self::bounded_pool_collators(collators)?;
+        Self::deposit_event(Event::PoolCollatorsSet {
+            pool_id,
+            collators: pool.collators.to_vec(),
+        });
+        <Pools<T>>::insert(pool_id, pool);
+
+        Ok(())
+    }
+
+    pub(crate) fn do_join_pool(
+        member: &T::AccountId,
+        pool_id: PoolId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolDNE)?;
+        let pool_account = Self::pool_account_id(pool_id);
+        Self::update_pool_rewards(&pool_account, &mut pool);
+
+        let mut member_state =
+            <PoolMembers<T>>::get(pool_id, member).unwrap_or_else(|| PoolMember {
+                shares: Zero::zero(),
+                reward_per_share_paid: pool.reward_per_share,
+                unbonding: BoundedVec::default(),
+            });
+        Self::pay_pool_reward(pool_id, &mut pool, &pool_account, member, &mut member_state)?;
+
+        let shares = Self::pool_shares(
+            &pool,
+            Self::pool_stake(&pool_account, &pool),
+            amount,
+            Rounding::Down,
+        );
+        ensure!(!shares.is_zero(), Error::<T>::PoolAmountZero);
+
+        T::Currency::transfer(member, &pool_account, amount, ExistenceRequirement::KeepAlive)?;
+        // the stake returned to the pool is bonded again with the new stake
+        Self::bond_pool_stake(&pool_account, &pool, amount.saturating_add(pool.returned))?;
+        pool.returned = Zero::zero();
+
+        pool.total_shares = pool.total_shares.saturating_add(shares);
+        member_state.shares = member_state.shares.saturating_add(shares);
+        <PoolMembers<T>>::insert(pool_id, member, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+
+        Self::deposit_event(Event::PoolBonded { pool_id, member: member.clone(), amount, shares });
+
+        Ok(())
+    }
+
+    pub(crate) fn do_unbond_from_pool(
+        member: &T::AccountId,
+        pool_id: PoolId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(!amount.is_zero(), Error::<T>::PoolAmountZero);
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolDNE)?;
+        let mut member_state =
+            <PoolMembers<T>>::get(pool_id, member).ok_or(Error::<T>::PoolMemberDNE)?;
+        ensure!(
+            member_state.unbonding.len() < T::MaxPoolUnbondingChunks::get() as usize,
+            Error::<T>::TooManyPoolUnbondingChunks
+        );
+
+        let pool_account = Self::pool_account_id(pool_id);
+        Self::update_pool_rewards(&pool_account, &mut pool);
+        Self::pay_pool_reward(pool_id, &mut pool, &pool_account, member, &mut member_state)?;
+        Self::rebond_returned_pool_stake(&pool_account, &mut pool);
+
+        let shares =
+            Self::pool_shares(&pool, Self::pool_stake(&pool_account, &pool), amount, Rounding::Up);
+        ensure!(shares <= member_state.shares, Error::<T>::InsufficientPoolShares);
+
+        let (collator, unlock_era) = Self::schedule_pool_unbond(&pool_account, &pool, amount)?;
+        let points = Self::issue_unbonding_points(pool_id, &collator, unlock_era, amount);
+        member_state
+            .unbonding
+            .try_push(PoolUnbondChunk { collator, points, unlock_era })
+            .map_err(|_| Error::<T>::TooManyPoolUnbondingChunks)?;
+
+        member_state.shares = member_state.shares.saturating_sub(shares);
+        pool.total_shares = pool.total_shares.saturating_sub(shares);
+        pool.unbonding = pool.unbonding.saturating_add(amount);
+        <PoolMembers<T>>::insert(pool_id, member, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+
+        Self::deposit_event(Event::PoolUnbonded {
+            pool_id,
+            member: member.clone(),
+            amount,
+            shares,
+            unlock_era,
+        });
+
+        Ok(())
+    }
+
+    /// Returns the number of unbonding chunks withdrawn
+    pub(crate) fn do_withdraw_from_pool(
+        member: &T::AccountId,
+        pool_id: PoolId,
+    ) -> Result<u32, DispatchError> {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolDNE)?;
+        let mut member_state =
+            <PoolMembers<T>>::get(pool_id, member).ok_or(Error::<T>::PoolMemberDNE)?;
+        let pool_account = Self::pool_account_id(pool_id);
+        let now = <Era<T>>::get().current;
+
+        let (due, pending): (Vec<_>, Vec<_>) =
+            member_state.unbonding.into_iter().partition(|chunk| chunk.unlock_era <= now);
+        ensure!(!due.is_empty(), Error::<T>::NothingToWithdraw);
+
+        let mut amount = BalanceOf::<T>::zero();
+        for chunk in &due {
+            let request_due = <NominationScheduledRequests<T>>::get(&chunk.collator, &pool_account)
+                .iter()
+                .any(|request| request.when_executable <= now);
+            if request_due {
+                Self::nomination_execute_scheduled_request(
+                    chunk.collator.clone(),
+                    pool_account.clone(),
+                )
+                .map_err(|e| e.error)?;
+            }
+            amount = amount.saturating_add(Self::redeem_unbonding_points(pool_id, chunk));
+        }
+
+        T::Currency::transfer(&pool_account, member, amount, ExistenceRequirement::KeepAlive)?;
+
+        pool.unbonding = pool.unbonding.saturating_sub(amount);
+        member_state.unbonding = BoundedVec::truncate_from(pending);
+        if member_state.shares.is_zero() && member_state.unbonding.is_empty() {
+            <PoolMembers<T>>::remove(pool_id, member);
+        } else {
+            <PoolMembers<T>>::insert(pool_id, member, member_state);
+        }
+        <Pools<T>>::insert(pool_id, pool);
+
+        Self::deposit_event(Event::PoolWithdrawn { pool_id, member: member.clone(), amount });
+
+        return Ok(due.len() as u32)
+    }
+
+    pub(crate) fn do_claim_pool_rewards(member: &T::AccountId, pool_id: PoolId) -> DispatchResult {
+        let mut pool = <Pools<T>>::get(pool_id).ok_or(Error::<T>::PoolDNE)?;
+        let mut member_state =
+            <PoolMembers<T>>::get(pool_id, member).ok_or(Error::<T>::PoolMemberDNE)?;
+        let pool_account = Self::pool_account_id(pool_id);
+
+        Self::update_pool_rewards(&pool_account, &mut pool);
+        Self::pay_pool_reward(pool_id, &mut pool, &pool_account, member, &mut member_state)?;
+
+        <PoolMembers<T>>::insert(pool_id, member, member_state);
+        <Pools<T>>::insert(pool_id, pool);
+
+        Ok(())
+    }
+
+    /// The staking rewards `member` can claim from the pool
+    pub fn pending_pool_reward(pool_id: PoolId, member: &T::AccountId) -> BalanceOf<T> {
+        let (Some(mut pool), Some(member_state)) =
+            (<Pools<T>>::get(pool_id), <PoolMembers<T>>::get(pool_id, member))
+        else {
+            return Zero::zero()
+        };
+
+        Self::update_pool_rewards(&Self::pool_account_id(pool_id), &mut pool);
+        return Self::member_reward(&pool, &member_state)
+    }
+
+    fn bounded_pool_collators(
+        collators: Vec<T::AccountId>,
+    ) -> Result<BoundedVec<T::AccountId, T::MaxNominationsPerNominator>, DispatchError> {
+        ensure!(!collators.is_empty(), Error::<T>::PoolCollatorsEmpty);
+        ensure!(collators.iter().all(Self::is_candidate), Error::<T>::CandidateDNE);
+        return BoundedVec::try_from(collators).map_err(|_| Error::<T>::TooManyPoolCollators.into())
+    }
+
+    /// Converts `amount` of pool stake into pool shares
+    fn pool_shares(
+        pool: &PoolInfo<T>,
+        active_stake: BalanceOf<T>,
+        amount: BalanceOf<T>,
+        rounding: Rounding,
+    ) -> BalanceOf<T> {
+        if pool.total_shares.is_zero() || active_stake.is_zero() {
+            return amount
+        }
+
+        return multiply_by_rational_with_rounding(
+            amount.saturated_into(),
+            pool.total_shares.saturated_into(),
+            active_stake.saturated_into(),
+            rounding,
+        )
+        .unwrap_or(u128::MAX)
+        .saturated_into()
+    }
+
+    /// The stake owned by the shares of a pool: its active stake and the stake returned to it
+    fn pool_stake(pool_account: &T::AccountId, pool: &PoolInfo<T>) -> BalanceOf<T> {
+        return Self::pool_active_stake(pool_account).saturating_add(pool.returned)
+    }
+
+    /// Any funds of the pool account that are not pool stake or unclaimed rewards are rewards
+    /// earned since the last update. They are shared between the current shares.
+    fn update_pool_rewards(pool_account: &T::AccountId, pool: &mut PoolInfo<T>) {
+        let held = Self::pool_stake(pool_account, pool)
+            .saturating_add(pool.unbonding)
+            .saturating_add(pool.recorded_rewards)
+            .saturating_add(T::Currency::minimum_balance());
+        let earned = T::Currency::free_balance(pool_account).saturating_sub(held);
+        if earned.is_zero() || pool.total_shares.is_zero() {
+            return
+        }
+
+        pool.reward_per_share =
+            pool.reward_per_share.saturating_add(FixedU128::saturating_from_rational(
+                earned.saturated_into::<u128>(),
+                pool.total_shares.saturated_into::<u128>(),
+            ));
+        pool.recorded_rewards = pool.recorded_rewards.saturating_add(earned);
+    }
+
+    /// Records the stake returned to `nominator`, if it is a pool account, when its nomination of
+    /// `bond` is removed because the collator left or kicked it. Of the returned funds, the
+    /// `unbonding` part is still owed to the members unbonding it. The rest stays pool stake
+    /// instead of being shared as rewards, until it is bonded again.
+    pub(crate) fn record_returned_pool_stake(
+        nominator: &T::AccountId,
+        bond: BalanceOf<T>,
+        unbonding: BalanceOf<T>,
+    ) {
+        let Some(pool_id) = <PoolAccounts<T>>::get(nominator) else { return };
+        <Pools<T>>::mutate(pool_id, |maybe_pool| {
+            if let Some(pool) = maybe_pool {
+                pool.returned = pool.returned.saturating_add(bond.saturating_sub(unbonding));
+            }
+        });
+    }
+
+    /// Bonds the stake returned to the pool again. It is kept as returned stake if no pool
+    /// collator can take it.
+    fn rebond_returned_pool_stake(pool_account: &T::AccountId, pool: &mut PoolInfo<T>) {
+        if pool.returned.is_zero() {
+            return
+        }
+
+        if Self::bond_pool_stake(pool_account, pool, pool.returned).is_ok() {
+            pool.returned = Zero::zero();
+        }
+    }
+
+    /// Slashes the funds members are unbonding from the nomination of `pool_account` to
+    /// `collator` by the fraction the nomination is slashed by, `amount` out of `bond`. The pool
+    /// requests and their buckets are reduced pro-rata. Returns false if `pool_account` is not
+    /// the account of a pool.
+    pub(crate) fn slash_pool_requests(
+        collator: &T::AccountId,
+        pool_account: &T::AccountId,
+        state: &mut Nominator<T::AccountId, BalanceOf<T>>,
+        bond: BalanceOf<T>,
+        amount: BalanceOf<T>,
+    ) -> bool {
+        let Some(pool_id) = <PoolAccounts<T>>::get(pool_account) else { return false };
+        let Some(mut pool) = <Pools<T>>::get(pool_id) else { return false };
+
+        let mut requests =
+            <NominationScheduledRequests<T>>::get(collator, pool_account).into_inner();
+        for request in &mut requests {
+            let requested = request.action.amount();
+            let slashed = Self::pro_rata(requested, amount, bond);
+            request.action = match request.action {
+                NominationAction::Revoke(_) => NominationAction::Revoke(requested - slashed),
+                NominationAction::Decrease(_) => NominationAction::Decrease(requested - slashed),
+            };
+
+            state.less_total = state.less_total.saturating_sub(slashed);
+            pool.unbonding = pool.unbonding.saturating_sub(slashed);
+            <PoolUnbonding<T>>::mutate(
+                pool_id,
+                (collator.clone(), request.when_executable),
+                |maybe_bucket| {
+                    if let Some(bucket) = maybe_bucket {
+                        bucket.balance = bucket.balance.saturating_sub(slashed);
+                    }
+                },
+            );
+        }
+
+        requests.retain(|request| !request.action.amount().is_zero());
+        Self::put_nomination_requests(collator, pool_account, requests);
+        <Pools<T>>::insert(pool_id, pool);
+
+        return true
+    }
+
+    /// Adds `amount` to the bucket of funds unbonding from `collator` in `unlock_era`. Returns the
+    /// points issued for it.
+    fn issue_unbonding_points(
+        pool_id: PoolId,
+        collator: &T::AccountId,
+        unlock_era: EraIndex,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        return <PoolUnbonding<T>>::mutate(pool_id, (collator.clone(), unlock_era), |maybe_bucket| {
+            let bucket = maybe_bucket.get_or_insert_with(Default::default);
+            let points = match (bucket.balance.is_zero(), bucket.points.is_zero()) {
+                (_, true) => amount,
+                // the bucket was slashed in full, so the existing points are left with no value
+                (true, false) => amount.saturating_mul(bucket.points),
+                (false, false) => Self::pro_rata(amount, bucket.points, bucket.balance),
+            };
+
+            bucket.balance = bucket.balance.saturating_add(amount);
+            bucket.points = bucket.points.saturating_add(points);
+            points
+        })
+    }
+
+    /// Removes the points of `chunk` from its bucket. Returns the funds they are worth.
+    fn redeem_unbonding_points(
+        pool_id: PoolId,
+        chunk: &PoolUnbondChunk<T::AccountId, BalanceOf<T>>,
+    ) -> BalanceOf<T> {
+        return <PoolUnbonding<T>>::mutate_exists(
+            pool_id,
+            (chunk.collator.clone(), chunk.unlock_era),
+            |maybe_bucket| {
+                let Some(bucket) = maybe_bucket.as_mut() else { return Zero::zero() };
+                let funds = match chunk.points >= bucket.points {
+                    true => bucket.balance,
+                    false => Self::pro_rata(bucket.balance, chunk.points, bucket.points),
+                };
+
+                bucket.balance = bucket.balance.saturating_sub(funds);
+                bucket.points = bucket.points.saturating_sub(chunk.points);
+                if bucket.points.is_zero() {
+                    *maybe_bucket = None;
+                }
+                funds
+            },
+        )
+    }
+
+    /// `amount * numerator / denominator`, rounded down
+    fn pro_rata(
+        amount: BalanceOf<T>,
+        numerator: BalanceOf<T>,
+        denominator: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if denominator.is_zero() {
+            return Zero::zero()
+        }
+
+        return multiply_by_rational_with_rounding(
+            amount.saturated_into(),
+            numerator.saturated_into(),
+            denominator.saturated_into(),
+            Rounding::Down,
+        )
+        .unwrap_or(u128::MAX)
+        .saturated_into()
+    }
+
+    fn member_reward(pool: &PoolInfo<T>, member_state: &PoolMember<T>) -> BalanceOf<T> {
+        let reward = pool
+            .reward_per_share
+            .saturating_sub(member_state.reward_per_share_paid)
+            .saturating_mul_int(member_state.shares.saturated_into::<u128>())
+            .saturated_into();
+
+        return pool.recorded_rewards.min(reward)
+    }
+
+    /// Pays `member` the rewards it has earned since its last payment. Must be called before the
+    /// shares of the member change.
+    fn pay_pool_reward(
+        pool_id: PoolId,
+        pool: &mut PoolInfo<T>,
+        pool_account: &T::AccountId,
+        member: &T::AccountId,
+        member_state: &mut PoolMember<T>,
+    ) -> DispatchResult {
+        let reward = Self::member_reward(pool, member_state);
+        member_state.reward_per_share_paid = pool.reward_per_share;
+        if reward.is_zero() {
+            return Ok(())
+        }
+
+        T::Currency::transfer(pool_account, member, reward, ExistenceRequirement::KeepAlive)?;
+        pool.recorded_rewards = pool.recorded_rewards.saturating_sub(reward);
+
+        Self::deposit_event(Event::PoolRewardPaid {
+            pool_id,
+            member: member.clone(),
+            amount: reward,
+        });
+
+        Ok(())
+    }
+
+    fn nominate_for_pool(
+        pool_account: &T::AccountId,
+        collator: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let candidate_nomination_count = <CandidateInfo<T>>::get(&collator)
+            .map(|c| c.nomination_count)
+            .unwrap_or_default();
+        let nomination_count = <NominatorState<T>>::get(pool_account)
+            .map(|state| state.nominations.0.len() as u32)
+            .unwrap_or_default();
+
+        Self::call_nominate(
+            pool_account,
+            collator,
+            amount,
+            candidate_nomination_count,
+            nomination_count,
+        )
+        .map(|_| ())
+        .map_err(|e| e.error)
+    }
+
+    /// Bonds `amount` to the pool collator with the smallest pool nomination. A collator the
+    /// pool does not nominate yet is only used if `amount` covers the minimum nomination.
+    fn bond_pool_stake(
+        pool_account: &T::AccountId,
+        pool: &PoolInfo<T>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let state = <NominatorState<T>>::get(pool_account);
+        let bonded = |collator: &T::AccountId| {
+            state.as_ref().and_then(|state| state.get_bond_amount(collator))
+        };
+
+        let mut collators = pool.collators.to_vec();
+        collators.sort_by_key(|collator| bonded(collator).unwrap_or_default());
+
+        for collator in collators {
+            let result = with_storage_layer(|| match bonded(&collator) {
+                Some(_) => Self::call_bond_extra(pool_account, collator.clone(), amount)
+                    .map(|_| ())
+                    .map_err(|e| e.error),
+                None => Self::nominate_for_pool(pool_account, collator.clone(), amount),
+            });
+
+            if result.is_ok() {
+                return Ok(())
+            }
+        }
+
+        Err(Error::<T>::NoPoolCollatorToBond.into())
+    }
+
+    /// Schedules a nomination request of the pool account for `amount`. Nominations to collators
+    /// the operator no longer selects are unbonded first, then the largest ones.
+    ///
+    /// Returns the collator the amount is unbonded from and the era it unlocks in.
+    fn schedule_pool_unbond(
+        pool_account: &T::AccountId,
+        pool: &PoolInfo<T>,
+        amount: BalanceOf<T>,
+    ) -> Result<(T::AccountId, EraIndex), DispatchError> {
+        let state =
+            <NominatorState<T>>::get(pool_account).ok_or(Error::<T>::NoPoolNominationToUnbond)?;
+        let unlock_era = <Era<T>>::get().current.saturating_add(<Delay<T>>::get());
+
+        let mut nominations = state.nominations.0.into_inner();
+        nominations.sort_by_key(|nomination| {
+            (pool.collators.contains(&nomination.owner), Reverse(nomination.amount))
+        });
+
+        for Bond { owner: collator, .. } in nominations {
+            let result =
+                with_storage_layer(|| Self::schedule_pool_request(pool_account, &collator, amount));
+
+            if result.is_ok() {
+                return Ok((collator, unlock_era))
+            }
+        }
+
+        Err(Error::<T>::NoPoolNominationToUnbond.into())
+    }
+
+    /// Executes the requests of earlier members towards `collator` that are due, then schedules
+    /// `amount` to be unbonded. Requests that unlock in the same era are merged.
+    fn schedule_pool_request(
+        pool_account: &T::AccountId,
+        collator: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let now = <Era<T>>::get().current;
+        let request_due = <NominationScheduledRequests<T>>::get(collator, pool_account)
+            .iter()
+            .any(|request| request.when_executable <= now);
+        if request_due {
+            Self::nomination_execute_scheduled_request(collator.clone(), pool_account.clone())
+                .map_err(|e| e.error)?;
+        }
+
+        let state = <NominatorState<T>>::get(pool_account).ok_or(Error::<T>::NominatorDNE)?;
+        let bonded = state.get_bond_amount(collator).ok_or(Error::<T>::NominationDNE)?;
+        let revoke = amount == bonded && !Self::nomination_request_exists(collator, pool_account);
+        let result = match revoke {
+            true => Self::nomination_schedule_revoke(collator.clone(), pool_account.clone()),
+            false => Self::nomination_schedule_bond_decrease(
+                collator.clone(),
+                pool_account.clone(),
+                amount,
+            ),
+        };
+
+        result.map(|_| ()).map_err(|e| e.error)
+    }
+}
@@ -34,6 +34,12 @@ pub const SIGNED_EXECUTE_NOMINATION_REQUESTS_CONTEXT: &'static [u8] =
     b"parachain authorization for executing nomination requests operation";
 pub const SIGNED_EXECUTE_CANDIDATE_UNBOND_CONTEXT: &'static [u8] =
     b"parachain authorization for executing candidate unbond operation";
+pub const SIGNED_SET_AUTO_COMPOUND_CONTEXT: &'static [u8] =
+    b"parachain authorization for setting auto compound operation";
+pub const SIGNED_CANDIDATE_REBOND_CONTEXT: &'static [u8] =
+    b"parachain authorization for candidate rebond operation";
+pub const SIGNED_NOMINATOR_REBOND_CONTEXT: &'static [u8] =
+    b"parachain authorization for nominator rebond operation";
 
 pub fn get_encoded_call_param<T: Config>(
     call: &<T as Config>::RuntimeCall,
@@ -144,6 +150,37 @@ pub fn get_encoded_call_param<T: Config>(
 
             return Some((proof, encoded_data))
         },
+        Call::signed_set_auto_compound { proof, candidate, value } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_set_auto_compound_params::<T>(
+                proof.relayer.clone(),
+                candidate,
+                value,
+                sender_nonce,
+            );
+
+            return Some((proof, encoded_data))
+        },
+        Call::signed_candidate_rebond { proof, amount } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_candidate_rebond_params::<T>(
+                proof.relayer.clone(),
+                amount,
+                sender_nonce,
+            );
+
+            return Some((proof, encoded_data))
+        },
+        Call::signed_nominator_rebond { proof, amount } => {
+            let sender_nonce = ParachainStaking::<T>::proxy_nonce(&proof.signer);
+            let encoded_data = encode_signed_nominator_rebond_params::<T>(
+                proof.relayer.clone(),
+                amount,
+                sender_nonce,
+            );
+
+            return Some((proof, encoded_data))
+        },
         _ => return None,
     }
 }
@@ -228,6 +265,31 @@ pub fn encode_signed_execute_candidate_unbond_params<T: Config>(
     return (SIGNED_EXECUTE_CANDIDATE_UNBOND_CONTEXT, relayer, candidate, sender_nonce).encode()
 }
 
+pub fn encode_signed_set_auto_compound_params<T: Config>(
+    relayer: T::AccountId,
+    candidate: &T::AccountId,
+    value: &Percent,
+    sender_nonce: u64,
+) -> Vec<u8> {
+    return (SIGNED_SET_AUTO_COMPOUND_CONTEXT, relayer, candidate, value, sender_nonce).encode()
+}
+
+pub fn encode_signed_candidate_rebond_params<T: Config>(
+    relayer: T::AccountId,
+    amount: &BalanceOf<T>,
+    sender_nonce: u64,
+) -> Vec<u8> {
+    return (SIGNED_CANDIDATE_REBOND_CONTEXT, relayer, amount, sender_nonce).encode()
+}
+
+pub fn encode_signed_nominator_rebond_params<T: Config>(
+    relayer: T::AccountId,
+    amount: &BalanceOf<T>,
+    sender_nonce: u64,
+) -> Vec<u8> {
+    return (SIGNED_NOMINATOR_REBOND_CONTEXT, relayer, amount, sender_nonce).encode()
+}
+
 impl<T: Config> InnerCallValidator for ParachainStaking<T> {
     type Call = <T as Config>::RuntimeCall;
 
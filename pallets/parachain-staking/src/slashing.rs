@@ -0,0 +1,230 @@
+//! Slashing of collators, and optionally their nominators, for offences reported through
+//! [`pallet_avn::Enforcer`].
+//!
+//! A reported slash is computed from the collator stake snapshot of the current era and applied
+//! `SlashDeferDuration` eras later, giving `SlashCancelOrigin` the chance to cancel it. The
+//! slashed funds are handed over to `Config::Slash`.
+
+use crate::*;
+use pallet_avn::Enforcer;
+
+impl<T: Config> Pallet<T> {
+    /// Computes the slash of `collator` for the current era and schedules it. If the collator
+    /// already has a slash due in the same era, the largest one is kept.
+    pub fn report_slash(collator: &T::AccountId, fraction: Perbill) -> DispatchResult {
+        if fraction.is_zero() {
+            return Ok(())
+        }
+
+        let now = <Era<T>>::get().current;
+        let slash = Self::compute_slash(now, collator, fraction)?;
+        let apply_era = now.saturating_add(T::SlashDeferDuration::get());
+
+        Self::deposit_event(Event::SlashReported {
+            collator: collator.clone(),
+            fraction,
+            apply_era,
+        });
+
+        if apply_era == now {
+            Self::apply_slash(collator, slash);
+            return Ok(())
+        }
+
+        <UnappliedSlashes<T>>::mutate(apply_era, collator, |maybe_slash| match maybe_slash {
+            Some(existing) if existing.own >= slash.own => (),
+            _ => *maybe_slash = Some(slash),
+        });
+
+        Ok(())
+    }
+
+    /// The slash of a collator that is not selected this era only covers its current self bond
+    fn compute_slash(
+        era: EraIndex,
+        collator: &T::AccountId,
+        fraction: Perbill,
+    ) -> Result<UnappliedSlash<T::AccountId, BalanceOf<T>>, DispatchError> {
+        let snapshot = match <AtStake<T>>::contains_key(era, collator) {
+            true => <AtStake<T>>::get(era, collator),
+            false => {
+                let state = <CandidateInfo<T>>::get(collator).ok_or(Error::<T>::CandidateDNE)?;
+                CollatorSnapshot { bond: state.bond, ..Default::default() }
+            },
+        };
+
+        let others = match T::SlashNominators::get() {
+            true => snapshot
+                .nominations
+                .into_iter()
+                .map(|nomination| Bond {
+                    owner: nomination.owner,
+                    amount: fraction * nomination.amount,
+                })
+                .filter(|nomination| !nomination.amount.is_zero())
+                .collect::<Vec<_>>(),
+            false => Vec::new(),
+        };
+
+        return Ok(UnappliedSlash {
+            own: fraction * snapshot.bond,
+            others: BoundedVec::truncate_from(others),
+        })
+    }
+
+    /// Applies all the slashes due in `era`. Returns the weight consumed.
+    pub(crate) fn apply_unapplied_slashes(era: EraIndex) -> Weight {
+        let mut weight = T::DbWeight::get().reads(1);
+
+        for (collator, slash) in <UnappliedSlashes<T>>::drain_prefix(era) {
+            weight = weight
+                .saturating_add(<T as Config>::WeightInfo::apply_slash(slash.others.len() as u32));
+            Self::apply_slash(&collator, slash);
+        }
+
+        return weight
+    }
+
+    pub(crate) fn apply_slash(
+        collator: &T::AccountId,
+        slash: UnappliedSlash<T::AccountId, BalanceOf<T>>,
+    ) {
+        Self::slash_collator_bond(collator, slash.own);
+
+        for nomination in slash.others {
+            Self::slash_nomination(&nomination.owner, collator, nomination.amount);
+        }
+    }
+
+    /// Reduces the self bond of `collator` by up to `amount`. A collator left with less than the
+    /// minimum stake goes offline. Pending unbond requests are trimmed to what is left.
+    fn slash_collator_bond(collator: &T::AccountId, amount: BalanceOf<T>) {
+        let Some(mut state) = <CandidateInfo<T>>::get(collator) else {
+            log::warn!("💔 Slashed collator {:?} is no longer a candidate", collator);
+            return
+        };
+
+        let amount = amount.min(state.bond);
+        if amount.is_zero() {
+            return
+        }
+
+        state.bond = state.bond.saturating_sub(amount);
+        state.total_counted = state.total_counted.saturating_sub(amount);
+        state.trim_unbond::<T>();
+        <Total<T>>::mutate(|total| *total = total.saturating_sub(amount));
+        T::Currency::set_lock(COLLATOR_LOCK_ID, collator, state.bond, WithdrawReasons::all());
+
+        if state.is_active() {
+            if state.bond < <MinCollatorStake<T>>::get() {
+                state.go_offline();
+                let mut candidates = <CandidatePool<T>>::get();
+                if candidates.remove(&Bond::from_owner(collator.clone())) {
+                    <CandidatePool<T>>::put(candidates);
+                }
+                Self::deposit_event(Event::CandidateWentOffline { candidate: collator.clone() });
+            } else {
+                Self::update_active(collator.clone(), state.total_counted);
+            }
+        }
+        <CandidateInfo<T>>::insert(collator, state);
+
+        Self::slash_staked_funds(collator, amount);
+    }
+
+    /// Reduces the nomination of `nominator` to `collator` by up to `amount`. A nomination slashed
+    /// in full is removed, otherwise its scheduled requests are trimmed to what is left. The
+    /// requests of a pool account are slashed pro-rata instead, so its unbonding members bear
+    /// their share of the slash.
+    fn slash_nomination(nominator: &T::AccountId, collator: &T::AccountId, amount: BalanceOf<T>) {
+        let Some(mut state) = <NominatorState<T>>::get(nominator) else { return };
+        let Some(bond) = state.get_bond_amount(collator) else { return };
+
+        let amount = amount.min(bond);
+        if amount.is_zero() {
+            return
+        }
+
+        if amount == bond {
+            if let Err(e) =
+                Self::nominator_leaves_candidate(collator.clone(), nominator.clone(), amount)
+            {
+                log::error!(
+                    "💔 Failed to remove slashed nomination of {:?} to {:?}: {:?}",
+                    nominator,
+                    collator,
+                    e
+                );
+                return
+            }
+
+            Self::slash_pool_requests(collator, nominator, &mut state, bond, amount);
+            Self::nomination_remove_request_with_state(collator, nominator, &mut state);
+            state.rm_nomination::<T>(collator);
+            if state.nominations.0.is_empty() {
+                <NominatorState<T>>::remove(nominator);
+                Self::deposit_event(Event::NominatorLeft {
+                    nominator: nominator.clone(),
+                    unstaked_amount: amount,
+                });
+            } else {
+                <NominatorState<T>>::insert(nominator, state);
+            }
+        } else {
+            let Some(mut collator_state) = <CandidateInfo<T>>::get(collator) else { return };
+            let before = collator_state.total_counted;
+            if let Err(e) =
+                collator_state.decrease_nomination::<T>(collator, nominator.clone(), bond, amount)
+            {
+                log::error!(
+                    "💔 Failed to decrease slashed nomination of {:?} to {:?}: {:?}",
+                    nominator,
+                    collator,
+                    e
+                );
+                return
+            }
+
+            let after = collator_state.total_counted;
+            if collator_state.is_active() && before != after {
+                Self::update_active(collator.clone(), after);
+            }
+            <CandidateInfo<T>>::insert(collator, collator_state);
+            <Total<T>>::mutate(|total| *total = total.saturating_sub(amount));
+
+            for nomination in &mut state.nominations.0 {
+                if &nomination.owner == collator {
+                    nomination.amount = nomination.amount.saturating_sub(amount);
+                }
+            }
+            state.total_sub::<T>(amount).expect("Decreasing lock cannot fail, qed");
+            if !Self::slash_pool_requests(collator, nominator, &mut state, bond, amount) {
+                Self::nomination_trim_requests(collator, nominator, &mut state, bond - amount);
+            }
+            <NominatorState<T>>::insert(nominator, state);
+        }
+
+        Self::slash_staked_funds(nominator, amount);
+    }
+
+    /// Removes `amount` from the balance of `who` and hands it over to the slash handler
+    fn slash_staked_funds(who: &T::AccountId, amount: BalanceOf<T>) {
+        let (imbalance, missing) = T::Currency::slash(who, amount);
+        if !missing.is_zero() {
+            log::warn!("💔 Could not slash {:?} from {:?}", missing, who);
+        }
+
+        let slashed = imbalance.peek();
+        T::Slash::on_unbalanced(imbalance);
+        Self::deposit_event(Event::Slashed { account: who.clone(), amount: slashed });
+    }
+}
+
+impl<T: Config> Enforcer<T::AccountId> for Pallet<T> {
+    fn slash_validator(
+        slashed_validator_id: &T::AccountId,
+        slash_fraction: Perbill,
+    ) -> DispatchResult {
+        return Self::report_slash(slashed_validator_id, slash_fraction)
+    }
+}
@@ -0,0 +1,179 @@
+//! Read-only views of the staking state, used by the `ParachainStakingApi` runtime API so that
+//! clients don't have to reconstruct rewards from events.
+
+use crate::*;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::SaturatedConversion, FixedPointNumber, FixedU128, RuntimeDebug};
+
+/// The stake and reward points of a collator selected for an era
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateEraInfo<AccountId, Balance> {
+    pub candidate: AccountId,
+    /// The self bond of the collator
+    pub bond: Balance,
+    /// The self bond plus the counted nominations of the collator
+    pub total: Balance,
+    pub commission: Perbill,
+    pub points: RewardPoint,
+}
+
+/// The part of a collator's era stake that belongs to a nominator
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominatorEraShare<AccountId, Balance> {
+    pub candidate: AccountId,
+    pub amount: Balance,
+    /// The share of the collator's total stake, and therefore of the reward left after commission
+    pub share: Perbill,
+    /// The reward due to the nominator, once the era payout has been computed
+    pub reward: Option<Balance>,
+}
+
+/// An era reward that has been computed but not fully paid out yet
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingPayout<Balance> {
+    pub era: EraIndex,
+    pub total_staking_reward: Balance,
+    pub total_points: RewardPoint,
+}
+
+/// A scheduled nomination request and the era in which it can be executed
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominationUnlock<AccountId, Balance> {
+    pub candidate: AccountId,
+    pub action: NominationAction<Balance>,
+    pub unlock_era: EraIndex,
+}
+
+/// The projected yearly return on a nomination to a collator
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CandidateApy<AccountId> {
+    pub candidate: AccountId,
+    pub apy: FixedU128,
+}
+
+impl<T: Config> Pallet<T> {
+    /// The stake snapshot and points of every collator selected for `era`. Snapshots are removed
+    /// once the collator has been paid for the era.
+    pub fn candidate_era_info(era: EraIndex) -> Vec<CandidateEraInfo<T::AccountId, BalanceOf<T>>> {
+        return <AtStake<T>>::iter_prefix(era)
+            .map(|(candidate, snapshot)| CandidateEraInfo {
+                points: <AwardedPts<T>>::get(era, &candidate),
+                candidate,
+                bond: snapshot.bond,
+                total: snapshot.total,
+                commission: snapshot.commission,
+            })
+            .collect()
+    }
+
+    /// The share of `nominator` in each collator it backed in `era`. The reward is only known
+    /// once the payout of the era has been computed.
+    pub fn nominator_era_shares(
+        nominator: &T::AccountId,
+        era: EraIndex,
+    ) -> Vec<NominatorEraShare<T::AccountId, BalanceOf<T>>> {
+        let payout = <DelayedPayouts<T>>::get(era);
+        let total_points = <Points<T>>::get(era);
+
+        return <AtStake<T>>::iter_prefix(era)
+            .filter_map(|(candidate, snapshot)| {
+                let amount = snapshot
+                    .nominations
+                    .iter()
+                    .find(|nomination| &nomination.owner == nominator)
+                    .map(|nomination| nomination.amount)?;
+                let share = Perbill::from_rational(amount, snapshot.total);
+
+                let reward = payout.as_ref().filter(|_| !total_points.is_zero()).map(|payout| {
+                    let points = <AwardedPts<T>>::get(era, &candidate);
+                    let collator_reward =
+                        Perbill::from_rational(points, total_points) * payout.total_staking_reward;
+                    share * collator_reward.saturating_sub(snapshot.commission * collator_reward)
+                });
+
+                Some(NominatorEraShare { candidate, amount, share, reward })
+            })
+            .collect()
+    }
+
+    /// The era rewards that are still being paid out
+    pub fn pending_payouts() -> Vec<PendingPayout<BalanceOf<T>>> {
+        return <DelayedPayouts<T>>::iter()
+            .map(|(era, payout)| PendingPayout {
+                era,
+                total_staking_reward: payout.total_staking_reward,
+                total_points: <Points<T>>::get(era),
+            })
+            .collect()
+    }
+
+    /// The scheduled requests of `nominator` across all the collators it nominates
+    pub fn nomination_unlocks(
+        nominator: &T::AccountId,
+    ) -> Vec<NominationUnlock<T::AccountId, BalanceOf<T>>> {
+        let Some(state) = <NominatorState<T>>::get(nominator) else { return Vec::new() };
+
+        return state
+            .nominations
+            .0
+            .into_iter()
+            .flat_map(|nomination| {
+                <NominationScheduledRequests<T>>::get(&nomination.owner, nominator)
+                    .into_iter()
+                    .map(move |request| NominationUnlock {
+                        candidate: nomination.owner.clone(),
+                        action: request.action,
+                        unlock_era: request.when_executable,
+                    })
+            })
+            .collect()
+    }
+
+    /// Projects the yearly return on a nomination to each collator selected for the current era.
+    ///
+    /// The era reward is what `compute_total_reward_to_pay` would return now, and it is shared
+    /// between collators by the points awarded so far in the era, or evenly if none have been
+    /// awarded yet. The collator commission is deducted from the reward.
+    pub fn projected_apys(blocks_per_year: u32) -> Vec<CandidateApy<T::AccountId>> {
+        let era = <Era<T>>::get();
+        let eras_per_year = blocks_per_year / era.length.max(1);
+        let era_reward = Self::reward_pot().saturating_sub(<LockedEraPayout<T>>::get());
+        let selected = <SelectedCandidates<T>>::get();
+        let total_points = <Points<T>>::get(era.current);
+
+        return selected
+            .iter()
+            .map(|candidate| {
+                let snapshot = <AtStake<T>>::get(era.current, candidate);
+                let points_share = match total_points.is_zero() {
+                    true => Perbill::from_rational(1, selected.len() as u32),
+                    false => Perbill::from_rational(
+                        <AwardedPts<T>>::get(era.current, candidate),
+                        total_points,
+                    ),
+                };
+
+                let collator_reward = points_share * era_reward;
+                let yearly_reward = collator_reward
+                    .saturating_sub(snapshot.commission * collator_reward)
+                    .saturated_into::<u128>()
+                    .saturating_mul(eras_per_year.into());
+                let apy = FixedU128::checked_from_rational(
+                    yearly_reward,
+                    snapshot.total.saturated_into::<u128>(),
+                )
+                .unwrap_or_default();
+
+                CandidateApy { candidate: candidate.clone(), apy }
+            })
+            .collect()
+    }
+}
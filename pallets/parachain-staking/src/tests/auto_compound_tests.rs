@@ -0,0 +1,360 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, assert_event_not_emitted, assert_last_event,
+    encode_signed_set_auto_compound_params,
+    mock::{
+        build_proof, roll_to_era_begin, set_author, set_reward_pot, sign, AccountId, AvnProxy,
+        ExtBuilder, ParachainStaking, RuntimeCall as MockCall, RuntimeEvent as MetaEvent,
+        RuntimeOrigin, Signature, Staker, Test, TestAccount,
+    },
+    Config, Error, Event, Proof,
+};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::{Perbill, Percent};
+
+const COLLATOR_STAKE: u128 = 1000;
+const NOMINATOR_STAKE: u128 = 500;
+const TOTAL_STAKE: u128 = COLLATOR_STAKE + NOMINATOR_STAKE;
+const REWARD_POT: u128 = 3000;
+
+fn collator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn other_collator() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn build_ext(nominator: &Staker) -> sp_io::TestExternalities {
+    return ExtBuilder::default()
+        .with_balances(vec![
+            (collator(), 10000),
+            (other_collator(), 10000),
+            (nominator.account_id, 10000),
+            (nominator.relayer, 10000),
+        ])
+        .with_candidates(vec![(collator(), COLLATOR_STAKE), (other_collator(), COLLATOR_STAKE)])
+        .with_nominations(vec![(nominator.account_id, collator(), NOMINATOR_STAKE)])
+        .build()
+}
+
+fn collator_reward() -> u128 {
+    return Perbill::from_rational(COLLATOR_STAKE, TOTAL_STAKE) * REWARD_POT
+}
+
+fn nominator_reward() -> u128 {
+    return Perbill::from_rational(NOMINATOR_STAKE, TOTAL_STAKE) * REWARD_POT
+}
+
+fn pay_rewards_for_era_1() {
+    set_author(1, collator(), 1);
+    set_reward_pot(REWARD_POT);
+    roll_to_era_begin((1 + <Test as Config>::RewardPaymentDelay::get()) as u64);
+}
+
+mod set_auto_compound {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn a_nominator_sets_a_preference() {
+            let nominator: Staker = Default::default();
+            build_ext(&nominator).execute_with(|| {
+                let value = Percent::from_percent(50);
+
+                assert_ok!(ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(nominator.account_id),
+                    collator(),
+                    value
+                ));
+
+                assert_eq!(
+                    ParachainStaking::auto_compound(collator(), nominator.account_id),
+                    value
+                );
+                assert_last_event!(MetaEvent::ParachainStaking(Event::AutoCompoundSet {
+                    candidate: collator(),
+                    account: nominator.account_id,
+                    value
+                }));
+            });
+        }
+
+        #[test]
+        fn a_candidate_sets_a_preference() {
+            let nominator: Staker = Default::default();
+            build_ext(&nominator).execute_with(|| {
+                let value = Percent::from_percent(100);
+
+                assert_ok!(ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(collator()),
+                    collator(),
+                    value
+                ));
+
+                assert_eq!(ParachainStaking::auto_compound(collator(), collator()), value);
+            });
+        }
+
+        #[test]
+        fn a_preference_is_removed() {
+            let nominator: Staker = Default::default();
+            build_ext(&nominator).execute_with(|| {
+                assert_ok!(ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(nominator.account_id),
+                    collator(),
+                    Percent::from_percent(50)
+                ));
+
+                assert_ok!(ParachainStaking::set_auto_compound(
+                    RuntimeOrigin::signed(nominator.account_id),
+                    collator(),
+                    Percent::zero()
+                ));
+
+                assert!(!crate::AutoCompound::<Test>::contains_key(
+                    collator(),
+                    nominator.account_id
+                ));
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn the_nominator_does_not_nominate_the_candidate() {
+            let nominator: Staker = Default::default();
+            build_ext(&nominator).execute_with(|| {
+                assert_noop!(
+                    ParachainStaking::set_auto_compound(
+                        RuntimeOrigin::signed(nominator.account_id),
+                        other_collator(),
+                        Percent::from_percent(50)
+                    ),
+                    Error::<Test>::NominationDNE
+                );
+            });
+        }
+
+        #[test]
+        fn the_sender_is_not_a_nominator() {
+            let nominator: Staker = Default::default();
+            build_ext(&nominator).execute_with(|| {
+                assert_noop!(
+                    ParachainStaking::set_auto_compound(
+                        RuntimeOrigin::signed(nominator.relayer),
+                        collator(),
+                        Percent::from_percent(50)
+                    ),
+                    Error::<Test>::NominatorDNE
+                );
+            });
+        }
+
+        #[test]
+        fn the_sender_is_not_a_candidate() {
+            let nominator: Staker = Default::default();
+            build_ext(&nominator).execute_with(|| {
+                assert_noop!(
+                    ParachainStaking::set_auto_compound(
+                        RuntimeOrigin::signed(nominator.account_id),
+                        nominator.account_id,
+                        Percent::from_percent(50)
+                    ),
+                    Error::<Test>::CandidateDNE
+                );
+            });
+        }
+    }
+}
+
+mod proxy_signed_set_auto_compound {
+    use super::*;
+
+    fn create_proof_for_signed_set_auto_compound(
+        sender_nonce: u64,
+        staker: &Staker,
+        candidate: &AccountId,
+        value: &Percent,
+    ) -> Proof<Signature, AccountId> {
+        let data_to_sign = encode_signed_set_auto_compound_params::<Test>(
+            staker.relayer.clone(),
+            candidate,
+            value,
+            sender_nonce,
+        );
+
+        let signature = sign(&staker.key_pair, &data_to_sign);
+        return build_proof(&staker.account_id, &staker.relayer, signature)
+    }
+
+    fn create_call_for_signed_set_auto_compound(
+        proof: Proof<Signature, AccountId>,
+        value: Percent,
+    ) -> Box<<Test as Config>::RuntimeCall> {
+        return Box::new(MockCall::ParachainStaking(
+            super::super::Call::<Test>::signed_set_auto_compound {
+                proof,
+                candidate: collator(),
+                value,
+            },
+        ))
+    }
+
+    #[test]
+    fn succeeds_with_good_values() {
+        let nominator: Staker = Default::default();
+        build_ext(&nominator).execute_with(|| {
+            let value = Percent::from_percent(50);
+            let nonce = ParachainStaking::proxy_nonce(nominator.account_id);
+            let proof =
+                create_proof_for_signed_set_auto_compound(nonce, &nominator, &collator(), &value);
+
+            assert_ok!(AvnProxy::proxy(
+                RuntimeOrigin::signed(nominator.relayer),
+                create_call_for_signed_set_auto_compound(proof, value),
+                None
+            ));
+
+            assert_eq!(ParachainStaking::auto_compound(collator(), nominator.account_id), value);
+            assert_eq!(ParachainStaking::proxy_nonce(nominator.account_id), nonce + 1);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_proof_is_for_a_different_value() {
+        let nominator: Staker = Default::default();
+        build_ext(&nominator).execute_with(|| {
+            let nonce = ParachainStaking::proxy_nonce(nominator.account_id);
+            let proof = create_proof_for_signed_set_auto_compound(
+                nonce,
+                &nominator,
+                &collator(),
+                &Percent::from_percent(10),
+            );
+
+            assert_noop!(
+                ParachainStaking::signed_set_auto_compound(
+                    RuntimeOrigin::signed(nominator.account_id),
+                    proof,
+                    collator(),
+                    Percent::from_percent(50)
+                ),
+                Error::<Test>::UnauthorizedSignedSetAutoCompoundTransaction
+            );
+        });
+    }
+}
+
+mod reward_payout {
+    use super::*;
+
+    #[test]
+    fn compounds_the_nominator_reward() {
+        let nominator: Staker = Default::default();
+        build_ext(&nominator).execute_with(|| {
+            let value = Percent::from_percent(50);
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(nominator.account_id),
+                collator(),
+                value
+            ));
+
+            pay_rewards_for_era_1();
+
+            let compounded = value * nominator_reward();
+            assert_event_emitted!(Event::Rewarded {
+                account: nominator.account_id,
+                rewards: nominator_reward()
+            });
+            assert_event_emitted!(Event::Compounded {
+                candidate: collator(),
+                account: nominator.account_id,
+                amount: compounded
+            });
+            assert_eq!(
+                ParachainStaking::nominator_state(nominator.account_id).unwrap().total,
+                NOMINATOR_STAKE + compounded
+            );
+            assert_eq!(
+                ParachainStaking::candidate_info(collator()).unwrap().total_counted,
+                TOTAL_STAKE + compounded
+            );
+        });
+    }
+
+    #[test]
+    fn compounds_the_collator_reward() {
+        let nominator: Staker = Default::default();
+        build_ext(&nominator).execute_with(|| {
+            assert_ok!(ParachainStaking::set_auto_compound(
+                RuntimeOrigin::signed(collator()),
+                collator(),
+                Percent::from_percent(100)
+            ));
+
+            pay_rewards_for_era_1();
+
+            assert_event_emitted!(Event::Compounded {
+                candidate: collator(),
+                account: collator(),
+                amount: collator_reward()
+            });
+            assert_eq!(
+                ParachainStaking::candidate_info(collator()).unwrap().bond,
+                COLLATOR_STAKE + collator_reward()
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_compound_without_a_preference() {
+        let nominator: Staker = Default::default();
+        build_ext(&nominator).execute_with(|| {
+            pay_rewards_for_era_1();
+
+            assert_event_not_emitted!(Event::Compounded {
+                candidate: collator(),
+                account: nominator.account_id,
+                amount: nominator_reward()
+            });
+            assert_eq!(
+                ParachainStaking::nominator_state(nominator.account_id).unwrap().total,
+                NOMINATOR_STAKE
+            );
+            assert_eq!(ParachainStaking::candidate_info(collator()).unwrap().bond, COLLATOR_STAKE);
+        });
+    }
+}
+
+#[test]
+fn revoking_a_nomination_removes_the_preference() {
+    let nominator: Staker = Default::default();
+    build_ext(&nominator).execute_with(|| {
+        assert_ok!(ParachainStaking::set_auto_compound(
+            RuntimeOrigin::signed(nominator.account_id),
+            collator(),
+            Percent::from_percent(50)
+        ));
+        assert_ok!(ParachainStaking::schedule_revoke_nomination(
+            RuntimeOrigin::signed(nominator.account_id),
+            collator()
+        ));
+        roll_to_era_begin((1 + ParachainStaking::delay()) as u64);
+
+        assert_ok!(ParachainStaking::execute_nomination_request(
+            RuntimeOrigin::signed(nominator.account_id),
+            nominator.account_id,
+            collator()
+        ));
+
+        assert!(!crate::AutoCompound::<Test>::contains_key(collator(), nominator.account_id));
+    });
+}
@@ -185,6 +185,11 @@ parameter_types! {
     pub const ErasPerGrowthPeriod: u32 = 2;
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
     pub const MaxCandidates:u32 = 100;
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
+    pub const CommissionIncreaseDelay: u32 = 2;
+    pub static SlashDeferDuration: u32 = 2;
+    pub static SlashNominators: bool = true;
+    pub const MaxPoolUnbondingChunks: u32 = 4;
 }
 
 pub struct IsRegistered;
@@ -234,6 +239,13 @@ impl Config for Test {
     type AccountToBytesConvert = Avn;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = TestGrowthEnabled;
+    type MaxCommission = MaxCommission;
+    type CommissionIncreaseDelay = CommissionIncreaseDelay;
+    type Slash = ();
+    type SlashDeferDuration = SlashDeferDuration;
+    type SlashNominators = SlashNominators;
+    type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxPoolUnbondingChunks = MaxPoolUnbondingChunks;
 }
 
 // Deal with any positive imbalance by sending it to the fake treasury
@@ -442,6 +454,15 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for TestAvnProxyConfig {
             RuntimeCall::ParachainStaking(
                 pallet_parachain_staking::Call::signed_schedule_leave_nominators { proof },
             ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_set_auto_compound { proof, .. },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_candidate_rebond { proof, .. },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_nominator_rebond { proof, .. },
+            ) => return Some(proof.clone()),
             RuntimeCall::ParachainStaking(
                 pallet_parachain_staking::Call::signed_execute_leave_nominators {
                     proof,
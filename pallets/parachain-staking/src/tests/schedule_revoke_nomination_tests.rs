@@ -1047,14 +1047,14 @@ fn execute_revoke_nomination_adds_revocation_to_nominator_state() {
         .with_nominations(vec![(account_id_2, account_id, 10), (account_id_2, to_acc_id(3), 10)])
         .build()
         .execute_with(|| {
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                 .iter()
                 .any(|x| x.nominator == account_id_2));
             assert_ok!(ParachainStaking::schedule_revoke_nomination(
                 RuntimeOrigin::signed(account_id_2),
                 account_id
             ));
-            assert!(ParachainStaking::nomination_scheduled_requests(&account_id)
+            assert!(ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                 .iter()
                 .any(|x| x.nominator == account_id_2));
         });
@@ -1080,7 +1080,7 @@ fn execute_revoke_nomination_removes_revocation_from_nominator_state_upon_execut
                 account_id_2,
                 account_id
             ));
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                 .iter()
                 .any(|x| x.nominator == account_id_2));
         });
@@ -1107,7 +1107,7 @@ fn execute_revoke_nomination_removes_revocation_from_state_for_single_nomination
                 account_id
             ));
             assert!(
-                !ParachainStaking::nomination_scheduled_requests(&account_id)
+                !ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                     .iter()
                     .any(|x| x.nominator == account_id_2),
                 "nomination was not removed"
@@ -13,7 +13,7 @@ use crate::{
     },
     Bond, Config, Error, Event, NominationAction, Proof, ScheduledRequest,
 };
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::Get};
 use frame_system::RawOrigin;
 use pallet_avn_proxy::Error as avn_proxy_error;
 use std::cell::RefCell;
@@ -1189,7 +1189,7 @@ fn nominator_unbond_updates_nominator_state() {
                 account_id,
                 5
             ));
-            let state = ParachainStaking::nomination_scheduled_requests(&account_id);
+            let state = ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2);
             assert_eq!(
                 state,
                 vec![ScheduledRequest {
@@ -1397,7 +1397,7 @@ fn schedule_candidate_unbond_event_emits_correctly() {
 }
 
 #[test]
-fn cannot_schedule_candidate_unbond_if_request_exists() {
+fn cannot_schedule_candidate_unbond_below_min_with_existing_request() {
     let account_id = to_acc_id(1u64);
     ExtBuilder::default()
         .with_balances(vec![(account_id, 30)])
@@ -1406,11 +1406,11 @@ fn cannot_schedule_candidate_unbond_if_request_exists() {
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_candidate_unbond(
                 RuntimeOrigin::signed(account_id),
-                5
+                15
             ));
             assert_noop!(
-                ParachainStaking::schedule_candidate_unbond(RuntimeOrigin::signed(account_id), 5),
-                Error::<Test>::PendingCandidateRequestAlreadyExists
+                ParachainStaking::schedule_candidate_unbond(RuntimeOrigin::signed(account_id), 6),
+                Error::<Test>::CandidateBondBelowMin
             );
         });
 }
@@ -1916,3 +1916,40 @@ fn can_execute_nominator_unbond_for_leaving_candidate() {
             ));
         });
 }
+
+#[test]
+fn requests_towards_a_collator_are_not_capped_by_nominations_per_nominator() {
+    let collator = to_acc_id(1u64);
+    let nominators: Vec<AccountId> = (2..=7).map(to_acc_id).collect();
+    ExtBuilder::default()
+        .with_balances(get_accounts(7, 30, None))
+        .with_candidates(vec![(collator, 30)])
+        .with_nominations(nominators.iter().map(|nominator| (*nominator, collator, 20)).collect())
+        .build()
+        .execute_with(|| {
+            // each nominator schedules requests in two eras, exceeding
+            // MaxNominationsPerNominator requests towards the collator in total
+            for era in 1..=2 {
+                roll_to_era_begin(era);
+                for nominator in &nominators {
+                    assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                        RuntimeOrigin::signed(*nominator),
+                        collator,
+                        1
+                    ));
+                }
+            }
+
+            let total_requests: usize = nominators
+                .iter()
+                .map(|nominator| {
+                    ParachainStaking::nomination_scheduled_requests(&collator, nominator).len()
+                })
+                .sum();
+            assert_eq!(total_requests, 2 * nominators.len());
+            assert!(
+                total_requests >
+                    <<Test as Config>::MaxNominationsPerNominator as Get<u32>>::get() as usize
+            );
+        });
+}
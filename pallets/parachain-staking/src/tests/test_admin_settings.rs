@@ -154,3 +154,27 @@ mod min_collator_stake_admin_setting {
         });
     }
 }
+
+mod max_missed_slot_rate_admin_setting {
+    use super::*;
+    use crate::MaxMissedSlotRate;
+    use sp_runtime::Perbill;
+
+    #[test]
+    fn can_be_updated() {
+        ExtBuilder::default().build().execute_with(|| {
+            let new_rate = Perbill::from_percent(20);
+            let new_rate_setting = AdminSettings::<BalanceOf<Test>>::MaxMissedSlotRate(new_rate);
+
+            assert_ok!(ParachainStaking::set_admin_setting(
+                Origin::root(),
+                new_rate_setting.clone()
+            ));
+
+            assert_eq!(<MaxMissedSlotRate<Test>>::get(), Some(new_rate));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::AdminSettingsUpdated {
+                value: new_rate_setting
+            }));
+        });
+    }
+}
@@ -0,0 +1,263 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, assert_event_not_emitted, assert_last_event,
+    mock::{
+        roll_to_era_begin, set_author, set_reward_pot, AccountId, Balances, ExtBuilder,
+        MaxCommission, ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin, Test,
+        TestAccount,
+    },
+    CommissionChangeRequest, Config, Error, Event,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Get},
+};
+use sp_runtime::Perbill;
+
+const COLLATOR_STAKE: u128 = 1000;
+const NOMINATOR_STAKE: u128 = 500;
+const TOTAL_STAKE: u128 = COLLATOR_STAKE + NOMINATOR_STAKE;
+const REWARD_POT: u128 = 3000;
+
+fn collator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn commission_delay() -> u32 {
+    return <Test as Config>::CommissionIncreaseDelay::get()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    return ExtBuilder::default()
+        .with_balances(vec![(collator(), 10000), (nominator(), 10000)])
+        .with_candidates(vec![(collator(), COLLATOR_STAKE)])
+        .with_nominations(vec![(nominator(), collator(), NOMINATOR_STAKE)])
+        .build()
+}
+
+mod set_commission {
+    use super::*;
+
+    mod succeeds_when {
+        use super::*;
+
+        #[test]
+        fn commission_is_increased() {
+            build_ext().execute_with(|| {
+                let commission = Perbill::from_percent(10);
+                let execute_era = 1 + commission_delay();
+
+                assert_ok!(ParachainStaking::set_commission(
+                    RuntimeOrigin::signed(collator()),
+                    commission
+                ));
+
+                let state = ParachainStaking::candidate_info(&collator()).unwrap();
+                assert_eq!(state.commission, Perbill::zero());
+                assert_eq!(
+                    state.commission_request,
+                    Some(CommissionChangeRequest { commission, when_executable: execute_era })
+                );
+                assert_last_event!(MetaEvent::ParachainStaking(
+                    Event::CommissionIncreaseScheduled {
+                        candidate: collator(),
+                        commission,
+                        execute_era
+                    }
+                ));
+            });
+        }
+
+        #[test]
+        fn commission_is_decreased() {
+            build_ext().execute_with(|| {
+                assert_ok!(ParachainStaking::set_commission(
+                    RuntimeOrigin::signed(collator()),
+                    Perbill::from_percent(10)
+                ));
+                roll_to_era_begin(1 + commission_delay() as u64);
+
+                let commission = Perbill::from_percent(5);
+                assert_ok!(ParachainStaking::set_commission(
+                    RuntimeOrigin::signed(collator()),
+                    commission
+                ));
+
+                let state = ParachainStaking::candidate_info(&collator()).unwrap();
+                assert_eq!(state.commission, commission);
+                assert_eq!(state.commission_request, None);
+                assert_last_event!(MetaEvent::ParachainStaking(Event::CommissionSet {
+                    candidate: collator(),
+                    commission
+                }));
+            });
+        }
+
+        #[test]
+        fn commission_is_decreased_while_an_increase_is_pending() {
+            build_ext().execute_with(|| {
+                assert_ok!(ParachainStaking::set_commission(
+                    RuntimeOrigin::signed(collator()),
+                    Perbill::from_percent(10)
+                ));
+
+                assert_ok!(ParachainStaking::set_commission(
+                    RuntimeOrigin::signed(collator()),
+                    Perbill::zero()
+                ));
+
+                let state = ParachainStaking::candidate_info(&collator()).unwrap();
+                assert_eq!(state.commission, Perbill::zero());
+                assert_eq!(state.commission_request, None);
+            });
+        }
+    }
+
+    mod fails_when {
+        use super::*;
+
+        #[test]
+        fn commission_is_above_the_maximum() {
+            build_ext().execute_with(|| {
+                let commission = MaxCommission::get() + Perbill::from_percent(1);
+
+                assert_noop!(
+                    ParachainStaking::set_commission(RuntimeOrigin::signed(collator()), commission),
+                    Error::<Test>::CommissionTooHigh
+                );
+            });
+        }
+
+        #[test]
+        fn sender_is_not_a_candidate() {
+            build_ext().execute_with(|| {
+                assert_noop!(
+                    ParachainStaking::set_commission(
+                        RuntimeOrigin::signed(nominator()),
+                        Perbill::from_percent(10)
+                    ),
+                    Error::<Test>::CandidateDNE
+                );
+            });
+        }
+    }
+}
+
+mod commission_increase {
+    use super::*;
+
+    #[test]
+    fn is_not_applied_before_the_delay() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::set_commission(
+                RuntimeOrigin::signed(collator()),
+                Perbill::from_percent(10)
+            ));
+
+            roll_to_era_begin(commission_delay() as u64);
+
+            let era = commission_delay();
+            assert_eq!(ParachainStaking::at_stake(era, collator()).commission, Perbill::zero());
+        });
+    }
+
+    #[test]
+    fn is_applied_to_the_snapshot_after_the_delay() {
+        build_ext().execute_with(|| {
+            let commission = Perbill::from_percent(10);
+            assert_ok!(ParachainStaking::set_commission(
+                RuntimeOrigin::signed(collator()),
+                commission
+            ));
+
+            let execute_era = 1 + commission_delay();
+            roll_to_era_begin(execute_era as u64);
+
+            assert_eq!(ParachainStaking::at_stake(execute_era, collator()).commission, commission);
+        });
+    }
+}
+
+mod reward_payout {
+    use super::*;
+
+    fn pay_rewards_for_era(era: u32) {
+        set_author(era, collator(), 1);
+        set_reward_pot(REWARD_POT);
+        roll_to_era_begin((era + <Test as Config>::RewardPaymentDelay::get()) as u64);
+    }
+
+    #[test]
+    fn takes_the_commission_before_the_pro_rata_split() {
+        build_ext().execute_with(|| {
+            let commission = Perbill::from_percent(10);
+            assert_ok!(ParachainStaking::set_commission(
+                RuntimeOrigin::signed(collator()),
+                commission
+            ));
+            let era = 1 + commission_delay();
+            roll_to_era_begin(era as u64);
+
+            let collator_balance_before = Balances::free_balance(&collator());
+            let nominator_balance_before = Balances::free_balance(&nominator());
+            pay_rewards_for_era(era);
+
+            let expected_commission = commission * REWARD_POT;
+            let reward_to_split = REWARD_POT - expected_commission;
+            let expected_collator_reward = expected_commission +
+                Perbill::from_rational(COLLATOR_STAKE, TOTAL_STAKE) * reward_to_split;
+            let expected_nominator_reward =
+                Perbill::from_rational(NOMINATOR_STAKE, TOTAL_STAKE) * reward_to_split;
+
+            assert_event_emitted!(Event::CommissionPaid {
+                account: collator(),
+                era,
+                amount: expected_commission
+            });
+            assert_event_emitted!(Event::Rewarded {
+                account: collator(),
+                rewards: expected_collator_reward
+            });
+            assert_event_emitted!(Event::Rewarded {
+                account: nominator(),
+                rewards: expected_nominator_reward
+            });
+            assert_eq!(
+                Balances::free_balance(&collator()),
+                collator_balance_before + expected_collator_reward
+            );
+            assert_eq!(
+                Balances::free_balance(&nominator()),
+                nominator_balance_before + expected_nominator_reward
+            );
+        });
+    }
+
+    #[test]
+    fn does_not_pay_a_commission_when_it_is_zero() {
+        build_ext().execute_with(|| {
+            let era = 1;
+            pay_rewards_for_era(era);
+
+            let expected_collator_reward =
+                Perbill::from_rational(COLLATOR_STAKE, TOTAL_STAKE) * REWARD_POT;
+
+            assert_event_emitted!(Event::Rewarded {
+                account: collator(),
+                rewards: expected_collator_reward
+            });
+            assert_event_not_emitted!(Event::CommissionPaid {
+                account: collator(),
+                era,
+                amount: 0
+            });
+        });
+    }
+}
@@ -0,0 +1,168 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted,
+    mock::{
+        events, roll_to_era_begin, set_author, AccountId, ExtBuilder, ParachainStaking,
+        RuntimeOrigin, Test, TestAccount,
+    },
+    AdminSettings, AuthoredBlocks, BalanceOf, Event, Points, SlotPerformance,
+};
+use frame_support::assert_ok;
+use pallet_authorship::EventHandler;
+use sp_runtime::Perbill;
+
+const INITIAL_BALANCE: u128 = 10000;
+const ERA: u32 = 1;
+
+fn candidate(n: u64) -> AccountId {
+    return TestAccount::new(n).account_id()
+}
+
+/// Candidates compete for five seats, candidate 1 having the most stake
+fn build_ext(candidate_count: u64) -> sp_io::TestExternalities {
+    let candidates = (1..=candidate_count).map(|n| (candidate(n), 1000 - n as u128 * 100));
+    return ExtBuilder::default()
+        .with_balances((1..=candidate_count).map(|n| (candidate(n), INITIAL_BALANCE)).collect())
+        .with_candidates(candidates.collect())
+        .build()
+}
+
+fn set_max_missed_slot_rate(rate: Perbill) {
+    assert_ok!(ParachainStaking::set_admin_setting(
+        RuntimeOrigin::root(),
+        AdminSettings::<BalanceOf<Test>>::MaxMissedSlotRate(rate)
+    ));
+}
+
+/// Each of the first five candidates is expected to author one block in an era. Candidate 5
+/// authors none.
+fn author_blocks_except_candidate_5() {
+    for n in 1..=4 {
+        ParachainStaking::note_author(candidate(n));
+    }
+}
+
+#[test]
+fn authored_blocks_are_counted() {
+    build_ext(6).execute_with(|| {
+        ParachainStaking::note_author(candidate(1));
+        ParachainStaking::note_author(candidate(1));
+
+        assert_eq!(<AuthoredBlocks<Test>>::get(ERA, candidate(1)), 2);
+    });
+}
+
+mod without_max_missed_slot_rate {
+    use super::*;
+
+    #[test]
+    fn performance_is_recorded() {
+        build_ext(6).execute_with(|| {
+            author_blocks_except_candidate_5();
+
+            roll_to_era_begin(2);
+
+            assert_eq!(
+                ParachainStaking::collator_performance(candidate(5)),
+                Some(SlotPerformance { era: ERA, expected_slots: 1, authored_slots: 0 })
+            );
+            assert_eq!(
+                ParachainStaking::collator_performance(candidate(1)),
+                Some(SlotPerformance { era: ERA, expected_slots: 1, authored_slots: 1 })
+            );
+            assert_eq!(<AuthoredBlocks<Test>>::iter_prefix(ERA).count(), 0);
+        });
+    }
+
+    #[test]
+    fn points_and_selection_are_unchanged() {
+        build_ext(6).execute_with(|| {
+            author_blocks_except_candidate_5();
+            set_author(ERA, candidate(5), 20);
+
+            roll_to_era_begin(2);
+
+            assert_eq!(ParachainStaking::awarded_pts(ERA, candidate(5)), 20);
+            assert!(ParachainStaking::is_selected_candidate(&candidate(5)));
+            assert!(!ParachainStaking::is_selected_candidate(&candidate(6)));
+            assert!(!events().iter().any(|e| matches!(
+                e,
+                Event::CollatorMissedSlots { .. } | Event::CandidateDeprioritised { .. }
+            )));
+        });
+    }
+}
+
+mod with_max_missed_slot_rate {
+    use super::*;
+
+    #[test]
+    fn scales_the_points_of_collators_that_missed_slots() {
+        build_ext(6).execute_with(|| {
+            set_max_missed_slot_rate(Perbill::from_percent(50));
+            author_blocks_except_candidate_5();
+            set_author(ERA, candidate(5), 20);
+            let total_points = <Points<Test>>::get(ERA);
+
+            roll_to_era_begin(2);
+
+            assert_eq!(ParachainStaking::awarded_pts(ERA, candidate(5)), 0);
+            assert_eq!(ParachainStaking::awarded_pts(ERA, candidate(1)), 20);
+            assert_eq!(<Points<Test>>::get(ERA), total_points - 20);
+            assert_event_emitted!(Event::CollatorMissedSlots {
+                era: ERA,
+                collator: candidate(5),
+                expected_slots: 1,
+                missed_slots: 1,
+                points_removed: 20
+            });
+        });
+    }
+
+    #[test]
+    fn deprioritises_candidates_that_missed_too_many_slots() {
+        build_ext(6).execute_with(|| {
+            set_max_missed_slot_rate(Perbill::from_percent(50));
+            author_blocks_except_candidate_5();
+
+            roll_to_era_begin(2);
+
+            assert!(!ParachainStaking::is_selected_candidate(&candidate(5)));
+            assert!(ParachainStaking::is_selected_candidate(&candidate(6)));
+            assert_event_emitted!(Event::CandidateDeprioritised {
+                era: 2,
+                candidate: candidate(5),
+                missed_slot_rate: Perbill::one()
+            });
+        });
+    }
+
+    #[test]
+    fn selects_deprioritised_candidates_when_there_are_not_enough_others() {
+        build_ext(5).execute_with(|| {
+            set_max_missed_slot_rate(Perbill::from_percent(50));
+            author_blocks_except_candidate_5();
+
+            roll_to_era_begin(2);
+
+            assert!(ParachainStaking::is_selected_candidate(&candidate(5)));
+            assert!(!events().iter().any(|e| matches!(e, Event::CandidateDeprioritised { .. })));
+        });
+    }
+
+    #[test]
+    fn does_not_deprioritise_candidates_within_the_rate() {
+        build_ext(6).execute_with(|| {
+            set_max_missed_slot_rate(Perbill::one());
+            author_blocks_except_candidate_5();
+
+            roll_to_era_begin(2);
+
+            assert!(ParachainStaking::is_selected_candidate(&candidate(5)));
+            assert!(!ParachainStaking::is_selected_candidate(&candidate(6)));
+        });
+    }
+}
@@ -0,0 +1,650 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, assert_last_event,
+    mock::{
+        roll_to_era_begin, AccountId, Balances, ExtBuilder, ParachainStaking,
+        RuntimeEvent as MetaEvent, RuntimeOrigin, SlashDeferDuration, Test, TestAccount,
+    },
+    Error, Event, NominationAction, PoolId,
+};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_avn::Enforcer;
+use sp_runtime::Perbill;
+
+const COLLATOR_STAKE: u128 = 1000;
+const OPERATOR_STAKE: u128 = 100;
+const MEMBER_STAKE: u128 = 200;
+const INITIAL_BALANCE: u128 = 10000;
+const POOL_ID: PoolId = 0;
+
+fn collator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn other_collator() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn operator() -> AccountId {
+    return TestAccount::new(3u64).account_id()
+}
+
+fn member() -> AccountId {
+    return TestAccount::new(4u64).account_id()
+}
+
+fn pool_account() -> AccountId {
+    return ParachainStaking::pool_account_id(POOL_ID)
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    return ExtBuilder::default()
+        .with_balances(vec![
+            (collator(), INITIAL_BALANCE),
+            (other_collator(), INITIAL_BALANCE),
+            (operator(), INITIAL_BALANCE),
+            (member(), INITIAL_BALANCE),
+        ])
+        .with_candidates(vec![(collator(), COLLATOR_STAKE), (other_collator(), COLLATOR_STAKE)])
+        .build()
+}
+
+fn create_pool() {
+    assert_ok!(ParachainStaking::create_pool(
+        RuntimeOrigin::signed(operator()),
+        vec![collator()],
+        OPERATOR_STAKE
+    ));
+}
+
+fn join_pool() {
+    assert_ok!(ParachainStaking::join_pool(RuntimeOrigin::signed(member()), POOL_ID, MEMBER_STAKE));
+}
+
+/// Simulates the staking rewards paid to the pool account
+fn reward_pool(amount: u128) {
+    let balance = Balances::free_balance(pool_account());
+    Balances::make_free_balance_be(&pool_account(), balance + amount);
+}
+
+mod create_pool {
+    use super::*;
+
+    #[test]
+    fn nominates_with_the_pool_account() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            let pool = ParachainStaking::pools(POOL_ID).unwrap();
+            assert_eq!(pool.operator, operator());
+            assert_eq!(pool.collators.to_vec(), vec![collator()]);
+            assert_eq!(pool.total_shares, OPERATOR_STAKE);
+            assert_eq!(
+                ParachainStaking::pool_members(POOL_ID, operator()).unwrap().shares,
+                OPERATOR_STAKE
+            );
+            assert_eq!(
+                ParachainStaking::nominator_state(pool_account())
+                    .unwrap()
+                    .get_bond_amount(&collator()),
+                Some(OPERATOR_STAKE)
+            );
+            assert_eq!(Balances::free_balance(operator()), INITIAL_BALANCE - OPERATOR_STAKE);
+            assert_eq!(ParachainStaking::next_pool_id(), POOL_ID + 1);
+
+            assert_event_emitted!(Event::PoolCreated {
+                pool_id: POOL_ID,
+                operator: operator(),
+                pool_account: pool_account()
+            });
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolBonded {
+                pool_id: POOL_ID,
+                member: operator(),
+                amount: OPERATOR_STAKE,
+                shares: OPERATOR_STAKE
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_without_collators() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::create_pool(
+                    RuntimeOrigin::signed(operator()),
+                    vec![],
+                    OPERATOR_STAKE
+                ),
+                Error::<Test>::PoolCollatorsEmpty
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_a_collator_is_not_a_candidate() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::create_pool(
+                    RuntimeOrigin::signed(operator()),
+                    vec![collator(), member()],
+                    OPERATOR_STAKE
+                ),
+                Error::<Test>::CandidateDNE
+            );
+        });
+    }
+}
+
+mod set_pool_collators {
+    use super::*;
+
+    #[test]
+    fn succeeds_for_the_operator() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            assert_ok!(ParachainStaking::set_pool_collators(
+                RuntimeOrigin::signed(operator()),
+                POOL_ID,
+                vec![other_collator()]
+            ));
+
+            assert_eq!(
+                ParachainStaking::pools(POOL_ID).unwrap().collators.to_vec(),
+                vec![other_collator()]
+            );
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolCollatorsSet {
+                pool_id: POOL_ID,
+                collators: vec![other_collator()]
+            }));
+        });
+    }
+
+    #[test]
+    fn new_stake_is_bonded_to_the_new_collators() {
+        build_ext().execute_with(|| {
+            create_pool();
+            assert_ok!(ParachainStaking::set_pool_collators(
+                RuntimeOrigin::signed(operator()),
+                POOL_ID,
+                vec![other_collator()]
+            ));
+
+            join_pool();
+
+            let state = ParachainStaking::nominator_state(pool_account()).unwrap();
+            assert_eq!(state.get_bond_amount(&collator()), Some(OPERATOR_STAKE));
+            assert_eq!(state.get_bond_amount(&other_collator()), Some(MEMBER_STAKE));
+        });
+    }
+
+    #[test]
+    fn fails_for_other_accounts() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            assert_noop!(
+                ParachainStaking::set_pool_collators(
+                    RuntimeOrigin::signed(member()),
+                    POOL_ID,
+                    vec![other_collator()]
+                ),
+                Error::<Test>::NotPoolOperator
+            );
+        });
+    }
+}
+
+mod join_pool {
+    use super::*;
+
+    #[test]
+    fn bonds_the_amount_for_pool_shares() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            join_pool();
+
+            assert_eq!(
+                ParachainStaking::pool_members(POOL_ID, member()).unwrap().shares,
+                MEMBER_STAKE
+            );
+            assert_eq!(
+                ParachainStaking::pools(POOL_ID).unwrap().total_shares,
+                OPERATOR_STAKE + MEMBER_STAKE
+            );
+            assert_eq!(
+                ParachainStaking::nominator_state(pool_account()).unwrap().total(),
+                OPERATOR_STAKE + MEMBER_STAKE
+            );
+            assert_eq!(Balances::free_balance(member()), INITIAL_BALANCE - MEMBER_STAKE);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolBonded {
+                pool_id: POOL_ID,
+                member: member(),
+                amount: MEMBER_STAKE,
+                shares: MEMBER_STAKE
+            }));
+        });
+    }
+
+    #[test]
+    fn accepts_less_than_the_minimum_nominator_stake() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            assert_ok!(ParachainStaking::join_pool(RuntimeOrigin::signed(member()), POOL_ID, 1));
+
+            assert_eq!(ParachainStaking::pool_members(POOL_ID, member()).unwrap().shares, 1);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_pool_does_not_exist() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::join_pool(RuntimeOrigin::signed(member()), POOL_ID, MEMBER_STAKE),
+                Error::<Test>::PoolDNE
+            );
+        });
+    }
+}
+
+mod unbond_from_pool {
+    use super::*;
+
+    #[test]
+    fn schedules_a_pool_nomination_decrease() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            let unlock_era = 1 + ParachainStaking::delay();
+
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                50
+            ));
+
+            let member_state = ParachainStaking::pool_members(POOL_ID, member()).unwrap();
+            assert_eq!(member_state.shares, MEMBER_STAKE - 50);
+            assert_eq!(member_state.unbonding[0].points, 50);
+            assert_eq!(member_state.unbonding[0].unlock_era, unlock_era);
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().unbonding, 50);
+            assert_eq!(
+                ParachainStaking::nomination_scheduled_requests(collator(), pool_account())[0]
+                    .action,
+                NominationAction::Decrease(50)
+            );
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolUnbonded {
+                pool_id: POOL_ID,
+                member: member(),
+                amount: 50,
+                shares: 50,
+                unlock_era
+            }));
+        });
+    }
+
+    #[test]
+    fn extends_the_pool_request_of_the_same_era() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                50
+            ));
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(operator()),
+                POOL_ID,
+                20
+            ));
+
+            let requests =
+                ParachainStaking::nomination_scheduled_requests(collator(), pool_account());
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].action, NominationAction::Decrease(70));
+            assert_eq!(ParachainStaking::nominator_state(pool_account()).unwrap().less_total, 70);
+        });
+    }
+
+    #[test]
+    fn fails_with_insufficient_shares() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+
+            assert_noop!(
+                ParachainStaking::unbond_from_pool(
+                    RuntimeOrigin::signed(member()),
+                    POOL_ID,
+                    MEMBER_STAKE + 1
+                ),
+                Error::<Test>::InsufficientPoolShares
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_non_members() {
+        build_ext().execute_with(|| {
+            create_pool();
+
+            assert_noop!(
+                ParachainStaking::unbond_from_pool(RuntimeOrigin::signed(member()), POOL_ID, 50),
+                Error::<Test>::PoolMemberDNE
+            );
+        });
+    }
+}
+
+mod withdraw_from_pool {
+    use super::*;
+
+    #[test]
+    fn returns_the_funds_once_unlocked() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                MEMBER_STAKE
+            ));
+
+            roll_to_era_begin((1 + ParachainStaking::delay()) as u64);
+            assert_ok!(ParachainStaking::withdraw_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID
+            ));
+
+            assert_eq!(Balances::free_balance(member()), INITIAL_BALANCE);
+            assert_eq!(ParachainStaking::pool_members(POOL_ID, member()), None);
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().unbonding, 0);
+            assert_eq!(
+                ParachainStaking::nominator_state(pool_account()).unwrap().total(),
+                OPERATOR_STAKE
+            );
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolWithdrawn {
+                pool_id: POOL_ID,
+                member: member(),
+                amount: MEMBER_STAKE
+            }));
+        });
+    }
+
+    #[test]
+    fn fails_before_the_unlock_era() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                50
+            ));
+
+            assert_noop!(
+                ParachainStaking::withdraw_from_pool(RuntimeOrigin::signed(member()), POOL_ID),
+                Error::<Test>::NothingToWithdraw
+            );
+        });
+    }
+}
+
+mod pool_rewards {
+    use super::*;
+
+    #[test]
+    fn are_shared_by_pool_shares() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+
+            reward_pool(300);
+
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &operator()), 100);
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &member()), 200);
+
+            assert_ok!(ParachainStaking::claim_pool_rewards(
+                RuntimeOrigin::signed(member()),
+                POOL_ID
+            ));
+            assert_ok!(ParachainStaking::claim_pool_rewards(
+                RuntimeOrigin::signed(operator()),
+                POOL_ID
+            ));
+
+            assert_eq!(Balances::free_balance(member()), INITIAL_BALANCE - MEMBER_STAKE + 200);
+            assert_eq!(Balances::free_balance(operator()), INITIAL_BALANCE - OPERATOR_STAKE + 100);
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().recorded_rewards, 0);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolRewardPaid {
+                pool_id: POOL_ID,
+                member: operator(),
+                amount: 100
+            }));
+        });
+    }
+
+    #[test]
+    fn earned_before_joining_are_not_shared_with_new_members() {
+        build_ext().execute_with(|| {
+            create_pool();
+            reward_pool(300);
+
+            join_pool();
+
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &operator()), 300);
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &member()), 0);
+        });
+    }
+
+    #[test]
+    fn are_paid_when_a_member_unbonds() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            reward_pool(300);
+
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                50
+            ));
+
+            assert_eq!(Balances::free_balance(member()), INITIAL_BALANCE - MEMBER_STAKE + 200);
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &member()), 0);
+        });
+    }
+}
+
+mod pool_slashing {
+    use super::*;
+
+    const SLASH: Perbill = Perbill::from_percent(50);
+
+    fn unlock_era() -> u64 {
+        return 2 + ParachainStaking::delay() as u64
+    }
+
+    /// Starts a new era, so the pool nomination is part of the collator's stake snapshot
+    fn build_pool() {
+        create_pool();
+        join_pool();
+        roll_to_era_begin(2);
+        SlashDeferDuration::set(0);
+    }
+
+    fn slash_collator() {
+        assert_ok!(<ParachainStaking as Enforcer<AccountId>>::slash_validator(&collator(), SLASH));
+    }
+
+    fn withdraw(who: AccountId) {
+        assert_ok!(ParachainStaking::withdraw_from_pool(RuntimeOrigin::signed(who), POOL_ID));
+    }
+
+    #[test]
+    fn reduces_unbonding_funds_pro_rata() {
+        build_ext().execute_with(|| {
+            build_pool();
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                100
+            ));
+
+            slash_collator();
+
+            let state = ParachainStaking::nominator_state(pool_account()).unwrap();
+            assert_eq!(state.total(), 150);
+            assert_eq!(state.less_total, 50);
+            assert_eq!(
+                ParachainStaking::nomination_scheduled_requests(collator(), pool_account())[0]
+                    .action,
+                NominationAction::Decrease(50)
+            );
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().unbonding, 50);
+            assert_eq!(ParachainStaking::pool_active_stake(&pool_account()), 100);
+
+            roll_to_era_begin(unlock_era());
+            withdraw(member());
+
+            assert_eq!(Balances::free_balance(member()), INITIAL_BALANCE - MEMBER_STAKE + 50);
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().unbonding, 0);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::PoolWithdrawn {
+                pool_id: POOL_ID,
+                member: member(),
+                amount: 50
+            }));
+        });
+    }
+
+    #[test]
+    fn does_not_reduce_funds_unbonded_after_the_slash() {
+        build_ext().execute_with(|| {
+            build_pool();
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                100
+            ));
+
+            slash_collator();
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(operator()),
+                POOL_ID,
+                50
+            ));
+
+            let bucket =
+                ParachainStaking::pool_unbonding(POOL_ID, (collator(), unlock_era() as u32))
+                    .unwrap();
+            assert_eq!(bucket.balance, 100);
+            assert_eq!(bucket.points, 200);
+
+            roll_to_era_begin(unlock_era());
+            withdraw(member());
+            withdraw(operator());
+
+            assert_eq!(Balances::free_balance(member()), INITIAL_BALANCE - MEMBER_STAKE + 50);
+            assert_eq!(Balances::free_balance(operator()), INITIAL_BALANCE - OPERATOR_STAKE + 50);
+            assert_eq!(
+                ParachainStaking::pool_unbonding(POOL_ID, (collator(), unlock_era() as u32)),
+                None
+            );
+        });
+    }
+}
+
+mod returned_stake {
+    use super::*;
+
+    /// The pool collator leaves, returning the pool nomination, after the operator moved new pool
+    /// stake to the other collator
+    fn collator_leaves() {
+        assert_ok!(ParachainStaking::set_pool_collators(
+            RuntimeOrigin::signed(operator()),
+            POOL_ID,
+            vec![other_collator()]
+        ));
+        assert_ok!(ParachainStaking::schedule_leave_candidates(
+            RuntimeOrigin::signed(collator()),
+            2
+        ));
+        roll_to_era_begin(3);
+        assert_ok!(ParachainStaking::execute_leave_candidates(
+            RuntimeOrigin::signed(collator()),
+            collator(),
+            1
+        ));
+    }
+
+    fn bond_to_other_collator() -> Option<u128> {
+        return ParachainStaking::nominator_state(pool_account())
+            .and_then(|state| state.get_bond_amount(&other_collator()))
+    }
+
+    #[test]
+    fn is_not_shared_as_rewards() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                100
+            ));
+
+            collator_leaves();
+
+            let pool = ParachainStaking::pools(POOL_ID).unwrap();
+            assert_eq!(pool.returned, OPERATOR_STAKE + MEMBER_STAKE - 100);
+            assert_eq!(pool.unbonding, 100);
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &operator()), 0);
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &member()), 0);
+        });
+    }
+
+    #[test]
+    fn is_bonded_again_when_a_member_joins() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            collator_leaves();
+
+            join_pool();
+
+            assert_eq!(bond_to_other_collator(), Some(OPERATOR_STAKE + 2 * MEMBER_STAKE));
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().returned, 0);
+            // the returned stake still backs the shares of the earlier members
+            assert_eq!(
+                ParachainStaking::pool_members(POOL_ID, member()).unwrap().shares,
+                2 * MEMBER_STAKE
+            );
+        });
+    }
+
+    #[test]
+    fn is_bonded_again_when_a_member_unbonds() {
+        build_ext().execute_with(|| {
+            create_pool();
+            join_pool();
+            collator_leaves();
+
+            assert_ok!(ParachainStaking::unbond_from_pool(
+                RuntimeOrigin::signed(member()),
+                POOL_ID,
+                100
+            ));
+
+            assert_eq!(bond_to_other_collator(), Some(OPERATOR_STAKE + MEMBER_STAKE));
+            assert_eq!(ParachainStaking::pools(POOL_ID).unwrap().returned, 0);
+            assert_eq!(ParachainStaking::pending_pool_reward(POOL_ID, &member()), 0);
+        });
+    }
+}
@@ -0,0 +1,763 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, assert_last_event, encode_signed_candidate_rebond_params,
+    encode_signed_nominator_rebond_params,
+    mock::{
+        build_proof, roll_to_era_begin, sign, AccountId, AvnProxy, ExtBuilder, ParachainStaking,
+        RuntimeCall as MockCall, RuntimeEvent as MetaEvent, RuntimeOrigin, Signature, Staker, Test,
+        TestAccount,
+    },
+    CancelledScheduledRequest, CandidateBondLessRequest, Config, Error, Event, MaxUnbondRequests,
+    NominationAction, Proof, ScheduledRequest,
+};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+
+const COLLATOR_STAKE: u128 = 1000;
+const NOMINATOR_STAKE: u128 = 500;
+const INITIAL_BALANCE: u128 = 10000;
+
+fn collator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn other_collator() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    return TestAccount::new(3u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    return ExtBuilder::default()
+        .with_balances(vec![
+            (collator(), INITIAL_BALANCE),
+            (other_collator(), INITIAL_BALANCE),
+            (nominator(), INITIAL_BALANCE),
+        ])
+        .with_candidates(vec![(collator(), COLLATOR_STAKE), (other_collator(), COLLATOR_STAKE)])
+        .with_nominations(vec![
+            (nominator(), collator(), NOMINATOR_STAKE),
+            (nominator(), other_collator(), NOMINATOR_STAKE),
+        ])
+        .build()
+}
+
+fn candidate_requests() -> Vec<CandidateBondLessRequest<u128>> {
+    return ParachainStaking::candidate_info(collator()).unwrap().requests.into_inner()
+}
+
+fn nominator_requests(collator: AccountId) -> Vec<ScheduledRequest<AccountId, u128>> {
+    return ParachainStaking::nomination_scheduled_requests(collator, nominator()).into_inner()
+}
+
+fn decrease(when_executable: u32, amount: u128) -> ScheduledRequest<AccountId, u128> {
+    return ScheduledRequest {
+        nominator: nominator(),
+        when_executable,
+        action: NominationAction::Decrease(amount),
+    }
+}
+
+/// Schedules `first` in era 1, unlocking in era 3, and `second` in era 2, unlocking in era 4
+fn schedule_candidate_unbonds(first: u128, second: u128) {
+    assert_ok!(ParachainStaking::schedule_candidate_unbond(
+        RuntimeOrigin::signed(collator()),
+        first
+    ));
+    roll_to_era_begin(2);
+    assert_ok!(ParachainStaking::schedule_candidate_unbond(
+        RuntimeOrigin::signed(collator()),
+        second
+    ));
+}
+
+/// Schedules `first` in era 1, unlocking in era 3, and `second` in era 2, unlocking in era 4
+fn schedule_nominator_unbonds(collator: AccountId, first: u128, second: u128) {
+    assert_ok!(ParachainStaking::schedule_nominator_unbond(
+        RuntimeOrigin::signed(nominator()),
+        collator,
+        first
+    ));
+    roll_to_era_begin(2);
+    assert_ok!(ParachainStaking::schedule_nominator_unbond(
+        RuntimeOrigin::signed(nominator()),
+        collator,
+        second
+    ));
+}
+
+mod candidate_unbond_requests {
+    use super::*;
+
+    #[test]
+    fn can_be_scheduled_for_different_eras() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_eq!(
+                candidate_requests(),
+                vec![
+                    CandidateBondLessRequest { amount: 100, when_executable: 3 },
+                    CandidateBondLessRequest { amount: 200, when_executable: 4 },
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn are_merged_when_they_unlock_in_the_same_era() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                RuntimeOrigin::signed(collator()),
+                100
+            ));
+            assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                RuntimeOrigin::signed(collator()),
+                50
+            ));
+
+            assert_eq!(
+                candidate_requests(),
+                vec![CandidateBondLessRequest { amount: 150, when_executable: 3 }]
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_take_the_bond_below_the_minimum_together() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                RuntimeOrigin::signed(collator()),
+                900
+            ));
+
+            assert_noop!(
+                ParachainStaking::schedule_candidate_unbond(RuntimeOrigin::signed(collator()), 91),
+                Error::<Test>::CandidateBondBelowMin
+            );
+        });
+    }
+
+    #[test]
+    fn are_limited() {
+        build_ext().execute_with(|| {
+            let max_requests: u32 = MaxUnbondRequests::get();
+            for era in 1..=max_requests {
+                roll_to_era_begin(era as u64);
+                assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                    RuntimeOrigin::signed(collator()),
+                    10
+                ));
+            }
+            roll_to_era_begin(max_requests as u64 + 1);
+
+            assert_noop!(
+                ParachainStaking::schedule_candidate_unbond(RuntimeOrigin::signed(collator()), 10),
+                Error::<Test>::TooManyUnbondRequests
+            );
+        });
+    }
+
+    #[test]
+    fn execute_only_the_due_requests() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+            roll_to_era_begin(3);
+
+            assert_ok!(ParachainStaking::execute_candidate_unbond(
+                RuntimeOrigin::signed(collator()),
+                collator()
+            ));
+
+            assert_eq!(ParachainStaking::candidate_info(collator()).unwrap().bond, 900);
+            assert_eq!(
+                candidate_requests(),
+                vec![CandidateBondLessRequest { amount: 200, when_executable: 4 }]
+            );
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateBondedLess {
+                candidate: collator(),
+                amount: 100,
+                new_bond: 900,
+            }));
+        });
+    }
+
+    #[test]
+    fn cannot_be_executed_before_any_is_due() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_noop!(
+                ParachainStaking::execute_candidate_unbond(
+                    RuntimeOrigin::signed(collator()),
+                    collator()
+                ),
+                Error::<Test>::PendingCandidateRequestNotDueYet
+            );
+        });
+    }
+
+    #[test]
+    fn are_all_cancelled() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_ok!(ParachainStaking::cancel_candidate_unbond(
+                RuntimeOrigin::signed(collator())
+            ));
+
+            assert!(candidate_requests().is_empty());
+            assert_event_emitted!(Event::CancelledCandidateBondLess {
+                candidate: collator(),
+                amount: 100,
+                execute_era: 3,
+            });
+            assert_event_emitted!(Event::CancelledCandidateBondLess {
+                candidate: collator(),
+                amount: 200,
+                execute_era: 4,
+            });
+        });
+    }
+}
+
+mod candidate_rebond {
+    use super::*;
+
+    #[test]
+    fn rebonds_from_the_requests_that_unlock_last() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_ok!(ParachainStaking::candidate_rebond(RuntimeOrigin::signed(collator()), 250));
+
+            assert_eq!(
+                candidate_requests(),
+                vec![CandidateBondLessRequest { amount: 50, when_executable: 3 }]
+            );
+            assert_eq!(ParachainStaking::candidate_info(collator()).unwrap().bond, COLLATOR_STAKE);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateRebonded {
+                candidate: collator(),
+                amount: 250,
+                remaining_unbond: 50,
+            }));
+        });
+    }
+
+    #[test]
+    fn can_rebond_everything_pending() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_ok!(ParachainStaking::candidate_rebond(RuntimeOrigin::signed(collator()), 300));
+
+            assert!(candidate_requests().is_empty());
+        });
+    }
+
+    #[test]
+    fn fails_when_the_amount_exceeds_the_pending_unbond() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_noop!(
+                ParachainStaking::candidate_rebond(RuntimeOrigin::signed(collator()), 301),
+                Error::<Test>::RebondAmountExceedsPendingUnbond
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_a_zero_amount() {
+        build_ext().execute_with(|| {
+            schedule_candidate_unbonds(100, 200);
+
+            assert_noop!(
+                ParachainStaking::candidate_rebond(RuntimeOrigin::signed(collator()), 0),
+                Error::<Test>::RebondAmountZero
+            );
+        });
+    }
+
+    #[test]
+    fn fails_without_pending_requests() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::candidate_rebond(RuntimeOrigin::signed(collator()), 10),
+                Error::<Test>::PendingCandidateRequestsDNE
+            );
+        });
+    }
+}
+
+mod nominator_unbond_requests {
+    use super::*;
+
+    #[test]
+    fn can_be_scheduled_for_different_eras() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+
+            assert_eq!(nominator_requests(collator()), vec![decrease(3, 100), decrease(4, 200)]);
+            assert_eq!(ParachainStaking::nominator_state(nominator()).unwrap().less_total, 300);
+            assert_eq!(ParachainStaking::nomination_unlocks(&nominator()).len(), 2);
+        });
+    }
+
+    #[test]
+    fn are_merged_when_they_unlock_in_the_same_era() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                100
+            ));
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                50
+            ));
+
+            assert_eq!(nominator_requests(collator()), vec![decrease(3, 150)]);
+            assert_eq!(ParachainStaking::nominator_state(nominator()).unwrap().less_total, 150);
+        });
+    }
+
+    #[test]
+    fn cannot_decrease_the_whole_nomination_together() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                400
+            ));
+
+            assert_noop!(
+                ParachainStaking::schedule_nominator_unbond(
+                    RuntimeOrigin::signed(nominator()),
+                    collator(),
+                    100
+                ),
+                Error::<Test>::NominatorBondBelowMin
+            );
+        });
+    }
+
+    #[test]
+    fn are_limited() {
+        build_ext().execute_with(|| {
+            let max_requests: u32 = MaxUnbondRequests::get();
+            for era in 1..=max_requests {
+                roll_to_era_begin(era as u64);
+                assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                    RuntimeOrigin::signed(nominator()),
+                    collator(),
+                    10
+                ));
+            }
+            roll_to_era_begin(max_requests as u64 + 1);
+
+            assert_noop!(
+                ParachainStaking::schedule_nominator_unbond(
+                    RuntimeOrigin::signed(nominator()),
+                    collator(),
+                    10
+                ),
+                Error::<Test>::TooManyUnbondRequests
+            );
+        });
+    }
+
+    #[test]
+    fn cannot_be_scheduled_alongside_a_revocation() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                100
+            ));
+
+            assert_noop!(
+                ParachainStaking::schedule_revoke_nomination(
+                    RuntimeOrigin::signed(nominator()),
+                    collator()
+                ),
+                Error::<Test>::PendingNominationRequestAlreadyExists
+            );
+        });
+    }
+
+    #[test]
+    fn execute_only_the_due_requests() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+            roll_to_era_begin(3);
+
+            assert_ok!(ParachainStaking::execute_nomination_request(
+                RuntimeOrigin::signed(nominator()),
+                nominator(),
+                collator()
+            ));
+
+            let state = ParachainStaking::nominator_state(nominator()).unwrap();
+            assert_eq!(state.get_bond_amount(&collator()), Some(400));
+            assert_eq!(state.less_total, 200);
+            assert_eq!(nominator_requests(collator()), vec![decrease(4, 200)]);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationDecreased {
+                nominator: nominator(),
+                candidate: collator(),
+                amount: 100,
+                in_top: true,
+            }));
+        });
+    }
+
+    #[test]
+    fn execute_all_the_due_requests_together() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+            roll_to_era_begin(4);
+
+            assert_ok!(ParachainStaking::execute_nomination_request(
+                RuntimeOrigin::signed(nominator()),
+                nominator(),
+                collator()
+            ));
+
+            let state = ParachainStaking::nominator_state(nominator()).unwrap();
+            assert_eq!(state.get_bond_amount(&collator()), Some(200));
+            assert_eq!(state.less_total, 0);
+            assert!(nominator_requests(collator()).is_empty());
+        });
+    }
+
+    #[test]
+    fn are_all_cancelled() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+
+            assert_ok!(ParachainStaking::cancel_nomination_request(
+                RuntimeOrigin::signed(nominator()),
+                collator()
+            ));
+
+            assert!(nominator_requests(collator()).is_empty());
+            assert_eq!(ParachainStaking::nominator_state(nominator()).unwrap().less_total, 0);
+            assert_event_emitted!(Event::CancelledNominationRequest {
+                nominator: nominator(),
+                collator: collator(),
+                cancelled_request: CancelledScheduledRequest {
+                    when_executable: 4,
+                    action: NominationAction::Decrease(200),
+                },
+            });
+        });
+    }
+}
+
+mod nominator_rebond {
+    use super::*;
+
+    #[test]
+    fn rebonds_from_the_requests_that_unlock_last() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+
+            assert_ok!(ParachainStaking::nominator_rebond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                250
+            ));
+
+            assert_eq!(nominator_requests(collator()), vec![decrease(3, 50)]);
+            assert_eq!(ParachainStaking::nominator_state(nominator()).unwrap().less_total, 50);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::NominationRebonded {
+                nominator: nominator(),
+                candidate: collator(),
+                amount: 250,
+                remaining_unbond: 50,
+            }));
+        });
+    }
+
+    #[test]
+    fn leaves_the_requests_towards_other_collators() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(nominator()),
+                other_collator(),
+                50
+            ));
+
+            assert_ok!(ParachainStaking::nominator_rebond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                300
+            ));
+
+            assert!(nominator_requests(collator()).is_empty());
+            assert_eq!(nominator_requests(other_collator()), vec![decrease(4, 50)]);
+            assert_eq!(ParachainStaking::nominator_state(nominator()).unwrap().less_total, 50);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_amount_exceeds_the_pending_unbond() {
+        build_ext().execute_with(|| {
+            schedule_nominator_unbonds(collator(), 100, 200);
+
+            assert_noop!(
+                ParachainStaking::nominator_rebond(
+                    RuntimeOrigin::signed(nominator()),
+                    collator(),
+                    301
+                ),
+                Error::<Test>::RebondAmountExceedsPendingUnbond
+            );
+        });
+    }
+
+    #[test]
+    fn fails_for_a_revocation() {
+        build_ext().execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(
+                RuntimeOrigin::signed(nominator()),
+                collator()
+            ));
+
+            assert_noop!(
+                ParachainStaking::nominator_rebond(
+                    RuntimeOrigin::signed(nominator()),
+                    collator(),
+                    100
+                ),
+                Error::<Test>::CannotRebondRevocation
+            );
+        });
+    }
+
+    #[test]
+    fn fails_without_pending_requests() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::nominator_rebond(
+                    RuntimeOrigin::signed(nominator()),
+                    collator(),
+                    100
+                ),
+                Error::<Test>::PendingNominationRequestDNE
+            );
+        });
+    }
+}
+
+mod proxy_signed_candidate_rebond {
+    use super::*;
+
+    fn build_ext_with_staker(candidate: &Staker) -> sp_io::TestExternalities {
+        return ExtBuilder::default()
+            .with_balances(vec![
+                (candidate.account_id, INITIAL_BALANCE),
+                (candidate.relayer, INITIAL_BALANCE),
+            ])
+            .with_candidates(vec![(candidate.account_id, COLLATOR_STAKE)])
+            .build()
+    }
+
+    fn create_proof_for_signed_candidate_rebond(
+        sender_nonce: u64,
+        staker: &Staker,
+        amount: &u128,
+    ) -> Proof<Signature, AccountId> {
+        let data_to_sign = encode_signed_candidate_rebond_params::<Test>(
+            staker.relayer.clone(),
+            amount,
+            sender_nonce,
+        );
+
+        let signature = sign(&staker.key_pair, &data_to_sign);
+        return build_proof(&staker.account_id, &staker.relayer, signature)
+    }
+
+    fn create_call_for_signed_candidate_rebond(
+        proof: Proof<Signature, AccountId>,
+        amount: u128,
+    ) -> Box<<Test as Config>::RuntimeCall> {
+        return Box::new(MockCall::ParachainStaking(
+            super::super::Call::<Test>::signed_candidate_rebond { proof, amount },
+        ))
+    }
+
+    #[test]
+    fn succeeds_with_good_values() {
+        let candidate: Staker = Default::default();
+        build_ext_with_staker(&candidate).execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                RuntimeOrigin::signed(candidate.account_id),
+                100
+            ));
+            let nonce = ParachainStaking::proxy_nonce(candidate.account_id);
+            let proof = create_proof_for_signed_candidate_rebond(nonce, &candidate, &60);
+
+            assert_ok!(AvnProxy::proxy(
+                RuntimeOrigin::signed(candidate.relayer),
+                create_call_for_signed_candidate_rebond(proof, 60),
+                None
+            ));
+
+            assert_eq!(
+                ParachainStaking::candidate_info(candidate.account_id).unwrap().requests[0].amount,
+                40
+            );
+            assert_eq!(ParachainStaking::proxy_nonce(candidate.account_id), nonce + 1);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_proof_is_for_a_different_amount() {
+        let candidate: Staker = Default::default();
+        build_ext_with_staker(&candidate).execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                RuntimeOrigin::signed(candidate.account_id),
+                100
+            ));
+            let nonce = ParachainStaking::proxy_nonce(candidate.account_id);
+            let proof = create_proof_for_signed_candidate_rebond(nonce, &candidate, &10);
+
+            assert_noop!(
+                ParachainStaking::signed_candidate_rebond(
+                    RuntimeOrigin::signed(candidate.account_id),
+                    proof,
+                    60
+                ),
+                Error::<Test>::UnauthorizedSignedCandidateRebondTransaction
+            );
+        });
+    }
+}
+
+mod proxy_signed_nominator_rebond {
+    use super::*;
+
+    fn build_ext_with_staker(staker: &Staker) -> sp_io::TestExternalities {
+        return ExtBuilder::default()
+            .with_balances(vec![
+                (collator(), INITIAL_BALANCE),
+                (other_collator(), INITIAL_BALANCE),
+                (staker.account_id, INITIAL_BALANCE),
+                (staker.relayer, INITIAL_BALANCE),
+            ])
+            .with_candidates(vec![(collator(), COLLATOR_STAKE), (other_collator(), COLLATOR_STAKE)])
+            .with_nominations(vec![
+                (staker.account_id, collator(), NOMINATOR_STAKE),
+                (staker.account_id, other_collator(), NOMINATOR_STAKE),
+            ])
+            .build()
+    }
+
+    fn schedule_unbonds(staker: &Staker) {
+        for collator in [collator(), other_collator()] {
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(staker.account_id),
+                collator,
+                100
+            ));
+        }
+    }
+
+    fn create_proof_for_signed_nominator_rebond(
+        sender_nonce: u64,
+        staker: &Staker,
+        amount: &u128,
+    ) -> Proof<Signature, AccountId> {
+        let data_to_sign = encode_signed_nominator_rebond_params::<Test>(
+            staker.relayer.clone(),
+            amount,
+            sender_nonce,
+        );
+
+        let signature = sign(&staker.key_pair, &data_to_sign);
+        return build_proof(&staker.account_id, &staker.relayer, signature)
+    }
+
+    fn create_call_for_signed_nominator_rebond(
+        proof: Proof<Signature, AccountId>,
+        amount: u128,
+    ) -> Box<<Test as Config>::RuntimeCall> {
+        return Box::new(MockCall::ParachainStaking(
+            super::super::Call::<Test>::signed_nominator_rebond { proof, amount },
+        ))
+    }
+
+    #[test]
+    fn rebonds_across_collators() {
+        let staker: Staker = Default::default();
+        build_ext_with_staker(&staker).execute_with(|| {
+            schedule_unbonds(&staker);
+            let nonce = ParachainStaking::proxy_nonce(staker.account_id);
+            let proof = create_proof_for_signed_nominator_rebond(nonce, &staker, &150);
+
+            assert_ok!(AvnProxy::proxy(
+                RuntimeOrigin::signed(staker.relayer),
+                create_call_for_signed_nominator_rebond(proof, 150),
+                None
+            ));
+
+            let pending: u128 = [collator(), other_collator()]
+                .iter()
+                .map(|collator| {
+                    ParachainStaking::nomination_pending_decrease(collator, &staker.account_id)
+                })
+                .sum();
+            assert_eq!(pending, 50);
+            assert_eq!(
+                ParachainStaking::nominator_state(staker.account_id).unwrap().less_total,
+                50
+            );
+            assert_eq!(ParachainStaking::proxy_nonce(staker.account_id), nonce + 1);
+        });
+    }
+
+    #[test]
+    fn fails_when_the_amount_exceeds_the_pending_unbond() {
+        let staker: Staker = Default::default();
+        build_ext_with_staker(&staker).execute_with(|| {
+            schedule_unbonds(&staker);
+            let nonce = ParachainStaking::proxy_nonce(staker.account_id);
+            let proof = create_proof_for_signed_nominator_rebond(nonce, &staker, &201);
+
+            assert_noop!(
+                ParachainStaking::signed_nominator_rebond(
+                    RuntimeOrigin::signed(staker.account_id),
+                    proof,
+                    201
+                ),
+                Error::<Test>::RebondAmountExceedsPendingUnbond
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_the_proof_is_for_a_different_amount() {
+        let staker: Staker = Default::default();
+        build_ext_with_staker(&staker).execute_with(|| {
+            schedule_unbonds(&staker);
+            let nonce = ParachainStaking::proxy_nonce(staker.account_id);
+            let proof = create_proof_for_signed_nominator_rebond(nonce, &staker, &10);
+
+            assert_noop!(
+                ParachainStaking::signed_nominator_rebond(
+                    RuntimeOrigin::signed(staker.account_id),
+                    proof,
+                    150
+                ),
+                Error::<Test>::UnauthorizedSignedRebondTransaction
+            );
+        });
+    }
+}
@@ -0,0 +1,366 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    assert_event_emitted, assert_last_event,
+    mock::{
+        roll_to_era_begin, AccountId, Balances, ExtBuilder, ParachainStaking,
+        RuntimeEvent as MetaEvent, RuntimeOrigin, SlashDeferDuration, SlashNominators, Test,
+        TestAccount,
+    },
+    Config, Delay, Error, Event, MinCollatorStake, MinTotalNominatorStake, Total, UnappliedSlashes,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Get},
+};
+use pallet_avn::Enforcer;
+use sp_runtime::{DispatchError, Perbill};
+
+const COLLATOR_STAKE: u128 = 1000;
+const NOMINATOR_STAKE: u128 = 500;
+const INITIAL_BALANCE: u128 = 10000;
+
+fn collator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn apply_era() -> u32 {
+    return 1 + <Test as Config>::SlashDeferDuration::get()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    return ExtBuilder::default()
+        .with_balances(vec![(collator(), INITIAL_BALANCE), (nominator(), INITIAL_BALANCE)])
+        .with_candidates(vec![(collator(), COLLATOR_STAKE)])
+        .with_nominations(vec![(nominator(), collator(), NOMINATOR_STAKE)])
+        .build()
+}
+
+fn report_slash(fraction: Perbill) {
+    assert_ok!(<ParachainStaking as Enforcer<AccountId>>::slash_validator(&collator(), fraction));
+}
+
+mod report_slash {
+    use super::*;
+
+    #[test]
+    fn defers_the_slash() {
+        build_ext().execute_with(|| {
+            let fraction = Perbill::from_percent(10);
+
+            report_slash(fraction);
+
+            let slash = ParachainStaking::unapplied_slashes(apply_era(), collator()).unwrap();
+            assert_eq!(slash.own, fraction * COLLATOR_STAKE);
+            assert_eq!(slash.others.len(), 1);
+            assert_eq!(slash.others[0].owner, nominator());
+            assert_eq!(slash.others[0].amount, fraction * NOMINATOR_STAKE);
+            assert_eq!(ParachainStaking::candidate_info(collator()).unwrap().bond, COLLATOR_STAKE);
+            assert_last_event!(MetaEvent::ParachainStaking(Event::SlashReported {
+                collator: collator(),
+                fraction,
+                apply_era: apply_era()
+            }));
+        });
+    }
+
+    #[test]
+    fn does_not_slash_nominations_when_disabled() {
+        build_ext().execute_with(|| {
+            SlashNominators::set(false);
+
+            report_slash(Perbill::from_percent(10));
+
+            let slash = ParachainStaking::unapplied_slashes(apply_era(), collator()).unwrap();
+            assert!(slash.others.is_empty());
+        });
+    }
+
+    #[test]
+    fn keeps_the_largest_slash_of_an_era() {
+        build_ext().execute_with(|| {
+            report_slash(Perbill::from_percent(20));
+            report_slash(Perbill::from_percent(10));
+
+            let slash = ParachainStaking::unapplied_slashes(apply_era(), collator()).unwrap();
+            assert_eq!(slash.own, Perbill::from_percent(20) * COLLATOR_STAKE);
+        });
+    }
+
+    #[test]
+    fn ignores_a_zero_fraction() {
+        build_ext().execute_with(|| {
+            report_slash(Perbill::zero());
+
+            assert!(ParachainStaking::unapplied_slashes(apply_era(), collator()).is_none());
+        });
+    }
+
+    #[test]
+    fn fails_when_the_offender_is_not_a_candidate() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                <ParachainStaking as Enforcer<AccountId>>::slash_validator(
+                    &nominator(),
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::CandidateDNE
+            );
+        });
+    }
+}
+
+mod apply_slash {
+    use super::*;
+
+    #[test]
+    fn slashes_the_collator_and_its_nominations_when_due() {
+        build_ext().execute_with(|| {
+            let fraction = Perbill::from_percent(10);
+            let collator_slash = fraction * COLLATOR_STAKE;
+            let nominator_slash = fraction * NOMINATOR_STAKE;
+            let total_issuance = Balances::total_issuance();
+            let total_staked = <Total<Test>>::get();
+
+            report_slash(fraction);
+            roll_to_era_begin((apply_era() - 1) as u64);
+            assert_eq!(ParachainStaking::candidate_info(collator()).unwrap().bond, COLLATOR_STAKE);
+
+            roll_to_era_begin(apply_era() as u64);
+
+            let candidate = ParachainStaking::candidate_info(collator()).unwrap();
+            assert_eq!(candidate.bond, COLLATOR_STAKE - collator_slash);
+            assert!(candidate.is_active());
+            assert_eq!(
+                ParachainStaking::nominator_state(nominator()).unwrap().total(),
+                NOMINATOR_STAKE - nominator_slash
+            );
+            assert_eq!(<Total<Test>>::get(), total_staked - collator_slash - nominator_slash);
+            assert_eq!(Balances::free_balance(collator()), INITIAL_BALANCE - collator_slash);
+            assert_eq!(Balances::free_balance(nominator()), INITIAL_BALANCE - nominator_slash);
+            assert_eq!(
+                Balances::total_issuance(),
+                total_issuance - collator_slash - nominator_slash
+            );
+            assert!(ParachainStaking::unapplied_slashes(apply_era(), collator()).is_none());
+
+            assert_event_emitted!(Event::Slashed { account: collator(), amount: collator_slash });
+            assert_event_emitted!(Event::Slashed { account: nominator(), amount: nominator_slash });
+        });
+    }
+
+    #[test]
+    fn applies_the_slash_immediately_without_a_defer_duration() {
+        build_ext().execute_with(|| {
+            SlashDeferDuration::set(0);
+            let fraction = Perbill::from_percent(10);
+
+            report_slash(fraction);
+
+            assert_eq!(
+                ParachainStaking::candidate_info(collator()).unwrap().bond,
+                COLLATOR_STAKE - fraction * COLLATOR_STAKE
+            );
+            assert_eq!(<UnappliedSlashes<Test>>::iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn removes_fully_slashed_nominations_and_takes_the_collator_offline() {
+        build_ext().execute_with(|| {
+            SlashDeferDuration::set(0);
+
+            report_slash(Perbill::from_percent(100));
+
+            let candidate = ParachainStaking::candidate_info(collator()).unwrap();
+            assert_eq!(candidate.bond, 0);
+            assert!(!candidate.is_active());
+            assert_eq!(ParachainStaking::nominator_state(nominator()), None);
+            assert_eq!(Balances::free_balance(nominator()), INITIAL_BALANCE - NOMINATOR_STAKE);
+
+            assert_event_emitted!(Event::CandidateWentOffline { candidate: collator() });
+            assert_event_emitted!(Event::NominatorLeft {
+                nominator: nominator(),
+                unstaked_amount: NOMINATOR_STAKE
+            });
+
+            assert_noop!(
+                ParachainStaking::go_online(RuntimeOrigin::signed(collator())),
+                Error::<Test>::CandidateBondBelowMin
+            );
+        });
+    }
+}
+
+mod cancel_deferred_slash {
+    use super::*;
+
+    #[test]
+    fn succeeds_with_the_cancel_origin() {
+        build_ext().execute_with(|| {
+            report_slash(Perbill::from_percent(10));
+
+            assert_ok!(ParachainStaking::cancel_deferred_slash(
+                RuntimeOrigin::root(),
+                apply_era(),
+                collator()
+            ));
+
+            assert!(ParachainStaking::unapplied_slashes(apply_era(), collator()).is_none());
+            assert_last_event!(MetaEvent::ParachainStaking(Event::SlashCancelled {
+                collator: collator(),
+                apply_era: apply_era()
+            }));
+
+            roll_to_era_begin(apply_era() as u64);
+            assert_eq!(ParachainStaking::candidate_info(collator()).unwrap().bond, COLLATOR_STAKE);
+            assert_eq!(Balances::free_balance(collator()), INITIAL_BALANCE);
+        });
+    }
+
+    #[test]
+    fn fails_with_a_signed_origin() {
+        build_ext().execute_with(|| {
+            report_slash(Perbill::from_percent(10));
+
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(
+                    RuntimeOrigin::signed(collator()),
+                    apply_era(),
+                    collator()
+                ),
+                DispatchError::BadOrigin
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_there_is_no_slash() {
+        build_ext().execute_with(|| {
+            assert_noop!(
+                ParachainStaking::cancel_deferred_slash(
+                    RuntimeOrigin::root(),
+                    apply_era(),
+                    collator()
+                ),
+                Error::<Test>::UnappliedSlashDNE
+            );
+        });
+    }
+}
+
+mod pending_requests {
+    use super::*;
+
+    const SLASH: Perbill = Perbill::from_percent(50);
+
+    fn execute_era() -> u64 {
+        return 1 + <Delay<Test>>::get() as u64
+    }
+
+    #[test]
+    fn trims_the_collator_unbond_to_the_slashed_bond() {
+        build_ext().execute_with(|| {
+            SlashDeferDuration::set(0);
+            assert_ok!(ParachainStaking::schedule_candidate_unbond(
+                RuntimeOrigin::signed(collator()),
+                900
+            ));
+
+            report_slash(SLASH);
+
+            let bond = COLLATOR_STAKE - SLASH * COLLATOR_STAKE;
+            let max_unbond = bond - <MinCollatorStake<Test>>::get();
+            let candidate = ParachainStaking::candidate_info(collator()).unwrap();
+            assert_eq!(candidate.bond, bond);
+            assert_eq!(candidate.pending_unbond(), max_unbond);
+
+            roll_to_era_begin(execute_era());
+            let total_staked = <Total<Test>>::get();
+            assert_ok!(ParachainStaking::execute_candidate_unbond(
+                RuntimeOrigin::signed(collator()),
+                collator()
+            ));
+
+            let candidate = ParachainStaking::candidate_info(collator()).unwrap();
+            assert_eq!(candidate.bond, <MinCollatorStake<Test>>::get());
+            assert_eq!(<Total<Test>>::get(), total_staked - max_unbond);
+        });
+    }
+
+    #[test]
+    fn trims_the_nomination_decrease_to_the_slashed_bond() {
+        build_ext().execute_with(|| {
+            SlashDeferDuration::set(0);
+            assert_ok!(ParachainStaking::schedule_nominator_unbond(
+                RuntimeOrigin::signed(nominator()),
+                collator(),
+                400
+            ));
+
+            report_slash(SLASH);
+
+            let bond = NOMINATOR_STAKE - SLASH * NOMINATOR_STAKE;
+            // the minimum nominator stake is larger than the minimum nomination
+            let max_decrease = bond - <MinTotalNominatorStake<Test>>::get();
+            let state = ParachainStaking::nominator_state(nominator()).unwrap();
+            assert_eq!(state.total(), bond);
+            assert_eq!(state.less_total, max_decrease);
+            assert_eq!(
+                ParachainStaking::nomination_pending_decrease(&collator(), &nominator()),
+                max_decrease
+            );
+
+            roll_to_era_begin(execute_era());
+            let total_staked = <Total<Test>>::get();
+            assert_ok!(ParachainStaking::execute_nomination_request(
+                RuntimeOrigin::signed(nominator()),
+                nominator(),
+                collator()
+            ));
+
+            let state = ParachainStaking::nominator_state(nominator()).unwrap();
+            assert_eq!(state.total(), <MinTotalNominatorStake<Test>>::get());
+            assert!(state.less_total.is_zero());
+            assert_eq!(<Total<Test>>::get(), total_staked - max_decrease);
+        });
+    }
+
+    #[test]
+    fn reduces_the_revocation_to_the_slashed_bond() {
+        build_ext().execute_with(|| {
+            SlashDeferDuration::set(0);
+            assert_ok!(ParachainStaking::schedule_revoke_nomination(
+                RuntimeOrigin::signed(nominator()),
+                collator()
+            ));
+
+            report_slash(SLASH);
+
+            let bond = NOMINATOR_STAKE - SLASH * NOMINATOR_STAKE;
+            assert_eq!(ParachainStaking::nominator_state(nominator()).unwrap().less_total, bond);
+
+            roll_to_era_begin(execute_era());
+            let total_staked = <Total<Test>>::get();
+            assert_ok!(ParachainStaking::execute_nomination_request(
+                RuntimeOrigin::signed(nominator()),
+                nominator(),
+                collator()
+            ));
+
+            assert_eq!(ParachainStaking::nominator_state(nominator()), None);
+            assert_eq!(<Total<Test>>::get(), total_staked - bond);
+            assert_event_emitted!(Event::NominationRevoked {
+                nominator: nominator(),
+                candidate: collator(),
+                unstaked_amount: bond
+            });
+        });
+    }
+}
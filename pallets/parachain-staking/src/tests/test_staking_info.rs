@@ -0,0 +1,192 @@
+//Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::{
+    mock::{
+        set_author, set_reward_pot, AccountId, ExtBuilder, ParachainStaking, RuntimeOrigin, Test,
+        TestAccount,
+    },
+    staking_info::{CandidateEraInfo, NominationUnlock, NominatorEraShare, PendingPayout},
+    AtStake, DelayedPayout, DelayedPayouts, LockedEraPayout, NominationAction,
+};
+use frame_support::assert_ok;
+use sp_runtime::{traits::Zero, FixedPointNumber, FixedU128, Perbill};
+
+const COLLATOR_STAKE: u128 = 1000;
+const NOMINATOR_STAKE: u128 = 500;
+const TOTAL_STAKE: u128 = COLLATOR_STAKE + NOMINATOR_STAKE;
+const ERA_REWARD: u128 = 3000;
+
+fn collator() -> AccountId {
+    return TestAccount::new(1u64).account_id()
+}
+
+fn nominator() -> AccountId {
+    return TestAccount::new(2u64).account_id()
+}
+
+fn build_ext() -> sp_io::TestExternalities {
+    return ExtBuilder::default()
+        .with_balances(vec![(collator(), 10000), (nominator(), 10000)])
+        .with_candidates(vec![(collator(), COLLATOR_STAKE)])
+        .with_nominations(vec![(nominator(), collator(), NOMINATOR_STAKE)])
+        .build()
+}
+
+fn insert_payout(era: u32) {
+    <DelayedPayouts<Test>>::insert(era, DelayedPayout { total_staking_reward: ERA_REWARD });
+}
+
+#[test]
+fn candidate_era_info_returns_the_snapshot_and_points() {
+    build_ext().execute_with(|| {
+        set_author(1, collator(), 5);
+
+        assert_eq!(
+            ParachainStaking::candidate_era_info(1),
+            vec![CandidateEraInfo {
+                candidate: collator(),
+                bond: COLLATOR_STAKE,
+                total: TOTAL_STAKE,
+                commission: Perbill::zero(),
+                points: 5,
+            }]
+        );
+        assert!(ParachainStaking::candidate_era_info(2).is_empty());
+    });
+}
+
+mod nominator_era_shares {
+    use super::*;
+
+    #[test]
+    fn has_no_reward_before_the_payout_is_computed() {
+        build_ext().execute_with(|| {
+            set_author(1, collator(), 5);
+
+            assert_eq!(
+                ParachainStaking::nominator_era_shares(&nominator(), 1),
+                vec![NominatorEraShare {
+                    candidate: collator(),
+                    amount: NOMINATOR_STAKE,
+                    share: Perbill::from_rational(NOMINATOR_STAKE, TOTAL_STAKE),
+                    reward: None,
+                }]
+            );
+        });
+    }
+
+    #[test]
+    fn includes_the_reward_once_the_payout_is_computed() {
+        build_ext().execute_with(|| {
+            set_author(1, collator(), 5);
+            insert_payout(1);
+
+            let share = ParachainStaking::nominator_era_shares(&nominator(), 1);
+
+            assert_eq!(
+                share[0].reward,
+                Some(Perbill::from_rational(NOMINATOR_STAKE, TOTAL_STAKE) * ERA_REWARD)
+            );
+        });
+    }
+
+    #[test]
+    fn is_empty_for_an_account_that_does_not_nominate() {
+        build_ext().execute_with(|| {
+            assert!(ParachainStaking::nominator_era_shares(&collator(), 1).is_empty());
+        });
+    }
+}
+
+#[test]
+fn pending_payouts_lists_the_delayed_payouts() {
+    build_ext().execute_with(|| {
+        set_author(1, collator(), 5);
+        insert_payout(1);
+
+        assert_eq!(
+            ParachainStaking::pending_payouts(),
+            vec![PendingPayout { era: 1, total_staking_reward: ERA_REWARD, total_points: 5 }]
+        );
+    });
+}
+
+#[test]
+fn nomination_unlocks_lists_the_scheduled_requests() {
+    build_ext().execute_with(|| {
+        assert!(ParachainStaking::nomination_unlocks(&nominator()).is_empty());
+
+        assert_ok!(ParachainStaking::schedule_revoke_nomination(
+            RuntimeOrigin::signed(nominator()),
+            collator()
+        ));
+
+        assert_eq!(
+            ParachainStaking::nomination_unlocks(&nominator()),
+            vec![NominationUnlock {
+                candidate: collator(),
+                action: NominationAction::Revoke(NOMINATOR_STAKE),
+                unlock_era: 1 + ParachainStaking::delay(),
+            }]
+        );
+    });
+}
+
+mod projected_apys {
+    use super::*;
+
+    #[test]
+    fn annualises_the_era_reward() {
+        build_ext().execute_with(|| {
+            set_reward_pot(ERA_REWARD);
+            let era_length = ParachainStaking::era().length;
+            let eras_per_year = 10;
+
+            let apys = ParachainStaking::projected_apys(era_length * eras_per_year);
+
+            assert_eq!(apys.len(), 1);
+            assert_eq!(apys[0].candidate, collator());
+            assert_eq!(
+                apys[0].apy,
+                FixedU128::saturating_from_rational(
+                    ERA_REWARD * eras_per_year as u128,
+                    TOTAL_STAKE
+                )
+            );
+            assert_eq!(<LockedEraPayout<Test>>::get(), 0);
+        });
+    }
+
+    #[test]
+    fn deducts_the_commission() {
+        build_ext().execute_with(|| {
+            set_reward_pot(ERA_REWARD);
+            let era_length = ParachainStaking::era().length;
+            let era = ParachainStaking::era().current;
+            <AtStake<Test>>::mutate(era, collator(), |snapshot| {
+                snapshot.commission = Perbill::from_percent(10)
+            });
+
+            let apys = ParachainStaking::projected_apys(era_length);
+
+            assert_eq!(
+                apys[0].apy,
+                FixedU128::saturating_from_rational(
+                    ERA_REWARD - Perbill::from_percent(10) * ERA_REWARD,
+                    TOTAL_STAKE
+                )
+            );
+        });
+    }
+
+    #[test]
+    fn is_zero_without_rewards() {
+        build_ext().execute_with(|| {
+            let apys = ParachainStaking::projected_apys(ParachainStaking::era().length);
+
+            assert_eq!(apys[0].apy, FixedU128::zero());
+        });
+    }
+}
@@ -29,8 +29,8 @@ use crate::{
         RuntimeEvent as MetaEvent, RuntimeOrigin as Origin, Test, TestAccount,
     },
     nomination_requests::{CancelledScheduledRequest, NominationAction, ScheduledRequest},
-    AtStake, CollatorSnapshot, CollatorStatus, Error, Event, NominationScheduledRequests,
-    NominatorAdded, NOMINATOR_LOCK_ID,
+    AtStake, CollatorSnapshot, CollatorStatus, Error, Event, MaxUnbondRequests,
+    NominationScheduledRequests, NominatorAdded, NOMINATOR_LOCK_ID,
 };
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::{traits::Zero, BoundedVec, DispatchError, ModuleError};
@@ -739,12 +739,12 @@ fn execute_leave_candidates_removes_pending_nomination_requests() {
                 account_id,
                 5
             ));
-            let state = ParachainStaking::nomination_scheduled_requests(&account_id);
+            let state = ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2);
             assert_eq!(
                 state,
                 BoundedVec::<
                     ScheduledRequest<sp_core::sr25519::Public, u128>,
-                    MaxNominationsPerNominator,
+                    MaxUnbondRequests,
                 >::truncate_from(vec![ScheduledRequest {
                     nominator: account_id_2,
                     when_executable: 3,
@@ -767,14 +767,14 @@ fn execute_leave_candidates_removes_pending_nomination_requests() {
             ));
             assert!(ParachainStaking::candidate_info(account_id).is_none());
             assert!(
-                !ParachainStaking::nomination_scheduled_requests(&account_id)
+                !ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                     .iter()
                     .any(|x| x.nominator == account_id_2),
                 "nomination request not removed"
             );
             assert!(
-                !<NominationScheduledRequests<Test>>::contains_key(&account_id),
-                "the key was not removed from storage"
+                <NominationScheduledRequests<Test>>::iter_prefix(&account_id).next().is_none(),
+                "the keys were not removed from storage"
             );
         });
 }
@@ -1075,7 +1075,7 @@ fn cancel_candidate_unbond_updates_candidate_state() {
         .execute_with(|| {
             assert_ok!(ParachainStaking::schedule_candidate_unbond(Origin::signed(account_id), 10));
             assert_ok!(ParachainStaking::cancel_candidate_unbond(Origin::signed(account_id)));
-            assert!(ParachainStaking::candidate_info(&account_id).unwrap().request.is_none());
+            assert!(ParachainStaking::candidate_info(&account_id).unwrap().requests.is_empty());
         });
 }
 
@@ -1258,12 +1258,12 @@ fn execute_leave_nominators_removes_pending_nomination_requests() {
                 account_id,
                 5
             ));
-            let state = ParachainStaking::nomination_scheduled_requests(&account_id);
+            let state = ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2);
             assert_eq!(
                 state,
                 BoundedVec::<
                     ScheduledRequest<sp_core::sr25519::Public, u128>,
-                    MaxNominationsPerNominator,
+                    MaxUnbondRequests,
                 >::truncate_from(vec![ScheduledRequest {
                     nominator: account_id_2,
                     when_executable: 3,
@@ -1279,7 +1279,7 @@ fn execute_leave_nominators_removes_pending_nomination_requests() {
             ));
             assert!(ParachainStaking::nominator_state(account_id_2).is_none());
             assert!(
-                !ParachainStaking::nomination_scheduled_requests(&account_id)
+                !ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                     .iter()
                     .any(|x| x.nominator == account_id_2),
                 "nomination request not removed"
@@ -1591,7 +1591,7 @@ fn cancel_revoke_nomination_updates_nominator_state() {
                 Origin::signed(account_id_2),
                 account_id
             ));
-            let state = ParachainStaking::nomination_scheduled_requests(&account_id);
+            let state = ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2);
             assert_eq!(
                 state,
                 vec![ScheduledRequest {
@@ -1610,7 +1610,7 @@ fn cancel_revoke_nomination_updates_nominator_state() {
                 Origin::signed(account_id_2),
                 account_id
             ));
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                 .iter()
                 .any(|x| x.nominator == account_id_2));
             assert_eq!(
@@ -1669,7 +1669,7 @@ fn cancel_nominator_unbond_updates_nominator_state() {
                 account_id,
                 5
             ));
-            let state = ParachainStaking::nomination_scheduled_requests(&account_id);
+            let state = ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2);
             assert_eq!(
                 state,
                 vec![ScheduledRequest {
@@ -1688,7 +1688,7 @@ fn cancel_nominator_unbond_updates_nominator_state() {
                 Origin::signed(account_id_2),
                 account_id
             ));
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                 .iter()
                 .any(|x| x.nominator == account_id_2));
             assert_eq!(
@@ -3558,7 +3558,7 @@ fn execute_leave_candidate_removes_nominations() {
         .build()
         .execute_with(|| {
             // Verifies the revocation request is initially empty
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id_2)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id_2, &account_id_3)
                 .iter()
                 .any(|x| x.nominator == account_id_3));
 
@@ -3571,7 +3571,7 @@ fn execute_leave_candidate_removes_nominations() {
                 account_id_2
             ));
             // Verifies the revocation request is present
-            assert!(ParachainStaking::nomination_scheduled_requests(&account_id_2)
+            assert!(ParachainStaking::nomination_scheduled_requests(&account_id_2, &account_id_3)
                 .iter()
                 .any(|x| x.nominator == account_id_3));
 
@@ -3582,7 +3582,7 @@ fn execute_leave_candidate_removes_nominations() {
                 2
             ));
             // Verifies the revocation request is again empty
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id_2)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id_2, &account_id_3)
                 .iter()
                 .any(|x| x.nominator == account_id_3));
         });
@@ -4995,7 +4995,7 @@ fn nomination_kicked_from_bottom_removes_pending_request() {
                 unstaked_amount: 19,
             });
             // ensure request DNE
-            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id)
+            assert!(!ParachainStaking::nomination_scheduled_requests(&account_id, &account_id_2)
                 .iter()
                 .any(|x| x.nominator == account_id_2));
         });
@@ -5501,6 +5501,7 @@ fn test_nomination_request_exists_returns_true_when_decrease_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 account_id,
+                account_id_2,
                 BoundedVec::truncate_from(vec![ScheduledRequest {
                     nominator: account_id_2,
                     when_executable: 3,
@@ -5523,6 +5524,7 @@ fn test_nomination_request_exists_returns_true_when_revoke_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 account_id,
+                account_id_2,
                 BoundedVec::truncate_from(vec![ScheduledRequest {
                     nominator: account_id_2,
                     when_executable: 3,
@@ -5562,6 +5564,7 @@ fn test_nomination_request_revoke_exists_returns_false_when_decrease_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 account_id,
+                account_id_2,
                 BoundedVec::truncate_from(vec![ScheduledRequest {
                     nominator: account_id_2,
                     when_executable: 3,
@@ -5587,6 +5590,7 @@ fn test_nomination_request_revoke_exists_returns_true_when_revoke_exists() {
         .execute_with(|| {
             <NominationScheduledRequests<Test>>::insert(
                 account_id,
+                account_id_2,
                 BoundedVec::truncate_from(vec![ScheduledRequest {
                     nominator: account_id_2,
                     when_executable: 3,
@@ -5610,11 +5614,13 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up() {
             // invalid state
             <NominationScheduledRequests<Test>>::insert(
                 account_id_2,
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                to_acc_id(10),
+                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxUnbondRequests>::default(),
             );
             <NominationScheduledRequests<Test>>::insert(
                 to_acc_id(3),
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                to_acc_id(10),
+                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxUnbondRequests>::default(),
             );
             assert_ok!(ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
                 Origin::signed(account_id),
@@ -5622,8 +5628,12 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up() {
                                                                 * for idempotency */
             ));
 
-            assert!(!<NominationScheduledRequests<Test>>::contains_key(account_id_2));
-            assert!(!<NominationScheduledRequests<Test>>::contains_key(to_acc_id(3)));
+            assert!(<NominationScheduledRequests<Test>>::iter_prefix(account_id_2)
+                .next()
+                .is_none());
+            assert!(<NominationScheduledRequests<Test>>::iter_prefix(to_acc_id(3))
+                .next()
+                .is_none());
         });
 }
 
@@ -5639,19 +5649,23 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_cleans_up_only_speci
             // invalid state
             <NominationScheduledRequests<Test>>::insert(
                 account_id_2,
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                to_acc_id(10),
+                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxUnbondRequests>::default(),
             );
             <NominationScheduledRequests<Test>>::insert(
                 to_acc_id(3),
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                to_acc_id(10),
+                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxUnbondRequests>::default(),
             );
             assert_ok!(ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
                 Origin::signed(account_id),
                 vec![account_id_2]
             ));
 
-            assert!(!<NominationScheduledRequests<Test>>::contains_key(account_id_2));
-            assert!(<NominationScheduledRequests<Test>>::contains_key(to_acc_id(3)));
+            assert!(<NominationScheduledRequests<Test>>::iter_prefix(account_id_2)
+                .next()
+                .is_none());
+            assert!(<NominationScheduledRequests<Test>>::contains_key(to_acc_id(3), to_acc_id(10)));
         });
 }
 
@@ -5666,10 +5680,12 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_requests
             // invalid state
             <NominationScheduledRequests<Test>>::insert(
                 to_acc_id(2),
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                to_acc_id(10),
+                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxUnbondRequests>::default(),
             );
             <NominationScheduledRequests<Test>>::insert(
                 to_acc_id(3),
+                to_acc_id(10),
                 BoundedVec::truncate_from(vec![ScheduledRequest {
                     nominator: to_acc_id(10),
                     when_executable: 1,
@@ -5698,7 +5714,8 @@ fn test_hotfix_remove_nomination_requests_exited_candidates_errors_when_candidat
             // invalid state
             <NominationScheduledRequests<Test>>::insert(
                 account_id,
-                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxNominationsPerNominator>::default(),
+                to_acc_id(10),
+                BoundedVec::<ScheduledRequest<AccountId, u128>, MaxUnbondRequests>::default(),
             );
             assert_noop!(
                 ParachainStaking::hotfix_remove_nomination_requests_exited_candidates(
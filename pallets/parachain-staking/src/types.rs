@@ -17,9 +17,9 @@
 //! Types for parachain-staking
 
 use crate::{
-    set::BoundedOrderedSet, BalanceOf, BottomNominations, CandidateInfo, Config, Delay, Era,
-    EraIndex, Error, Event, GrowthPeriodIndex, MinCollatorStake, NominatorState, Pallet,
-    RewardPoint, TopNominations, Total, COLLATOR_LOCK_ID, NOMINATOR_LOCK_ID,
+    set::BoundedOrderedSet, AutoCompound, BalanceOf, BottomNominations, CandidateInfo, Config,
+    Delay, Era, EraIndex, Error, Event, GrowthPeriodIndex, MinCollatorStake, NominatorState,
+    Pallet, RewardPoint, TopNominations, Total, COLLATOR_LOCK_ID, NOMINATOR_LOCK_ID,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -29,7 +29,7 @@ use frame_support::{
 use sp_avn_common::eth::EthereumId;
 use sp_runtime::{
     traits::{Saturating, Zero},
-    RuntimeDebug,
+    FixedU128, Perbill, RuntimeDebug,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 
@@ -50,6 +50,10 @@ impl Get<u32> for MaxCloneableNominations {
 
 pub type MaxNominations = ConstU32<300>;
 
+/// The maximum number of unbond requests a candidate, or a nominator towards a collator, can have
+/// pending at any given time
+pub type MaxUnbondRequests = ConstU32<8>;
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct Bond<AccountId, Balance> {
     pub owner: AccountId,
@@ -123,11 +127,16 @@ pub struct CollatorSnapshot<AccountId, Balance> {
     /// The total counted value locked for the collator, including the self bond + total staked by
     /// top nominators.
     pub total: Balance,
+
+    /// The share of the collator's reward taken by the collator before it is split pro-rata.
+    pub commission: Perbill,
 }
 
 impl<A: PartialEq, B: PartialEq> PartialEq for CollatorSnapshot<A, B> {
     fn eq(&self, other: &Self) -> bool {
-        let must_be_true = self.bond == other.bond && self.total == other.total;
+        let must_be_true = self.bond == other.bond &&
+            self.total == other.total &&
+            self.commission == other.commission;
         if !must_be_true {
             return false
         }
@@ -148,10 +157,117 @@ impl<A, B: Default> Default for CollatorSnapshot<A, B> {
             bond: B::default(),
             nominations: BoundedVec::default(),
             total: B::default(),
+            commission: Perbill::zero(),
         }
     }
 }
 
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// A slash reported for a collator that has not been applied yet
+pub struct UnappliedSlash<AccountId, Balance> {
+    /// The amount slashed from the collator self bond
+    pub own: Balance,
+    /// The amounts slashed from the nominations of the collator
+    pub others: BoundedVec<Bond<AccountId, Balance>, MaxNominations>,
+}
+
+/// Identifier of a nomination pool
+pub type PoolId = u32;
+
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+/// A nomination pool. The pool account nominates on behalf of its members, who own the pool
+/// stake in proportion to their shares.
+pub struct PoolInfo<T: Config> {
+    /// The account that manages the collators nominated by the pool
+    pub operator: T::AccountId,
+    /// The collators new pool stake is bonded to
+    pub collators: BoundedVec<T::AccountId, T::MaxNominationsPerNominator>,
+    /// The shares issued to the members of the pool
+    pub total_shares: BalanceOf<T>,
+    /// The staking rewards earned by the pool for each share
+    pub reward_per_share: FixedU128,
+    /// The staking rewards earned by the pool that have not been claimed yet
+    pub recorded_rewards: BalanceOf<T>,
+    /// The funds unbonded by members that have not been withdrawn yet
+    pub unbonding: BalanceOf<T>,
+    /// The stake returned to the pool account when a collator left or kicked a pool nomination,
+    /// which has not been bonded again yet
+    pub returned: BalanceOf<T>,
+}
+
+#[derive(
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    Encode,
+    Decode,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+/// An account that has joined a nomination pool
+pub struct PoolMember<T: Config> {
+    pub shares: BalanceOf<T>,
+    /// The value of `PoolInfo::reward_per_share` when the member last claimed its rewards
+    pub reward_per_share_paid: FixedU128,
+    /// The funds the member has unbonded, and the pool nominations they are unbonded from
+    pub unbonding:
+        BoundedVec<PoolUnbondChunk<T::AccountId, BalanceOf<T>>, T::MaxPoolUnbondingChunks>,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Funds a pool member has unbonded, which can be withdrawn once the pool request towards
+/// `collator` has been executed. The member owns `points` of the [`PoolUnbondingBucket`] of
+/// `collator` and `unlock_era`.
+pub struct PoolUnbondChunk<AccountId, Balance> {
+    pub collator: AccountId,
+    pub points: Balance,
+    pub unlock_era: EraIndex,
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// The funds of a pool unbonding from a collator in the same era. Members own them through
+/// points, so a slash of the pool request reduces the chunk of every member pro-rata.
+pub struct PoolUnbondingBucket<Balance> {
+    pub balance: Balance,
+    pub points: Balance,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// The block production of a collator over an era
+pub struct SlotPerformance {
+    pub era: EraIndex,
+    /// The blocks the collator was expected to author, assuming slots rotate evenly between the
+    /// selected collators
+    pub expected_slots: u32,
+    pub authored_slots: u32,
+}
+
+impl SlotPerformance {
+    pub fn missed_slots(&self) -> u32 {
+        return self.expected_slots.saturating_sub(self.authored_slots)
+    }
+
+    pub fn missed_slot_rate(&self) -> Perbill {
+        if self.expected_slots == 0 {
+            return Perbill::zero()
+        }
+
+        return Perbill::from_rational(self.missed_slots(), self.expected_slots)
+    }
+}
+
 #[derive(Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// Info needed to make delayed payments to stakers after era end
 pub struct DelayedPayout<Balance> {
@@ -166,6 +282,13 @@ pub struct CandidateBondLessRequest<Balance> {
     pub when_executable: EraIndex,
 }
 
+#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Request scheduled to increase the collator candidate commission
+pub struct CommissionChangeRequest {
+    pub commission: Perbill,
+    pub when_executable: EraIndex,
+}
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 /// Type for top and bottom nomination storage item
 pub struct Nominations<AccountId, Balance> {
@@ -275,10 +398,14 @@ pub struct CandidateMetadata<Balance> {
     pub top_capacity: CapacityStatus,
     /// Capacity status for bottom nominations
     pub bottom_capacity: CapacityStatus,
-    /// Maximum 1 pending request to decrease candidate self bond at any given time
-    pub request: Option<CandidateBondLessRequest<Balance>>,
+    /// Pending requests to decrease candidate self bond, each executable from a different era
+    pub requests: BoundedVec<CandidateBondLessRequest<Balance>, MaxUnbondRequests>,
     /// Current status of the collator
     pub status: CollatorStatus,
+    /// The share of the rewards taken by the candidate before they are split pro-rata
+    pub commission: Perbill,
+    /// Maximum 1 pending request to increase the candidate commission at any given time
+    pub commission_request: Option<CommissionChangeRequest>,
 }
 
 impl<
@@ -302,8 +429,10 @@ impl<
             lowest_bottom_nomination_amount: Zero::zero(),
             top_capacity: CapacityStatus::Empty,
             bottom_capacity: CapacityStatus::Empty,
-            request: None,
+            requests: BoundedVec::default(),
             status: CollatorStatus::Active,
+            commission: Perbill::zero(),
+            commission_request: None,
         }
     }
     pub fn is_active(&self) -> bool {
@@ -358,54 +487,76 @@ impl<
         });
         Ok(())
     }
-    /// Schedule executable decrease of collator candidate self bond
+    /// Total amount of the pending requests to decrease the self bond
+    pub fn pending_unbond(&self) -> Balance {
+        self.requests
+            .iter()
+            .fold(Zero::zero(), |total, request| total.saturating_add(request.amount))
+    }
+    /// Schedule executable decrease of collator candidate self bond. A request executable in the
+    /// same era is extended rather than adding a new one.
     /// Returns the era at which the collator can execute the pending request
     pub fn schedule_unbond<T: Config>(&mut self, less: Balance) -> Result<EraIndex, DispatchError>
     where
         BalanceOf<T>: Into<Balance>,
     {
-        // ensure no pending request
-        ensure!(self.request.is_none(), Error::<T>::PendingCandidateRequestAlreadyExists);
-        // ensure bond above min after decrease
-        ensure!(self.bond > less, Error::<T>::CandidateBondBelowMin);
+        // ensure bond above min after all pending decreases
+        let remaining_bond = self.bond.saturating_sub(self.pending_unbond());
+        ensure!(remaining_bond > less, Error::<T>::CandidateBondBelowMin);
         ensure!(
-            self.bond - less >= <MinCollatorStake<T>>::get().into(),
+            remaining_bond - less >= <MinCollatorStake<T>>::get().into(),
             Error::<T>::CandidateBondBelowMin
         );
         let when_executable = <Era<T>>::get().current + <Delay<T>>::get();
-        self.request = Some(CandidateBondLessRequest { amount: less, when_executable });
+        match self
+            .requests
+            .iter_mut()
+            .find(|request| request.when_executable == when_executable)
+        {
+            Some(request) => request.amount = request.amount.saturating_add(less),
+            None => self
+                .requests
+                .try_push(CandidateBondLessRequest { amount: less, when_executable })
+                .map_err(|_| Error::<T>::TooManyUnbondRequests)?,
+        }
         Ok(when_executable)
     }
-    /// Execute pending request to decrease the collator self bond
-    /// Returns the event to be emitted
+    /// Execute the pending requests to decrease the collator self bond that are due
     pub fn execute_unbond<T: Config>(&mut self, who: T::AccountId) -> DispatchResult
     where
         BalanceOf<T>: From<Balance>,
     {
-        let request = self.request.ok_or(Error::<T>::PendingCandidateRequestsDNE)?;
+        ensure!(!self.requests.is_empty(), Error::<T>::PendingCandidateRequestsDNE);
+        let now = <Era<T>>::get().current;
         ensure!(
-            request.when_executable <= <Era<T>>::get().current,
+            self.requests.iter().any(|request| request.when_executable <= now),
             Error::<T>::PendingCandidateRequestNotDueYet
         );
-        let new_total_staked = <Total<T>>::get().saturating_sub(request.amount.into());
+        let mut amount: Balance = Zero::zero();
+        self.requests.retain(|request| {
+            let due = request.when_executable <= now;
+            if due {
+                amount = amount.saturating_add(request.amount);
+            }
+            !due
+        });
+        let new_total_staked = <Total<T>>::get().saturating_sub(amount.into());
         <Total<T>>::put(new_total_staked);
         // Arithmetic assumptions are self.bond > less && self.bond - less > CollatorMinBond
         // (assumptions enforced by `schedule_unbond`; if storage corrupts, must re-verify)
-        self.bond = self.bond.saturating_sub(request.amount);
+        self.bond = self.bond.saturating_sub(amount);
         T::Currency::set_lock(
             COLLATOR_LOCK_ID,
             &who.clone(),
             self.bond.into(),
             WithdrawReasons::all(),
         );
-        self.total_counted = self.total_counted.saturating_sub(request.amount);
+        self.total_counted = self.total_counted.saturating_sub(amount);
         let event = Event::CandidateBondedLess {
             candidate: who.clone().into(),
-            amount: request.amount.into(),
+            amount: amount.into(),
             new_bond: self.bond.into(),
         };
-        // reset s.t. no pending request
-        self.request = None;
         // update candidate pool value because it must change if self bond changes
         if self.is_active() {
             Pallet::<T>::update_active(who.into(), self.total_counted.into());
@@ -413,21 +564,91 @@ impl<
         Pallet::<T>::deposit_event(event);
         Ok(())
     }
-    /// Cancel candidate bond less request
+    /// Cancel all the candidate bond less requests
     pub fn cancel_unbond<T: Config>(&mut self, who: T::AccountId) -> DispatchResult
     where
         BalanceOf<T>: From<Balance>,
     {
-        let request = self.request.ok_or(Error::<T>::PendingCandidateRequestsDNE)?;
-        let event = Event::CancelledCandidateBondLess {
-            candidate: who.clone().into(),
-            amount: request.amount.into(),
-            execute_era: request.when_executable,
-        };
-        self.request = None;
-        Pallet::<T>::deposit_event(event);
+        ensure!(!self.requests.is_empty(), Error::<T>::PendingCandidateRequestsDNE);
+        for request in sp_std::mem::take(&mut self.requests) {
+            Pallet::<T>::deposit_event(Event::CancelledCandidateBondLess {
+                candidate: who.clone().into(),
+                amount: request.amount.into(),
+                execute_era: request.when_executable,
+            });
+        }
         Ok(())
     }
+    /// Re-stake `amount` of the pending self bond decrease, taken from the requests that unlock
+    /// last.
+    /// Returns the amount that remains scheduled to be unbonded
+    pub fn rebond<T: Config>(&mut self, amount: Balance) -> Result<Balance, DispatchError> {
+        ensure!(!amount.is_zero(), Error::<T>::RebondAmountZero);
+        ensure!(!self.requests.is_empty(), Error::<T>::PendingCandidateRequestsDNE);
+        ensure!(amount <= self.pending_unbond(), Error::<T>::RebondAmountExceedsPendingUnbond);
+
+        self.remove_latest_unbond(amount);
+
+        Ok(self.pending_unbond())
+    }
+    /// Trim the pending requests to decrease the self bond, starting from the ones that unlock
+    /// last, so that executing them leaves at least the minimum collator stake bonded. Used when
+    /// the bond is slashed after the requests were scheduled.
+    pub fn trim_unbond<T: Config>(&mut self)
+    where
+        BalanceOf<T>: Into<Balance>,
+    {
+        let max_unbond = self.bond.saturating_sub(<MinCollatorStake<T>>::get().into());
+        let excess = self.pending_unbond().saturating_sub(max_unbond);
+        if !excess.is_zero() {
+            self.remove_latest_unbond(excess);
+        }
+    }
+    /// Remove `amount` from the pending requests to decrease the self bond, starting from the ones
+    /// that unlock last
+    fn remove_latest_unbond(&mut self, amount: Balance) {
+        let mut requests = self.requests.clone().into_inner();
+        requests.sort_by_key(|request| request.when_executable);
+        let mut to_remove = amount;
+        while !to_remove.is_zero() {
+            let Some(latest) = requests.last_mut() else { break };
+            if latest.amount > to_remove {
+                latest.amount -= to_remove;
+                to_remove = Zero::zero();
+            } else {
+                to_remove -= latest.amount;
+                requests.pop();
+            }
+        }
+        self.requests = BoundedVec::truncate_from(requests);
+    }
+    /// The commission in effect during `era`, including a pending increase that is due
+    pub fn effective_commission(&self, era: EraIndex) -> Perbill {
+        match self.commission_request {
+            Some(request) if request.when_executable <= era => request.commission,
+            _ => self.commission,
+        }
+    }
+    /// Set the candidate commission. Decreases apply immediately, increases are scheduled to
+    /// apply after the commission increase delay.
+    /// Returns the era from which the new commission applies
+    pub fn set_commission<T: Config>(
+        &mut self,
+        commission: Perbill,
+    ) -> Result<EraIndex, DispatchError> {
+        ensure!(commission <= T::MaxCommission::get(), Error::<T>::CommissionTooHigh);
+        let now = <Era<T>>::get().current;
+        // apply a pending increase that is already due before comparing
+        self.commission = self.effective_commission(now);
+        self.commission_request = None;
+        if commission <= self.commission {
+            self.commission = commission;
+            return Ok(now)
+        }
+        let when_executable = now.saturating_add(T::CommissionIncreaseDelay::get());
+        self.commission_request = Some(CommissionChangeRequest { commission, when_executable });
+        Ok(when_executable)
+    }
     /// Reset top nominations metadata
     pub fn reset_top_data<T: Config>(
         &mut self,
@@ -574,12 +795,19 @@ impl<
                 .expect("Nomination existence => NominatorState existence");
             let leaving = nominator_state.nominations.0.len() == 1usize;
             nominator_state.rm_nomination::<T>(candidate);
+            let less_total = nominator_state.less_total;
             <Pallet<T>>::nomination_remove_request_with_state(
                 &candidate,
                 &lowest_bottom_to_be_kicked.owner,
                 &mut nominator_state,
             );
+            <Pallet<T>>::record_returned_pool_stake(
+                &lowest_bottom_to_be_kicked.owner,
+                lowest_bottom_to_be_kicked.amount,
+                less_total.saturating_sub(nominator_state.less_total),
+            );
 
+            <AutoCompound<T>>::remove(candidate, &lowest_bottom_to_be_kicked.owner);
             Pallet::<T>::deposit_event(Event::NominationKicked {
                 nominator: lowest_bottom_to_be_kicked.owner.clone(),
                 candidate: candidate.clone(),
@@ -1438,6 +1666,8 @@ pub enum AdminSettings<Balance> {
     MinCollatorStake(Balance),
     /// Minimum nominator stake amount
     MinTotalNominatorStake(Balance),
+    /// The share of its expected slots a collator can miss in an era before it is deprioritised
+    MaxMissedSlotRate(Perbill),
 }
 
 impl<
@@ -1462,6 +1692,7 @@ impl<
             AdminSettings::MinTotalNominatorStake(s) =>
                 s >= &<<T as Config>::MinNominationPerCollator as Get<BalanceOf<T>>>::get().into(),
             AdminSettings::MinCollatorStake(_) => true,
+            AdminSettings::MaxMissedSlotRate(_) => true,
             _ => false,
         }
     }
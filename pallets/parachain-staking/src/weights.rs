@@ -76,6 +76,22 @@ pub trait WeightInfo {
 	fn select_top_candidates() -> Weight;
 	fn note_author() -> Weight;
 	fn set_admin_setting() -> Weight;
+	fn set_commission() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn signed_set_auto_compound() -> Weight;
+	fn cancel_deferred_slash() -> Weight;
+	fn apply_slash(x: u32, ) -> Weight;
+	fn create_pool() -> Weight;
+	fn set_pool_collators() -> Weight;
+	fn join_pool() -> Weight;
+	fn unbond_from_pool() -> Weight;
+	fn withdraw_from_pool(x: u32, ) -> Weight;
+	fn claim_pool_rewards() -> Weight;
+	fn record_collator_performance(x: u32, ) -> Weight;
+	fn candidate_rebond() -> Weight;
+	fn signed_candidate_rebond() -> Weight;
+	fn nominator_rebond() -> Weight;
+	fn signed_nominator_rebond() -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Substrate node and recommended hardware.
@@ -108,7 +124,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
@@ -143,7 +159,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(x.into()))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -163,7 +179,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
@@ -177,28 +193,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `System::Account` (r:350 w:350)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::BottomNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::BottomNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
 	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolAccounts` (r:349 w:0)
+	/// Proof: `ParachainStaking::PoolAccounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Pools` (r:349 w:349)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[2, 350]`.
 	fn execute_leave_candidates(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `7980 + x * (476 ±0)`
-		//  Estimated: `17971 + x * (16837 ±0)`
-		// Minimum execution time: 139_133_000 picoseconds.
+		//  Measured:  `7980 + x * (512 ±0)`
+		//  Estimated: `17971 + x * (25145 ±0)`
+		// Minimum execution time: 146_418_000 picoseconds.
 		Weight::from_parts(55_697_684, 17971)
-			// Standard Error: 60_478
-			.saturating_add(Weight::from_parts(38_027_760, 0).saturating_mul(x.into()))
+			// Standard Error: 64_113
+			.saturating_add(Weight::from_parts(44_375_210, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 16837).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 25145).saturating_mul(x.into()))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
 	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[3, 97]`.
@@ -214,7 +234,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
 	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
 	fn go_offline() -> Weight {
@@ -227,7 +247,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
 	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
 	fn go_online() -> Weight {
@@ -236,11 +258,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6287`
 		// Minimum execution time: 27_931_000 picoseconds.
 		Weight::from_parts(28_431_000, 6287)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -263,7 +285,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
 	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -284,7 +306,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
@@ -303,7 +325,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
 	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
@@ -320,7 +342,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -345,7 +367,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
 	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -368,7 +390,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	fn cancel_candidate_unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `587`
@@ -383,7 +405,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -396,20 +418,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolAccounts` (r:1 w:0)
+	/// Proof: `ParachainStaking::PoolAccounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[3, 100]`.
 	/// The range of component `y` is `[2, 300]`.
 	fn nominate(x: u32, y: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2542 + x * (104 ±0) + y * (50 ±0)`
 		//  Estimated: `17971 + y * (5 ±0)`
-		// Minimum execution time: 106_083_000 picoseconds.
-		Weight::from_parts(112_094_917, 17971)
+		// Minimum execution time: 112_530_000 picoseconds.
+		Weight::from_parts(118_641_917, 17971)
 			// Standard Error: 14_730
 			.saturating_add(Weight::from_parts(154_723, 0).saturating_mul(x.into()))
 			// Standard Error: 4_832
 			.saturating_add(Weight::from_parts(52_347, 0).saturating_mul(y.into()))
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 			.saturating_add(Weight::from_parts(0, 5).saturating_mul(y.into()))
 	}
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
@@ -421,7 +447,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:97 w:96)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:96 w:96)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -455,7 +481,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
 	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn schedule_leave_nominators() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `638`
@@ -474,7 +500,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
 	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn signed_schedule_leave_nominators() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
@@ -489,9 +515,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:96 w:96)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:96 w:96)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:96 w:96)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -526,9 +552,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:96 w:96)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:96 w:96)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:96 w:96)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -559,7 +585,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn cancel_leave_nominators() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `691`
@@ -572,7 +598,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -591,7 +617,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -606,7 +632,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -616,7 +642,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -639,13 +665,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -664,7 +690,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
@@ -687,7 +713,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -704,7 +730,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -714,7 +740,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -733,11 +759,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -768,9 +794,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -797,7 +823,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn cancel_revoke_nomination() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `691`
@@ -810,7 +836,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn cancel_nominator_unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `785`
@@ -843,9 +869,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:22 w:0)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:22 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:22 w:0)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:0)
@@ -919,9 +945,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:20 w:0)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:20 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:20 w:0)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::SelectedCandidates` (r:0 w:1)
@@ -943,14 +969,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ParachainStaking::AwardedPts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Points` (r:1 w:1)
 	/// Proof: `ParachainStaking::Points` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `ParachainStaking::AuthoredBlocks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	fn note_author() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `368`
 		//  Estimated: `3521`
-		// Minimum execution time: 15_701_000 picoseconds.
-		Weight::from_parts(15_890_000, 3521)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 19_342_000 picoseconds.
+		Weight::from_parts(19_805_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ParachainStaking::Delay` (r:0 w:1)
 	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -962,6 +990,344 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_231_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `3630`
+		// Minimum execution time: 17_480_000 picoseconds.
+		Weight::from_parts(18_012_000, 3630)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `ParachainStaking::AutoCompound` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1231`
+		//  Estimated: `17971`
+		// Minimum execution time: 21_370_000 picoseconds.
+		Weight::from_parts(22_104_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `ParachainStaking::AutoCompound` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn signed_set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1385`
+		//  Estimated: `17971`
+		// Minimum execution time: 74_860_000 picoseconds.
+		Weight::from_parts(76_011_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `ParachainStaking::UnappliedSlashes` (`max_values`: None, `max_size`: Some(14494), added: 16969, mode: `MaxEncodedLen`)
+	fn cancel_deferred_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `446`
+		//  Estimated: `17959`
+		// Minimum execution time: 19_630_000 picoseconds.
+		Weight::from_parts(20_241_000, 17959)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:301 w:301)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:301 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:301 w:301)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:300 w:300)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 300]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502 + x * (1447 ±0)`
+		//  Estimated: `17923 + x * (16981 ±0)`
+		// Minimum execution time: 61_120_000 picoseconds.
+		Weight::from_parts(63_408_000, 17923)
+			// Standard Error: 21_337
+			.saturating_add(Weight::from_parts(88_416_202, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 27836).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Pools` (r:0 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolAccounts` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolAccounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1430`
+		//  Estimated: `17971`
+		// Minimum execution time: 118_412_000 picoseconds.
+		Weight::from_parts(121_906_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	fn set_pool_collators() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `741`
+		//  Estimated: `6763`
+		// Minimum execution time: 21_530_000 picoseconds.
+		Weight::from_parts(22_318_000, 6763)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn join_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2218`
+		//  Estimated: `17971`
+		// Minimum execution time: 126_880_000 picoseconds.
+		Weight::from_parts(130_224_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
+	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolUnbonding` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolUnbonding` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn unbond_from_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2387`
+		//  Estimated: `17971`
+		// Minimum execution time: 118_604_000 picoseconds.
+		Weight::from_parts(121_377_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:16 w:16)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:16 w:16)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:16 w:16)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolUnbonding` (r:16 w:16)
+	/// Proof: `ParachainStaking::PoolUnbonding` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn withdraw_from_pool(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2347 + x * (274 ±0)`
+		//  Estimated: `17971 + x * (7825 ±0)`
+		// Minimum execution time: 84_760_000 picoseconds.
+		Weight::from_parts(87_018_000, 17971)
+			// Standard Error: 18_204
+			.saturating_add(Weight::from_parts(62_413_850, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 10388).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_pool_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `17971`
+		// Minimum execution time: 52_410_000 picoseconds.
+		Weight::from_parts(54_102_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::SelectedCandidates` (r:1 w:0)
+	/// Proof: `ParachainStaking::SelectedCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MaxMissedSlotRate` (r:1 w:0)
+	/// Proof: `ParachainStaking::MaxMissedSlotRate` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CollatorPerformance` (r:100 w:200)
+	/// Proof: `ParachainStaking::CollatorPerformance` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AuthoredBlocks` (r:100 w:100)
+	/// Proof: `ParachainStaking::AuthoredBlocks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AwardedPts` (r:100 w:100)
+	/// Proof: `ParachainStaking::AwardedPts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Points` (r:1 w:1)
+	/// Proof: `ParachainStaking::Points` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn record_collator_performance(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482 + x * (118 ±0)`
+		//  Estimated: `4687 + x * (2535 ±0)`
+		// Minimum execution time: 24_910_000 picoseconds.
+		Weight::from_parts(25_730_000, 4687)
+			// Standard Error: 6_912
+			.saturating_add(Weight::from_parts(31_226_405, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
+	fn candidate_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `667`
+		//  Estimated: `3757`
+		// Minimum execution time: 22_140_000 picoseconds.
+		Weight::from_parts(22_781_000, 3757)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
+	fn signed_candidate_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `752`
+		//  Estimated: `3757`
+		// Minimum execution time: 168_902_000 picoseconds.
+		Weight::from_parts(172_513_000, 3757)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	fn nominator_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `785`
+		//  Estimated: `17971`
+		// Minimum execution time: 28_420_000 picoseconds.
+		Weight::from_parts(29_130_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	fn signed_nominator_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `870`
+		//  Estimated: `17971`
+		// Minimum execution time: 176_311_000 picoseconds.
+		Weight::from_parts(180_062_000, 17971)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -993,7 +1359,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
@@ -1028,7 +1394,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(x.into()))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -1048,7 +1414,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
@@ -1062,28 +1428,32 @@ impl WeightInfo for () {
 	/// Storage: `System::Account` (r:350 w:350)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::BottomNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::BottomNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
 	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolAccounts` (r:349 w:0)
+	/// Proof: `ParachainStaking::PoolAccounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Pools` (r:349 w:349)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[2, 350]`.
 	fn execute_leave_candidates(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `7980 + x * (476 ±0)`
-		//  Estimated: `17971 + x * (16837 ±0)`
-		// Minimum execution time: 139_133_000 picoseconds.
+		//  Measured:  `7980 + x * (512 ±0)`
+		//  Estimated: `17971 + x * (25145 ±0)`
+		// Minimum execution time: 146_418_000 picoseconds.
 		Weight::from_parts(55_697_684, 17971)
-			// Standard Error: 60_478
-			.saturating_add(Weight::from_parts(38_027_760, 0).saturating_mul(x.into()))
+			// Standard Error: 64_113
+			.saturating_add(Weight::from_parts(44_375_210, 0).saturating_mul(x.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 16837).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 25145).saturating_mul(x.into()))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
 	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[3, 97]`.
@@ -1099,7 +1469,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
 	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
 	fn go_offline() -> Weight {
@@ -1112,7 +1482,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
 	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
 	fn go_online() -> Weight {
@@ -1121,11 +1493,11 @@ impl WeightInfo for () {
 		//  Estimated: `6287`
 		// Minimum execution time: 27_931_000 picoseconds.
 		Weight::from_parts(28_431_000, 6287)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -1148,7 +1520,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
 	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -1169,7 +1541,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
@@ -1188,7 +1560,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
 	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
@@ -1205,7 +1577,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -1230,7 +1602,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
 	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:1)
@@ -1253,7 +1625,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	fn cancel_candidate_unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `587`
@@ -1268,7 +1640,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1281,20 +1653,24 @@ impl WeightInfo for () {
 	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolAccounts` (r:1 w:0)
+	/// Proof: `ParachainStaking::PoolAccounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[3, 100]`.
 	/// The range of component `y` is `[2, 300]`.
 	fn nominate(x: u32, y: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2542 + x * (104 ±0) + y * (50 ±0)`
 		//  Estimated: `17971 + y * (5 ±0)`
-		// Minimum execution time: 106_083_000 picoseconds.
-		Weight::from_parts(112_094_917, 17971)
+		// Minimum execution time: 112_530_000 picoseconds.
+		Weight::from_parts(118_641_917, 17971)
 			// Standard Error: 14_730
 			.saturating_add(Weight::from_parts(154_723, 0).saturating_mul(x.into()))
 			// Standard Error: 4_832
 			.saturating_add(Weight::from_parts(52_347, 0).saturating_mul(y.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 			.saturating_add(Weight::from_parts(0, 5).saturating_mul(y.into()))
 	}
 	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
@@ -1306,7 +1682,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:97 w:96)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:96 w:96)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1340,7 +1716,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
 	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn schedule_leave_nominators() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `638`
@@ -1359,7 +1735,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
 	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn signed_schedule_leave_nominators() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
@@ -1374,9 +1750,9 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:96 w:96)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:96 w:96)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:96 w:96)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1411,9 +1787,9 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:96 w:96)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:96 w:96)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:96 w:96)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1444,7 +1820,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn cancel_leave_nominators() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `691`
@@ -1457,7 +1833,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -1476,7 +1852,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -1491,7 +1867,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -1501,7 +1877,7 @@ impl WeightInfo for () {
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1524,13 +1900,13 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1549,7 +1925,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
@@ -1572,7 +1948,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
@@ -1589,7 +1965,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -1599,7 +1975,7 @@ impl WeightInfo for () {
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
@@ -1618,11 +1994,11 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Era` (r:1 w:0)
 	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -1653,9 +2029,9 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -1682,7 +2058,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn cancel_revoke_nomination() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `691`
@@ -1695,7 +2071,7 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
 	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	fn cancel_nominator_unbond() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `785`
@@ -1728,9 +2104,9 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:22 w:0)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:22 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:22 w:0)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Total` (r:1 w:0)
@@ -1804,9 +2180,9 @@ impl WeightInfo for () {
 	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
 	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::CandidateInfo` (r:20 w:0)
-	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:20 w:0)
-	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(5350), added: 7825, mode: `MaxEncodedLen`)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::TopNominations` (r:20 w:0)
 	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::SelectedCandidates` (r:0 w:1)
@@ -1828,14 +2204,16 @@ impl WeightInfo for () {
 	/// Proof: `ParachainStaking::AwardedPts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// Storage: `ParachainStaking::Points` (r:1 w:1)
 	/// Proof: `ParachainStaking::Points` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `ParachainStaking::AuthoredBlocks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	fn note_author() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `368`
 		//  Estimated: `3521`
-		// Minimum execution time: 15_701_000 picoseconds.
-		Weight::from_parts(15_890_000, 3521)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Minimum execution time: 19_342_000 picoseconds.
+		Weight::from_parts(19_805_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ParachainStaking::Delay` (r:0 w:1)
 	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -1847,4 +2225,342 @@ impl WeightInfo for () {
 		Weight::from_parts(9_231_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn set_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `3630`
+		// Minimum execution time: 17_480_000 picoseconds.
+		Weight::from_parts(18_012_000, 3630)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `ParachainStaking::AutoCompound` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1231`
+		//  Estimated: `17971`
+		// Minimum execution time: 21_370_000 picoseconds.
+		Weight::from_parts(22_104_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompound` (r:0 w:1)
+	/// Proof: `ParachainStaking::AutoCompound` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn signed_set_auto_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1385`
+		//  Estimated: `17971`
+		// Minimum execution time: 74_860_000 picoseconds.
+		Weight::from_parts(76_011_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `ParachainStaking::UnappliedSlashes` (`max_values`: None, `max_size`: Some(14494), added: 16969, mode: `MaxEncodedLen`)
+	fn cancel_deferred_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `446`
+		//  Estimated: `17959`
+		// Minimum execution time: 19_630_000 picoseconds.
+		Weight::from_parts(20_241_000, 17959)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:301 w:301)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:301 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:301 w:301)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinCollatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinCollatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:300 w:300)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 300]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1502 + x * (1447 ±0)`
+		//  Estimated: `17923 + x * (16981 ±0)`
+		// Minimum execution time: 61_120_000 picoseconds.
+		Weight::from_parts(63_408_000, 17923)
+			// Standard Error: 21_337
+			.saturating_add(Weight::from_parts(88_416_202, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 27836).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Pools` (r:0 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolAccounts` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolAccounts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1430`
+		//  Estimated: `17971`
+		// Minimum execution time: 118_412_000 picoseconds.
+		Weight::from_parts(121_906_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	fn set_pool_collators() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `741`
+		//  Estimated: `6763`
+		// Minimum execution time: 21_530_000 picoseconds.
+		Weight::from_parts(22_318_000, 6763)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn join_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2218`
+		//  Estimated: `17971`
+		// Minimum execution time: 126_880_000 picoseconds.
+		Weight::from_parts(130_224_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Delay` (r:1 w:0)
+	/// Proof: `ParachainStaking::Delay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MinTotalNominatorStake` (r:1 w:0)
+	/// Proof: `ParachainStaking::MinTotalNominatorStake` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolUnbonding` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolUnbonding` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn unbond_from_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2387`
+		//  Estimated: `17971`
+		// Minimum execution time: 118_604_000 picoseconds.
+		Weight::from_parts(121_377_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Era` (r:1 w:0)
+	/// Proof: `ParachainStaking::Era` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:16 w:16)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:16 w:16)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::TopNominations` (r:16 w:16)
+	/// Proof: `ParachainStaking::TopNominations` (`max_values`: None, `max_size`: Some(14458), added: 16933, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolUnbonding` (r:16 w:16)
+	/// Proof: `ParachainStaking::PoolUnbonding` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 16]`.
+	fn withdraw_from_pool(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2347 + x * (274 ±0)`
+		//  Estimated: `17971 + x * (7825 ±0)`
+		// Minimum execution time: 84_760_000 picoseconds.
+		Weight::from_parts(87_018_000, 17971)
+			// Standard Error: 18_204
+			.saturating_add(Weight::from_parts(62_413_850, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 10388).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: Some(3314), added: 5789, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::PoolMembers` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolMembers` (`max_values`: None, `max_size`: Some(881), added: 3356, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:0)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_pool_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1612`
+		//  Estimated: `17971`
+		// Minimum execution time: 52_410_000 picoseconds.
+		Weight::from_parts(54_102_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ParachainStaking::SelectedCandidates` (r:1 w:0)
+	/// Proof: `ParachainStaking::SelectedCandidates` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::MaxMissedSlotRate` (r:1 w:0)
+	/// Proof: `ParachainStaking::MaxMissedSlotRate` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CollatorPerformance` (r:100 w:200)
+	/// Proof: `ParachainStaking::CollatorPerformance` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AuthoredBlocks` (r:100 w:100)
+	/// Proof: `ParachainStaking::AuthoredBlocks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AwardedPts` (r:100 w:100)
+	/// Proof: `ParachainStaking::AwardedPts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::Points` (r:1 w:1)
+	/// Proof: `ParachainStaking::Points` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn record_collator_performance(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `482 + x * (118 ±0)`
+		//  Estimated: `4687 + x * (2535 ±0)`
+		// Minimum execution time: 24_910_000 picoseconds.
+		Weight::from_parts(25_730_000, 4687)
+			// Standard Error: 6_912
+			.saturating_add(Weight::from_parts(31_226_405, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2535).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
+	fn candidate_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `667`
+		//  Estimated: `3757`
+		// Minimum execution time: 22_140_000 picoseconds.
+		Weight::from_parts(22_781_000, 3757)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: Some(292), added: 2767, mode: `MaxEncodedLen`)
+	fn signed_candidate_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `752`
+		//  Estimated: `3757`
+		// Minimum execution time: 168_902_000 picoseconds.
+		Weight::from_parts(172_513_000, 3757)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	fn nominator_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `785`
+		//  Estimated: `17971`
+		// Minimum execution time: 28_420_000 picoseconds.
+		Weight::from_parts(29_130_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::ProxyNonces` (r:1 w:1)
+	/// Proof: `ParachainStaking::ProxyNonces` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominatorState` (`max_values`: None, `max_size`: Some(14506), added: 16981, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::NominationScheduledRequests` (r:1 w:1)
+	/// Proof: `ParachainStaking::NominationScheduledRequests` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+	fn signed_nominator_rebond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `870`
+		//  Estimated: `17971`
+		// Minimum execution time: 176_311_000 picoseconds.
+		Weight::from_parts(180_062_000, 17971)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
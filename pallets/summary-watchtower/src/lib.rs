@@ -335,7 +335,7 @@ pub mod pallet {
         }
     }
 
-    impl<T: Config> WatchtowerHooks<Proposal<T>> for Pallet<T> {
+    impl<T: Config> WatchtowerHooks<Proposal<T>, T::AccountId> for Pallet<T> {
         fn on_proposal_submitted(proposal_id: ProposalId, proposal: Proposal<T>) -> DispatchResult {
             match &proposal.source {
                 // it source is Internal(ProposalType::Anchor) or Internal(ProposalType::Summary)
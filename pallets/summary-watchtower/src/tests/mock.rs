@@ -51,12 +51,15 @@ impl pallet_watchtower::Config for TestRuntime {
     type Signature = Signature;
     type Watchtowers = TestNodeManager;
     type WatchtowerHooks = SummaryWatchtower;
+    type PayloadValidator = ();
     type WeightInfo = ();
     type SignedTxLifetime = ConstU32<5>;
     type MaxTitleLen = ConstU32<512>;
     type MaxInlineLen = ConstU32<8192>;
     type MaxUriLen = ConstU32<2040>;
     type MaxInternalProposalLen = ConstU32<100>;
+    type MaxWeightedVoters = ConstU32<100>;
+    type MaxActiveExternalProposals = ConstU32<10>;
 }
 
 impl Config for TestRuntime {
@@ -296,6 +299,15 @@ impl NodesInterface<AccountId, SignerId> for TestNodeManager {
         NODE_SIGNING_KEYS.with(|keys| keys.borrow().get(node).cloned())
     }
 
+    fn get_node_owner(node: &AccountId) -> Option<AccountId> {
+        NODE_OWNERS.with(|keys| {
+            keys.borrow()
+                .iter()
+                .find(|(_, nodes)| nodes.contains(node))
+                .map(|(owner, _)| owner.clone())
+        })
+    }
+
     fn get_node_from_local_signing_keys() -> Option<(AccountId, SignerId)> {
         let maybe_watchtower_1 =
             AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().first().unwrap().clone());
@@ -311,6 +323,10 @@ impl NodesInterface<AccountId, SignerId> for TestNodeManager {
         NODE_OWNERS.with(|keys| keys.borrow().get(owner).map_or(0, |v| v.len() as u32))
     }
 
+    fn get_watchtower_stakes(_source: &ProposalSource) -> Vec<(AccountId, u128)> {
+        Vec::new()
+    }
+
     fn get_authorized_watchtowers_count() -> u32 {
         AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().len() as u32)
     }
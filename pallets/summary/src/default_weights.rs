@@ -271,6 +271,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Summary::TxIdToRoot` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::NextBlockToProcess` (r:0 w:1)
 	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::ValidatedRootsCount` (r:1 w:1)
+	/// Proof: `Summary::ValidatedRootsCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::ValidatedRoots` (r:0 w:1)
+	/// Proof: `Summary::ValidatedRoots` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn end_voting_period_with_rejected_valid_votes(v: u32, o: u32, ) -> Weight {
@@ -281,9 +285,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(260_940_466, 22431)
 			// Standard Error: 430_089
 			.saturating_add(Weight::from_parts(11_808_017, 0).saturating_mul(o.into()))
-			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -726,6 +730,10 @@ impl WeightInfo for () {
 	/// Proof: `Summary::TxIdToRoot` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// Storage: `Summary::NextBlockToProcess` (r:0 w:1)
 	/// Proof: `Summary::NextBlockToProcess` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::ValidatedRootsCount` (r:1 w:1)
+	/// Proof: `Summary::ValidatedRootsCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Summary::ValidatedRoots` (r:0 w:1)
+	/// Proof: `Summary::ValidatedRoots` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	/// The range of component `v` is `[7, 10]`.
 	/// The range of component `o` is `[1, 2]`.
 	fn end_voting_period_with_rejected_valid_votes(v: u32, o: u32, ) -> Weight {
@@ -736,9 +744,9 @@ impl WeightInfo for () {
 		Weight::from_parts(260_940_466, 22431)
 			// Standard Error: 430_089
 			.saturating_add(Weight::from_parts(11_808_017, 0).saturating_mul(o.into()))
-			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(o.into())))
-			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
 			.saturating_add(Weight::from_parts(0, 2524).saturating_mul(o.into()))
 			.saturating_add(Weight::from_parts(0, 67).saturating_mul(v.into()))
@@ -8,8 +8,10 @@ use alloc::string::ToString;
 use codec::{Decode, Encode, MaxEncodedLen};
 use sp_avn_common::{
     event_types::Validator,
+    merkle,
     ocw_lock::{self as OcwLock},
     safe_add_block_numbers, safe_sub_block_numbers, BridgeContractMethod, IngressCounter,
+    SummaryLeafProvider,
 };
 use sp_runtime::{
     scale_info::TypeInfo,
@@ -63,10 +65,10 @@ const ERROR_CODE_VALIDATOR_IS_NOT_PRIMARY: u8 = 10;
 const ERROR_CODE_INVALID_ROOT_RANGE: u8 = 30;
 
 const MIN_SCHEDULE_PERIOD: u32 = 30; // 6 MINUTES
-const DEFAULT_SCHEDULE_PERIOD: u32 = 28800; // 1 DAY
+pub const DEFAULT_SCHEDULE_PERIOD: u32 = 28800; // 1 DAY
 const MIN_VOTING_PERIOD: u32 = 25; // 5 MINUTES
 const MAX_VOTING_PERIOD: u32 = 28800; // 1 DAY
-const DEFAULT_VOTING_PERIOD: u32 = 600; // 30 MINUTES
+pub const DEFAULT_VOTING_PERIOD: u32 = 600; // 30 MINUTES
 
 const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -147,6 +149,10 @@ pub mod pallet {
         /// disable.
         #[pallet::no_default_bounds]
         type ExternalValidator: WatchtowerInterface;
+        /// A type that provides the leaves of the summary tree from on-chain state. Use `()` to
+        /// summarise extrinsics through the external service.
+        #[pallet::no_default_bounds]
+        type LeafProvider: SummaryLeafProvider<BlockNumberFor<Self>>;
     }
 
     #[pallet::pallet]
@@ -368,6 +374,16 @@ pub mod pallet {
     pub type AnchorRoots<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Blake2_128Concat, u32, H256, ValueQuery>;
 
+    /// Validated roots in the order they were validated, which is also the order of their ranges.
+    /// Roots validated before this index was added are not included.
+    #[pallet::storage]
+    pub type ValidatedRoots<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, u32, RootId<BlockNumberFor<T>>, OptionQuery>;
+
+    /// The number of roots in [`ValidatedRoots`]
+    #[pallet::storage]
+    pub type ValidatedRootsCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
     /// Map from RootId to the status of its external validation
     #[pallet::storage]
     pub type ExternalValidationStatus<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -1024,6 +1040,18 @@ pub mod pallet {
             from_block: BlockNumberFor<T>,
             to_block: BlockNumberFor<T>,
         ) -> Result<H256, DispatchError> {
+            if let Some(leaves) = T::LeafProvider::leaves(from_block, to_block) {
+                let root_hash = merkle::generate_tree_root(&leaves).unwrap_or(Self::empty_root());
+                log::trace!(
+                    target: "avn",
+                    "🥽 Instance({}) Calculated root hash {:?} from {} leaves",
+                    T::InstanceId::get(),
+                    &root_hash,
+                    leaves.len()
+                );
+                return Ok(root_hash)
+            }
+
             let from_block_number: u32 = TryInto::<u32>::try_into(from_block)
                 .map_err(|_| Error::<T, I>::ErrorConvertingBlockNumber)?;
             let to_block_number: u32 = TryInto::<u32>::try_into(to_block)
@@ -1402,6 +1430,9 @@ pub mod pallet {
                 <Roots<T, I>>::mutate(root_id.range, root_id.ingress_counter, |root| {
                     root.is_validated = true
                 });
+                let validated_roots_count = <ValidatedRootsCount<T, I>>::get();
+                <ValidatedRoots<T, I>>::insert(validated_roots_count, root_id);
+                <ValidatedRootsCount<T, I>>::put(validated_roots_count.saturating_add(1));
                 <SlotOfLastPublishedSummary<T, I>>::put(Self::current_slot());
 
                 Self::deposit_event(Event::<T, I>::SummaryRootValidated {
@@ -1559,12 +1590,33 @@ pub mod pallet {
             Err(Error::<T, I>::RootDataNotFound)?
         }
 
+        /// The validated root whose range includes `block_number`, if any. Binary searches
+        /// [`ValidatedRoots`], so only roots validated since the index was added are found.
+        pub fn validated_root_covering(
+            block_number: BlockNumberFor<T>,
+        ) -> Option<(RootId<BlockNumberFor<T>>, RootData<T::AccountId>)> {
+            let (mut low, mut high) = (0u32, <ValidatedRootsCount<T, I>>::get());
+            while low < high {
+                let middle = low + (high - low) / 2;
+                let root_id = <ValidatedRoots<T, I>>::get(middle)?;
+                if block_number < root_id.range.from_block {
+                    high = middle;
+                } else if block_number > root_id.range.to_block {
+                    low = middle + 1;
+                } else {
+                    return Some((root_id, Self::try_get_root_data(&root_id).ok()?))
+                }
+            }
+
+            None
+        }
+
         pub(crate) fn pallet_id() -> Vec<u8> {
             [PALLET_ID.to_vec(), vec![T::InstanceId::get()]].concat()
         }
     }
 
-    impl<T: Config<I>, I: 'static, P> WatchtowerHooks<P> for Pallet<T, I> {
+    impl<T: Config<I>, I: 'static, P, A> WatchtowerHooks<P, A> for Pallet<T, I> {
         fn on_proposal_submitted(_id: ProposalId, _p: P) -> DispatchResult {
             Ok(())
         }
@@ -1644,6 +1696,10 @@ impl<T: Config<I>, I: 'static> BridgeInterfaceNotification for Pallet<T, I> {
                 <Roots<T, I>>::mutate(root_id.range, root_id.ingress_counter, |root| {
                     root.is_finalised = true;
                 });
+                T::LeafProvider::on_leaves_published(
+                    root_id.range.from_block,
+                    root_id.range.to_block,
+                );
                 log::info!(
                     "✅  Transaction with ID {} was successfully published to Ethereum.",
                     tx_id
@@ -1694,3 +1750,7 @@ mod test_ocw_locks;
 #[cfg(test)]
 #[path = "tests/anchor_tests.rs"]
 mod anchor_tests;
+
+#[cfg(test)]
+#[path = "tests/tests_leaf_provider.rs"]
+mod tests_leaf_provider;
@@ -26,6 +26,22 @@ pub enum SummaryOffenceType {
 }
 use crate::Event;
 
+impl SummaryOffenceType {
+    /// The fraction of stake slashed for the offence. Liveness faults are slashed lightly because
+    /// they can be caused by network issues, faults against the validity of roots are not.
+    pub fn slash_fraction(&self) -> Perbill {
+        match self {
+            SummaryOffenceType::SlotNotAdvanced | SummaryOffenceType::NoSummaryCreated =>
+                Perbill::from_parts(1_000_000),
+            SummaryOffenceType::InvalidSignatureSubmitted |
+            SummaryOffenceType::InvalidVoteSubmitted => Perbill::from_percent(1),
+            SummaryOffenceType::RejectedValidRoot => Perbill::from_percent(5),
+            SummaryOffenceType::CreatedInvalidRoot | SummaryOffenceType::ApprovedInvalidRoot =>
+                Perbill::from_percent(10),
+        }
+    }
+}
+
 #[derive(PartialEq, Clone, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct SummaryOffence<Offender> {
     /// The current session index in which we report the validators that submitted an invalid
@@ -60,8 +76,7 @@ impl<Offender: Clone> Offence<Offender> for SummaryOffence<Offender> {
     }
 
     fn slash_fraction(&self, _offenders: u32) -> Perbill {
-        // We don't implement fraction slashes at the moment.
-        Perbill::from_percent(100)
+        self.offence_type.slash_fraction()
     }
 }
 
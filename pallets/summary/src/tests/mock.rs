@@ -368,6 +368,22 @@ thread_local! {
     static ETH_PUBLIC_KEY_VALID: RefCell<bool> = RefCell::new(true);
 
     static MOCK_RECOVERED_ACCOUNT_ID: RefCell<AccountId> = RefCell::new(FIRST_VALIDATOR_INDEX);
+
+    static MOCK_SUMMARY_LEAVES: RefCell<Option<Vec<Vec<u8>>>> = RefCell::new(None);
+}
+
+/// Provides the leaves set with `set_leaves`, or none so the roots come from the external service.
+pub struct TestLeafProvider;
+impl TestLeafProvider {
+    pub fn set_leaves(leaves: Option<Vec<Vec<u8>>>) {
+        MOCK_SUMMARY_LEAVES.with(|l| *l.borrow_mut() = leaves);
+    }
+}
+
+impl SummaryLeafProvider<BlockNumber> for TestLeafProvider {
+    fn leaves(_from_block: BlockNumber, _to_block: BlockNumber) -> Option<Vec<Vec<u8>>> {
+        MOCK_SUMMARY_LEAVES.with(|l| l.borrow().clone())
+    }
 }
 
 parameter_types! {
@@ -393,6 +409,7 @@ impl Config for TestRuntime {
     type InstanceId = InstanceId;
     type ExternalValidator = NoopWatchtower<AccountId>;
     type ExternalValidationEnabled = ExternalValidationEnabled;
+    type LeafProvider = TestLeafProvider;
 }
 
 type AvnAnchorSummary = summary::Instance1;
@@ -408,6 +425,7 @@ impl Config<AvnAnchorSummary> for TestRuntime {
     type InstanceId = AnchorInstanceId;
     type ExternalValidator = NoopWatchtower<AccountId>;
     type ExternalValidationEnabled = ExternalValidationEnabled;
+    type LeafProvider = ();
 }
 
 impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for TestRuntime
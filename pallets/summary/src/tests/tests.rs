@@ -1369,6 +1369,12 @@ mod if_process_summary_is_called_a_second_time {
 
         assert!(Summary::get_root_data(&context.root_id).is_validated);
         assert!(!PendingApproval::<TestRuntime>::contains_key(&context.root_id.range));
+        for block_number in [context.root_id.range.from_block, context.root_id.range.to_block] {
+            assert_eq!(
+                Summary::validated_root_covering(block_number).map(|(root_id, _)| root_id),
+                Some(context.root_id)
+            );
+        }
         assert_eq!(
             Summary::get_next_block_to_process(),
             context.next_block_to_process + Summary::schedule_period()
@@ -1388,6 +1394,7 @@ mod if_process_summary_is_called_a_second_time {
 
         assert!(!Summary::get_root_data(&context.root_id).is_validated);
         assert!(!PendingApproval::<TestRuntime>::contains_key(&context.root_id.range));
+        assert!(Summary::validated_root_covering(context.root_id.range.to_block).is_none());
         assert_eq!(Summary::get_next_block_to_process(), context.next_block_to_process);
         assert_eq!(Summary::last_summary_slot(), previous_summary_slot_before_voting);
 
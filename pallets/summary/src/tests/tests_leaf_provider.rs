@@ -0,0 +1,74 @@
+// Copyright 2026 Aventus DAO Ltd
+
+#![cfg(test)]
+
+use crate::mock::{Summary, *};
+use codec::alloc::sync::Arc;
+use hex_literal::hex;
+use parking_lot::RwLock;
+use sp_core::{offchain::testing::PoolState, H256};
+use sp_runtime::generic::Preamble;
+
+fn leaves(count: u8) -> Vec<Vec<u8>> {
+    (0..count).map(|n| vec![n]).collect()
+}
+
+fn submitted_root_hash(pool_state: &Arc<RwLock<PoolState>>) -> Option<H256> {
+    let tx = pool_state.write().transactions.pop()?;
+    let tx = Extrinsic::decode(&mut &*tx).unwrap();
+    assert!(matches!(tx.preamble, Preamble::Bare(_)));
+    match tx.function {
+        mock::RuntimeCall::Summary(crate::Call::record_summary_calculation {
+            root_hash, ..
+        }) => Some(root_hash),
+        _ => None,
+    }
+}
+
+#[test]
+fn root_hash_is_built_from_the_provided_leaves() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        TestLeafProvider::set_leaves(Some(leaves(3)));
+
+        // The same root the external service builds for the leaves [0, 1, 2]
+        assert_eq!(
+            Summary::compute_root_hash(1, 10),
+            Ok(H256(hex!("d359d2743bb3a93ded4c902716931497ae3080f478c14e7af96344a92e9ddd51")))
+        );
+    });
+}
+
+#[test]
+fn root_hash_is_empty_without_leaves() {
+    let mut ext = ExtBuilder::build_default().with_validators().as_externality();
+    ext.execute_with(|| {
+        TestLeafProvider::set_leaves(Some(vec![]));
+
+        assert_eq!(Summary::compute_root_hash(1, 10), Ok(Summary::empty_root()));
+    });
+}
+
+#[test]
+fn summary_of_provided_leaves_is_recorded_without_the_external_service() {
+    let (mut ext, pool_state, _offchain_state) = ExtBuilder::build_default()
+        .with_validators()
+        .for_offchain_worker()
+        .as_externality_with_state();
+
+    ext.execute_with(|| {
+        let context = setup_context();
+        TestLeafProvider::set_leaves(Some(leaves(5)));
+
+        setup_blocks(&context);
+        setup_total_ingresses(&context);
+
+        // No response from the external service is mocked
+        Summary::process_summary_if_required(context.current_block_number, &context.validator);
+
+        assert_eq!(
+            submitted_root_hash(&pool_state),
+            Some(H256(hex!("11aeafa56c9b34805cc86b1c320c9331672c07e600f0a44317051cfa05a0c296")))
+        );
+    });
+}
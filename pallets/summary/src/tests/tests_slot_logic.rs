@@ -1253,6 +1253,29 @@ mod cases_for_no_summary_created_offences {
     }
 }
 
+#[test]
+fn liveness_offences_are_slashed_less_than_invalid_roots() {
+    use crate::offence::SummaryOffence;
+    use sp_runtime::Perbill;
+    use sp_staking::offence::Offence;
+
+    let offence = |offence_type| SummaryOffence {
+        session_index: 1,
+        validator_set_count: 4,
+        offenders: vec![FIRST_VALIDATOR_INDEX],
+        offence_type,
+    };
+
+    let no_summary = offence(SummaryOffenceType::NoSummaryCreated).slash_fraction(1);
+    let slot_not_advanced = offence(SummaryOffenceType::SlotNotAdvanced).slash_fraction(1);
+    let invalid_root = offence(SummaryOffenceType::CreatedInvalidRoot).slash_fraction(1);
+
+    assert_eq!(no_summary, Perbill::from_parts(1_000_000));
+    assert_eq!(slot_not_advanced, no_summary);
+    assert_eq!(invalid_root, Perbill::from_percent(10));
+    assert!(invalid_root < Perbill::from_percent(100));
+}
+
 fn event_is_a_not_published_offence(e: &mock::RuntimeEvent) -> bool {
     if let mock::RuntimeEvent::Summary(crate::Event::<TestRuntime>::SummaryNotPublishedOffence {
         ..
@@ -264,6 +264,11 @@ parameter_types! {
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
     pub const MaxCandidates: u32 = 100;
     pub const GrowthEnabled: bool = true;
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
+    pub const CommissionIncreaseDelay: u32 = 2;
+    pub const SlashDeferDuration: u32 = 2;
+    pub const MaxPoolUnbondingChunks: u32 = 4;
+    pub const SlashNominators: bool = true;
 }
 
 impl parachain_staking::Config for TestRuntime {
@@ -289,6 +294,13 @@ impl parachain_staking::Config for TestRuntime {
     type AccountToBytesConvert = Avn;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = GrowthEnabled;
+    type MaxCommission = MaxCommission;
+    type CommissionIncreaseDelay = CommissionIncreaseDelay;
+    type Slash = ();
+    type SlashDeferDuration = SlashDeferDuration;
+    type SlashNominators = SlashNominators;
+    type SlashCancelOrigin = EnsureRoot<AccountId>;
+    type MaxPoolUnbondingChunks = MaxPoolUnbondingChunks;
 }
 
 impl pallet_session::historical::Config for TestRuntime {
@@ -28,7 +28,7 @@ use sp_core::{
 use sp_runtime::{
     testing::{TestXt, UintAuthorityId},
     traits::{ConvertInto, IdentityLookup, Verify},
-    BuildStorage,
+    BuildStorage, Perbill,
 };
 
 use codec::alloc::sync::Arc;
@@ -271,6 +271,11 @@ parameter_types! {
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
     pub const MaxCandidates: u32 = 256;
     pub const GrowthEnabled: bool = true;
+    pub const MaxCommission: Perbill = Perbill::from_percent(50);
+    pub const CommissionIncreaseDelay: u32 = 2;
+    pub const SlashDeferDuration: u32 = 2;
+    pub const MaxPoolUnbondingChunks: u32 = 4;
+    pub const SlashNominators: bool = true;
 }
 
 impl parachain_staking::Config for TestRuntime {
@@ -296,6 +301,13 @@ impl parachain_staking::Config for TestRuntime {
     type AccountToBytesConvert = Avn;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = GrowthEnabled;
+    type MaxCommission = MaxCommission;
+    type CommissionIncreaseDelay = CommissionIncreaseDelay;
+    type Slash = ();
+    type SlashDeferDuration = SlashDeferDuration;
+    type SlashNominators = SlashNominators;
+    type SlashCancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxPoolUnbondingChunks = MaxPoolUnbondingChunks;
 }
 
 pub const INITIAL_TRANSACTION_ID: EthereumId = 0;
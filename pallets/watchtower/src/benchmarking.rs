@@ -139,8 +139,17 @@ fn setup_votes<T: Config>(proposal_id: ProposalId, vote_count: u32) {
     }
 }
 
+/// Fill the active external proposals list up to one free slot, so adding to it is most expensive.
+fn setup_active_external_proposals<T: Config>() {
+    let active = (1..T::MaxActiveExternalProposals::get())
+        .map(|i| H256::from_slice(T::Hashing::hash_of(&("active", i)).as_ref()))
+        .collect::<Vec<_>>();
+    ActiveExternalProposals::<T>::put(BoundedVec::truncate_from(active));
+}
+
 benchmarks! {
     submit_external_proposal {
+        setup_active_external_proposals::<T>();
         let signer: T::AccountId = account("signer", 0, 0);
         let proposal_request = create_proposal_request::<T>(1, 1u32, false);
         let external_ref = proposal_request.external_ref;
@@ -156,6 +165,7 @@ benchmarks! {
     }
 
     signed_submit_external_proposal {
+        setup_active_external_proposals::<T>();
         let (signer_key, signer) = get_voter::<T>();
         let proposal_request = create_proposal_request::<T>(1, 1u32, false);
         let external_ref = proposal_request.external_ref;
@@ -377,6 +387,19 @@ benchmarks! {
         assert!(ActiveInternalProposal::<T>::get() == Some(queued_proposal_id));
     }
 
+    snapshot_voting_weights {
+        let v in 1 .. T::MaxWeightedVoters::get();
+
+        let proposal_id = H256::repeat_byte(5);
+        let stakes: Vec<(T::AccountId, u128)> =
+            (0..v).map(|i| (account("voter", i, 0), 1_000u128)).collect();
+    }: { assert!(Pallet::<T>::snapshot_voting_weights(proposal_id, stakes).is_ok()); }
+    verify {
+        let first_voter: T::AccountId = account("voter", 0, 0);
+        assert!(VotingWeights::<T>::get(proposal_id, &first_voter) > 0);
+        assert!(TotalVotingWeight::<T>::get(proposal_id).is_some());
+    }
+
 }
 
 impl_benchmark_test_suite!(
@@ -16,11 +16,20 @@ use frame_support::{
     weights::WeightMeter,
 };
 use frame_system::{
-    offchain::{CreateInherent, CreateTransactionBase},
+    offchain::{CreateInherent, CreateTransactionBase, SubmitTransaction},
     pallet_prelude::*,
 };
-pub use sp_avn_common::{verify_signature, InnerCallValidator, Proof};
+pub use pallet_avn::{self as avn};
+pub use sp_avn_common::{
+    ocw_lock::{self as OcwLock, OcwStorageError},
+    verify_signature, InnerCallValidator, Proof,
+};
 use sp_core::{MaxEncodedLen, H256};
+use sp_runtime::{
+    offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+    traits::{IdentifyAccount, Verify},
+    RuntimeAppPublic, Saturating,
+};
 pub use sp_runtime::{
     traits::{AtLeast32Bit, Dispatchable, ValidateUnsigned},
     transaction_validity::{
@@ -29,18 +38,25 @@ pub use sp_runtime::{
     },
     Perbill, SaturatedConversion,
 };
-use sp_runtime::{
-    traits::{IdentifyAccount, Verify},
-    RuntimeAppPublic, Saturating,
-};
 use sp_std::prelude::*;
 pub use sp_watchtower::*;
 
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 pub const DEFAULT_VOTING_PERIOD_BLOCKS: u32 = 100;
 pub const WATCHTOWER_UNSIGNED_VOTE_CONTEXT: &'static [u8] = b"wt_unsigned_vote";
 pub const WATCHTOWER_FINALISE_PROPOSAL_CONTEXT: &'static [u8] = b"wt_finalise_proposal";
 pub const UNSIGNED_VOTE_NOT_VALID: u8 = 2;
+pub const OC_DB_PREFIX: &[u8] = b"wt::ocw::";
+pub const OC_PAYLOAD_CHECK_PREFIX: &[u8] = b"wt::ocw::payload::";
+const BLOCK_INCLUSION_PERIOD: u32 = 5;
+/// Blocks an external proposal's payload must keep failing verification before nodes vote against
+/// it.
+const PAYLOAD_RETRY_PERIOD: u32 = 5;
+
+pub type AVN<T> = avn::Pallet<T>;
+
+pub mod migration;
+pub mod offchain;
 
 pub mod proxy;
 pub mod types;
@@ -62,9 +78,15 @@ mod add_proposal;
 #[path = "tests/admin.rs"]
 mod admin;
 #[cfg(test)]
+#[path = "tests/decision_rules.rs"]
+mod decision_rules;
+#[cfg(test)]
 #[path = "tests/mock.rs"]
 mod mock;
 #[cfg(test)]
+#[path = "tests/payload_verification.rs"]
+mod payload_verification;
+#[cfg(test)]
 #[path = "tests/voting.rs"]
 mod voting;
 
@@ -79,7 +101,10 @@ pub mod pallet {
 
     #[pallet::config]
     pub trait Config:
-        CreateTransactionBase<Call<Self>> + CreateInherent<Call<Self>> + frame_system::Config
+        CreateTransactionBase<Call<Self>>
+        + CreateInherent<Call<Self>>
+        + frame_system::Config
+        + avn::Config
     {
         type RuntimeEvent: From<Event<Self>>
             + IsType<<Self as frame_system::Config>::RuntimeEvent>
@@ -112,7 +137,10 @@ pub mod pallet {
         type Watchtowers: NodesInterface<Self::AccountId, Self::SignerId>;
 
         /// Hooks for other pallets to implement custom logic on certain events
-        type WatchtowerHooks: WatchtowerHooks<Proposal<Self>>;
+        type WatchtowerHooks: WatchtowerHooks<Proposal<Self>, Self::AccountId>;
+
+        /// Validators for the content of `HashedUri` payloads, checked by the offchain worker
+        type PayloadValidator: ProposalPayloadValidator;
 
         /// Weight information for extrinsics in this pallet
         type WeightInfo: WeightInfo;
@@ -136,6 +164,14 @@ pub mod pallet {
         /// Maximum length of Internal proposals
         #[pallet::constant]
         type MaxInternalProposalLen: Get<u32>;
+
+        /// Maximum number of voters whose stake is snapshotted for a stake-weighted proposal
+        #[pallet::constant]
+        type MaxWeightedVoters: Get<u32>;
+
+        /// Maximum number of external proposals that can be voted on at the same time
+        #[pallet::constant]
+        type MaxActiveExternalProposals: Get<u32>;
     }
 
     #[pallet::type_value]
@@ -177,10 +213,34 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Voting weight of each voter on a stake-weighted proposal, snapshotted when voting on the
+    /// proposal starts
+    #[pallet::storage]
+    pub type VotingWeights<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProposalId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Total voting weight of a stake-weighted proposal, snapshotted when voting on the proposal
+    /// starts. Votes on stake-weighted proposals without a snapshot are counted by voter.
+    #[pallet::storage]
+    pub type TotalVotingWeight<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProposalId, u32, OptionQuery>;
+
     /// The currently active internal proposal being voted on, if any
     #[pallet::storage]
     pub type ActiveInternalProposal<T: Config> = StorageValue<_, ProposalId, OptionQuery>;
 
+    /// The external proposals currently being voted on
+    #[pallet::storage]
+    pub type ActiveExternalProposals<T: Config> =
+        StorageValue<_, BoundedVec<ProposalId, T::MaxActiveExternalProposals>, ValueQuery>;
+
     #[pallet::storage] // ring slots: physical index -> item id
     pub type InternalProposalQueue<T: Config> =
         StorageMap<_, Blake2_128Concat, (QueueId, u32), ProposalId, OptionQuery>;
@@ -280,6 +340,16 @@ pub mod pallet {
         InvalidProposalForUnsignedVote,
         /// Admin account is not set
         AdminAccountNotSet,
+        /// Failed to acquire the offchain db lock to record a vote
+        FailedToAcquireOcwDbLock,
+        /// The owner of the voting watchtower node could not be found
+        NodeOwnerNotFound,
+        /// Too many external proposals are being voted on
+        TooManyActiveExternalProposals,
+        /// A stake-weighted proposal has more voters than `MaxWeightedVoters`
+        TooManyWeightedVoters,
+        /// None of the voters of a stake-weighted proposal has stake
+        NoWeightedVotingStake,
     }
 
     #[pallet::call]
@@ -287,7 +357,10 @@ pub mod pallet {
         // We don't want external users to add internal proposals to avoid
         // DOSing the internal proposal queue.
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as Config>::WeightInfo::submit_external_proposal())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::submit_external_proposal()
+            .saturating_add(Pallet::<T>::voting_weights_snapshot_weight(&proposal.decision_rule))
+        )]
         pub fn submit_external_proposal(
             origin: OriginFor<T>,
             proposal: ProposalRequest,
@@ -303,7 +376,10 @@ pub mod pallet {
         }
 
         #[pallet::call_index(1)]
-        #[pallet::weight(<T as Config>::WeightInfo::signed_submit_external_proposal())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::signed_submit_external_proposal()
+            .saturating_add(Pallet::<T>::voting_weights_snapshot_weight(&proposal.decision_rule))
+        )]
         pub fn signed_submit_external_proposal(
             origin: OriginFor<T>,
             proof: Proof<T::Signature, T::AccountId>,
@@ -342,6 +418,7 @@ pub mod pallet {
         #[pallet::weight(
             <T as Config>::WeightInfo::vote()
             .max(<T as Config>::WeightInfo::vote_end_proposal())
+            .saturating_add(T::WatchtowerHooks::on_vote_submitted_weight())
         )]
         pub fn vote(
             origin: OriginFor<T>,
@@ -351,17 +428,19 @@ pub mod pallet {
             let owner = ensure_signed(origin)?;
             let finalised = Self::process_vote(&owner, proposal_id, in_favor)?;
 
-            if finalised {
-                Ok(Some(<T as Config>::WeightInfo::vote_end_proposal()).into())
+            let weight = if finalised {
+                <T as Config>::WeightInfo::vote_end_proposal()
             } else {
-                Ok(Some(<T as Config>::WeightInfo::vote()).into())
-            }
+                <T as Config>::WeightInfo::vote()
+            };
+            Ok(Some(weight.saturating_add(T::WatchtowerHooks::on_vote_submitted_weight())).into())
         }
 
         #[pallet::call_index(3)]
         #[pallet::weight(
             <T as Config>::WeightInfo::signed_vote()
             .max(<T as Config>::WeightInfo::signed_vote_end_proposal())
+            .saturating_add(T::WatchtowerHooks::on_vote_submitted_weight())
         )]
         pub fn signed_vote(
             origin: OriginFor<T>,
@@ -393,17 +472,19 @@ pub mod pallet {
 
             let finalised = Self::process_vote(&owner, proposal_id, in_favor)?;
 
-            if finalised {
-                Ok(Some(<T as Config>::WeightInfo::signed_vote_end_proposal()).into())
+            let weight = if finalised {
+                <T as Config>::WeightInfo::signed_vote_end_proposal()
             } else {
-                Ok(Some(<T as Config>::WeightInfo::signed_vote()).into())
-            }
+                <T as Config>::WeightInfo::signed_vote()
+            };
+            Ok(Some(weight.saturating_add(T::WatchtowerHooks::on_vote_submitted_weight())).into())
         }
 
         #[pallet::call_index(4)]
         #[pallet::weight(
             <T as Config>::WeightInfo::unsigned_vote()
             .max(<T as Config>::WeightInfo::unsigned_vote_end_proposal())
+            .saturating_add(T::WatchtowerHooks::on_vote_submitted_weight())
         )]
         pub fn unsigned_vote(
             origin: OriginFor<T>,
@@ -414,15 +495,16 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_none(origin)?;
 
-            Self::validate_unsigned_vote(proposal_id, watchtower.clone(), signature, in_favor)?;
+            let voter = Self::validate_unsigned_vote(proposal_id, watchtower, signature, in_favor)?;
 
-            let finalised = Self::process_vote(&watchtower, proposal_id, in_favor)?;
+            let finalised = Self::process_vote(&voter, proposal_id, in_favor)?;
 
-            if finalised {
-                Ok(Some(<T as Config>::WeightInfo::unsigned_vote_end_proposal()).into())
+            let weight = if finalised {
+                <T as Config>::WeightInfo::unsigned_vote_end_proposal()
             } else {
-                Ok(Some(<T as Config>::WeightInfo::unsigned_vote()).into())
-            }
+                <T as Config>::WeightInfo::unsigned_vote()
+            };
+            Ok(Some(weight.saturating_add(T::WatchtowerHooks::on_vote_submitted_weight())).into())
         }
 
         #[pallet::call_index(5)]
@@ -481,18 +563,19 @@ pub mod pallet {
             match call {
                 Call::unsigned_vote { proposal_id, in_favor, watchtower, signature } => {
                     // Fail early if vote is invalid. This avoids DDos attacks with invalid votes
-                    if let Err(_) = Self::validate_unsigned_vote(
+                    let voter = match Self::validate_unsigned_vote(
                         *proposal_id,
                         watchtower.clone(),
                         signature.clone(),
                         *in_favor,
                     ) {
-                        return InvalidTransaction::Custom(UNSIGNED_VOTE_NOT_VALID).into()
-                    }
+                        Ok(voter) => voter,
+                        Err(_) => return InvalidTransaction::Custom(UNSIGNED_VOTE_NOT_VALID).into(),
+                    };
 
                     ValidTransaction::with_tag_prefix("wt_unsignedVote")
                         .priority(TransactionPriority::max_value() - reduce_priority)
-                        .and_provides((watchtower, proposal_id))
+                        .and_provides((voter, proposal_id))
                         .longevity(64_u64)
                         .propagate(true)
                         .build()
@@ -507,6 +590,21 @@ pub mod pallet {
         fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
             Self::cleanup_proposals(n, remaining_weight)
         }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            log::debug!("Watchtower OCW running for block {:?}", now);
+
+            if Self::ocw_already_run(now).is_err() {
+                return
+            }
+
+            if sp_io::offchain::is_validator() {
+                log::debug!("🛠️  Node is validator, skipping proposal payload verification.");
+                return
+            }
+
+            Self::process_payload_verification(now);
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -533,6 +631,13 @@ pub mod pallet {
             let proposal_id = proposal.generate_id();
             ensure!(!Proposals::<T>::contains_key(proposal_id), Error::<T>::DuplicateProposal);
 
+            // Snapshot voting weights at creation so stake changes made while the proposal is
+            // queued or open cannot affect its outcome
+            if proposal.decision_rule == DecisionRule::StakeWeighted {
+                let stakes = T::Watchtowers::get_watchtower_stakes(&proposal.source);
+                Self::snapshot_voting_weights(proposal_id, stakes)?;
+            }
+
             let status: ProposalStatusEnum;
             if let ProposalSource::Internal(_) = proposal.source {
                 if ActiveInternalProposal::<T>::get().is_none() {
@@ -545,6 +650,8 @@ pub mod pallet {
                     status = ProposalStatusEnum::Queued;
                 }
             } else {
+                ActiveExternalProposals::<T>::try_append(proposal_id)
+                    .map_err(|_| Error::<T>::TooManyActiveExternalProposals)?;
                 proposal.end_at = Some(current_block.saturating_add(proposal.vote_duration.into()));
                 status = ProposalStatusEnum::Active;
             }
@@ -583,7 +690,7 @@ pub mod pallet {
 
             ensure!(!Voters::<T>::contains_key(proposal_id, voter), Error::<T>::AlreadyVoted);
 
-            let vote_weight;
+            let mut vote_weight;
             match proposal.source {
                 ProposalSource::Internal(_) => {
                     ensure!(
@@ -611,7 +718,16 @@ pub mod pallet {
                 },
             };
 
+            if proposal.decision_rule == DecisionRule::StakeWeighted &&
+                TotalVotingWeight::<T>::contains_key(proposal_id)
+            {
+                // Use the weight snapshotted when voting started. Voters without stake can still
+                // vote, but their vote carries no weight.
+                vote_weight = VotingWeights::<T>::get(proposal_id, voter);
+            }
+
             Voters::<T>::insert(proposal_id, voter, in_favor);
+            T::WatchtowerHooks::on_vote_submitted(proposal_id, voter, in_favor);
             Votes::<T>::mutate(proposal_id, |vote| {
                 if in_favor {
                     vote.in_favors = vote.in_favors.saturating_add(vote_weight);
@@ -702,19 +818,37 @@ pub mod pallet {
                 Voters::<T>::remove(&proposal_id, who);
             }
 
-            // Check if we have finished removing all votes
-            if meter.try_consume(dbw.reads(1)).is_err() {
+            let mut weights_to_delete: Vec<T::AccountId> = Vec::new();
+            for who in VotingWeights::<T>::iter_key_prefix(&proposal_id).take(MAX_VOTERS) {
+                if meter.try_consume(dbw.reads(1)).is_err() {
+                    break
+                }
+                weights_to_delete.push(who);
+            }
+
+            for who in weights_to_delete.iter() {
+                if meter.try_consume(dbw.writes(1)).is_err() {
+                    break
+                }
+                VotingWeights::<T>::remove(&proposal_id, who);
+            }
+
+            // Check if we have finished removing all votes and voting weights
+            if meter.try_consume(dbw.reads(2)).is_err() {
                 return meter.consumed()
             }
 
-            if Voters::<T>::iter_prefix(proposal_id).next().is_none() {
+            if Voters::<T>::iter_prefix(proposal_id).next().is_none() &&
+                VotingWeights::<T>::iter_prefix(proposal_id).next().is_none()
+            {
                 // We have removed all votes, now we can remove the proposal and its data
-                if meter.try_consume(dbw.writes(4)).is_err() {
+                if meter.try_consume(dbw.writes(5)).is_err() {
                     return meter.consumed()
                 }
 
                 Proposals::<T>::remove(proposal_id);
                 Votes::<T>::remove(proposal_id);
+                TotalVotingWeight::<T>::remove(proposal_id);
                 ProposalsToRemove::<T>::remove(proposal_id);
 
                 Self::deposit_event(Event::ProposalCleaned { proposal_id });
@@ -723,17 +857,37 @@ pub mod pallet {
             meter.consumed()
         }
 
+        fn voting_weights_snapshot_weight(decision_rule: &DecisionRule) -> Weight {
+            match decision_rule {
+                DecisionRule::StakeWeighted =>
+                    <T as Config>::WeightInfo::snapshot_voting_weights(T::MaxWeightedVoters::get()),
+                _ => Weight::zero(),
+            }
+        }
+
+        /// Returns the account the vote is recorded for. Nodes vote on the active internal
+        /// proposal themselves, and can only vote against an active external proposal, on behalf
+        /// of their owner, when its payload fails verification.
         fn validate_unsigned_vote(
             proposal_id: ProposalId,
             watchtower: T::AccountId,
             signature: <T::SignerId as RuntimeAppPublic>::Signature,
             in_favor: bool,
-        ) -> Result<(), DispatchError> {
-            // Only Active internal proposals can be voted on with unsigned txs
-            ensure!(
-                ActiveInternalProposal::<T>::get() == Some(proposal_id),
-                Error::<T>::InvalidProposalForUnsignedVote
-            );
+        ) -> Result<T::AccountId, DispatchError> {
+            let voter = if ActiveInternalProposal::<T>::get() == Some(proposal_id) {
+                watchtower.clone()
+            } else {
+                let is_active_external = ProposalStatus::<T>::get(proposal_id) ==
+                    ProposalStatusEnum::Active &&
+                    Proposals::<T>::get(proposal_id)
+                        .map_or(false, |p| matches!(p.source, ProposalSource::External));
+                ensure!(
+                    !in_favor && is_active_external,
+                    Error::<T>::InvalidProposalForUnsignedVote
+                );
+                T::Watchtowers::get_node_owner(&watchtower).ok_or(Error::<T>::NodeOwnerNotFound)?
+            };
+            ensure!(!Voters::<T>::contains_key(proposal_id, &voter), Error::<T>::AlreadyVoted);
 
             let voter_signing_key = match T::Watchtowers::get_node_signing_key(&watchtower) {
                 Some(key) => key,
@@ -748,7 +902,7 @@ pub mod pallet {
                 return Err(Error::<T>::UnauthorizedUnsignedTransaction.into())
             }
 
-            Ok(())
+            Ok(voter)
         }
     }
 
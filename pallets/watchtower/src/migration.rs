@@ -0,0 +1,100 @@
+// Copyright 2026 Aventus DAO.
+
+use crate::{
+    ActiveExternalProposals, Config, Pallet, ProposalSource, ProposalStatus, ProposalStatusEnum,
+    Proposals, STORAGE_VERSION,
+};
+use frame_support::{
+    pallet_prelude::PhantomData,
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+    weights::Weight,
+    BoundedVec,
+};
+
+#[cfg(feature = "try-runtime")]
+use crate::Vec;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+// External proposals that are being voted on
+fn active_external_proposals<T: Config>() -> (crate::Vec<crate::ProposalId>, u64) {
+    let mut reads = 0;
+    let mut active = crate::Vec::new();
+    for (proposal_id, status) in ProposalStatus::<T>::iter() {
+        reads += 1;
+        if status != ProposalStatusEnum::Active {
+            continue
+        }
+
+        reads += 1;
+        if Proposals::<T>::get(proposal_id)
+            .map_or(false, |p| matches!(p.source, ProposalSource::External))
+        {
+            active.push(proposal_id);
+        }
+    }
+
+    (active, reads)
+}
+
+pub fn migrate_to_v1<T: Config>() -> Weight {
+    log::info!("🚧 🚧 Running migration to record the active external proposals");
+
+    let (active, reads) = active_external_proposals::<T>();
+    if active.len() > T::MaxActiveExternalProposals::get() as usize {
+        log::warn!(
+            "⚠️ {} external proposals are active, only the first {} are verified by watchtowers",
+            active.len(),
+            T::MaxActiveExternalProposals::get()
+        );
+    }
+
+    ActiveExternalProposals::<T>::put(BoundedVec::truncate_from(active));
+    STORAGE_VERSION.put::<Pallet<T>>();
+
+    log::info!("✅ Active external proposals recorded successfully");
+
+    // add a bit extra as safety margin for computation
+    T::DbWeight::get().reads_writes(reads, 2) + Weight::from_parts(25_000_000 as u64, 0)
+}
+
+pub struct WatchtowerMigrations<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for WatchtowerMigrations<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current = Pallet::<T>::in_code_storage_version();
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        let mut total_weight = Weight::zero();
+
+        if onchain < 1 {
+            log::info!(
+                "💽 Running Watchtower migration with current storage version {:?} / onchain {:?}",
+                current,
+                onchain
+            );
+            total_weight += migrate_to_v1::<T>();
+        }
+
+        total_weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        use codec::Encode;
+
+        Ok((active_external_proposals::<T>().0.len() as u64).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(input: Vec<u8>) -> Result<(), TryRuntimeError> {
+        use codec::Decode;
+
+        let active_before: u64 =
+            Decode::decode(&mut input.as_slice()).expect("Initial active count is invalid");
+        let active_after = ActiveExternalProposals::<T>::get().len() as u64;
+        assert_eq!(active_after, active_before.min(T::MaxActiveExternalProposals::get() as u64));
+        assert_eq!(Pallet::<T>::on_chain_storage_version(), STORAGE_VERSION);
+
+        Ok(())
+    }
+}
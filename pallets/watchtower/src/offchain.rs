@@ -0,0 +1,295 @@
+// No runtime state mutation allowed in this file because it is used in offchain context.
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    /// Fetch and verify the payloads of the active internal proposal and the active external
+    /// proposals, and vote on them with the local watchtower node.
+    pub fn process_payload_verification(now: BlockNumberFor<T>) {
+        let (watchtower, signing_key) = match T::Watchtowers::get_node_from_local_signing_keys() {
+            Some(info) => info,
+            None => return,
+        };
+
+        Self::process_internal_payload_verification(now, &watchtower, &signing_key);
+        Self::process_external_payload_verification(now, &watchtower, &signing_key);
+    }
+
+    /// Votes on the active internal proposal, if it is a `HashedUri`, with the result of
+    /// verifying its payload.
+    fn process_internal_payload_verification(
+        now: BlockNumberFor<T>,
+        watchtower: &T::AccountId,
+        signing_key: &T::SignerId,
+    ) {
+        let Some((proposal_id, proposal, expired)) = Self::active_proposal_expiry_status(now)
+        else {
+            return
+        };
+
+        let Payload::HashedUri { uri, content_hash } = &proposal.payload else { return };
+        let ProposalSource::Internal(proposal_type) = &proposal.source else { return };
+        if expired || Self::vote_already_submitted(proposal_id, watchtower, watchtower, now) {
+            return
+        }
+
+        let in_favor = match Self::verify_payload(uri, content_hash, proposal_type) {
+            Some(in_favor) => in_favor,
+            None => {
+                log::warn!(
+                    "No payload validator for proposal {:?} of type {:?}, skipping vote.",
+                    proposal_id,
+                    proposal_type
+                );
+                return
+            },
+        };
+
+        if let Err(e) =
+            Self::submit_vote(proposal_id, in_favor, signing_key.clone(), watchtower.clone(), now)
+        {
+            log::error!("Error voting on proposal {:?}. Error: {:?}", proposal_id, e);
+        };
+    }
+
+    /// Votes against the active external `Uri` and `HashedUri` proposals whose payload cannot be
+    /// fetched or doesn't match its hash for `PAYLOAD_RETRY_PERIOD` blocks. The vote is cast on
+    /// behalf of the owner of the local node, because only owners vote on external proposals.
+    /// Payloads that can be fetched are left for the owners to review.
+    fn process_external_payload_verification(
+        now: BlockNumberFor<T>,
+        watchtower: &T::AccountId,
+        signing_key: &T::SignerId,
+    ) {
+        let Some(owner) = T::Watchtowers::get_node_owner(watchtower) else { return };
+
+        for proposal_id in ActiveExternalProposals::<T>::get() {
+            let Some(proposal) = Proposals::<T>::get(proposal_id) else { continue };
+            if Self::proposal_expired(now, &proposal) ||
+                Self::vote_already_submitted(proposal_id, &owner, watchtower, now)
+            {
+                continue
+            }
+
+            let check = match Self::check_external_payload(now, proposal_id, &proposal.payload) {
+                Some(check) => check,
+                None => continue,
+            };
+            if check != PayloadCheck::Invalid {
+                continue
+            }
+
+            if let Err(e) =
+                Self::submit_vote(proposal_id, false, signing_key.clone(), watchtower.clone(), now)
+            {
+                log::error!("Error voting on proposal {:?}. Error: {:?}", proposal_id, e);
+            };
+        }
+    }
+
+    /// Verifies the payload of an external proposal unless a previous check already decided it,
+    /// and caches the result. `None` if the payload is inline and there is nothing to verify.
+    fn check_external_payload(
+        now: BlockNumberFor<T>,
+        proposal_id: ProposalId,
+        payload: &Payload<T>,
+    ) -> Option<PayloadCheck<BlockNumberFor<T>>> {
+        let mut key = OC_PAYLOAD_CHECK_PREFIX.to_vec();
+        key.extend(proposal_id.encode());
+        let storage = StorageValueRef::persistent(&key);
+
+        let first_failure = match storage.get::<PayloadCheck<BlockNumberFor<T>>>().ok().flatten() {
+            Some(PayloadCheck::Failing { first_failure }) => first_failure,
+            Some(decided) => return Some(decided),
+            None => now,
+        };
+
+        let payload_is_valid = match payload {
+            Payload::Uri(uri) => Self::fetch_payload(uri).is_ok(),
+            Payload::HashedUri { uri, content_hash } =>
+                Self::fetch_and_check_payload(uri, content_hash).is_some(),
+            Payload::Inline(_) => return None,
+        };
+
+        let check = if payload_is_valid {
+            PayloadCheck::Valid
+        } else if now.saturating_sub(first_failure) >=
+            BlockNumberFor::<T>::from(PAYLOAD_RETRY_PERIOD)
+        {
+            PayloadCheck::Invalid
+        } else {
+            PayloadCheck::Failing { first_failure }
+        };
+
+        storage.set(&check);
+        Some(check)
+    }
+
+    /// Returns `Some(false)` if the payload cannot be fetched or doesn't match `content_hash`,
+    /// otherwise the result of the configured payload validator.
+    pub fn verify_payload(
+        uri: &[u8],
+        content_hash: &H256,
+        proposal_type: &ProposalType,
+    ) -> Option<bool> {
+        match Self::fetch_and_check_payload(uri, content_hash) {
+            Some(payload) => T::PayloadValidator::validate(proposal_type, &payload),
+            None => Some(false),
+        }
+    }
+
+    /// Fetches the payload at `uri`, `None` if it cannot be fetched or doesn't match
+    /// `content_hash`.
+    fn fetch_and_check_payload(uri: &[u8], content_hash: &H256) -> Option<Vec<u8>> {
+        let payload = match Self::fetch_payload(uri) {
+            Ok(payload) => payload,
+            Err(e) => {
+                log::warn!("Failed to fetch proposal payload from {:?}. Error: {}", uri, e);
+                return None
+            },
+        };
+
+        if H256::from(sp_io::hashing::blake2_256(&payload)) != *content_hash {
+            log::warn!("Proposal payload fetched from {:?} does not match its hash.", uri);
+            return None
+        }
+
+        Some(payload)
+    }
+
+    /// Whether `voter` already voted on the proposal, or the local `watchtower` node submitted a
+    /// vote for it that may still be included.
+    fn vote_already_submitted(
+        proposal_id: ProposalId,
+        voter: &T::AccountId,
+        watchtower: &T::AccountId,
+        now: BlockNumberFor<T>,
+    ) -> bool {
+        if Voters::<T>::contains_key(proposal_id, voter) ||
+            Self::vote_in_progress(proposal_id, watchtower.clone(), now)
+        {
+            log::debug!(
+                "Vote already submitted. Proposal {:?}, Watchtower {:?}",
+                proposal_id,
+                watchtower
+            );
+            return true
+        }
+
+        false
+    }
+
+    fn fetch_payload(uri: &[u8]) -> Result<Vec<u8>, String> {
+        let url_path = format!("watchtower/payload/{}", hex::encode(uri));
+
+        log::debug!("Fetching proposal payload using AVN service, path: {}", url_path);
+
+        let response = AVN::<T>::get_data_from_service(url_path)
+            .map_err(|e| format!("AVN service call failed: {:?}", e))?;
+
+        let response = core::str::from_utf8(&response)
+            .map_err(|_| "Response contains invalid UTF8 bytes".to_string())?;
+
+        hex::decode(response.trim()).map_err(|_| "Response contains invalid hex string".to_string())
+    }
+
+    /// Signs and submits the unsigned vote of the local `watchtower` node, and records it so it
+    /// isn't submitted again while it may still be included
+    pub fn submit_vote(
+        proposal_id: ProposalId,
+        in_favor: bool,
+        signing_key: T::SignerId,
+        watchtower: T::AccountId,
+        block_number: BlockNumberFor<T>,
+    ) -> Result<(), &'static str> {
+        let data_to_sign = (WATCHTOWER_UNSIGNED_VOTE_CONTEXT, proposal_id, in_favor, &watchtower);
+        let signature = match signing_key.sign(&data_to_sign.encode()) {
+            Some(sig) => sig,
+            None => return Err("Failed to sign vote data"),
+        };
+
+        let call = <T as CreateInherent<Call<T>>>::create_inherent(
+            Call::unsigned_vote {
+                proposal_id,
+                in_favor,
+                watchtower: watchtower.clone(),
+                signature,
+            }
+            .into(),
+        );
+
+        match SubmitTransaction::<T, Call<T>>::submit_transaction(call) {
+            Ok(()) => {
+                Self::record_vote_submission(block_number, proposal_id, watchtower)
+                    .map_err(|_| "Failed to record vote submission")?;
+                Ok(())
+            },
+            Err(_e) => Err("Error submitting watchtower vote."),
+        }
+    }
+
+    pub fn record_vote_submission(
+        block_number: BlockNumberFor<T>,
+        proposal_id: ProposalId,
+        watchtower: T::AccountId,
+    ) -> Result<(), Error<T>> {
+        let mut key = OC_DB_PREFIX.to_vec();
+        key.extend((proposal_id, watchtower).encode());
+
+        let storage = StorageValueRef::persistent(&key);
+        let result = storage
+            .mutate(|_: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| Ok(block_number));
+        match result {
+            Err(MutateStorageError::ValueFunctionFailed(e)) => Err(e),
+            Err(MutateStorageError::ConcurrentModification(_)) =>
+                Err(Error::<T>::FailedToAcquireOcwDbLock),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    /// Whether the local `watchtower` node submitted a vote on the proposal that may still be
+    /// included
+    pub fn vote_in_progress(
+        proposal_id: ProposalId,
+        watchtower: T::AccountId,
+        block_number: BlockNumberFor<T>,
+    ) -> bool {
+        let mut key = OC_DB_PREFIX.to_vec();
+        key.extend((proposal_id, watchtower).encode());
+
+        match StorageValueRef::persistent(&key).get::<BlockNumberFor<T>>().ok().flatten() {
+            Some(last_submission) => {
+                // Allow BLOCK_INCLUSION_PERIOD blocks for the transaction to be included
+                let deadline = last_submission
+                    .saturating_add(BlockNumberFor::<T>::from(BLOCK_INCLUSION_PERIOD));
+                block_number <= deadline
+            },
+            _ => false,
+        }
+    }
+
+    pub fn ocw_already_run(block_number: BlockNumberFor<T>) -> Result<(), ()> {
+        Self::record_ocw_run(b"watchtower", block_number)
+    }
+
+    /// Records that the offchain worker identified by `caller_id` ran for `block_number`. Fails if
+    /// it has already run for this block.
+    pub fn record_ocw_run(caller_id: &[u8], block_number: BlockNumberFor<T>) -> Result<(), ()> {
+        // Offchain workers could run multiple times for the same block number (re-orgs...)
+        // so we need to make sure we only run this once per block
+        OcwLock::record_block_run(block_number, caller_id.to_vec()).map_err(|e| match e {
+            OcwStorageError::OffchainWorkerAlreadyRun => {
+                log::warn!(
+                    "❌ {} OCW has already run for block number {:?}",
+                    core::str::from_utf8(caller_id).unwrap_or_default(),
+                    block_number
+                );
+            },
+            OcwStorageError::ErrorRecordingOffchainWorkerRun => {
+                log::error!("❌ Unable to record ocw run for block {:?}, skipping", block_number);
+            },
+        })?;
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 #![cfg(test)]
 
 use crate::{mock::*, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::GetStorageVersion};
 use frame_system::RawOrigin;
 use sp_core::Get;
 
@@ -25,11 +25,62 @@ mod adding_external_proposal {
             assert_eq!(ProposalStatus::<TestRuntime>::get(&proposal_id), ProposalStatusEnum::Active);
             // External proposals are not added to active proposals
             assert_eq!(ActiveInternalProposal::<TestRuntime>::get().is_none(), true);
+            assert_eq!(ActiveExternalProposals::<TestRuntime>::get().into_inner(), vec![proposal_id]);
 
             System::assert_last_event(Event::ProposalSubmitted { proposal_id, external_ref: context.external_ref, status: ProposalStatusEnum::Active }.into());
         });
     }
 
+    #[test]
+    fn fails_when_too_many_external_proposals_are_active() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            MaxActiveExternalProposals::set(1);
+            let context = Context::default();
+            assert_ok!(Watchtower::submit_external_proposal(
+                RawOrigin::Root.into(),
+                context.build_external_request(b"test".to_vec())
+            ));
+
+            let second_context = Context { external_ref: H256::repeat_byte(2), ..context.clone() };
+            assert_noop!(
+                Watchtower::submit_external_proposal(
+                    RawOrigin::Root.into(),
+                    second_context.build_external_request(b"test".to_vec())
+                ),
+                Error::<TestRuntime>::TooManyActiveExternalProposals
+            );
+        });
+    }
+
+    #[test]
+    fn migration_records_the_active_external_proposals() {
+        let mut ext = ExtBuilder::build_default().as_externality();
+        ext.execute_with(|| {
+            let context = Context::default();
+            assert_ok!(Watchtower::submit_external_proposal(
+                RawOrigin::Root.into(),
+                context.build_external_request(b"test".to_vec())
+            ));
+            let internal_context =
+                Context { external_ref: H256::repeat_byte(2), ..context.clone() };
+            assert_ok!(Watchtower::submit_proposal(
+                None,
+                internal_context.build_internal_request(b"test".to_vec())
+            ));
+            ActiveExternalProposals::<TestRuntime>::kill();
+
+            crate::migration::migrate_to_v1::<TestRuntime>();
+
+            let proposal_id = ExternalRef::<TestRuntime>::get(&context.external_ref);
+            assert_eq!(
+                ActiveExternalProposals::<TestRuntime>::get().into_inner(),
+                vec![proposal_id]
+            );
+            assert_eq!(Watchtower::on_chain_storage_version(), STORAGE_VERSION);
+        });
+    }
+
     #[test]
     fn works_with_multiple_proposals() {
         let mut ext = ExtBuilder::build_default().as_externality();
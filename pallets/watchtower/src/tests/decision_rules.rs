@@ -0,0 +1,384 @@
+// Copyright 2026 Aventus DAO.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+
+fn submit_external_proposal(
+    decision_rule: DecisionRule,
+    threshold: Perbill,
+) -> (Context, ProposalId) {
+    let context = Context { decision_rule, threshold, ..Default::default() };
+    assert_ok!(Watchtower::submit_external_proposal(
+        RawOrigin::Signed(watchtower_owner_1()).into(),
+        context.build_external_request(b"test".to_vec())
+    ));
+    let proposal_id = ExternalRef::<TestRuntime>::get(&context.external_ref);
+    (context, proposal_id)
+}
+
+fn vote(voter: AccountId, proposal_id: ProposalId, in_favor: bool) {
+    assert_ok!(Watchtower::vote(RawOrigin::Signed(voter).into(), proposal_id, in_favor));
+}
+
+fn expire_and_finalise(context: &Context, proposal_id: ProposalId) {
+    roll_forward(context.vote_duration.unwrap() as u64 + 1);
+    assert_ok!(Watchtower::finalise_proposal(RawOrigin::Signed(random_user()).into(), proposal_id));
+}
+
+fn set_owner_stakes() {
+    set_watchtower_stake(&watchtower_owner_1(), 600);
+    set_watchtower_stake(&watchtower_owner_2(), 300);
+    set_watchtower_stake(&watchtower_owner_3(), 100);
+}
+
+#[test]
+fn finalised_external_proposals_are_no_longer_active() {
+    ExtBuilder::build_default().as_externality().execute_with(|| {
+        let (context, proposal_id) =
+            submit_external_proposal(DecisionRule::SimpleMajority, Perbill::from_percent(50));
+        assert_eq!(ActiveExternalProposals::<TestRuntime>::get().into_inner(), vec![proposal_id]);
+
+        expire_and_finalise(&context, proposal_id);
+
+        assert!(ActiveExternalProposals::<TestRuntime>::get().is_empty());
+    });
+}
+
+// Owners 1, 2 and 3 have 3, 3 and 4 watchtowers out of 10
+mod supermajority {
+    use super::*;
+
+    #[test]
+    fn passes_when_threshold_and_quorum_are_reached() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (_, proposal_id) = submit_external_proposal(
+                DecisionRule::Supermajority { quorum: Perbill::from_percent(50) },
+                Perbill::from_percent(66),
+            );
+
+            vote(watchtower_owner_1(), proposal_id, true);
+            assert_eq!(ProposalStatus::<TestRuntime>::get(proposal_id), ProposalStatusEnum::Active);
+
+            vote(watchtower_owner_3(), proposal_id, true);
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: true }
+            );
+        });
+    }
+
+    #[test]
+    fn fails_as_soon_as_threshold_is_unreachable() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (_, proposal_id) = submit_external_proposal(
+                DecisionRule::Supermajority { quorum: Perbill::from_percent(50) },
+                Perbill::from_percent(66),
+            );
+
+            vote(watchtower_owner_3(), proposal_id, false);
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: false }
+            );
+        });
+    }
+
+    #[test]
+    fn passes_on_expiry_with_a_supermajority_of_votes_cast() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (context, proposal_id) = submit_external_proposal(
+                DecisionRule::Supermajority { quorum: Perbill::from_percent(50) },
+                Perbill::from_percent(55),
+            );
+
+            vote(watchtower_owner_3(), proposal_id, true);
+            vote(watchtower_owner_1(), proposal_id, false);
+            expire_and_finalise(&context, proposal_id);
+
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: true }
+            );
+        });
+    }
+
+    #[test]
+    fn fails_on_expiry_without_quorum() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (context, proposal_id) = submit_external_proposal(
+                DecisionRule::Supermajority { quorum: Perbill::from_percent(80) },
+                Perbill::from_percent(60),
+            );
+
+            vote(watchtower_owner_1(), proposal_id, true);
+            expire_and_finalise(&context, proposal_id);
+
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: false }
+            );
+        });
+    }
+
+    #[test]
+    fn requires_a_threshold_above_half() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let context = Context {
+                decision_rule: DecisionRule::Supermajority { quorum: Perbill::from_percent(50) },
+                threshold: Perbill::from_percent(50),
+                ..Default::default()
+            };
+
+            assert_noop!(
+                Watchtower::submit_external_proposal(
+                    RawOrigin::Signed(watchtower_owner_1()).into(),
+                    context.build_external_request(b"test".to_vec())
+                ),
+                Error::<TestRuntime>::InvalidProposal
+            );
+        });
+    }
+}
+
+mod veto {
+    use super::*;
+
+    #[test]
+    fn fails_when_veto_threshold_is_reached() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (_, proposal_id) = submit_external_proposal(
+                DecisionRule::Veto { veto_threshold: Perbill::from_percent(30) },
+                Perbill::from_percent(50),
+            );
+
+            vote(watchtower_owner_1(), proposal_id, false);
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: false }
+            );
+        });
+    }
+
+    #[test]
+    fn passes_as_soon_as_a_veto_is_impossible() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (_, proposal_id) = submit_external_proposal(
+                DecisionRule::Veto { veto_threshold: Perbill::from_percent(40) },
+                Perbill::from_percent(50),
+            );
+
+            vote(watchtower_owner_3(), proposal_id, true);
+            assert_eq!(ProposalStatus::<TestRuntime>::get(proposal_id), ProposalStatusEnum::Active);
+
+            vote(watchtower_owner_2(), proposal_id, true);
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: true }
+            );
+        });
+    }
+
+    #[test]
+    fn passes_on_expiry_without_a_veto() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let (context, proposal_id) = submit_external_proposal(
+                DecisionRule::Veto { veto_threshold: Perbill::from_percent(40) },
+                Perbill::from_percent(50),
+            );
+
+            vote(watchtower_owner_1(), proposal_id, false);
+            expire_and_finalise(&context, proposal_id);
+
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: true }
+            );
+        });
+    }
+}
+
+mod stake_weighted {
+    use super::*;
+
+    #[test]
+    fn snapshots_voting_weights_when_the_proposal_is_created() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_owner_stakes();
+            let (_, proposal_id) =
+                submit_external_proposal(DecisionRule::StakeWeighted, Perbill::from_percent(50));
+
+            assert_eq!(
+                VotingWeights::<TestRuntime>::get(proposal_id, watchtower_owner_1()),
+                Perbill::from_percent(60).deconstruct()
+            );
+            assert_eq!(
+                VotingWeights::<TestRuntime>::get(proposal_id, watchtower_owner_3()),
+                Perbill::from_percent(10).deconstruct()
+            );
+            assert_eq!(
+                TotalVotingWeight::<TestRuntime>::get(proposal_id),
+                Some(Perbill::one().deconstruct())
+            );
+        });
+    }
+
+    #[test]
+    fn weighs_votes_by_stake() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_owner_stakes();
+            let (_, proposal_id) =
+                submit_external_proposal(DecisionRule::StakeWeighted, Perbill::from_percent(50));
+
+            // Owners 2 and 3 own most watchtowers but only 40% of the stake
+            vote(watchtower_owner_3(), proposal_id, false);
+            vote(watchtower_owner_2(), proposal_id, false);
+            assert_eq!(ProposalStatus::<TestRuntime>::get(proposal_id), ProposalStatusEnum::Active);
+
+            vote(watchtower_owner_1(), proposal_id, true);
+            assert_eq!(
+                ProposalStatus::<TestRuntime>::get(proposal_id),
+                ProposalStatusEnum::Resolved { passed: true }
+            );
+        });
+    }
+
+    #[test]
+    fn ignores_stake_changes_after_the_proposal_is_created() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_owner_stakes();
+            let (_, proposal_id) =
+                submit_external_proposal(DecisionRule::StakeWeighted, Perbill::from_percent(50));
+
+            set_watchtower_stake(&watchtower_owner_2(), 10_000);
+            vote(watchtower_owner_2(), proposal_id, true);
+
+            System::assert_last_event(
+                Event::VoteSubmitted {
+                    voter: watchtower_owner_2(),
+                    proposal_id,
+                    in_favor: true,
+                    vote_weight: Perbill::from_percent(30).deconstruct(),
+                }
+                .into(),
+            );
+            assert_eq!(ProposalStatus::<TestRuntime>::get(proposal_id), ProposalStatusEnum::Active);
+        });
+    }
+
+    #[test]
+    fn accepts_votes_without_stake_with_no_weight() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_watchtower_stake(&watchtower_owner_1(), 600);
+            let (_, proposal_id) =
+                submit_external_proposal(DecisionRule::StakeWeighted, Perbill::from_percent(50));
+
+            vote(watchtower_owner_2(), proposal_id, true);
+
+            System::assert_last_event(
+                Event::VoteSubmitted {
+                    voter: watchtower_owner_2(),
+                    proposal_id,
+                    in_favor: true,
+                    vote_weight: 0,
+                }
+                .into(),
+            );
+            assert_eq!(ProposalStatus::<TestRuntime>::get(proposal_id), ProposalStatusEnum::Active);
+        });
+    }
+
+    #[test]
+    fn fails_when_no_voter_has_stake() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let context = Context {
+                decision_rule: DecisionRule::StakeWeighted,
+                threshold: Perbill::from_percent(50),
+                ..Default::default()
+            };
+            assert_noop!(
+                Watchtower::submit_external_proposal(
+                    RawOrigin::Signed(watchtower_owner_1()).into(),
+                    context.build_external_request(b"test".to_vec())
+                ),
+                Error::<TestRuntime>::NoWeightedVotingStake
+            );
+        });
+    }
+
+    #[test]
+    fn fails_when_there_are_too_many_voters() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_owner_stakes();
+            MaxWeightedVoters::set(2);
+            let context = Context {
+                decision_rule: DecisionRule::StakeWeighted,
+                threshold: Perbill::from_percent(50),
+                ..Default::default()
+            };
+            assert_noop!(
+                Watchtower::submit_external_proposal(
+                    RawOrigin::Signed(watchtower_owner_1()).into(),
+                    context.build_external_request(b"test".to_vec())
+                ),
+                Error::<TestRuntime>::TooManyWeightedVoters
+            );
+        });
+    }
+
+    #[test]
+    fn snapshots_queued_proposals_when_they_are_created() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            let active_context = Context::default();
+            assert_ok!(<Watchtower as WatchtowerInterface>::submit_proposal(
+                None,
+                active_context.build_internal_request(b"active".to_vec())
+            ));
+
+            let queued_context = Context {
+                external_ref: H256::repeat_byte(2),
+                decision_rule: DecisionRule::StakeWeighted,
+                ..Default::default()
+            };
+            set_watchtower_stake(&watchtower_1(), 100);
+            set_watchtower_stake(&watchtower_2(), 300);
+            assert_ok!(<Watchtower as WatchtowerInterface>::submit_proposal(
+                None,
+                queued_context.build_internal_request(b"queued".to_vec())
+            ));
+            let queued_id = ExternalRef::<TestRuntime>::get(&queued_context.external_ref);
+            assert_eq!(ProposalStatus::<TestRuntime>::get(queued_id), ProposalStatusEnum::Queued);
+
+            set_watchtower_stake(&watchtower_1(), 300);
+            roll_forward(active_context.vote_duration.unwrap() as u64 + 1);
+
+            assert_eq!(ActiveInternalProposal::<TestRuntime>::get(), Some(queued_id));
+            assert_eq!(
+                VotingWeights::<TestRuntime>::get(queued_id, watchtower_1()),
+                Perbill::from_percent(25).deconstruct()
+            );
+            assert_eq!(
+                TotalVotingWeight::<TestRuntime>::get(queued_id),
+                Some(Perbill::one().deconstruct())
+            );
+        });
+    }
+
+    #[test]
+    fn voting_weights_are_cleaned_up() {
+        ExtBuilder::build_default().as_externality().execute_with(|| {
+            set_owner_stakes();
+            let (_, proposal_id) =
+                submit_external_proposal(DecisionRule::StakeWeighted, Perbill::from_percent(50));
+            vote(watchtower_owner_1(), proposal_id, true);
+
+            roll_forward(1);
+
+            assert!(!Proposals::<TestRuntime>::contains_key(proposal_id));
+            assert!(VotingWeights::<TestRuntime>::iter_prefix(proposal_id).next().is_none());
+            assert!(!TotalVotingWeight::<TestRuntime>::contains_key(proposal_id));
+        });
+    }
+}
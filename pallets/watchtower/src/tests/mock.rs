@@ -7,14 +7,19 @@ use frame_support::{
 };
 use frame_system::{self as system, EnsureRoot, EnsureSigned};
 
+pub use parking_lot::RwLock;
 pub use sp_avn_common::avn_tests_helpers::utilities::{
     get_test_account_from_mnemonic, TestAccount,
 };
+use sp_core::offchain::{
+    testing::{OffchainState, PoolState, TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
 pub use sp_core::{crypto::DEV_PHRASE, sr25519, H256};
 
-use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
 pub use sp_runtime::{
-    testing::TestXt,
+    testing::{TestXt, UintAuthorityId},
     traits::{IdentityLookup, Verify},
     BuildStorage, Perbill,
 };
@@ -33,6 +38,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+        AVN: pallet_avn::{Pallet, Storage, Event, Config<T>},
         Watchtower: pallet_watchtower::{Pallet, Call, Storage, Event<T>},
     }
 );
@@ -47,11 +53,19 @@ impl Config for TestRuntime {
     type ExternalProposerOrigin = EnsureExternalProposerOrRoot;
     type Watchtowers = TestNodeManager;
     type WatchtowerHooks = ();
+    type PayloadValidator = TestPayloadValidator;
     type SignedTxLifetime = ConstU32<5>;
     type MaxTitleLen = ConstU32<512>;
     type MaxInlineLen = ConstU32<8192>;
     type MaxUriLen = ConstU32<2040>;
     type MaxInternalProposalLen = ConstU32<100>;
+    type MaxWeightedVoters = MaxWeightedVoters;
+    type MaxActiveExternalProposals = MaxActiveExternalProposals;
+}
+
+parameter_types! {
+    pub static MaxWeightedVoters: u32 = 100;
+    pub static MaxActiveExternalProposals: u32 = 10;
 }
 
 parameter_types! {
@@ -115,6 +129,28 @@ impl pallet_timestamp::Config for TestRuntime {
     type WeightInfo = ();
 }
 
+impl pallet_avn::Config for TestRuntime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = UintAuthorityId;
+    type EthereumPublicKeyChecker = ();
+    type NewSessionHandler = ();
+    type DisabledValidatorChecker = ();
+    type WeightInfo = ();
+}
+
+pub const VALID_GOVERNANCE_PAYLOAD: &[u8] = b"valid governance payload";
+
+/// Accepts a single governance payload and leaves every other proposal type unhandled
+pub struct TestPayloadValidator;
+impl ProposalPayloadValidator for TestPayloadValidator {
+    fn validate(proposal_type: &ProposalType, payload: &[u8]) -> Option<bool> {
+        match proposal_type {
+            ProposalType::Governance => Some(payload == VALID_GOVERNANCE_PAYLOAD),
+            _ => None,
+        }
+    }
+}
+
 pub fn get_default_voter() -> TestAccount {
     get_test_account_from_mnemonic(DEV_PHRASE)
 }
@@ -211,10 +247,22 @@ thread_local! {
             keys.insert(watchtower_owner_3(), vec![watchtower_7(), watchtower_8(), watchtower_9(), watchtower_10()]);
             keys
         });
+
+    pub static WATCHTOWER_STAKES: RefCell<std::collections::HashMap<AccountId, u128>> =
+        RefCell::new(std::collections::HashMap::new());
+}
+
+pub fn set_watchtower_stake(who: &AccountId, stake: u128) {
+    WATCHTOWER_STAKES.with(|stakes| stakes.borrow_mut().insert(*who, stake));
 }
 
 pub struct ExtBuilder {
     pub storage: sp_runtime::Storage,
+    offchain_state: Option<Arc<RwLock<OffchainState>>>,
+    pool_state: Option<Arc<RwLock<PoolState>>>,
+    txpool_extension: Option<TestTransactionPoolExt>,
+    offchain_extension: Option<TestOffchainExt>,
+    offchain_registered: bool,
 }
 
 impl ExtBuilder {
@@ -224,7 +272,26 @@ impl ExtBuilder {
             .unwrap()
             .into();
 
-        Self { storage }
+        Self {
+            storage,
+            pool_state: None,
+            offchain_state: None,
+            txpool_extension: None,
+            offchain_extension: None,
+            offchain_registered: false,
+        }
+    }
+
+    pub fn for_offchain_worker(mut self) -> Self {
+        assert!(!self.offchain_registered);
+        let (offchain, offchain_state) = TestOffchainExt::new();
+        let (pool, pool_state) = TestTransactionPoolExt::new();
+        self.txpool_extension = Some(pool);
+        self.offchain_extension = Some(offchain);
+        self.pool_state = Some(pool_state);
+        self.offchain_state = Some(offchain_state);
+        self.offchain_registered = true;
+        self
     }
 
     pub fn as_externality(self) -> sp_io::TestExternalities {
@@ -238,6 +305,27 @@ impl ExtBuilder {
         });
         ext
     }
+
+    pub fn as_externality_with_state(
+        self,
+    ) -> (sp_io::TestExternalities, Arc<RwLock<PoolState>>, Arc<RwLock<OffchainState>>) {
+        assert!(self.offchain_registered);
+        // The local watchtower node signs its votes with the default voter's key
+        let keystore = MemoryKeystore::new();
+        keystore
+            .sr25519_generate_new(<SignerId as RuntimeAppPublic>::ID, Some(DEV_PHRASE))
+            .unwrap();
+
+        let mut ext = sp_io::TestExternalities::from(self.storage);
+        ext.register_extension(KeystoreExt(Arc::new(keystore)));
+        ext.register_extension(OffchainDbExt::new(self.offchain_extension.clone().unwrap()));
+        ext.register_extension(OffchainWorkerExt::new(self.offchain_extension.unwrap()));
+        ext.register_extension(TransactionPoolExt::new(self.txpool_extension.unwrap()));
+        ext.execute_with(|| {
+            frame_system::Pallet::<TestRuntime>::set_block_number(1u32.into());
+        });
+        (ext, self.pool_state.unwrap(), self.offchain_state.unwrap())
+    }
 }
 
 /// Rolls desired block number of times.
@@ -273,6 +361,15 @@ impl NodesInterface<AccountId, SignerId> for TestNodeManager {
         NODE_SIGNING_KEYS.with(|keys| keys.borrow().get(node).cloned())
     }
 
+    fn get_node_owner(node: &AccountId) -> Option<AccountId> {
+        NODE_OWNERS.with(|keys| {
+            keys.borrow()
+                .iter()
+                .find(|(_, nodes)| nodes.contains(node))
+                .map(|(owner, _)| owner.clone())
+        })
+    }
+
     fn get_node_from_local_signing_keys() -> Option<(AccountId, SignerId)> {
         let maybe_watchtower_1 =
             AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().first().unwrap().clone());
@@ -288,6 +385,25 @@ impl NodesInterface<AccountId, SignerId> for TestNodeManager {
         NODE_OWNERS.with(|keys| keys.borrow().get(owner).map_or(0, |v| v.len() as u32))
     }
 
+    fn get_watchtower_stakes(source: &ProposalSource) -> Vec<(AccountId, u128)> {
+        let voters = match source {
+            ProposalSource::Internal(_) => AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().clone()),
+            ProposalSource::External =>
+                NODE_OWNERS.with(|keys| keys.borrow().keys().cloned().collect()),
+        };
+
+        WATCHTOWER_STAKES.with(|stakes| {
+            let stakes = stakes.borrow();
+            voters
+                .into_iter()
+                .map(|voter| {
+                    let stake = stakes.get(&voter).copied().unwrap_or_default();
+                    (voter, stake)
+                })
+                .collect()
+        })
+    }
+
     fn get_authorized_watchtowers_count() -> u32 {
         AUTHORIZED_WATCHTOWERS.with(|w| w.borrow().len() as u32)
     }
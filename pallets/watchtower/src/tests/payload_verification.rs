@@ -0,0 +1,325 @@
+// Copyright 2026 Aventus DAO.
+
+#![cfg(test)]
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::offchain::testing::{OffchainState, PendingRequest, PoolState};
+
+const PAYLOAD_URI: &[u8] = b"https://proposals.example.com/1";
+
+fn submit_hashed_uri_proposal(proposal_type: ProposalType, content: &[u8]) -> ProposalId {
+    let context = Context::default();
+    let payload = RawPayload::HashedUri {
+        uri: PAYLOAD_URI.to_vec(),
+        content_hash: H256::from(sp_io::hashing::blake2_256(content)),
+    };
+    let request = context.build_request(payload, ProposalSource::Internal(proposal_type));
+    assert_ok!(Watchtower::submit_proposal(None, request));
+    ExternalRef::<TestRuntime>::get(&context.external_ref)
+}
+
+fn submit_external_proposal(payload: RawPayload) -> ProposalId {
+    let context = Context::default();
+    assert_ok!(Watchtower::submit_external_proposal(
+        frame_system::RawOrigin::Signed(watchtower_owner_1()).into(),
+        context.build_request(payload, ProposalSource::External)
+    ));
+    ExternalRef::<TestRuntime>::get(&context.external_ref)
+}
+
+fn mock_payload_response(state: &mut OffchainState, response: Vec<u8>) {
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: format!("http://127.0.0.1:2020/watchtower/payload/{}", hex::encode(PAYLOAD_URI)),
+        response: Some(response),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+fn pop_vote_from_mempool(pool_state: Arc<RwLock<PoolState>>) -> Option<(ProposalId, bool)> {
+    let tx = pool_state.write().transactions.pop()?;
+    let tx = Extrinsic::decode(&mut &*tx).unwrap();
+    let RuntimeCall::Watchtower(crate::Call::unsigned_vote {
+        proposal_id,
+        in_favor,
+        watchtower,
+        ..
+    }) = tx.function.clone()
+    else {
+        panic!("Unexpected transaction in the mempool")
+    };
+
+    assert_eq!(watchtower, watchtower_1());
+    assert_ok!(tx.function.dispatch(frame_system::RawOrigin::None.into()));
+    Some((proposal_id, in_favor))
+}
+
+#[test]
+fn votes_in_favor_of_a_valid_payload() {
+    let (mut ext, pool_state, offchain_state) =
+        ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+    ext.execute_with(|| {
+        let proposal_id =
+            submit_hashed_uri_proposal(ProposalType::Governance, VALID_GOVERNANCE_PAYLOAD);
+        mock_payload_response(
+            &mut offchain_state.write(),
+            hex::encode(VALID_GOVERNANCE_PAYLOAD).into_bytes(),
+        );
+
+        Watchtower::offchain_worker(System::block_number());
+
+        assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, true)));
+        assert!(Voters::<TestRuntime>::get(proposal_id, watchtower_1()));
+    });
+}
+
+#[test]
+fn votes_against_a_payload_rejected_by_the_validator() {
+    let (mut ext, pool_state, offchain_state) =
+        ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+    ext.execute_with(|| {
+        let content = b"invalid governance payload";
+        let proposal_id = submit_hashed_uri_proposal(ProposalType::Governance, content);
+        mock_payload_response(&mut offchain_state.write(), hex::encode(content).into_bytes());
+
+        Watchtower::offchain_worker(System::block_number());
+
+        assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+    });
+}
+
+#[test]
+fn votes_against_a_payload_that_does_not_match_its_hash() {
+    let (mut ext, pool_state, offchain_state) =
+        ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+    ext.execute_with(|| {
+        let proposal_id =
+            submit_hashed_uri_proposal(ProposalType::Governance, VALID_GOVERNANCE_PAYLOAD);
+        mock_payload_response(
+            &mut offchain_state.write(),
+            hex::encode(b"tampered governance payload").into_bytes(),
+        );
+
+        Watchtower::offchain_worker(System::block_number());
+
+        assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+    });
+}
+
+#[test]
+fn votes_against_a_payload_that_cannot_be_retrieved() {
+    let (mut ext, pool_state, offchain_state) =
+        ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+    ext.execute_with(|| {
+        let proposal_id =
+            submit_hashed_uri_proposal(ProposalType::Governance, VALID_GOVERNANCE_PAYLOAD);
+        mock_payload_response(&mut offchain_state.write(), b"Failed to fetch payload".to_vec());
+
+        Watchtower::offchain_worker(System::block_number());
+
+        assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+    });
+}
+
+#[test]
+fn does_not_vote_when_no_validator_handles_the_proposal_type() {
+    let (mut ext, pool_state, offchain_state) =
+        ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+    ext.execute_with(|| {
+        let content = b"other payload";
+        submit_hashed_uri_proposal(ProposalType::Other(1), content);
+        mock_payload_response(&mut offchain_state.write(), hex::encode(content).into_bytes());
+
+        Watchtower::offchain_worker(System::block_number());
+
+        assert_eq!(pop_vote_from_mempool(pool_state), None);
+    });
+}
+
+#[test]
+fn does_not_vote_twice_on_the_same_proposal() {
+    let (mut ext, pool_state, offchain_state) =
+        ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+    ext.execute_with(|| {
+        submit_hashed_uri_proposal(ProposalType::Governance, VALID_GOVERNANCE_PAYLOAD);
+        mock_payload_response(
+            &mut offchain_state.write(),
+            hex::encode(VALID_GOVERNANCE_PAYLOAD).into_bytes(),
+        );
+
+        Watchtower::offchain_worker(System::block_number());
+        assert!(pop_vote_from_mempool(pool_state.clone()).is_some());
+
+        roll_forward(1);
+        Watchtower::offchain_worker(System::block_number());
+        assert_eq!(pop_vote_from_mempool(pool_state), None);
+    });
+}
+
+#[test]
+fn hashed_uri_proposals_can_be_external() {
+    let mut ext = ExtBuilder::build_default().as_externality();
+    ext.execute_with(|| {
+        let context = Context::default();
+        let payload =
+            RawPayload::HashedUri { uri: PAYLOAD_URI.to_vec(), content_hash: H256::repeat_byte(1) };
+        assert_ok!(Watchtower::submit_external_proposal(
+            frame_system::RawOrigin::Signed(watchtower_owner_1()).into(),
+            context.build_request(payload, ProposalSource::External)
+        ));
+    });
+}
+
+mod external_proposals {
+    use super::*;
+
+    fn hashed_uri_payload(content: &[u8]) -> RawPayload {
+        RawPayload::HashedUri {
+            uri: PAYLOAD_URI.to_vec(),
+            content_hash: H256::from(sp_io::hashing::blake2_256(content)),
+        }
+    }
+
+    /// Runs the offchain worker in the next block, with the payload fetched as `response`.
+    fn verify_in_next_block(offchain_state: &Arc<RwLock<OffchainState>>, response: Vec<u8>) {
+        roll_forward(1);
+        mock_payload_response(&mut offchain_state.write(), response);
+        Watchtower::offchain_worker(System::block_number());
+    }
+
+    /// Runs the offchain worker until the payload has failed verification for the whole retry
+    /// period, checking no vote is cast before.
+    fn fail_verification_until_retries_run_out(
+        offchain_state: &Arc<RwLock<OffchainState>>,
+        pool_state: &Arc<RwLock<PoolState>>,
+        response: Vec<u8>,
+    ) {
+        mock_payload_response(&mut offchain_state.write(), response.clone());
+        Watchtower::offchain_worker(System::block_number());
+
+        for _ in 1..PAYLOAD_RETRY_PERIOD {
+            assert_eq!(pop_vote_from_mempool(pool_state.clone()), None);
+            verify_in_next_block(offchain_state, response.clone());
+        }
+        assert_eq!(pop_vote_from_mempool(pool_state.clone()), None);
+
+        verify_in_next_block(offchain_state, response);
+    }
+
+    #[test]
+    fn votes_against_a_payload_that_does_not_match_its_hash_for_the_node_owner() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let proposal_id = submit_external_proposal(hashed_uri_payload(b"payload"));
+
+            fail_verification_until_retries_run_out(
+                &offchain_state,
+                &pool_state,
+                hex::encode(b"tampered payload").into_bytes(),
+            );
+
+            assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+            assert!(Voters::<TestRuntime>::get(proposal_id, watchtower_owner_1()));
+            assert!(!Voters::<TestRuntime>::get(proposal_id, watchtower_1()));
+        });
+    }
+
+    #[test]
+    fn votes_against_a_uri_payload_that_cannot_be_retrieved() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let proposal_id = submit_external_proposal(RawPayload::Uri(PAYLOAD_URI.to_vec()));
+
+            fail_verification_until_retries_run_out(
+                &offchain_state,
+                &pool_state,
+                b"Failed to fetch payload".to_vec(),
+            );
+
+            assert_eq!(pop_vote_from_mempool(pool_state), Some((proposal_id, false)));
+            assert!(Voters::<TestRuntime>::get(proposal_id, watchtower_owner_1()));
+        });
+    }
+
+    #[test]
+    fn does_not_vote_against_a_payload_that_recovers_within_the_retry_period() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let content = b"payload";
+            submit_external_proposal(hashed_uri_payload(content));
+            mock_payload_response(&mut offchain_state.write(), b"Failed to fetch payload".to_vec());
+            Watchtower::offchain_worker(System::block_number());
+
+            verify_in_next_block(&offchain_state, hex::encode(content).into_bytes());
+
+            // The payload is verified, so it isn't fetched again.
+            roll_forward(PAYLOAD_RETRY_PERIOD as u64);
+            Watchtower::offchain_worker(System::block_number());
+            assert_eq!(pop_vote_from_mempool(pool_state), None);
+        });
+    }
+
+    #[test]
+    fn does_not_vote_on_a_valid_payload() {
+        let (mut ext, pool_state, offchain_state) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let content = b"payload";
+            submit_external_proposal(hashed_uri_payload(content));
+            mock_payload_response(&mut offchain_state.write(), hex::encode(content).into_bytes());
+
+            Watchtower::offchain_worker(System::block_number());
+            assert_eq!(pop_vote_from_mempool(pool_state.clone()), None);
+
+            // No request is expected, the verified payload is not fetched again.
+            roll_forward(1);
+            Watchtower::offchain_worker(System::block_number());
+            assert_eq!(pop_vote_from_mempool(pool_state), None);
+        });
+    }
+
+    #[test]
+    fn does_not_vote_when_the_owner_already_voted() {
+        let (mut ext, pool_state, _) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let proposal_id = submit_external_proposal(hashed_uri_payload(b"payload"));
+            Voters::<TestRuntime>::insert(proposal_id, watchtower_owner_1(), true);
+
+            Watchtower::offchain_worker(System::block_number());
+
+            assert_eq!(pop_vote_from_mempool(pool_state), None);
+        });
+    }
+
+    #[test]
+    fn nodes_cannot_vote_in_favor_of_external_proposals() {
+        let (mut ext, _, _) =
+            ExtBuilder::build_default().for_offchain_worker().as_externality_with_state();
+        ext.execute_with(|| {
+            let proposal_id = submit_external_proposal(hashed_uri_payload(b"payload"));
+            let signature = get_signing_key_for_wt_1()
+                .sign(
+                    &(WATCHTOWER_UNSIGNED_VOTE_CONTEXT, proposal_id, true, &watchtower_1())
+                        .encode(),
+                )
+                .unwrap();
+
+            assert_noop!(
+                Watchtower::unsigned_vote(
+                    frame_system::RawOrigin::None.into(),
+                    proposal_id,
+                    true,
+                    watchtower_1(),
+                    signature
+                ),
+                Error::<TestRuntime>::InvalidProposalForUnsignedVote
+            );
+        });
+    }
+}
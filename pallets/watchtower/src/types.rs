@@ -19,6 +19,21 @@ pub enum Payload<T: Config> {
 
     /// A link to off-chain proposal data (e.g. IPFS hash)
     Uri(BoundedVec<u8, T::MaxUriLen>),
+
+    /// A link to off-chain proposal data, committing to the blake2-256 hash of its content
+    HashedUri { uri: BoundedVec<u8, T::MaxUriLen>, content_hash: H256 },
+}
+
+/// The result of verifying an external proposal's payload, cached in offchain storage so the
+/// payload isn't fetched again every block.
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub enum PayloadCheck<BlockNumber> {
+    /// The payload was fetched and matches its hash
+    Valid,
+    /// The payload has failed verification since `first_failure`
+    Failing { first_failure: BlockNumber },
+    /// The payload kept failing verification for `PAYLOAD_RETRY_PERIOD` blocks
+    Invalid,
 }
 
 pub fn to_proposal<T: Config>(
@@ -64,6 +79,10 @@ pub fn to_payload<T: Config>(raw: RawPayload) -> Result<Payload<T>, Error<T>> {
             let bounded = BoundedVec::try_from(data).map_err(|_| Error::<T>::InvalidUri)?;
             Ok(Payload::Uri(bounded))
         },
+        RawPayload::HashedUri { uri, content_hash } => {
+            let bounded = BoundedVec::try_from(uri).map_err(|_| Error::<T>::InvalidUri)?;
+            Ok(Payload::HashedUri { uri: bounded, content_hash })
+        },
     }
 }
 
@@ -114,9 +133,19 @@ impl<T: Config> Proposal<T> {
                 !data.is_empty() && matches!(self.source, ProposalSource::Internal(_)),
             Payload::Uri(data) =>
                 !data.is_empty() && matches!(self.source, ProposalSource::External),
+            // The content hash lets watchtower nodes verify the payload, whatever its source
+            Payload::HashedUri { uri, content_hash } =>
+                !uri.is_empty() && *content_hash != H256::zero(),
         };
 
-        base_is_valid && payload_valid
+        let rule_valid = match self.decision_rule {
+            DecisionRule::SimpleMajority | DecisionRule::StakeWeighted => true,
+            DecisionRule::Supermajority { quorum } =>
+                self.threshold > Perbill::from_percent(50) && quorum > Perbill::zero(),
+            DecisionRule::Veto { veto_threshold } => veto_threshold > Perbill::zero(),
+        };
+
+        base_is_valid && payload_valid && rule_valid
     }
 }
 
@@ -133,9 +162,18 @@ pub trait NodesInterface<AccountId, SignerId> {
     /// Get the voting weight of a given watchtower
     fn get_watchtower_voting_weight(who: &AccountId) -> u32;
 
+    /// Get the stake backing each account that can vote on proposals from `source`. Used to
+    /// snapshot voting weights of stake-weighted proposals. Implementations can stop after
+    /// `MaxWeightedVoters + 1` accounts and should read at most two storage items per account, as
+    /// assumed by the `snapshot_voting_weights` weight.
+    fn get_watchtower_stakes(source: &ProposalSource) -> Vec<(AccountId, u128)>;
+
     /// Get the signing key for a given watchtower account
     fn get_node_signing_key(node: &AccountId) -> Option<SignerId>;
 
+    /// Get the owner of a given watchtower account
+    fn get_node_owner(node: &AccountId) -> Option<AccountId>;
+
     /// Get a local watchtower account and its signing key, if available on this node
     fn get_node_from_local_signing_keys() -> Option<(AccountId, SignerId)>;
 }
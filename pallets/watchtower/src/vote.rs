@@ -1,23 +1,88 @@
 use crate::*;
 
 impl<T: Config> Pallet<T> {
-    pub fn threshold_achieved(proposal_id: ProposalId, threshold: Perbill) -> Option<bool> {
+    pub fn threshold_achieved(proposal_id: ProposalId, proposal: &Proposal<T>) -> Option<bool> {
         let vote = Votes::<T>::get(proposal_id);
-        let total_voters = T::Watchtowers::get_authorized_watchtowers_count();
-        if total_voters == 0 {
+        let total_weight = Self::total_voting_weight(proposal_id, proposal);
+        if total_weight == 0 {
             return None
         }
 
-        let min_votes = threshold.mul_ceil(total_voters);
-        if vote.in_favors >= min_votes {
-            Some(true)
-        } else if vote.againsts >= min_votes {
-            Some(false)
-        } else {
-            None
+        match proposal.decision_rule {
+            DecisionRule::SimpleMajority | DecisionRule::StakeWeighted => {
+                let min_votes = proposal.threshold.mul_ceil(total_weight);
+                if vote.in_favors >= min_votes {
+                    Some(true)
+                } else if vote.againsts >= min_votes {
+                    Some(false)
+                } else {
+                    None
+                }
+            },
+            DecisionRule::Supermajority { quorum } => {
+                let min_votes = proposal.threshold.mul_ceil(total_weight);
+                let turnout = vote.in_favors.saturating_add(vote.againsts);
+                if vote.in_favors >= min_votes && turnout >= quorum.mul_ceil(total_weight) {
+                    Some(true)
+                } else if total_weight.saturating_sub(vote.againsts) < min_votes {
+                    // Even if everyone left votes in favor, the threshold can't be reached
+                    Some(false)
+                } else {
+                    None
+                }
+            },
+            DecisionRule::Veto { veto_threshold } => {
+                let min_vetoes = veto_threshold.mul_ceil(total_weight);
+                if vote.againsts >= min_vetoes {
+                    Some(false)
+                } else if total_weight.saturating_sub(vote.in_favors) < min_vetoes {
+                    // Not enough voting weight left to veto
+                    Some(true)
+                } else {
+                    None
+                }
+            },
+        }
+    }
+
+    /// The voting weight thresholds are measured against. Stake-weighted proposals use the
+    /// snapshot taken when voting started, if there is one.
+    pub fn total_voting_weight(proposal_id: ProposalId, proposal: &Proposal<T>) -> u32 {
+        match proposal.decision_rule {
+            DecisionRule::StakeWeighted => TotalVotingWeight::<T>::get(proposal_id)
+                .unwrap_or_else(T::Watchtowers::get_authorized_watchtowers_count),
+            _ => T::Watchtowers::get_authorized_watchtowers_count(),
         }
     }
 
+    /// Store each voter's share of the total stake, in parts per billion. Fails if there are more
+    /// voters than `MaxWeightedVoters` or none of them has stake, so a stake-weighted proposal is
+    /// never counted by voter instead.
+    pub fn snapshot_voting_weights(
+        proposal_id: ProposalId,
+        stakes: Vec<(T::AccountId, u128)>,
+    ) -> DispatchResult {
+        ensure!(
+            stakes.len() <= T::MaxWeightedVoters::get() as usize,
+            Error::<T>::TooManyWeightedVoters
+        );
+        let total_stake =
+            stakes.iter().fold(0u128, |total, (_, stake)| total.saturating_add(*stake));
+        ensure!(total_stake > 0, Error::<T>::NoWeightedVotingStake);
+
+        let mut total_weight: u32 = 0;
+        for (voter, stake) in stakes {
+            let weight = Perbill::from_rational(stake, total_stake).deconstruct();
+            if weight > 0 {
+                VotingWeights::<T>::insert(proposal_id, voter, weight);
+                total_weight = total_weight.saturating_add(weight);
+            }
+        }
+
+        TotalVotingWeight::<T>::insert(proposal_id, total_weight);
+        Ok(())
+    }
+
     pub fn get_proposal_status(result: bool) -> ProposalStatusEnum {
         if result {
             ProposalStatusEnum::Resolved { passed: true }
@@ -34,13 +99,23 @@ impl<T: Config> Pallet<T> {
             ProposalSource::Internal(_) => ProposalStatusEnum::Expired,
             ProposalSource::External => {
                 let votes = Votes::<T>::get(proposal_id);
-                if proposal.decision_rule == DecisionRule::SimpleMajority &&
-                    votes.in_favors > votes.againsts
-                {
-                    ProposalStatusEnum::Resolved { passed: true }
-                } else {
-                    ProposalStatusEnum::Resolved { passed: false }
-                }
+                let passed = match proposal.decision_rule {
+                    DecisionRule::SimpleMajority | DecisionRule::StakeWeighted =>
+                        votes.in_favors > votes.againsts,
+                    DecisionRule::Supermajority { quorum } => {
+                        let total_weight = Self::total_voting_weight(proposal_id, proposal);
+                        let turnout = votes.in_favors.saturating_add(votes.againsts);
+                        turnout > 0 &&
+                            turnout >= quorum.mul_ceil(total_weight) &&
+                            votes.in_favors >= proposal.threshold.mul_ceil(turnout)
+                    },
+                    DecisionRule::Veto { veto_threshold } => {
+                        let total_weight = Self::total_voting_weight(proposal_id, proposal);
+                        votes.againsts < veto_threshold.mul_ceil(total_weight)
+                    },
+                };
+
+                ProposalStatusEnum::Resolved { passed }
             },
         }
     }
@@ -76,6 +151,10 @@ impl<T: Config> Pallet<T> {
             consensus_result,
         });
 
+        if let ProposalSource::External = proposal.source {
+            ActiveExternalProposals::<T>::mutate(|active| active.retain(|id| *id != proposal_id));
+        }
+
         // If this was an internal proposal, activate the next one in the queue
         if let ProposalSource::Internal(_) = proposal.source {
             ActiveInternalProposal::<T>::kill();
@@ -104,7 +183,7 @@ impl<T: Config> Pallet<T> {
         proposal: &Proposal<T>,
         current_block: BlockNumberFor<T>,
     ) -> Option<ProposalStatusEnum> {
-        if let Some(result) = Self::threshold_achieved(proposal_id, proposal.threshold) {
+        if let Some(result) = Self::threshold_achieved(proposal_id, proposal) {
             Some(Self::get_proposal_status(result))
         } else if Self::proposal_expired(current_block, proposal) {
             Some(Self::get_vote_result_on_expiry(proposal_id, proposal))
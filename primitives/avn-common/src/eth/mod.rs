@@ -6,7 +6,7 @@ extern crate alloc;
 use alloc::string::String;
 
 use alloy_primitives::{Address, Bytes, FixedBytes, B256 as AlloyB256, U256 as AlloyU256};
-use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolStruct};
+use alloy_sol_types::{eip712_domain, sol, Eip712Domain, SolStruct, SolValue};
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::str::{self, FromStr};
 use sp_core::{ConstU32, H160, H256};
@@ -233,6 +233,32 @@ sol! {
   }
 }
 
+sol! {
+    struct AnchorCheckpoint {
+        uint32 chainId;
+        uint64 checkpointId;
+        bytes32 checkpoint;
+        uint64 originId;
+    }
+}
+
+/// ABI encodes an avn-anchor checkpoint as a summary tree leaf, so Ethereum contracts can decode
+/// it once its inclusion in a published root is proven.
+pub fn encode_anchor_checkpoint_leaf(
+    chain_id: u32,
+    checkpoint_id: u64,
+    checkpoint: H256,
+    origin_id: u64,
+) -> Vec<u8> {
+    AnchorCheckpoint {
+        chainId: chain_id,
+        checkpointId: checkpoint_id,
+        checkpoint: FixedBytes::from_slice(checkpoint.as_fixed_bytes()),
+        originId: origin_id,
+    }
+    .abi_encode()
+}
+
 impl TryFrom<LowerParams> for LowerData {
     type Error = ();
 
@@ -516,6 +542,20 @@ mod test {
             H256(hex!("e5bf20ae6173912260d45213e1fc29b9d68f7ddc72f2922779a4f040f373f50e"))
         );
     }
+
+    #[test]
+    fn anchor_checkpoint_leaf_is_abi_encoded() {
+        let checkpoint = H256::repeat_byte(0xab);
+        let leaf = encode_anchor_checkpoint_leaf(7, 258, checkpoint, 9);
+
+        // Four static values, each padded to a 32 byte word.
+        let mut expected = [0u8; 128];
+        expected[31] = 7;
+        expected[62..64].copy_from_slice(&[1, 2]);
+        expected[64..96].copy_from_slice(checkpoint.as_bytes());
+        expected[127] = 9;
+        assert_eq!(leaf, expected.to_vec());
+    }
 }
 
 pub fn concat_lower_data(
@@ -55,6 +55,7 @@ pub mod eth_key_actions;
 pub mod event_discovery;
 pub mod event_types;
 pub mod http_data_codec;
+pub mod merkle;
 pub mod node;
 pub mod ocw_lock;
 use crate::node::Moment;
@@ -137,6 +138,35 @@ pub struct Proof<Signature: TypeInfo, AccountId> {
     pub signature: Signature,
 }
 
+/// Identifies a runtime call by the index of its pallet and the index of the call in that pallet
+#[derive(
+    Encode,
+    Decode,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Debug,
+    TypeInfo,
+    MaxEncodedLen,
+    DecodeWithMemTracking,
+)]
+pub struct CallType {
+    pub pallet_index: u8,
+    pub call_index: u8,
+}
+
+impl CallType {
+    /// The first two bytes of an encoded runtime call are its pallet and call indexes
+    pub fn of<Call: Encode>(call: &Call) -> Option<Self> {
+        return call.using_encoded(|bytes| match bytes {
+            [pallet_index, call_index, ..] =>
+                Some(CallType { pallet_index: *pallet_index, call_index: *call_index }),
+            _ => None,
+        })
+    }
+}
+
 pub trait CallDecoder {
     // The type that represents an account id defined in the trait (T::AccountId)
     type AccountId;
@@ -468,6 +498,23 @@ impl<BlockNumber, Weight: Zero> OnIdleHandler<BlockNumber, Weight> for () {
     }
 }
 
+/// Provides the leaves of a summary tree from on-chain state, for summary instances that do not
+/// summarise extrinsics.
+pub trait SummaryLeafProvider<BlockNumber> {
+    /// The ABI encoded leaves of the blocks `from_block..=to_block`, or `None` if the leaves are
+    /// not provided on chain and the root must come from the external service.
+    fn leaves(from_block: BlockNumber, to_block: BlockNumber) -> Option<Vec<Vec<u8>>>;
+
+    /// Called once Ethereum confirms the root of the blocks `from_block..=to_block`.
+    fn on_leaves_published(_from_block: BlockNumber, _to_block: BlockNumber) {}
+}
+
+impl<BlockNumber> SummaryLeafProvider<BlockNumber> for () {
+    fn leaves(_from_block: BlockNumber, _to_block: BlockNumber) -> Option<Vec<Vec<u8>>> {
+        None
+    }
+}
+
 /// Shared index type for reward periods, used by both node-manager and avn-anchor.
 pub type RewardPeriodIndex = u64;
 
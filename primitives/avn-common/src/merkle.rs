@@ -0,0 +1,128 @@
+//! Keccak merkle trees that can be verified by the Ethereum contracts.
+//!
+//! Leaves are hashed with keccak256, each pair of nodes is sorted before it is hashed and an odd
+//! node is carried up to the next level unchanged. These are the same trees the external service
+//! builds over extrinsics for summary roots.
+
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// The root of the tree built over `leaves`, `None` if there are no leaves.
+pub fn generate_tree_root(leaves: &[Vec<u8>]) -> Option<H256> {
+    let mut nodes = hash_leaves(leaves);
+    while nodes.len() > 1 {
+        nodes = next_level(&nodes);
+    }
+
+    nodes.first().copied()
+}
+
+/// The sibling hashes from the leaf at `leaf_index` up to the root, `None` if there is no such
+/// leaf.
+pub fn generate_merkle_path(leaves: &[Vec<u8>], leaf_index: usize) -> Option<Vec<H256>> {
+    if leaf_index >= leaves.len() {
+        return None
+    }
+
+    let mut merkle_path = Vec::new();
+    let mut nodes = hash_leaves(leaves);
+    let mut index = leaf_index;
+    while nodes.len() > 1 {
+        // A node without a sibling is carried up, so it adds nothing to the path.
+        if let Some(sibling) = nodes.get(index ^ 1) {
+            merkle_path.push(*sibling);
+        }
+        nodes = next_level(&nodes);
+        index /= 2;
+    }
+
+    Some(merkle_path)
+}
+
+fn hash_leaves(leaves: &[Vec<u8>]) -> Vec<H256> {
+    leaves.iter().map(|leaf| H256(keccak_256(leaf))).collect()
+}
+
+fn next_level(nodes: &[H256]) -> Vec<H256> {
+    nodes
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_pair(left, right),
+            _ => pair[0],
+        })
+        .collect()
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+    let (first, second) = if left <= right { (left, right) } else { (right, left) };
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(first.as_bytes());
+    data[32..].copy_from_slice(second.as_bytes());
+    H256(keccak_256(&data))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex_literal::hex;
+
+    fn leaves(count: u8) -> Vec<Vec<u8>> {
+        (0..count).map(|n| vec![n]).collect()
+    }
+
+    fn root_from_path(leaf: &[u8], merkle_path: &[H256]) -> H256 {
+        merkle_path
+            .iter()
+            .fold(H256(keccak_256(leaf)), |node, sibling| hash_pair(&node, sibling))
+    }
+
+    #[test]
+    fn tree_root_matches_the_external_service() {
+        // Roots of the trees [0], [0, 1], [0, 1, 2]... as built by the external service.
+        let expected_roots = [
+            hex!("bc36789e7a1e281436464229828f817d6612f7b477d66591ff96a9e064bcc98a"),
+            hex!("b2521d64679bc4720dabfbae7ce17947a5d373d987d3b0cc1e3042ba2054da4a"),
+            hex!("d359d2743bb3a93ded4c902716931497ae3080f478c14e7af96344a92e9ddd51"),
+            hex!("fecce4ac8ed6fc57f4d880d6af2b443418d564df8f5d52c6782e952564ed79eb"),
+            hex!("11aeafa56c9b34805cc86b1c320c9331672c07e600f0a44317051cfa05a0c296"),
+        ];
+
+        for (index, expected_root) in expected_roots.iter().enumerate() {
+            assert_eq!(generate_tree_root(&leaves(index as u8 + 1)), Some(H256(*expected_root)));
+        }
+        assert_eq!(generate_tree_root(&[]), None);
+    }
+
+    #[test]
+    fn merkle_path_matches_the_external_service() {
+        assert_eq!(
+            generate_merkle_path(&leaves(5), 2),
+            Some(vec![
+                H256(hex!("69c322e3248a5dfc29d73c5b0553b0185a35cd5bb6386747517ef7e53b15e287")),
+                H256(hex!("b2521d64679bc4720dabfbae7ce17947a5d373d987d3b0cc1e3042ba2054da4a")),
+                H256(hex!("f343681465b9efe82c933c3e8748c70cb8aa06539c361de20f72eac04e766393")),
+            ])
+        );
+        assert_eq!(
+            generate_merkle_path(&leaves(5), 4),
+            Some(vec![H256(hex!(
+                "fecce4ac8ed6fc57f4d880d6af2b443418d564df8f5d52c6782e952564ed79eb"
+            ))])
+        );
+        assert_eq!(generate_merkle_path(&leaves(1), 0), Some(vec![]));
+        assert_eq!(generate_merkle_path(&leaves(5), 5), None);
+    }
+
+    #[test]
+    fn every_merkle_path_leads_to_the_root() {
+        for count in 1..=9 {
+            let leaves = leaves(count);
+            let root = generate_tree_root(&leaves).unwrap();
+            for (index, leaf) in leaves.iter().enumerate() {
+                let merkle_path = generate_merkle_path(&leaves, index).unwrap();
+                assert_eq!(root_from_path(leaf, &merkle_path), root);
+            }
+        }
+    }
+}
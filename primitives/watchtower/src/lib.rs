@@ -9,7 +9,7 @@ use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::{traits::Member, DispatchResult, Perbill, RuntimeDebug};
+use sp_runtime::{traits::Member, DispatchResult, Perbill, RuntimeDebug, Weight};
 
 pub type ProposalId = H256;
 
@@ -20,6 +20,10 @@ pub enum RawPayload {
 
     /// A link to off-chain proposal data (e.g. IPFS hash)
     Uri(Vec<u8>),
+
+    /// A link to off-chain proposal data, committing to the blake2-256 hash of its content.
+    /// Watchtower nodes fetch and verify the content before voting.
+    HashedUri { uri: Vec<u8>, content_hash: H256 },
 }
 
 #[derive(
@@ -56,6 +60,8 @@ pub enum ProposalType {
     Anchor,
     Governance,
     Other(u8),
+    /// App chain checkpoints anchored on AvN
+    Checkpoint,
 }
 
 #[derive(
@@ -92,6 +98,15 @@ pub enum ProposalStatusEnum {
 pub enum DecisionRule {
     /// Yes > No to win
     SimpleMajority,
+    /// `threshold` of the total voting weight must vote Yes, and at least `quorum` of it must
+    /// have voted. On expiry, Yes must reach `threshold` of the votes cast.
+    Supermajority { quorum: Perbill },
+    /// Same as `SimpleMajority` but each vote is weighted by the voter's stake, snapshotted when
+    /// voting starts. Votes are counted as for `SimpleMajority` if there are more voters than can
+    /// be snapshotted or none of them has stake.
+    StakeWeighted,
+    /// Passes unless No votes reach `veto_threshold` of the total voting weight.
+    Veto { veto_threshold: Perbill },
 }
 
 //implement default for ProposalStatusEnum to be Unknown
@@ -148,9 +163,35 @@ where
     }
 }
 
-pub trait WatchtowerHooks<P> {
+/// Validates the content of off-chain proposal payloads, once it has been fetched and matched
+/// against the hash committed in the proposal.
+pub trait ProposalPayloadValidator {
+    /// Returns whether `payload` is acceptable for a proposal of `proposal_type`, or `None` if the
+    /// type is not handled by this validator.
+    fn validate(proposal_type: &ProposalType, payload: &[u8]) -> Option<bool>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl ProposalPayloadValidator for Tuple {
+    fn validate(proposal_type: &ProposalType, payload: &[u8]) -> Option<bool> {
+        for_tuples!( #(
+            if let Some(valid) = Tuple::validate(proposal_type, payload) {
+                return Some(valid)
+            }
+        )* );
+        None
+    }
+}
+
+pub trait WatchtowerHooks<P, AccountId> {
     /// Called when Watchtower raises an alert/notification.
     fn on_proposal_submitted(proposal_id: ProposalId, proposal: P) -> DispatchResult;
+    /// Called when a vote on a proposal is recorded, before the proposal is finalised.
+    fn on_vote_submitted(_proposal_id: ProposalId, _voter: &AccountId, _in_favor: bool) {}
+    /// The maximum weight of `on_vote_submitted`, charged to every vote.
+    fn on_vote_submitted_weight() -> Weight {
+        Weight::zero()
+    }
     fn on_voting_completed(
         proposal_id: ProposalId,
         external_ref: &H256,
@@ -160,12 +201,22 @@ pub trait WatchtowerHooks<P> {
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(30)]
-impl<P: Clone> WatchtowerHooks<P> for Tuple {
+impl<P: Clone, AccountId> WatchtowerHooks<P, AccountId> for Tuple {
     fn on_proposal_submitted(proposal_id: ProposalId, proposal: P) -> DispatchResult {
         for_tuples!( #( Tuple::on_proposal_submitted(proposal_id, proposal.clone()); )* );
         Ok(())
     }
 
+    fn on_vote_submitted(proposal_id: ProposalId, voter: &AccountId, in_favor: bool) {
+        for_tuples!( #( Tuple::on_vote_submitted(proposal_id, voter, in_favor); )* );
+    }
+
+    fn on_vote_submitted_weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::on_vote_submitted_weight()); )* );
+        weight
+    }
+
     fn on_voting_completed(
         proposal_id: ProposalId,
         external_ref: &H256,
@@ -28,7 +28,8 @@ use polkadot_sdk::{
     sp_consensus_aura::{self, sr25519::AuthorityId as AuraId},
     sp_core::{crypto::KeyTypeId, ByteArray, OpaqueMetadata, H160},
     sp_runtime::{
-        traits::Block as BlockT,
+        generic::Preamble,
+        traits::{Block as BlockT, StaticLookup},
         transaction_validity::{TransactionSource, TransactionValidity},
         ApplyExtrinsicResult,
     },
@@ -38,14 +39,15 @@ use polkadot_sdk::{
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    SLOT_DURATION, VERSION,
+    AccountId, Balance, Block, BlockNumber, ConsensusHook, Executive, InherentDataExt, Nonce,
+    ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+    TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 use crate::{
-    AdditionalEvents, AuthorityDiscovery, AuthorityDiscoveryId, Avn, CrossChainVoting,
-    EthBlockRange, EthBridge, EthBridgeInstance, EthereumEventsPartition, InstanceId, Timestamp,
+    AdditionalEvents, AuthorityDiscovery, AuthorityDiscoveryId, Avn, AvnAnchor,
+    AvnTransactionPayment, CheckpointSummary, CrossChainVoting, EthBlockRange, EthBridge,
+    EthBridgeInstance, EthereumEventsPartition, InstanceId, ParachainStaking, Timestamp, DAYS,
     MAIN_ETH_BRIDGE_ID,
 };
 
@@ -222,6 +224,22 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_avn_transaction_payment_runtime_api::AvnTransactionPaymentApi<Block, Balance> for Runtime {
+        fn query_adjusted_fee(uxt: <Block as BlockT>::Extrinsic, len: u32) -> Balance {
+            let fee = TransactionPayment::query_info(uxt.clone(), len).partial_fee;
+            let sender = match &uxt.preamble {
+                Preamble::Signed(address, _, _) =>
+                    <Runtime as frame_system::Config>::Lookup::lookup(address.clone()).ok(),
+                _ => None,
+            };
+
+            match sender {
+                Some(who) => AvnTransactionPayment::preview_adjusted_fee(&who, &uxt.function, fee),
+                None => fee,
+            }
+        }
+    }
+
     impl pallet_eth_bridge_runtime_api::EthEventHandlerApi<Block, AccountId> for Runtime {
         fn query_authors() -> Vec<([u8; 32], [u8; 32])> {
             let validators = Avn::validators().to_vec();
@@ -287,6 +305,64 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_avn_anchor_runtime_api::AvnAnchorApi<Block, BlockNumber> for Runtime {
+        fn checkpoint_proof(
+            chain_id: pallet_avn_anchor::ChainId,
+            checkpoint_id: pallet_avn_anchor::CheckpointId,
+        ) -> Option<pallet_avn_anchor::CheckpointProof<BlockNumber>> {
+            let publication_block = AvnAnchor::publication_block(chain_id, checkpoint_id)?;
+            let (root_id, root) = CheckpointSummary::validated_root_covering(publication_block)?;
+            AvnAnchor::checkpoint_proof(
+                chain_id,
+                checkpoint_id,
+                pallet_avn_anchor::SummaryRoot {
+                    from_block: root_id.range.from_block,
+                    to_block: root_id.range.to_block,
+                    root_hash: root.root_hash,
+                    tx_id: root.tx_id,
+                    is_finalised: root.is_finalised,
+                },
+            )
+        }
+    }
+
+    impl pallet_parachain_staking_runtime_api::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+        fn current_era() -> pallet_parachain_staking::EraIndex {
+            ParachainStaking::era().current
+        }
+
+        fn candidate_era_info(
+            era: pallet_parachain_staking::EraIndex,
+        ) -> Vec<pallet_parachain_staking::staking_info::CandidateEraInfo<AccountId, Balance>> {
+            ParachainStaking::candidate_era_info(era)
+        }
+
+        fn era_points(era: pallet_parachain_staking::EraIndex) -> pallet_parachain_staking::RewardPoint {
+            ParachainStaking::points(era)
+        }
+
+        fn nominator_era_shares(
+            nominator: AccountId,
+            era: pallet_parachain_staking::EraIndex,
+        ) -> Vec<pallet_parachain_staking::staking_info::NominatorEraShare<AccountId, Balance>> {
+            ParachainStaking::nominator_era_shares(&nominator, era)
+        }
+
+        fn pending_payouts() -> Vec<pallet_parachain_staking::staking_info::PendingPayout<Balance>> {
+            ParachainStaking::pending_payouts()
+        }
+
+        fn nomination_unlocks(
+            nominator: AccountId,
+        ) -> Vec<pallet_parachain_staking::staking_info::NominationUnlock<AccountId, Balance>> {
+            ParachainStaking::nomination_unlocks(&nominator)
+        }
+
+        fn projected_apys() -> Vec<pallet_parachain_staking::staking_info::CandidateApy<AccountId>> {
+            ParachainStaking::projected_apys(DAYS * 365)
+        }
+    }
+
     impl pallet_cross_chain_voting_runtime_api::CrossChainVotingApi<Block> for Runtime {
         fn get_total_linked_balance(t1_identity_account: H160) -> Balance {
             CrossChainVoting::get_total_linked_balance(t1_identity_account)
@@ -37,6 +37,7 @@ polkadot_sdk::frame_benchmarking::define_benchmarks!(
     [pallet_utility, Utility]
     [pallet_parachain_staking, ParachainStaking]
     [pallet_avn_anchor, AvnAnchor]
+    [pallet_anchor_watchtower, AnchorWatchtower]
     [cumulus_pallet_parachain_system, ParachainSystem]
     [cumulus_pallet_xcmp_queue, XcmpQueue]
     [cumulus_pallet_weight_reclaim, WeightReclaim]
@@ -70,18 +70,19 @@ use crate::{
     asset_registry::AvnAssetProcessor,
     fungible,
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
-    AccountId, Amount, AsEnsureOriginWithArg, AssetManager, AssetRegistry, Aura, Avn, AvnAnchor,
-    AvnGasFeeAdapter, AvnId, AvnOffenceHandler, AvnProxyConfig, Balance, Balances, Block,
-    BlockNumber, ConsensusHook, Contains, CurrencyId, EnsureSigned, EthBridge, Hash, Historical,
-    HoldConsideration, ImOnlineId, Imbalance, LinearStoragePrice, MessageQueue, Moment, NftManager,
-    NodeManager, Nonce, Offences, OnUnbalanced, Ordering, OriginCaller, OrmlTokens, PalletInfo,
-    ParachainStaking, ParachainSystem, Preimage, PrivilegeCmp, ResolveTo, RestrictedEndpointFilter,
-    Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin,
-    RuntimeTask, Scheduler, Session, SessionKeys, Signature, StakingPotAccountId, Summary,
-    SummaryWatchtower, System, Timestamp, TokenManager, TransactionByteFee, UncheckedExtrinsic,
-    ValidatorsManager, Watchtower, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO,
-    EXISTENTIAL_DEPOSIT, FOREIGN_ASSET_DEFAULT_ED, HOURS, MAXIMUM_BLOCK_WEIGHT,
-    NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    AccountId, Amount, AnchorWatchtower, AsEnsureOriginWithArg, AssetManager, AssetRegistry, Aura,
+    Avn, AvnAnchor, AvnId, AvnOffenceHandler, AvnProxyConfig, Balance, Balances, Block,
+    BlockNumber, CheckpointSummary, ConsensusHook, Contains, CurrencyId, EnsureSigned, EthBridge,
+    Hash, Historical, HoldConsideration, ImOnlineId, Imbalance, LinearStoragePrice, MessageQueue,
+    MetaTransactionFilter, Moment, MultiAssetFeeAdapter, NftManager, NodeManager, Nonce, Offences,
+    OnUnbalanced, Ordering, OriginCaller, OrmlTokens, PalletInfo, ParachainStaking,
+    ParachainSystem, Preimage, PrivilegeCmp, ResolveTo, RestrictedEndpointFilter, Runtime,
+    RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
+    Scheduler, Session, SessionKeys, Signature, StakingPotAccountId, Summary, SummaryWatchtower,
+    System, Timestamp, TokenManager, TransactionByteFee, UncheckedExtrinsic, ValidatorsManager,
+    Watchtower, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO, DAYS, EXISTENTIAL_DEPOSIT,
+    FOREIGN_ASSET_DEFAULT_ED, HOURS, MAXIMUM_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, SLOT_DURATION,
+    VERSION,
 };
 
 use xcm_config::XcmOriginToTransactDispatchOrigin;
@@ -198,7 +199,7 @@ impl pallet_balances::Config for Runtime {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = AvnGasFeeAdapter<Balances, DealWithFees<Runtime>>;
+    type OnChargeTransaction = MultiAssetFeeAdapter<Balances, DealWithFees<Runtime>>;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
@@ -312,6 +313,7 @@ impl pallet_aura::Config for Runtime {
 parameter_types! {
     // The accountId that will hold the reward for the staking pallet
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
+    pub const MaxCollatorCommission: Perbill = Perbill::from_percent(20);
 }
 impl pallet_parachain_staking::Config for Runtime {
     type RuntimeCall = RuntimeCall;
@@ -343,6 +345,16 @@ impl pallet_parachain_staking::Config for Runtime {
     type AccountToBytesConvert = Avn;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = ConstBool<false>;
+    type MaxCommission = MaxCollatorCommission;
+    /// Eras before a commission increase is applied
+    type CommissionIncreaseDelay = ConstU32<2>;
+    type Slash = crate::governance::ToTreasury<Runtime>;
+    /// Eras before a reported slash is applied, giving governance time to cancel it
+    type SlashDeferDuration = ConstU32<7>;
+    /// Only collators are slashed until the offence fractions have been reviewed
+    type SlashNominators = ConstBool<false>;
+    type SlashCancelOrigin = EnsureRoot<AccountId>;
+    type MaxPoolUnbondingChunks = ConstU32<16>;
 }
 
 // Substrate pallets that AvN has dependency
@@ -414,7 +426,7 @@ impl pallet_utility::Config for Runtime {
 // AvN pallets
 impl pallet_avn_offence_handler::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type Enforcer = ();
+    type Enforcer = ParachainStaking;
     type WeightInfo = pallet_avn_offence_handler::default_weights::SubstrateWeight<Runtime>;
 }
 
@@ -484,6 +496,30 @@ impl pallet_summary::Config for Runtime {
     type InstanceId = EthereumInstanceId;
     type ExternalValidationEnabled = ExternalValidationEnabled;
     type ExternalValidator = Watchtower;
+    type LeafProvider = ();
+}
+
+parameter_types! {
+    pub const CheckpointSummaryInstanceId: u8 = 3u8;
+}
+
+/// Publishes avn-anchor checkpoints to Ethereum.
+pub type CheckpointSummaryInstance = pallet_summary::Instance1;
+impl pallet_summary::Config<CheckpointSummaryInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AdvanceSlotGracePeriod = AdvanceSlotGracePeriod;
+    type MinBlockAge = MinBlockAge;
+    type AccountToBytesConvert = Avn;
+    type ReportSummaryOffence = Offences;
+    type WeightInfo = pallet_summary::default_weights::SubstrateWeight<Runtime>;
+    type BridgeInterface = EthBridge;
+    type AutoSubmitSummaries = EthAutoSubmitSummaries;
+    type InstanceId = CheckpointSummaryInstanceId;
+    // Watchtowers verify a root by recomputing it from the summary leaves, which they cannot do
+    // for checkpoint roots
+    type ExternalValidationEnabled = ConstBool<false>;
+    type ExternalValidator = Watchtower;
+    type LeafProvider = AvnAnchor;
 }
 
 pub type EthAddress = H160;
@@ -519,9 +555,23 @@ impl pallet_nft_manager::Config for Runtime {
     type Public = <Signature as sp_runtime::traits::Verify>::Signer;
     type Signature = Signature;
     type BatchBound = pallet_nft_manager::BatchNftBound;
+    type ReleaseExternalRefOnBurn = ConstBool<false>;
+    type Token = EthAddress;
+    type TokenBalance = Balance;
+    type PaymentHandler = TokenManager;
+    type EscrowPalletId = NftEscrowPalletId;
+    type FiatSettlementOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_nft_manager::default_weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const NftEscrowPalletId: PalletId = PalletId(*b"nft/escr");
+}
+
+parameter_types! {
+    pub const SponsorshipPeriod: BlockNumber = DAYS;
+}
+
 impl pallet_avn_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -532,6 +582,10 @@ impl pallet_avn_proxy::Config for Runtime {
     type WeightInfo = pallet_avn_proxy::default_weights::SubstrateWeight<Runtime>;
     type PaymentHandler = TokenManager;
     type Token = EthAddress;
+    type MaxSponsoredCallTypes = ConstU32<32>;
+    type MaxSponsoredBeneficiaries = ConstU32<100>;
+    type SponsorshipPeriod = SponsorshipPeriod;
+    type MetaTransactionFilter = MetaTransactionFilter;
 }
 
 impl pallet_avn_transaction_payment::Config for Runtime {
@@ -540,6 +594,17 @@ impl pallet_avn_transaction_payment::Config for Runtime {
     type Currency = Balances;
     type KnownUserOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_avn_transaction_payment::default_weights::SubstrateWeight<Runtime>;
+    type AssetManager = AssetManager;
+    type AssetRegistryStringLimit = AssetRegistryStringLimit;
+    type AssetRegistry = AssetRegistry;
+    type FeeAssetRateOrigin = EnsureRoot<AccountId>;
+    type AssetFeeRecipient = StakingPotAccountId<Runtime>;
+    type MaxVolumeTiers = ConstU32<10>;
+    type VolumeTierPeriod = VolumeTierPeriod;
+}
+
+parameter_types! {
+    pub const VolumeTierPeriod: BlockNumber = 30 * DAYS;
 }
 
 parameter_types! {
@@ -547,6 +612,8 @@ parameter_types! {
     pub const AvnAnchorRewardPotId: PalletId = NODE_MANAGER_PALLET_ID;
     pub AvnAnchorRewardPot: AccountId = AvnAnchorRewardPotId::get().into_account_truncating();
     pub const MaxPeriodsPerPayout: u32 = 10;
+    pub const MaxCheckpointsPerBatch: u32 = 64;
+    pub const PublishedCheckpointRetention: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_avn_anchor::Config for Runtime {
@@ -559,14 +626,18 @@ impl pallet_avn_anchor::Config for Runtime {
     type Signature = Signature;
     type Token = EthAddress;
     type DefaultCheckpointFee = DefaultCheckpointFee;
+    type ChallengeBond = CheckpointChallengeBond;
     type AppChainAssetId = CurrencyId;
     type MaxRegisteredAppChains = MaxRegisteredAppChains;
     type AssetRegistryStringLimit = AssetRegistryStringLimit;
     type AssetRegistry = AssetRegistry;
     type RewardPot = AvnAnchorRewardPot;
     type MaxPeriodsPerPayout = MaxPeriodsPerPayout;
+    type MaxCheckpointsPerBatch = MaxCheckpointsPerBatch;
+    type PublishedCheckpointRetention = PublishedCheckpointRetention;
     // replace `()` with a runtime type implementing app-chain/node eligibility logic when ready.
     type AppChainRewardEligibility = ();
+    type CheckpointHooks = AnchorWatchtower;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -591,8 +662,14 @@ impl pallet_eth_bridge::Config for Runtime {
     type TimeProvider = Timestamp;
     type ReportCorroborationOffence = Offences;
     type WeightInfo = pallet_eth_bridge::default_weights::SubstrateWeight<Runtime>;
-    type BridgeInterfaceNotification =
-        (NodeManager, ParachainStaking, Summary, TokenManager, ValidatorsManager);
+    type BridgeInterfaceNotification = (
+        NodeManager,
+        ParachainStaking,
+        Summary,
+        CheckpointSummary,
+        TokenManager,
+        ValidatorsManager,
+    );
     type ProcessedEventsHandler = CorePrimaryEventsFilter;
     type EthereumEventsMigration = ();
     type Quorum = Avn;
@@ -613,6 +690,7 @@ parameter_types! {
     pub const NodeManagerPalletId: PalletId = NODE_MANAGER_PALLET_ID;
     pub const VirtualNodeStake: Balance = 10000 * AVT;
     pub const BonusNodeSerialStart: u32 = 1_000_000;
+    pub const MaxNodeCommission: Perbill = Perbill::from_percent(20);
 }
 
 impl pallet_node_manager::Config for Runtime {
@@ -633,6 +711,10 @@ impl pallet_node_manager::Config for Runtime {
     type ProcessedEventsChecker = EthBridge;
     type AppChainInterface = AvnAnchor;
     type BonusNodeSerialStart = BonusNodeSerialStart;
+    type MaxNodeCommission = MaxNodeCommission;
+    /// Reward periods before a commission increase is applied
+    type NodeCommissionIncreaseDelay = ConstU64<2>;
+    type WorkVerifier = WatchtowerWorkVerifier;
 }
 
 // Other pallets
@@ -643,6 +725,7 @@ parameter_types! {
     pub const MetadataDepositBase: Balance = 1 * MILLI_AVT;
     pub const MetadataDepositPerByte: Balance = 100 * MICRO_AVT;
     pub const DefaultCheckpointFee: Balance = 60 * MILLI_AVT;
+    pub const CheckpointChallengeBond: Balance = 10 * AVT;
 }
 const ASSET_ACCOUNT_DEPOSIT: Balance = 100 * MICRO_AVT;
 
@@ -763,7 +846,9 @@ impl pallet_watchtower::Config for Runtime {
     type Watchtowers = RuntimeNodeManager;
     type SignerId = NodeManagerKeyId;
     type ExternalProposerOrigin = EnsureExternalProposerOrRoot;
-    type WatchtowerHooks = (Summary, SummaryWatchtower);
+    type WatchtowerHooks =
+        (Summary, CheckpointSummary, SummaryWatchtower, AnchorWatchtower, NodeManager);
+    type PayloadValidator = pallet_anchor_watchtower::CheckpointPayloadValidator<Runtime>;
     type MaxTitleLen = ConstU32<512>;
     type MaxInlineLen = ConstU32<8192>;
     type MaxUriLen = ConstU32<2040>;
@@ -771,6 +856,8 @@ impl pallet_watchtower::Config for Runtime {
     type Signature = Signature;
     type SignedTxLifetime = ConstU32<64>;
     type MaxInternalProposalLen = ConstU32<4096>;
+    type MaxWeightedVoters = ConstU32<1000>;
+    type MaxActiveExternalProposals = ConstU32<64>;
 }
 
 impl pallet_summary_watchtower::Config for Runtime {
@@ -779,6 +866,34 @@ impl pallet_summary_watchtower::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const AnchorVerificationThreshold: Perbill = Perbill::from_percent(51);
+}
+
+impl pallet_anchor_watchtower::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type Checkpoints = AvnAnchor;
+    type MaxEndpointLen = ConstU32<256>;
+    type VerificationThreshold = AnchorVerificationThreshold;
+    type MaxPendingProposals = ConstU32<4>;
+    type MaxQueuedCheckpoints = ConstU32<64>;
+    type WeightInfo = pallet_anchor_watchtower::default_weights::SubstrateWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_anchor_watchtower::benchmarking::BenchmarkHelper<Runtime> for Runtime {
+    fn setup_chain_handler(handler: &AccountId) -> pallet_avn_anchor::ChainId {
+        let chain_id = pallet_avn_anchor::NextChainId::<Runtime>::mutate(|id| {
+            let chain_id = *id;
+            *id = id.saturating_add(1);
+            chain_id
+        });
+        pallet_avn_anchor::ChainHandlers::<Runtime>::insert(handler, chain_id);
+        chain_id
+    }
+}
+
 parameter_types! {
     pub AvnTreasuryAccount: AccountId = AvnTreasuryPotId::get().into_account_truncating();
 }
@@ -898,6 +1013,10 @@ impl pallet_watchtower::NodesInterface<AccountId, NodeManagerKeyId> for RuntimeN
             .map(|node_info| node_info.signing_key)
     }
 
+    fn get_node_owner(node: &AccountId) -> Option<AccountId> {
+        pallet_node_manager::NodeRegistry::<Runtime>::get(node).map(|node_info| node_info.owner)
+    }
+
     fn get_node_from_local_signing_keys() -> Option<(AccountId, NodeManagerKeyId)> {
         pallet_node_manager::Pallet::<Runtime>::get_node_from_signing_key()
     }
@@ -906,6 +1025,25 @@ impl pallet_watchtower::NodesInterface<AccountId, NodeManagerKeyId> for RuntimeN
         pallet_node_manager::OwnedNodesCount::<Runtime>::get(owner)
     }
 
+    fn get_watchtower_stakes(
+        source: &pallet_watchtower::ProposalSource,
+    ) -> alloc::vec::Vec<(AccountId, u128)> {
+        // Read one more than allowed so the watchtower pallet can detect oversized snapshots
+        let limit = <Runtime as pallet_watchtower::Config>::MaxWeightedVoters::get() as usize + 1;
+        match source {
+            pallet_watchtower::ProposalSource::Internal(_) =>
+                pallet_node_manager::NodeRegistry::<Runtime>::iter()
+                    .take(limit)
+                    .map(|(node, info)| {
+                        let delegated = NodeManager::delegated_stake(&node);
+                        (node, info.stake.amount.saturating_add(delegated))
+                    })
+                    .collect(),
+            pallet_watchtower::ProposalSource::External =>
+                pallet_node_manager::TotalStake::<Runtime>::iter().take(limit).collect(),
+        }
+    }
+
     fn get_authorized_watchtowers_count() -> u32 {
         #[cfg(feature = "runtime-benchmarks")]
         {
@@ -917,9 +1055,63 @@ impl pallet_watchtower::NodesInterface<AccountId, NodeManagerKeyId> for RuntimeN
     }
 }
 
+// Blocks after the end of its voting period a watchtower proposal can still be claimed as work
+const WATCHTOWER_WORK_MAX_AGE: BlockNumber = HOURS;
+
+/// Accepts votes on the active internal watchtower proposal, or on an internal proposal whose
+/// voting ended within `WATCHTOWER_WORK_MAX_AGE` blocks.
+pub struct WatchtowerWorkVerifier;
+impl pallet_node_manager::types::NodeWorkVerifier<AccountId> for WatchtowerWorkVerifier {
+    fn work_evidence(node: &AccountId) -> Option<pallet_node_manager::types::WorkEvidence> {
+        let proposal_id = pallet_watchtower::ActiveInternalProposal::<Runtime>::get()?;
+        pallet_watchtower::Voters::<Runtime>::contains_key(proposal_id, node)
+            .then_some(pallet_node_manager::types::WorkEvidence::WatchtowerVote { proposal_id })
+    }
+
+    fn verify_work(node: &AccountId, evidence: &pallet_node_manager::types::WorkEvidence) -> bool {
+        match evidence {
+            pallet_node_manager::types::WorkEvidence::WatchtowerVote { proposal_id } => {
+                if !pallet_watchtower::Voters::<Runtime>::contains_key(proposal_id, node) {
+                    return false
+                }
+
+                if pallet_watchtower::ActiveInternalProposal::<Runtime>::get() == Some(*proposal_id)
+                {
+                    return true
+                }
+
+                let Some(proposal) = pallet_watchtower::Proposals::<Runtime>::get(proposal_id)
+                else {
+                    return false
+                };
+                let finalised = matches!(
+                    pallet_watchtower::ProposalStatus::<Runtime>::get(proposal_id),
+                    pallet_watchtower::ProposalStatusEnum::Resolved { .. } |
+                        pallet_watchtower::ProposalStatusEnum::Expired
+                );
+                let recent = proposal.end_at.map_or(false, |end_at| {
+                    System::block_number() <= end_at.saturating_add(WATCHTOWER_WORK_MAX_AGE)
+                });
+
+                matches!(proposal.source, pallet_watchtower::ProposalSource::Internal(_)) &&
+                    finalised &&
+                    recent
+            },
+        }
+    }
+
+    fn verify_work_weight() -> Weight {
+        RocksDbWeight::get().reads(4)
+    }
+}
+
 // Accounts protected from being deleted due to a too low amount of funds.
-const IMMORTAL_ACCOUNTS: &[PalletId] =
-    &[AvnTreasuryPotId::get(), NodeManagerPalletId::get(), RewardPotId::get()];
+const IMMORTAL_ACCOUNTS: &[PalletId] = &[
+    AvnTreasuryPotId::get(),
+    NodeManagerPalletId::get(),
+    RewardPotId::get(),
+    NftEscrowPalletId::get(),
+];
 pub struct DustRemovalWhitelist;
 
 impl Contains<AccountId> for DustRemovalWhitelist
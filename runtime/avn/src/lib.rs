@@ -50,7 +50,7 @@ use polkadot_sdk::frame_support::{
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 pub use polkadot_sdk::sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use proxy_config::AvnProxyConfig;
+use proxy_config::{AvnProxyConfig, MetaTransactionFilter};
 
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_session::historical::{self as pallet_session_historical};
@@ -59,7 +59,7 @@ use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use pallet_avn::sr25519::AuthorityId as AvnId;
 
 pub use pallet_avn_proxy::{Event as AvnProxyEvent, ProvableProxy};
-use pallet_avn_transaction_payment::AvnGasFeeAdapter;
+use pallet_avn_transaction_payment::MultiAssetFeeAdapter;
 use pallet_eth_bridge_runtime_api::InstanceId;
 use pallet_parachain_staking::{self, StakingPotAccountId};
 use sp_avn_common::{
@@ -104,6 +104,7 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
         frame_system::CheckEra<Runtime>,
         frame_system::CheckNonce<Runtime>,
         frame_system::CheckWeight<Runtime>,
+        pallet_avn_transaction_payment::ChargeFeeAsset<Runtime>,
         pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
         frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
     ),
@@ -159,7 +160,12 @@ pub type Executive = frame_executive::Executive<
     AllPalletsWithSystem,
     (
         migrations::register_avt_token::RegisterAvtToken<Runtime>,
+        migrations::start_checkpoint_summary::StartCheckpointSummary<Runtime>,
         pallet_eth_bridge::migration::EthBridgeMigrations<Runtime>,
+        pallet_nft_manager::migration::NftManagerMigrations<Runtime>,
+        pallet_parachain_staking::migration::ParachainStakingMigrations<Runtime>,
+        pallet_node_manager::migration::NodeManagerMigrations<Runtime>,
+        pallet_watchtower::migration::WatchtowerMigrations<Runtime>,
         pallet_session::migrations::v1::MigrateV0ToV1<
             Runtime,
             pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
@@ -188,7 +194,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_version: 230,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -443,6 +449,12 @@ mod runtime {
     #[runtime::pallet_index(103)]
     pub type CrossChainVoting = pallet_cross_chain_voting;
 
+    #[runtime::pallet_index(104)]
+    pub type AnchorWatchtower = pallet_anchor_watchtower;
+
+    #[runtime::pallet_index(105)]
+    pub type CheckpointSummary = pallet_summary<Instance1>;
+
     // ORML pallets
     #[runtime::pallet_index(110)]
     pub type OrmlTokens = orml_tokens;
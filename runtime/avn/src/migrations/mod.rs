@@ -15,3 +15,4 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod register_avt_token;
+pub mod start_checkpoint_summary;
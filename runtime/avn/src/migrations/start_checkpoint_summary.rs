@@ -0,0 +1,168 @@
+// This file is part of Aventus.
+// Copyright 2026 Aventus DAO Ltd
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Migration to start the checkpoint summary from the current block.
+//!
+//! A new summary instance would otherwise summarise every block since genesis, one schedule period
+//! at a time, before it reaches the checkpoints submitted after the upgrade. Checkpoints are only
+//! queued for publication from this upgrade onwards, so the earlier blocks have no leaves. The
+//! instance has no genesis on a live chain, so the migration also sets the schedule and voting
+//! periods, the external validation threshold, the first slot and its validator, and the storage
+//! version so the pallet's own initialisation does not overwrite them. The migration is idempotent:
+//! it is a no-op once the instance has started processing blocks.
+
+use polkadot_sdk::{
+    frame_support::{
+        pallet_prelude::PhantomData,
+        traits::{GetStorageVersion, OnRuntimeUpgrade},
+        weights::Weight,
+    },
+    frame_system,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+use crate::{configs::CheckpointSummaryInstance, BlockNumber, Runtime};
+
+type CheckpointSummary = pallet_summary::Pallet<Runtime, CheckpointSummaryInstance>;
+
+/// Used if the Ethereum summary has no external validation threshold to copy
+const DEFAULT_EXTERNAL_VALIDATION_THRESHOLD: u32 = 51;
+
+pub struct StartCheckpointSummary<T>(PhantomData<T>);
+
+impl OnRuntimeUpgrade for StartCheckpointSummary<Runtime> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+        if pallet_summary::NextBlockToProcess::<Runtime, CheckpointSummaryInstance>::get() != 0 {
+            log::info!("⏭️  StartCheckpointSummary migration: summary already started, skipping.");
+            return weight
+        }
+
+        let current_block = frame_system::Pallet::<Runtime>::block_number();
+        let schedule_period: BlockNumber = pallet_summary::DEFAULT_SCHEDULE_PERIOD;
+        let voting_period: BlockNumber = pallet_summary::DEFAULT_VOTING_PERIOD;
+
+        pallet_summary::NextBlockToProcess::<Runtime, CheckpointSummaryInstance>::put(
+            current_block,
+        );
+        pallet_summary::SchedulePeriod::<Runtime, CheckpointSummaryInstance>::put(schedule_period);
+        pallet_summary::VotingPeriod::<Runtime, CheckpointSummaryInstance>::put(voting_period);
+        pallet_summary::NextSlotAtBlock::<Runtime, CheckpointSummaryInstance>::put(
+            current_block.saturating_add(schedule_period),
+        );
+        weight += <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 4);
+
+        let threshold = pallet_summary::ExternalValidationThreshold::<Runtime>::get()
+            .unwrap_or(DEFAULT_EXTERNAL_VALIDATION_THRESHOLD);
+        pallet_summary::ExternalValidationThreshold::<Runtime, CheckpointSummaryInstance>::put(
+            threshold,
+        );
+        weight += <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+
+        match pallet_avn::Validators::<Runtime>::get().first() {
+            Some(validator) => pallet_summary::CurrentSlotsValidator::<
+                Runtime,
+                CheckpointSummaryInstance,
+            >::put(validator.account_id.clone()),
+            None => log::error!(
+                "❌ StartCheckpointSummary migration: no validators to assign the first slot to."
+            ),
+        }
+        weight += <Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+
+        CheckpointSummary::in_code_storage_version().put::<CheckpointSummary>();
+        weight += <Runtime as frame_system::Config>::DbWeight::get().writes(1);
+
+        log::info!(
+            "✅ StartCheckpointSummary migration: checkpoint summary starts at block {:?}",
+            current_block,
+        );
+
+        weight
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, TryRuntimeError> {
+        use codec::Encode;
+
+        let started =
+            pallet_summary::NextBlockToProcess::<Runtime, CheckpointSummaryInstance>::get() != 0;
+        log::info!("🔍 StartCheckpointSummary pre_upgrade: already_started={}", started);
+
+        Ok(started.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), TryRuntimeError> {
+        use codec::Decode;
+
+        let started: bool = Decode::decode(&mut state.as_slice()).map_err(|_| {
+            TryRuntimeError::Other("StartCheckpointSummary: failed to decode pre-upgrade state")
+        })?;
+        if started {
+            return Ok(())
+        }
+
+        let current_block = frame_system::Pallet::<Runtime>::block_number();
+        if pallet_summary::NextBlockToProcess::<Runtime, CheckpointSummaryInstance>::get() !=
+            current_block
+        {
+            return Err(TryRuntimeError::Other(
+                "StartCheckpointSummary: summary does not start at the upgrade block",
+            ))
+        }
+
+        if pallet_summary::SchedulePeriod::<Runtime, CheckpointSummaryInstance>::get() !=
+            pallet_summary::DEFAULT_SCHEDULE_PERIOD ||
+            pallet_summary::VotingPeriod::<Runtime, CheckpointSummaryInstance>::get() !=
+                pallet_summary::DEFAULT_VOTING_PERIOD
+        {
+            return Err(TryRuntimeError::Other(
+                "StartCheckpointSummary: schedule or voting period not set to the defaults",
+            ))
+        }
+
+        if pallet_summary::ExternalValidationThreshold::<Runtime, CheckpointSummaryInstance>::get()
+            .is_none()
+        {
+            return Err(TryRuntimeError::Other(
+                "StartCheckpointSummary: external validation threshold not set",
+            ))
+        }
+
+        if pallet_summary::NextSlotAtBlock::<Runtime, CheckpointSummaryInstance>::get() <=
+            current_block
+        {
+            return Err(TryRuntimeError::Other(
+                "StartCheckpointSummary: first slot does not start after the upgrade block",
+            ))
+        }
+
+        if CheckpointSummary::on_chain_storage_version() !=
+            CheckpointSummary::in_code_storage_version()
+        {
+            return Err(TryRuntimeError::Other(
+                "StartCheckpointSummary: storage version not set, the pallet would re-initialise",
+            ))
+        }
+
+        log::info!("✅ StartCheckpointSummary post_upgrade: checkpoint summary correctly started.");
+        Ok(())
+    }
+}
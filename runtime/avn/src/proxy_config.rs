@@ -1,6 +1,6 @@
 use super::{
-    AccountId, Box, Decode, Encode, InnerCallValidator, Proof, ProvableProxy, Runtime, RuntimeCall,
-    RuntimeDebug, Signature, TypeInfo,
+    AccountId, Box, Contains, Decode, Encode, InnerCallValidator, Proof, ProvableProxy, Runtime,
+    RuntimeCall, RuntimeDebug, Signature, TypeInfo,
 };
 use codec::DecodeWithMemTracking;
 
@@ -67,6 +67,11 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                 nft_id: _,
                 t2_transfer_to_public_key: _,
             }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(
+                pallet_nft_manager::Call::signed_transfer_fiat_nft_with_settlement {
+                    proof, ..
+                },
+            ) => return Some(proof.clone()),
             RuntimeCall::NftManager(pallet_nft_manager::Call::signed_cancel_list_fiat_nft {
                 proof,
                 nft_id: _,
@@ -93,6 +98,34 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                 proof,
                 batch_id: _,
             }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_lock_nft {
+                proof,
+                nft_id: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_unlock_nft {
+                proof,
+                nft_id: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_burn_nft {
+                proof,
+                nft_id: _,
+            }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_set_attribute {
+                proof,
+                ..
+            }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_clear_attribute {
+                proof,
+                ..
+            }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_set_batch_allowlist {
+                proof,
+                ..
+            }) => return Some(proof.clone()),
+            RuntimeCall::NftManager(pallet_nft_manager::Call::signed_mint_from_allowlist {
+                proof,
+                ..
+            }) => return Some(proof.clone()),
             RuntimeCall::ParachainStaking(
                 pallet_parachain_staking::Call::signed_candidate_bond_extra {
                     proof,
@@ -141,6 +174,15 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
                     nominator: _,
                 },
             ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_set_auto_compound { proof, .. },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_candidate_rebond { proof, .. },
+            ) => return Some(proof.clone()),
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::signed_nominator_rebond { proof, .. },
+            ) => return Some(proof.clone()),
             RuntimeCall::AvnAnchor(pallet_avn_anchor::Call::signed_register_chain_handler {
                 proof,
                 ..
@@ -152,6 +194,14 @@ impl ProvableProxy<RuntimeCall, Signature, AccountId> for AvnProxyConfig {
             RuntimeCall::AvnAnchor(
                 pallet_avn_anchor::Call::signed_submit_checkpoint_with_identity { proof, .. },
             ) => return Some(proof.clone()),
+            RuntimeCall::AvnAnchor(pallet_avn_anchor::Call::signed_submit_linked_checkpoint {
+                proof,
+                ..
+            }) => return Some(proof.clone()),
+            RuntimeCall::AvnAnchor(pallet_avn_anchor::Call::signed_submit_checkpoints {
+                proof,
+                ..
+            }) => return Some(proof.clone()),
             RuntimeCall::NodeManager(pallet_node_manager::Call::signed_register_node {
                 proof,
                 node: _,
@@ -204,3 +254,44 @@ impl InnerCallValidator for AvnProxyConfig {
         }
     }
 }
+
+/// The calls that can be relayed as meta transactions by `pallet_avn_proxy`
+pub struct MetaTransactionFilter;
+impl Contains<RuntimeCall> for MetaTransactionFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::nominate { .. } |
+                    pallet_parachain_staking::Call::bond_extra { .. } |
+                    pallet_parachain_staking::Call::schedule_nominator_unbond { .. } |
+                    pallet_parachain_staking::Call::schedule_revoke_nomination { .. } |
+                    pallet_parachain_staking::Call::execute_nomination_request { .. } |
+                    pallet_parachain_staking::Call::cancel_nomination_request { .. } |
+                    pallet_parachain_staking::Call::schedule_leave_nominators { .. } |
+                    pallet_parachain_staking::Call::execute_leave_nominators { .. } |
+                    pallet_parachain_staking::Call::cancel_leave_nominators { .. } |
+                    pallet_parachain_staking::Call::candidate_bond_extra { .. } |
+                    pallet_parachain_staking::Call::schedule_candidate_unbond { .. } |
+                    pallet_parachain_staking::Call::execute_candidate_unbond { .. } |
+                    pallet_parachain_staking::Call::cancel_candidate_unbond { .. } |
+                    pallet_parachain_staking::Call::set_auto_compound { .. } |
+                    pallet_parachain_staking::Call::go_offline { .. } |
+                    pallet_parachain_staking::Call::go_online { .. }
+            ) | RuntimeCall::AvnAnchor(
+                pallet_avn_anchor::Call::register_chain_handler { .. } |
+                    pallet_avn_anchor::Call::update_chain_handler { .. } |
+                    pallet_avn_anchor::Call::submit_checkpoint_with_identity { .. } |
+                    pallet_avn_anchor::Call::submit_linked_checkpoint { .. } |
+                    pallet_avn_anchor::Call::submit_checkpoints { .. } |
+                    pallet_avn_anchor::Call::claim { .. }
+            ) | RuntimeCall::NodeManager(
+                pallet_node_manager::Call::update_signing_key { .. } |
+                    pallet_node_manager::Call::add_stake { .. } |
+                    pallet_node_manager::Call::remove_stake { .. } |
+                    pallet_node_manager::Call::update_auto_stake_preference { .. } |
+                    pallet_node_manager::Call::deregister_nodes { .. }
+            )
+        )
+    }
+}
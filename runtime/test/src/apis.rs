@@ -38,15 +38,16 @@ use polkadot_sdk::{
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-    SLOT_DURATION, VERSION,
+    AccountId, Balance, Block, BlockNumber, ConsensusHook, Executive, InherentDataExt, Nonce,
+    ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+    TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 use crate::{
-    AdditionalEvents, AuthorityDiscovery, AuthorityDiscoveryId, Avn, CrossChainVoting,
-    EthBlockRange, EthBridge, EthBridgeInstance, EthSecondBridge, EthereumEventsPartition,
-    InstanceId, Timestamp, MAIN_ETH_BRIDGE_ID, SECONDARY_ETH_BRIDGE_ID,
+    AdditionalEvents, AuthorityDiscovery, AuthorityDiscoveryId, Avn, AvnAnchor, CheckpointSummary,
+    CrossChainVoting, EthBlockRange, EthBridge, EthBridgeInstance, EthSecondBridge,
+    EthereumEventsPartition, InstanceId, ParachainStaking, Timestamp, DAYS, MAIN_ETH_BRIDGE_ID,
+    SECONDARY_ETH_BRIDGE_ID,
 };
 
 use codec::Encode;
@@ -348,6 +349,64 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_avn_anchor_runtime_api::AvnAnchorApi<Block, BlockNumber> for Runtime {
+        fn checkpoint_proof(
+            chain_id: pallet_avn_anchor::ChainId,
+            checkpoint_id: pallet_avn_anchor::CheckpointId,
+        ) -> Option<pallet_avn_anchor::CheckpointProof<BlockNumber>> {
+            let publication_block = AvnAnchor::publication_block(chain_id, checkpoint_id)?;
+            let (root_id, root) = CheckpointSummary::validated_root_covering(publication_block)?;
+            AvnAnchor::checkpoint_proof(
+                chain_id,
+                checkpoint_id,
+                pallet_avn_anchor::SummaryRoot {
+                    from_block: root_id.range.from_block,
+                    to_block: root_id.range.to_block,
+                    root_hash: root.root_hash,
+                    tx_id: root.tx_id,
+                    is_finalised: root.is_finalised,
+                },
+            )
+        }
+    }
+
+    impl pallet_parachain_staking_runtime_api::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+        fn current_era() -> pallet_parachain_staking::EraIndex {
+            ParachainStaking::era().current
+        }
+
+        fn candidate_era_info(
+            era: pallet_parachain_staking::EraIndex,
+        ) -> Vec<pallet_parachain_staking::staking_info::CandidateEraInfo<AccountId, Balance>> {
+            ParachainStaking::candidate_era_info(era)
+        }
+
+        fn era_points(era: pallet_parachain_staking::EraIndex) -> pallet_parachain_staking::RewardPoint {
+            ParachainStaking::points(era)
+        }
+
+        fn nominator_era_shares(
+            nominator: AccountId,
+            era: pallet_parachain_staking::EraIndex,
+        ) -> Vec<pallet_parachain_staking::staking_info::NominatorEraShare<AccountId, Balance>> {
+            ParachainStaking::nominator_era_shares(&nominator, era)
+        }
+
+        fn pending_payouts() -> Vec<pallet_parachain_staking::staking_info::PendingPayout<Balance>> {
+            ParachainStaking::pending_payouts()
+        }
+
+        fn nomination_unlocks(
+            nominator: AccountId,
+        ) -> Vec<pallet_parachain_staking::staking_info::NominationUnlock<AccountId, Balance>> {
+            ParachainStaking::nomination_unlocks(&nominator)
+        }
+
+        fn projected_apys() -> Vec<pallet_parachain_staking::staking_info::CandidateApy<AccountId>> {
+            ParachainStaking::projected_apys(DAYS * 365)
+        }
+    }
+
     impl pallet_cross_chain_voting_runtime_api::CrossChainVotingApi<Block> for Runtime {
         fn get_total_linked_balance(t1_identity_account: H160) -> Balance {
             CrossChainVoting::get_total_linked_balance(t1_identity_account)
@@ -29,7 +29,10 @@ use polkadot_sdk::{
         derive_impl,
         dispatch::DispatchClass,
         parameter_types,
-        traits::{ConstBool, ConstU32, ConstU64, TransformOrigin, VariantCountOf},
+        traits::{
+            ConstBool, ConstU32, ConstU64, Currency, OnUnbalanced, TransformOrigin,
+            VariantCountOf,
+        },
         weights::{ConstantMultiplier, Weight},
         PalletId,
     },
@@ -69,16 +72,17 @@ use orml_traits::{
 use crate::{
     asset_registry::AvnAssetProcessor,
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
-    AccountId, Amount, AsEnsureOriginWithArg, AssetManager, AssetRegistry, Aura, Avn, AvnId,
-    AvnOffenceHandler, AvnProxyConfig, Balance, Balances, Block, BlockNumber, ConsensusHook,
-    Contains, CurrencyId, EnsureSigned, EthBridge, EthSecondBridge, Hash, Historical,
-    HoldConsideration, ImOnlineId, LinearStoragePrice, MainEthBridge, MessageQueue, Moment,
-    NftManager, Nonce, Offences, Ordering, OriginCaller, OrmlTokens, PalletInfo, ParachainStaking,
+    AccountId, Amount, AsEnsureOriginWithArg, AssetManager, AssetRegistry, Aura, Avn, AvnAnchor,
+    AvnId, AvnOffenceHandler, AvnProxyConfig, Balance, Balances, Block, BlockNumber,
+    CheckpointSummary, ConsensusHook, Contains, CurrencyId, EnsureSigned, EthBridge,
+    EthSecondBridge, Hash, Historical, HoldConsideration, ImOnlineId, LinearStoragePrice,
+    MainEthBridge, MessageQueue, MetaTransactionFilter, Moment, NegativeImbalance, NftManager,
+    Nonce, Offences, Ordering, OriginCaller, OrmlTokens, PalletInfo, ParachainStaking,
     ParachainSystem, Preimage, PrivilegeCmp, RestrictedEndpointFilter, Runtime, RuntimeCall,
     RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Scheduler,
     SecondaryEthBridge, Session, SessionKeys, Signature, Summary, System, Timestamp, TokenManager,
     TransactionByteFee, UncheckedExtrinsic, ValidatorsManager, WeightToFee, XcmpQueue,
-    AVERAGE_ON_INITIALIZE_RATIO, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT,
+    AVERAGE_ON_INITIALIZE_RATIO, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT,
     NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
 };
 
@@ -304,6 +308,7 @@ impl pallet_aura::Config for Runtime {
 parameter_types! {
     // The accountId that will hold the reward for the staking pallet
     pub const RewardPotId: PalletId = PalletId(*b"av/vamgr");
+    pub const MaxCollatorCommission: Perbill = Perbill::from_percent(20);
 }
 impl pallet_parachain_staking::Config for Runtime {
     type RuntimeCall = RuntimeCall;
@@ -335,6 +340,24 @@ impl pallet_parachain_staking::Config for Runtime {
     type AccountToBytesConvert = Avn;
     type BridgeInterface = EthBridge;
     type GrowthEnabled = ConstBool<false>;
+    type MaxCommission = MaxCollatorCommission;
+    /// Eras before a commission increase is applied
+    type CommissionIncreaseDelay = ConstU32<2>;
+    type Slash = SlashToTreasury;
+    /// Eras before a reported slash is applied, giving governance time to cancel it
+    type SlashDeferDuration = ConstU32<7>;
+    /// Only collators are slashed until the offence fractions have been reviewed
+    type SlashNominators = ConstBool<false>;
+    type SlashCancelOrigin = EnsureRoot<AccountId>;
+    type MaxPoolUnbondingChunks = ConstU32<16>;
+}
+
+/// Sends the funds slashed by parachain staking to the AvN treasury
+pub struct SlashToTreasury;
+impl OnUnbalanced<NegativeImbalance<Runtime>> for SlashToTreasury {
+    fn on_nonzero_unbalanced(amount: NegativeImbalance<Runtime>) {
+        Balances::resolve_creating(&TokenManager::compute_treasury_account_id(), amount);
+    }
 }
 
 // Substrate pallets that AvN has dependency
@@ -406,7 +429,7 @@ impl pallet_utility::Config for Runtime {
 // AvN pallets
 impl pallet_avn_offence_handler::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type Enforcer = ();
+    type Enforcer = ParachainStaking;
     type WeightInfo = pallet_avn_offence_handler::default_weights::SubstrateWeight<Runtime>;
 }
 
@@ -464,6 +487,7 @@ parameter_types! {
     pub const EthAutoSubmitSummaries: bool = true;
     pub const AvnAutoSubmitSummaries: bool = false;
     pub const AvnInstanceId: u8 = 2u8;
+    pub const CheckpointSummaryInstanceId: u8 = 3u8;
     pub const ExternalValidationEnabled: bool = false;
 }
 
@@ -480,6 +504,7 @@ impl pallet_summary::Config<EthSummary> for Runtime {
     type InstanceId = EthereumInstanceId;
     type ExternalValidationEnabled = ExternalValidationEnabled;
     type ExternalValidator = NoopWatchtower<AccountId>;
+    type LeafProvider = ();
 }
 
 pub type AvnAnchorSummary = pallet_summary::Instance2;
@@ -495,6 +520,24 @@ impl pallet_summary::Config<AvnAnchorSummary> for Runtime {
     type InstanceId = AvnInstanceId;
     type ExternalValidationEnabled = ExternalValidationEnabled;
     type ExternalValidator = NoopWatchtower<AccountId>;
+    type LeafProvider = ();
+}
+
+/// Publishes avn-anchor checkpoints to Ethereum.
+pub type CheckpointSummaryInstance = pallet_summary::Instance3;
+impl pallet_summary::Config<CheckpointSummaryInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AdvanceSlotGracePeriod = AdvanceSlotGracePeriod;
+    type MinBlockAge = MinBlockAge;
+    type AccountToBytesConvert = Avn;
+    type ReportSummaryOffence = Offences;
+    type WeightInfo = pallet_summary::default_weights::SubstrateWeight<Runtime>;
+    type BridgeInterface = EthBridge;
+    type AutoSubmitSummaries = EthAutoSubmitSummaries;
+    type InstanceId = CheckpointSummaryInstanceId;
+    type ExternalValidationEnabled = ExternalValidationEnabled;
+    type ExternalValidator = NoopWatchtower<AccountId>;
+    type LeafProvider = AvnAnchor;
 }
 
 parameter_types! {
@@ -502,6 +545,8 @@ parameter_types! {
     pub const AvnAnchorRewardPotId: PalletId = NODE_MANAGER_PALLET_ID;
     pub AvnAnchorRewardPot: AccountId = AvnAnchorRewardPotId::get().into_account_truncating();
     pub const MaxPeriodsPerPayout: u32 = 10;
+    pub const MaxCheckpointsPerBatch: u32 = 64;
+    pub const PublishedCheckpointRetention: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_avn_anchor::Config for Runtime {
@@ -514,14 +559,18 @@ impl pallet_avn_anchor::Config for Runtime {
     type Signature = Signature;
     type Token = EthAddress;
     type DefaultCheckpointFee = DefaultCheckpointFee;
+    type ChallengeBond = CheckpointChallengeBond;
     type AppChainAssetId = CurrencyId;
     type MaxRegisteredAppChains = MaxRegisteredAppChains;
     type AssetRegistryStringLimit = AssetRegistryStringLimit;
     type AssetRegistry = AssetRegistry;
     type RewardPot = AvnAnchorRewardPot;
     type MaxPeriodsPerPayout = MaxPeriodsPerPayout;
+    type MaxCheckpointsPerBatch = MaxCheckpointsPerBatch;
+    type PublishedCheckpointRetention = PublishedCheckpointRetention;
     // TODO: replace `()` with a runtime type implementing app-chain/node eligibility logic.
     type AppChainRewardEligibility = ();
+    type CheckpointHooks = ();
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -569,9 +618,23 @@ impl pallet_nft_manager::Config for Runtime {
     type Public = <Signature as sp_runtime::traits::Verify>::Signer;
     type Signature = Signature;
     type BatchBound = pallet_nft_manager::BatchNftBound;
+    type ReleaseExternalRefOnBurn = ConstBool<false>;
+    type Token = EthAddress;
+    type TokenBalance = Balance;
+    type PaymentHandler = TokenManager;
+    type EscrowPalletId = NftEscrowPalletId;
+    type FiatSettlementOrigin = EnsureRoot<AccountId>;
     type WeightInfo = pallet_nft_manager::default_weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const NftEscrowPalletId: PalletId = PalletId(*b"nft/escr");
+}
+
+parameter_types! {
+    pub const SponsorshipPeriod: BlockNumber = DAYS;
+}
+
 impl pallet_avn_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -582,6 +645,10 @@ impl pallet_avn_proxy::Config for Runtime {
     type WeightInfo = pallet_avn_proxy::default_weights::SubstrateWeight<Runtime>;
     type PaymentHandler = TokenManager;
     type Token = EthAddress;
+    type MaxSponsoredCallTypes = ConstU32<32>;
+    type MaxSponsoredBeneficiaries = ConstU32<100>;
+    type SponsorshipPeriod = SponsorshipPeriod;
+    type MetaTransactionFilter = MetaTransactionFilter;
 }
 
 impl pallet_eth_bridge::Config<MainEthBridge> for Runtime {
@@ -595,7 +662,7 @@ impl pallet_eth_bridge::Config<MainEthBridge> for Runtime {
     type TimeProvider = Timestamp;
     type WeightInfo = pallet_eth_bridge::default_weights::SubstrateWeight<Runtime>;
     type BridgeInterfaceNotification =
-        (Summary, TokenManager, NftManager, ParachainStaking, ValidatorsManager);
+        (Summary, CheckpointSummary, TokenManager, NftManager, ParachainStaking, ValidatorsManager);
     type ProcessedEventsHandler = NoEventsFilter;
     type EthereumEventsMigration = EthSecondBridge;
     type Quorum = Avn;
@@ -612,7 +679,7 @@ impl pallet_eth_bridge::Config<SecondaryEthBridge> for Runtime {
     type TimeProvider = Timestamp;
     type WeightInfo = pallet_eth_bridge::default_weights::SubstrateWeight<Runtime>;
     type BridgeInterfaceNotification =
-        (Summary, TokenManager, NftManager, ParachainStaking, ValidatorsManager);
+        (Summary, CheckpointSummary, TokenManager, NftManager, ParachainStaking, ValidatorsManager);
     type ProcessedEventsHandler = NoEventsFilter;
     type EthereumEventsMigration = ();
     type Quorum = Avn;
@@ -637,6 +704,7 @@ parameter_types! {
     pub const MetadataDepositBase: Balance = 1 * MILLI_AVT;
     pub const MetadataDepositPerByte: Balance = 100 * MICRO_AVT;
     pub const DefaultCheckpointFee: Balance = 100 * MILLI_AVT;
+    pub const CheckpointChallengeBond: Balance = 10 * AVT;
 }
 const ASSET_ACCOUNT_DEPOSIT: Balance = 100 * MICRO_AVT;
 
@@ -791,7 +859,8 @@ impl pallet_preimage::Config for Runtime {
 }
 
 // Accounts protected from being deleted due to a too low amount of funds.
-const IMMORTAL_ACCOUNTS: &[PalletId] = &[AvnTreasuryPotId::get(), RewardPotId::get()];
+const IMMORTAL_ACCOUNTS: &[PalletId] =
+    &[AvnTreasuryPotId::get(), RewardPotId::get(), NftEscrowPalletId::get()];
 pub struct DustRemovalWhitelist;
 
 impl Contains<AccountId> for DustRemovalWhitelist
@@ -43,7 +43,7 @@ use polkadot_sdk::frame_support::{
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 pub use polkadot_sdk::sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use proxy_config::AvnProxyConfig;
+use proxy_config::{AvnProxyConfig, MetaTransactionFilter};
 
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_session::historical::{self as pallet_session_historical};
@@ -110,7 +110,11 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
-    (pallet_validators_manager::migration::ValidatorsManagerMigrations<Runtime>,),
+    (
+        pallet_validators_manager::migration::ValidatorsManagerMigrations<Runtime>,
+        pallet_nft_manager::migration::NftManagerMigrations<Runtime>,
+        pallet_parachain_staking::migration::ParachainStakingMigrations<Runtime>,
+    ),
 >;
 
 impl_opaque_keys! {
@@ -371,6 +375,9 @@ mod runtime {
     #[runtime::pallet_index(111)]
     pub type EthSecondBridge = pallet_eth_bridge<Instance2>;
 
+    #[runtime::pallet_index(112)]
+    pub type CheckpointSummary = pallet_summary<Instance3>;
+
     #[runtime::pallet_index(97)]
     pub type Preimage = pallet_preimage;
 